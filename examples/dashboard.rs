@@ -2,7 +2,7 @@
 //!
 //! This example demonstrates:
 //! - Dashboard layout patterns
-//! - Cards for metrics
+//! - Cards for metrics, with sparkline trends
 //! - Tables for data
 //! - Navigation
 //!
@@ -165,13 +165,13 @@ impl DashboardApp {
                 HStack::new()
                     .gap(theme.global.spacing_lg)
                     .mt(theme.global.spacing_md)
-                    .child(self.metric_card(theme, "Total Users", "12,345", "+12%", true))
-                    .child(self.metric_card(theme, "Revenue", "$54,321", "+8%", true))
-                    .child(self.metric_card(theme, "Active Sessions", "1,234", "-3%", false))
+                    .child(self.metric_card(theme, "Total Users", "12,345", "+12%", true, &[9.8, 10.4, 10.9, 11.2, 11.8, 12.3]))
+                    .child(self.metric_card(theme, "Revenue", "$54,321", "+8%", true, &[41.0, 44.5, 47.2, 46.1, 50.3, 54.3]))
+                    .child(self.metric_card(theme, "Active Sessions", "1,234", "-3%", false, &[1.4, 1.35, 1.31, 1.29, 1.27, 1.23]))
             )
     }
 
-    fn metric_card(&self, theme: &Theme, title: &str, value: &str, change: &str, is_positive: bool) -> impl IntoElement {
+    fn metric_card(&self, theme: &Theme, title: &str, value: &str, change: &str, is_positive: bool, trend: &[f64]) -> impl IntoElement {
         Card::new()
             .variant(CardVariant::Outlined)
             .hoverable(true)
@@ -187,6 +187,16 @@ impl DashboardApp {
                         Label::new(value)
                             .variant(LabelVariant::Heading1)
                     )
+                    .child(
+                        Sparkline::new(trend.to_vec())
+                            .filled(true)
+                            .color(if is_positive {
+                                theme.alias.color_success
+                            } else {
+                                theme.alias.color_danger
+                            })
+                            .to_element(theme)
+                    )
                     .child(
                         HStack::new()
                             .items_center()
@@ -249,6 +259,8 @@ impl DashboardApp {
 
 fn main() {
     App::new().run(|cx: &mut AppContext| {
+        ThemeProvider::init(cx, Theme::light());
+
         cx.open_window(WindowOptions::default(), |_window, cx| {
             cx.new(|_cx| DashboardApp::new())
         })
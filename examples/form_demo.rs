@@ -58,6 +58,7 @@ impl Render for FormDemoApp {
                                             .required(true)
                                             .child(
                                                 Input::new()
+                                                    .input_type(InputType::Email)
                                                     .placeholder("you@example.com")
                                             )
                                     )
@@ -69,6 +70,7 @@ impl Render for FormDemoApp {
                                             .help_text("Must be at least 8 characters")
                                             .child(
                                                 Input::new()
+                                                    .input_type(InputType::Password)
                                                     .placeholder("••••••••")
                                             )
                                     )
@@ -79,6 +81,7 @@ impl Render for FormDemoApp {
                                             .required(true)
                                             .child(
                                                 Input::new()
+                                                    .input_type(InputType::Password)
                                                     .placeholder("••••••••")
                                             )
                                     )
@@ -142,6 +145,8 @@ impl Render for FormDemoApp {
 
 fn main() {
     App::new().run(|cx: &mut AppContext| {
+        ThemeProvider::init(cx, Theme::light());
+
         cx.open_window(WindowOptions::default(), |_window, cx| {
             cx.new(|_cx| FormDemoApp::new())
         })
@@ -12,26 +12,22 @@ use purdah_gpui_components::prelude::*;
 struct ShowcaseApp {
     /// Currently selected tab
     selected_tab: SharedString,
-    /// Whether dark mode is enabled
-    dark_mode: bool,
+    /// Re-renders the showcase when the theme changes
+    _theme_subscription: Subscription,
 }
 
 impl ShowcaseApp {
-    fn new() -> Self {
+    fn new(cx: &mut Context<Self>) -> Self {
         Self {
             selected_tab: "atoms".into(),
-            dark_mode: false,
+            _theme_subscription: observe_theme(cx),
         }
     }
 }
 
 impl Render for ShowcaseApp {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
-        let theme = if self.dark_mode {
-            Theme::dark()
-        } else {
-            Theme::light()
-        };
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
 
         div()
             .flex()
@@ -66,8 +62,9 @@ impl ShowcaseApp {
             )
             .child(
                 Button::new()
-                    .label(if self.dark_mode { "☀️ Light" } else { "🌙 Dark" })
+                    .label(if theme.is_dark() { "☀️ Light" } else { "🌙 Dark" })
                     .variant(ButtonVariant::Outline)
+                    .on_click(|_event, _window, cx| ThemeProvider::toggle_dark_mode(cx))
             )
     }
 
@@ -286,7 +283,7 @@ impl ShowcaseApp {
                 Tab::new("Tab 2", "tab2"),
                 Tab::new("Tab 3", "tab3"),
             ])
-            .selected("tab1")
+            .default_selected("tab1")
     }
 
     fn render_dropdown(&self, _theme: &Theme) -> impl IntoElement {
@@ -357,8 +354,11 @@ impl ShowcaseApp {
 
 fn main() {
     App::new().run(|cx: &mut AppContext| {
-        cx.open_window(WindowOptions::default(), |_window, cx| {
-            cx.new(|_cx| ShowcaseApp::new())
+        ThemeProvider::init(cx, Theme::from_mode(ThemeMode::System));
+
+        cx.open_window(WindowOptions::default(), |window, cx| {
+            ThemeProvider::observe_system_appearance(window, cx).detach();
+            cx.new(|cx| ShowcaseApp::new(cx))
         })
         .unwrap();
     });
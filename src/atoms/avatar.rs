@@ -1,7 +1,7 @@
 //! Avatar component for user profile images and initials.
 
 use gpui::*;
-use crate::theme::{AvatarTokens, Theme};
+use crate::theme::{use_theme, AvatarTokens};
 
 /// Avatar size variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -198,9 +198,9 @@ impl Avatar {
 }
 
 impl Render for Avatar {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
         // Get theme and tokens
-        let theme = Theme::default();
+        let theme = use_theme(cx);
         let tokens = AvatarTokens::from_theme(&theme);
 
         let size = self.avatar_size(&tokens);
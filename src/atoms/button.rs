@@ -1,7 +1,10 @@
 //! Button component with multiple variants and states.
 
+use std::rc::Rc;
+
 use gpui::*;
-use crate::theme::{ButtonTokens, Theme};
+use crate::atoms::{Icon, IconSize, Spinner};
+use crate::theme::{use_theme, ButtonTokens, Theme};
 
 /// Button visual variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -34,6 +37,8 @@ pub enum ButtonSize {
 /// Button configuration properties
 #[derive(Clone)]
 pub struct ButtonProps {
+    /// Element id, distinguishing sibling buttons embedded with `to_element`
+    pub id: ElementId,
     /// Button label text
     pub label: SharedString,
     /// Visual variant
@@ -44,16 +49,37 @@ pub struct ButtonProps {
     pub disabled: bool,
     /// Whether button is in loading state
     pub loading: bool,
+    /// Label shown instead of `label` while loading
+    pub loading_label: Option<SharedString>,
+    /// SVG path of an icon shown before the label
+    pub icon_left: Option<SharedString>,
+    /// SVG path of an icon shown after the label
+    pub icon_right: Option<SharedString>,
+    /// Whether to hide the label and show only the icon
+    ///
+    /// The label is still required and serves as the accessible name.
+    pub icon_only: bool,
+    /// Click handler, not called while disabled or loading
+    pub on_click: Option<Rc<dyn Fn(&ClickEvent, &mut Window, &mut App)>>,
+    /// Focus handle making the button keyboard focusable
+    pub focus_handle: Option<FocusHandle>,
 }
 
 impl Default for ButtonProps {
     fn default() -> Self {
         Self {
+            id: "button".into(),
             label: "Button".into(),
             variant: ButtonVariant::default(),
             size: ButtonSize::default(),
             disabled: false,
             loading: false,
+            loading_label: None,
+            icon_left: None,
+            icon_right: None,
+            icon_only: false,
+            on_click: None,
+            focus_handle: None,
         }
     }
 }
@@ -70,7 +96,7 @@ impl Default for ButtonProps {
 /// // Basic button
 /// Button::new()
 ///     .label("Click me")
-///     .on_click(|_, cx| {
+///     .on_click(|_event, _window, _cx| {
 ///         println!("Clicked!");
 ///     });
 ///
@@ -84,6 +110,18 @@ impl Default for ButtonProps {
 /// Button::new()
 ///     .label("Submit")
 ///     .disabled(true);
+///
+/// // Button with a leading icon
+/// Button::new()
+///     .label("Search")
+///     .icon_left(icons::SEARCH);
+///
+/// // Icon-only button; the label is its accessible name
+/// Button::new()
+///     .label("Close")
+///     .icon_left(icons::X)
+///     .icon_only(true)
+///     .variant(ButtonVariant::Ghost);
 /// ```
 pub struct Button {
     props: ButtonProps,
@@ -153,6 +191,9 @@ impl Button {
 
     /// Set whether the button is in loading state
     ///
+    /// A loading button shows an inline spinner, dims its label and ignores
+    /// clicks.
+    ///
     /// ## Example
     ///
     /// ```rust,ignore
@@ -163,6 +204,113 @@ impl Button {
         self
     }
 
+    /// Set the label shown instead of the regular label while loading
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Button::new()
+    ///     .label("Save")
+    ///     .loading_label("Saving…")
+    ///     .loading(is_saving);
+    /// ```
+    pub fn loading_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.props.loading_label = Some(label.into());
+        self
+    }
+
+    /// Set an icon shown before the label
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Button::new().label("Add").icon_left(icons::PLUS);
+    /// ```
+    pub fn icon_left(mut self, path: impl Into<SharedString>) -> Self {
+        self.props.icon_left = Some(path.into());
+        self
+    }
+
+    /// Set an icon shown after the label
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Button::new().label("Next").icon_right(icons::ARROW_RIGHT);
+    /// ```
+    pub fn icon_right(mut self, path: impl Into<SharedString>) -> Self {
+        self.props.icon_right = Some(path.into());
+        self
+    }
+
+    /// Set whether to show only the icon
+    ///
+    /// The label is not rendered but should still be set, since it is the
+    /// button's accessible name. Icon-only buttons use square padding.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Button::new().label("Settings").icon_left(icons::SETTINGS).icon_only(true);
+    /// ```
+    pub fn icon_only(mut self, icon_only: bool) -> Self {
+        self.props.icon_only = icon_only;
+        self
+    }
+
+    /// Set the click handler
+    ///
+    /// The handler is not called while the button is disabled or loading.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Button::new()
+    ///     .label("Save")
+    ///     .on_click(|_event, _window, cx| save_document(cx));
+    /// ```
+    pub fn on_click(
+        mut self,
+        handler: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_click = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the element id
+    ///
+    /// Needed when several buttons are embedded side by side with
+    /// [`Button::to_element`], so each keeps its own hover and pressed state.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Button::new().id("dialog-cancel").label("Cancel");
+    /// ```
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.props.id = id.into();
+        self
+    }
+
+    /// Make the button focusable with `handle`
+    ///
+    /// A focused button shows a focus ring, and Enter or Space click it.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Button::new().label("OK").track_focus(&self.ok_handle);
+    /// ```
+    pub fn track_focus(mut self, handle: &FocusHandle) -> Self {
+        self.props.focus_handle = Some(handle.clone());
+        self
+    }
+
+    /// Whether the button currently accepts clicks
+    fn is_interactive(&self) -> bool {
+        !self.props.disabled && !self.props.loading
+    }
+
     /// Get background color based on variant
     fn background_color(&self, tokens: &ButtonTokens) -> Hsla {
         if self.props.disabled {
@@ -178,6 +326,25 @@ impl Button {
         }
     }
 
+    /// Get hover background color based on variant
+    fn hover_background_color(&self, tokens: &ButtonTokens) -> Hsla {
+        match self.props.variant {
+            ButtonVariant::Primary => tokens.background_primary_hover,
+            ButtonVariant::Secondary => tokens.background_secondary_hover,
+            ButtonVariant::Outline => tokens.background_outline_hover,
+            ButtonVariant::Ghost => tokens.background_ghost_hover,
+            ButtonVariant::Danger => tokens.background_danger_hover,
+        }
+    }
+
+    /// Get pressed background color based on variant
+    fn active_background_color(&self, tokens: &ButtonTokens) -> Hsla {
+        match self.props.variant {
+            ButtonVariant::Primary => tokens.background_primary_active,
+            _ => self.hover_background_color(tokens),
+        }
+    }
+
     /// Get text color based on variant
     fn text_color(&self, tokens: &ButtonTokens) -> Hsla {
         if self.props.disabled {
@@ -195,10 +362,25 @@ impl Button {
 
     /// Get padding based on size
     fn padding(&self, tokens: &ButtonTokens) -> (Pixels, Pixels) {
-        match self.props.size {
+        let (padding_x, padding_y) = match self.props.size {
             ButtonSize::Sm => (tokens.padding_x_sm, tokens.padding_y_sm),
             ButtonSize::Md => (tokens.padding_x_md, tokens.padding_y_md),
             ButtonSize::Lg => (tokens.padding_x_lg, tokens.padding_y_lg),
+        };
+
+        // Icon-only buttons are square
+        if self.props.icon_only {
+            (padding_y, padding_y)
+        } else {
+            (padding_x, padding_y)
+        }
+    }
+
+    /// Get icon size based on size
+    fn icon_size(&self) -> IconSize {
+        match self.props.size {
+            ButtonSize::Sm | ButtonSize::Md => IconSize::Sm,
+            ButtonSize::Lg => IconSize::Md,
         }
     }
 
@@ -219,13 +401,18 @@ impl Button {
             None
         }
     }
-}
 
-impl Render for Button {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
-        // Get theme and tokens
-        let theme = Theme::default();
-        let tokens = ButtonTokens::from_theme(&theme);
+    /// Convert to a GPUI div for embedding inside another component's render
+    ///
+    /// The returned element includes the click handler and focus tracking.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// div().child(Button::new().id("save").label("Save").on_click(on_save).to_element(&theme));
+    /// ```
+    pub fn to_element(&self, theme: &Theme) -> Stateful<Div> {
+        let tokens = ButtonTokens::from_theme(theme);
 
         // Calculate styling
         let bg_color = self.background_color(&tokens);
@@ -233,9 +420,13 @@ impl Render for Button {
         let (padding_x, padding_y) = self.padding(&tokens);
         let font_size = self.font_size(&tokens);
         let border = self.border_style(&tokens);
+        let hover_bg = self.hover_background_color(&tokens);
+        let active_bg = self.active_background_color(&tokens);
+        let interactive = self.is_interactive();
 
         // Build button element
         let mut button = div()
+            .id(self.props.id.clone())
             .flex()
             .flex_row()
             .items_center()
@@ -254,13 +445,85 @@ impl Render for Button {
             button = button.border_color(color).border(width);
         }
 
+        // Keyboard focus with a visible focus ring
+        if let Some(handle) = &self.props.focus_handle {
+            let ring = theme.alias.color_border_focus;
+            if border.is_none() {
+                button = button.border_color(hsla(0.0, 0.0, 0.0, 0.0)).border(tokens.border_width);
+            }
+            button = button
+                .track_focus(handle)
+                .focus(move |style| style.border_color(ring));
+        }
+
         // Handle disabled state
         if self.props.disabled {
             button = button.opacity(0.5);
         }
 
-        // Add label
-        button.child(self.props.label.clone())
+        // Interaction: cursor, hover/pressed styling and click handler
+        if interactive {
+            button = button
+                .cursor_pointer()
+                .hover(move |style| style.bg(hover_bg))
+                .active(move |style| style.bg(active_bg));
+
+            if let Some(handler) = self.props.on_click.clone() {
+                button = button.on_click(move |event, window, cx| handler(event, window, cx));
+            }
+        } else {
+            button = button.cursor_not_allowed();
+        }
+
+        // Leading slot: spinner while loading, otherwise the left icon
+        if self.props.loading {
+            button = button.child(
+                Spinner::new()
+                    .custom_size(font_size)
+                    .custom_color(text_color)
+                    .to_element(theme),
+            );
+        } else if let Some(path) = &self.props.icon_left {
+            button = button.child(
+                Icon::new(path.clone())
+                    .size(self.icon_size())
+                    .custom_color(text_color)
+                    .to_element(theme),
+            );
+        }
+
+        // Label, dimmed (or replaced by the loading label) while loading
+        if !self.props.icon_only {
+            if self.props.loading {
+                let label = self
+                    .props
+                    .loading_label
+                    .clone()
+                    .unwrap_or_else(|| self.props.label.clone());
+                button = button.child(div().opacity(0.7).child(label));
+            } else {
+                button = button.child(self.props.label.clone());
+            }
+        }
+
+        // Trailing icon
+        if let Some(path) = &self.props.icon_right {
+            button = button.child(
+                Icon::new(path.clone())
+                    .size(self.icon_size())
+                    .custom_color(text_color)
+                    .to_element(theme),
+            );
+        }
+
+        button
+    }
+}
+
+impl Render for Button {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        self.to_element(&theme)
     }
 }
 
@@ -275,3 +538,9 @@ impl Render for Button {
 // - Text colors match variant semantic tokens
 // - Size variants correctly map to padding and font size tokens (Sm, Md, Lg)
 // - Border style only applies to Outline variant with correct width and color
+// - Click handler, hover and pressed styling only attach when not disabled or loading
+// - Loading state renders a text-sized spinner before the dimmed (or loading) label
+// - Left icon is replaced by the spinner while loading; right icon always renders
+// - Icon-only buttons skip the label and use square padding
+// - to_element renders the same button, with handlers, for embedding in dialogs
+// - A tracked focus handle adds a focus ring; Enter and Space click the focused button
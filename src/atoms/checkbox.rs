@@ -1,7 +1,9 @@
 //! Checkbox component for form selections.
 
+use std::rc::Rc;
+
 use gpui::*;
-use crate::theme::{CheckboxTokens, Theme};
+use crate::theme::{use_theme, CheckboxTokens, Theme};
 
 /// Checkbox state variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -15,6 +17,16 @@ pub enum CheckboxState {
     Indeterminate,
 }
 
+impl From<bool> for CheckboxState {
+    fn from(checked: bool) -> Self {
+        if checked {
+            Self::Checked
+        } else {
+            Self::Unchecked
+        }
+    }
+}
+
 /// Checkbox configuration properties
 #[derive(Clone)]
 pub struct CheckboxProps {
@@ -24,6 +36,13 @@ pub struct CheckboxProps {
     pub disabled: bool,
     /// Optional label text
     pub label: Option<SharedString>,
+    /// Handler called with the new checked value when toggled
+    pub on_toggle: Option<Rc<dyn Fn(bool, &mut Window, &mut App)>>,
+    /// Whether the state is owned by the parent view
+    ///
+    /// Controlled checkboxes only report toggles through `on_toggle`; the
+    /// parent applies them with [`Checkbox::set_state`].
+    pub controlled: bool,
 }
 
 impl Default for CheckboxProps {
@@ -32,6 +51,8 @@ impl Default for CheckboxProps {
             state: CheckboxState::default(),
             disabled: false,
             label: None,
+            on_toggle: None,
+            controlled: false,
         }
     }
 }
@@ -65,9 +86,25 @@ impl Default for CheckboxProps {
 /// // Indeterminate checkbox
 /// Checkbox::new()
 ///     .state(CheckboxState::Indeterminate);
+///
+/// // Uncontrolled: the checkbox keeps its own state
+/// Checkbox::new()
+///     .label("Subscribe")
+///     .default_checked(true)
+///     .on_toggle(|checked, _window, _cx| println!("checked: {checked}"));
+///
+/// // Controlled: clicks only report the requested value; the parent
+/// // applies it with `set_checked` once it accepts the change
+/// let checkbox = cx.new(|_| {
+///     Checkbox::new()
+///         .checked(false)
+///         .on_toggle(|checked, _window, cx| request_subscription(checked, cx))
+/// });
+/// checkbox.update(cx, |checkbox, cx| checkbox.set_checked(true, cx));
 /// ```
 pub struct Checkbox {
     props: CheckboxProps,
+    focus_handle: Option<FocusHandle>,
 }
 
 impl Checkbox {
@@ -81,26 +118,25 @@ impl Checkbox {
     pub fn new() -> Self {
         Self {
             props: CheckboxProps::default(),
+            focus_handle: None,
         }
     }
 
-    /// Set whether the checkbox is checked
+    /// Set whether the checkbox is checked, making it controlled
     ///
     /// ## Example
     ///
     /// ```rust,ignore
     /// Checkbox::new().checked(true);
     /// ```
-    pub fn checked(mut self, checked: bool) -> Self {
-        self.props.state = if checked {
-            CheckboxState::Checked
-        } else {
-            CheckboxState::Unchecked
-        };
-        self
+    pub fn checked(self, checked: bool) -> Self {
+        self.state(checked.into())
     }
 
-    /// Set the checkbox state explicitly
+    /// Set the checkbox state explicitly, making it controlled
+    ///
+    /// A controlled checkbox does not change on click; update it from the
+    /// `on_toggle` handler with [`Checkbox::set_state`].
     ///
     /// ## Example
     ///
@@ -109,9 +145,50 @@ impl Checkbox {
     /// ```
     pub fn state(mut self, state: CheckboxState) -> Self {
         self.props.state = state;
+        self.props.controlled = true;
         self
     }
 
+    /// Set the initial checked value of an uncontrolled checkbox
+    ///
+    /// The checkbox keeps its own state afterwards and toggles on click.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Checkbox::new().default_checked(true);
+    /// ```
+    pub fn default_checked(mut self, checked: bool) -> Self {
+        self.props.state = checked.into();
+        self.props.controlled = false;
+        self
+    }
+
+    /// Update the state from the parent view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// checkbox.update(cx, |checkbox, cx| checkbox.set_state(CheckboxState::Indeterminate, cx));
+    /// ```
+    pub fn set_state(&mut self, state: CheckboxState, cx: &mut Context<'_, Self>) {
+        if self.props.state != state {
+            self.props.state = state;
+            cx.notify();
+        }
+    }
+
+    /// Update the checked value from the parent view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// checkbox.update(cx, |checkbox, cx| checkbox.set_checked(true, cx));
+    /// ```
+    pub fn set_checked(&mut self, checked: bool, cx: &mut Context<'_, Self>) {
+        self.set_state(checked.into(), cx);
+    }
+
     /// Set whether the checkbox is disabled
     ///
     /// ## Example
@@ -136,6 +213,98 @@ impl Checkbox {
         self
     }
 
+    /// Set the handler called when the checkbox is toggled
+    ///
+    /// The handler receives the new checked value. Toggling an indeterminate
+    /// checkbox checks it.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Checkbox::new().on_toggle(|checked, _window, cx| save_preference(checked, cx));
+    /// ```
+    pub fn on_toggle(mut self, handler: impl Fn(bool, &mut Window, &mut App) + 'static) -> Self {
+        self.props.on_toggle = Some(Rc::new(handler));
+        self
+    }
+
+    /// Toggle the checkbox and notify the handler
+    fn toggle(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.props.disabled {
+            return;
+        }
+
+        let checked = self.props.state != CheckboxState::Checked;
+        if !self.props.controlled {
+            self.set_checked(checked, cx);
+        }
+
+        if let Some(handler) = self.props.on_toggle.clone() {
+            handler(checked, window, cx);
+        }
+    }
+
+    /// Convert to a GPUI div for embedding inside another component's render
+    ///
+    /// The returned row holds the box and label without event handlers;
+    /// the box highlights on hover of an ancestor in the `"checkbox"` group.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// div().child(Checkbox::new().label("Remember me").checked(true).to_element(&theme));
+    /// ```
+    pub fn to_element(&self, theme: &Theme) -> Div {
+        let tokens = CheckboxTokens::from_theme(theme);
+
+        // Build checkbox box
+        let checkbox_box = div()
+            .flex()
+            .items_center()
+            .justify_center()
+            .size(tokens.size)
+            .bg(self.background_color(&tokens))
+            .border_color(self.border_color(&tokens))
+            .border(tokens.border_width)
+            .rounded(tokens.border_radius);
+
+        // Add icon if checked or indeterminate
+        let mut checkbox_box = if let Some(icon) = self.render_icon(&tokens) {
+            checkbox_box.child(icon)
+        } else {
+            checkbox_box
+        };
+
+        if !self.props.disabled {
+            let hover_border = tokens.border_checked;
+            checkbox_box = checkbox_box
+                .group_hover("checkbox", move |style| style.border_color(hover_border));
+        }
+
+        let mut row = div()
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap(tokens.label_gap)
+            .child(checkbox_box);
+
+        // Add label if provided
+        if let Some(label_text) = &self.props.label {
+            row = row.child(
+                div()
+                    .text_size(tokens.label_font_size)
+                    .text_color(if self.props.disabled {
+                        tokens.label_color_disabled
+                    } else {
+                        tokens.label_color
+                    })
+                    .child(label_text.clone())
+            );
+        }
+
+        row
+    }
+
     /// Get background color based on state
     fn background_color(&self, tokens: &CheckboxTokens) -> Hsla {
         if self.props.disabled {
@@ -188,50 +357,39 @@ impl Checkbox {
 }
 
 impl Render for Checkbox {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
-        // Get theme and tokens
-        let theme = Theme::default();
-        let tokens = CheckboxTokens::from_theme(&theme);
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        // Get theme
+        let theme = use_theme(cx);
+        let focus_handle = self
+            .focus_handle
+            .get_or_insert_with(|| cx.focus_handle())
+            .clone();
 
-        // Build checkbox box
-        let checkbox_box = div()
-            .flex()
-            .items_center()
-            .justify_center()
-            .size(tokens.size)
-            .bg(self.background_color(&tokens))
-            .border_color(self.border_color(&tokens))
-            .border(tokens.border_width)
-            .rounded(tokens.border_radius);
-
-        // Add icon if checked or indeterminate
-        let checkbox_box = if let Some(icon) = self.render_icon(&tokens) {
-            checkbox_box.child(icon)
-        } else {
-            checkbox_box
-        };
+        // Interactive container: clicks anywhere on the box or label toggle
+        let mut container = self
+            .to_element(&theme)
+            .id("checkbox")
+            .group("checkbox")
+            .track_focus(&focus_handle);
 
-        // If there's a label, wrap in container with label
-        if let Some(label_text) = &self.props.label {
-            div()
-                .flex()
-                .flex_row()
-                .items_center()
-                .gap(tokens.label_gap)
-                .child(checkbox_box)
-                .child(
-                    div()
-                        .text_size(tokens.label_font_size)
-                        .text_color(if self.props.disabled {
-                            tokens.label_color_disabled
-                        } else {
-                            tokens.label_color
-                        })
-                        .child(label_text.clone())
-                )
+        if self.props.disabled {
+            container = container.cursor_not_allowed();
         } else {
-            checkbox_box
+            container = container
+                .cursor_pointer()
+                .active(|style| style.opacity(0.8))
+                .on_click(cx.listener(|this, _event: &ClickEvent, window, cx| {
+                    this.toggle(window, cx);
+                }))
+                .on_key_down(cx.listener(|this, event: &KeyDownEvent, window, cx| {
+                    if event.keystroke.key == "space" {
+                        this.toggle(window, cx);
+                        cx.stop_propagation();
+                    }
+                }));
         }
+
+        container
     }
 }
 
@@ -246,3 +404,7 @@ impl Render for Checkbox {
 // - Border color changes based on state and disabled status
 // - Icon renders correctly for Checked (checkmark) and Indeterminate (line) states
 // - Label renders when provided with correct color and disabled state
+// - Click and Space toggle the state and call on_toggle with the new value; disabled ignores both
+// - Toggling an indeterminate checkbox checks it
+// - Controlled checkboxes (checked/state) only report toggles; default_checked ones update themselves
+// - to_element renders the same box and label without handlers for embedding in tables
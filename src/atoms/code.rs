@@ -0,0 +1,95 @@
+//! Code component for inline monospace text.
+
+use gpui::*;
+use crate::theme::{use_theme, Theme};
+
+/// Monospace font used for code, one that ships with each platform
+#[cfg(target_os = "macos")]
+pub const MONOSPACE_FONT: &str = "Menlo";
+/// Monospace font used for code, one that ships with each platform
+#[cfg(target_os = "windows")]
+pub const MONOSPACE_FONT: &str = "Consolas";
+/// Monospace font used for code, one that ships with each platform
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+pub const MONOSPACE_FONT: &str = "DejaVu Sans Mono";
+
+/// Code configuration properties
+#[derive(Clone)]
+pub struct CodeProps {
+    /// Code text
+    pub text: SharedString,
+}
+
+impl Default for CodeProps {
+    fn default() -> Self {
+        Self { text: "".into() }
+    }
+}
+
+/// Inline code in a monospace font on a tinted background.
+///
+/// Code is for identifiers, paths and commands set inside running text.
+/// It does not wrap or highlight; it is a single run of text.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::atoms::*;
+///
+/// h_flex()
+///     .child("Run ")
+///     .child(Code::new("cargo build").to_element(&theme))
+///     .child(" first");
+/// ```
+pub struct Code {
+    props: CodeProps,
+}
+
+impl Code {
+    /// Create inline code showing `text`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let code = Code::new("Cargo.toml");
+    /// ```
+    pub fn new(text: impl Into<SharedString>) -> Self {
+        Self {
+            props: CodeProps { text: text.into() },
+        }
+    }
+
+    /// Convert to a GPUI element for embedding inside another component's render
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// row.child(Code::new("--release").to_element(&theme));
+    /// ```
+    pub fn to_element(&self, theme: &Theme) -> Div {
+        div()
+            .flex_none()
+            .px(theme.global.spacing_xs)
+            .rounded(theme.global.radius_sm)
+            .bg(theme.alias.color_surface_hover)
+            .font_family(MONOSPACE_FONT)
+            .text_size(theme.global.font_size_sm)
+            .text_color(theme.alias.color_text_primary)
+            .child(self.props.text.clone())
+    }
+}
+
+impl Render for Code {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        self.to_element(&theme)
+    }
+}
+
+// NOTE: Unit tests temporarily removed due to GPUI procedural macro incompatibility with #[test]
+// The macro causes infinite recursion during test compilation (SIGBUS error).
+// Tests can be re-added once GPUI's macro system is updated, or moved to integration tests.
+//
+// Test coverage validated manually:
+// - Text renders in the platform monospace font on the hover surface color
+// - Padding and radius come from global spacing and radius tokens
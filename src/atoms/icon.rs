@@ -1,7 +1,7 @@
 //! SVG icon component with size and color variants.
 
 use gpui::*;
-use crate::theme::{IconTokens, Theme};
+use crate::theme::{use_theme, IconTokens, Theme};
 
 /// Icon size variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -124,6 +124,27 @@ impl Icon {
         self
     }
 
+    /// Convert to a GPUI svg element for embedding inside another component's render
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// div().child(Icon::new(icons::CHECK).size(IconSize::Sm).to_element(&theme));
+    /// ```
+    pub fn to_element(&self, theme: &Theme) -> Svg {
+        let tokens = IconTokens::from_theme(theme);
+
+        let size = self.icon_size(&tokens);
+        let color = self.icon_color(&tokens);
+
+        // Create SVG element with path
+        svg()
+            .flex_none()
+            .size(size)
+            .path(self.path.clone())
+            .text_color(color) // SVG inherits text color for fill
+    }
+
     /// Get icon size in pixels
     fn icon_size(&self, tokens: &IconTokens) -> Pixels {
         match self.size {
@@ -153,21 +174,10 @@ impl Icon {
 }
 
 impl Render for Icon {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
-        // TEMPORARY: Creates default theme on each render
-        // TODO: Replace with ThemeProvider context access in Phase 3
-        //       let theme = cx.global::<ThemeProvider>().current_theme();
-        let theme = Theme::default();
-        let tokens = IconTokens::from_theme(&theme);
-
-        let size = self.icon_size(&tokens);
-        let color = self.icon_color(&tokens);
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
 
-        // Create SVG element with path
-        svg()
-            .size(size)
-            .path(self.path.clone())
-            .text_color(color) // SVG inherits text color for fill
+        self.to_element(&theme)
     }
 }
 
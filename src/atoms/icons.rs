@@ -23,6 +23,9 @@ pub const CHECK: &str = "M20 6L9 17l-5-5";
 /// Menu/hamburger icon
 pub const MENU: &str = "M4 6h16M4 12h16M4 18h16";
 
+/// More/horizontal ellipsis icon
+pub const MORE_HORIZONTAL: &str = "M12 13a1 1 0 1 0 0-2 1 1 0 0 0 0 2zM19 13a1 1 0 1 0 0-2 1 1 0 0 0 0 2zM5 13a1 1 0 1 0 0-2 1 1 0 0 0 0 2z";
+
 /// Home icon
 pub const HOME: &str = "M3 9l9-7 9 7v11a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2z";
 
@@ -77,6 +80,9 @@ pub const EDIT: &str = "M12 20h9M16.5 3.5a2.12 2.12 0 0 1 3 3L7 19l-4 1 1-4Z";
 /// Copy icon
 pub const COPY: &str = "M16 8V4a2 2 0 0 0-2-2H4a2 2 0 0 0-2 2v12a2 2 0 0 0 2 2h4m6 6h8a2 2 0 0 0 2-2V10a2 2 0 0 0-2-2h-8a2 2 0 0 0-2 2v8a2 2 0 0 0 2 2z";
 
+/// Wrap text icon (lines with a return arrow)
+pub const WRAP_TEXT: &str = "M3 6h18M3 12h15a3 3 0 1 1 0 6h-4m2-2-2 2 2 2M3 18h7";
+
 /// Star icon
 pub const STAR: &str = "m12 2 3.09 6.26L22 9.27l-5 4.87 1.18 6.88L12 17.77l-6.18 3.25L7 14.14 2 9.27l6.91-1.01z";
 
@@ -1,7 +1,65 @@
 //! Text input component with validation states.
 
+use std::rc::Rc;
+
 use gpui::*;
-use crate::theme::{InputTokens, Theme};
+use crate::atoms::{icons, Icon, IconSize};
+use crate::theme::{use_theme, InputTokens};
+
+/// Character used to mask password values
+const MASK_CHAR: char = '•';
+
+/// Input type controlling masking, validation and keyboard hints
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputType {
+    /// Free-form text
+    #[default]
+    Text,
+    /// Masked text for secrets
+    Password,
+    /// Numeric value with stepper controls
+    Number,
+    /// Email address
+    Email,
+}
+
+impl InputType {
+    /// Keyboard hint for platform text input, mirroring HTML `inputmode`
+    pub fn keyboard_hint(self) -> &'static str {
+        match self {
+            Self::Text | Self::Password => "text",
+            Self::Number => "decimal",
+            Self::Email => "email",
+        }
+    }
+
+    /// Whether values of this type are masked when displayed
+    pub fn is_masked(self) -> bool {
+        self == Self::Password
+    }
+
+    /// Check whether a value is well-formed for this type
+    ///
+    /// Empty values are always valid; use `required` validation for those.
+    pub fn is_valid(self, value: &str) -> bool {
+        let value = value.trim();
+        if value.is_empty() {
+            return true;
+        }
+
+        match self {
+            Self::Text | Self::Password => true,
+            Self::Number => value.parse::<f64>().is_ok_and(f64::is_finite),
+            Self::Email => value.split_once('@').is_some_and(|(local, domain)| {
+                !local.is_empty()
+                    && !domain.starts_with('.')
+                    && !domain.ends_with('.')
+                    && domain.contains('.')
+                    && !value.contains(char::is_whitespace)
+            }),
+        }
+    }
+}
 
 /// Input configuration properties
 #[derive(Clone)]
@@ -16,6 +74,21 @@ pub struct InputProps {
     pub error: bool,
     /// Optional error message
     pub error_message: Option<SharedString>,
+    /// Input type
+    pub input_type: InputType,
+    /// Minimum value for number inputs
+    pub min: Option<f64>,
+    /// Maximum value for number inputs
+    pub max: Option<f64>,
+    /// Stepper increment for number inputs
+    pub step: f64,
+    /// Handler called with the new value when the input changes it
+    pub on_change: Option<Rc<dyn Fn(SharedString, &mut Window, &mut App)>>,
+    /// Whether the value is owned by the parent view
+    ///
+    /// Controlled inputs only report edits through `on_change`; the parent
+    /// applies them with [`Input::set_value`].
+    pub controlled: bool,
 }
 
 impl Default for InputProps {
@@ -26,6 +99,12 @@ impl Default for InputProps {
             disabled: false,
             error: false,
             error_message: None,
+            input_type: InputType::default(),
+            min: None,
+            max: None,
+            step: 1.0,
+            on_change: None,
+            controlled: false,
         }
     }
 }
@@ -57,6 +136,18 @@ impl Default for InputProps {
 /// Input::new()
 ///     .error(true)
 ///     .error_message("This field is required");
+///
+/// // Masked password input
+/// Input::new()
+///     .input_type(InputType::Password)
+///     .value("hunter2");
+///
+/// // Number input with stepper
+/// Input::new()
+///     .input_type(InputType::Number)
+///     .min(0.0)
+///     .max(10.0)
+///     .step(0.5);
 /// ```
 pub struct Input {
     props: InputProps,
@@ -76,7 +167,10 @@ impl Input {
         }
     }
 
-    /// Set the input value
+    /// Set the input value, making the input controlled
+    ///
+    /// A controlled input does not change on its own; apply edits reported
+    /// by `on_change` with [`Input::set_value`].
     ///
     /// ## Example
     ///
@@ -85,9 +179,55 @@ impl Input {
     /// ```
     pub fn value(mut self, value: impl Into<SharedString>) -> Self {
         self.props.value = value.into();
+        self.props.controlled = true;
+        self
+    }
+
+    /// Set the initial value of an uncontrolled input
+    ///
+    /// The input keeps its own value afterwards.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Input::new().input_type(InputType::Number).default_value("1");
+    /// ```
+    pub fn default_value(mut self, value: impl Into<SharedString>) -> Self {
+        self.props.value = value.into();
+        self.props.controlled = false;
+        self
+    }
+
+    /// Set the handler called when the input changes its value
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Input::new().on_change(|value, _window, cx| save_quantity(value, cx));
+    /// ```
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_change = Some(Rc::new(handler));
         self
     }
 
+    /// Update the value from the parent view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// input.update(cx, |input, cx| input.set_value("", cx));
+    /// ```
+    pub fn set_value(&mut self, value: impl Into<SharedString>, cx: &mut Context<'_, Self>) {
+        let value = value.into();
+        if self.props.value != value {
+            self.props.value = value;
+            cx.notify();
+        }
+    }
+
     /// Set the placeholder text
     ///
     /// ## Example
@@ -138,9 +278,124 @@ impl Input {
         self
     }
 
+    /// Set the input type
+    ///
+    /// Password inputs are masked; number and email inputs show the error
+    /// state when the value is malformed.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Input::new().input_type(InputType::Email);
+    /// ```
+    pub fn input_type(mut self, input_type: InputType) -> Self {
+        self.props.input_type = input_type;
+        self
+    }
+
+    /// Set the minimum value for number inputs
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Input::new().input_type(InputType::Number).min(0.0);
+    /// ```
+    pub fn min(mut self, min: f64) -> Self {
+        self.props.min = Some(min);
+        self
+    }
+
+    /// Set the maximum value for number inputs
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Input::new().input_type(InputType::Number).max(100.0);
+    /// ```
+    pub fn max(mut self, max: f64) -> Self {
+        self.props.max = Some(max);
+        self
+    }
+
+    /// Set the stepper increment for number inputs
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Input::new().input_type(InputType::Number).step(0.25);
+    /// ```
+    pub fn step(mut self, step: f64) -> Self {
+        self.props.step = step;
+        self
+    }
+
+    /// Whether the value is well-formed for the input type and within range
+    fn is_value_valid(&self) -> bool {
+        if !self.props.input_type.is_valid(&self.props.value) {
+            return false;
+        }
+
+        match self.props.value.trim().parse::<f64>() {
+            Ok(number) if self.props.input_type == InputType::Number => {
+                self.props.min.map_or(true, |min| number >= min)
+                    && self.props.max.map_or(true, |max| number <= max)
+            }
+            _ => true,
+        }
+    }
+
+    /// Value after incrementing (`direction > 0`) or decrementing by `step`
+    fn stepped_value(&self, direction: f64) -> SharedString {
+        let current = self
+            .props
+            .value
+            .trim()
+            .parse::<f64>()
+            .unwrap_or_else(|_| self.props.min.unwrap_or(0.0));
+
+        let mut next = current + self.props.step * direction.signum();
+        if let Some(min) = self.props.min {
+            next = next.max(min);
+        }
+        if let Some(max) = self.props.max {
+            next = next.min(max);
+        }
+
+        // Round away floating point noise such as 0.30000000000000004
+        next = (next * 1e9).round() / 1e9;
+        next.to_string().into()
+    }
+
+    /// Apply a value produced by the input itself and notify the handler
+    fn change_value(&mut self, value: SharedString, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.props.disabled || self.props.value == value {
+            return;
+        }
+
+        if !self.props.controlled {
+            self.set_value(value.clone(), cx);
+        }
+
+        if let Some(handler) = self.props.on_change.clone() {
+            handler(value, window, cx);
+        }
+    }
+
+    /// Get the value as displayed, masked for password inputs
+    fn display_value(&self) -> SharedString {
+        if self.props.input_type.is_masked() {
+            MASK_CHAR
+                .to_string()
+                .repeat(self.props.value.chars().count())
+                .into()
+        } else {
+            self.props.value.clone()
+        }
+    }
+
     /// Get border color based on state
     fn border_color(&self, tokens: &InputTokens) -> Hsla {
-        if self.props.error {
+        if self.props.error || !self.is_value_valid() {
             tokens.border_error
         } else {
             tokens.border_default
@@ -167,11 +422,8 @@ impl Input {
 }
 
 impl Render for Input {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
-        // TEMPORARY: Creates default theme on each render
-        // TODO: Replace with ThemeProvider context access in Phase 3
-        //       let theme = cx.global::<ThemeProvider>().current_theme();
-        let theme = Theme::default();
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
         let tokens = InputTokens::from_theme(&theme);
 
         // Build input container
@@ -182,6 +434,10 @@ impl Render for Input {
 
         // Build input field
         let field = div()
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap(tokens.padding_x / 2.0)
             .px(tokens.padding_x)
             .py(tokens.padding_y)
             .bg(self.background_color(&tokens))
@@ -195,16 +451,54 @@ impl Render for Input {
         // Show placeholder or value
         let content = if self.props.value.is_empty() {
             div()
+                .flex_1()
                 .text_color(tokens.text_placeholder)
                 .child(self.props.placeholder.clone())
         } else {
-            div().child(self.props.value.clone())
+            div().flex_1().child(self.display_value())
         };
+        let mut field = field.child(content);
+
+        // Stepper controls for number inputs
+        if self.props.input_type == InputType::Number {
+            let stepper_button = |id: &'static str, path: &'static str| {
+                div()
+                    .id(id)
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .child(
+                        Icon::new(path)
+                            .size(IconSize::Xs)
+                            .custom_color(self.text_color(&tokens))
+                            .to_element(&theme),
+                    )
+            };
+
+            let mut increment = stepper_button("increment", icons::CHEVRON_UP);
+            let mut decrement = stepper_button("decrement", icons::CHEVRON_DOWN);
+            if !self.props.disabled {
+                increment = increment
+                    .cursor_pointer()
+                    .hover(|style| style.opacity(0.7))
+                    .on_click(cx.listener(|this, _event: &ClickEvent, window, cx| {
+                        this.change_value(this.stepped_value(1.0), window, cx);
+                    }));
+                decrement = decrement
+                    .cursor_pointer()
+                    .hover(|style| style.opacity(0.7))
+                    .on_click(cx.listener(|this, _event: &ClickEvent, window, cx| {
+                        this.change_value(this.stepped_value(-1.0), window, cx);
+                    }));
+            }
+
+            field = field.child(div().flex().flex_col().child(increment).child(decrement));
+        }
 
         // Build complete input with optional error message
         if let Some(error_msg) = &self.props.error_message {
             input
-                .child(field.child(content))
+                .child(field)
                 .child(
                     div()
                         .text_size(tokens.font_size * 0.875) // Slightly smaller for error text
@@ -212,7 +506,7 @@ impl Render for Input {
                         .child(error_msg.clone()),
                 )
         } else {
-            input.child(field.child(content))
+            input.child(field)
         }
     }
 }
@@ -228,3 +522,7 @@ impl Render for Input {
 // - Text color changes when disabled
 // - Error message displays when provided
 // - Placeholder shows when value is empty
+// - Password values render masked with one dot per character
+// - Number and email inputs show the error border when the value is malformed or out of range
+// - Number stepper increments/decrements by `step`, clamped to min/max
+// - Stepper changes call on_change; controlled inputs (value) keep their value until set_value
@@ -0,0 +1,92 @@
+//! Kbd component showing a single key as a key cap.
+
+use gpui::*;
+use crate::theme::{use_theme, Theme};
+
+/// Kbd configuration properties
+#[derive(Clone)]
+pub struct KbdProps {
+    /// Key label, such as "Esc", "⌘" or "K"
+    pub key: SharedString,
+}
+
+impl Default for KbdProps {
+    fn default() -> Self {
+        Self { key: "".into() }
+    }
+}
+
+/// A single key drawn as a key cap.
+///
+/// Kbd is for keys mentioned in running text ("press Esc to close"). For
+/// a full shortcut with platform-aware modifiers, use
+/// [`KeybindingHint`](crate::atoms::KeybindingHint), which draws each of
+/// its keys with Kbd.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::atoms::*;
+///
+/// h_flex()
+///     .child("Press ")
+///     .child(Kbd::new("Esc").to_element(&theme))
+///     .child(" to close");
+/// ```
+pub struct Kbd {
+    props: KbdProps,
+}
+
+impl Kbd {
+    /// Create a key cap for `key`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let kbd = Kbd::new("Enter");
+    /// ```
+    pub fn new(key: impl Into<SharedString>) -> Self {
+        Self {
+            props: KbdProps { key: key.into() },
+        }
+    }
+
+    /// Convert to a GPUI element for embedding inside another component's render
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// row.child(Kbd::new("Tab").to_element(&theme));
+    /// ```
+    pub fn to_element(&self, theme: &Theme) -> Div {
+        div()
+            .px(theme.global.spacing_xs)
+            .min_w(px(20.0))
+            .flex()
+            .flex_none()
+            .justify_center()
+            .rounded(theme.global.radius_sm)
+            .border(px(1.0))
+            .border_b(px(2.0))
+            .border_color(theme.alias.color_border)
+            .bg(theme.alias.color_surface_elevated)
+            .text_size(theme.global.font_size_xs)
+            .text_color(theme.alias.color_text_muted)
+            .child(self.props.key.clone())
+    }
+}
+
+impl Render for Kbd {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        self.to_element(&theme)
+    }
+}
+
+// NOTE: Unit tests temporarily removed due to GPUI procedural macro incompatibility with #[test]
+// The macro causes infinite recursion during test compilation (SIGBUS error).
+// Tests can be re-added once GPUI's macro system is updated, or moved to integration tests.
+//
+// Test coverage validated manually:
+// - The key label renders in a bordered cap with a heavier bottom edge
+// - Single-character keys keep a minimum width so caps line up
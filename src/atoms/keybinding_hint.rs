@@ -0,0 +1,126 @@
+//! KeybindingHint component showing a keyboard shortcut as key caps.
+
+use gpui::*;
+use crate::{
+    atoms::Kbd,
+    theme::{use_theme, Theme},
+    utils::shortcuts::{keybinding_labels, KeyLabelStyle},
+};
+
+/// KeybindingHint configuration properties
+#[derive(Clone)]
+pub struct KeybindingHintProps {
+    /// Keystrokes in GPUI syntax, chords separated by spaces
+    pub keystrokes: SharedString,
+    /// How modifiers and special keys are labelled
+    pub style: KeyLabelStyle,
+}
+
+impl Default for KeybindingHintProps {
+    fn default() -> Self {
+        Self {
+            keystrokes: "".into(),
+            style: KeyLabelStyle::default(),
+        }
+    }
+}
+
+/// A keyboard shortcut shown as key caps.
+///
+/// KeybindingHint renders each key of a shortcut in its own small cap,
+/// with a gap between chords. Modifiers show as ⌘ ⌥ ⇧ ⌃ on macOS and as
+/// words elsewhere; `secondary` shows as whichever of Cmd and Ctrl the
+/// platform uses.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::atoms::*;
+///
+/// // ⌘K on macOS, Ctrl K elsewhere
+/// KeybindingHint::new("secondary-k");
+///
+/// // Two chords
+/// KeybindingHint::new("cmd-k cmd-s").style(KeyLabelStyle::Text);
+///
+/// // The current binding of a registered shortcut
+/// shortcut_keystrokes("file.save", cx).map(KeybindingHint::new);
+/// ```
+///
+/// ## Accessibility
+///
+/// - Hints are supplementary; the action they describe stays reachable
+///   through its menu item or button
+pub struct KeybindingHint {
+    props: KeybindingHintProps,
+}
+
+impl KeybindingHint {
+    /// Create a hint for `keystrokes`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let hint = KeybindingHint::new("cmd-shift-p");
+    /// ```
+    pub fn new(keystrokes: impl Into<SharedString>) -> Self {
+        Self {
+            props: KeybindingHintProps {
+                keystrokes: keystrokes.into(),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Set how modifiers and special keys are labelled
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// KeybindingHint::new("cmd-s").style(KeyLabelStyle::Symbols);
+    /// ```
+    pub fn style(mut self, style: KeyLabelStyle) -> Self {
+        self.props.style = style;
+        self
+    }
+
+    /// Convert to a GPUI element for embedding inside another component's render
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// row.child(KeybindingHint::new("cmd-o").to_element(&theme));
+    /// ```
+    pub fn to_element(&self, theme: &Theme) -> Div {
+        let chords = keybinding_labels(&self.props.keystrokes, self.props.style);
+        div()
+            .flex()
+            .flex_row()
+            .flex_none()
+            .items_center()
+            .gap(theme.global.spacing_sm)
+            .children(chords.into_iter().map(|chord| {
+                div()
+                    .flex()
+                    .flex_row()
+                    .gap(px(2.0))
+                    .children(chord.into_iter().map(|key| Kbd::new(key).to_element(theme)))
+            }))
+    }
+}
+
+impl Render for KeybindingHint {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        self.to_element(&theme)
+    }
+}
+
+// NOTE: Unit tests temporarily removed due to GPUI procedural macro incompatibility with #[test]
+// The macro causes infinite recursion during test compilation (SIGBUS error).
+// Tests can be re-added once GPUI's macro system is updated, or moved to integration tests.
+//
+// Test coverage validated manually:
+// - Each key of each chord renders in its own cap, chords separated by a gap
+// - Symbols style shows ⌘ ⌥ ⇧ ⌃; text style shows Cmd, Alt, Shift, Ctrl
+// - Key label parsing is covered by the utils::shortcuts tests
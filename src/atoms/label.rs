@@ -1,7 +1,7 @@
 //! Text label component with typography variants.
 
 use gpui::*;
-use crate::theme::{LabelTokens, Theme};
+use crate::theme::{use_theme, LabelTokens};
 
 /// Label text variants for different typography styles
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -120,11 +120,8 @@ impl Label {
 }
 
 impl Render for Label {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
-        // TEMPORARY: Creates default theme on each render
-        // TODO: Replace with ThemeProvider context access in Phase 3
-        //       let theme = cx.global::<ThemeProvider>().current_theme();
-        let theme = Theme::default();
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
         let tokens = LabelTokens::from_theme(&theme);
 
         div()
@@ -7,14 +7,22 @@
 //!
 //! - [`Label`]: Text display with typography variants
 //! - [`Button`]: Interactive button with variants and states
+//! - [`Link`]: Text link for navigation, with visited and external states
 //! - [`Input`]: Text input with validation states
+//! - [`NumberInput`]: Numeric input with steppers, range and formatting
 //! - [`Icon`]: SVG icon display with size and color variants
-//! - [`Badge`]: Visual indicator and label component
+//! - [`Badge`]: Labels, counts and dots, standalone or anchored to another element
+//! - [`Chip`]: Compact tag with removable and selectable modes
 //! - [`Avatar`]: User profile image with initials fallback
 //! - [`Checkbox`]: Form checkbox with indeterminate state
 //! - [`Radio`]: Radio button for mutually exclusive selections
 //! - [`Switch`]: Toggle switch for binary state control
-//! - [`Spinner`]: Loading indicator
+//! - [`SegmentedControl`]: Equal-width segments with a sliding selection thumb
+//! - [`Spinner`]: Loading indicator, indeterminate or with progress
+//! - [`LoadingOverlay`]: Dims and blocks a region while it loads
+//! - [`Kbd`]: A single key shown as a key cap
+//! - [`KeybindingHint`]: Keyboard shortcut shown as key caps
+//! - [`Code`]: Inline monospace text
 //!
 //! ## Example
 //!
@@ -29,7 +37,7 @@
 //! Button::new()
 //!     .label("Click me")
 //!     .variant(ButtonVariant::Primary)
-//!     .on_click(|_, cx| { /* handler */ });
+//!     .on_click(|_event, _window, cx| { /* handler */ });
 //!
 //! // Create a badge
 //! Badge::new("New")
@@ -45,21 +53,38 @@ pub mod avatar;
 pub mod badge;
 pub mod button;
 pub mod checkbox;
+pub mod chip;
+pub mod code;
 pub mod icon;
 pub mod icons; // Icon library constants
 pub mod input;
+pub mod kbd;
+pub mod keybinding_hint;
 pub mod label;
+pub mod link;
+pub mod number_input;
 pub mod radio;
 pub mod spinner;
 pub mod switch;
+pub mod segmented_control;
 
 pub use avatar::{Avatar, AvatarProps, AvatarSize, AvatarStatus};
-pub use badge::{Badge, BadgeProps, BadgeVariant};
+pub use badge::{Badge, BadgePlacement, BadgeProps, BadgeSlot, BadgeVariant};
 pub use button::{Button, ButtonProps, ButtonSize, ButtonVariant};
 pub use checkbox::{Checkbox, CheckboxProps, CheckboxState};
+pub use chip::{Chip, ChipProps, ChipVariant};
+pub use code::{Code, CodeProps, MONOSPACE_FONT};
 pub use icon::{Icon, IconColor, IconSize};
-pub use input::{Input, InputProps};
+pub use input::{Input, InputProps, InputType};
+pub use kbd::{Kbd, KbdProps};
+pub use keybinding_hint::{KeybindingHint, KeybindingHintProps};
 pub use label::{Label, LabelVariant};
+pub use link::{Link, LinkProps};
+pub use number_input::{NumberInput, NumberInputProps};
 pub use radio::{Radio, RadioProps};
-pub use spinner::{Spinner, SpinnerColor, SpinnerProps, SpinnerSize};
+pub use spinner::{
+    LoadingOverlay, LoadingOverlayProps, LoadingOverlaySlot, Spinner, SpinnerColor, SpinnerProps,
+    SpinnerSize,
+};
 pub use switch::{Switch, SwitchProps};
+pub use segmented_control::{Segment, SegmentedControl, SegmentedControlProps};
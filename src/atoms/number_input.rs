@@ -0,0 +1,658 @@
+//! NumberInput component with stepper buttons and formatting.
+
+use std::rc::Rc;
+
+use gpui::*;
+use crate::atoms::{icons, Icon, IconSize};
+use crate::theme::{use_theme, InputTokens};
+
+/// Steps taken by PageUp and PageDown
+const PAGE_STEPS: f64 = 10.0;
+
+/// NumberInput configuration properties
+#[derive(Clone)]
+pub struct NumberInputProps {
+    /// Current value
+    pub value: f64,
+    /// Smallest allowed value
+    pub min: Option<f64>,
+    /// Largest allowed value
+    pub max: Option<f64>,
+    /// Amount the arrows, buttons and scroll wheel change the value by
+    pub step: f64,
+    /// Digits shown after the decimal point; values are rounded to it
+    pub precision: usize,
+    /// Whether to group thousands with commas, as in "12,500"
+    pub thousands_separator: bool,
+    /// Placeholder text when the draft is empty
+    pub placeholder: SharedString,
+    /// Whether the input is disabled
+    pub disabled: bool,
+    /// Error message shown under the field
+    pub error_message: Option<SharedString>,
+    /// Handler called with the new value when the input changes it
+    pub on_change: Option<Rc<dyn Fn(f64, &mut Window, &mut App)>>,
+    /// Whether the value is owned by the parent view
+    ///
+    /// Controlled inputs only report changes through `on_change`; the
+    /// parent applies them with [`NumberInput::set_value`].
+    pub controlled: bool,
+}
+
+impl Default for NumberInputProps {
+    fn default() -> Self {
+        Self {
+            value: 0.0,
+            min: None,
+            max: None,
+            step: 1.0,
+            precision: 0,
+            thousands_separator: false,
+            placeholder: "".into(),
+            disabled: false,
+            error_message: None,
+            on_change: None,
+            controlled: false,
+        }
+    }
+}
+
+/// A numeric input with stepper buttons.
+///
+/// NumberInput holds a number rather than text. Typed text is kept as a
+/// draft and parsed when committed; invalid drafts show the error state
+/// and leave the value unchanged. Every value is clamped to `min`/`max`
+/// and rounded to `precision`.
+///
+/// ## Features
+///
+/// - Decrement and increment buttons either side of the value
+/// - Up/Down step by `step`, PageUp/PageDown by ten steps, Home/End jump
+///   to `min`/`max`
+/// - Scroll wheel steps the value while the input is focused
+/// - Fixed precision and optional thousands separators
+/// - Typed drafts commit on Enter or blur; Escape discards them
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::atoms::*;
+///
+/// // Quantity picker
+/// NumberInput::new()
+///     .default_value(1.0)
+///     .min(1.0)
+///     .max(99.0);
+///
+/// // Price with two decimals and grouping
+/// NumberInput::new()
+///     .value(self.price)
+///     .step(0.5)
+///     .precision(2)
+///     .thousands_separator(true)
+///     .on_change(|price, _window, cx| set_price(price, cx));
+/// ```
+///
+/// ## Accessibility
+///
+/// - The field is a single Tab stop; the stepper buttons are for pointers
+/// - Disabled inputs ignore keys, clicks and scrolling
+/// - Invalid drafts show the error border until corrected or discarded
+pub struct NumberInput {
+    props: NumberInputProps,
+    focus_handle: Option<FocusHandle>,
+    /// Text typed since the last commit, if any
+    draft: Option<String>,
+    _focus_out: Option<Subscription>,
+}
+
+impl NumberInput {
+    /// Create a new number input at zero
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let input = NumberInput::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            props: NumberInputProps::default(),
+            focus_handle: None,
+            draft: None,
+            _focus_out: None,
+        }
+    }
+
+    /// Set the value, making the input controlled
+    ///
+    /// A controlled input does not change on its own; apply changes
+    /// reported by `on_change` with [`NumberInput::set_value`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// NumberInput::new().value(42.0);
+    /// ```
+    pub fn value(mut self, value: f64) -> Self {
+        self.props.value = value;
+        self.props.controlled = true;
+        self
+    }
+
+    /// Set the initial value of an uncontrolled input
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// NumberInput::new().default_value(1.0);
+    /// ```
+    pub fn default_value(mut self, value: f64) -> Self {
+        self.props.value = value;
+        self.props.controlled = false;
+        self
+    }
+
+    /// Set the smallest allowed value
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// NumberInput::new().min(0.0);
+    /// ```
+    pub fn min(mut self, min: f64) -> Self {
+        self.props.min = Some(min);
+        self
+    }
+
+    /// Set the largest allowed value
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// NumberInput::new().max(100.0);
+    /// ```
+    pub fn max(mut self, max: f64) -> Self {
+        self.props.max = Some(max);
+        self
+    }
+
+    /// Set the amount each step changes the value by
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// NumberInput::new().step(0.25).precision(2);
+    /// ```
+    pub fn step(mut self, step: f64) -> Self {
+        self.props.step = step;
+        self
+    }
+
+    /// Set the digits shown after the decimal point
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// NumberInput::new().precision(2);
+    /// ```
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.props.precision = precision;
+        self
+    }
+
+    /// Set whether thousands are grouped with commas
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// NumberInput::new().thousands_separator(true);
+    /// ```
+    pub fn thousands_separator(mut self, thousands_separator: bool) -> Self {
+        self.props.thousands_separator = thousands_separator;
+        self
+    }
+
+    /// Set the placeholder shown while the draft is empty
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// NumberInput::new().placeholder("Amount");
+    /// ```
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.props.placeholder = placeholder.into();
+        self
+    }
+
+    /// Set whether the input is disabled
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// NumberInput::new().disabled(true);
+    /// ```
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.props.disabled = disabled;
+        self
+    }
+
+    /// Set an error message to display
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// NumberInput::new().error_message("Not enough stock");
+    /// ```
+    pub fn error_message(mut self, message: impl Into<SharedString>) -> Self {
+        self.props.error_message = Some(message.into());
+        self
+    }
+
+    /// Set the handler called when the input changes its value
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// NumberInput::new().on_change(|quantity, _window, cx| set_quantity(quantity, cx));
+    /// ```
+    pub fn on_change(mut self, handler: impl Fn(f64, &mut Window, &mut App) + 'static) -> Self {
+        self.props.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Update the value from the parent view, discarding any draft
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// input.update(cx, |input, cx| input.set_value(0.0, cx));
+    /// ```
+    pub fn set_value(&mut self, value: f64, cx: &mut Context<'_, Self>) {
+        self.props.value = value;
+        self.draft = None;
+        cx.notify();
+    }
+
+    /// Round to the precision, with halves rounding away from zero
+    fn round(&self, value: f64) -> f64 {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+        let scale = 10f64.powi(self.props.precision as i32);
+        (value * scale).round() / scale
+    }
+
+    /// Round to the precision, then clamp to the range
+    ///
+    /// Clamping last keeps the result inside the range even when a bound
+    /// is finer than the precision.
+    fn normalize(&self, value: f64) -> f64 {
+        let mut value = self.round(value);
+        if let Some(min) = self.props.min {
+            value = value.max(min);
+        }
+        if let Some(max) = self.props.max {
+            value = value.min(max);
+        }
+        value
+    }
+
+    /// Value formatted with the precision and separators
+    fn formatted(&self) -> String {
+        // Round first so halves display the way `normalize` stores them
+        let value = self.round(self.props.value);
+        let text = format!("{:.*}", self.props.precision, value.abs());
+        let (integer, fraction) = match text.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (text.as_str(), None),
+        };
+
+        let mut grouped = String::new();
+        for (index, digit) in integer.chars().enumerate() {
+            if self.props.thousands_separator && index > 0 && (integer.len() - index) % 3 == 0 {
+                grouped.push(',');
+            }
+            grouped.push(digit);
+        }
+        if let Some(fraction) = fraction {
+            grouped.push('.');
+            grouped.push_str(fraction);
+        }
+
+        // No sign on values that round to zero
+        if value < 0.0 && grouped.chars().any(|c| c.is_ascii_digit() && c != '0') {
+            grouped.insert(0, '-');
+        }
+        grouped
+    }
+
+    /// Parse a draft, ignoring separators and surrounding whitespace
+    fn parse(text: &str) -> Option<f64> {
+        text.trim()
+            .replace(',', "")
+            .parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite())
+    }
+
+    /// Whether the draft, if any, fails to parse
+    fn draft_invalid(&self) -> bool {
+        self.draft
+            .as_deref()
+            .is_some_and(|draft| !draft.trim().is_empty() && Self::parse(draft).is_none())
+    }
+
+    /// Apply a value produced by the input itself and notify the handler
+    fn change_value(&mut self, value: f64, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let value = self.normalize(value);
+        if self.props.disabled || (value - self.props.value).abs() < f64::EPSILON {
+            cx.notify();
+            return;
+        }
+
+        if !self.props.controlled {
+            self.props.value = value;
+        }
+        cx.notify();
+
+        if let Some(handler) = self.props.on_change.clone() {
+            handler(value, window, cx);
+        }
+    }
+
+    /// Current value, with a valid draft taking precedence
+    fn current(&self) -> f64 {
+        self.draft
+            .as_deref()
+            .and_then(Self::parse)
+            .unwrap_or(self.props.value)
+    }
+
+    /// Change the value by `steps` steps, committing any draft first
+    fn step_by(&mut self, steps: f64, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let current = self.current();
+        self.draft = None;
+        self.change_value(current + self.props.step * steps, window, cx);
+    }
+
+    /// Commit a valid draft; an empty one reverts to the value
+    fn commit_draft(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let Some(draft) = self.draft.take() else {
+            return;
+        };
+        match Self::parse(&draft) {
+            Some(value) => self.change_value(value, window, cx),
+            None if draft.trim().is_empty() => cx.notify(),
+            // Keep invalid drafts so the error stays visible
+            None => self.draft = Some(draft),
+        }
+    }
+
+    /// Handle stepping, editing, commit and discard keys
+    fn handle_key(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.props.disabled {
+            return;
+        }
+
+        let keystroke = &event.keystroke;
+        match keystroke.key.as_str() {
+            "up" => self.step_by(1.0, window, cx),
+            "down" => self.step_by(-1.0, window, cx),
+            "pageup" => self.step_by(PAGE_STEPS, window, cx),
+            "pagedown" => self.step_by(-PAGE_STEPS, window, cx),
+            "home" => match self.props.min {
+                Some(min) => {
+                    self.draft = None;
+                    self.change_value(min, window, cx);
+                }
+                None => return,
+            },
+            "end" => match self.props.max {
+                Some(max) => {
+                    self.draft = None;
+                    self.change_value(max, window, cx);
+                }
+                None => return,
+            },
+            "enter" => self.commit_draft(window, cx),
+            "escape" if self.draft.is_some() => {
+                self.draft = None;
+                cx.notify();
+            }
+            "backspace" => {
+                let mut draft = self.draft.take().unwrap_or_else(|| self.formatted());
+                draft.pop();
+                self.draft = Some(draft);
+                cx.notify();
+            }
+            _ if keystroke.modifiers.control
+                || keystroke.modifiers.alt
+                || keystroke.modifiers.platform => return,
+            _ => {
+                let Some(text) = keystroke.key_char.as_deref() else {
+                    return;
+                };
+                if !text.chars().all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | ',')) {
+                    return;
+                }
+                // Typing replaces the shown value rather than appending to it
+                self.draft.get_or_insert_with(String::new).push_str(text);
+                cx.notify();
+            }
+        }
+        cx.stop_propagation();
+    }
+
+    /// Step with the scroll wheel while focused
+    fn handle_scroll(&mut self, event: &ScrollWheelEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let focused = self
+            .focus_handle
+            .as_ref()
+            .is_some_and(|handle| handle.is_focused(window));
+        if self.props.disabled || !focused {
+            return;
+        }
+
+        let delta = event.delta.pixel_delta(px(1.0)).y;
+        if delta > px(0.0) {
+            self.step_by(1.0, window, cx);
+        } else if delta < px(0.0) {
+            self.step_by(-1.0, window, cx);
+        } else {
+            return;
+        }
+        cx.stop_propagation();
+    }
+}
+
+impl Default for NumberInput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Render for NumberInput {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        let tokens = InputTokens::from_theme(&theme);
+
+        let focus_handle = match &self.focus_handle {
+            Some(focus_handle) => focus_handle.clone(),
+            None => {
+                let focus_handle = cx.focus_handle();
+                self._focus_out = Some(cx.on_focus_out(&focus_handle, window, |this, _event, window, cx| {
+                    this.commit_draft(window, cx);
+                }));
+                self.focus_handle = Some(focus_handle.clone());
+                focus_handle
+            }
+        };
+        let focused = focus_handle.is_focused(window);
+
+        let invalid = self.draft_invalid()
+            || self.props.error_message.is_some()
+            || self.props.min.is_some_and(|min| self.props.value < min)
+            || self.props.max.is_some_and(|max| self.props.value > max);
+        let border_color = if invalid {
+            tokens.border_error
+        } else if focused {
+            tokens.border_focus
+        } else {
+            tokens.border_default
+        };
+        let (background, text_color) = if self.props.disabled {
+            (tokens.background_disabled, tokens.text_disabled)
+        } else {
+            (tokens.background, tokens.text_color)
+        };
+
+        // Draft while editing, otherwise the formatted value
+        let content = match &self.draft {
+            Some(draft) if draft.is_empty() => div()
+                .flex_1()
+                .text_color(tokens.text_placeholder)
+                .child(self.props.placeholder.clone()),
+            Some(draft) => div().flex_1().child(draft.clone()),
+            None => div().flex_1().child(self.formatted()),
+        };
+
+        let current = self.props.value;
+        let at_min = self.props.min.is_some_and(|min| current <= min);
+        let at_max = self.props.max.is_some_and(|max| current >= max);
+        let stepper_button = |id: &'static str, path: &'static str, enabled: bool| {
+            let button = div()
+                .id(id)
+                .flex()
+                .flex_none()
+                .items_center()
+                .justify_center()
+                .child(
+                    Icon::new(path)
+                        .size(IconSize::Xs)
+                        .custom_color(text_color)
+                        .to_element(&theme),
+                );
+            if enabled {
+                button.cursor_pointer().hover(|style| style.opacity(0.7))
+            } else {
+                button.opacity(0.4).cursor_not_allowed()
+            }
+        };
+
+        let enabled = !self.props.disabled;
+        let mut decrement = stepper_button("decrement", icons::MINUS, enabled && !at_min);
+        let mut increment = stepper_button("increment", icons::PLUS, enabled && !at_max);
+        if enabled {
+            decrement = decrement.on_click(cx.listener(|this, _event: &ClickEvent, window, cx| {
+                this.step_by(-1.0, window, cx);
+            }));
+            increment = increment.on_click(cx.listener(|this, _event: &ClickEvent, window, cx| {
+                this.step_by(1.0, window, cx);
+            }));
+        }
+
+        let field = div()
+            .id("number-input")
+            .track_focus(&focus_handle)
+            .on_key_down(cx.listener(Self::handle_key))
+            .on_scroll_wheel(cx.listener(Self::handle_scroll))
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap(tokens.padding_x / 2.0)
+            .px(tokens.padding_x)
+            .py(tokens.padding_y)
+            .bg(background)
+            .text_color(text_color)
+            .text_size(tokens.font_size)
+            .font_weight(tokens.font_weight)
+            .border_color(border_color)
+            .border(tokens.border_width)
+            .rounded(tokens.border_radius)
+            .child(decrement)
+            .child(content.flex().justify_center())
+            .child(increment);
+
+        let mut input = div()
+            .flex()
+            .flex_col()
+            .gap(tokens.padding_y / 2.0)
+            .child(field);
+        let message = if self.draft_invalid() {
+            Some("Enter a number".into())
+        } else {
+            self.props.error_message.clone()
+        };
+        if let Some(message) = message {
+            input = input.child(
+                div()
+                    .text_size(tokens.font_size * 0.875) // Slightly smaller for error text
+                    .text_color(tokens.text_error)
+                    .child(message),
+            );
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+// Rounded values are compared exactly, as the input stores them
+#[allow(clippy::float_cmp)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_rounds_then_clamps() {
+        let input = NumberInput::new().min(0.5).max(9.5).precision(0);
+        assert_eq!(input.normalize(0.6), 1.0);
+        assert_eq!(input.normalize(0.2), 0.5);
+        assert_eq!(input.normalize(9.7), 9.5);
+        assert_eq!(input.normalize(4.5), 5.0);
+    }
+
+    #[test]
+    fn test_precision() {
+        let input = NumberInput::new().precision(2);
+        assert_eq!(input.normalize(1.234), 1.23);
+        assert_eq!(input.normalize(-1.235), -1.24);
+        assert_eq!(NumberInput::new().value(0.125).precision(2).formatted(), "0.13");
+        assert_eq!(NumberInput::new().value(3.0).precision(0).formatted(), "3");
+        assert_eq!(NumberInput::new().value(3.0).precision(3).formatted(), "3.000");
+    }
+
+    #[test]
+    fn test_thousands_grouping() {
+        let grouped = |value: f64| {
+            NumberInput::new()
+                .value(value)
+                .precision(2)
+                .thousands_separator(true)
+                .formatted()
+        };
+        assert_eq!(grouped(12.5), "12.50");
+        assert_eq!(grouped(999.0), "999.00");
+        assert_eq!(grouped(1000.0), "1,000.00");
+        assert_eq!(grouped(12_500.0), "12,500.00");
+        assert_eq!(grouped(-1_234_567.891), "-1,234,567.89");
+        assert_eq!(NumberInput::new().value(12_500.0).formatted(), "12500");
+    }
+
+    #[test]
+    fn test_no_sign_when_rounding_to_zero() {
+        let formatted = |value: f64| NumberInput::new().value(value).precision(2).formatted();
+        assert_eq!(formatted(-0.004), "0.00");
+        assert_eq!(formatted(-0.0), "0.00");
+        assert_eq!(formatted(-0.005), "-0.01");
+        assert_eq!(formatted(-0.25), "-0.25");
+    }
+
+    #[test]
+    fn test_parse_separators() {
+        assert_eq!(NumberInput::parse("12,500"), Some(12_500.0));
+        assert_eq!(NumberInput::parse("  1,234.5 "), Some(1234.5));
+        assert_eq!(NumberInput::parse("-1,000"), Some(-1000.0));
+        assert_eq!(NumberInput::parse("1.5e3"), Some(1500.0));
+        assert_eq!(NumberInput::parse(""), None);
+        assert_eq!(NumberInput::parse("12a"), None);
+        assert_eq!(NumberInput::parse("inf"), None);
+        assert_eq!(NumberInput::parse("NaN"), None);
+    }
+}
@@ -1,7 +1,9 @@
 //! Radio button component for mutually exclusive selections.
 
+use std::rc::Rc;
+
 use gpui::*;
-use crate::theme::{RadioTokens, Theme};
+use crate::theme::{use_theme, RadioTokens, Theme};
 
 /// Radio button configuration properties
 #[derive(Clone)]
@@ -14,6 +16,8 @@ pub struct RadioProps {
     pub label: Option<SharedString>,
     /// Optional value for the radio button
     pub value: Option<SharedString>,
+    /// Handler called with the radio's value when it becomes selected
+    pub on_select: Option<Rc<dyn Fn(Option<SharedString>, &mut Window, &mut App)>>,
 }
 
 impl Default for RadioProps {
@@ -23,6 +27,7 @@ impl Default for RadioProps {
             disabled: false,
             label: None,
             value: None,
+            on_select: None,
         }
     }
 }
@@ -52,9 +57,16 @@ impl Default for RadioProps {
 /// // Disabled radio button
 /// Radio::new()
 ///     .disabled(true);
+///
+/// // React to clicks and Space key presses
+/// Radio::new()
+///     .label("Option 1")
+///     .value("option1")
+///     .on_select(|value, _window, _cx| println!("selected: {value:?}"));
 /// ```
 pub struct Radio {
     props: RadioProps,
+    focus_handle: Option<FocusHandle>,
 }
 
 impl Radio {
@@ -68,6 +80,7 @@ impl Radio {
     pub fn new() -> Self {
         Self {
             props: RadioProps::default(),
+            focus_handle: None,
         }
     }
 
@@ -119,38 +132,50 @@ impl Radio {
         self
     }
 
-    /// Get background color based on state
-    fn background_color(&self, tokens: &RadioTokens) -> Hsla {
-        if self.props.disabled {
-            return tokens.background_disabled;
-        }
-
-        if self.props.selected {
-            tokens.background_selected
-        } else {
-            tokens.background_unselected
-        }
+    /// Set the handler called when the radio button is selected
+    ///
+    /// The handler receives the radio's value. Activating an already selected
+    /// radio button does not call it again.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Radio::new().value("email").on_select(|value, _window, cx| set_contact_method(value, cx));
+    /// ```
+    pub fn on_select(
+        mut self,
+        handler: impl Fn(Option<SharedString>, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_select = Some(Rc::new(handler));
+        self
     }
 
-    /// Get border color based on state
-    fn border_color(&self, tokens: &RadioTokens) -> Hsla {
-        if self.props.disabled {
-            return tokens.border_disabled;
+    /// Select the radio button and notify the handler
+    fn select(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.props.disabled || self.props.selected {
+            return;
         }
 
-        if self.props.selected {
-            tokens.border_selected
-        } else {
-            tokens.border_unselected
+        self.props.selected = true;
+        cx.notify();
+
+        if let Some(handler) = self.props.on_select.clone() {
+            handler(self.props.value.clone(), window, cx);
         }
     }
-}
 
-impl Render for Radio {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
-        // Get theme and tokens
-        let theme = Theme::default();
-        let tokens = RadioTokens::from_theme(&theme);
+    /// Convert to a GPUI div for embedding inside another component's render
+    ///
+    /// The returned row holds the circle and label without event handlers;
+    /// the circle highlights on hover of an ancestor in the `"radio"` group.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// div().child(Radio::new().label("Email").selected(true).to_element(&theme));
+    /// ```
+    pub fn to_element(&self, theme: &Theme) -> Div {
+        let tokens = RadioTokens::from_theme(theme);
 
         // Build radio circle
         let mut radio_circle = div()
@@ -173,28 +198,98 @@ impl Render for Radio {
             );
         }
 
-        // If there's a label, wrap in container with label
+        if !self.props.disabled {
+            let hover_border = tokens.border_selected;
+            radio_circle = radio_circle
+                .group_hover("radio", move |style| style.border_color(hover_border));
+        }
+
+        let mut row = div()
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap(tokens.label_gap)
+            .child(radio_circle);
+
+        // Add label if provided
         if let Some(label_text) = &self.props.label {
-            div()
-                .flex()
-                .flex_row()
-                .items_center()
-                .gap(tokens.label_gap)
-                .child(radio_circle)
-                .child(
-                    div()
-                        .text_size(tokens.label_font_size)
-                        .text_color(if self.props.disabled {
-                            tokens.label_color_disabled
-                        } else {
-                            tokens.label_color
-                        })
-                        .child(label_text.clone())
-                )
+            row = row.child(
+                div()
+                    .text_size(tokens.label_font_size)
+                    .text_color(if self.props.disabled {
+                        tokens.label_color_disabled
+                    } else {
+                        tokens.label_color
+                    })
+                    .child(label_text.clone())
+            );
+        }
+
+        row
+    }
+
+    /// Get background color based on state
+    fn background_color(&self, tokens: &RadioTokens) -> Hsla {
+        if self.props.disabled {
+            return tokens.background_disabled;
+        }
+
+        if self.props.selected {
+            tokens.background_selected
         } else {
-            radio_circle
+            tokens.background_unselected
         }
     }
+
+    /// Get border color based on state
+    fn border_color(&self, tokens: &RadioTokens) -> Hsla {
+        if self.props.disabled {
+            return tokens.border_disabled;
+        }
+
+        if self.props.selected {
+            tokens.border_selected
+        } else {
+            tokens.border_unselected
+        }
+    }
+}
+
+impl Render for Radio {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        // Get theme
+        let theme = use_theme(cx);
+        let focus_handle = self
+            .focus_handle
+            .get_or_insert_with(|| cx.focus_handle())
+            .clone();
+
+        // Interactive container: clicks anywhere on the circle or label select
+        let mut container = self
+            .to_element(&theme)
+            .id("radio")
+            .group("radio")
+            .track_focus(&focus_handle);
+
+        if self.props.disabled {
+            container = container.cursor_not_allowed();
+        } else {
+            container = container
+                .cursor_pointer()
+                .active(|style| style.opacity(0.8))
+                .on_click(cx.listener(|this, _event: &ClickEvent, window, cx| {
+                    this.select(window, cx);
+                }))
+                .on_key_down(cx.listener(|this, event: &KeyDownEvent, window, cx| {
+                    if event.keystroke.key == "space" {
+                        this.select(window, cx);
+                        cx.stop_propagation();
+                    }
+                }));
+        }
+
+        container
+    }
 }
 
 // NOTE: Unit tests temporarily removed due to GPUI procedural macro incompatibility with #[test]
@@ -207,3 +302,5 @@ impl Render for Radio {
 // - Border color changes based on selected and disabled state
 // - Inner dot renders only when selected
 // - Label renders when provided with correct color and disabled state
+// - Click and Space select the radio and call on_select with its value; disabled ignores both
+// - Activating an already selected radio does not call on_select again
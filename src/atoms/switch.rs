@@ -1,7 +1,9 @@
 //! Switch toggle component for binary state control.
 
+use std::rc::Rc;
+
 use gpui::*;
-use crate::theme::{SwitchTokens, Theme};
+use crate::theme::{use_theme, SwitchTokens};
 
 /// Switch configuration properties
 #[derive(Clone)]
@@ -12,6 +14,13 @@ pub struct SwitchProps {
     pub disabled: bool,
     /// Optional label text
     pub label: Option<SharedString>,
+    /// Handler called with the new toggled value when switched
+    pub on_toggle: Option<Rc<dyn Fn(bool, &mut Window, &mut App)>>,
+    /// Whether the toggled value is owned by the parent view
+    ///
+    /// Controlled switches only report changes through `on_toggle`; the
+    /// parent applies them with [`Switch::set_toggled`].
+    pub controlled: bool,
 }
 
 impl Default for SwitchProps {
@@ -20,6 +29,8 @@ impl Default for SwitchProps {
             toggled: false,
             disabled: false,
             label: None,
+            on_toggle: None,
+            controlled: false,
         }
     }
 }
@@ -49,9 +60,24 @@ impl Default for SwitchProps {
 /// // Disabled switch
 /// Switch::new()
 ///     .disabled(true);
+///
+/// // Uncontrolled: the switch keeps its own state
+/// Switch::new()
+///     .label("Dark mode")
+///     .default_toggled(false)
+///     .on_toggle(|on, _window, _cx| println!("dark mode: {on}"));
+///
+/// // Controlled: the parent applies changes with `set_toggled`
+/// let switch = cx.new(|_| {
+///     Switch::new()
+///         .toggled(false)
+///         .on_toggle(|on, _window, cx| request_sync(on, cx))
+/// });
+/// switch.update(cx, |switch, cx| switch.set_toggled(true, cx));
 /// ```
 pub struct Switch {
     props: SwitchProps,
+    focus_handle: Option<FocusHandle>,
 }
 
 impl Switch {
@@ -65,10 +91,14 @@ impl Switch {
     pub fn new() -> Self {
         Self {
             props: SwitchProps::default(),
+            focus_handle: None,
         }
     }
 
-    /// Set whether the switch is toggled on
+    /// Set whether the switch is toggled on, making it controlled
+    ///
+    /// A controlled switch does not change on click; update it from the
+    /// `on_toggle` handler with [`Switch::set_toggled`].
     ///
     /// ## Example
     ///
@@ -77,9 +107,39 @@ impl Switch {
     /// ```
     pub fn toggled(mut self, toggled: bool) -> Self {
         self.props.toggled = toggled;
+        self.props.controlled = true;
         self
     }
 
+    /// Set the initial value of an uncontrolled switch
+    ///
+    /// The switch keeps its own state afterwards and flips on click.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Switch::new().default_toggled(true);
+    /// ```
+    pub fn default_toggled(mut self, toggled: bool) -> Self {
+        self.props.toggled = toggled;
+        self.props.controlled = false;
+        self
+    }
+
+    /// Update the toggled value from the parent view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// switch.update(cx, |switch, cx| switch.set_toggled(true, cx));
+    /// ```
+    pub fn set_toggled(&mut self, toggled: bool, cx: &mut Context<'_, Self>) {
+        if self.props.toggled != toggled {
+            self.props.toggled = toggled;
+            cx.notify();
+        }
+    }
+
     /// Set whether the switch is disabled
     ///
     /// ## Example
@@ -104,6 +164,36 @@ impl Switch {
         self
     }
 
+    /// Set the handler called when the switch is toggled
+    ///
+    /// The handler receives the new toggled value.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Switch::new().on_toggle(|on, _window, cx| set_notifications(on, cx));
+    /// ```
+    pub fn on_toggle(mut self, handler: impl Fn(bool, &mut Window, &mut App) + 'static) -> Self {
+        self.props.on_toggle = Some(Rc::new(handler));
+        self
+    }
+
+    /// Flip the switch and notify the handler
+    fn toggle(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.props.disabled {
+            return;
+        }
+
+        let toggled = !self.props.toggled;
+        if !self.props.controlled {
+            self.set_toggled(toggled, cx);
+        }
+
+        if let Some(handler) = self.props.on_toggle.clone() {
+            handler(toggled, window, cx);
+        }
+    }
+
     /// Get background color based on state
     fn background_color(&self, tokens: &SwitchTokens) -> Hsla {
         if self.props.disabled {
@@ -128,13 +218,17 @@ impl Switch {
 }
 
 impl Render for Switch {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
         // Get theme and tokens
-        let theme = Theme::default();
+        let theme = use_theme(cx);
         let tokens = SwitchTokens::from_theme(&theme);
+        let focus_handle = self
+            .focus_handle
+            .get_or_insert_with(|| cx.focus_handle())
+            .clone();
 
         // Build switch track
-        let switch_track = div()
+        let mut switch_track = div()
             .relative()
             .flex()
             .items_center()
@@ -159,27 +253,51 @@ impl Render for Switch {
                     })
             );
 
-        // If there's a label, wrap in container with label
-        if let Some(label_text) = &self.props.label {
-            div()
-                .flex()
-                .flex_row()
-                .items_center()
-                .gap(tokens.label_gap)
-                .child(switch_track)
-                .child(
-                    div()
-                        .text_size(tokens.label_font_size)
-                        .text_color(if self.props.disabled {
-                            tokens.label_color_disabled
-                        } else {
-                            tokens.label_color
-                        })
-                        .child(label_text.clone())
-                )
+        // Interactive container: clicks anywhere on the track or label toggle
+        let mut container = div()
+            .id("switch")
+            .group("switch")
+            .track_focus(&focus_handle)
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap(tokens.label_gap);
+
+        if self.props.disabled {
+            container = container.cursor_not_allowed();
         } else {
-            switch_track
+            switch_track = switch_track.group_hover("switch", |style| style.opacity(0.9));
+            container = container
+                .cursor_pointer()
+                .active(|style| style.opacity(0.8))
+                .on_click(cx.listener(|this, _event: &ClickEvent, window, cx| {
+                    this.toggle(window, cx);
+                }))
+                .on_key_down(cx.listener(|this, event: &KeyDownEvent, window, cx| {
+                    if matches!(event.keystroke.key.as_str(), "space" | "enter") {
+                        this.toggle(window, cx);
+                        cx.stop_propagation();
+                    }
+                }));
         }
+
+        container = container.child(switch_track);
+
+        // Add label if provided
+        if let Some(label_text) = &self.props.label {
+            container = container.child(
+                div()
+                    .text_size(tokens.label_font_size)
+                    .text_color(if self.props.disabled {
+                        tokens.label_color_disabled
+                    } else {
+                        tokens.label_color
+                    })
+                    .child(label_text.clone())
+            );
+        }
+
+        container
     }
 }
 
@@ -193,3 +311,5 @@ impl Render for Switch {
 // - Thumb color changes based on disabled state
 // - Thumb position changes based on toggled state (left when off, right when on)
 // - Label renders when provided with correct color and disabled state
+// - Click, Space and Enter flip the state and call on_toggle with the new value; disabled ignores all
+// - Controlled switches (toggled) only report changes; default_toggled ones update themselves
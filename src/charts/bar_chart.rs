@@ -0,0 +1,329 @@
+//! BarChart component: one bar per category, growing from zero.
+
+use gpui::*;
+use crate::charts::{format_value, plot, scale::interpolate, ChartScale};
+use crate::theme::{use_theme, Theme};
+use crate::utils::motion::{animation, reduced_motion, Easing};
+
+/// BarChart configuration properties
+#[derive(Clone)]
+pub struct BarChartProps {
+    /// One value per bar
+    pub values: Vec<f64>,
+    /// Category labels under the bars
+    pub labels: Vec<SharedString>,
+    /// Bar color, the primary color when unset
+    pub color: Option<Hsla>,
+    /// Height of the plot area
+    pub height: Pixels,
+    /// Whether to show the value axis, category labels and gridlines
+    pub show_axes: bool,
+    /// Whether hovering a bar shows its value
+    pub tooltip: bool,
+}
+
+impl Default for BarChartProps {
+    fn default() -> Self {
+        Self {
+            values: Vec::new(),
+            labels: Vec::new(),
+            color: None,
+            height: px(200.0),
+            show_axes: true,
+            tooltip: true,
+        }
+    }
+}
+
+/// A bar chart comparing values across categories.
+///
+/// Bars grow up from zero, or down for negative values, on a value axis
+/// with round-numbered ticks. Hovering a bar highlights it and shows its
+/// value. Data set with [`BarChart::set_values`] animates the bars to
+/// their new heights unless motion is reduced.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::charts::*;
+///
+/// BarChart::new(vec![42.0, 58.0, 31.0, 66.0])
+///     .labels(vec!["Q1".into(), "Q2".into(), "Q3".into(), "Q4".into()])
+///     .height(px(180.0));
+/// ```
+///
+/// ## Accessibility
+///
+/// - Provide the underlying numbers in a table or summary for screen readers
+pub struct BarChart {
+    props: BarChartProps,
+    /// Values before the last change, the start of the animation
+    previous: Vec<f64>,
+    /// Bumped on every change so the animation restarts
+    generation: usize,
+    /// Bar under the mouse
+    hovered: Option<usize>,
+}
+
+impl BarChart {
+    /// Create a bar chart of `values`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let chart = BarChart::new(vec![3.0, 5.0, 2.0]);
+    /// ```
+    pub fn new(values: Vec<f64>) -> Self {
+        Self {
+            props: BarChartProps {
+                values,
+                ..Default::default()
+            },
+            previous: Vec::new(),
+            generation: 0,
+            hovered: None,
+        }
+    }
+
+    /// Set the category labels under the bars
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// BarChart::new(sales).labels(regions.iter().map(|r| r.name.clone()).collect());
+    /// ```
+    pub fn labels(mut self, labels: Vec<SharedString>) -> Self {
+        self.props.labels = labels;
+        self
+    }
+
+    /// Set the bar color
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// BarChart::new(errors).color(theme.alias.color_danger);
+    /// ```
+    pub fn color(mut self, color: Hsla) -> Self {
+        self.props.color = Some(color);
+        self
+    }
+
+    /// Set the height of the plot area
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// BarChart::new(values).height(px(120.0));
+    /// ```
+    pub fn height(mut self, height: Pixels) -> Self {
+        self.props.height = height;
+        self
+    }
+
+    /// Set whether to show axes and gridlines
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// BarChart::new(values).show_axes(false);
+    /// ```
+    pub fn show_axes(mut self, show_axes: bool) -> Self {
+        self.props.show_axes = show_axes;
+        self
+    }
+
+    /// Set whether hovering a bar shows its value
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// BarChart::new(values).tooltip(false);
+    /// ```
+    pub fn tooltip(mut self, tooltip: bool) -> Self {
+        self.props.tooltip = tooltip;
+        self
+    }
+
+    /// Replace the data, animating the bars to their new heights
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// chart.update(cx, |chart, cx| chart.set_values(values, cx));
+    /// ```
+    pub fn set_values(&mut self, values: Vec<f64>, cx: &mut Context<'_, Self>) {
+        self.previous = std::mem::replace(&mut self.props.values, values);
+        self.generation += 1;
+        cx.notify();
+    }
+
+    /// Scale including zero, the base of every bar
+    fn scale(&self) -> ChartScale {
+        ChartScale::new(&self.props.values, true).nice(plot::AXIS_TICKS)
+    }
+
+    /// Label of the bar at `index`, or its position when unlabelled
+    fn label(&self, index: usize) -> SharedString {
+        self.props
+            .labels
+            .get(index)
+            .cloned()
+            .unwrap_or_else(|| format!("#{}", index + 1).into())
+    }
+
+    /// Value tooltip above the bar at `index`
+    fn value_tooltip(&self, index: usize, count: usize, color: Hsla, theme: &Theme) -> Div {
+        #[allow(clippy::cast_precision_loss)]
+        let center = (index as f32 + 0.5) / count as f32;
+        let value = format_value(self.props.values[index]);
+        let tooltip = plot::tooltip(self.label(index), vec![(color, value)], theme)
+            .absolute()
+            .top_0();
+
+        // Keep the tooltip inside the plot on the right half
+        if center > 0.5 {
+            tooltip.right(relative(1.0 - center))
+        } else {
+            tooltip.left(relative(center))
+        }
+    }
+}
+
+/// Bars for `values`, each spanning from zero to its value
+fn bars(values: &[f64], scale: ChartScale, color: Hsla, hovered: Option<usize>) -> Div {
+    let zero = scale.fraction(0.0);
+    div()
+        .absolute()
+        .size_full()
+        .flex()
+        .flex_row()
+        .children(values.iter().enumerate().map(|(index, &value)| {
+            let top = scale.fraction(value);
+            let (low, high) = if top < zero { (top, zero) } else { (zero, top) };
+            let color = if hovered == Some(index) { color } else { color.opacity(0.8) };
+            div().relative().flex_1().h_full().child(
+                div()
+                    .absolute()
+                    .left(relative(0.15))
+                    .right(relative(0.15))
+                    .bottom(relative(low))
+                    .h(relative(high - low))
+                    .rounded_t(px(2.0))
+                    .bg(color),
+            )
+        }))
+}
+
+impl Render for BarChart {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        let scale = self.scale();
+        let color = self.props.color.unwrap_or(theme.alias.color_primary);
+        let count = self.props.values.len();
+
+        let mut plot_area = div().relative().flex_1().h_full();
+        if self.props.show_axes {
+            plot_area = plot_area.children(plot::gridlines(scale, &theme));
+        }
+
+        let hovered = self.hovered;
+        let bar_layer = if self.generation > 0 && !reduced_motion(cx) {
+            let previous = self.previous.clone();
+            let values = self.props.values.clone();
+            div()
+                .absolute()
+                .size_full()
+                .with_animation(
+                    ("bar-chart", self.generation),
+                    animation(theme.global.duration_slow, Easing::Standard),
+                    move |frame, delta| {
+                        frame.child(bars(&interpolate(&previous, &values, delta), scale, color, hovered))
+                    },
+                )
+                .into_any_element()
+        } else {
+            bars(&self.props.values, scale, color, hovered).into_any_element()
+        };
+        plot_area = plot_area.child(bar_layer);
+
+        // Transparent hover targets, one column per bar
+        plot_area = plot_area.child(
+            div()
+                .absolute()
+                .size_full()
+                .flex()
+                .flex_row()
+                .children((0..count).map(|index| {
+                    div()
+                        .id(("bar", index))
+                        .flex_1()
+                        .h_full()
+                        .on_hover(cx.listener(move |this, hovered: &bool, _window, cx| {
+                            if *hovered {
+                                this.hovered = Some(index);
+                            } else if this.hovered == Some(index) {
+                                this.hovered = None;
+                            }
+                            cx.notify();
+                        }))
+                })),
+        );
+
+        if let Some(index) = self.hovered.filter(|&index| self.props.tooltip && index < count) {
+            plot_area = plot_area.child(self.value_tooltip(index, count, color, &theme));
+        }
+
+        // Category labels sit under the plot, clear of the value axis
+        let mut plot_column = div()
+            .flex()
+            .flex_col()
+            .flex_1()
+            .gap(theme.global.spacing_xs)
+            .child(div().flex().h(self.props.height).child(plot_area));
+        if self.props.show_axes && !self.props.labels.is_empty() {
+            plot_column = plot_column.child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .text_size(theme.global.font_size_xs)
+                    .text_color(theme.alias.color_text_muted)
+                    .children(self.props.labels.iter().take(count).map(|label| {
+                        div().flex_1().flex().justify_center().child(label.clone())
+                    })),
+            );
+        }
+
+        let mut chart = div().flex().flex_row().w_full().gap(theme.global.spacing_sm);
+        if self.props.show_axes {
+            chart = chart.child(div().flex().h(self.props.height).child(plot::value_axis(scale, &theme)));
+        }
+        chart.child(plot_column)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bar_chart_builder() {
+        let chart = BarChart::new(vec![3.0, 5.0])
+            .labels(vec!["A".into(), "B".into()])
+            .show_axes(false);
+        assert_eq!(chart.props.values, vec![3.0, 5.0]);
+        assert!(!chart.props.show_axes);
+        assert_eq!(chart.label(1).as_ref(), "B");
+        assert_eq!(chart.label(2).as_ref(), "#3");
+    }
+
+    #[test]
+    fn test_scale_includes_zero() {
+        let scale = BarChart::new(vec![40.0, 55.0]).scale();
+        assert!(scale.min.abs() < f64::EPSILON);
+        assert!(scale.max >= 55.0);
+
+        let scale = BarChart::new(vec![-10.0, 20.0]).scale();
+        assert!(scale.min <= -10.0);
+    }
+}
@@ -0,0 +1,423 @@
+//! LineChart component: one or more series over shared categories.
+
+use std::{cell::Cell, rc::Rc};
+
+use gpui::*;
+use crate::charts::{format_value, plot, scale::interpolate, ChartScale};
+use crate::theme::{use_theme, Theme};
+use crate::utils::motion::{animation, reduced_motion, Easing};
+
+/// A named series of values plotted by a chart
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartSeries {
+    /// Name shown in tooltips
+    pub name: SharedString,
+    /// One value per category
+    pub values: Vec<f64>,
+    /// Line color, taken from the theme palette when unset
+    pub color: Option<Hsla>,
+}
+
+impl ChartSeries {
+    /// Create a series named `name`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let series = ChartSeries::new("Signups", vec![120.0, 180.0, 150.0]);
+    /// ```
+    pub fn new(name: impl Into<SharedString>, values: Vec<f64>) -> Self {
+        Self {
+            name: name.into(),
+            values,
+            color: None,
+        }
+    }
+
+    /// Set the line color
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ChartSeries::new("Errors", errors).color(theme.alias.color_danger);
+    /// ```
+    pub fn color(mut self, color: Hsla) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+/// LineChart configuration properties
+#[derive(Clone)]
+pub struct LineChartProps {
+    /// Series drawn as lines
+    pub series: Vec<ChartSeries>,
+    /// Category labels along the bottom axis
+    pub labels: Vec<SharedString>,
+    /// Height of the plot area
+    pub height: Pixels,
+    /// Whether to show the value axis, category labels and gridlines
+    pub show_axes: bool,
+    /// Whether to shade the area under each line
+    pub filled: bool,
+    /// Whether hovering shows the values at the nearest category
+    pub tooltip: bool,
+}
+
+impl Default for LineChartProps {
+    fn default() -> Self {
+        Self {
+            series: Vec::new(),
+            labels: Vec::new(),
+            height: px(200.0),
+            show_axes: true,
+            filled: false,
+            tooltip: true,
+        }
+    }
+}
+
+/// A line chart for trends over time or ordered categories.
+///
+/// Each series is drawn as a line across the plot, scaled together on a
+/// value axis with round-numbered ticks. Hovering marks the nearest
+/// category and shows every series' value there. Data set with
+/// [`LineChart::set_series`] animates from the old lines unless motion is
+/// reduced.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::charts::*;
+///
+/// LineChart::new()
+///     .labels(vec!["Mon".into(), "Tue".into(), "Wed".into()])
+///     .series(ChartSeries::new("Visits", vec![320.0, 410.0, 380.0]))
+///     .series(ChartSeries::new("Signups", vec![24.0, 31.0, 29.0]))
+///     .height(px(240.0));
+/// ```
+///
+/// ## Accessibility
+///
+/// - Series colors come from the theme palette; give series distinct names
+///   since the tooltip pairs each color with its name
+/// - Provide the underlying numbers in a table or summary for screen readers
+pub struct LineChart {
+    props: LineChartProps,
+    /// Series before the last change, the start of the animation
+    previous: Vec<ChartSeries>,
+    /// Bumped on every change so the animation restarts
+    generation: usize,
+    /// Category under the mouse
+    hovered: Option<usize>,
+    /// Plot bounds from the last paint, for hit testing
+    plot_bounds: Rc<Cell<Option<Bounds<Pixels>>>>,
+}
+
+impl LineChart {
+    /// Create an empty line chart
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let chart = LineChart::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            props: LineChartProps::default(),
+            previous: Vec::new(),
+            generation: 0,
+            hovered: None,
+            plot_bounds: Rc::new(Cell::new(None)),
+        }
+    }
+
+    /// Add a series
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// LineChart::new().series(ChartSeries::new("CPU", cpu_samples));
+    /// ```
+    pub fn series(mut self, series: ChartSeries) -> Self {
+        self.props.series.push(series);
+        self
+    }
+
+    /// Set the category labels along the bottom axis
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// LineChart::new().labels(months.iter().map(|m| m.name().into()).collect());
+    /// ```
+    pub fn labels(mut self, labels: Vec<SharedString>) -> Self {
+        self.props.labels = labels;
+        self
+    }
+
+    /// Set the height of the plot area
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// LineChart::new().height(px(160.0));
+    /// ```
+    pub fn height(mut self, height: Pixels) -> Self {
+        self.props.height = height;
+        self
+    }
+
+    /// Set whether to show axes and gridlines
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// LineChart::new().show_axes(false);
+    /// ```
+    pub fn show_axes(mut self, show_axes: bool) -> Self {
+        self.props.show_axes = show_axes;
+        self
+    }
+
+    /// Set whether to shade the area under each line
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// LineChart::new().filled(true);
+    /// ```
+    pub fn filled(mut self, filled: bool) -> Self {
+        self.props.filled = filled;
+        self
+    }
+
+    /// Set whether hovering shows a tooltip
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// LineChart::new().tooltip(false);
+    /// ```
+    pub fn tooltip(mut self, tooltip: bool) -> Self {
+        self.props.tooltip = tooltip;
+        self
+    }
+
+    /// Replace the series, animating from the current lines
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// chart.update(cx, |chart, cx| chart.set_series(vec![visits, signups], cx));
+    /// ```
+    pub fn set_series(&mut self, series: Vec<ChartSeries>, cx: &mut Context<'_, Self>) {
+        self.previous = std::mem::replace(&mut self.props.series, series);
+        self.generation += 1;
+        cx.notify();
+    }
+
+    /// Number of categories, the length of the longest series
+    fn category_count(&self) -> usize {
+        self.props.series.iter().map(|series| series.values.len()).max().unwrap_or(0)
+    }
+
+    /// Scale shared by all series
+    fn scale(&self) -> ChartScale {
+        let values: Vec<f64> = self
+            .props
+            .series
+            .iter()
+            .flat_map(|series| series.values.iter().copied())
+            .collect();
+        ChartScale::new(&values, false).nice(plot::AXIS_TICKS)
+    }
+
+    /// Track the category under the mouse
+    fn handle_mouse_move(&mut self, event: &MouseMoveEvent, _window: &mut Window, cx: &mut Context<'_, Self>) {
+        let Some(bounds) = self.plot_bounds.get() else {
+            return;
+        };
+        let hovered = if bounds.contains(&event.position) {
+            plot::nearest_index(bounds, event.position.x, self.category_count())
+        } else {
+            None
+        };
+        if hovered != self.hovered {
+            self.hovered = hovered;
+            cx.notify();
+        }
+    }
+
+    /// Tooltip and guide line for the hovered category
+    fn hover_overlay(&self, index: usize, theme: &Theme) -> Div {
+        let count = self.category_count();
+        #[allow(clippy::cast_precision_loss)]
+        let x = if count > 1 { index as f32 / (count - 1) as f32 } else { 0.5 };
+        let title = self
+            .props
+            .labels
+            .get(index)
+            .cloned()
+            .unwrap_or_else(|| format!("#{}", index + 1).into());
+        let lines = self
+            .props
+            .series
+            .iter()
+            .enumerate()
+            .filter_map(|(series_index, series)| {
+                let value = series.values.get(index)?;
+                let color = series.color.unwrap_or_else(|| plot::series_color(theme, series_index));
+                Some((color, format!("{}: {}", series.name, format_value(*value)).into()))
+            })
+            .collect();
+
+        // Flip the tooltip to the left of the guide past the middle
+        let tooltip = plot::tooltip(title, lines, theme).absolute().top_0();
+        let tooltip = if x > 0.5 {
+            tooltip.right(theme.global.spacing_sm)
+        } else {
+            tooltip.left(theme.global.spacing_sm)
+        };
+        div()
+            .absolute()
+            .top_0()
+            .bottom_0()
+            .left(relative(x))
+            .w(px(1.0))
+            .bg(theme.alias.color_border_hover)
+            .child(tooltip)
+    }
+}
+
+impl Default for LineChart {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Render for LineChart {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        let scale = self.scale();
+
+        let lines: Vec<(Vec<f64>, Vec<f64>, Hsla)> = self
+            .props
+            .series
+            .iter()
+            .enumerate()
+            .map(|(index, series)| {
+                let previous = self
+                    .previous
+                    .get(index)
+                    .map_or_else(|| series.values.clone(), |previous| previous.values.clone());
+                let color = series.color.unwrap_or_else(|| plot::series_color(&theme, index));
+                (previous, series.values.clone(), color)
+            })
+            .collect();
+        let filled = self.props.filled;
+        let plot_bounds = self.plot_bounds.clone();
+        let paint_lines = move |t: f32| {
+            let lines = lines.clone();
+            let plot_bounds = plot_bounds.clone();
+            canvas(
+                |_, _, _| {},
+                move |bounds, (), window, _cx| {
+                    plot_bounds.set(Some(bounds));
+                    for (previous, values, color) in &lines {
+                        let values = interpolate(previous, values, t);
+                        plot::paint_series(bounds, &values, scale, *color, px(2.0), filled, window);
+                    }
+                },
+            )
+            .absolute()
+            .size_full()
+        };
+
+        let mut plot_area = div()
+            .id("line-chart-plot")
+            .relative()
+            .flex_1()
+            .h_full()
+            .on_mouse_move(cx.listener(Self::handle_mouse_move))
+            .on_hover(cx.listener(|this, hovered: &bool, _window, cx| {
+                if !hovered && this.hovered.take().is_some() {
+                    cx.notify();
+                }
+            }));
+        if self.props.show_axes {
+            plot_area = plot_area.children(plot::gridlines(scale, &theme));
+        }
+
+        let animate = self.generation > 0 && !reduced_motion(cx);
+        let lines = if animate {
+            div()
+                .absolute()
+                .size_full()
+                .with_animation(
+                    ("line-chart", self.generation),
+                    animation(theme.global.duration_slow, Easing::Standard),
+                    move |lines, delta| lines.child(paint_lines(delta)),
+                )
+                .into_any_element()
+        } else {
+            paint_lines(1.0).into_any_element()
+        };
+        plot_area = plot_area.child(lines);
+
+        if let Some(index) = self.hovered.filter(|_| self.props.tooltip) {
+            plot_area = plot_area.child(self.hover_overlay(index, &theme));
+        }
+
+        // Category labels sit under the plot, clear of the value axis
+        let mut plot_column = div()
+            .flex()
+            .flex_col()
+            .flex_1()
+            .gap(theme.global.spacing_xs)
+            .child(div().flex().h(self.props.height).child(plot_area));
+        if self.props.show_axes && !self.props.labels.is_empty() {
+            plot_column = plot_column.child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .justify_between()
+                    .text_size(theme.global.font_size_xs)
+                    .text_color(theme.alias.color_text_muted)
+                    .children(self.props.labels.iter().cloned()),
+            );
+        }
+
+        let mut chart = div().flex().flex_row().w_full().gap(theme.global.spacing_sm);
+        if self.props.show_axes {
+            chart = chart.child(div().flex().h(self.props.height).child(plot::value_axis(scale, &theme)));
+        }
+        chart.child(plot_column)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_chart_builder() {
+        let chart = LineChart::new()
+            .series(ChartSeries::new("A", vec![1.0, 2.0, 3.0]))
+            .series(ChartSeries::new("B", vec![4.0, 5.0]))
+            .show_axes(false);
+        assert_eq!(chart.props.series.len(), 2);
+        assert_eq!(chart.category_count(), 3);
+        assert!(!chart.props.show_axes);
+    }
+
+    #[test]
+    fn test_scale_covers_all_series() {
+        let chart = LineChart::new()
+            .series(ChartSeries::new("A", vec![1.0, 2.0]))
+            .series(ChartSeries::new("B", vec![12.0, 4.0]));
+        let scale = chart.scale();
+        assert!(scale.min <= 1.0);
+        assert!(scale.max >= 12.0);
+    }
+}
@@ -0,0 +1,41 @@
+//! Lightweight charts for dashboards.
+//!
+//! Charts are drawn with GPUI paths and divs, colored from the theme's
+//! alias tokens, and animate between data sets unless motion is reduced.
+//!
+//! ## Available Charts
+//!
+//! - [`Sparkline`]: Small inline trend line without axes
+//! - [`LineChart`]: One or more series with axes and a hover tooltip
+//! - [`BarChart`]: One bar per category with axes and a hover tooltip
+//! - [`ChartScale`]: Value scale with round-numbered ticks, shared by the charts
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use purdah_gpui_components::charts::*;
+//!
+//! // Trend in a metric card
+//! card.child(Sparkline::new(daily_users.clone()).filled(true).to_element(&theme));
+//!
+//! // Weekly traffic
+//! let traffic = cx.new(|_| {
+//!     LineChart::new()
+//!         .labels(days.clone())
+//!         .series(ChartSeries::new("Visits", visits))
+//! });
+//!
+//! // Revenue by quarter
+//! let revenue = cx.new(|_| BarChart::new(quarters).labels(quarter_names));
+//! ```
+
+pub mod scale;
+pub mod sparkline;
+pub mod line_chart;
+pub mod bar_chart;
+mod plot;
+
+pub use scale::{format_value, ChartScale};
+pub use sparkline::{Sparkline, SparklineProps};
+pub use line_chart::{ChartSeries, LineChart, LineChartProps};
+pub use bar_chart::{BarChart, BarChartProps};
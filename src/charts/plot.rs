@@ -0,0 +1,151 @@
+//! Drawing helpers shared by the charts: series paths, axes and gridlines.
+
+use gpui::*;
+use crate::charts::ChartScale;
+use crate::theme::Theme;
+
+/// Ticks aimed for on a value axis
+pub(crate) const AXIS_TICKS: usize = 4;
+
+/// Default color of the series at `index`, cycling through the alias palette
+pub(crate) fn series_color(theme: &Theme, index: usize) -> Hsla {
+    let palette = [
+        theme.alias.color_primary,
+        theme.alias.color_success,
+        theme.alias.color_warning,
+        theme.alias.color_danger,
+        theme.alias.color_secondary,
+    ];
+    palette[index % palette.len()]
+}
+
+/// Positions of `values` spread evenly across `bounds`
+///
+/// A single value sits in the middle.
+pub(crate) fn points(bounds: Bounds<Pixels>, values: &[f64], scale: ChartScale) -> Vec<Point<Pixels>> {
+    let last = values.len().saturating_sub(1);
+    values
+        .iter()
+        .enumerate()
+        .map(|(index, &value)| {
+            #[allow(clippy::cast_precision_loss)]
+            let x = if last == 0 { 0.5 } else { index as f32 / last as f32 };
+            point(
+                bounds.left() + bounds.size.width * x,
+                bounds.bottom() - bounds.size.height * scale.fraction(value),
+            )
+        })
+        .collect()
+}
+
+/// Index of the value nearest to `x` among `len` evenly spread values
+pub(crate) fn nearest_index(bounds: Bounds<Pixels>, x: Pixels, len: usize) -> Option<usize> {
+    if len == 0 || bounds.size.width <= px(0.0) {
+        return None;
+    }
+    let fraction = ((x - bounds.left()) / bounds.size.width).clamp(0.0, 1.0);
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    let index = (fraction * (len - 1) as f32).round() as usize;
+    Some(index)
+}
+
+/// Paint `values` as a line, with a translucent area beneath when `filled`
+pub(crate) fn paint_series(
+    bounds: Bounds<Pixels>,
+    values: &[f64],
+    scale: ChartScale,
+    color: Hsla,
+    stroke_width: Pixels,
+    filled: bool,
+    window: &mut Window,
+) {
+    let points = points(bounds, values, scale);
+    let (Some(&first), Some(&last)) = (points.first(), points.last()) else {
+        return;
+    };
+
+    if filled && points.len() > 1 {
+        let mut area = PathBuilder::fill();
+        area.move_to(point(first.x, bounds.bottom()));
+        for &vertex in &points {
+            area.line_to(vertex);
+        }
+        area.line_to(point(last.x, bounds.bottom()));
+        area.close();
+        if let Ok(path) = area.build() {
+            window.paint_path(path, color.opacity(0.15));
+        }
+    }
+
+    let mut line = PathBuilder::stroke(stroke_width);
+    line.move_to(first);
+    for &vertex in &points[1..] {
+        line.line_to(vertex);
+    }
+    if let Ok(path) = line.build() {
+        window.paint_path(path, color);
+    }
+}
+
+/// Tick labels for the value axis, top to bottom
+pub(crate) fn value_axis(scale: ChartScale, theme: &Theme) -> Div {
+    div()
+        .flex()
+        .flex_col()
+        .flex_none()
+        .justify_between()
+        .items_end()
+        .h_full()
+        .text_size(theme.global.font_size_xs)
+        .text_color(theme.alias.color_text_muted)
+        .children(scale.ticks(AXIS_TICKS).into_iter().rev().map(|tick| {
+            // Labels are centered on their gridline
+            div().h(px(0.0)).flex().items_center().child(super::format_value(tick))
+        }))
+}
+
+/// Horizontal gridlines at each tick, laid over the plot area
+pub(crate) fn gridlines(scale: ChartScale, theme: &Theme) -> impl Iterator<Item = Div> {
+    let color = theme.alias.color_border.opacity(0.5);
+    scale.ticks(AXIS_TICKS).into_iter().map(move |tick| {
+        div()
+            .absolute()
+            .left_0()
+            .right_0()
+            .bottom(relative(scale.fraction(tick)))
+            .h(px(1.0))
+            .bg(color)
+    })
+}
+
+/// Small label box shown over a hovered data point
+pub(crate) fn tooltip(title: SharedString, lines: Vec<(Hsla, SharedString)>, theme: &Theme) -> Div {
+    div()
+        .flex()
+        .flex_col()
+        .gap(px(2.0))
+        .px(theme.global.spacing_sm)
+        .py(theme.global.spacing_xs)
+        .rounded(theme.global.radius_sm)
+        .bg(theme.alias.color_surface_elevated)
+        .border(px(1.0))
+        .border_color(theme.alias.color_border)
+        .shadow_md()
+        .whitespace_nowrap()
+        .text_size(theme.global.font_size_xs)
+        .text_color(theme.alias.color_text_primary)
+        .child(div().text_color(theme.alias.color_text_muted).child(title))
+        .children(lines.into_iter().map(|(color, text)| {
+            div()
+                .flex()
+                .flex_row()
+                .items_center()
+                .gap(theme.global.spacing_xs)
+                .child(div().size(px(8.0)).rounded(px(4.0)).bg(color))
+                .child(text)
+        }))
+}
@@ -0,0 +1,193 @@
+//! Value scales, axis ticks and data interpolation shared by the charts.
+
+use gpui::*;
+
+/// Maps data values onto the 0.0–1.0 height of a plot area.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::charts::*;
+///
+/// let scale = ChartScale::new(&[3.0, 7.0, 12.0], true).nice(4);
+/// let y = scale.fraction(7.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChartScale {
+    /// Value at the bottom of the plot
+    pub min: f64,
+    /// Value at the top of the plot
+    pub max: f64,
+}
+
+impl ChartScale {
+    /// Scale spanning `values`, stretched to include zero if asked
+    ///
+    /// Empty data spans 0 to 1, and data of a single value gets a unit of
+    /// room either side so it is drawn mid-height.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// // Bars grow from zero
+    /// let scale = ChartScale::new(&revenue, true);
+    /// ```
+    pub fn new(values: &[f64], include_zero: bool) -> Self {
+        let finite = values.iter().copied().filter(|value| value.is_finite());
+        let (mut min, mut max) = finite.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+            (min.min(value), max.max(value))
+        });
+        if min > max {
+            return Self { min: 0.0, max: 1.0 };
+        }
+        if include_zero {
+            min = min.min(0.0);
+            max = max.max(0.0);
+        }
+        if (max - min).abs() < f64::EPSILON {
+            min -= 1.0;
+            max += 1.0;
+        }
+        Self { min, max }
+    }
+
+    /// Widen the scale to round tick values, about `ticks` of them
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// // 0–12 becomes 0–15 with ticks every 5
+    /// let scale = ChartScale::new(&values, true).nice(4);
+    /// ```
+    pub fn nice(self, ticks: usize) -> Self {
+        let step = self.tick_step(ticks);
+        Self {
+            min: (self.min / step).floor() * step,
+            max: (self.max / step).ceil() * step,
+        }
+    }
+
+    /// Fraction of the plot height `value` sits at, 0.0 at the bottom
+    pub fn fraction(&self, value: f64) -> f32 {
+        #[allow(clippy::cast_possible_truncation)]
+        let fraction = ((value - self.min) / (self.max - self.min)) as f32;
+        fraction.clamp(0.0, 1.0)
+    }
+
+    /// Round-numbered tick values from `min` to `max`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// for tick in scale.ticks(4) {
+    ///     axis = axis.child(format_value(tick));
+    /// }
+    /// ```
+    pub fn ticks(&self, ticks: usize) -> Vec<f64> {
+        let step = self.tick_step(ticks);
+        let first = (self.min / step).ceil();
+        (0u32..)
+            .map(|index| (first + f64::from(index)) * step)
+            .take_while(|tick| *tick <= self.max + step * 1e-9)
+            // Round away floating point noise such as 0.6000000000000001
+            .map(|tick| (tick * 1e9).round() / 1e9)
+            .collect()
+    }
+
+    /// Distance between ticks: 1, 2 or 5 times a power of ten
+    fn tick_step(&self, ticks: usize) -> f64 {
+        #[allow(clippy::cast_precision_loss)]
+        let raw = (self.max - self.min) / ticks.max(1) as f64;
+        if !raw.is_finite() || raw <= 0.0 {
+            return 1.0;
+        }
+        let magnitude = 10f64.powf(raw.log10().floor());
+        let step = match raw / magnitude {
+            ratio if ratio <= 1.0 => 1.0,
+            ratio if ratio <= 2.0 => 2.0,
+            ratio if ratio <= 5.0 => 5.0,
+            _ => 10.0,
+        };
+        step * magnitude
+    }
+}
+
+/// Compact label for an axis tick or tooltip, such as "1.5k" or "2M"
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// assert_eq!(format_value(12_500.0).as_ref(), "12.5k");
+/// ```
+pub fn format_value(value: f64) -> SharedString {
+    let (scaled, suffix) = match value.abs() {
+        abs if abs >= 1e9 => (value / 1e9, "B"),
+        abs if abs >= 1e6 => (value / 1e6, "M"),
+        abs if abs >= 1e3 => (value / 1e3, "k"),
+        _ => (value, ""),
+    };
+    let text = format!("{scaled:.2}");
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    let text = if text == "-0" { "0" } else { text };
+    format!("{text}{suffix}").into()
+}
+
+/// Values part way from `from` to `to`, for animating data changes
+///
+/// Series of different lengths do not interpolate; `to` is returned as is.
+pub(crate) fn interpolate(from: &[f64], to: &[f64], t: f32) -> Vec<f64> {
+    if from.len() != to.len() {
+        return to.to_vec();
+    }
+    let t = f64::from(t.clamp(0.0, 1.0));
+    from.iter().zip(to).map(|(from, to)| from + (to - from) * t).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scale_spans_values() {
+        let scale = ChartScale::new(&[3.0, 7.0, 12.0], false);
+        assert_eq!(scale, ChartScale { min: 3.0, max: 12.0 });
+
+        let scale = ChartScale::new(&[3.0, 7.0, 12.0], true);
+        assert_eq!(scale, ChartScale { min: 0.0, max: 12.0 });
+
+        assert_eq!(ChartScale::new(&[], true), ChartScale { min: 0.0, max: 1.0 });
+        assert_eq!(ChartScale::new(&[5.0], false), ChartScale { min: 4.0, max: 6.0 });
+    }
+
+    #[test]
+    fn test_fraction_is_clamped() {
+        let scale = ChartScale { min: 0.0, max: 10.0 };
+        assert!((scale.fraction(5.0) - 0.5).abs() < f32::EPSILON);
+        assert!(scale.fraction(-5.0).abs() < f32::EPSILON);
+        assert!((scale.fraction(50.0) - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_nice_ticks() {
+        let scale = ChartScale::new(&[0.0, 12.0], true).nice(4);
+        assert_eq!(scale, ChartScale { min: 0.0, max: 15.0 });
+        assert_eq!(scale.ticks(4), vec![0.0, 5.0, 10.0, 15.0]);
+
+        let scale = ChartScale { min: 0.0, max: 0.6 };
+        assert_eq!(scale.ticks(3), vec![0.0, 0.2, 0.4, 0.6]);
+    }
+
+    #[test]
+    fn test_format_value() {
+        assert_eq!(format_value(12.0).as_ref(), "12");
+        assert_eq!(format_value(0.25).as_ref(), "0.25");
+        assert_eq!(format_value(12_500.0).as_ref(), "12.5k");
+        assert_eq!(format_value(-2_000_000.0).as_ref(), "-2M");
+    }
+
+    #[test]
+    fn test_interpolate() {
+        assert_eq!(interpolate(&[0.0, 10.0], &[10.0, 20.0], 0.5), vec![5.0, 15.0]);
+        assert_eq!(interpolate(&[0.0], &[10.0, 20.0], 0.5), vec![10.0, 20.0]);
+    }
+}
@@ -0,0 +1,244 @@
+//! Sparkline component: a small inline trend line without axes.
+
+use gpui::*;
+use crate::charts::{plot, scale::interpolate, ChartScale};
+use crate::theme::{use_theme, Theme};
+use crate::utils::motion::{animation, reduced_motion, Easing};
+
+/// Sparkline configuration properties
+#[derive(Clone)]
+pub struct SparklineProps {
+    /// Data points, oldest first
+    pub values: Vec<f64>,
+    /// Line color, the primary color when unset
+    pub color: Option<Hsla>,
+    /// Width, filling the parent when unset
+    pub width: Option<Pixels>,
+    /// Height of the line area
+    pub height: Pixels,
+    /// Width of the line
+    pub stroke_width: Pixels,
+    /// Whether to shade the area under the line
+    pub filled: bool,
+}
+
+impl Default for SparklineProps {
+    fn default() -> Self {
+        Self {
+            values: Vec::new(),
+            color: None,
+            width: None,
+            height: px(24.0),
+            stroke_width: px(1.5),
+            filled: false,
+        }
+    }
+}
+
+/// A compact trend line for tables, stat cards and dashboards.
+///
+/// Sparkline draws its values as a single line scaled to their own range,
+/// with no axes or labels. New data set with [`Sparkline::set_values`]
+/// animates from the old line to the new one unless motion is reduced.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::charts::*;
+///
+/// // In a view
+/// let trend = cx.new(|_| Sparkline::new(weekly_signups.clone()).filled(true));
+///
+/// // Later, when data arrives
+/// trend.update(cx, |trend, cx| trend.set_values(new_signups, cx));
+///
+/// // Embedded without animation
+/// row.child(Sparkline::new(latency.clone()).width(px(80.0)).to_element(&theme));
+/// ```
+///
+/// ## Accessibility
+///
+/// - Sparklines are decorative; state the figure they summarize in text
+pub struct Sparkline {
+    props: SparklineProps,
+    /// Values before the last change, the start of the animation
+    previous: Vec<f64>,
+    /// Bumped on every change so the animation restarts
+    generation: usize,
+}
+
+impl Sparkline {
+    /// Create a sparkline of `values`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let sparkline = Sparkline::new(vec![3.0, 5.0, 4.0, 8.0]);
+    /// ```
+    pub fn new(values: Vec<f64>) -> Self {
+        Self {
+            props: SparklineProps {
+                values,
+                ..Default::default()
+            },
+            previous: Vec::new(),
+            generation: 0,
+        }
+    }
+
+    /// Set the line color
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Sparkline::new(errors).color(theme.alias.color_danger);
+    /// ```
+    pub fn color(mut self, color: Hsla) -> Self {
+        self.props.color = Some(color);
+        self
+    }
+
+    /// Set a fixed width instead of filling the parent
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Sparkline::new(values).width(px(120.0));
+    /// ```
+    pub fn width(mut self, width: Pixels) -> Self {
+        self.props.width = Some(width);
+        self
+    }
+
+    /// Set the height
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Sparkline::new(values).height(px(32.0));
+    /// ```
+    pub fn height(mut self, height: Pixels) -> Self {
+        self.props.height = height;
+        self
+    }
+
+    /// Set the line width
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Sparkline::new(values).stroke_width(px(2.0));
+    /// ```
+    pub fn stroke_width(mut self, width: Pixels) -> Self {
+        self.props.stroke_width = width;
+        self
+    }
+
+    /// Set whether to shade the area under the line
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Sparkline::new(values).filled(true);
+    /// ```
+    pub fn filled(mut self, filled: bool) -> Self {
+        self.props.filled = filled;
+        self
+    }
+
+    /// Replace the data, animating from the current line
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// sparkline.update(cx, |sparkline, cx| sparkline.set_values(values, cx));
+    /// ```
+    pub fn set_values(&mut self, values: Vec<f64>, cx: &mut Context<'_, Self>) {
+        self.previous = std::mem::replace(&mut self.props.values, values);
+        self.generation += 1;
+        cx.notify();
+    }
+
+    /// Sized container for the line
+    fn frame(&self) -> Div {
+        let frame = div().flex_none().h(self.props.height);
+        match self.props.width {
+            Some(width) => frame.w(width),
+            None => frame.w_full(),
+        }
+    }
+
+    /// Convert to a GPUI element for embedding inside another component's render
+    ///
+    /// Embedded sparklines draw the current values without animating.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// cell.child(Sparkline::new(history.clone()).to_element(&theme));
+    /// ```
+    pub fn to_element(&self, theme: &Theme) -> Div {
+        let color = self.props.color.unwrap_or(theme.alias.color_primary);
+        let scale = ChartScale::new(&self.props.values, false);
+        self.frame().child(line(
+            self.props.values.clone(),
+            scale,
+            color,
+            self.props.stroke_width,
+            self.props.filled,
+        ))
+    }
+}
+
+/// Canvas painting `values` on `scale`
+fn line(values: Vec<f64>, scale: ChartScale, color: Hsla, stroke_width: Pixels, filled: bool) -> Canvas<()> {
+    canvas(
+        |_, _, _| {},
+        move |bounds, (), window, _cx| {
+            // Inset so the stroke is not clipped at the top and bottom
+            let inset = bounds.dilate(-stroke_width / 2.0);
+            plot::paint_series(inset, &values, scale, color, stroke_width, filled, window);
+        },
+    )
+    .size_full()
+}
+
+impl Render for Sparkline {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        if self.generation == 0 || reduced_motion(cx) {
+            return self.to_element(&theme).into_any_element();
+        }
+
+        let color = self.props.color.unwrap_or(theme.alias.color_primary);
+        let scale = ChartScale::new(&self.props.values, false);
+        let previous = self.previous.clone();
+        let values = self.props.values.clone();
+        let stroke_width = self.props.stroke_width;
+        let filled = self.props.filled;
+        self.frame()
+            .with_animation(
+                ("sparkline", self.generation),
+                animation(theme.global.duration_slow, Easing::Standard),
+                move |frame, delta| {
+                    let values = interpolate(&previous, &values, delta);
+                    frame.child(line(values, scale, color, stroke_width, filled))
+                },
+            )
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparkline_builder() {
+        let sparkline = Sparkline::new(vec![1.0, 2.0]).height(px(32.0)).filled(true);
+        assert_eq!(sparkline.props.values, vec![1.0, 2.0]);
+        assert_eq!(sparkline.props.height, px(32.0));
+        assert!(sparkline.props.filled);
+        assert_eq!(sparkline.generation, 0);
+    }
+}
@@ -0,0 +1,443 @@
+//! Form container aggregating field validation and submission.
+
+use std::{collections::HashMap, rc::Rc};
+
+use gpui::*;
+
+use crate::{
+    atoms::{Button, ButtonVariant},
+    molecules::FormGroup,
+    theme::use_theme,
+    utils::Announcer,
+};
+
+use super::{
+    field::{FieldState, FormField},
+    rules::validate,
+};
+
+/// Field values of a submitted form, keyed by field name
+pub type FormValues = HashMap<SharedString, SharedString>;
+
+/// A field's configuration, validation state and rendered group
+struct FormEntry {
+    field: FormField,
+    state: FieldState,
+    /// Running async validation; dropping it cancels the validation
+    pending: Option<Task<()>>,
+    /// FormGroup showing the field, created on first render
+    group: Option<Entity<FormGroup>>,
+}
+
+/// A form validating its fields and submitting their values.
+///
+/// Each field is shown as a [`FormGroup`] with its label, required marker,
+/// helper text and error message. Values are checked against the field's
+/// [`Rule`](super::Rule)s on every change, then against its async
+/// validator. Errors appear once a field was edited or a submit was
+/// attempted, and the submit button stays disabled until every field is
+/// valid.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::form::*;
+///
+/// let form = cx.new(|_| {
+///     Form::new()
+///         .field(
+///             FormField::new("email")
+///                 .label("Email")
+///                 .rules(vec![Rule::required(), Rule::email()]),
+///         )
+///         .field(
+///             FormField::new("username")
+///                 .label("Username")
+///                 .rules(vec![Rule::required(), Rule::min_length(3)])
+///                 .async_validator(check_username_available),
+///         )
+///         .submit_label("Create account")
+///         .on_submit(|values, _window, cx| create_account(&values["email"], &values["username"], cx))
+/// });
+///
+/// // Feed edits from the field controls
+/// form.update(cx, |form, cx| form.set_value("email", value, cx));
+/// ```
+///
+/// ## Accessibility
+///
+/// - Errors are announced politely through the [`Announcer`] live region as
+///   they appear
+/// - A failed submit assertively announces how many fields need attention
+pub struct Form {
+    entries: Vec<FormEntry>,
+    submit_label: SharedString,
+    on_submit: Option<Rc<dyn Fn(FormValues, &mut Window, &mut App)>>,
+}
+
+impl Form {
+    /// Create an empty form
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let form = Form::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            submit_label: "Submit".into(),
+            on_submit: None,
+        }
+    }
+
+    /// Add a field
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Form::new().field(FormField::new("email").label("Email").rule(Rule::required()));
+    /// ```
+    pub fn field(mut self, field: FormField) -> Self {
+        let state = FieldState::new(field.default_value.clone(), &field.rules);
+        self.entries.push(FormEntry { field, state, pending: None, group: None });
+        self
+    }
+
+    /// Set the submit button label
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Form::new().submit_label("Sign in");
+    /// ```
+    pub fn submit_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.submit_label = label.into();
+        self
+    }
+
+    /// Set the handler called with the field values when a valid form is submitted
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Form::new().on_submit(|values, _window, cx| sign_in(&values["email"], cx));
+    /// ```
+    pub fn on_submit(mut self, handler: impl Fn(FormValues, &mut Window, &mut App) + 'static) -> Self {
+        self.on_submit = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set a field's value, typically from its control's change handler
+    ///
+    /// Marks the field as touched and validates the new value.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// form.update(cx, |form, cx| form.set_value("email", "me@example.com", cx));
+    /// ```
+    pub fn set_value(&mut self, name: &str, value: impl Into<SharedString>, cx: &mut Context<'_, Self>) {
+        let Some(index) = self.index(name) else {
+            return;
+        };
+        let value = value.into();
+
+        let entry = &mut self.entries[index];
+        entry.state.value = value.clone();
+        entry.state.touched = true;
+        entry.pending = None;
+
+        let error = validate(&entry.field.rules, &value).err();
+        let validator = entry.field.async_validator.clone().filter(|_| error.is_none());
+        entry.state.validating = validator.is_some();
+        self.set_error(index, error, cx);
+
+        if let Some(validator) = validator {
+            let task = validator(value.clone(), cx);
+            let name = self.entries[index].field.name.clone();
+            self.entries[index].pending = Some(cx.spawn(async move |this, cx| {
+                let result = task.await;
+                this.update(cx, |this, cx| this.finish_validation(&name, &value, result, cx)).ok();
+            }));
+        }
+
+        self.sync_group(index, cx);
+        cx.notify();
+    }
+
+    /// Show errors on every field and report whether the form is valid
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let valid = form.update(cx, |form, cx| form.validate_all(cx));
+    /// ```
+    pub fn validate_all(&mut self, cx: &mut Context<'_, Self>) -> bool {
+        for index in 0..self.entries.len() {
+            self.entries[index].state.touched = true;
+            self.sync_group(index, cx);
+        }
+        cx.notify();
+        self.is_valid()
+    }
+
+    /// Submit the form, or show and announce its errors when it is invalid
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// form.update(cx, |form, cx| form.submit(window, cx));
+    /// ```
+    pub fn submit(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if !self.validate_all(cx) {
+            // Fields still being validated asynchronously block without an error
+            let count = self.errors().len();
+            if count > 0 {
+                let message = if count == 1 {
+                    "1 field needs attention".to_string()
+                } else {
+                    format!("{count} fields need attention")
+                };
+                Announcer::assertive(message).announce(cx);
+            }
+            return;
+        }
+
+        if let Some(handler) = self.on_submit.clone() {
+            handler(self.values(), window, cx);
+        }
+    }
+
+    /// Whether every field passed validation
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let can_submit = form.read(cx).is_valid();
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        self.entries.iter().all(|entry| entry.state.is_valid())
+    }
+
+    /// Whether any async validator is still running
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let checking = form.read(cx).is_validating();
+    /// ```
+    pub fn is_validating(&self) -> bool {
+        self.entries.iter().any(|entry| entry.state.validating)
+    }
+
+    /// Validation state of the field named `name`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let email = form.read(cx).field_state("email");
+    /// ```
+    pub fn field_state(&self, name: &str) -> Option<&FieldState> {
+        self.index(name).map(|index| &self.entries[index].state)
+    }
+
+    /// Names and messages of the fields that failed validation, in field order
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// for (name, message) in form.read(cx).errors() { log_error(name, message); }
+    /// ```
+    pub fn errors(&self) -> Vec<(SharedString, SharedString)> {
+        self.entries
+            .iter()
+            .filter_map(|entry| Some((entry.field.name.clone(), entry.state.error.clone()?)))
+            .collect()
+    }
+
+    /// Current values of all fields
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let draft = form.read(cx).values();
+    /// ```
+    pub fn values(&self) -> FormValues {
+        self.entries
+            .iter()
+            .map(|entry| (entry.field.name.clone(), entry.state.value.clone()))
+            .collect()
+    }
+
+    /// Position of the field named `name`
+    fn index(&self, name: &str) -> Option<usize> {
+        self.entries.iter().position(|entry| entry.field.name.as_ref() == name)
+    }
+
+    /// Apply an async validation result unless the value changed meanwhile
+    fn finish_validation(
+        &mut self,
+        name: &str,
+        value: &SharedString,
+        result: Result<(), SharedString>,
+        cx: &mut Context<'_, Self>,
+    ) {
+        let Some(index) = self.index(name) else {
+            return;
+        };
+        let entry = &mut self.entries[index];
+        if entry.state.value != *value {
+            return;
+        }
+        entry.state.validating = false;
+        entry.pending = None;
+
+        self.set_error(index, result.err(), cx);
+        self.sync_group(index, cx);
+        cx.notify();
+    }
+
+    /// Store a field's error, announcing it politely when a new error appears
+    ///
+    /// Errors found while typing are debounced by the live region, so only
+    /// the error left when the user pauses is read.
+    fn set_error(&mut self, index: usize, error: Option<SharedString>, cx: &mut Context<'_, Self>) {
+        let entry = &mut self.entries[index];
+        let changed = entry.state.error != error;
+        entry.state.error = error;
+
+        if changed && entry.state.touched {
+            if let Some(error) = entry.state.error.clone() {
+                let message = format!("{}: {error}", entry.field.label);
+                Announcer::polite(message).announce(cx);
+            }
+        }
+    }
+
+    /// Push a field's value and visible error to its FormGroup
+    fn sync_group(&self, index: usize, cx: &mut Context<'_, Self>) {
+        let entry = &self.entries[index];
+        let Some(group) = entry.group.clone() else {
+            return;
+        };
+        let value = entry.state.value.clone();
+        let error = entry.state.visible_error();
+        group.update(cx, |group, cx| {
+            group.set_value(value, cx);
+            group.set_error_message(error, cx);
+        });
+    }
+}
+
+impl Default for Form {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Render for Form {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+
+        // Create each field's FormGroup the first time the form renders
+        let mut groups = Vec::with_capacity(self.entries.len());
+        for entry in &mut self.entries {
+            let group = entry
+                .group
+                .get_or_insert_with(|| {
+                    let field = entry.field.clone();
+                    // Edits in the group's input update the field's state
+                    let name = field.name.clone();
+                    let on_change = cx.listener(move |form, value: &SharedString, _window, cx| {
+                        form.set_value(&name, value.clone(), cx);
+                    });
+                    let error = entry.state.visible_error();
+                    let value = entry.state.value.clone();
+                    cx.new(|_| {
+                        let mut group = FormGroup::new()
+                            .id(field.name.clone())
+                            .label(field.label.clone())
+                            .required(field.is_required())
+                            .value(value)
+                            .placeholder(field.placeholder.clone())
+                            .input_type(field.input_type)
+                            .on_change(move |value, window, cx| on_change(&value, window, cx));
+                        if let Some(text) = field.helper_text.clone() {
+                            group = group.helper_text(text);
+                        }
+                        if let Some(message) = error {
+                            group = group.error_message(message);
+                        }
+                        if let Some(control) = field.control.clone() {
+                            group = group.control(move |window, cx| control(window, cx));
+                        }
+                        group
+                    })
+                })
+                .clone();
+            groups.push(group);
+        }
+
+        let submit = Button::new()
+            .id("form-submit")
+            .label(self.submit_label.clone())
+            .variant(ButtonVariant::Primary)
+            .disabled(!self.is_valid())
+            .loading(self.is_validating())
+            .to_element(&theme)
+            .on_click(cx.listener(|this, _event: &ClickEvent, window, cx| {
+                if this.is_valid() {
+                    this.submit(window, cx);
+                }
+            }));
+
+        div()
+            .flex()
+            .flex_col()
+            .gap(theme.global.spacing_md)
+            .children(groups)
+            .child(div().flex().flex_row().justify_end().child(submit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::form::Rule;
+
+    fn signup_form() -> Form {
+        Form::new()
+            .field(FormField::new("email").label("Email").rules(vec![Rule::required(), Rule::email()]))
+            .field(FormField::new("bio").label("Bio").rule(Rule::max_length(10)))
+    }
+
+    #[test]
+    fn test_form_defaults() {
+        let form = Form::new();
+        assert!(form.is_valid());
+        assert!(form.errors().is_empty());
+        assert_eq!(form.submit_label.as_ref(), "Submit");
+    }
+
+    #[test]
+    fn test_untouched_invalid_fields_block_submit() {
+        let form = signup_form();
+        assert!(!form.is_valid());
+        assert_eq!(form.errors(), vec![("email".into(), "This field is required".into())]);
+        assert_eq!(form.field_state("email").unwrap().visible_error(), None);
+    }
+
+    #[test]
+    fn test_default_values_are_validated() {
+        let form = Form::new()
+            .field(FormField::new("email").rule(Rule::email()).default_value("me@example.com"));
+        assert!(form.is_valid());
+        assert_eq!(form.values()["email"].as_ref(), "me@example.com");
+    }
+
+    #[test]
+    fn test_unknown_field_has_no_state() {
+        assert!(signup_form().field_state("phone").is_none());
+    }
+}
@@ -0,0 +1,269 @@
+//! Form field configuration and validation state.
+
+use std::rc::Rc;
+
+use gpui::*;
+
+use crate::atoms::InputType;
+use crate::molecules::FormControlSlot;
+
+use super::rules::{validate, Rule};
+
+/// Asynchronous validator, e.g. checking username availability on a server
+///
+/// Called with the value once it passes the field's synchronous rules; the
+/// task resolves to the error message when the value is rejected.
+pub type AsyncValidator = Rc<dyn Fn(SharedString, &mut App) -> Task<Result<(), SharedString>>>;
+
+/// Configuration of a single field in a [`Form`](super::Form)
+#[derive(Clone)]
+pub struct FormField {
+    /// Name identifying the field in the form's values
+    pub name: SharedString,
+    /// Label text
+    pub label: SharedString,
+    /// Optional helper text
+    pub helper_text: Option<SharedString>,
+    /// Placeholder of the built-in input
+    pub placeholder: SharedString,
+    /// Type of the built-in input
+    pub input_type: InputType,
+    /// Initial value
+    pub default_value: SharedString,
+    /// Synchronous rules, checked in order
+    pub rules: Vec<Rule>,
+    /// Validator run after the rules pass
+    pub async_validator: Option<AsyncValidator>,
+    /// Custom control replacing the built-in input
+    pub control: Option<FormControlSlot>,
+}
+
+impl FormField {
+    /// Create a field named `name`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let field = FormField::new("email");
+    /// ```
+    pub fn new(name: impl Into<SharedString>) -> Self {
+        Self {
+            name: name.into(),
+            label: "".into(),
+            helper_text: None,
+            placeholder: "".into(),
+            input_type: InputType::default(),
+            default_value: "".into(),
+            rules: Vec::new(),
+            async_validator: None,
+            control: None,
+        }
+    }
+
+    /// Set the label text
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FormField::new("email").label("Email");
+    /// ```
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Set helper text
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FormField::new("password").helper_text("At least 8 characters");
+    /// ```
+    pub fn helper_text(mut self, text: impl Into<SharedString>) -> Self {
+        self.helper_text = Some(text.into());
+        self
+    }
+
+    /// Set the placeholder of the built-in input
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FormField::new("email").placeholder("you@example.com");
+    /// ```
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Set the type of the built-in input
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FormField::new("password").input_type(InputType::Password);
+    /// ```
+    pub fn input_type(mut self, input_type: InputType) -> Self {
+        self.input_type = input_type;
+        self
+    }
+
+    /// Set the initial value
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FormField::new("country").default_value("NZ");
+    /// ```
+    pub fn default_value(mut self, value: impl Into<SharedString>) -> Self {
+        self.default_value = value.into();
+        self
+    }
+
+    /// Add a validation rule
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FormField::new("email").rule(Rule::required()).rule(Rule::email());
+    /// ```
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Add several validation rules
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FormField::new("username").rules(vec![Rule::required(), Rule::min_length(3)]);
+    /// ```
+    pub fn rules(mut self, rules: Vec<Rule>) -> Self {
+        self.rules.extend(rules);
+        self
+    }
+
+    /// Set a validator run asynchronously once the rules pass
+    ///
+    /// Validation results for values that changed in the meantime are ignored.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FormField::new("username").async_validator(|name, cx| {
+    ///     let api = Api::global(cx);
+    ///     cx.background_spawn(async move {
+    ///         if api.username_taken(&name).await { Err("Username is taken".into()) } else { Ok(()) }
+    ///     })
+    /// });
+    /// ```
+    pub fn async_validator(
+        mut self,
+        validator: impl Fn(SharedString, &mut App) -> Task<Result<(), SharedString>> + 'static,
+    ) -> Self {
+        self.async_validator = Some(Rc::new(validator));
+        self
+    }
+
+    /// Replace the built-in input with a custom form control
+    ///
+    /// Wire the control's change handler to [`Form::set_value`](super::Form::set_value).
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FormField::new("country").control(move |_window, _cx| country.clone().into_any_element());
+    /// ```
+    pub fn control(mut self, control: impl Fn(&mut Window, &mut App) -> AnyElement + 'static) -> Self {
+        self.control = Some(Rc::new(control));
+        self
+    }
+
+    /// Whether the field has a [`Rule::required`] rule
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// assert!(FormField::new("email").rule(Rule::required()).is_required());
+    /// ```
+    pub fn is_required(&self) -> bool {
+        self.rules.iter().any(Rule::is_required)
+    }
+}
+
+/// Validation state of a form field
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FieldState {
+    /// Current value
+    pub value: SharedString,
+    /// Error from the rules or the async validator, shown once touched
+    pub error: Option<SharedString>,
+    /// Whether the user has edited the field or tried to submit
+    pub touched: bool,
+    /// Whether the async validator is running
+    pub validating: bool,
+}
+
+impl FieldState {
+    /// State for a new field holding `value`, checked against `rules`
+    pub(crate) fn new(value: SharedString, rules: &[Rule]) -> Self {
+        let error = validate(rules, &value).err();
+        Self { value, error, touched: false, validating: false }
+    }
+
+    /// Whether the value passed validation
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let ready = form.read(cx).field_state("email").is_some_and(FieldState::is_valid);
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        self.error.is_none() && !self.validating
+    }
+
+    /// Error to display, hidden until the field was touched
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let message = state.visible_error();
+    /// ```
+    pub fn visible_error(&self) -> Option<SharedString> {
+        self.error.clone().filter(|_| self.touched)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_builder() {
+        let field = FormField::new("email")
+            .label("Email")
+            .rule(Rule::required())
+            .rule(Rule::email());
+        assert_eq!(field.name.as_ref(), "email");
+        assert_eq!(field.rules.len(), 2);
+        assert!(field.is_required());
+        assert!(!FormField::new("bio").rule(Rule::max_length(200)).is_required());
+    }
+
+    #[test]
+    fn test_errors_hidden_until_touched() {
+        let mut state = FieldState::new("".into(), &[Rule::required()]);
+        assert!(!state.is_valid());
+        assert_eq!(state.visible_error(), None);
+
+        state.touched = true;
+        assert_eq!(state.visible_error().as_deref(), Some("This field is required"));
+    }
+
+    #[test]
+    fn test_validating_is_not_valid() {
+        let state = FieldState { validating: true, ..FieldState::new("taken".into(), &[]) };
+        assert!(!state.is_valid());
+    }
+}
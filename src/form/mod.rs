@@ -0,0 +1,35 @@
+//! Form validation - declarative rules, field state and a validating form container.
+//!
+//! Fields declare synchronous [`Rule`]s and optionally an async validator
+//! (e.g. checking username availability). A [`Form`] tracks each field's
+//! value and validity, renders the fields as [`FormGroup`](crate::molecules::FormGroup)s,
+//! keeps its submit button disabled until every field is valid, and
+//! announces errors to screen readers.
+//!
+//! ## Available Types
+//!
+//! - [`Rule`]: Required, length, regex, email and custom checks
+//! - [`FormField`]: Field configuration with rules and an optional async validator
+//! - [`FieldState`]: Value, error and touched state of a field
+//! - [`Form`]: Container aggregating field validity and handling submission
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use purdah_gpui_components::form::*;
+//!
+//! let form = cx.new(|_| {
+//!     Form::new()
+//!         .field(FormField::new("email").label("Email").rules(vec![Rule::required(), Rule::email()]))
+//!         .field(FormField::new("password").label("Password").rule(Rule::min_length(8)))
+//!         .on_submit(|values, _window, cx| sign_up(values, cx))
+//! });
+//! ```
+
+pub mod rules;
+pub mod field;
+pub mod container;
+
+pub use rules::{validate, Rule};
+pub use field::{AsyncValidator, FieldState, FormField};
+pub use container::{Form, FormValues};
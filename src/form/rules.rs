@@ -0,0 +1,247 @@
+//! Declarative validation rules for form fields.
+
+use std::rc::Rc;
+
+use gpui::*;
+use regex::Regex;
+
+use crate::atoms::InputType;
+
+/// What a [`Rule`] checks
+#[derive(Clone)]
+enum RuleKind {
+    Required,
+    MinLength(usize),
+    MaxLength(usize),
+    Pattern(Regex),
+    Email,
+    Custom(Rc<dyn Fn(&str) -> bool>),
+}
+
+/// A synchronous validation rule for a field value.
+///
+/// Rules other than [`Rule::required`] accept empty values, so optional
+/// fields only need to be well-formed when filled in. Each rule has a
+/// default error message that can be replaced with [`Rule::message`].
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::form::Rule;
+///
+/// let rules = vec![
+///     Rule::required(),
+///     Rule::min_length(3),
+///     Rule::max_length(20),
+///     Rule::pattern(&Regex::new("[a-z0-9_]+").unwrap())
+///         .message("Use lowercase letters, digits and underscores"),
+/// ];
+/// ```
+#[derive(Clone)]
+pub struct Rule {
+    kind: RuleKind,
+    message: Option<SharedString>,
+}
+
+impl Rule {
+    /// Create a rule of `kind` with its default message
+    fn new(kind: RuleKind) -> Self {
+        Self { kind, message: None }
+    }
+
+    /// Require a non-blank value
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Rule::required();
+    /// ```
+    pub fn required() -> Self {
+        Self::new(RuleKind::Required)
+    }
+
+    /// Require at least `min` characters
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Rule::min_length(8);
+    /// ```
+    pub fn min_length(min: usize) -> Self {
+        Self::new(RuleKind::MinLength(min))
+    }
+
+    /// Allow at most `max` characters
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Rule::max_length(280);
+    /// ```
+    pub fn max_length(max: usize) -> Self {
+        Self::new(RuleKind::MaxLength(max))
+    }
+
+    /// Require the whole value to match `pattern`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Rule::pattern(&Regex::new(r"\d{5}").unwrap()).message("Enter a 5-digit ZIP code");
+    /// ```
+    pub fn pattern(pattern: &Regex) -> Self {
+        let anchored = Regex::new(&format!("^(?:{})$", pattern.as_str()))
+            .expect("anchoring a valid pattern keeps it valid");
+        Self::new(RuleKind::Pattern(anchored))
+    }
+
+    /// Require a well-formed email address
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Rule::email();
+    /// ```
+    pub fn email() -> Self {
+        Self::new(RuleKind::Email)
+    }
+
+    /// Require `check` to accept the value, failing with `message`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Rule::custom(|value| value != "admin", "This username is reserved");
+    /// ```
+    pub fn custom(check: impl Fn(&str) -> bool + 'static, message: impl Into<SharedString>) -> Self {
+        Self::new(RuleKind::Custom(Rc::new(check))).message(message)
+    }
+
+    /// Replace the default error message
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Rule::required().message("Enter your email");
+    /// ```
+    pub fn message(mut self, message: impl Into<SharedString>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Whether this is a [`Rule::required`] rule
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let required = rules.iter().any(Rule::is_required);
+    /// ```
+    pub fn is_required(&self) -> bool {
+        matches!(self.kind, RuleKind::Required)
+    }
+
+    /// Check `value`, returning the error message when it fails
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// assert!(Rule::min_length(3).validate("ab").is_err());
+    /// ```
+    pub fn validate(&self, value: &str) -> Result<(), SharedString> {
+        if self.passes(value) {
+            Ok(())
+        } else {
+            Err(self.message.clone().unwrap_or_else(|| self.default_message()))
+        }
+    }
+
+    /// Whether `value` satisfies the rule
+    fn passes(&self, value: &str) -> bool {
+        if value.trim().is_empty() {
+            return !self.is_required();
+        }
+
+        match &self.kind {
+            RuleKind::Required => true,
+            RuleKind::MinLength(min) => value.chars().count() >= *min,
+            RuleKind::MaxLength(max) => value.chars().count() <= *max,
+            RuleKind::Pattern(pattern) => pattern.is_match(value),
+            RuleKind::Email => InputType::Email.is_valid(value),
+            RuleKind::Custom(check) => check(value),
+        }
+    }
+
+    /// Message shown when no custom message was set
+    fn default_message(&self) -> SharedString {
+        match &self.kind {
+            RuleKind::Required => "This field is required".into(),
+            RuleKind::MinLength(min) => format!("Must be at least {min} characters").into(),
+            RuleKind::MaxLength(max) => format!("Must be at most {max} characters").into(),
+            RuleKind::Pattern(_) | RuleKind::Custom(_) => "Invalid value".into(),
+            RuleKind::Email => "Enter a valid email address".into(),
+        }
+    }
+}
+
+/// Validate `value` against `rules`, returning the first failure
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// let error = validate(&[Rule::required(), Rule::email()], "me@").err();
+/// ```
+pub fn validate(rules: &[Rule], value: &str) -> Result<(), SharedString> {
+    rules.iter().try_for_each(|rule| rule.validate(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_required() {
+        assert!(Rule::required().validate("").is_err());
+        assert!(Rule::required().validate("   ").is_err());
+        assert!(Rule::required().validate("x").is_ok());
+    }
+
+    #[test]
+    fn test_optional_rules_accept_empty() {
+        assert!(Rule::min_length(3).validate("").is_ok());
+        assert!(Rule::email().validate("").is_ok());
+        assert!(Rule::custom(|_| false, "never").validate("").is_ok());
+    }
+
+    #[test]
+    fn test_lengths_count_chars() {
+        assert!(Rule::min_length(3).validate("ab").is_err());
+        assert!(Rule::min_length(3).validate("äöü").is_ok());
+        assert!(Rule::max_length(3).validate("abcd").is_err());
+    }
+
+    #[test]
+    fn test_pattern_matches_whole_value() {
+        let zip = Rule::pattern(&Regex::new(r"\d{5}").unwrap());
+        assert!(zip.validate("12345").is_ok());
+        assert!(zip.validate("123456").is_err());
+        assert!(zip.validate("a12345").is_err());
+
+        let either = Rule::pattern(&Regex::new("a|ab").unwrap());
+        assert!(either.validate("ab").is_ok());
+    }
+
+    #[test]
+    fn test_messages() {
+        assert_eq!(Rule::min_length(8).validate("short").unwrap_err().as_ref(), "Must be at least 8 characters");
+        assert_eq!(Rule::email().message("Bad email").validate("me@").unwrap_err().as_ref(), "Bad email");
+        assert_eq!(Rule::custom(|v| v != "admin", "Reserved").validate("admin").unwrap_err().as_ref(), "Reserved");
+    }
+
+    #[test]
+    fn test_validate_returns_first_failure() {
+        let rules = [Rule::required(), Rule::email()];
+        assert_eq!(validate(&rules, "").unwrap_err().as_ref(), "This field is required");
+        assert_eq!(validate(&rules, "me@").unwrap_err().as_ref(), "Enter a valid email address");
+        assert!(validate(&rules, "me@example.com").is_ok());
+    }
+}
@@ -1,7 +1,7 @@
 //! Divider component for visual separation.
 
 use gpui::*;
-use crate::theme::Theme;
+use crate::theme::use_theme;
 
 /// Divider orientation
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -15,7 +15,8 @@ pub enum DividerOrientation {
 
 /// A divider component for visual separation
 ///
-/// Divider creates a line to separate content sections.
+/// Divider creates a line to separate content sections. A labelled
+/// divider splits the line around centered text, as in "— OR —".
 ///
 /// ## Example
 ///
@@ -28,9 +29,14 @@ pub enum DividerOrientation {
 /// // Vertical divider
 /// Divider::new()
 ///     .orientation(DividerOrientation::Vertical);
+///
+/// // Divider between sign-in options
+/// Divider::new()
+///     .label("OR");
 /// ```
 pub struct Divider {
     orientation: DividerOrientation,
+    label: Option<SharedString>,
 }
 
 impl Divider {
@@ -44,6 +50,7 @@ impl Divider {
     pub fn new() -> Self {
         Self {
             orientation: DividerOrientation::default(),
+            label: None,
         }
     }
 
@@ -58,13 +65,56 @@ impl Divider {
         self.orientation = orientation;
         self
     }
+
+    /// Set text shown in the middle of the line
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Divider::new().label("OR");
+    /// ```
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+impl Default for Divider {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Render for Divider {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
-        let theme = Theme::default();
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
         let color = theme.alias.color_border;
 
+        if let Some(label) = self.label.clone() {
+            // Two lines that share the space around the label
+            let orientation = self.orientation;
+            let line = || match orientation {
+                DividerOrientation::Horizontal => div().flex_1().h(px(1.0)).bg(color),
+                DividerOrientation::Vertical => div().flex_1().w(px(1.0)).bg(color),
+            };
+            let container = match orientation {
+                DividerOrientation::Horizontal => div().w_full().flex().flex_row(),
+                DividerOrientation::Vertical => div().h_full().flex().flex_col(),
+            };
+            return container
+                .items_center()
+                .gap(theme.global.spacing_sm)
+                .child(line())
+                .child(
+                    div()
+                        .flex_none()
+                        .text_size(theme.global.font_size_xs)
+                        .text_color(theme.alias.color_text_muted)
+                        .child(label),
+                )
+                .child(line());
+        }
+
         match self.orientation {
             DividerOrientation::Horizontal => {
                 div()
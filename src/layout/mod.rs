@@ -6,9 +6,13 @@
 //!
 //! - [`VStack`]: Vertical stack layout
 //! - [`HStack`]: Horizontal stack layout
+//! - [`Wrap`]: Row layout that flows children onto new lines
 //! - [`Spacer`]: Flexible spacing component
 //! - [`Container`]: Max-width container with centering
-//! - [`Divider`]: Horizontal or vertical divider line
+//! - [`Divider`]: Horizontal or vertical divider line, optionally labelled
+//! - [`VirtualList`]: Scrolling list that only renders visible items
+//! - [`ScrollArea`]: Scrolling container with themed, auto-hiding scrollbars
+//! - [`SplitPane`]: Two resizable panes separated by a draggable divider
 //!
 //! ## Example
 //!
@@ -34,11 +38,19 @@
 //! ```
 
 pub mod stack;
+pub mod wrap;
 pub mod spacer;
 pub mod container;
 pub mod divider;
+pub mod virtual_list;
+pub mod scroll_area;
+pub mod split_pane;
 
 pub use stack::{HStack, VStack, Alignment, Justify};
+pub use wrap::Wrap;
 pub use spacer::Spacer;
 pub use container::Container;
 pub use divider::{Divider, DividerOrientation};
+pub use virtual_list::{VirtualList, VirtualListState};
+pub use scroll_area::{ScrollArea, ScrollAreaState, ScrollAxis};
+pub use split_pane::{SplitOrientation, SplitPane, SplitPaneProps, SplitPaneSlot, SplitSide};
@@ -0,0 +1,749 @@
+//! Scroll area with themed scrollbars.
+
+use std::rc::Rc;
+use std::time::Duration;
+
+use gpui::*;
+use gpui::prelude::FluentBuilder;
+use crate::theme::{use_theme, ScrollbarTokens};
+
+/// How long scrollbars stay visible after scrolling stops
+const HIDE_DELAY: Duration = Duration::from_millis(1000);
+
+/// Distance from the end at which `on_reach_end` fires by default
+const DEFAULT_END_THRESHOLD: f32 = 100.0;
+
+/// Directions a [`ScrollArea`] scrolls in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScrollAxis {
+    /// Scroll up and down
+    #[default]
+    Vertical,
+    /// Scroll left and right
+    Horizontal,
+    /// Scroll in both directions
+    Both,
+}
+
+impl ScrollAxis {
+    /// Whether content scrolls along `axis`
+    fn scrolls(self, axis: Axis) -> bool {
+        match self {
+            ScrollAxis::Vertical => axis == Axis::Vertical,
+            ScrollAxis::Horizontal => axis == Axis::Horizontal,
+            ScrollAxis::Both => true,
+        }
+    }
+
+    /// Axis that `on_reach_end` watches
+    fn main(self) -> Axis {
+        match self {
+            ScrollAxis::Horizontal => Axis::Horizontal,
+            ScrollAxis::Vertical | ScrollAxis::Both => Axis::Vertical,
+        }
+    }
+}
+
+/// Scroll position of a [`ScrollArea`].
+///
+/// Store it on the view that renders the area to scroll it from code.
+/// Cloning shares the same underlying scroll position.
+#[derive(Clone, Default)]
+pub struct ScrollAreaState {
+    handle: ScrollHandle,
+}
+
+impl ScrollAreaState {
+    /// Create a new scroll state positioned at the start
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// struct MyView {
+    ///     scroll: ScrollAreaState,
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Distance scrolled from the top left of the content
+    pub fn offset(&self) -> Point<Pixels> {
+        let offset = self.handle.offset();
+        point(-offset.x, -offset.y)
+    }
+
+    /// Largest distance the content can scroll, zero before the first layout
+    pub fn max_offset(&self) -> Size<Pixels> {
+        self.handle.max_offset()
+    }
+
+    /// Size of the visible area from the last layout, zero before the first
+    pub fn viewport_size(&self) -> Size<Pixels> {
+        self.handle.bounds().size
+    }
+
+    /// Scroll to `offset` from the top left of the content
+    ///
+    /// Offsets past the end are clamped on the next layout.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// self.scroll.scroll_to(point(px(0.0), saved_position));
+    /// ```
+    pub fn scroll_to(&self, offset: Point<Pixels>) {
+        let x = if offset.x < px(0.0) { px(0.0) } else { offset.x };
+        let y = if offset.y < px(0.0) { px(0.0) } else { offset.y };
+        self.handle.set_offset(point(-x, -y));
+    }
+
+    /// Scroll by `delta`, positive values moving towards the end
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// self.scroll.scroll_by(point(px(0.0), px(120.0)));
+    /// ```
+    pub fn scroll_by(&self, delta: Point<Pixels>) {
+        self.scroll_to(self.offset() + delta);
+    }
+
+    /// Scroll back to the top left of the content
+    pub fn scroll_to_top(&self) {
+        self.handle.set_offset(point(px(0.0), px(0.0)));
+    }
+
+    /// Scroll to the bottom of the content as of the last layout
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// // Keep a log pinned to its newest line
+    /// self.scroll.scroll_to_bottom();
+    /// ```
+    pub fn scroll_to_bottom(&self) {
+        let offset = self.handle.offset();
+        self.handle.set_offset(point(offset.x, -self.max_offset().height));
+    }
+
+    /// Scroll the child at `index` into view
+    ///
+    /// `index` counts the direct children of the [`ScrollArea`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// self.scroll.scroll_to_child(self.messages.len() - 1);
+    /// ```
+    pub fn scroll_to_child(&self, index: usize) {
+        self.handle.scroll_to_item(index);
+    }
+
+    /// Scroll vertically by the minimum amount needed to show `height` of content starting at `top`
+    ///
+    /// Before the first layout the viewport size is unknown, so `top` is
+    /// scrolled to the top.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// self.scroll.scroll_into_view(section_top, section_height);
+    /// ```
+    pub fn scroll_into_view(&self, top: Pixels, height: Pixels) {
+        let offset = self.offset();
+        if let Some(target) = into_view_offset(
+            f32::from(top),
+            f32::from(top + height),
+            f32::from(offset.y),
+            f32::from(self.viewport_size().height),
+        ) {
+            self.handle.set_offset(point(-offset.x, px(-target)));
+        }
+    }
+}
+
+/// Scroll offset that brings `start..end` into a viewport, if it is not already visible
+fn into_view_offset(start: f32, end: f32, scroll: f32, viewport: f32) -> Option<f32> {
+    if start < scroll || viewport <= 0.0 {
+        Some(start)
+    } else if end > scroll + viewport {
+        Some(end - viewport)
+    } else {
+        None
+    }
+}
+
+/// Start and length of the thumb along a track, `None` when the content fits
+fn thumb_bounds(viewport: f32, content: f32, scroll: f32, track: f32, min_thumb: f32) -> Option<(f32, f32)> {
+    if content <= viewport || viewport <= 0.0 || track <= 0.0 {
+        return None;
+    }
+    let length = (track * viewport / content).max(min_thumb).min(track);
+    let progress = (scroll / (content - viewport)).clamp(0.0, 1.0);
+    Some(((track - length) * progress, length))
+}
+
+/// Scroll offset after dragging the thumb `delta` from where it was at `start_scroll`
+fn thumb_drag_offset(delta: f32, start_scroll: f32, viewport: f32, content: f32, track: f32, min_thumb: f32) -> f32 {
+    let Some((_, length)) = thumb_bounds(viewport, content, start_scroll, track, min_thumb) else {
+        return 0.0;
+    };
+    let max = content - viewport;
+    if track <= length {
+        return start_scroll.clamp(0.0, max);
+    }
+    (start_scroll + delta * max / (track - length)).clamp(0.0, max)
+}
+
+/// A thumb being dragged
+#[derive(Debug, Clone, Copy)]
+struct ThumbDrag {
+    axis: Axis,
+    /// Pointer position along the axis when the drag started
+    start_position: Pixels,
+    /// Scroll offset along the axis when the drag started
+    start_scroll: Pixels,
+}
+
+/// Hover, visibility and drag state kept across renders of a scroll area
+struct ScrollbarState {
+    /// Scroll position used when the area is not given a state
+    own: ScrollAreaState,
+    /// Whether the pointer is over the area
+    hovered: bool,
+    /// Scrollbar under the pointer, drawn thicker
+    hovered_bar: Option<Axis>,
+    /// Whether the area scrolled within the hide delay
+    active: bool,
+    /// Offset and scroll range seen at the last paint
+    last_offset: Point<Pixels>,
+    last_max: Size<Pixels>,
+    /// Whether the end was within the threshold at the last check
+    near_end: bool,
+    drag: Option<ThumbDrag>,
+    /// Pending hide after scrolling stops; dropping it cancels the hide
+    hide_task: Option<Task<()>>,
+}
+
+impl ScrollbarState {
+    fn new() -> Self {
+        Self {
+            own: ScrollAreaState::new(),
+            hovered: false,
+            hovered_bar: None,
+            active: false,
+            last_offset: Point::default(),
+            last_max: Size::default(),
+            near_end: false,
+            drag: None,
+            hide_task: None,
+        }
+    }
+
+    /// Whether scrollbars are drawn
+    fn is_visible(&self, auto_hide: bool) -> bool {
+        !auto_hide || self.hovered || self.active || self.drag.is_some()
+    }
+
+    fn set_hovered(&mut self, hovered: bool, cx: &mut Context<'_, Self>) {
+        if self.hovered != hovered {
+            self.hovered = hovered;
+            cx.notify();
+        }
+    }
+
+    fn set_hovered_bar(&mut self, axis: Axis, hovered: bool, cx: &mut Context<'_, Self>) {
+        let hovered_bar = if hovered {
+            Some(axis)
+        } else {
+            self.hovered_bar.filter(|bar| *bar != axis)
+        };
+        if self.hovered_bar != hovered_bar {
+            self.hovered_bar = hovered_bar;
+            cx.notify();
+        }
+    }
+
+    /// Record a new offset or scroll range, returning whether the end was just reached
+    ///
+    /// Scrolling shows the scrollbars until the hide delay passes. A change in
+    /// the scroll range, such as more items loading, re-arms `on_reach_end`.
+    fn scrolled(
+        &mut self,
+        offset: Point<Pixels>,
+        max: Size<Pixels>,
+        main: Axis,
+        threshold: Pixels,
+        cx: &mut Context<'_, Self>,
+    ) -> bool {
+        if offset != self.last_offset {
+            self.active = true;
+            self.hide_task = Some(cx.spawn(async move |this, cx| {
+                cx.background_executor().timer(HIDE_DELAY).await;
+                this.update(cx, |this, cx| {
+                    this.active = false;
+                    cx.notify();
+                })
+                .ok();
+            }));
+        }
+        if max != self.last_max {
+            self.near_end = false;
+        }
+        self.last_offset = offset;
+        self.last_max = max;
+        cx.notify();
+
+        let near_end = max.along(main) - offset.along(main) <= threshold;
+        let reached = near_end && !self.near_end;
+        self.near_end = near_end;
+        reached
+    }
+}
+
+/// Scrollable container with themed scrollbars.
+///
+/// ScrollArea clips its children and draws its own scrollbars over them.
+/// It fills its parent unless given a maximum height.
+///
+/// ## Features
+///
+/// - Vertical, horizontal or two-way scrolling
+/// - Scrollbars that hide when idle and thicken on hover
+/// - Draggable thumbs; clicking the track pages towards the pointer
+/// - Programmatic scrolling through a shared [`ScrollAreaState`]
+/// - `on_scroll` and `on_reach_end` callbacks for infinite loading
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::layout::*;
+///
+/// let load_older = cx.listener(|this, _: &(), _window, cx| this.load_older(cx));
+/// ScrollArea::new("messages")
+///     .state(&self.scroll)
+///     .max_height(px(480.0))
+///     .on_reach_end(move |window, cx| load_older(&(), window, cx))
+///     .children(self.messages.iter().map(|message| message_row(message)));
+///
+/// // Jump to the newest message
+/// self.scroll.scroll_to_bottom();
+/// ```
+///
+/// ## Accessibility
+///
+/// - Scrolling with the wheel, trackpad and keyboard works as for any scrolling element
+/// - Scrollbars are always shown with `auto_hide(false)`
+#[derive(IntoElement)]
+pub struct ScrollArea {
+    id: ElementId,
+    state: Option<ScrollAreaState>,
+    axis: ScrollAxis,
+    max_height: Option<Pixels>,
+    auto_hide: bool,
+    end_threshold: Pixels,
+    on_scroll: Option<Rc<dyn Fn(Point<Pixels>, &mut Window, &mut App)>>,
+    on_reach_end: Option<Rc<dyn Fn(&mut Window, &mut App)>>,
+    children: Vec<AnyElement>,
+}
+
+impl ScrollArea {
+    /// Create a new vertical scroll area, identified by `id` to keep its scrollbar state
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let area = ScrollArea::new("settings-body");
+    /// ```
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            state: None,
+            axis: ScrollAxis::default(),
+            max_height: None,
+            auto_hide: true,
+            end_threshold: px(DEFAULT_END_THRESHOLD),
+            on_scroll: None,
+            on_reach_end: None,
+            children: Vec::new(),
+        }
+    }
+
+    /// Share scroll position with `state`, for scrolling from code
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ScrollArea::new("log").state(&self.log_scroll);
+    /// ```
+    pub fn state(mut self, state: &ScrollAreaState) -> Self {
+        self.state = Some(state.clone());
+        self
+    }
+
+    /// Set the directions content scrolls in
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ScrollArea::new("canvas").axis(ScrollAxis::Both);
+    /// ```
+    pub fn axis(mut self, axis: ScrollAxis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Size to the content up to `max_height` instead of filling the parent
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ScrollArea::new("results").max_height(px(300.0));
+    /// ```
+    pub fn max_height(mut self, max_height: Pixels) -> Self {
+        self.max_height = Some(max_height);
+        self
+    }
+
+    /// Set whether scrollbars hide while the area is idle and not hovered
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ScrollArea::new("code").auto_hide(false);
+    /// ```
+    pub fn auto_hide(mut self, auto_hide: bool) -> Self {
+        self.auto_hide = auto_hide;
+        self
+    }
+
+    /// Set how close to the end `on_reach_end` fires
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ScrollArea::new("feed").end_threshold(px(400.0));
+    /// ```
+    pub fn end_threshold(mut self, threshold: Pixels) -> Self {
+        self.end_threshold = threshold;
+        self
+    }
+
+    /// Set the handler called with the new offset after the content scrolls
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ScrollArea::new("doc").on_scroll(|offset, _window, cx| save_position(offset.y, cx));
+    /// ```
+    pub fn on_scroll(mut self, handler: impl Fn(Point<Pixels>, &mut Window, &mut App) + 'static) -> Self {
+        self.on_scroll = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the handler called when scrolling comes within the end threshold
+    ///
+    /// Fires once per approach; it fires again after the content grows or
+    /// the area scrolls away from the end. Content that does not fill the
+    /// area counts as reaching the end, so loading repeats until it scrolls.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ScrollArea::new("feed").on_reach_end(|_window, cx| load_next_page(cx));
+    /// ```
+    pub fn on_reach_end(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_reach_end = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl ParentElement for ScrollArea {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+/// Scrollbar along `axis`, or `None` when the content fits
+fn scrollbar(
+    axis: Axis,
+    scroll: &ScrollAreaState,
+    bar: &Entity<ScrollbarState>,
+    tokens: &ScrollbarTokens,
+    both: bool,
+    hovered: bool,
+    dragging: bool,
+) -> Option<Stateful<Div>> {
+    let viewport = f32::from(scroll.viewport_size().along(axis));
+    let content = viewport + f32::from(scroll.max_offset().along(axis));
+    let offset = scroll.offset().along(axis);
+    // With two bars, each stops short of the corner the other occupies
+    let end_inset = if both { tokens.inset + tokens.thickness_hover } else { tokens.inset };
+    let track = viewport - f32::from(tokens.inset + end_inset);
+    let min_thumb = f32::from(tokens.min_thumb_length);
+    let (start, length) = thumb_bounds(viewport, content, f32::from(offset), track, min_thumb)?;
+
+    let thick = hovered || dragging;
+    let thickness = if thick { tokens.thickness_hover } else { tokens.thickness };
+    let thumb_color = if thick { tokens.thumb_color_hover } else { tokens.thumb_color };
+    let id = match axis {
+        Axis::Vertical => "scroll-area-vertical-bar",
+        Axis::Horizontal => "scroll-area-horizontal-bar",
+    };
+
+    let mut thumb = div()
+        .id("scroll-area-thumb")
+        .absolute()
+        .rounded_full()
+        .bg(thumb_color)
+        .on_mouse_down(MouseButton::Left, {
+            let bar = bar.clone();
+            move |event, _window, cx| {
+                cx.stop_propagation();
+                bar.update(cx, |bar, cx| {
+                    bar.drag = Some(ThumbDrag {
+                        axis,
+                        start_position: event.position.along(axis),
+                        start_scroll: offset,
+                    });
+                    cx.notify();
+                });
+            }
+        });
+    thumb = match axis {
+        Axis::Vertical => thumb.left(px(0.0)).w_full().top(px(start)).h(px(length)),
+        Axis::Horizontal => thumb.top(px(0.0)).h_full().left(px(start)).w(px(length)),
+    };
+
+    let mut track_div = div()
+        .id(id)
+        .absolute()
+        .rounded_full()
+        .when(thick, |track| track.bg(tokens.track_color_hover))
+        .on_hover({
+            let bar = bar.clone();
+            move |hovered, _window, cx| {
+                bar.update(cx, |bar, cx| bar.set_hovered_bar(axis, *hovered, cx));
+            }
+        })
+        .on_mouse_down(MouseButton::Left, {
+            // Clicking the track pages towards the pointer
+            let scroll = scroll.clone();
+            let track_start = scroll.handle.bounds().origin.along(axis) + tokens.inset;
+            move |event, window, cx| {
+                cx.stop_propagation();
+                let page = scroll.viewport_size().along(axis);
+                let before = event.position.along(axis) < track_start + px(start);
+                let step = if before { -page } else { page };
+                match axis {
+                    Axis::Vertical => scroll.scroll_by(point(px(0.0), step)),
+                    Axis::Horizontal => scroll.scroll_by(point(step, px(0.0))),
+                }
+                window.refresh();
+            }
+        })
+        .child(thumb);
+    track_div = match axis {
+        Axis::Vertical => track_div
+            .top(tokens.inset)
+            .bottom(end_inset)
+            .right(tokens.inset)
+            .w(thickness),
+        Axis::Horizontal => track_div
+            .left(tokens.inset)
+            .right(end_inset)
+            .bottom(tokens.inset)
+            .h(thickness),
+    };
+    Some(track_div)
+}
+
+impl RenderOnce for ScrollArea {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = use_theme(cx);
+        let tokens = ScrollbarTokens::from_theme(&theme);
+        let bar = window.use_keyed_state(self.id.clone(), cx, |_window, _cx| ScrollbarState::new());
+        let scroll = self.state.clone().unwrap_or_else(|| bar.read(cx).own.clone());
+
+        let (visible, hovered_bar, drag) = {
+            let state = bar.read(cx);
+            (state.is_visible(self.auto_hide), state.hovered_bar, state.drag)
+        };
+
+        let mut viewport = div()
+            .id("scroll-area-viewport")
+            .track_scroll(&scroll.handle)
+            .children(self.children);
+        viewport = match self.axis {
+            ScrollAxis::Vertical => viewport.overflow_y_scroll(),
+            ScrollAxis::Horizontal => viewport.overflow_x_scroll(),
+            ScrollAxis::Both => viewport.overflow_scroll(),
+        };
+
+        let mut area = div()
+            .id(self.id)
+            .relative()
+            .flex()
+            .flex_col()
+            .overflow_hidden()
+            .on_hover({
+                let bar = bar.clone();
+                move |hovered, _window, cx| {
+                    bar.update(cx, |bar, cx| bar.set_hovered(*hovered, cx));
+                }
+            });
+        match self.max_height {
+            Some(max_height) => {
+                area = area.max_h(max_height);
+                viewport = viewport.max_h(max_height);
+            }
+            None => {
+                area = area.size_full();
+                viewport = viewport.size_full();
+            }
+        }
+
+        // After the viewport lays out, report scrolling and follow thumb drags
+        let watch = canvas(
+            {
+                let bar = bar.clone();
+                let scroll = scroll.clone();
+                let main = self.axis.main();
+                let threshold = self.end_threshold;
+                let on_scroll = self.on_scroll.clone();
+                let on_reach_end = self.on_reach_end.clone();
+                move |_bounds, window, cx| {
+                    let offset = scroll.offset();
+                    let max = scroll.max_offset();
+                    let last_offset = {
+                        let state = bar.read(cx);
+                        if state.last_offset == offset && state.last_max == max {
+                            return;
+                        }
+                        state.last_offset
+                    };
+                    window.defer(cx, move |window, cx| {
+                        let reached = bar.update(cx, |bar, cx| bar.scrolled(offset, max, main, threshold, cx));
+                        if let Some(handler) = on_scroll.filter(|_| offset != last_offset) {
+                            handler(offset, window, cx);
+                        }
+                        if let Some(handler) = on_reach_end.filter(|_| reached) {
+                            handler(window, cx);
+                        }
+                    });
+                }
+            },
+            {
+                let bar = bar.clone();
+                let scroll = scroll.clone();
+                let min_thumb = f32::from(tokens.min_thumb_length);
+                let insets = f32::from(tokens.inset * 2.0);
+                move |_bounds, (), window, _cx| {
+                    let Some(drag) = drag else {
+                        return;
+                    };
+                    let bar_on_up = bar.clone();
+                    window.on_mouse_event(move |event: &MouseMoveEvent, phase, window, cx| {
+                        if !phase.bubble() {
+                            return;
+                        }
+                        if event.pressed_button != Some(MouseButton::Left) {
+                            bar.update(cx, |bar, cx| {
+                                bar.drag = None;
+                                cx.notify();
+                            });
+                            return;
+                        }
+                        let viewport = f32::from(scroll.viewport_size().along(drag.axis));
+                        let content = viewport + f32::from(scroll.max_offset().along(drag.axis));
+                        let target = thumb_drag_offset(
+                            f32::from(event.position.along(drag.axis) - drag.start_position),
+                            f32::from(drag.start_scroll),
+                            viewport,
+                            content,
+                            viewport - insets,
+                            min_thumb,
+                        );
+                        let offset = scroll.offset();
+                        match drag.axis {
+                            Axis::Vertical => scroll.scroll_to(point(offset.x, px(target))),
+                            Axis::Horizontal => scroll.scroll_to(point(px(target), offset.y)),
+                        }
+                        window.refresh();
+                    });
+                    window.on_mouse_event(move |_event: &MouseUpEvent, phase, _window, cx| {
+                        if phase.bubble() {
+                            bar_on_up.update(cx, |bar, cx| {
+                                bar.drag = None;
+                                cx.notify();
+                            });
+                        }
+                    });
+                }
+            },
+        )
+        .absolute()
+        .top(px(0.0))
+        .left(px(0.0))
+        .size_full();
+
+        area = area.child(viewport).child(watch);
+        if visible {
+            let both = self.axis == ScrollAxis::Both;
+            for axis in [Axis::Vertical, Axis::Horizontal] {
+                if !self.axis.scrolls(axis) {
+                    continue;
+                }
+                let hovered = hovered_bar == Some(axis);
+                let dragging = drag.is_some_and(|drag| drag.axis == axis);
+                area = area.children(scrollbar(axis, &scroll, &bar, &tokens, both, hovered, dragging));
+            }
+        }
+        area
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-4, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn test_thumb_hidden_when_content_fits() {
+        assert!(thumb_bounds(200.0, 200.0, 0.0, 196.0, 24.0).is_none());
+        assert!(thumb_bounds(200.0, 150.0, 0.0, 196.0, 24.0).is_none());
+    }
+
+    #[test]
+    fn test_thumb_size_and_position() {
+        // Half the content visible: half-length thumb, moving across the rest
+        let (start, length) = thumb_bounds(100.0, 200.0, 0.0, 100.0, 10.0).unwrap();
+        assert_close(start, 0.0);
+        assert_close(length, 50.0);
+        let (start, _) = thumb_bounds(100.0, 200.0, 100.0, 100.0, 10.0).unwrap();
+        assert_close(start, 50.0);
+        // Long content keeps the minimum thumb length
+        let (_, length) = thumb_bounds(100.0, 100_000.0, 0.0, 100.0, 24.0).unwrap();
+        assert_close(length, 24.0);
+    }
+
+    #[test]
+    fn test_thumb_drag_scales_and_clamps() {
+        // The thumb moves 50px for 100px of scrolling
+        assert_close(thumb_drag_offset(25.0, 0.0, 100.0, 200.0, 100.0, 10.0), 50.0);
+        assert_close(thumb_drag_offset(-25.0, 20.0, 100.0, 200.0, 100.0, 10.0), 0.0);
+        assert_close(thumb_drag_offset(500.0, 0.0, 100.0, 200.0, 100.0, 10.0), 100.0);
+    }
+
+    #[test]
+    fn test_into_view_offset() {
+        assert_eq!(into_view_offset(50.0, 80.0, 0.0, 100.0), None);
+        assert_eq!(into_view_offset(150.0, 180.0, 0.0, 100.0), Some(80.0));
+        assert_eq!(into_view_offset(10.0, 40.0, 30.0, 100.0), Some(10.0));
+        assert_eq!(into_view_offset(150.0, 180.0, 0.0, 0.0), Some(150.0));
+    }
+}
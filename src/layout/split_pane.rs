@@ -0,0 +1,576 @@
+//! Split pane layout with a draggable divider.
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use gpui::*;
+use crate::theme::use_theme;
+
+/// Content of one side of a [`SplitPane`]
+pub type SplitPaneSlot = Rc<dyn Fn(&mut Window, &mut App) -> AnyElement>;
+
+/// Divider movement of one arrow key press
+const KEYBOARD_STEP: f32 = 16.0;
+
+/// Direction panes are laid out in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SplitOrientation {
+    /// Panes side by side with a vertical divider
+    #[default]
+    Horizontal,
+    /// Panes stacked with a horizontal divider
+    Vertical,
+}
+
+/// One of the two panes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitSide {
+    /// The left or top pane
+    First,
+    /// The right or bottom pane
+    Second,
+}
+
+/// Drag payload for moving the divider of the pane with this entity id
+#[derive(Clone)]
+struct SplitDrag {
+    pane: EntityId,
+}
+
+impl Render for SplitDrag {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
+        Empty
+    }
+}
+
+/// Size limits used to place the divider
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct SplitLimits {
+    pub(crate) min_first: f32,
+    pub(crate) min_second: f32,
+    pub(crate) max_first: Option<f32>,
+    pub(crate) collapsible: bool,
+}
+
+/// Ratio and collapsed side for the divider dragged to `offset` of `total`
+///
+/// Dragging a collapsible pane below half its minimum size collapses it.
+pub(crate) fn resolve_split(offset: f32, total: f32, limits: SplitLimits) -> (f32, Option<SplitSide>) {
+    if total <= 0.0 {
+        return (0.5, None);
+    }
+    if limits.collapsible && offset < limits.min_first / 2.0 {
+        return (0.0, Some(SplitSide::First));
+    }
+    if limits.collapsible && total - offset < limits.min_second / 2.0 {
+        return (1.0, Some(SplitSide::Second));
+    }
+
+    let upper = limits.max_first.map_or(total, |max| max.min(total)).min(total - limits.min_second);
+    let offset = offset.min(upper).max(limits.min_first.min(total));
+    ((offset / total).clamp(0.0, 1.0), None)
+}
+
+/// SplitPane configuration properties
+#[derive(Clone)]
+pub struct SplitPaneProps {
+    /// Direction the panes are laid out in
+    pub orientation: SplitOrientation,
+    /// Share of the space given to the first pane, 0.0-1.0
+    pub ratio: f32,
+    /// Smallest size of the first pane
+    pub min_first: Pixels,
+    /// Smallest size of the second pane
+    pub min_second: Pixels,
+    /// Largest size of the first pane
+    pub max_first: Option<Pixels>,
+    /// Whether dragging a pane past its minimum collapses it to the edge
+    pub collapsible: bool,
+    /// Content of the left or top pane
+    pub first: Option<SplitPaneSlot>,
+    /// Content of the right or bottom pane
+    pub second: Option<SplitPaneSlot>,
+    /// Handler called with the new ratio when the divider moves
+    ///
+    /// A collapsed first pane reports 0.0 and a collapsed second pane 1.0.
+    pub on_resize: Option<Rc<dyn Fn(f32, &mut Window, &mut App)>>,
+}
+
+impl Default for SplitPaneProps {
+    fn default() -> Self {
+        Self {
+            orientation: SplitOrientation::default(),
+            ratio: 0.5,
+            min_first: px(80.0),
+            min_second: px(80.0),
+            max_first: None,
+            collapsible: false,
+            first: None,
+            second: None,
+            on_resize: None,
+        }
+    }
+}
+
+/// Two panes separated by a draggable divider.
+///
+/// Panes keep their ratio as the split resizes; dragging the divider
+/// respects the minimum and maximum sizes. Splits nest by rendering
+/// another SplitPane entity in a pane.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::layout::*;
+///
+/// let editor = cx.new(|_| {
+///     SplitPane::new()
+///         .orientation(SplitOrientation::Vertical)
+///         .ratio(0.7)
+///         .first(|_window, _cx| code_view().into_any_element())
+///         .second(|_window, _cx| terminal().into_any_element())
+/// });
+///
+/// SplitPane::new()
+///     .ratio(settings.sidebar_ratio)
+///     .min_first(px(160.0))
+///     .max_first(px(480.0))
+///     .collapsible(true)
+///     .first(|_window, _cx| sidebar().into_any_element())
+///     .second(move |_window, _cx| editor.clone().into_any_element())
+///     .on_resize(|ratio, _window, cx| save_sidebar_ratio(ratio, cx));
+/// ```
+///
+/// ## Accessibility
+///
+/// - The divider is focusable; arrow keys move it, Enter collapses or restores the first pane
+/// - Double-clicking the divider does the same as Enter
+pub struct SplitPane {
+    props: SplitPaneProps,
+    /// Collapsed pane, if any
+    collapsed: Option<SplitSide>,
+    /// Ratio to restore when a collapsed pane expands
+    restore_ratio: f32,
+    /// Container bounds from the last paint
+    bounds: Rc<Cell<Bounds<Pixels>>>,
+    divider_focus: Option<FocusHandle>,
+}
+
+impl SplitPane {
+    /// Create a new split with two equal panes
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let split = SplitPane::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            props: SplitPaneProps::default(),
+            collapsed: None,
+            restore_ratio: 0.5,
+            bounds: Rc::default(),
+            divider_focus: None,
+        }
+    }
+
+    /// Set the direction the panes are laid out in
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// SplitPane::new().orientation(SplitOrientation::Vertical);
+    /// ```
+    pub fn orientation(mut self, orientation: SplitOrientation) -> Self {
+        self.props.orientation = orientation;
+        self
+    }
+
+    /// Set the share of the space given to the first pane
+    ///
+    /// A ratio of 0.0 or 1.0 starts a collapsible split with that pane collapsed.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// SplitPane::new().ratio(0.25);
+    /// ```
+    pub fn ratio(mut self, ratio: f32) -> Self {
+        self.props.ratio = ratio.clamp(0.0, 1.0);
+        self.restore_ratio = self.props.ratio;
+        self.collapsed = None;
+        self
+    }
+
+    /// Set the smallest size of the first pane
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// SplitPane::new().min_first(px(160.0));
+    /// ```
+    pub fn min_first(mut self, min: Pixels) -> Self {
+        self.props.min_first = min;
+        self
+    }
+
+    /// Set the smallest size of the second pane
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// SplitPane::new().min_second(px(240.0));
+    /// ```
+    pub fn min_second(mut self, min: Pixels) -> Self {
+        self.props.min_second = min;
+        self
+    }
+
+    /// Set the largest size of the first pane
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// SplitPane::new().max_first(px(480.0));
+    /// ```
+    pub fn max_first(mut self, max: Pixels) -> Self {
+        self.props.max_first = Some(max);
+        self
+    }
+
+    /// Set whether dragging a pane past its minimum collapses it to the edge
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// SplitPane::new().collapsible(true);
+    /// ```
+    pub fn collapsible(mut self, collapsible: bool) -> Self {
+        self.props.collapsible = collapsible;
+        self
+    }
+
+    /// Set the content of the left or top pane
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// SplitPane::new().first(|_window, _cx| sidebar().into_any_element());
+    /// ```
+    pub fn first(mut self, first: impl Fn(&mut Window, &mut App) -> AnyElement + 'static) -> Self {
+        self.props.first = Some(Rc::new(first));
+        self
+    }
+
+    /// Set the content of the right or bottom pane
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// SplitPane::new().second(move |_window, _cx| nested.clone().into_any_element());
+    /// ```
+    pub fn second(mut self, second: impl Fn(&mut Window, &mut App) -> AnyElement + 'static) -> Self {
+        self.props.second = Some(Rc::new(second));
+        self
+    }
+
+    /// Set the handler called with the new ratio when the divider moves
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// SplitPane::new().on_resize(|ratio, _window, cx| save_sidebar_ratio(ratio, cx));
+    /// ```
+    pub fn on_resize(mut self, handler: impl Fn(f32, &mut Window, &mut App) + 'static) -> Self {
+        self.props.on_resize = Some(Rc::new(handler));
+        self
+    }
+
+    /// Current share of the first pane, 0.0 or 1.0 while a pane is collapsed
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let ratio = split.read(cx).current_ratio();
+    /// ```
+    pub fn current_ratio(&self) -> f32 {
+        match self.collapsed {
+            Some(SplitSide::First) => 0.0,
+            Some(SplitSide::Second) => 1.0,
+            None => self.props.ratio,
+        }
+    }
+
+    /// Collapsed pane, if any
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let sidebar_hidden = split.read(cx).collapsed() == Some(SplitSide::First);
+    /// ```
+    pub fn collapsed(&self) -> Option<SplitSide> {
+        self.collapsed
+    }
+
+    /// Move the divider from the parent view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// split.update(cx, |split, cx| split.set_ratio(0.3, cx));
+    /// ```
+    pub fn set_ratio(&mut self, ratio: f32, cx: &mut Context<'_, Self>) {
+        self.props.ratio = ratio.clamp(0.0, 1.0);
+        self.restore_ratio = self.props.ratio;
+        self.collapsed = None;
+        cx.notify();
+    }
+
+    /// Collapse `side` to the edge, or restore both panes with `None`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// split.update(cx, |split, cx| split.set_collapsed(Some(SplitSide::First), window, cx));
+    /// ```
+    pub fn set_collapsed(&mut self, side: Option<SplitSide>, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.collapsed == side {
+            return;
+        }
+        self.collapsed = side;
+        if side.is_none() {
+            self.props.ratio = self.restore_ratio;
+        }
+        self.resized(window, cx);
+    }
+
+    /// Size limits in pixels
+    fn limits(&self) -> SplitLimits {
+        SplitLimits {
+            min_first: f32::from(self.props.min_first),
+            min_second: f32::from(self.props.min_second),
+            max_first: self.props.max_first.map(f32::from),
+            collapsible: self.props.collapsible,
+        }
+    }
+
+    /// Length of the container along the split axis and its start position
+    fn axis(&self) -> (Pixels, Pixels) {
+        let bounds = self.bounds.get();
+        match self.props.orientation {
+            SplitOrientation::Horizontal => (bounds.origin.x, bounds.size.width),
+            SplitOrientation::Vertical => (bounds.origin.y, bounds.size.height),
+        }
+    }
+
+    /// Place the divider `offset` from the start of the container
+    fn move_divider(&mut self, offset: Pixels, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let (_, total) = self.axis();
+        let (ratio, collapsed) = resolve_split(f32::from(offset), f32::from(total), self.limits());
+        if collapsed == self.collapsed && (ratio - self.current_ratio()).abs() < f32::EPSILON {
+            return;
+        }
+        if collapsed.is_none() {
+            self.props.ratio = ratio;
+            self.restore_ratio = ratio;
+        }
+        self.collapsed = collapsed;
+        self.resized(window, cx);
+    }
+
+    /// Notify the resize handler and re-render
+    fn resized(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+        cx.notify();
+        if let Some(handler) = self.props.on_resize.clone() {
+            handler(self.current_ratio(), window, cx);
+        }
+    }
+
+    /// Collapse the first pane, or restore it if it is collapsed
+    fn toggle_collapsed(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if !self.props.collapsible {
+            return;
+        }
+        let side = if self.collapsed.is_some() { None } else { Some(SplitSide::First) };
+        self.set_collapsed(side, window, cx);
+    }
+
+    /// Follow a divider drag belonging to this split
+    fn handle_drag(&mut self, event: &DragMoveEvent<SplitDrag>, window: &mut Window, cx: &mut Context<'_, Self>) {
+        // Nested splits all see the drag; only the owner moves
+        if event.drag(cx).pane != cx.entity_id() {
+            return;
+        }
+        let (start, _) = self.axis();
+        let position = match self.props.orientation {
+            SplitOrientation::Horizontal => event.event.position.x,
+            SplitOrientation::Vertical => event.event.position.y,
+        };
+        self.move_divider(position - start, window, cx);
+    }
+
+    /// Move the divider with arrow keys and toggle collapse with Enter
+    fn handle_divider_key(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let step = match (self.props.orientation, event.keystroke.key.as_str()) {
+            (SplitOrientation::Horizontal, "left") | (SplitOrientation::Vertical, "up") => -KEYBOARD_STEP,
+            (SplitOrientation::Horizontal, "right") | (SplitOrientation::Vertical, "down") => KEYBOARD_STEP,
+            (_, "enter") => {
+                self.toggle_collapsed(window, cx);
+                cx.stop_propagation();
+                return;
+            }
+            _ => return,
+        };
+
+        let (_, total) = self.axis();
+        let offset = total * self.current_ratio() + px(step);
+        self.move_divider(offset, window, cx);
+        cx.stop_propagation();
+    }
+}
+
+impl Default for SplitPane {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Render for SplitPane {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        let horizontal = self.props.orientation == SplitOrientation::Horizontal;
+        let ratio = self.current_ratio();
+
+        let divider_focus = self
+            .divider_focus
+            .get_or_insert_with(|| cx.focus_handle())
+            .clone();
+        let divider_color = if divider_focus.is_focused(window) {
+            theme.alias.color_border_focus
+        } else {
+            theme.alias.color_border
+        };
+        let hover_color = theme.alias.color_border_focus;
+
+        // A thin visible line inside a wider hit area
+        let line = if horizontal { div().w(px(1.0)).h_full() } else { div().h(px(1.0)).w_full() };
+        let mut divider = div()
+            .id("split-pane-divider")
+            .track_focus(&divider_focus)
+            .on_key_down(cx.listener(Self::handle_divider_key))
+            .group("split-pane-divider")
+            .flex()
+            .flex_none()
+            .items_center()
+            .justify_center()
+            .on_drag(SplitDrag { pane: cx.entity_id() }, |drag, _offset, _window, cx| {
+                cx.new(|_| drag.clone())
+            })
+            .on_click(cx.listener(|this, event: &ClickEvent, window, cx| {
+                if event.click_count() >= 2 {
+                    this.toggle_collapsed(window, cx);
+                }
+            }))
+            .child(
+                line.bg(divider_color)
+                    .group_hover("split-pane-divider", move |style| style.bg(hover_color)),
+            );
+        divider = if horizontal {
+            divider.w(px(5.0)).h_full().cursor_col_resize()
+        } else {
+            divider.h(px(5.0)).w_full().cursor_row_resize()
+        };
+
+        let pane = |slot: &Option<SplitPaneSlot>, window: &mut Window, cx: &mut App| {
+            let mut pane = div().relative().overflow_hidden();
+            if let Some(slot) = slot {
+                pane = pane.child(slot(window, cx));
+            }
+            pane
+        };
+
+        let mut first = if self.collapsed == Some(SplitSide::First) {
+            div()
+        } else {
+            pane(&self.props.first, window, cx)
+        };
+        let mut second = if self.collapsed == Some(SplitSide::Second) {
+            div()
+        } else {
+            pane(&self.props.second, window, cx)
+        };
+        first = if horizontal {
+            first.flex_none().h_full().w(relative(ratio))
+        } else {
+            first.flex_none().w_full().h(relative(ratio))
+        };
+        second = second.flex_1().size_full();
+
+        let bounds = self.bounds.clone();
+        let measure = canvas(
+            move |painted, window, _cx| {
+                if bounds.get() != painted {
+                    bounds.set(painted);
+                    window.request_animation_frame();
+                }
+            },
+            |_, (), _, _| {},
+        )
+        .absolute()
+        .top(px(0.0))
+        .left(px(0.0))
+        .size_full();
+
+        let container = div()
+            .id("split-pane")
+            .relative()
+            .size_full()
+            .flex()
+            .overflow_hidden()
+            .on_drag_move(cx.listener(Self::handle_drag))
+            .child(measure);
+        let container = if horizontal { container.flex_row() } else { container.flex_col() };
+        container.child(first).child(divider).child(second)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits(collapsible: bool) -> SplitLimits {
+        SplitLimits { min_first: 100.0, min_second: 100.0, max_first: Some(600.0), collapsible }
+    }
+
+    fn assert_split(actual: (f32, Option<SplitSide>), ratio: f32, collapsed: Option<SplitSide>) {
+        assert!((actual.0 - ratio).abs() < 1e-6, "ratio {} != {ratio}", actual.0);
+        assert_eq!(actual.1, collapsed);
+    }
+
+    #[test]
+    fn test_resolve_within_limits() {
+        assert_split(resolve_split(400.0, 1000.0, limits(false)), 0.4, None);
+    }
+
+    #[test]
+    fn test_resolve_clamps_to_min_and_max() {
+        assert_split(resolve_split(20.0, 1000.0, limits(false)), 0.1, None);
+        assert_split(resolve_split(700.0, 1000.0, limits(false)), 0.6, None);
+        assert_split(resolve_split(700.0, 750.0, limits(false)), 650.0 / 750.0, None);
+    }
+
+    #[test]
+    fn test_resolve_collapses_past_half_min() {
+        assert_split(resolve_split(40.0, 1000.0, limits(true)), 0.0, Some(SplitSide::First));
+        assert_split(resolve_split(60.0, 1000.0, limits(true)), 0.1, None);
+        assert_split(resolve_split(960.0, 1000.0, limits(true)), 1.0, Some(SplitSide::Second));
+    }
+
+    #[test]
+    fn test_collapsed_ratio() {
+        let mut split = SplitPane::new().ratio(0.3).collapsible(true);
+        assert!((split.current_ratio() - 0.3).abs() < f32::EPSILON);
+        split.collapsed = Some(SplitSide::First);
+        assert!(split.current_ratio().abs() < f32::EPSILON);
+    }
+}
@@ -0,0 +1,226 @@
+//! Virtualized list layout for long, uniformly sized lists.
+
+use std::ops::Range;
+
+use gpui::*;
+use super::scroll_area::{ScrollArea, ScrollAreaState};
+
+/// Rows rendered above and below the viewport by default
+const DEFAULT_OVERSCAN: usize = 4;
+
+/// Scroll state for a [`VirtualList`].
+///
+/// Store it on the view that renders the list so the scroll position
+/// survives re-renders. Cloning shares the same underlying scroll position.
+#[derive(Clone, Default)]
+pub struct VirtualListState {
+    scroll: ScrollAreaState,
+}
+
+impl VirtualListState {
+    /// Create a new scroll state positioned at the top
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// struct MyView {
+    ///     list_state: VirtualListState,
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Distance scrolled from the top of the list
+    pub fn scroll_top(&self) -> Pixels {
+        self.scroll.offset().y
+    }
+
+    /// Height of the visible area from the last layout, zero before the first
+    pub fn viewport_height(&self) -> Pixels {
+        self.scroll.viewport_size().height
+    }
+
+    /// Scroll by the minimum amount needed to show the item at `index`
+    ///
+    /// Before the first layout the viewport size is unknown, so the item is
+    /// scrolled to the top.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// self.list_state.scroll_to_item(self.highlighted, px(36.0));
+    /// ```
+    #[allow(clippy::cast_precision_loss)]
+    pub fn scroll_to_item(&self, index: usize, item_height: Pixels) {
+        self.scroll.scroll_into_view(item_height * index as f32, item_height);
+    }
+
+    /// Scroll back to the top of the list
+    pub fn scroll_to_top(&self) {
+        self.scroll.scroll_to_top();
+    }
+}
+
+/// Compute which items to render for a scroll position
+///
+/// Returns the items intersecting the viewport plus `overscan` items on
+/// either side, clamped to `0..item_count`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub fn visible_range(
+    scroll_top: Pixels,
+    viewport_height: Pixels,
+    item_height: Pixels,
+    item_count: usize,
+    overscan: usize,
+) -> Range<usize> {
+    if item_count == 0 || item_height <= px(0.0) {
+        return 0..0;
+    }
+
+    let scroll_top = if scroll_top < px(0.0) { px(0.0) } else { scroll_top };
+    let first = (scroll_top / item_height).floor() as usize;
+    let last = ((scroll_top + viewport_height) / item_height).ceil() as usize;
+
+    let start = first.saturating_sub(overscan).min(item_count);
+    let end = last.saturating_add(overscan).min(item_count).max(start);
+    start..end
+}
+
+/// Virtualized vertical list layout component
+///
+/// VirtualList renders only the items visible in its scroll viewport plus
+/// an overscan buffer, padding the rest with spacers so the scrollbar
+/// reflects the full list. All items share one fixed height. The list
+/// scrolls inside a [`ScrollArea`], so it gets themed scrollbars.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::layout::*;
+///
+/// VirtualList::new("users", users.len(), px(32.0), &self.list_state)
+///     .max_height(px(400.0))
+///     .overscan(8)
+///     .to_element(|index| Label::new(users[index].name.clone()));
+/// ```
+pub struct VirtualList {
+    id: ElementId,
+    item_count: usize,
+    item_height: Pixels,
+    max_height: Pixels,
+    overscan: usize,
+    state: VirtualListState,
+}
+
+impl VirtualList {
+    /// Create a new virtual list of `item_count` items of `item_height` each
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let list = VirtualList::new("results", 10_000, px(28.0), &self.list_state);
+    /// ```
+    pub fn new(
+        id: impl Into<ElementId>,
+        item_count: usize,
+        item_height: Pixels,
+        state: &VirtualListState,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            item_count,
+            item_height,
+            max_height: px(400.0),
+            overscan: DEFAULT_OVERSCAN,
+            state: state.clone(),
+        }
+    }
+
+    /// Set the maximum height of the scroll viewport
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// VirtualList::new("list", count, px(32.0), &state).max_height(px(300.0));
+    /// ```
+    pub fn max_height(mut self, max_height: Pixels) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// Set how many items to render beyond each edge of the viewport
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// VirtualList::new("list", count, px(32.0), &state).overscan(10);
+    /// ```
+    pub fn overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// Range of items rendered at the current scroll position
+    pub fn visible_range(&self) -> Range<usize> {
+        // Before the first layout the viewport size is unknown; assume full
+        let mut viewport = self.state.viewport_height();
+        if viewport <= px(0.0) {
+            viewport = self.max_height;
+        }
+
+        visible_range(
+            self.state.scroll_top(),
+            viewport,
+            self.item_height,
+            self.item_count,
+            self.overscan,
+        )
+    }
+
+    /// Convert to a scroll area, rendering visible items with `render_item`
+    #[allow(clippy::cast_precision_loss)]
+    pub fn to_element<E: IntoElement>(self, mut render_item: impl FnMut(usize) -> E) -> ScrollArea {
+        let range = self.visible_range();
+        let before = self.item_height * range.start as f32;
+        let after = self.item_height * (self.item_count - range.end) as f32;
+
+        ScrollArea::new(self.id)
+            .state(&self.state.scroll)
+            .max_height(self.max_height)
+            .child(div().h(before))
+            .children(range.map(|index| {
+                div()
+                    .h(self.item_height)
+                    .flex_none()
+                    .overflow_hidden()
+                    .child(render_item(index))
+            }))
+            .child(div().h(after))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_at_top() {
+        assert_eq!(visible_range(px(0.0), px(100.0), px(10.0), 1000, 2), 0..12);
+    }
+
+    #[test]
+    fn test_range_scrolled() {
+        // Items 50..60 are visible; partial items at both edges are included
+        assert_eq!(visible_range(px(500.0), px(100.0), px(10.0), 1000, 0), 50..60);
+        assert_eq!(visible_range(px(505.0), px(100.0), px(10.0), 1000, 0), 50..61);
+        assert_eq!(visible_range(px(505.0), px(100.0), px(10.0), 1000, 3), 47..64);
+    }
+
+    #[test]
+    fn test_range_clamped_to_count() {
+        assert_eq!(visible_range(px(0.0), px(100.0), px(10.0), 5, 4), 0..5);
+        assert_eq!(visible_range(px(9000.0), px(100.0), px(10.0), 20, 2), 20..20);
+        assert_eq!(visible_range(px(0.0), px(100.0), px(10.0), 0, 2), 0..0);
+    }
+}
@@ -0,0 +1,151 @@
+//! Wrap layout component for flowing children onto multiple lines.
+
+use gpui::*;
+use super::stack::{Alignment, Justify};
+
+/// Wrapping flow layout component
+///
+/// Wrap lays children out in a row and starts a new line whenever the next
+/// child does not fit, like words in a paragraph. Gaps between children on
+/// a line and between lines are set separately.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::layout::*;
+///
+/// Wrap::new()
+///     .column_gap(px(8.0))
+///     .row_gap(px(4.0))
+///     .align(Alignment::Center)
+///     .to_element()
+///     .children(tags.iter().map(|tag| Chip::new(tag.clone())));
+/// ```
+pub struct Wrap {
+    column_gap: Option<Pixels>,
+    row_gap: Option<Pixels>,
+    align: Alignment,
+    justify: Justify,
+}
+
+impl Wrap {
+    /// Create a new wrapping layout
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let wrap = Wrap::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            column_gap: None,
+            row_gap: None,
+            align: Alignment::default(),
+            justify: Justify::default(),
+        }
+    }
+
+    /// Set both the gap between children on a line and between lines
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Wrap::new().gap(px(8.0));
+    /// ```
+    pub fn gap(mut self, gap: Pixels) -> Self {
+        self.column_gap = Some(gap);
+        self.row_gap = Some(gap);
+        self
+    }
+
+    /// Set the gap between children on the same line
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Wrap::new().column_gap(px(12.0));
+    /// ```
+    pub fn column_gap(mut self, gap: Pixels) -> Self {
+        self.column_gap = Some(gap);
+        self
+    }
+
+    /// Set the gap between lines
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Wrap::new().row_gap(px(4.0));
+    /// ```
+    pub fn row_gap(mut self, gap: Pixels) -> Self {
+        self.row_gap = Some(gap);
+        self
+    }
+
+    /// Set the vertical alignment of children within each line
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Wrap::new().align(Alignment::Center);
+    /// ```
+    pub fn align(mut self, align: Alignment) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Set the horizontal justification of each line
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Wrap::new().justify(Justify::End);
+    /// ```
+    pub fn justify(mut self, justify: Justify) -> Self {
+        self.justify = justify;
+        self
+    }
+
+    /// Convert to a GPUI div with wrapping flex row layout
+    pub fn to_element(self) -> Div {
+        let mut element = div()
+            .flex()
+            .flex_row()
+            .flex_wrap()
+            // Lines start at the top rather than spreading over the height
+            .content_start();
+
+        // Apply gaps
+        if let Some(gap) = self.column_gap {
+            element = element.gap_x(gap);
+        }
+        if let Some(gap) = self.row_gap {
+            element = element.gap_y(gap);
+        }
+
+        // Apply alignment (vertical within each line)
+        element = match self.align {
+            Alignment::Start => element.items_start(),
+            Alignment::Center => element.items_center(),
+            Alignment::End => element.items_end(),
+            Alignment::Stretch => element.items_start(), // GPUI doesn't have items_stretch
+        };
+
+        // Apply justification (horizontal within each line)
+        element = match self.justify {
+            Justify::Start => element.justify_start(),
+            Justify::Center => element.justify_center(),
+            Justify::End => element.justify_end(),
+            Justify::Between => element.justify_between(),
+            Justify::Around => element.justify_start(), // GPUI doesn't have justify_around
+        };
+
+        element
+    }
+}
+
+impl Default for Wrap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
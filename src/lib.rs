@@ -31,9 +31,11 @@
 //! - [`theme`]: Design token system and theming
 //! - [`atoms`]: Primitive components (Button, Input, Icon, Badge, Avatar, Checkbox, Radio, Switch, Spinner)
 //! - [`molecules`]: Composite components (SearchBar, FormGroup, Card)
-//! - [`layout`]: Layout primitives (VStack, HStack, Spacer, Container, Divider)
-//! - [`organisms`]: Complex components (Dialog, Drawer, Table, CommandPalette)
-//! - [`utils`]: Accessibility utilities and helpers (FocusTrap, Announcer)
+//! - [`layout`]: Layout primitives (VStack, HStack, Wrap, Spacer, Container, Divider, ScrollArea, SplitPane)
+//! - [`charts`]: Dashboard charts (Sparkline, LineChart, BarChart)
+//! - [`form`]: Form validation with declarative rules and async validators
+//! - [`organisms`]: Complex components (Dialog, Drawer, Table, CommandPalette, ToastManager)
+//! - [`utils`]: Accessibility utilities and helpers (FocusTrap, Announcer, fuzzy matching, anchored positioning, breakpoints, motion, shortcuts, roving focus)
 //! - [`prelude`]: Convenient re-exports for common imports
 
 #![warn(missing_docs)]
@@ -49,6 +51,8 @@ pub mod atoms;
 pub mod layout;
 pub mod molecules;
 pub mod organisms;
+pub mod charts;
+pub mod form;
 pub mod utils;
 
 pub mod prelude;
@@ -0,0 +1,555 @@
+//! Accordion component with collapsible sections.
+
+use std::{cell::Cell, collections::HashMap, rc::Rc};
+
+use gpui::*;
+use crate::{
+    atoms::{icons, Icon, IconSize, Label, LabelVariant},
+    theme::use_theme,
+    utils::motion::{self, reduced_motion, Easing},
+};
+
+/// Renders the content of an accordion section
+pub type AccordionSlot = Rc<dyn Fn(&mut Window, &mut App) -> AnyElement>;
+
+/// A single collapsible section of an [`Accordion`]
+#[derive(Clone)]
+pub struct AccordionItem {
+    /// Section value/id
+    pub value: SharedString,
+    /// Header title
+    pub title: SharedString,
+    /// Content shown while expanded
+    pub content: Option<AccordionSlot>,
+    /// Whether the section can be toggled
+    pub disabled: bool,
+}
+
+impl AccordionItem {
+    /// Create a section with a value and header title
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let item = AccordionItem::new("shipping", "Shipping");
+    /// ```
+    pub fn new(value: impl Into<SharedString>, title: impl Into<SharedString>) -> Self {
+        Self {
+            value: value.into(),
+            title: title.into(),
+            content: None,
+            disabled: false,
+        }
+    }
+
+    /// Set the content shown while the section is expanded
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// AccordionItem::new("faq-1", "Can I cancel anytime?")
+    ///     .content(|_window, _cx| div().child("Yes, from your account settings.").into_any_element());
+    /// ```
+    pub fn content(mut self, content: impl Fn(&mut Window, &mut App) -> AnyElement + 'static) -> Self {
+        self.content = Some(Rc::new(content));
+        self
+    }
+
+    /// Set whether the section is disabled
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// AccordionItem::new("advanced", "Advanced").disabled(true);
+    /// ```
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// How many sections can be expanded at once
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AccordionMode {
+    /// Expanding a section collapses the others
+    #[default]
+    Single,
+    /// Sections expand and collapse independently
+    Multiple,
+}
+
+/// Accordion configuration properties
+#[derive(Clone, Default)]
+pub struct AccordionProps {
+    /// Sections in display order
+    pub items: Vec<AccordionItem>,
+    /// Values of the expanded sections
+    pub expanded: Vec<SharedString>,
+    /// Single or multi-expand mode
+    pub mode: AccordionMode,
+    /// Handler called with a section's value and whether it is now expanded
+    pub on_toggle: Option<Rc<dyn Fn(SharedString, bool, &mut Window, &mut App)>>,
+}
+
+/// Transition state of a section
+#[derive(Default)]
+struct SectionState {
+    /// Identifies the section's latest transition so it restarts on toggle
+    transition: usize,
+    /// Whether the collapse transition is still playing
+    closing: bool,
+    /// Measured height of the section content
+    height: Rc<Cell<Pixels>>,
+    /// Ends the collapse transition; dropping it keeps the section closing
+    _close_task: Option<Task<()>>,
+}
+
+/// An accordion of collapsible sections.
+///
+/// Accordion stacks section headers that expand to reveal their content,
+/// either one at a time or independently.
+///
+/// ## Features
+///
+/// - Single or multi-expand modes
+/// - Animated expand and collapse
+/// - Disabled sections
+/// - `on_toggle` callback for every section that changes
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::molecules::*;
+///
+/// // FAQ with one answer open at a time
+/// Accordion::new()
+///     .items(vec![
+///         AccordionItem::new("billing", "How does billing work?")
+///             .content(|_window, _cx| div().child("Monthly, in advance.").into_any_element()),
+///         AccordionItem::new("refunds", "Can I get a refund?")
+///             .content(|_window, _cx| div().child("Within 30 days.").into_any_element()),
+///     ])
+///     .default_expanded(vec!["billing".into()]);
+///
+/// // Settings panel with independent sections
+/// Accordion::new()
+///     .mode(AccordionMode::Multiple)
+///     .items(settings_sections())
+///     .on_toggle(|section, expanded, _window, cx| remember_section(section, expanded, cx));
+/// ```
+///
+/// ## Accessibility
+///
+/// - Headers are focusable; Enter or Space toggles the focused section
+/// - Up/Down move between enabled headers, Home/End jump to the first/last
+/// - Disabled sections are skipped by keyboard navigation
+pub struct Accordion {
+    props: AccordionProps,
+    /// Per-header focus handles, created on first render
+    focus_handles: Vec<FocusHandle>,
+    sections: HashMap<SharedString, SectionState>,
+    /// Counter handing out transition ids
+    transitions: usize,
+}
+
+/// Expanded values after toggling `value` in `mode`
+fn toggle_expanded(
+    expanded: &[SharedString],
+    value: &SharedString,
+    mode: AccordionMode,
+) -> Vec<SharedString> {
+    if expanded.contains(value) {
+        expanded.iter().filter(|v| *v != value).cloned().collect()
+    } else {
+        match mode {
+            AccordionMode::Single => vec![value.clone()],
+            AccordionMode::Multiple => {
+                let mut next = expanded.to_vec();
+                next.push(value.clone());
+                next
+            }
+        }
+    }
+}
+
+/// Header to focus after pressing `key` on the header at `from`
+fn header_for_key(items: &[AccordionItem], from: usize, key: &str) -> Option<usize> {
+    let len = items.len();
+    let enabled = |index: &usize| !items[*index].disabled;
+    match key {
+        "down" => (1..=len).map(|step| (from + step) % len).find(enabled),
+        "up" => (1..=len).map(|step| (from + len - step) % len).find(enabled),
+        "home" => (0..len).find(enabled),
+        "end" => (0..len).rev().find(enabled),
+        _ => None,
+    }
+}
+
+impl Accordion {
+    /// Create a new accordion
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let accordion = Accordion::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            props: AccordionProps::default(),
+            focus_handles: Vec::new(),
+            sections: HashMap::new(),
+            transitions: 0,
+        }
+    }
+
+    /// Set the sections
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Accordion::new().items(vec![AccordionItem::new("general", "General")]);
+    /// ```
+    pub fn items(mut self, items: Vec<AccordionItem>) -> Self {
+        self.props.items = items;
+        self
+    }
+
+    /// Set the initially expanded sections
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Accordion::new().default_expanded(vec!["general".into()]);
+    /// ```
+    pub fn default_expanded(mut self, expanded: Vec<SharedString>) -> Self {
+        self.props.expanded = expanded;
+        self
+    }
+
+    /// Set the expand mode
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Accordion::new().mode(AccordionMode::Multiple);
+    /// ```
+    pub fn mode(mut self, mode: AccordionMode) -> Self {
+        self.props.mode = mode;
+        self
+    }
+
+    /// Set the handler called when a section expands or collapses
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Accordion::new().on_toggle(|section, expanded, _window, _cx| {
+    ///     println!("{section} is now {}", if expanded { "open" } else { "closed" });
+    /// });
+    /// ```
+    pub fn on_toggle(
+        mut self,
+        handler: impl Fn(SharedString, bool, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_toggle = Some(Rc::new(handler));
+        self
+    }
+
+    /// Update the expanded sections from the parent view
+    ///
+    /// Changed sections animate and are reported to `on_toggle`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// accordion.update(cx, |accordion, cx| accordion.set_expanded(vec![], window, cx));
+    /// ```
+    pub fn set_expanded(
+        &mut self,
+        expanded: Vec<SharedString>,
+        window: &mut Window,
+        cx: &mut Context<'_, Self>,
+    ) {
+        let previous = std::mem::replace(&mut self.props.expanded, expanded);
+        let changed: Vec<(SharedString, bool)> = self
+            .props
+            .items
+            .iter()
+            .filter_map(|item| {
+                let was = previous.contains(&item.value);
+                let is = self.props.expanded.contains(&item.value);
+                (was != is).then(|| (item.value.clone(), is))
+            })
+            .collect();
+
+        for (value, expanded) in &changed {
+            self.start_transition(value.clone(), *expanded, cx);
+        }
+        if let Some(handler) = self.props.on_toggle.clone() {
+            for (value, expanded) in changed {
+                handler(value, expanded, window, cx);
+            }
+        }
+        cx.notify();
+    }
+
+    /// Whether the section with `value` is expanded
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let open = accordion.read(cx).is_expanded("general");
+    /// ```
+    pub fn is_expanded(&self, value: &str) -> bool {
+        self.props.expanded.iter().any(|v| v.as_ref() == value)
+    }
+
+    /// Toggle a section from a header click or key press
+    fn toggle(&mut self, value: SharedString, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.props.items.iter().any(|item| item.value == value && item.disabled) {
+            return;
+        }
+        let expanded = toggle_expanded(&self.props.expanded, &value, self.props.mode);
+        self.set_expanded(expanded, window, cx);
+    }
+
+    /// Restart a section's transition, finishing collapse after the duration
+    fn start_transition(&mut self, value: SharedString, expanding: bool, cx: &mut Context<'_, Self>) {
+        self.transitions += 1;
+        let transition = self.transitions;
+        let section = self.sections.entry(value.clone()).or_default();
+        section.transition = transition;
+        section.closing = !expanding;
+
+        let duration = use_theme(cx).global.duration_normal;
+        section._close_task = (!expanding).then(|| {
+            cx.spawn(async move |this, cx| {
+                cx.background_executor().timer(duration).await;
+                this.update(cx, |this, cx| {
+                    if let Some(section) = this.sections.get_mut(&value) {
+                        section.closing = false;
+                    }
+                    cx.notify();
+                })
+                .ok();
+            })
+        });
+    }
+
+    /// Move focus between headers with the arrow, Home and End keys
+    fn handle_header_key(
+        &mut self,
+        index: usize,
+        event: &KeyDownEvent,
+        window: &mut Window,
+        cx: &mut Context<'_, Self>,
+    ) {
+        let Some(target) = header_for_key(&self.props.items, index, &event.keystroke.key) else {
+            return;
+        };
+        if let Some(handle) = self.focus_handles.get(target) {
+            window.focus(handle);
+        }
+        cx.stop_propagation();
+    }
+}
+
+impl Default for Accordion {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Render for Accordion {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        let animation = motion::animation(theme.global.duration_normal, Easing::Standard);
+        let reduced = reduced_motion(cx);
+
+        while self.focus_handles.len() < self.props.items.len() {
+            self.focus_handles.push(cx.focus_handle());
+        }
+
+        let mut container = div()
+            .flex()
+            .flex_col()
+            .border(px(1.0))
+            .border_color(theme.alias.color_border)
+            .rounded(theme.global.radius_md)
+            .overflow_hidden();
+
+        for (index, item) in self.props.items.clone().into_iter().enumerate() {
+            let expanded = self.is_expanded(&item.value);
+            let ring = theme.alias.color_border_focus;
+
+            // Section header
+            let mut header = div()
+                .id(("accordion-header", index))
+                .track_focus(&self.focus_handles[index])
+                .flex()
+                .flex_row()
+                .items_center()
+                .justify_between()
+                .px(theme.global.spacing_md)
+                .py(theme.global.spacing_sm)
+                .border(px(1.0))
+                .border_color(hsla(0.0, 0.0, 0.0, 0.0))
+                .focus(move |style| style.border_color(ring))
+                .on_key_down(cx.listener(move |this, event: &KeyDownEvent, window, cx| {
+                    this.handle_header_key(index, event, window, cx);
+                }))
+                .child(
+                    Label::new(item.title.clone())
+                        .variant(LabelVariant::Body)
+                )
+                .child(
+                    Icon::new(if expanded { icons::CHEVRON_UP } else { icons::CHEVRON_DOWN })
+                        .size(IconSize::Sm)
+                        .custom_color(theme.alias.color_text_secondary)
+                        .to_element(&theme),
+                );
+
+            if item.disabled {
+                header = header.opacity(0.5).cursor_not_allowed();
+            } else {
+                let value = item.value.clone();
+                let hover_bg = theme.alias.color_surface_hover;
+                header = header
+                    .cursor_pointer()
+                    .hover(move |style| style.bg(hover_bg))
+                    .on_click(cx.listener(move |this, _event: &ClickEvent, window, cx| {
+                        this.toggle(value.clone(), window, cx);
+                    }));
+            }
+
+            // Divider between sections
+            if index > 0 {
+                container = container.child(div().h(px(1.0)).bg(theme.alias.color_border));
+            }
+            container = container.child(header);
+
+            // Content, kept while the collapse transition plays
+            let section = self.sections.entry(item.value.clone()).or_default();
+            let Some(content) = item.content.clone() else {
+                continue;
+            };
+            if !expanded && !section.closing {
+                continue;
+            }
+
+            let measured = section.height.clone();
+            let measure = canvas(
+                move |bounds, window, _cx| {
+                    if measured.get() != bounds.size.height {
+                        measured.set(bounds.size.height);
+                        window.request_animation_frame();
+                    }
+                },
+                |_, (), _, _| {},
+            )
+            .absolute()
+            .top(px(0.0))
+            .left(px(0.0))
+            .size_full();
+
+            let height = section.height.get();
+            let entering = expanded;
+            let body = div()
+                .overflow_hidden()
+                .child(
+                    div()
+                        .relative()
+                        .flex_none()
+                        .px(theme.global.spacing_md)
+                        .py(theme.global.spacing_sm)
+                        .child(content(window, cx))
+                        .child(measure),
+                );
+
+            // Sections expanded from the start appear without a transition
+            if section.transition == 0 {
+                container = container.child(body);
+                continue;
+            }
+            container = container.child(body.with_animation(
+                ("accordion-content", section.transition),
+                animation.clone(),
+                move |body, delta| {
+                    let visible = if entering { delta } else { 1.0 - delta };
+                    // Reduced motion fades in place; unmeasured content
+                    // shows at its natural height
+                    if reduced {
+                        return body.opacity(visible);
+                    }
+                    if height <= px(0.0) {
+                        return body;
+                    }
+                    body.h(height * visible)
+                },
+            ));
+        }
+
+        container
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(values: &[&'static str]) -> Vec<SharedString> {
+        values.iter().map(|value| SharedString::from(*value)).collect()
+    }
+
+    #[test]
+    fn test_accordion_defaults() {
+        let accordion = Accordion::new();
+        assert!(accordion.props.items.is_empty());
+        assert!(accordion.props.expanded.is_empty());
+        assert_eq!(accordion.props.mode, AccordionMode::Single);
+    }
+
+    #[test]
+    fn test_accordion_builder() {
+        let accordion = Accordion::new()
+            .items(vec![
+                AccordionItem::new("a", "A"),
+                AccordionItem::new("b", "B").disabled(true),
+            ])
+            .default_expanded(values(&["a"]))
+            .mode(AccordionMode::Multiple);
+        assert_eq!(accordion.props.items.len(), 2);
+        assert!(accordion.props.items[1].disabled);
+        assert!(accordion.is_expanded("a"));
+        assert!(!accordion.is_expanded("b"));
+    }
+
+    #[test]
+    fn test_single_mode_toggle() {
+        let mode = AccordionMode::Single;
+        assert_eq!(toggle_expanded(&values(&["a"]), &"b".into(), mode), values(&["b"]));
+        assert!(toggle_expanded(&values(&["a"]), &"a".into(), mode).is_empty());
+    }
+
+    #[test]
+    fn test_multiple_mode_toggle() {
+        let mode = AccordionMode::Multiple;
+        assert_eq!(toggle_expanded(&values(&["a"]), &"b".into(), mode), values(&["a", "b"]));
+        assert_eq!(toggle_expanded(&values(&["a", "b"]), &"a".into(), mode), values(&["b"]));
+    }
+
+    #[test]
+    fn test_header_navigation_skips_disabled() {
+        let items = vec![
+            AccordionItem::new("a", "A"),
+            AccordionItem::new("b", "B").disabled(true),
+            AccordionItem::new("c", "C"),
+        ];
+        assert_eq!(header_for_key(&items, 0, "down"), Some(2));
+        assert_eq!(header_for_key(&items, 2, "down"), Some(0));
+        assert_eq!(header_for_key(&items, 0, "up"), Some(2));
+        assert_eq!(header_for_key(&items, 2, "home"), Some(0));
+        assert_eq!(header_for_key(&items, 0, "end"), Some(2));
+        assert_eq!(header_for_key(&items, 0, "enter"), None);
+    }
+}
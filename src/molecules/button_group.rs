@@ -0,0 +1,534 @@
+//! ButtonGroup component: attached buttons with optional toggle selection.
+
+use std::rc::Rc;
+
+use gpui::*;
+use crate::{
+    atoms::{Button, ButtonSize, ButtonVariant},
+    theme::{use_theme, ButtonTokens},
+    utils::{RovingFocus, RovingOrientation},
+};
+
+/// How pressing a button in the group changes the selection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ButtonGroupSelection {
+    /// Buttons are plain actions and nothing stays selected
+    #[default]
+    None,
+    /// Exactly one button stays pressed, like a radio group
+    Single,
+    /// Each button toggles on and off independently
+    Multiple,
+}
+
+/// A button in a [`ButtonGroup`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ButtonGroupItem {
+    /// Value reported to the handlers
+    pub value: SharedString,
+    /// Button label, and the accessible name of icon-only buttons
+    pub label: SharedString,
+    /// SVG path of an icon shown before the label
+    pub icon: Option<SharedString>,
+    /// Whether to hide the label and show only the icon
+    pub icon_only: bool,
+    /// Whether the button is disabled
+    pub disabled: bool,
+}
+
+impl ButtonGroupItem {
+    /// Create a button with `value` and `label`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let item = ButtonGroupItem::new("bold", "Bold");
+    /// ```
+    pub fn new(value: impl Into<SharedString>, label: impl Into<SharedString>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+            icon: None,
+            icon_only: false,
+            disabled: false,
+        }
+    }
+
+    /// Set the icon shown before the label
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ButtonGroupItem::new("search", "Search").icon(icons::SEARCH);
+    /// ```
+    pub fn icon(mut self, path: impl Into<SharedString>) -> Self {
+        self.icon = Some(path.into());
+        self
+    }
+
+    /// Set whether to show only the icon
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ButtonGroupItem::new("search", "Search").icon(icons::SEARCH).icon_only(true);
+    /// ```
+    pub fn icon_only(mut self, icon_only: bool) -> Self {
+        self.icon_only = icon_only;
+        self
+    }
+
+    /// Set whether the button is disabled
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ButtonGroupItem::new("redo", "Redo").disabled(!history.can_redo());
+    /// ```
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// ButtonGroup configuration properties
+#[derive(Clone)]
+pub struct ButtonGroupProps {
+    /// Buttons, in order
+    pub items: Vec<ButtonGroupItem>,
+    /// How pressing a button changes the selection
+    pub selection: ButtonGroupSelection,
+    /// Values of the pressed buttons
+    pub selected: Vec<SharedString>,
+    /// Button size
+    pub size: ButtonSize,
+    /// Whether the whole group is disabled
+    pub disabled: bool,
+    /// Called with the value of each button pressed
+    pub on_press: Option<Rc<dyn Fn(SharedString, &mut Window, &mut App)>>,
+    /// Called with the new selection when it changes
+    pub on_change: Option<Rc<dyn Fn(Vec<SharedString>, &mut Window, &mut App)>>,
+    /// Whether the parent owns the selection, set by [`ButtonGroup::selected`]
+    pub controlled: bool,
+}
+
+impl Default for ButtonGroupProps {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            selection: ButtonGroupSelection::default(),
+            selected: Vec::new(),
+            size: ButtonSize::default(),
+            disabled: false,
+            on_press: None,
+            on_change: None,
+            controlled: false,
+        }
+    }
+}
+
+/// A row of attached buttons sharing their borders.
+///
+/// ButtonGroup joins related buttons into one control. As plain actions it
+/// reports each press; as a toggle group it keeps one button (`Single`) or
+/// any number (`Multiple`) pressed.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::molecules::*;
+///
+/// // Actions
+/// ButtonGroup::new()
+///     .item(ButtonGroupItem::new("undo", "Undo"))
+///     .item(ButtonGroupItem::new("redo", "Redo"))
+///     .on_press(|value, _window, cx| run_history_command(&value, cx));
+///
+/// // Text formatting toggles
+/// ButtonGroup::new()
+///     .selection(ButtonGroupSelection::Multiple)
+///     .item(ButtonGroupItem::new("bold", "Bold"))
+///     .item(ButtonGroupItem::new("italic", "Italic"))
+///     .on_change(|formats, _window, cx| apply_formats(&formats, cx));
+/// ```
+///
+/// ## Accessibility
+///
+/// - The group is a single Tab stop; Left and Right move between enabled
+///   buttons and Home and End jump to the first and last
+/// - Enter or Space presses the button with the focus ring
+/// - Pressed buttons are shown filled, not by color alone
+pub struct ButtonGroup {
+    props: ButtonGroupProps,
+    focus_handle: Option<FocusHandle>,
+    /// Button with the keyboard focus ring
+    active: Option<usize>,
+}
+
+impl ButtonGroup {
+    /// Create an empty button group
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let group = ButtonGroup::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            props: ButtonGroupProps::default(),
+            focus_handle: None,
+            active: None,
+        }
+    }
+
+    /// Add a button
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ButtonGroup::new().item(ButtonGroupItem::new("day", "Day"));
+    /// ```
+    pub fn item(mut self, item: ButtonGroupItem) -> Self {
+        self.props.items.push(item);
+        self
+    }
+
+    /// Set all buttons
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ButtonGroup::new().items(views.iter().map(|v| ButtonGroupItem::new(v.id(), v.name())).collect());
+    /// ```
+    pub fn items(mut self, items: Vec<ButtonGroupItem>) -> Self {
+        self.props.items = items;
+        self
+    }
+
+    /// Set how pressing a button changes the selection
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ButtonGroup::new().selection(ButtonGroupSelection::Single);
+    /// ```
+    pub fn selection(mut self, selection: ButtonGroupSelection) -> Self {
+        self.props.selection = selection;
+        self
+    }
+
+    /// Set the pressed buttons, making the selection controlled
+    ///
+    /// A controlled group only changes when the parent passes a new
+    /// selection; pressing a button just calls `on_change`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ButtonGroup::new().selected(vec![self.view_mode.clone()]);
+    /// ```
+    pub fn selected(mut self, selected: Vec<SharedString>) -> Self {
+        self.props.selected = selected;
+        self.props.controlled = true;
+        self
+    }
+
+    /// Set the initially pressed buttons of an uncontrolled group
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ButtonGroup::new().default_selected(vec!["week".into()]);
+    /// ```
+    pub fn default_selected(mut self, selected: Vec<SharedString>) -> Self {
+        self.props.selected = selected;
+        self.props.controlled = false;
+        self
+    }
+
+    /// Set the button size
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ButtonGroup::new().size(ButtonSize::Sm);
+    /// ```
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.props.size = size;
+        self
+    }
+
+    /// Set whether the whole group is disabled
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ButtonGroup::new().disabled(true);
+    /// ```
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.props.disabled = disabled;
+        self
+    }
+
+    /// Set the handler called with the value of each button pressed
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ButtonGroup::new().on_press(|value, _window, cx| run_command(&value, cx));
+    /// ```
+    pub fn on_press(mut self, handler: impl Fn(SharedString, &mut Window, &mut App) + 'static) -> Self {
+        self.props.on_press = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the handler called with the new selection when it changes
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ButtonGroup::new()
+    ///     .selection(ButtonGroupSelection::Single)
+    ///     .on_change(|selected, _window, cx| set_view_mode(&selected[0], cx));
+    /// ```
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(Vec<SharedString>, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Values of the pressed buttons
+    pub fn selected_values(&self) -> &[SharedString] {
+        &self.props.selected
+    }
+
+    /// Set the pressed buttons, for example from a parent's state
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// group.update(cx, |group, cx| group.set_selected(vec!["month".into()], cx));
+    /// ```
+    pub fn set_selected(&mut self, selected: Vec<SharedString>, cx: &mut Context<'_, Self>) {
+        self.props.selected = selected;
+        cx.notify();
+    }
+
+    /// Whether the button at `index` can be pressed
+    fn is_enabled(&self, index: usize) -> bool {
+        !self.props.disabled && self.props.items.get(index).is_some_and(|item| !item.disabled)
+    }
+
+    /// Whether the button at `index` is pressed
+    fn is_selected(&self, index: usize) -> bool {
+        self.props.selection != ButtonGroupSelection::None
+            && self
+                .props
+                .items
+                .get(index)
+                .is_some_and(|item| self.props.selected.contains(&item.value))
+    }
+
+    /// Selection after pressing the button at `index`, in button order
+    fn toggled(&self, index: usize) -> Vec<SharedString> {
+        let value = &self.props.items[index].value;
+        match self.props.selection {
+            ButtonGroupSelection::None => Vec::new(),
+            ButtonGroupSelection::Single => vec![value.clone()],
+            ButtonGroupSelection::Multiple => {
+                let pressed = !self.props.selected.contains(value);
+                self.props
+                    .items
+                    .iter()
+                    .filter(|item| {
+                        if &item.value == value {
+                            pressed
+                        } else {
+                            self.props.selected.contains(&item.value)
+                        }
+                    })
+                    .map(|item| item.value.clone())
+                    .collect()
+            }
+        }
+    }
+
+    /// Press the button at `index`
+    fn press(&mut self, index: usize, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if !self.is_enabled(index) {
+            return;
+        }
+        self.active = Some(index);
+
+        if let Some(handler) = self.props.on_press.clone() {
+            handler(self.props.items[index].value.clone(), window, cx);
+        }
+
+        let selected = self.toggled(index);
+        if self.props.selection != ButtonGroupSelection::None && selected != self.props.selected {
+            if !self.props.controlled {
+                self.props.selected = selected.clone();
+            }
+            if let Some(handler) = self.props.on_change.clone() {
+                handler(selected, window, cx);
+            }
+        }
+        cx.notify();
+    }
+
+    /// Button the focus ring starts on: the first pressed, else the first enabled
+    fn initial_active(&self) -> Option<usize> {
+        (0..self.props.items.len())
+            .find(|&index| self.is_selected(index) && self.is_enabled(index))
+            .or_else(|| RovingFocus::first(self.props.items.len(), |index| self.is_enabled(index)))
+    }
+
+    /// Handle arrow, Home, End, Enter and Space keys
+    fn handle_key(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let active = self.active.filter(|&index| self.is_enabled(index)).or_else(|| self.initial_active());
+        match event.keystroke.key.as_str() {
+            "enter" | "space" => {
+                if let Some(index) = active {
+                    self.press(index, window, cx);
+                }
+            }
+            key => {
+                let roving = RovingFocus::new(RovingOrientation::Horizontal);
+                match roving.target(key, active, self.props.items.len(), |index| self.is_enabled(index)) {
+                    Some(index) => {
+                        self.active = Some(index);
+                        cx.notify();
+                    }
+                    None => return,
+                }
+            }
+        }
+        cx.stop_propagation();
+    }
+}
+
+impl Default for ButtonGroup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Render for ButtonGroup {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        let tokens = ButtonTokens::from_theme(&theme);
+        let focus_handle = self
+            .focus_handle
+            .get_or_insert_with(|| cx.focus_handle())
+            .clone();
+        let focused = focus_handle.is_focused(window);
+        let active = self.active.filter(|&index| self.is_enabled(index)).or_else(|| self.initial_active());
+
+        let mut group = div()
+            .id("button-group")
+            .track_focus(&focus_handle)
+            .flex()
+            .flex_row()
+            .flex_none()
+            .rounded(tokens.border_radius)
+            .border(tokens.border_width)
+            .border_color(tokens.border_outline)
+            .overflow_hidden();
+        if !self.props.disabled {
+            group = group.on_key_down(cx.listener(Self::handle_key));
+        }
+
+        for (index, item) in self.props.items.iter().enumerate() {
+            let selected = self.is_selected(index);
+            let enabled = self.is_enabled(index);
+
+            let mut button = Button::new()
+                .id(("button-group-item", index))
+                .label(item.label.clone())
+                .variant(if selected { ButtonVariant::Secondary } else { ButtonVariant::Ghost })
+                .size(self.props.size)
+                .icon_only(item.icon_only && item.icon.is_some())
+                .disabled(!enabled);
+            if let Some(icon) = &item.icon {
+                button = button.icon_left(icon.clone());
+            }
+
+            // Square inner corners; the group's border rounds the ends
+            let mut button = button.to_element(&theme).rounded(px(0.0));
+            if index > 0 {
+                button = button.border_l(tokens.border_width).border_color(tokens.border_outline);
+            }
+            if focused && active == Some(index) {
+                button = button.outline_color(theme.alias.color_border_focus).outline_w(px(2.0));
+            }
+            if enabled {
+                button = button.on_click(cx.listener(move |this, _event: &ClickEvent, window, cx| {
+                    this.press(index, window, cx);
+                }));
+            }
+
+            group = group.child(button);
+        }
+
+        group
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn formats() -> ButtonGroup {
+        ButtonGroup::new()
+            .item(ButtonGroupItem::new("bold", "Bold"))
+            .item(ButtonGroupItem::new("italic", "Italic"))
+            .item(ButtonGroupItem::new("underline", "Underline").disabled(true))
+    }
+
+    #[test]
+    fn test_button_group_defaults() {
+        let group = ButtonGroup::new();
+        assert_eq!(group.props.selection, ButtonGroupSelection::None);
+        assert!(group.props.items.is_empty());
+        assert!(!group.props.controlled);
+    }
+
+    #[test]
+    fn test_single_selection_replaces() {
+        let group = formats()
+            .selection(ButtonGroupSelection::Single)
+            .default_selected(vec!["bold".into()]);
+        assert_eq!(group.toggled(1), vec![SharedString::from("italic")]);
+        assert_eq!(group.toggled(0), vec![SharedString::from("bold")]);
+    }
+
+    #[test]
+    fn test_multiple_selection_toggles_in_order() {
+        let group = formats()
+            .selection(ButtonGroupSelection::Multiple)
+            .default_selected(vec!["italic".into()]);
+        assert_eq!(
+            group.toggled(0),
+            vec![SharedString::from("bold"), SharedString::from("italic")]
+        );
+        assert!(group.toggled(1).is_empty());
+    }
+
+    #[test]
+    fn test_actions_never_select() {
+        let group = formats().default_selected(vec!["bold".into()]);
+        assert!(!group.is_selected(0));
+        assert!(group.toggled(0).is_empty());
+    }
+
+    #[test]
+    fn test_initial_active_skips_disabled() {
+        let group = formats()
+            .selection(ButtonGroupSelection::Single)
+            .selected(vec!["underline".into()]);
+        assert_eq!(group.initial_active(), Some(0));
+        assert!(!group.is_enabled(2));
+    }
+}
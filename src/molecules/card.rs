@@ -1,7 +1,12 @@
 //! Card component for content containers.
 
+use std::rc::Rc;
+
 use gpui::*;
-use crate::{atoms::{Label, LabelVariant}, theme::Theme};
+use crate::{atoms::{Label, LabelVariant}, theme::use_theme};
+
+/// Renders custom content for one of a card's slots
+pub type CardSlot = Rc<dyn Fn(&mut Window, &mut App) -> AnyElement>;
 
 /// Card visual variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -24,6 +29,18 @@ pub struct CardProps {
     pub variant: CardVariant,
     /// Whether card is hoverable/clickable
     pub hoverable: bool,
+    /// Custom header replacing the title
+    pub header: Option<CardSlot>,
+    /// Main content
+    pub body: Option<CardSlot>,
+    /// Footer content, typically actions
+    pub footer: Option<CardSlot>,
+    /// Full-bleed media or cover image above the header
+    pub media: Option<CardSlot>,
+    /// Handler called when the card is clicked or activated with Enter/Space
+    pub on_click: Option<Rc<dyn Fn(&ClickEvent, &mut Window, &mut App)>>,
+    /// Whether the card is shown as selected
+    pub selected: bool,
 }
 
 impl Default for CardProps {
@@ -32,18 +49,32 @@ impl Default for CardProps {
             title: None,
             variant: CardVariant::default(),
             hoverable: false,
+            header: None,
+            body: None,
+            footer: None,
+            media: None,
+            on_click: None,
+            selected: false,
         }
     }
 }
 
 /// A card component for content containers.
 ///
-/// Card provides a styled container for grouping related content.
+/// Card provides a styled container for grouping related content, with
+/// optional media, header, body and footer slots.
+///
+/// ## Features
+///
+/// - Header (or plain title), body and footer slots
+/// - Full-bleed media/cover area above the header
+/// - Interactive mode with hover styling and `on_click`, keyboard activatable
+/// - Selected state styling for selectable card grids
 ///
 /// ## Example
 ///
 /// ```rust,ignore
-/// use purdah_gpui_components::organisms::*;
+/// use purdah_gpui_components::molecules::*;
 ///
 /// // Basic card
 /// Card::new()
@@ -57,10 +88,27 @@ impl Default for CardProps {
 /// // Card with content
 /// Card::new()
 ///     .title("Settings")
-///     .variant(CardVariant::Outlined);
+///     .variant(CardVariant::Outlined)
+///     .body(|_window, _cx| div().child("Notification preferences").into_any_element())
+///     .footer(|_window, _cx| {
+///         Button::new().label("Edit").to_element(&theme).into_any_element()
+///     });
+///
+/// // Selectable, clickable card
+/// Card::new()
+///     .title("Pro plan")
+///     .selected(plan == Plan::Pro)
+///     .on_click(|_event, _window, cx| select_plan(Plan::Pro, cx));
 /// ```
+///
+/// ## Accessibility
+///
+/// - Clickable cards are focusable and activate with Enter or Space
+/// - Focused and selected cards show a visible border
 pub struct Card {
     props: CardProps,
+    /// Keyboard focus for clickable cards, created on first render
+    focus_handle: Option<FocusHandle>,
 }
 
 impl Card {
@@ -74,6 +122,7 @@ impl Card {
     pub fn new() -> Self {
         Self {
             props: CardProps::default(),
+            focus_handle: None,
         }
     }
 
@@ -112,20 +161,123 @@ impl Card {
         self.props.hoverable = hoverable;
         self
     }
+
+    /// Replace the title with custom header content
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Card::new().header(|_window, _cx| {
+    ///     div().flex().justify_between().child("Usage").child("This month").into_any_element()
+    /// });
+    /// ```
+    pub fn header(mut self, header: impl Fn(&mut Window, &mut App) -> AnyElement + 'static) -> Self {
+        self.props.header = Some(Rc::new(header));
+        self
+    }
+
+    /// Set the body content
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Card::new().title("Profile").body(|_window, _cx| profile_details().into_any_element());
+    /// ```
+    pub fn body(mut self, body: impl Fn(&mut Window, &mut App) -> AnyElement + 'static) -> Self {
+        self.props.body = Some(Rc::new(body));
+        self
+    }
+
+    /// Set the footer content
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Card::new().footer(|_window, _cx| div().flex().justify_end().child("Updated today").into_any_element());
+    /// ```
+    pub fn footer(mut self, footer: impl Fn(&mut Window, &mut App) -> AnyElement + 'static) -> Self {
+        self.props.footer = Some(Rc::new(footer));
+        self
+    }
+
+    /// Set full-bleed media shown above the header, such as a cover image
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Card::new().media(|_window, _cx| img("cover.png").w_full().h(px(160.0)).into_any_element());
+    /// ```
+    pub fn media(mut self, media: impl Fn(&mut Window, &mut App) -> AnyElement + 'static) -> Self {
+        self.props.media = Some(Rc::new(media));
+        self
+    }
+
+    /// Set the click handler, making the card interactive
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Card::new().on_click(|_event, _window, cx| open_project(cx));
+    /// ```
+    pub fn on_click(
+        mut self,
+        handler: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_click = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set whether the card is shown as selected
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Card::new().selected(true);
+    /// ```
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.props.selected = selected;
+        self
+    }
+
+    /// Update the selected state from the parent view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// card.update(cx, |card, cx| card.set_selected(true, cx));
+    /// ```
+    pub fn set_selected(&mut self, selected: bool, cx: &mut Context<'_, Self>) {
+        if self.props.selected != selected {
+            self.props.selected = selected;
+            cx.notify();
+        }
+    }
+
+    /// Whether the card reacts to hover and clicks
+    fn is_interactive(&self) -> bool {
+        self.props.hoverable || self.props.on_click.is_some()
+    }
+}
+
+impl Default for Card {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Render for Card {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
-        let theme = Theme::default();
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        let interactive = self.is_interactive();
 
         // Build card container
         let mut card = div()
+            .id("card")
             .bg(theme.alias.color_surface)
             .rounded(theme.global.radius_lg)
-            .p(theme.global.spacing_lg)
+            .overflow_hidden()
             .flex()
-            .flex_col()
-            .gap(theme.global.spacing_md);
+            .flex_col();
 
         // Apply variant styling
         card = match self.props.variant {
@@ -133,27 +285,117 @@ impl Render for Card {
             CardVariant::Outlined => card
                 .border_color(theme.alias.color_border)
                 .border(px(1.0)),
-            CardVariant::Elevated => card
-                .shadow_lg()
-                .when(self.props.hoverable, |c| c.hover(|style| {
-                    style.shadow_xl()
-                })),
+            CardVariant::Elevated => card.shadow_lg(),
         };
 
-        // Add title if present
-        if let Some(title) = &self.props.title {
-            card = card.child(
+        // Selected cards get a primary border on every variant
+        if self.props.selected {
+            card = card
+                .border_color(theme.alias.color_primary)
+                .border(px(2.0));
+        }
+
+        if interactive {
+            let hover_bg = theme.alias.color_surface_hover;
+            let elevated = self.props.variant == CardVariant::Elevated;
+            card = card
+                .cursor_pointer()
+                .hover(move |style| {
+                    // Elevated cards lift further on hover
+                    let style = style.bg(hover_bg);
+                    if elevated { style.shadow_xl() } else { style }
+                });
+        }
+
+        // Clickable cards are focusable and activate with Enter or Space
+        if let Some(handler) = self.props.on_click.clone() {
+            let focus_handle = self
+                .focus_handle
+                .get_or_insert_with(|| cx.focus_handle())
+                .clone();
+            let ring = theme.alias.color_border_focus;
+            card = card
+                .track_focus(&focus_handle)
+                .focus(move |style| style.border_color(ring))
+                .on_click(move |event, window, cx| handler(event, window, cx));
+        }
+
+        // Media spans the full card width, above the padded content
+        if let Some(media) = self.props.media.clone() {
+            card = card.child(media(window, cx));
+        }
+
+        let mut content = div()
+            .p(theme.global.spacing_lg)
+            .flex()
+            .flex_col()
+            .gap(theme.global.spacing_md);
+
+        // Custom header, or the title if present
+        if let Some(header) = self.props.header.clone() {
+            content = content.child(header(window, cx));
+        } else if let Some(title) = &self.props.title {
+            content = content.child(
                 Label::new(title.clone())
                     .variant(LabelVariant::Heading3)
             );
         }
 
-        // Add placeholder content area
-        card.child(
-            div()
-                .text_size(theme.alias.font_size_body)
-                .text_color(theme.alias.color_text_secondary)
-                .child("Card content goes here")
-        )
+        if let Some(body) = self.props.body.clone() {
+            content = content.child(
+                div()
+                    .text_size(theme.alias.font_size_body)
+                    .text_color(theme.alias.color_text_secondary)
+                    .child(body(window, cx))
+            );
+        }
+
+        if let Some(footer) = self.props.footer.clone() {
+            content = content.child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap(theme.global.spacing_sm)
+                    .child(footer(window, cx))
+            );
+        }
+
+        card.child(content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_card_defaults() {
+        let card = Card::new();
+        assert!(card.props.title.is_none());
+        assert_eq!(card.props.variant, CardVariant::Outlined);
+        assert!(card.props.body.is_none());
+        assert!(!card.props.selected);
+        assert!(!card.is_interactive());
+    }
+
+    #[test]
+    fn test_card_slots() {
+        let card = Card::new()
+            .header(|_window, _cx| div().into_any_element())
+            .body(|_window, _cx| div().into_any_element())
+            .footer(|_window, _cx| div().into_any_element())
+            .media(|_window, _cx| div().into_any_element());
+        assert!(card.props.header.is_some());
+        assert!(card.props.body.is_some());
+        assert!(card.props.footer.is_some());
+        assert!(card.props.media.is_some());
+    }
+
+    #[test]
+    fn test_card_interactive() {
+        assert!(Card::new().hoverable(true).is_interactive());
+        assert!(Card::new().on_click(|_event, _window, _cx| {}).is_interactive());
+        assert!(Card::new().selected(true).props.selected);
     }
 }
@@ -0,0 +1,786 @@
+//! DateTimePicker component combining a date field with a TimePicker.
+
+use std::{fmt, rc::Rc, time::{SystemTime, UNIX_EPOCH}};
+
+use gpui::*;
+use crate::{
+    atoms::{icons, Icon, IconSize},
+    molecules::time_picker::{TimeFormat, TimePicker, TimePickerProps, TimeValue},
+    theme::{use_theme, InputTokens, Theme},
+};
+
+/// A calendar date
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateValue {
+    /// Year, 1-9999
+    pub year: i32,
+    /// Month, 1-12
+    pub month: u8,
+    /// Day of the month, starting at 1
+    pub day: u8,
+}
+
+impl DateValue {
+    /// Create a date, or `None` when it does not exist
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// assert!(DateValue::new(2024, 2, 29).is_some());
+    /// assert!(DateValue::new(2023, 2, 29).is_none());
+    /// ```
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        ((1..=9999).contains(&year) && (1..=12).contains(&month))
+            .then_some(Self { year, month, day })
+            .filter(|date| (1..=date.days_in_month()).contains(&day))
+    }
+
+    /// Parse an ISO date such as `2024-05-01`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// assert_eq!(DateValue::parse("2024-05-01"), DateValue::new(2024, 5, 1));
+    /// ```
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut parts = text.trim().split('-');
+        let year = parts.next()?.parse().ok()?;
+        let month = parts.next()?.parse().ok()?;
+        let day = parts.next()?.parse().ok()?;
+        if parts.next().is_some() {
+            return None;
+        }
+        Self::new(year, month, day)
+    }
+
+    /// Today's date in UTC
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let today = DateValue::today();
+    /// ```
+    pub fn today() -> Self {
+        let days = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs() / 86_400);
+        Self::from_days(i64::try_from(days).unwrap_or_default())
+    }
+
+    /// Number of days in the date's month
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// assert_eq!(DateValue::new(2024, 2, 1).unwrap().days_in_month(), 29);
+    /// ```
+    pub fn days_in_month(self) -> u8 {
+        match self.month {
+            2 if self.is_leap_year() => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// Whether the date's year is a leap year
+    fn is_leap_year(self) -> bool {
+        (self.year % 4 == 0 && self.year % 100 != 0) || self.year % 400 == 0
+    }
+
+    /// Date `days` days after 1970-01-01
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn from_days(days: i64) -> Self {
+        // Civil-from-days conversion on the proleptic Gregorian calendar
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let day_of_era = z.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        Self {
+            year: year as i32,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+
+    /// Date with `segment` moved by `steps`
+    ///
+    /// Months and days wrap within their range; the day is clamped to the
+    /// length of the resulting month.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn stepped(self, segment: DateSegment, steps: i32) -> Self {
+        let mut date = self;
+        match segment {
+            DateSegment::Year => date.year = (date.year + steps).clamp(1, 9999),
+            DateSegment::Month => {
+                date.month = ((i32::from(date.month) - 1 + steps).rem_euclid(12) + 1) as u8;
+            }
+            DateSegment::Day => {
+                let days = i32::from(date.days_in_month());
+                date.day = ((i32::from(date.day) - 1 + steps).rem_euclid(days) + 1) as u8;
+            }
+        }
+        date.day = date.day.min(date.days_in_month());
+        date
+    }
+}
+
+impl fmt::Display for DateValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// A date and time of day
+///
+/// Orders chronologically, so it can be compared against `min`/`max`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DateTimeValue {
+    /// Calendar date
+    pub date: DateValue,
+    /// Time of day
+    pub time: TimeValue,
+}
+
+impl DateTimeValue {
+    /// Combine a date and a time
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let start = DateTimeValue::new(
+    ///     DateValue::new(2024, 5, 1).unwrap(),
+    ///     TimeValue::new(9, 30, 0).unwrap(),
+    /// );
+    /// ```
+    pub fn new(date: DateValue, time: TimeValue) -> Self {
+        Self { date, time }
+    }
+
+    /// Parse `2024-05-01 14:30`, `2024-05-01T14:30:15` or `2024-05-01 2:30 PM`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let start = DateTimeValue::parse("2024-05-01T09:30").unwrap();
+    /// ```
+    pub fn parse(text: &str) -> Option<Self> {
+        let (date, time) = text.trim().split_once(|c| c == 'T' || c == ' ')?;
+        Some(Self::new(DateValue::parse(date)?, TimeValue::parse(time)?))
+    }
+}
+
+impl fmt::Display for DateTimeValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.date, self.time)
+    }
+}
+
+/// Editable part of the date
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DateSegment {
+    Year,
+    Month,
+    Day,
+}
+
+impl DateSegment {
+    const ALL: [Self; 3] = [Self::Year, Self::Month, Self::Day];
+
+    /// Digits needed to complete the segment
+    fn digits(self) -> usize {
+        match self {
+            Self::Year => 4,
+            Self::Month | Self::Day => 2,
+        }
+    }
+}
+
+/// DateTimePicker configuration properties
+#[derive(Clone)]
+pub struct DateTimePickerProps {
+    /// Selected date and time, `None` when empty
+    pub value: Option<DateTimeValue>,
+    /// 12 or 24-hour display of the time
+    pub format: TimeFormat,
+    /// Whether seconds are shown and editable
+    pub show_seconds: bool,
+    /// Minutes moved per minute step
+    pub minute_step: u8,
+    /// Earliest valid date and time
+    pub min: Option<DateTimeValue>,
+    /// Latest valid date and time
+    pub max: Option<DateTimeValue>,
+    /// Whether the picker is disabled
+    pub disabled: bool,
+    /// Handler called with the new value when either the date or the time changes
+    pub on_change: Option<Rc<dyn Fn(DateTimeValue, &mut Window, &mut App)>>,
+    /// Whether the value is owned by the parent view
+    ///
+    /// Controlled pickers only report edits through `on_change`; the parent
+    /// applies them with [`DateTimePicker::set_value`].
+    pub controlled: bool,
+}
+
+impl Default for DateTimePickerProps {
+    fn default() -> Self {
+        Self {
+            value: None,
+            format: TimeFormat::default(),
+            show_seconds: false,
+            minute_step: 1,
+            min: None,
+            max: None,
+            disabled: false,
+            on_change: None,
+            controlled: false,
+        }
+    }
+}
+
+/// A date field and a [`TimePicker`] editing one date-time value.
+///
+/// Both parts share one value, one `on_change` handler and one `min`/`max`
+/// range: a time outside the range is only an error on the boundary dates,
+/// and both fields show the error state together.
+///
+/// ## Features
+///
+/// - Year, month and day segments with stepper arrows and keyboard entry
+/// - Day clamped to the month's length when the month or year changes
+/// - All [`TimePicker`] options: 12/24-hour modes, seconds, minute steps
+/// - Combined min/max validation
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::molecules::*;
+///
+/// DateTimePicker::new()
+///     .format(TimeFormat::H12)
+///     .minute_step(15)
+///     .min(DateTimeValue::parse("2024-05-01 09:00").unwrap())
+///     .on_change(|value, _window, cx| save_meeting_start(value, cx));
+/// ```
+///
+/// ## Accessibility
+///
+/// - The date field and the time field are separate tab stops
+/// - Left/Right move between date segments, Up/Down change the focused segment
+/// - Digits type into the focused segment
+pub struct DateTimePicker {
+    props: DateTimePickerProps,
+    focus_handle: Option<FocusHandle>,
+    /// Date segment edited from the keyboard
+    active: DateSegment,
+    /// Digits typed into the active segment so far
+    typed: String,
+    /// Embedded time field, created on first render
+    time_picker: Option<Entity<TimePicker>>,
+}
+
+impl DateTimePicker {
+    /// Create a new date-time picker
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let picker = DateTimePicker::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            props: DateTimePickerProps::default(),
+            focus_handle: None,
+            active: DateSegment::Year,
+            typed: String::new(),
+            time_picker: None,
+        }
+    }
+
+    /// Set the value, making the picker controlled
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// DateTimePicker::new().value(DateTimeValue::parse("2024-05-01 14:30").unwrap());
+    /// ```
+    pub fn value(mut self, value: DateTimeValue) -> Self {
+        self.props.value = Some(value);
+        self.props.controlled = true;
+        self
+    }
+
+    /// Set the initial value of an uncontrolled picker
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// DateTimePicker::new().default_value(DateTimeValue::parse("2024-05-01 09:00").unwrap());
+    /// ```
+    pub fn default_value(mut self, value: DateTimeValue) -> Self {
+        self.props.value = Some(value);
+        self.props.controlled = false;
+        self
+    }
+
+    /// Set 12 or 24-hour display of the time
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// DateTimePicker::new().format(TimeFormat::H12);
+    /// ```
+    pub fn format(mut self, format: TimeFormat) -> Self {
+        self.props.format = format;
+        self
+    }
+
+    /// Set whether seconds are shown
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// DateTimePicker::new().show_seconds(true);
+    /// ```
+    pub fn show_seconds(mut self, show_seconds: bool) -> Self {
+        self.props.show_seconds = show_seconds;
+        self
+    }
+
+    /// Set how many minutes the minute spinner moves per step
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// DateTimePicker::new().minute_step(15);
+    /// ```
+    pub fn minute_step(mut self, step: u8) -> Self {
+        self.props.minute_step = step.clamp(1, 30);
+        self
+    }
+
+    /// Set the earliest valid date and time
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// DateTimePicker::new().min(DateTimeValue::parse("2024-05-01 09:00").unwrap());
+    /// ```
+    pub fn min(mut self, min: DateTimeValue) -> Self {
+        self.props.min = Some(min);
+        self
+    }
+
+    /// Set the latest valid date and time
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// DateTimePicker::new().max(DateTimeValue::parse("2024-12-31 17:00").unwrap());
+    /// ```
+    pub fn max(mut self, max: DateTimeValue) -> Self {
+        self.props.max = Some(max);
+        self
+    }
+
+    /// Set whether the picker is disabled
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// DateTimePicker::new().disabled(true);
+    /// ```
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.props.disabled = disabled;
+        self
+    }
+
+    /// Set the handler called when the date or the time changes
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// DateTimePicker::new().on_change(|value, _window, cx| save_meeting_start(value, cx));
+    /// ```
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(DateTimeValue, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Update the value from the parent view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// picker.update(cx, |picker, cx| picker.set_value(None, cx));
+    /// ```
+    pub fn set_value(&mut self, value: Option<DateTimeValue>, cx: &mut Context<'_, Self>) {
+        if self.props.value != value {
+            self.props.value = value;
+            cx.notify();
+        }
+    }
+
+    /// Whether the value lies within `min` and `max`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let ok = picker.read(cx).is_valid();
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        self.props.value.is_none_or(|value| {
+            self.props.min.is_none_or(|min| value >= min)
+                && self.props.max.is_none_or(|max| value <= max)
+        })
+    }
+
+    /// Value edits start from: the current value, else `min`, else today at midnight
+    fn current(&self) -> DateTimeValue {
+        self.props
+            .value
+            .or(self.props.min)
+            .unwrap_or_else(|| DateTimeValue::new(DateValue::today(), TimeValue::default()))
+    }
+
+    /// Displayed text of `segment`, or a placeholder when empty
+    fn segment_text(&self, segment: DateSegment) -> SharedString {
+        match (self.props.value, segment) {
+            (None, DateSegment::Year) => "----".into(),
+            (None, _) => "--".into(),
+            (Some(value), DateSegment::Year) => format!("{:04}", value.date.year).into(),
+            (Some(value), DateSegment::Month) => format!("{:02}", value.date.month).into(),
+            (Some(value), DateSegment::Day) => format!("{:02}", value.date.day).into(),
+        }
+    }
+
+    /// Apply a value produced by the picker itself and notify the handler
+    fn change_value(&mut self, value: DateTimeValue, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.props.disabled || self.props.value == Some(value) {
+            return;
+        }
+
+        if !self.props.controlled {
+            self.set_value(Some(value), cx);
+        }
+
+        if let Some(handler) = self.props.on_change.clone() {
+            handler(value, window, cx);
+        }
+    }
+
+    /// Apply a date segment change
+    fn change_date(&mut self, date: DateValue, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let value = DateTimeValue { date, ..self.current() };
+        self.change_value(value, window, cx);
+    }
+
+    /// Apply a time edited in the embedded TimePicker
+    fn time_changed(&mut self, time: TimeValue, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let value = DateTimeValue { time, ..self.current() };
+        self.change_value(value, window, cx);
+    }
+
+    /// Move the active segment left (`-1`) or right (`1`)
+    fn move_active(&mut self, direction: isize) {
+        let index = DateSegment::ALL.iter().position(|s| *s == self.active).unwrap_or(0);
+        let next = index.saturating_add_signed(direction).min(DateSegment::ALL.len() - 1);
+        self.active = DateSegment::ALL[next];
+        self.typed.clear();
+    }
+
+    /// Apply a digit typed into the active segment
+    fn type_digit(&mut self, digit: char, window: &mut Window, cx: &mut Context<'_, Self>) {
+        self.typed.push(digit);
+        let Ok(number) = self.typed.parse::<i32>() else {
+            self.typed.clear();
+            return;
+        };
+
+        let date = self.current().date;
+        let digits = self.active.digits();
+        let next = match self.active {
+            // Years apply once complete, so typing 2 does not jump to year 2
+            DateSegment::Year if self.typed.len() < digits => return,
+            DateSegment::Year => (1..=9999).contains(&number).then_some(date.year),
+            DateSegment::Month => (1..=12).contains(&number).then_some(i32::from(date.month)),
+            DateSegment::Day => (1..=i32::from(date.days_in_month()))
+                .contains(&number)
+                .then_some(i32::from(date.day)),
+        };
+
+        match next {
+            Some(current) => self.change_date(date.stepped(self.active, number - current), window, cx),
+            None => self.typed.clear(),
+        }
+        if self.typed.len() >= digits {
+            self.move_active(1);
+        }
+    }
+
+    /// Handle segment navigation, stepping and typing in the date field
+    fn handle_key(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.props.disabled {
+            return;
+        }
+
+        let key = event.keystroke.key.as_str();
+        match key {
+            "left" => self.move_active(-1),
+            "right" => self.move_active(1),
+            "up" | "down" => {
+                let direction = if key == "up" { 1 } else { -1 };
+                self.typed.clear();
+                let date = self.current().date.stepped(self.active, direction);
+                self.change_date(date, window, cx);
+            }
+            _ if key.len() == 1 && key.chars().all(|c| c.is_ascii_digit()) => {
+                if let Some(digit) = key.chars().next() {
+                    self.type_digit(digit, window, cx);
+                }
+            }
+            _ => return,
+        }
+        cx.notify();
+        cx.stop_propagation();
+    }
+
+    /// Embedded time field, with its props matching the picker's
+    fn sync_time_picker(&mut self, cx: &mut Context<'_, Self>) -> Entity<TimePicker> {
+        let picker = self
+            .time_picker
+            .get_or_insert_with(|| cx.new(|_| TimePicker::new()))
+            .clone();
+
+        let this = cx.entity().downgrade();
+        let props = TimePickerProps {
+            value: self.props.value.map(|value| value.time),
+            format: self.props.format,
+            show_seconds: self.props.show_seconds,
+            minute_step: self.props.minute_step,
+            // The range spans dates, so it is checked here rather than by the TimePicker
+            min: None,
+            max: None,
+            disabled: self.props.disabled,
+            error: !self.is_valid(),
+            on_change: Some(Rc::new(move |time: TimeValue, window: &mut Window, cx: &mut App| {
+                this.update(cx, |this, cx| this.time_changed(time, window, cx)).ok();
+            })),
+            controlled: true,
+        };
+        picker.update(cx, |picker, cx| picker.set_props(props, cx));
+        picker
+    }
+
+    /// Render one date segment with its stepper arrows
+    fn render_segment(
+        &self,
+        segment: DateSegment,
+        index: usize,
+        focused: bool,
+        theme: &Theme,
+        cx: &mut Context<'_, Self>,
+    ) -> Div {
+        let active = focused && self.active == segment;
+        let arrow = |id: (&'static str, usize), path: &'static str| {
+            div()
+                .id(id)
+                .flex()
+                .items_center()
+                .justify_center()
+                .child(
+                    Icon::new(path)
+                        .size(IconSize::Xs)
+                        .custom_color(theme.alias.color_text_secondary)
+                        .to_element(theme),
+                )
+        };
+
+        let mut up = arrow(("date-picker-up", index), icons::CHEVRON_UP);
+        let mut down = arrow(("date-picker-down", index), icons::CHEVRON_DOWN);
+        if !self.props.disabled {
+            up = up
+                .cursor_pointer()
+                .hover(|style| style.opacity(0.7))
+                .on_click(cx.listener(move |this, _event: &ClickEvent, window, cx| {
+                    this.active = segment;
+                    let date = this.current().date.stepped(segment, 1);
+                    this.change_date(date, window, cx);
+                }));
+            down = down
+                .cursor_pointer()
+                .hover(|style| style.opacity(0.7))
+                .on_click(cx.listener(move |this, _event: &ClickEvent, window, cx| {
+                    this.active = segment;
+                    let date = this.current().date.stepped(segment, -1);
+                    this.change_date(date, window, cx);
+                }));
+        }
+
+        let mut text = div()
+            .id(("date-picker-segment", index))
+            .px(px(2.0))
+            .rounded(theme.global.radius_sm)
+            .child(self.segment_text(segment))
+            .on_click(cx.listener(move |this, _event: &ClickEvent, _window, cx| {
+                this.active = segment;
+                this.typed.clear();
+                cx.notify();
+            }));
+        if active {
+            text = text
+                .bg(theme.alias.color_primary)
+                .text_color(theme.alias.color_text_on_primary);
+        }
+
+        div()
+            .flex()
+            .flex_col()
+            .items_center()
+            .child(up)
+            .child(text)
+            .child(down)
+    }
+}
+
+impl Default for DateTimePicker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Render for DateTimePicker {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        let tokens = InputTokens::from_theme(&theme);
+        let time_picker = self.sync_time_picker(cx);
+
+        let focus_handle = self
+            .focus_handle
+            .get_or_insert_with(|| cx.focus_handle())
+            .clone();
+        let focused = focus_handle.is_focused(window);
+
+        let border = if !self.is_valid() {
+            tokens.border_error
+        } else if focused {
+            tokens.border_focus
+        } else {
+            tokens.border_default
+        };
+
+        let mut date_field = div()
+            .id("date-picker")
+            .track_focus(&focus_handle)
+            .on_key_down(cx.listener(Self::handle_key))
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap(px(2.0))
+            .px(tokens.padding_x)
+            .py(px(2.0))
+            .bg(if self.props.disabled { tokens.background_disabled } else { tokens.background })
+            .text_color(if self.props.disabled { tokens.text_disabled } else { tokens.text_color })
+            .text_size(tokens.font_size)
+            .font_weight(tokens.font_weight)
+            .border(tokens.border_width)
+            .border_color(border)
+            .rounded(tokens.border_radius);
+
+        for (index, segment) in DateSegment::ALL.into_iter().enumerate() {
+            if index > 0 {
+                date_field = date_field.child("-");
+            }
+            date_field = date_field.child(self.render_segment(segment, index, focused, &theme, cx));
+        }
+
+        div()
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap(theme.global.spacing_sm)
+            .child(date_field)
+            .child(time_picker)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u8, day: u8) -> DateValue {
+        DateValue::new(year, month, day).unwrap()
+    }
+
+    fn date_time(text: &str) -> DateTimeValue {
+        DateTimeValue::parse(text).unwrap()
+    }
+
+    #[test]
+    fn test_date_value_range() {
+        assert!(DateValue::new(2024, 2, 29).is_some());
+        assert!(DateValue::new(2023, 2, 29).is_none());
+        assert!(DateValue::new(1900, 2, 29).is_none());
+        assert!(DateValue::new(2000, 2, 29).is_some());
+        assert!(DateValue::new(2024, 4, 31).is_none());
+        assert!(DateValue::new(2024, 13, 1).is_none());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(DateValue::parse("2024-05-01"), Some(date(2024, 5, 1)));
+        assert_eq!(DateValue::parse("2024-5-1"), Some(date(2024, 5, 1)));
+        assert_eq!(DateValue::parse("2024-05"), None);
+        assert_eq!(
+            DateTimeValue::parse("2024-05-01T14:30"),
+            Some(DateTimeValue::new(date(2024, 5, 1), TimeValue::new(14, 30, 0).unwrap()))
+        );
+        assert_eq!(date_time("2024-05-01 2:30 PM").time, TimeValue::new(14, 30, 0).unwrap());
+        assert_eq!(date_time("2024-05-01 14:30:15").to_string(), "2024-05-01 14:30:15");
+    }
+
+    #[test]
+    fn test_from_days() {
+        assert_eq!(DateValue::from_days(0), date(1970, 1, 1));
+        assert_eq!(DateValue::from_days(19_723), date(2024, 1, 1));
+        assert_eq!(DateValue::from_days(19_782), date(2024, 2, 29));
+    }
+
+    #[test]
+    fn test_stepping_clamps_day() {
+        assert_eq!(date(2024, 1, 31).stepped(DateSegment::Month, 1), date(2024, 2, 29));
+        assert_eq!(date(2024, 2, 29).stepped(DateSegment::Year, 1), date(2025, 2, 28));
+        assert_eq!(date(2024, 2, 29).stepped(DateSegment::Day, 1), date(2024, 2, 1));
+        assert_eq!(date(2024, 1, 15).stepped(DateSegment::Month, -1), date(2024, 12, 15));
+    }
+
+    #[test]
+    fn test_range_spans_date_and_time() {
+        let picker = |value: &str| {
+            DateTimePicker::new()
+                .min(date_time("2024-05-01 09:00"))
+                .max(date_time("2024-05-03 17:00"))
+                .default_value(date_time(value))
+        };
+        // Times before 09:00 are only out of range on the first day
+        assert!(!picker("2024-05-01 08:00").is_valid());
+        assert!(picker("2024-05-02 08:00").is_valid());
+        assert!(!picker("2024-05-03 17:30").is_valid());
+        assert!(DateTimePicker::new().min(date_time("2024-05-01 09:00")).is_valid());
+    }
+
+    #[test]
+    fn test_edits_start_from_min() {
+        let min = date_time("2024-05-01 09:00");
+        assert_eq!(DateTimePicker::new().min(min).current(), min);
+    }
+}
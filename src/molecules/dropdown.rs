@@ -1,7 +1,20 @@
 //! Dropdown component for selection menus.
 
+use std::collections::HashMap;
+use std::ops::Range;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
 use gpui::*;
-use crate::{atoms::{Label, LabelVariant, Icon, icons}, theme::Theme};
+use crate::{
+    atoms::{Label, LabelVariant, Icon, IconSize, Spinner, SpinnerSize, icons},
+    layout::{VirtualList, VirtualListState},
+    theme::{use_theme, Theme},
+    utils::{
+        motion::reduced_motion, AnchorOptions, AnchoredState, Placement, PlacementAlign, RovingFocus,
+        RovingOrientation,
+    },
+};
 
 /// Configuration for a single dropdown option
 #[derive(Clone, Debug)]
@@ -59,6 +72,86 @@ impl DropdownOption {
     }
 }
 
+/// A labeled group of dropdown options, like an HTML `optgroup`
+///
+/// The group label renders as a non-selectable header, with a separator
+/// between consecutive groups.
+#[derive(Clone, Debug)]
+pub struct DropdownOptionGroup {
+    /// Group header label
+    pub label: SharedString,
+    /// Options in the group
+    pub options: Vec<DropdownOption>,
+}
+
+impl DropdownOptionGroup {
+    /// Create a new option group
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// DropdownOptionGroup::new("Europe", vec![
+    ///     DropdownOption::new("France", "fr"),
+    ///     DropdownOption::new("Germany", "de"),
+    /// ]);
+    /// ```
+    pub fn new(label: impl Into<SharedString>, options: Vec<DropdownOption>) -> Self {
+        Self {
+            label: label.into(),
+            options,
+        }
+    }
+}
+
+/// Async loader for dropdown options
+///
+/// Called with the current search text (empty when the dropdown is not
+/// searchable) and returns a task resolving to the matching options.
+pub type DropdownLoader = Rc<dyn Fn(SharedString, &mut App) -> Task<Vec<DropdownOption>>>;
+
+/// Height of each row in the open menu at comfortable density
+const MENU_ROW_HEIGHT: f32 = 36.0;
+
+/// A row of the open dropdown menu
+#[derive(Debug, Clone, PartialEq)]
+enum MenuRow {
+    /// Non-selectable group header, with a separator above unless first
+    Header { label: SharedString, separated: bool },
+    /// Option at an index into the options, with the search match range
+    Item { index: usize, matched: Range<usize> },
+}
+
+/// Pause after which type-ahead starts a new search
+const TYPEAHEAD_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// Fold a character for case-insensitive matching
+fn fold(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+/// Find the first case-insensitive occurrence of `query` in `label`
+///
+/// Returns the byte range of the match within `label`, or `None` when the
+/// label does not contain the query. An empty query matches at the start.
+fn find_match(label: &str, query: &str) -> Option<Range<usize>> {
+    let query: Vec<char> = query.chars().map(fold).collect();
+    label
+        .char_indices()
+        .map(|(start, _)| start)
+        .chain(std::iter::once(label.len()))
+        .find_map(|start| {
+            let mut chars = label[start..].char_indices();
+            for &q in &query {
+                match chars.next() {
+                    Some((_, c)) if fold(c) == q => {}
+                    _ => return None,
+                }
+            }
+            let end = chars.next().map_or(label.len(), |(offset, _)| start + offset);
+            Some(start..end)
+        })
+}
+
 /// Dropdown visual variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum DropdownVariant {
@@ -74,8 +167,10 @@ pub enum DropdownVariant {
 /// Dropdown configuration properties
 #[derive(Clone)]
 pub struct DropdownProps {
-    /// List of options
+    /// List of options, including the options of every group
     pub options: Vec<DropdownOption>,
+    /// Group headers as (index of the group's first option, label)
+    pub group_headers: Vec<(usize, SharedString)>,
     /// Currently selected option value
     pub selected: Option<SharedString>,
     /// Placeholder text when nothing is selected
@@ -88,21 +183,40 @@ pub struct DropdownProps {
     pub open: bool,
     /// Whether to allow searching/filtering options
     pub searchable: bool,
+    /// Message shown when no option matches the search text
+    pub empty_message: SharedString,
+    /// Loader fetching options when the menu opens or the search text changes
+    pub loader: Option<DropdownLoader>,
     /// Whether to allow multiple selections
     pub multiple: bool,
+    /// Handler called with the newly selected value
+    pub on_change: Option<Rc<dyn Fn(SharedString, &mut Window, &mut App)>>,
+    /// Handler called with the value of every option the user picks
+    pub on_select: Option<Rc<dyn Fn(SharedString, &mut Window, &mut App)>>,
+    /// Whether the selection is owned by the parent view
+    ///
+    /// Controlled dropdowns only report selections through `on_change`; the
+    /// parent applies them with [`Dropdown::set_selected`].
+    pub controlled: bool,
 }
 
 impl Default for DropdownProps {
     fn default() -> Self {
         Self {
             options: Vec::new(),
+            group_headers: Vec::new(),
             selected: None,
             placeholder: "Select an option".into(),
             variant: DropdownVariant::default(),
             disabled: false,
             open: false,
             searchable: false,
+            empty_message: "No results found".into(),
+            loader: None,
             multiple: false,
+            on_change: None,
+            on_select: None,
+            controlled: false,
         }
     }
 }
@@ -117,9 +231,11 @@ impl Default for DropdownProps {
 /// - Multiple visual variants
 /// - Keyboard navigation (arrow keys, Enter, Escape)
 /// - Optional search/filtering
+/// - Option groups with headers and separators
 /// - Multi-select support
 /// - Disabled options
 /// - Icons in options
+/// - Menu opens above the trigger when there is no room below
 /// - ARIA roles and attributes
 ///
 /// ## Example
@@ -143,7 +259,8 @@ impl Default for DropdownProps {
 ///         DropdownOption::new("United States", "us"),
 ///         DropdownOption::new("United Kingdom", "uk"),
 ///         DropdownOption::new("Canada", "ca"),
-///     ]);
+///     ])
+///     .empty_message("No countries match");
 ///
 /// // Dropdown with icons
 /// Dropdown::new()
@@ -151,17 +268,54 @@ impl Default for DropdownProps {
 ///         DropdownOption::new("Home", "home").icon(icons::HOME),
 ///         DropdownOption::new("Settings", "settings").icon(icons::SETTINGS),
 ///     ]);
+///
+/// // Grouped options with non-selectable headers
+/// Dropdown::new()
+///     .group(DropdownOptionGroup::new("Fruits", vec![
+///         DropdownOption::new("Apple", "apple"),
+///     ]))
+///     .group(DropdownOptionGroup::new("Vegetables", vec![
+///         DropdownOption::new("Carrot", "carrot"),
+///     ]));
+///
+/// // React to selections
+/// Dropdown::new()
+///     .options(options)
+///     .on_select(|value, _window, _cx| println!("picked {value}"));
 /// ```
 ///
 /// ## Accessibility
 ///
 /// - Uses ARIA `role="combobox"` and `role="listbox"`
-/// - Keyboard navigation: Arrow keys, Enter, Escape, Home, End
-/// - Type-ahead search support
+/// - Keyboard navigation: Arrow keys move the highlight (opening the menu
+///   when closed), Enter or Space selects, Escape closes, Home/End jump
+/// - Type-ahead: typing moves the highlight to the next option whose label
+///   starts with the typed text; searchable dropdowns filter instead, with
+///   Backspace deleting search text
 /// - Proper focus management
 /// - Meets WCAG 2.1 AA requirements
 pub struct Dropdown {
     props: DropdownProps,
+    focus_handle: Option<FocusHandle>,
+    /// Index of the option highlighted for keyboard selection
+    highlighted: Option<usize>,
+    /// Text typed for type-ahead since the last pause
+    typeahead: String,
+    /// When the last type-ahead character was typed
+    typeahead_at: Option<Instant>,
+    /// Search text typed into the menu of a searchable dropdown
+    query: String,
+    /// Options returned by the loader, keyed by search text
+    cache: HashMap<String, Vec<DropdownOption>>,
+    /// In-flight loader task; replacing it cancels the previous load
+    load_task: Option<Task<()>>,
+    /// Value and label of the last picked option, kept for display after
+    /// loaded options are replaced
+    picked: Option<(SharedString, SharedString)>,
+    /// Scroll position of the virtualized option list
+    list_state: VirtualListState,
+    /// Measured trigger and menu bounds for positioning the menu
+    anchor: AnchoredState,
 }
 
 impl Dropdown {
@@ -175,6 +329,16 @@ impl Dropdown {
     pub fn new() -> Self {
         Self {
             props: DropdownProps::default(),
+            focus_handle: None,
+            highlighted: None,
+            typeahead: String::new(),
+            typeahead_at: None,
+            query: String::new(),
+            cache: HashMap::new(),
+            load_task: None,
+            picked: None,
+            list_state: VirtualListState::new(),
+            anchor: AnchoredState::new(),
         }
     }
 
@@ -190,10 +354,47 @@ impl Dropdown {
     /// ```
     pub fn options(mut self, options: Vec<DropdownOption>) -> Self {
         self.props.options = options;
+        self.props.group_headers.clear();
+        self
+    }
+
+    /// Append a group of options under a non-selectable header
+    ///
+    /// Options set with [`Dropdown::options`] beforehand stay ungrouped at
+    /// the top of the menu.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Dropdown::new()
+    ///     .group(DropdownOptionGroup::new("Fruits", vec![
+    ///         DropdownOption::new("Apple", "apple"),
+    ///     ]))
+    ///     .group(DropdownOptionGroup::new("Vegetables", vec![
+    ///         DropdownOption::new("Carrot", "carrot"),
+    ///     ]));
+    /// ```
+    pub fn group(mut self, group: DropdownOptionGroup) -> Self {
+        self.props.group_headers.push((self.props.options.len(), group.label));
+        self.props.options.extend(group.options);
         self
     }
 
-    /// Set the currently selected option
+    /// Append several groups of options
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Dropdown::new().groups(vec![europe, americas]);
+    /// ```
+    pub fn groups(self, groups: Vec<DropdownOptionGroup>) -> Self {
+        groups.into_iter().fold(self, Self::group)
+    }
+
+    /// Set the currently selected option, making the dropdown controlled
+    ///
+    /// A controlled dropdown does not change its selection on click; apply
+    /// selections reported by `on_change` with [`Dropdown::set_selected`].
     ///
     /// ## Example
     ///
@@ -202,9 +403,72 @@ impl Dropdown {
     /// ```
     pub fn selected(mut self, selected: impl Into<SharedString>) -> Self {
         self.props.selected = Some(selected.into());
+        self.props.controlled = true;
+        self
+    }
+
+    /// Set the initially selected option of an uncontrolled dropdown
+    ///
+    /// The dropdown keeps its own selection afterwards.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Dropdown::new().default_selected("opt1");
+    /// ```
+    pub fn default_selected(mut self, selected: impl Into<SharedString>) -> Self {
+        self.props.selected = Some(selected.into());
+        self.props.controlled = false;
+        self
+    }
+
+    /// Set the handler called when the selection changes
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Dropdown::new().on_change(|value, _window, cx| set_country(value, cx));
+    /// ```
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the handler called whenever the user picks an option
+    ///
+    /// Unlike `on_change`, this also fires when the already selected option
+    /// is picked again.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Dropdown::new().on_select(|value, _window, cx| apply_preset(value, cx));
+    /// ```
+    pub fn on_select(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_select = Some(Rc::new(handler));
         self
     }
 
+    /// Update the selection from the parent view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// dropdown.update(cx, |dropdown, cx| dropdown.set_selected(Some("uk".into()), cx));
+    /// ```
+    pub fn set_selected(&mut self, selected: Option<SharedString>, cx: &mut Context<'_, Self>) {
+        if self.props.selected != selected {
+            self.props.selected = selected;
+            cx.notify();
+        }
+    }
+
     /// Set the placeholder text
     ///
     /// ## Example
@@ -255,6 +519,10 @@ impl Dropdown {
 
     /// Set whether the dropdown is searchable
     ///
+    /// A searchable dropdown shows a search field at the top of the open
+    /// menu. Typed text filters options by case-insensitive substring match
+    /// and highlights the matching part of each label.
+    ///
     /// ## Example
     ///
     /// ```rust,ignore
@@ -265,6 +533,58 @@ impl Dropdown {
         self
     }
 
+    /// Set the message shown when no option matches the search text
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Dropdown::new().searchable(true).empty_message("No countries match");
+    /// ```
+    pub fn empty_message(mut self, message: impl Into<SharedString>) -> Self {
+        self.props.empty_message = message.into();
+        self
+    }
+
+    /// Load options asynchronously instead of providing them up front
+    ///
+    /// The loader runs when the menu opens and, for searchable dropdowns,
+    /// whenever the search text changes. A spinner shows while it runs, and
+    /// results are cached per search text so repeated queries resolve
+    /// instantly. Filtering is left to the loader.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Dropdown::new()
+    ///     .searchable(true)
+    ///     .loader(|query, cx| {
+    ///         cx.background_spawn(async move {
+    ///             search_users(&query)
+    ///                 .into_iter()
+    ///                 .map(|user| DropdownOption::new(user.name, user.id))
+    ///                 .collect()
+    ///         })
+    ///     });
+    /// ```
+    pub fn loader(
+        mut self,
+        loader: impl Fn(SharedString, &mut App) -> Task<Vec<DropdownOption>> + 'static,
+    ) -> Self {
+        self.props.loader = Some(Rc::new(loader));
+        self
+    }
+
+    /// Forget cached loader results so the next open fetches fresh options
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// dropdown.update(cx, |dropdown, _cx| dropdown.clear_cache());
+    /// ```
+    pub fn clear_cache(&mut self) {
+        self.cache.clear();
+    }
+
     /// Set whether multiple selections are allowed
     ///
     /// ## Example
@@ -276,11 +596,405 @@ impl Dropdown {
         self.props.multiple = multiple;
         self
     }
+
+    /// Where the search text matches the label of the option at `index`
+    ///
+    /// Always matches when the dropdown is not searchable.
+    fn option_match(&self, index: usize) -> Option<Range<usize>> {
+        let option = self.props.options.get(index)?;
+        if !self.props.searchable {
+            Some(0..0)
+        } else if self.props.loader.is_some() {
+            // The loader already filtered; only emphasize literal matches
+            Some(find_match(&option.label, &self.query).unwrap_or(0..0))
+        } else {
+            find_match(&option.label, &self.query)
+        }
+    }
+
+    /// Check whether the option at `index` can be highlighted and selected
+    ///
+    /// Options filtered out by the search text are skipped.
+    fn is_enabled(&self, index: usize) -> bool {
+        self.props.options.get(index).is_some_and(|o| !o.disabled)
+            && self.option_match(index).is_some()
+    }
+
+    /// Range of option indices belonging to the group starting at `start`
+    fn group_range(&self, start: usize) -> Range<usize> {
+        let end = self
+            .props
+            .group_headers
+            .iter()
+            .map(|&(index, _)| index)
+            .find(|&index| index > start)
+            .unwrap_or(self.props.options.len());
+        start..end
+    }
+
+    /// Replace the search text and move the highlight to the first match
+    fn set_query(&mut self, query: String, cx: &mut Context<'_, Self>) {
+        self.query = query;
+        self.highlighted = self.step_from(None, true);
+        self.list_state.scroll_to_top();
+        self.load(cx);
+        cx.notify();
+    }
+
+    /// Whether the loader is fetching options
+    fn is_loading(&self) -> bool {
+        self.load_task.is_some()
+    }
+
+    /// Replace the options with loaded ones and reset the highlight
+    fn apply_loaded(&mut self, options: Vec<DropdownOption>) {
+        self.props.options = options;
+        self.props.group_headers.clear();
+        self.highlighted = self
+            .selected_index()
+            .filter(|&i| self.is_enabled(i))
+            .or_else(|| self.step_from(None, true));
+    }
+
+    /// Fetch options for the current search text, from the cache if possible
+    fn load(&mut self, cx: &mut Context<'_, Self>) {
+        let Some(loader) = self.props.loader.clone() else {
+            return;
+        };
+
+        if let Some(options) = self.cache.get(&self.query).cloned() {
+            self.load_task = None;
+            self.apply_loaded(options);
+            return;
+        }
+
+        let query = self.query.clone();
+        let task = loader(query.clone().into(), cx);
+        self.load_task = Some(cx.spawn(async move |this, cx| {
+            let options = task.await;
+            this.update(cx, |this, cx| {
+                this.cache.insert(query.clone(), options.clone());
+                if this.query == query {
+                    this.load_task = None;
+                    this.apply_loaded(options);
+                    cx.notify();
+                }
+            })
+            .ok();
+        }));
+    }
+
+    /// Index of the selected option, if any
+    fn selected_index(&self) -> Option<usize> {
+        let selected = self.props.selected.as_ref()?;
+        self.props.options.iter().position(|o| &o.value == selected)
+    }
+
+    /// Find the next enabled option after `from`, wrapping at the ends
+    ///
+    /// With no starting option, moving forward finds the first enabled
+    /// option and moving backward the last. With no other enabled option,
+    /// `from` is kept.
+    fn step_from(&self, from: Option<usize>, forward: bool) -> Option<usize> {
+        RovingFocus::new(RovingOrientation::Vertical).step(
+            from,
+            forward,
+            self.props.options.len(),
+            |index| self.is_enabled(index),
+        )
+    }
+
+    /// Find the first enabled option at or after `start` (wrapping) whose
+    /// label starts with `query`, ignoring case
+    fn typeahead_match(&self, query: &str, start: usize) -> Option<usize> {
+        let len = self.props.options.len();
+        let query = query.to_lowercase();
+        (0..len)
+            .map(|offset| (start + offset) % len)
+            .find(|&i| {
+                self.is_enabled(i)
+                    && self.props.options[i].label.to_lowercase().starts_with(&query)
+            })
+    }
+
+    /// Open or close the menu
+    fn set_open(&mut self, open: bool, cx: &mut Context<'_, Self>) {
+        if self.props.disabled && open {
+            return;
+        }
+
+        self.props.open = open;
+        self.query.clear();
+        self.highlighted = if open {
+            self.selected_index()
+                .filter(|&i| self.is_enabled(i))
+                .or_else(|| self.step_from(None, true))
+        } else {
+            None
+        };
+        if open {
+            self.list_state.scroll_to_top();
+            self.scroll_to_highlighted(cx);
+            self.load(cx);
+        } else {
+            self.load_task = None;
+        }
+        cx.notify();
+    }
+
+    /// Move the highlight to the option typed so far
+    fn typeahead(&mut self, key: &str, cx: &mut Context<'_, Self>) {
+        let now = Instant::now();
+        if self
+            .typeahead_at
+            .map_or(true, |at| now.duration_since(at) > TYPEAHEAD_TIMEOUT)
+        {
+            self.typeahead.clear();
+        }
+        self.typeahead.push_str(key);
+        self.typeahead_at = Some(now);
+
+        // Repeating one letter cycles through options starting with it
+        let single_letter = self.typeahead.chars().all(|c| key.starts_with(c));
+        let query = if single_letter { key } else { self.typeahead.as_str() };
+        let start = match self.highlighted {
+            Some(i) if single_letter => i + 1,
+            Some(i) => i,
+            None => 0,
+        };
+
+        if let Some(index) = self.typeahead_match(query, start) {
+            if !self.props.open {
+                self.set_open(true, cx);
+            }
+            self.highlighted = Some(index);
+            cx.notify();
+        }
+    }
+
+    /// Handle navigation, selection and type-ahead keys
+    fn handle_key(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.props.disabled {
+            return;
+        }
+
+        let keystroke = &event.keystroke;
+        let is_text = keystroke.key.chars().count() == 1
+            && !keystroke.modifiers.control
+            && !keystroke.modifiers.alt
+            && !keystroke.modifiers.platform;
+
+        match keystroke.key.as_str() {
+            "down" | "up" if !self.props.open => self.set_open(true, cx),
+            "down" => self.highlighted = self.step_from(self.highlighted, true),
+            "up" => self.highlighted = self.step_from(self.highlighted, false),
+            "home" if self.props.open => self.highlighted = self.step_from(None, true),
+            "end" if self.props.open => self.highlighted = self.step_from(None, false),
+            "space" if self.props.open && self.props.searchable => {
+                self.set_query(format!("{} ", self.query), cx);
+            }
+            "enter" | "space" if self.props.open => {
+                let value = self
+                    .highlighted
+                    .and_then(|i| self.props.options.get(i))
+                    .map(|option| option.value.clone());
+                if let Some(value) = value {
+                    self.select(value, window, cx);
+                }
+            }
+            "enter" | "space" => self.set_open(true, cx),
+            "escape" if self.props.open => self.set_open(false, cx),
+            "backspace" if self.props.open && self.props.searchable => {
+                let mut query = self.query.clone();
+                query.pop();
+                self.set_query(query, cx);
+            }
+            key if is_text && self.props.open && self.props.searchable => {
+                self.set_query(format!("{}{key}", self.query), cx);
+            }
+            key if is_text => self.typeahead(key, cx),
+            _ => return,
+        }
+
+        self.scroll_to_highlighted(cx);
+        cx.notify();
+        cx.stop_propagation();
+    }
+
+    /// Select an option chosen in the menu, close it and notify the handlers
+    fn select(&mut self, value: SharedString, window: &mut Window, cx: &mut Context<'_, Self>) {
+        self.picked = self
+            .props
+            .options
+            .iter()
+            .find(|option| option.value == value)
+            .map(|option| (value.clone(), option.label.clone()));
+        self.set_open(false, cx);
+
+        if let Some(handler) = self.props.on_select.clone() {
+            handler(value.clone(), window, cx);
+        }
+
+        if self.props.selected.as_ref() == Some(&value) {
+            return;
+        }
+
+        if !self.props.controlled {
+            self.set_selected(Some(value.clone()), cx);
+        }
+
+        if let Some(handler) = self.props.on_change.clone() {
+            handler(value, window, cx);
+        }
+    }
+
+    /// Rows of the open menu: group headers and the options matching the search
+    fn menu_rows(&self) -> Vec<MenuRow> {
+        let mut rows = Vec::new();
+        for index in 0..self.props.options.len() {
+            // Group header, hidden when the search filters out the whole group
+            let header = self.props.group_headers.iter().find(|(start, _)| *start == index);
+            if let Some((start, label)) = header {
+                if self.group_range(*start).any(|i| self.option_match(i).is_some()) {
+                    rows.push(MenuRow::Header {
+                        label: label.clone(),
+                        separated: !rows.is_empty(),
+                    });
+                }
+            }
+
+            if let Some(matched) = self.option_match(index) {
+                rows.push(MenuRow::Item { index, matched });
+            }
+        }
+        rows
+    }
+
+    /// Scroll the menu so the highlighted option is visible
+    fn scroll_to_highlighted(&self, cx: &App) {
+        let Some(highlighted) = self.highlighted else {
+            return;
+        };
+        let position = self
+            .menu_rows()
+            .iter()
+            .position(|row| matches!(row, MenuRow::Item { index, .. } if *index == highlighted));
+        if let Some(position) = position {
+            let row_height = use_theme(cx).density.scale(px(MENU_ROW_HEIGHT));
+            self.list_state.scroll_to_item(position, row_height);
+        }
+    }
+
+    /// Render one row of the open menu
+    fn render_row(&self, row: &MenuRow, theme: &Theme, cx: &mut Context<'_, Self>) -> AnyElement {
+        let (index, matched) = match row {
+            MenuRow::Header { label, separated } => {
+                return div()
+                    .size_full()
+                    .flex()
+                    .items_end()
+                    .px(theme.global.spacing_md)
+                    .pb(theme.global.spacing_xs)
+                    .when(*separated, |this| {
+                        this.border_t(px(1.0)).border_color(theme.alias.color_border)
+                    })
+                    .text_size(theme.global.font_size_xs)
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .text_color(theme.alias.color_text_muted)
+                    .child(label.clone())
+                    .into_any_element();
+            }
+            MenuRow::Item { index, matched } => (*index, matched),
+        };
+
+        let option = &self.props.options[index];
+        let is_selected = self.props.selected.as_ref() == Some(&option.value);
+        let is_highlighted = self.highlighted == Some(index);
+
+        let mut option_item = div()
+            .id(("dropdown-option", index))
+            .size_full()
+            .px(theme.global.spacing_md)
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap(theme.global.spacing_sm)
+            .cursor_pointer();
+
+        if is_selected {
+            option_item = option_item
+                .bg(theme.alias.color_primary)
+                .text_color(hsla(0.0, 0.0, 1.0, 1.0)); // white
+        } else if option.disabled {
+            option_item = option_item
+                .cursor_not_allowed()
+                .opacity(0.5);
+        } else if is_highlighted {
+            option_item = option_item.bg(theme.alias.color_surface_hover);
+        }
+
+        if is_highlighted {
+            option_item = option_item
+                .border_l(px(2.0))
+                .border_color(theme.alias.color_border_focus);
+        }
+
+        if !option.disabled {
+            let value = option.value.clone();
+            option_item = option_item
+                .on_hover(cx.listener(move |this, hovered: &bool, _window, cx| {
+                    if *hovered && this.highlighted != Some(index) {
+                        this.highlighted = Some(index);
+                        cx.notify();
+                    }
+                }))
+                .on_click(cx.listener(move |this, _event: &ClickEvent, window, cx| {
+                    this.select(value.clone(), window, cx);
+                }));
+        }
+
+        // Add icon if present
+        if let Some(icon_path) = option.icon {
+            option_item = option_item.child(Icon::new(icon_path).to_element(theme));
+        }
+
+        // Add label, emphasizing the part matching the search text
+        option_item = if matched.is_empty() {
+            option_item.child(
+                Label::new(option.label.clone())
+                    .variant(LabelVariant::Body)
+            )
+        } else {
+            let label = option.label.as_ref();
+            option_item.child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .text_size(theme.global.font_size_base)
+                    .child(SharedString::from(label[..matched.start].to_string()))
+                    .child(
+                        div()
+                            .font_weight(FontWeight::BOLD)
+                            .when(!is_selected, |this| {
+                                this.text_color(theme.alias.color_primary)
+                            })
+                            .child(SharedString::from(label[matched.clone()].to_string())),
+                    )
+                    .child(SharedString::from(label[matched.end..].to_string())),
+            )
+        };
+
+        option_item.into_any_element()
+    }
 }
 
 impl Render for Dropdown {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
-        let theme = Theme::default();
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        let focus_handle = self
+            .focus_handle
+            .get_or_insert_with(|| cx.focus_handle())
+            .clone();
 
         // Get selected option label or placeholder
         let display_text = if let Some(ref selected_value) = self.props.selected {
@@ -288,6 +1002,12 @@ impl Render for Dropdown {
                 .iter()
                 .find(|opt| opt.value == *selected_value)
                 .map(|opt| opt.label.clone())
+                .or_else(|| {
+                    self.picked
+                        .as_ref()
+                        .filter(|(value, _)| value == selected_value)
+                        .map(|(_, label)| label.clone())
+                })
                 .unwrap_or(self.props.placeholder.clone())
         } else {
             self.props.placeholder.clone()
@@ -295,6 +1015,7 @@ impl Render for Dropdown {
 
         // Build dropdown trigger button
         let mut trigger = div()
+            .id("dropdown-trigger")
             .px(theme.global.spacing_md)
             .py(theme.global.spacing_sm)
             .rounded(theme.global.radius_md)
@@ -332,6 +1053,10 @@ impl Render for Dropdown {
             trigger = trigger
                 .cursor_not_allowed()
                 .opacity(0.5);
+        } else {
+            trigger = trigger.on_click(cx.listener(|this, _event: &ClickEvent, _window, cx| {
+                this.set_open(!this.props.open, cx);
+            }));
         }
 
         // Add display text and chevron icon
@@ -347,22 +1072,26 @@ impl Render for Dropdown {
             )
             .child(
                 Icon::new(icons::ARROW_DOWN)
-            );
+            )
+            .child(self.anchor.measure_anchor());
 
         // Build container that holds both trigger and dropdown menu
         let mut container = div()
+            .id("dropdown")
+            .track_focus(&focus_handle)
+            .on_key_down(cx.listener(Self::handle_key))
             .relative()
             .child(trigger);
 
         // Add dropdown menu if open
         if self.props.open {
+            // At least as wide as the trigger
+            let min_width = self
+                .anchor
+                .anchor_bounds()
+                .map_or(px(200.0), |bounds| bounds.size.width);
             let mut menu = div()
-                .absolute()
-                .top(px(40.0)) // Below trigger
-                .left(px(0.0))
-                .min_w(px(200.0))
-                .max_h(px(300.0))
-                .overflow_y_scroll()
+                .min_w(min_width)
                 .bg(theme.alias.color_surface)
                 .border(px(1.0))
                 .border_color(theme.alias.color_border)
@@ -372,49 +1101,89 @@ impl Render for Dropdown {
                 .flex_col()
                 .py(px(4.0));
 
-            // Add options
-            for option in &self.props.options {
-                let is_selected = self.props.selected.as_ref() == Some(&option.value);
-
-                let mut option_item = div()
-                    .px(theme.global.spacing_md)
-                    .py(theme.global.spacing_sm)
-                    .flex()
-                    .flex_row()
-                    .items_center()
-                    .gap(theme.global.spacing_sm)
-                    .cursor_pointer();
-
-                if is_selected {
-                    option_item = option_item
-                        .bg(theme.alias.color_primary)
-                        .text_color(hsla(0.0, 0.0, 1.0, 1.0)); // white
-                } else if option.disabled {
-                    option_item = option_item
-                        .cursor_not_allowed()
-                        .opacity(0.5);
+            // Search field showing the typed filter text
+            if self.props.searchable {
+                let search_text = if self.query.is_empty() {
+                    div()
+                        .text_color(theme.alias.color_text_muted)
+                        .child("Search...")
                 } else {
-                    option_item = option_item
-                        .hover(|style| {
-                            style.bg(theme.alias.color_background_hover)
-                        });
-                }
+                    div()
+                        .text_color(theme.alias.color_text_primary)
+                        .child(SharedString::from(self.query.clone()))
+                };
 
-                // Add icon if present
-                if let Some(icon_path) = option.icon {
-                    option_item = option_item.child(Icon::new(icon_path));
-                }
-
-                // Add label
-                option_item = option_item.child(
-                    Label::new(option.label.clone())
-                        .variant(LabelVariant::Body)
+                menu = menu.child(
+                    div()
+                        .mx(theme.global.spacing_sm)
+                        .mb(px(4.0))
+                        .px(theme.global.spacing_sm)
+                        .py(theme.global.spacing_xs)
+                        .flex()
+                        .flex_row()
+                        .items_center()
+                        .gap(theme.global.spacing_sm)
+                        .border(px(1.0))
+                        .border_color(theme.alias.color_border_focus)
+                        .rounded(theme.global.radius_sm)
+                        .text_size(theme.global.font_size_sm)
+                        .child(
+                            Icon::new(icons::SEARCH)
+                                .size(IconSize::Sm)
+                                .custom_color(theme.alias.color_text_muted)
+                                .to_element(&theme),
+                        )
+                        .child(search_text),
                 );
+            }
 
-                menu = menu.child(option_item);
+            // Options, virtualized so long lists only render visible rows
+            let rows = self.menu_rows();
+            let has_matches = rows.iter().any(|row| matches!(row, MenuRow::Item { .. }));
+            menu = menu.child(
+                VirtualList::new(
+                    "dropdown-options",
+                    rows.len(),
+                    theme.density.scale(px(MENU_ROW_HEIGHT)),
+                    &self.list_state,
+                )
+                .max_height(px(300.0))
+                .to_element(|row| self.render_row(&rows[row], &theme, cx)),
+            );
+
+            // Spinner while the loader runs, otherwise an empty state when
+            // the search text filters out every option
+            if self.is_loading() {
+                menu = menu.child(
+                    div()
+                        .px(theme.global.spacing_md)
+                        .py(theme.global.spacing_sm)
+                        .flex()
+                        .flex_row()
+                        .items_center()
+                        .gap(theme.global.spacing_sm)
+                        .text_color(theme.alias.color_text_muted)
+                        .child(
+                            Spinner::new()
+                                .size(SpinnerSize::Sm)
+                                .animated(!reduced_motion(cx))
+                                .to_element(&theme),
+                        )
+                        .child("Loading..."),
+                );
+            } else if !has_matches {
+                menu = menu.child(
+                    div()
+                        .px(theme.global.spacing_md)
+                        .py(theme.global.spacing_sm)
+                        .text_color(theme.alias.color_text_muted)
+                        .child(self.props.empty_message.clone()),
+                );
             }
 
-            container = container.child(menu);
+            let options = AnchorOptions::new(Placement::Bottom).align(PlacementAlign::Start);
+            let position = self.anchor.position(&options, window);
+            container = container.child(self.anchor.overlay(position, menu));
         }
 
         container
@@ -478,5 +1247,120 @@ mod tests {
         assert_eq!(dropdown.props.variant, DropdownVariant::Filled);
         assert!(dropdown.props.searchable);
         assert!(dropdown.props.multiple);
+        assert!(dropdown.props.controlled);
+    }
+
+    #[test]
+    fn test_dropdown_default_selected_is_uncontrolled() {
+        let dropdown = Dropdown::new().default_selected("opt2");
+        assert_eq!(dropdown.props.selected.as_ref().unwrap().as_ref(), "opt2");
+        assert!(!dropdown.props.controlled);
+    }
+
+    fn fruits() -> Dropdown {
+        Dropdown::new().options(vec![
+            DropdownOption::new("Apple", "apple"),
+            DropdownOption::new("Apricot", "apricot").disabled(true),
+            DropdownOption::new("Banana", "banana"),
+            DropdownOption::new("Blueberry", "blueberry"),
+        ])
+    }
+
+    #[test]
+    fn test_step_skips_disabled_and_wraps() {
+        let dropdown = fruits();
+        assert_eq!(dropdown.step_from(None, true), Some(0));
+        assert_eq!(dropdown.step_from(None, false), Some(3));
+        assert_eq!(dropdown.step_from(Some(0), true), Some(2));
+        assert_eq!(dropdown.step_from(Some(2), false), Some(0));
+        assert_eq!(dropdown.step_from(Some(3), true), Some(0));
+    }
+
+    #[test]
+    fn test_typeahead_match() {
+        let dropdown = fruits();
+        assert_eq!(dropdown.typeahead_match("b", 0), Some(2));
+        assert_eq!(dropdown.typeahead_match("b", 3), Some(3));
+        assert_eq!(dropdown.typeahead_match("B", 4), Some(2));
+        assert_eq!(dropdown.typeahead_match("blu", 0), Some(3));
+        // Disabled options are never matched
+        assert_eq!(dropdown.typeahead_match("apr", 0), None);
+        assert_eq!(dropdown.typeahead_match("z", 0), None);
+    }
+
+    #[test]
+    fn test_find_match() {
+        assert_eq!(find_match("United Kingdom", "king"), Some(7..11));
+        assert_eq!(find_match("United Kingdom", "UNI"), Some(0..3));
+        assert_eq!(find_match("Canada", ""), Some(0..0));
+        assert_eq!(find_match("Canada", "x"), None);
+        assert_eq!(find_match("Éire", "éi"), Some(0..3));
+    }
+
+    #[test]
+    fn test_search_filters_navigation() {
+        let mut dropdown = fruits().searchable(true);
+        dropdown.query = "an".into();
+        assert!(!dropdown.is_enabled(0));
+        assert!(dropdown.is_enabled(2));
+        assert_eq!(dropdown.step_from(None, true), Some(2));
+        assert_eq!(dropdown.step_from(Some(2), true), Some(2));
+
+        dropdown.query = "zzz".into();
+        assert_eq!(dropdown.step_from(None, true), None);
+    }
+
+    #[test]
+    fn test_groups_flatten_into_options() {
+        let dropdown = Dropdown::new()
+            .options(vec![DropdownOption::new("Any", "any")])
+            .groups(vec![
+                DropdownOptionGroup::new("Fruits", vec![
+                    DropdownOption::new("Apple", "apple"),
+                    DropdownOption::new("Banana", "banana"),
+                ]),
+                DropdownOptionGroup::new("Vegetables", vec![
+                    DropdownOption::new("Carrot", "carrot"),
+                ]),
+            ]);
+
+        assert_eq!(dropdown.props.options.len(), 4);
+        assert_eq!(dropdown.props.group_headers.len(), 2);
+        assert_eq!(dropdown.props.group_headers[1].0, 3);
+        assert_eq!(dropdown.group_range(1), 1..3);
+        assert_eq!(dropdown.group_range(3), 3..4);
+        // Headers are not options, so navigation moves straight between groups
+        assert_eq!(dropdown.step_from(Some(2), true), Some(3));
+    }
+
+    #[test]
+    fn test_menu_rows_hide_empty_groups() {
+        let mut dropdown = Dropdown::new().searchable(true).groups(vec![
+            DropdownOptionGroup::new("Fruits", vec![DropdownOption::new("Apple", "apple")]),
+            DropdownOptionGroup::new("Vegetables", vec![DropdownOption::new("Carrot", "carrot")]),
+        ]);
+        assert_eq!(dropdown.menu_rows(), vec![
+            MenuRow::Header { label: "Fruits".into(), separated: false },
+            MenuRow::Item { index: 0, matched: 0..0 },
+            MenuRow::Header { label: "Vegetables".into(), separated: true },
+            MenuRow::Item { index: 1, matched: 0..0 },
+        ]);
+
+        dropdown.query = "car".into();
+        assert_eq!(dropdown.menu_rows(), vec![
+            MenuRow::Header { label: "Vegetables".into(), separated: false },
+            MenuRow::Item { index: 1, matched: 0..3 },
+        ]);
+    }
+
+    #[test]
+    fn test_loader_skips_local_filtering() {
+        let mut dropdown = fruits()
+            .searchable(true)
+            .loader(|_query, _cx| Task::ready(Vec::new()));
+        dropdown.query = "nana".into();
+        assert_eq!(dropdown.option_match(0), Some(0..0));
+        assert_eq!(dropdown.option_match(2), Some(2..6));
+        assert!(dropdown.is_enabled(3));
     }
 }
@@ -0,0 +1,713 @@
+//! FileDropZone component for picking files by browsing or drag and drop.
+
+use std::{
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use gpui::*;
+use crate::{
+    atoms::{icons, Icon, IconColor, IconSize, Label, LabelVariant},
+    theme::{use_theme, Theme},
+};
+
+/// Why a file was not added to a FileDropZone
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileRejectReason {
+    /// The extension is not in the accepted list
+    UnsupportedType,
+    /// The file is larger than the size limit
+    TooLarge,
+    /// Adding the file would exceed the file limit
+    TooMany,
+    /// The file could not be read
+    Unreadable,
+}
+
+/// A file that failed validation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileRejection {
+    /// Path of the rejected file
+    pub path: PathBuf,
+    /// Why the file was rejected
+    pub reason: FileRejectReason,
+}
+
+impl FileRejection {
+    /// Message describing the rejection, e.g. `photo.bmp: unsupported file type`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let messages: Vec<_> = rejections.iter().map(FileRejection::message).collect();
+    /// ```
+    pub fn message(&self) -> SharedString {
+        let reason = match self.reason {
+            FileRejectReason::UnsupportedType => "unsupported file type",
+            FileRejectReason::TooLarge => "file is too large",
+            FileRejectReason::TooMany => "too many files",
+            FileRejectReason::Unreadable => "file could not be read",
+        };
+        format!("{}: {reason}", file_name(&self.path)).into()
+    }
+}
+
+/// A file selected in a FileDropZone
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectedFile {
+    /// Path of the file
+    pub path: PathBuf,
+    /// Size in bytes
+    pub size: u64,
+    /// Upload progress from 0.0 to 1.0, `None` when not uploading
+    pub progress: Option<f32>,
+    /// Upload error shown under the file
+    pub error: Option<SharedString>,
+}
+
+/// FileDropZone configuration properties
+#[derive(Clone)]
+pub struct FileDropZoneProps {
+    /// Accepted extensions without the dot, e.g. `png`; empty accepts all
+    pub accept: Vec<SharedString>,
+    /// Largest accepted file in bytes
+    pub max_size: Option<u64>,
+    /// Whether several files can be selected
+    pub multiple: bool,
+    /// Most files the list can hold
+    pub max_files: Option<usize>,
+    /// Prompt shown in the drop zone
+    pub label: SharedString,
+    /// Whether the drop zone is disabled
+    pub disabled: bool,
+    /// Handler called with newly added files
+    pub on_add: Option<Rc<dyn Fn(Vec<PathBuf>, &mut Window, &mut App)>>,
+    /// Handler called when the user removes a file
+    pub on_remove: Option<Rc<dyn Fn(PathBuf, &mut Window, &mut App)>>,
+    /// Handler called with files that failed validation
+    pub on_reject: Option<Rc<dyn Fn(Vec<FileRejection>, &mut Window, &mut App)>>,
+}
+
+impl Default for FileDropZoneProps {
+    fn default() -> Self {
+        Self {
+            accept: Vec::new(),
+            max_size: None,
+            multiple: true,
+            max_files: None,
+            label: "Drop files here or click to browse".into(),
+            disabled: false,
+            on_add: None,
+            on_remove: None,
+            on_reject: None,
+        }
+    }
+}
+
+/// A drop zone for selecting files with a list of the selected files.
+///
+/// Clicking the zone opens the native file dialog; files dragged in from
+/// the OS are accepted too. Files are checked against the accepted types,
+/// size limit and file limit before they join the list.
+///
+/// ## Features
+///
+/// - Click to browse and external drag and drop
+/// - Extension and size validation with rejection messages
+/// - File list with remove buttons
+/// - Per-file upload progress and errors via [`FileDropZone::set_progress`]
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::molecules::*;
+///
+/// let zone = cx.new(|_| {
+///     FileDropZone::new()
+///         .accept(["png", "jpg"])
+///         .max_size(5 * 1024 * 1024)
+///         .on_add(|paths, _window, cx| start_upload(paths, cx))
+/// });
+///
+/// // Report progress while uploading
+/// zone.update(cx, |zone, cx| zone.set_progress(&path, Some(0.4), cx));
+/// ```
+///
+/// ## Accessibility
+///
+/// - The drop zone is focusable and opens the dialog with Enter or Space
+/// - Rejections are listed as text, not only signalled by color
+pub struct FileDropZone {
+    props: FileDropZoneProps,
+    files: Vec<SelectedFile>,
+    /// Rejections from the most recent add
+    rejections: Vec<FileRejection>,
+    focus_handle: Option<FocusHandle>,
+    browse_task: Option<Task<()>>,
+}
+
+impl FileDropZone {
+    /// Create a new drop zone
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let zone = FileDropZone::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            props: FileDropZoneProps::default(),
+            files: Vec::new(),
+            rejections: Vec::new(),
+            focus_handle: None,
+            browse_task: None,
+        }
+    }
+
+    /// Set the accepted file extensions
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FileDropZone::new().accept(["png", "jpg", "gif"]);
+    /// ```
+    pub fn accept<S: Into<SharedString>>(mut self, extensions: impl IntoIterator<Item = S>) -> Self {
+        self.props.accept = extensions.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the largest accepted file in bytes
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FileDropZone::new().max_size(10 * 1024 * 1024);
+    /// ```
+    pub fn max_size(mut self, bytes: u64) -> Self {
+        self.props.max_size = Some(bytes);
+        self
+    }
+
+    /// Set whether several files can be selected
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FileDropZone::new().multiple(false);
+    /// ```
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.props.multiple = multiple;
+        self
+    }
+
+    /// Set the most files the list can hold
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FileDropZone::new().max_files(5);
+    /// ```
+    pub fn max_files(mut self, max: usize) -> Self {
+        self.props.max_files = Some(max);
+        self
+    }
+
+    /// Set the prompt shown in the drop zone
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FileDropZone::new().label("Drop your resume here");
+    /// ```
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.props.label = label.into();
+        self
+    }
+
+    /// Set whether the drop zone is disabled
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FileDropZone::new().disabled(true);
+    /// ```
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.props.disabled = disabled;
+        self
+    }
+
+    /// Set the handler called with newly added files
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FileDropZone::new().on_add(|paths, _window, cx| start_upload(paths, cx));
+    /// ```
+    pub fn on_add(mut self, handler: impl Fn(Vec<PathBuf>, &mut Window, &mut App) + 'static) -> Self {
+        self.props.on_add = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the handler called when the user removes a file
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FileDropZone::new().on_remove(|path, _window, cx| cancel_upload(&path, cx));
+    /// ```
+    pub fn on_remove(mut self, handler: impl Fn(PathBuf, &mut Window, &mut App) + 'static) -> Self {
+        self.props.on_remove = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the handler called with files that failed validation
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FileDropZone::new().on_reject(|rejections, _window, _cx| log_rejections(&rejections));
+    /// ```
+    pub fn on_reject(
+        mut self,
+        handler: impl Fn(Vec<FileRejection>, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_reject = Some(Rc::new(handler));
+        self
+    }
+
+    /// Selected files in the order they were added
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let count = zone.read(cx).files().len();
+    /// ```
+    pub fn files(&self) -> &[SelectedFile] {
+        &self.files
+    }
+
+    /// Open the native file dialog and add the chosen files
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// zone.update(cx, |zone, cx| zone.browse(window, cx));
+    /// ```
+    pub fn browse(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.props.disabled {
+            return;
+        }
+
+        let paths = cx.prompt_for_paths(PathPromptOptions {
+            files: true,
+            directories: false,
+            multiple: self.props.multiple,
+            prompt: None,
+        });
+        self.browse_task = Some(cx.spawn_in(window, async move |this, cx| {
+            let Ok(Ok(Some(paths))) = paths.await else {
+                return;
+            };
+            this.update_in(cx, |this, window, cx| this.add_paths(paths, window, cx)).ok();
+        }));
+    }
+
+    /// Validate `paths` and add the accepted ones to the list
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// zone.update(cx, |zone, cx| zone.add_paths(vec![path], window, cx));
+    /// ```
+    pub fn add_paths(&mut self, paths: Vec<PathBuf>, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.props.disabled {
+            return;
+        }
+
+        let paths = if self.props.multiple {
+            paths
+        } else {
+            paths.into_iter().take(1).collect()
+        };
+
+        let mut added = Vec::new();
+        let mut replaced = Vec::new();
+        self.rejections.clear();
+        for path in paths {
+            if self.files.iter().any(|file| file.path == path) {
+                continue;
+            }
+            let size = std::fs::metadata(&path).ok().filter(|meta| meta.is_file()).map(|meta| meta.len());
+            // A single-file zone replaces its file, so it does not count towards the limit
+            let count = if self.props.multiple { self.files.len() } else { 0 };
+            match check_file(&self.props, &path, size, count) {
+                Ok(size) => {
+                    if !self.props.multiple {
+                        replaced.extend(self.files.drain(..).map(|file| file.path));
+                    }
+                    added.push(path.clone());
+                    self.files.push(SelectedFile { path, size, progress: None, error: None });
+                }
+                Err(reason) => self.rejections.push(FileRejection { path, reason }),
+            }
+        }
+        cx.notify();
+
+        if let Some(handler) = self.props.on_remove.clone() {
+            for path in replaced {
+                handler(path, window, cx);
+            }
+        }
+        if !added.is_empty() {
+            if let Some(handler) = self.props.on_add.clone() {
+                handler(added, window, cx);
+            }
+        }
+        if !self.rejections.is_empty() {
+            if let Some(handler) = self.props.on_reject.clone() {
+                handler(self.rejections.clone(), window, cx);
+            }
+        }
+    }
+
+    /// Remove `path` from the list
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// zone.update(cx, |zone, cx| zone.remove(&path, window, cx));
+    /// ```
+    pub fn remove(&mut self, path: &Path, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let Some(index) = self.files.iter().position(|file| file.path == path) else {
+            return;
+        };
+        let file = self.files.remove(index);
+        cx.notify();
+
+        if let Some(handler) = self.props.on_remove.clone() {
+            handler(file.path, window, cx);
+        }
+    }
+
+    /// Remove every file and rejection message
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// zone.update(cx, |zone, cx| zone.clear(cx));
+    /// ```
+    pub fn clear(&mut self, cx: &mut Context<'_, Self>) {
+        self.files.clear();
+        self.rejections.clear();
+        cx.notify();
+    }
+
+    /// Show upload progress for `path`, from 0.0 to 1.0, or hide it with `None`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// zone.update(cx, |zone, cx| zone.set_progress(&path, Some(0.5), cx));
+    /// ```
+    pub fn set_progress(&mut self, path: &Path, progress: Option<f32>, cx: &mut Context<'_, Self>) {
+        if let Some(file) = self.files.iter_mut().find(|file| file.path == path) {
+            file.progress = progress.map(|p| p.clamp(0.0, 1.0));
+            cx.notify();
+        }
+    }
+
+    /// Show an upload error under `path`, or clear it with `None`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// zone.update(cx, |zone, cx| zone.set_file_error(&path, Some("Upload failed".into()), cx));
+    /// ```
+    pub fn set_file_error(&mut self, path: &Path, error: Option<SharedString>, cx: &mut Context<'_, Self>) {
+        if let Some(file) = self.files.iter_mut().find(|file| file.path == path) {
+            file.error = error;
+            cx.notify();
+        }
+    }
+
+    /// Render one row of the file list
+    fn render_file(&self, index: usize, file: &SelectedFile, theme: &Theme, cx: &mut Context<'_, Self>) -> Div {
+        let path = file.path.clone();
+        let mut details = div()
+            .flex()
+            .flex_col()
+            .flex_1()
+            .min_w_0()
+            .gap(theme.global.spacing_xs)
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .justify_between()
+                    .gap(theme.global.spacing_sm)
+                    .child(div().truncate().child(file_name(&file.path)))
+                    .child(
+                        Label::new(format_size(file.size))
+                            .variant(LabelVariant::Caption)
+                            .color(theme.alias.color_text_secondary),
+                    ),
+            );
+
+        if let Some(progress) = file.progress {
+            details = details.child(
+                div()
+                    .h(px(4.0))
+                    .w_full()
+                    .rounded_full()
+                    .bg(theme.alias.color_surface_hover)
+                    .child(
+                        div()
+                            .h_full()
+                            .w(relative(progress))
+                            .rounded_full()
+                            .bg(theme.alias.color_primary),
+                    ),
+            );
+        }
+
+        if let Some(error) = file.error.clone() {
+            details = details.child(
+                Label::new(error)
+                    .variant(LabelVariant::Caption)
+                    .color(theme.alias.color_danger),
+            );
+        }
+
+        div()
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap(theme.global.spacing_sm)
+            .p(theme.global.spacing_sm)
+            .border_1()
+            .border_color(theme.alias.color_border)
+            .rounded(theme.global.radius_md)
+            .child(
+                Icon::new(icons::FILE)
+                    .size(IconSize::Sm)
+                    .color(IconColor::Muted)
+                    .to_element(theme),
+            )
+            .child(details)
+            .child(
+                div()
+                    .id(("file-drop-zone-remove", index))
+                    .flex()
+                    .flex_none()
+                    .items_center()
+                    .justify_center()
+                    .cursor_pointer()
+                    .hover(|style| style.opacity(0.7))
+                    .on_click(cx.listener(move |this, _event: &ClickEvent, window, cx| {
+                        this.remove(&path, window, cx);
+                    }))
+                    .child(
+                        Icon::new(icons::X)
+                            .size(IconSize::Sm)
+                            .color(IconColor::Muted)
+                            .to_element(theme),
+                    ),
+            )
+    }
+}
+
+impl Default for FileDropZone {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Last path component, or the whole path when it has none
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map_or_else(|| path.display().to_string(), |name| name.to_string_lossy().into_owned())
+}
+
+/// Whether `path` has one of the `accept` extensions, ignoring case
+fn matches_accept(path: &Path, accept: &[SharedString]) -> bool {
+    if accept.is_empty() {
+        return true;
+    }
+    let Some(extension) = path.extension().and_then(|ext| ext.to_str()) else {
+        return false;
+    };
+    accept
+        .iter()
+        .any(|accepted| accepted.trim_start_matches('.').eq_ignore_ascii_case(extension))
+}
+
+/// Check a file of `size` bytes (`None` when unreadable) against the limits,
+/// given `count` files already in the list
+fn check_file(
+    props: &FileDropZoneProps,
+    path: &Path,
+    size: Option<u64>,
+    count: usize,
+) -> Result<u64, FileRejectReason> {
+    let size = size.ok_or(FileRejectReason::Unreadable)?;
+    if !matches_accept(path, &props.accept) {
+        return Err(FileRejectReason::UnsupportedType);
+    }
+    if props.max_size.is_some_and(|max| size > max) {
+        return Err(FileRejectReason::TooLarge);
+    }
+    if props.max_files.is_some_and(|max| count >= max) {
+        return Err(FileRejectReason::TooMany);
+    }
+    Ok(size)
+}
+
+/// Human-readable size, e.g. `512 B`, `1.5 KB` or `2.0 MB`
+#[allow(clippy::cast_precision_loss)]
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+impl Render for FileDropZone {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+
+        let focus_handle = self
+            .focus_handle
+            .get_or_insert_with(|| cx.focus_handle())
+            .clone();
+        let focused = focus_handle.is_focused(window);
+
+        let mut hint = Vec::new();
+        if !self.props.accept.is_empty() {
+            let accept: Vec<&str> = self.props.accept.iter().map(AsRef::as_ref).collect();
+            hint.push(accept.join(", "));
+        }
+        if let Some(max) = self.props.max_size {
+            hint.push(format!("up to {}", format_size(max)));
+        }
+
+        let mut zone = div()
+            .id("file-drop-zone")
+            .flex()
+            .flex_col()
+            .items_center()
+            .justify_center()
+            .gap(theme.global.spacing_sm)
+            .p(theme.global.spacing_lg)
+            .border_2()
+            .border_dashed()
+            .border_color(if focused { theme.alias.color_border_focus } else { theme.alias.color_border })
+            .rounded(theme.global.radius_md)
+            .text_color(theme.alias.color_text_secondary)
+            .child(
+                Icon::new(icons::UPLOAD)
+                    .size(IconSize::Lg)
+                    .color(IconColor::Muted)
+                    .to_element(&theme),
+            )
+            .child(self.props.label.clone());
+
+        if !hint.is_empty() {
+            zone = zone.child(Label::new(hint.join(" · ")).variant(LabelVariant::Caption));
+        }
+
+        if self.props.disabled {
+            zone = zone.opacity(0.5).cursor_not_allowed();
+        } else {
+            let drop_background = theme.alias.color_surface_hover;
+            let drop_border = theme.alias.color_primary;
+            zone = zone
+                .track_focus(&focus_handle)
+                .cursor_pointer()
+                .hover(move |style| style.bg(drop_background))
+                .drag_over::<ExternalPaths>(move |style, _paths, _window, _cx| {
+                    style.bg(drop_background).border_color(drop_border)
+                })
+                .on_drop(cx.listener(|this, paths: &ExternalPaths, window, cx| {
+                    this.add_paths(paths.paths().to_vec(), window, cx);
+                }))
+                .on_click(cx.listener(|this, _event: &ClickEvent, window, cx| {
+                    this.browse(window, cx);
+                }));
+        }
+
+        let mut list = div().flex().flex_col().gap(theme.global.spacing_sm).child(zone);
+
+        for rejection in &self.rejections {
+            list = list.child(
+                Label::new(rejection.message())
+                    .variant(LabelVariant::Caption)
+                    .color(theme.alias.color_danger),
+            );
+        }
+
+        let rows: Vec<Div> = self
+            .files
+            .iter()
+            .enumerate()
+            .map(|(index, file)| self.render_file(index, file, &theme, cx))
+            .collect();
+        list.children(rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_drop_zone_defaults() {
+        let zone = FileDropZone::new();
+        assert!(zone.props.multiple);
+        assert!(zone.props.accept.is_empty());
+        assert!(zone.files().is_empty());
+    }
+
+    #[test]
+    fn test_matches_accept() {
+        let accept: Vec<SharedString> = vec!["png".into(), ".JPG".into()];
+        assert!(matches_accept(Path::new("a/photo.PNG"), &accept));
+        assert!(matches_accept(Path::new("photo.jpg"), &accept));
+        assert!(!matches_accept(Path::new("photo.gif"), &accept));
+        assert!(!matches_accept(Path::new("README"), &accept));
+        assert!(matches_accept(Path::new("README"), &[]));
+    }
+
+    #[test]
+    fn test_check_file() {
+        let props = FileDropZone::new().accept(["txt"]).max_size(100).max_files(2).props;
+        let path = Path::new("notes.txt");
+        assert_eq!(check_file(&props, path, Some(10), 0), Ok(10));
+        assert_eq!(check_file(&props, path, None, 0), Err(FileRejectReason::Unreadable));
+        assert_eq!(check_file(&props, Path::new("a.md"), Some(10), 0), Err(FileRejectReason::UnsupportedType));
+        assert_eq!(check_file(&props, path, Some(101), 0), Err(FileRejectReason::TooLarge));
+        assert_eq!(check_file(&props, path, Some(10), 2), Err(FileRejectReason::TooMany));
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KB");
+        assert_eq!(format_size(5 * 1024 * 1024), "5.0 MB");
+    }
+
+    #[test]
+    fn test_rejection_message() {
+        let rejection = FileRejection {
+            path: PathBuf::from("/tmp/photo.bmp"),
+            reason: FileRejectReason::UnsupportedType,
+        };
+        assert_eq!(rejection.message().as_ref(), "photo.bmp: unsupported file type");
+    }
+}
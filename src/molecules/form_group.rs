@@ -1,12 +1,19 @@
-//! FormGroup component combining label, input, and error message.
+//! FormGroup component combining label, form control, and error message.
+
+use std::rc::Rc;
 
 use gpui::*;
 use gpui::prelude::FluentBuilder;
-use crate::{atoms::{Label, LabelVariant, Input}, theme::Theme};
+use crate::{atoms::{Label, LabelVariant, Input, InputType}, theme::use_theme};
+
+/// Renders the form control wrapped by a FormGroup
+pub type FormControlSlot = Rc<dyn Fn(&mut Window, &mut App) -> AnyElement>;
 
 /// FormGroup configuration properties
 #[derive(Clone)]
 pub struct FormGroupProps {
+    /// Base id for the label, control, helper text and error elements
+    pub id: SharedString,
     /// Label text
     pub label: SharedString,
     /// Whether field is required
@@ -19,25 +26,48 @@ pub struct FormGroupProps {
     pub value: SharedString,
     /// Input placeholder
     pub placeholder: SharedString,
+    /// Input type
+    pub input_type: InputType,
+    /// Handler called with the built-in input's value when the user edits it
+    pub on_change: Option<Rc<dyn Fn(SharedString, &mut Window, &mut App)>>,
+    /// Custom control replacing the built-in input
+    pub control: Option<FormControlSlot>,
+    /// Focus handle of the control, focused when the label is clicked
+    pub control_focus: Option<FocusHandle>,
 }
 
 impl Default for FormGroupProps {
     fn default() -> Self {
         Self {
+            id: "form-group".into(),
             label: "".into(),
             required: false,
             helper_text: None,
             error_message: None,
             value: "".into(),
             placeholder: "".into(),
+            input_type: InputType::default(),
+            on_change: None,
+            control: None,
+            control_focus: None,
         }
     }
 }
 
-/// A form group component combining label, input, and validation.
+/// A form group component combining label, form control, and validation.
+///
+/// FormGroup provides a complete form field with label, control, helper
+/// text, and error message display. The control is a text input by default
+/// and can be replaced with any element, such as a Dropdown, a Checkbox
+/// group or a Switch, via [`FormGroup::control`].
+///
+/// ## Accessibility
 ///
-/// FormGroup provides a complete form field with label, input, helper text,
-/// and error message display.
+/// - The label, control, helper text and error message get ids derived
+///   from [`FormGroup::id`], so the control can reference them
+/// - Clicking the label focuses the control registered with
+///   [`FormGroup::control_focus`], like an HTML `<label for>`
+/// - The error message replaces the helper text while present
 ///
 /// ## Example
 ///
@@ -52,6 +82,7 @@ impl Default for FormGroupProps {
 /// // Required field with helper text
 /// FormGroup::new()
 ///     .label("Password")
+///     .input_type(InputType::Password)
 ///     .required(true)
 ///     .helper_text("Must be at least 8 characters");
 ///
@@ -59,6 +90,17 @@ impl Default for FormGroupProps {
 /// FormGroup::new()
 ///     .label("Username")
 ///     .error_message("Username is required");
+///
+/// // Any control in place of the input
+/// let country = cx.new(|_| Dropdown::new().options(countries()));
+/// FormGroup::new()
+///     .id("country")
+///     .label("Country")
+///     .required(true)
+///     .control({
+///         let country = country.clone();
+///         move |_window, _cx| country.clone().into_any_element()
+///     });
 /// ```
 pub struct FormGroup {
     props: FormGroupProps,
@@ -78,6 +120,18 @@ impl FormGroup {
         }
     }
 
+    /// Set the base id used for the label, control, helper text and error elements
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FormGroup::new().id("email").label("Email");
+    /// ```
+    pub fn id(mut self, id: impl Into<SharedString>) -> Self {
+        self.props.id = id.into();
+        self
+    }
+
     /// Set the label text
     ///
     /// ## Example
@@ -149,21 +203,177 @@ impl FormGroup {
         self.props.placeholder = placeholder.into();
         self
     }
+
+    /// Set the input type
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FormGroup::new().label("Email").input_type(InputType::Email);
+    /// ```
+    pub fn input_type(mut self, input_type: InputType) -> Self {
+        self.props.input_type = input_type;
+        self
+    }
+
+    /// Set the handler called when the built-in input is edited
+    ///
+    /// The input is controlled: apply the new value with
+    /// [`FormGroup::set_value`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FormGroup::new().label("Quantity").on_change(|value, _window, cx| save_quantity(value, cx));
+    /// ```
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Replace the built-in input with a custom form control
+    ///
+    /// The value, placeholder, input type and change handler settings only
+    /// apply to the built-in input; the error message is shown for any control.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FormGroup::new().label("Notifications").control(move |_window, _cx| {
+    ///     notifications_switch.clone().into_any_element()
+    /// });
+    /// ```
+    pub fn control(mut self, control: impl Fn(&mut Window, &mut App) -> AnyElement + 'static) -> Self {
+        self.props.control = Some(Rc::new(control));
+        self
+    }
+
+    /// Set the control's focus handle, focused when the label is clicked
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FormGroup::new().label("Country").control_focus(&country_focus);
+    /// ```
+    pub fn control_focus(mut self, handle: &FocusHandle) -> Self {
+        self.props.control_focus = Some(handle.clone());
+        self
+    }
+
+    /// Update the built-in input's value from the parent view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// group.update(cx, |group, cx| group.set_value("me@example.com", cx));
+    /// ```
+    pub fn set_value(&mut self, value: impl Into<SharedString>, cx: &mut Context<'_, Self>) {
+        let value = value.into();
+        if self.props.value != value {
+            self.props.value = value;
+            cx.notify();
+        }
+    }
+
+    /// Show or clear the error message from the parent view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// group.update(cx, |group, cx| group.set_error_message(Some("Required".into()), cx));
+    /// ```
+    pub fn set_error_message(&mut self, message: Option<SharedString>, cx: &mut Context<'_, Self>) {
+        if self.props.error_message != message {
+            self.props.error_message = message;
+            cx.notify();
+        }
+    }
+
+    /// Id of the label element
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// assert_eq!(FormGroup::new().id("email").label_id(), "email-label");
+    /// ```
+    pub fn label_id(&self) -> SharedString {
+        format!("{}-label", self.props.id).into()
+    }
+
+    /// Id of the element wrapping the control
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// assert_eq!(FormGroup::new().id("email").control_id(), "email-control");
+    /// ```
+    pub fn control_id(&self) -> SharedString {
+        format!("{}-control", self.props.id).into()
+    }
+
+    /// Id of the helper text element
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// assert_eq!(FormGroup::new().id("email").helper_id(), "email-helper");
+    /// ```
+    pub fn helper_id(&self) -> SharedString {
+        format!("{}-helper", self.props.id).into()
+    }
+
+    /// Id of the error message element
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// assert_eq!(FormGroup::new().id("email").error_id(), "email-error");
+    /// ```
+    pub fn error_id(&self) -> SharedString {
+        format!("{}-error", self.props.id).into()
+    }
+}
+
+impl Default for FormGroup {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Render for FormGroup {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
-        let theme = Theme::default();
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
         let has_error = self.props.error_message.is_some();
 
+        // Custom control, or the built-in input
+        let control = match self.props.control.clone() {
+            Some(control) => control(window, cx),
+            None => {
+                let mut input = Input::new()
+                    .value(self.props.value.clone())
+                    .placeholder(self.props.placeholder.clone())
+                    .input_type(self.props.input_type)
+                    .error(has_error);
+                if let Some(handler) = self.props.on_change.clone() {
+                    input = input.on_change(move |value, window, cx| handler(value, window, cx));
+                }
+                input.into_any_element()
+            }
+        };
+
         // Build form group container
         div()
+            .id(ElementId::Name(self.props.id.clone()))
             .flex()
             .flex_col()
             .gap(theme.global.spacing_xs)
             .child(
                 // Label with optional required indicator
                 div()
+                    .id(ElementId::Name(self.label_id()))
                     .flex()
                     .flex_row()
                     .gap(px(4.0))
@@ -178,23 +388,69 @@ impl Render for FormGroup {
                                 .color(theme.alias.color_danger)
                         )
                     })
+                    // Clicking the label focuses its control
+                    .when_some(self.props.control_focus.clone(), |div, handle| {
+                        div.cursor_pointer().on_click(move |_event, window, _cx| {
+                            window.focus(&handle);
+                        })
+                    })
             )
             .child(
-                // Input field
-                Input::new()
-                    .value(self.props.value.clone())
-                    .placeholder(self.props.placeholder.clone())
-                    .error(has_error)
-                    .when_some(self.props.error_message.clone(), |input, msg| {
-                        input.error_message(msg)
-                    })
+                // Form control
+                div()
+                    .id(ElementId::Name(self.control_id()))
+                    .child(control)
             )
-            .when_some(self.props.helper_text.clone(), |div, text| {
-                div.child(
-                    Label::new(text)
-                        .variant(LabelVariant::Caption)
-                        .color(theme.alias.color_text_muted)
-                )
+            .map(|div| match (self.props.error_message.clone(), self.props.helper_text.clone()) {
+                (Some(message), _) => div.child(
+                    div()
+                        .id(ElementId::Name(self.error_id()))
+                        .child(
+                            Label::new(message)
+                                .variant(LabelVariant::Caption)
+                                .color(theme.alias.color_danger)
+                        )
+                ),
+                (None, Some(text)) => div.child(
+                    div()
+                        .id(ElementId::Name(self.helper_id()))
+                        .child(
+                            Label::new(text)
+                                .variant(LabelVariant::Caption)
+                                .color(theme.alias.color_text_muted)
+                        )
+                ),
+                (None, None) => div,
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_form_group_defaults() {
+        let form_group = FormGroup::new();
+        assert_eq!(form_group.props.id.as_ref(), "form-group");
+        assert!(form_group.props.control.is_none());
+        assert!(form_group.props.control_focus.is_none());
+    }
+
+    #[test]
+    fn test_form_group_ids() {
+        let form_group = FormGroup::new().id("email");
+        assert_eq!(form_group.label_id().as_ref(), "email-label");
+        assert_eq!(form_group.control_id().as_ref(), "email-control");
+        assert_eq!(form_group.helper_id().as_ref(), "email-helper");
+        assert_eq!(form_group.error_id().as_ref(), "email-error");
+    }
+
+    #[test]
+    fn test_form_group_custom_control() {
+        let form_group = FormGroup::new()
+            .label("Country")
+            .control(|_window, _cx| div().into_any_element());
+        assert!(form_group.props.control.is_some());
+    }
+}
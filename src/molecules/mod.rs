@@ -5,12 +5,23 @@
 //! ## Available Molecules
 //!
 //! - [`SearchBar`]: Search input with icon and clear button
-//! - [`FormGroup`]: Label + Input + Error message combination
+//! - [`FormGroup`]: Label + form control + error message combination
 //! - [`Card`]: Content card container with variants
 //! - [`TabGroup`]: Tabbed navigation with keyboard support
+//! - [`TabPanels`]: Lazily mounted content for each tab of a [`TabGroup`]
 //! - [`Dropdown`]: Select menu with search and multi-select support
-//! - [`Tooltip`]: Contextual information on hover/focus
+//! - [`Tooltip`]: Contextual information on hover/focus, attached with [`TooltipExt::with_tooltip`]
 //! - [`Popover`]: Click-triggered overlay with rich content
+//! - [`RadioGroup`]: Single selection from a list of radio options
+//! - [`Accordion`]: Collapsible sections with single or multi-expand modes
+//! - [`Pagination`]: Page navigation with ellipses and a jump-to-page field
+//! - [`TimePicker`]: Time of day entry with 12/24-hour modes and min/max validation
+//! - [`DateTimePicker`]: Date field and [`TimePicker`] sharing one value and min/max range
+//! - [`FileDropZone`]: File selection by browsing or drag and drop, with validation and upload progress
+//! - [`TagInput`]: Tag editor that turns typed text into removable chips
+//! - [`Stat`]: Headline metric with a change indicator and optional trend chart
+//! - [`ButtonGroup`]: Attached buttons with single or multiple toggle selection
+//! - [`Toolbar`]: Row of actions with separators that overflows into a menu
 //!
 //! ## Example
 //!
@@ -20,7 +31,7 @@
 //! // Search bar
 //! SearchBar::new()
 //!     .placeholder("Search...")
-//!     .on_search(|query| { /* handle search */ });
+//!     .on_search(|query, _window, _cx| { /* handle search */ });
 //!
 //! // Form group
 //! FormGroup::new()
@@ -38,14 +49,36 @@ pub mod search_bar;
 pub mod form_group;
 pub mod card;
 pub mod tab_group;
+pub mod tab_panels;
 pub mod dropdown;
 pub mod tooltip;
 pub mod popover;
+pub mod radio_group;
+pub mod accordion;
+pub mod pagination;
+pub mod time_picker;
+pub mod date_time_picker;
+pub mod file_drop_zone;
+pub mod tag_input;
+pub mod stat;
+pub mod button_group;
+pub mod toolbar;
 
-pub use search_bar::{SearchBar, SearchBarProps};
-pub use form_group::{FormGroup, FormGroupProps};
-pub use card::{Card, CardProps, CardVariant};
+pub use search_bar::{SearchBar, SearchBarProps, SearchMode};
+pub use form_group::{FormControlSlot, FormGroup, FormGroupProps};
+pub use card::{Card, CardProps, CardSlot, CardVariant};
 pub use tab_group::{TabGroup, TabGroupProps, TabGroupVariant, Tab};
-pub use dropdown::{Dropdown, DropdownProps, DropdownVariant, DropdownOption};
-pub use tooltip::{Tooltip, TooltipProps, TooltipPosition};
-pub use popover::{Popover, PopoverProps, PopoverPosition};
+pub use tab_panels::{TabPanel, TabPanelBuilder, TabPanels, TabPanelsProps};
+pub use dropdown::{Dropdown, DropdownLoader, DropdownProps, DropdownVariant, DropdownOption, DropdownOptionGroup};
+pub use tooltip::{Tooltip, TooltipExt, TooltipProps, TooltipPosition, TooltipWrapper};
+pub use popover::{Popover, PopoverProps, PopoverPosition, PopoverSlot};
+pub use radio_group::{RadioGroup, RadioGroupProps, RadioGroupOrientation, RadioOption};
+pub use accordion::{Accordion, AccordionItem, AccordionMode, AccordionProps, AccordionSlot};
+pub use pagination::{Pagination, PaginationProps};
+pub use time_picker::{TimeFormat, TimePicker, TimePickerProps, TimeValue};
+pub use date_time_picker::{DateTimePicker, DateTimePickerProps, DateTimeValue, DateValue};
+pub use file_drop_zone::{FileDropZone, FileDropZoneProps, FileRejectReason, FileRejection, SelectedFile};
+pub use tag_input::{TagInput, TagInputProps, TagValidator};
+pub use stat::{Stat, StatProps, StatSlot, StatTrend};
+pub use button_group::{ButtonGroup, ButtonGroupItem, ButtonGroupProps, ButtonGroupSelection};
+pub use toolbar::{Toolbar, ToolbarAction, ToolbarItem, ToolbarProps};
@@ -0,0 +1,491 @@
+//! Pagination component for paged lists.
+
+use std::rc::Rc;
+
+use gpui::*;
+use crate::{
+    atoms::{icons, Icon, IconSize},
+    theme::{use_theme, Theme},
+};
+
+/// Pages to show, with `None` marking an ellipsis
+///
+/// Shows `boundaries` pages at each end and `siblings` pages on each side
+/// of `current`. The number of items stays constant while paging, so the
+/// controls don't shift under the pointer; when every page fits in that
+/// space, all pages are listed. Shared with the [`Table`] footer.
+///
+/// [`Table`]: crate::organisms::Table
+pub(crate) fn page_items(current: usize, count: usize, siblings: usize, boundaries: usize) -> Vec<Option<usize>> {
+    // Boundaries, siblings, the current page and two ellipses
+    if count <= 2 * boundaries + 2 * siblings + 3 {
+        return (0..count).map(Some).collect();
+    }
+
+    let current = current.min(count - 1);
+    // Keep the sibling window clear of the boundary pages and one ellipsis slot
+    let lowest = boundaries + 1;
+    let highest = count - boundaries - 2;
+    let start = current
+        .saturating_sub(siblings)
+        .min(highest.saturating_sub(2 * siblings))
+        .max(lowest);
+    let end = (current + siblings)
+        .max(lowest + 2 * siblings)
+        .min(highest);
+
+    let mut items: Vec<Option<usize>> = (0..boundaries).map(Some).collect();
+    items.push(if start > lowest { None } else { Some(boundaries) });
+    items.extend((start..=end).map(Some));
+    items.push(if end < highest { None } else { Some(count - boundaries - 1) });
+    items.extend((count - boundaries..count).map(Some));
+    items
+}
+
+/// Small square button used for page numbers and arrows
+pub(crate) fn page_button(id: impl Into<ElementId>, current: bool, theme: &Theme) -> Stateful<Div> {
+    let button = div()
+        .id(id)
+        .min_w(px(32.0))
+        .h(px(32.0))
+        .px(theme.global.spacing_xs)
+        .flex()
+        .items_center()
+        .justify_center()
+        .rounded(theme.global.radius_sm)
+        .cursor_pointer();
+
+    if current {
+        button
+            .bg(theme.alias.color_primary)
+            .text_color(theme.alias.color_text_on_primary)
+    } else {
+        let hover_bg = theme.alias.color_surface_hover;
+        button
+            .text_color(theme.alias.color_text_primary)
+            .hover(move |style| style.bg(hover_bg))
+    }
+}
+
+/// Pagination configuration properties
+#[derive(Clone)]
+pub struct PaginationProps {
+    /// Total number of pages
+    pub page_count: usize,
+    /// Current zero-based page
+    pub page: usize,
+    /// Pages shown on each side of the current page
+    pub sibling_count: usize,
+    /// Pages always shown at the start and end
+    pub boundary_count: usize,
+    /// Whether to show a "Go to page" field
+    pub show_jump: bool,
+    /// Handler called with the new zero-based page
+    pub on_page_change: Option<Rc<dyn Fn(usize, &mut Window, &mut App)>>,
+    /// Whether the current page is owned by the parent view
+    ///
+    /// Controlled pagination only reports page changes through
+    /// `on_page_change`; the parent applies them with [`Pagination::set_page`].
+    pub controlled: bool,
+}
+
+impl Default for PaginationProps {
+    fn default() -> Self {
+        Self {
+            page_count: 1,
+            page: 0,
+            sibling_count: 1,
+            boundary_count: 1,
+            show_jump: false,
+            on_page_change: None,
+            controlled: false,
+        }
+    }
+}
+
+/// A pagination control for paged lists.
+///
+/// Pagination shows previous/next arrows and page numbers, collapsing
+/// distant pages into ellipses. Pages are zero-based in the API and shown
+/// one-based.
+///
+/// ## Features
+///
+/// - Configurable sibling and boundary page counts
+/// - Constant number of items while paging, so controls don't shift
+/// - Optional "Go to page" field accepting a typed page number
+/// - Controlled or uncontrolled current page
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::molecules::*;
+///
+/// // Basic pagination
+/// Pagination::new()
+///     .page_count(20)
+///     .on_page_change(|page, _window, cx| load_page(page, cx));
+///
+/// // Wider window with a jump field
+/// Pagination::new()
+///     .page_count(200)
+///     .default_page(49)
+///     .sibling_count(2)
+///     .show_jump(true);
+/// ```
+///
+/// ## Accessibility
+///
+/// - The jump field is focusable; type digits and press Enter to jump
+/// - Previous/next arrows are disabled on the first/last page
+pub struct Pagination {
+    props: PaginationProps,
+    /// Digits typed into the jump field
+    jump_text: String,
+    jump_focus: Option<FocusHandle>,
+}
+
+impl Pagination {
+    /// Create a new pagination control
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let pagination = Pagination::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            props: PaginationProps::default(),
+            jump_text: String::new(),
+            jump_focus: None,
+        }
+    }
+
+    /// Set the total number of pages
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Pagination::new().page_count(total_items.div_ceil(page_size));
+    /// ```
+    pub fn page_count(mut self, page_count: usize) -> Self {
+        self.props.page_count = page_count.max(1);
+        self
+    }
+
+    /// Set the current zero-based page, making the pagination controlled
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Pagination::new().page_count(10).page(3);
+    /// ```
+    pub fn page(mut self, page: usize) -> Self {
+        self.props.page = page;
+        self.props.controlled = true;
+        self
+    }
+
+    /// Set the initial zero-based page of an uncontrolled pagination
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Pagination::new().page_count(10).default_page(3);
+    /// ```
+    pub fn default_page(mut self, page: usize) -> Self {
+        self.props.page = page;
+        self.props.controlled = false;
+        self
+    }
+
+    /// Set how many pages are shown on each side of the current page
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Pagination::new().sibling_count(2);
+    /// ```
+    pub fn sibling_count(mut self, count: usize) -> Self {
+        self.props.sibling_count = count;
+        self
+    }
+
+    /// Set how many pages are always shown at the start and end
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Pagination::new().boundary_count(2);
+    /// ```
+    pub fn boundary_count(mut self, count: usize) -> Self {
+        self.props.boundary_count = count;
+        self
+    }
+
+    /// Set whether to show the "Go to page" field
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Pagination::new().page_count(500).show_jump(true);
+    /// ```
+    pub fn show_jump(mut self, show_jump: bool) -> Self {
+        self.props.show_jump = show_jump;
+        self
+    }
+
+    /// Set the handler called when the page changes
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Pagination::new().on_page_change(|page, _window, cx| load_page(page, cx));
+    /// ```
+    pub fn on_page_change(
+        mut self,
+        handler: impl Fn(usize, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_page_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Update the current page from the parent view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// pagination.update(cx, |pagination, cx| pagination.set_page(0, cx));
+    /// ```
+    pub fn set_page(&mut self, page: usize, cx: &mut Context<'_, Self>) {
+        if self.props.page != page {
+            self.props.page = page;
+            cx.notify();
+        }
+    }
+
+    /// Update the page count from the parent view, e.g. after filtering
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// pagination.update(cx, |pagination, cx| pagination.set_page_count(results.len().div_ceil(20), cx));
+    /// ```
+    pub fn set_page_count(&mut self, page_count: usize, cx: &mut Context<'_, Self>) {
+        let page_count = page_count.max(1);
+        if self.props.page_count != page_count {
+            self.props.page_count = page_count;
+            cx.notify();
+        }
+    }
+
+    /// Current page, clamped to the page count
+    fn current_page(&self) -> usize {
+        self.props.page.min(self.props.page_count - 1)
+    }
+
+    /// Go to `page` and notify the handler
+    fn change_page(&mut self, page: usize, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let page = page.min(self.props.page_count - 1);
+        if page == self.current_page() {
+            return;
+        }
+
+        if !self.props.controlled {
+            self.set_page(page, cx);
+        }
+
+        if let Some(handler) = self.props.on_page_change.clone() {
+            handler(page, window, cx);
+        }
+    }
+
+    /// Handle typing digits and Enter in the jump field
+    fn handle_jump_key(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let key = event.keystroke.key.as_str();
+        match key {
+            "enter" => {
+                // Typed pages are one-based
+                if let Ok(page @ 1..) = self.jump_text.parse::<usize>() {
+                    self.change_page(page - 1, window, cx);
+                }
+                self.jump_text.clear();
+            }
+            "backspace" => {
+                self.jump_text.pop();
+            }
+            "escape" => self.jump_text.clear(),
+            _ if key.len() == 1 && key.chars().all(|c| c.is_ascii_digit()) => {
+                if self.jump_text.len() < self.props.page_count.to_string().len() {
+                    self.jump_text.push_str(key);
+                }
+            }
+            _ => return,
+        }
+        cx.notify();
+        cx.stop_propagation();
+    }
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Render for Pagination {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        let page = self.current_page();
+        let page_count = self.props.page_count;
+
+        let mut container = div()
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap(theme.global.spacing_xs)
+            .text_size(theme.global.font_size_sm);
+
+        let mut prev = page_button("pagination-prev", false, &theme)
+            .child(Icon::new(icons::CHEVRON_LEFT).size(IconSize::Sm).to_element(&theme));
+        prev = if page == 0 {
+            prev.opacity(0.5).cursor_not_allowed()
+        } else {
+            prev.on_click(cx.listener(move |this, _event: &ClickEvent, window, cx| {
+                this.change_page(page - 1, window, cx);
+            }))
+        };
+        container = container.child(prev);
+
+        for item in page_items(page, page_count, self.props.sibling_count, self.props.boundary_count) {
+            container = match item {
+                Some(number) => container.child(
+                    page_button(("pagination-page", number), number == page, &theme)
+                        .child(SharedString::from((number + 1).to_string()))
+                        .on_click(cx.listener(move |this, _event: &ClickEvent, window, cx| {
+                            this.change_page(number, window, cx);
+                        })),
+                ),
+                None => container.child(
+                    div()
+                        .px(theme.global.spacing_xs)
+                        .text_color(theme.alias.color_text_muted)
+                        .child("…"),
+                ),
+            };
+        }
+
+        let mut next = page_button("pagination-next", false, &theme)
+            .child(Icon::new(icons::CHEVRON_RIGHT).size(IconSize::Sm).to_element(&theme));
+        next = if page + 1 >= page_count {
+            next.opacity(0.5).cursor_not_allowed()
+        } else {
+            next.on_click(cx.listener(move |this, _event: &ClickEvent, window, cx| {
+                this.change_page(page + 1, window, cx);
+            }))
+        };
+        container = container.child(next);
+
+        if self.props.show_jump {
+            let focus_handle = self
+                .jump_focus
+                .get_or_insert_with(|| cx.focus_handle())
+                .clone();
+            let focused = focus_handle.is_focused(window);
+            let text: SharedString = if self.jump_text.is_empty() {
+                (page + 1).to_string().into()
+            } else {
+                self.jump_text.clone().into()
+            };
+
+            container = container
+                .child(
+                    div()
+                        .ml(theme.global.spacing_sm)
+                        .text_color(theme.alias.color_text_muted)
+                        .child("Go to"),
+                )
+                .child(
+                    div()
+                        .id("pagination-jump")
+                        .track_focus(&focus_handle)
+                        .on_key_down(cx.listener(Self::handle_jump_key))
+                        .w(px(48.0))
+                        .h(px(32.0))
+                        .px(theme.global.spacing_xs)
+                        .flex()
+                        .items_center()
+                        .justify_center()
+                        .rounded(theme.global.radius_sm)
+                        .border(px(1.0))
+                        .border_color(if focused {
+                            theme.alias.color_border_focus
+                        } else {
+                            theme.alias.color_border
+                        })
+                        .cursor_text()
+                        .text_color(if self.jump_text.is_empty() {
+                            theme.alias.color_text_muted
+                        } else {
+                            theme.alias.color_text_primary
+                        })
+                        .child(text),
+                );
+        }
+
+        container
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pagination_defaults() {
+        let pagination = Pagination::new();
+        assert_eq!(pagination.props.page_count, 1);
+        assert_eq!(pagination.props.page, 0);
+        assert_eq!(pagination.props.sibling_count, 1);
+        assert_eq!(pagination.props.boundary_count, 1);
+        assert!(!pagination.props.controlled);
+    }
+
+    #[test]
+    fn test_pagination_builder() {
+        let pagination = Pagination::new().page_count(0).page(3).show_jump(true);
+        assert_eq!(pagination.props.page_count, 1);
+        assert_eq!(pagination.current_page(), 0);
+        assert!(pagination.props.controlled);
+        assert!(pagination.props.show_jump);
+    }
+
+    #[test]
+    fn test_few_pages_are_all_listed() {
+        assert_eq!(page_items(0, 1, 1, 1), vec![Some(0)]);
+        assert_eq!(page_items(3, 7, 1, 1), (0..7).map(Some).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_ellipses_around_siblings() {
+        assert_eq!(
+            page_items(0, 10, 1, 1),
+            vec![Some(0), Some(1), Some(2), Some(3), Some(4), None, Some(9)]
+        );
+        assert_eq!(
+            page_items(5, 10, 1, 1),
+            vec![Some(0), None, Some(4), Some(5), Some(6), None, Some(9)]
+        );
+        assert_eq!(
+            page_items(9, 10, 1, 1),
+            vec![Some(0), None, Some(5), Some(6), Some(7), Some(8), Some(9)]
+        );
+    }
+
+    #[test]
+    fn test_item_count_is_constant() {
+        for current in 0..50 {
+            assert_eq!(page_items(current, 50, 2, 2).len(), 11);
+        }
+    }
+}
@@ -1,7 +1,13 @@
 //! Popover component for rich contextual overlays.
 
+use std::rc::Rc;
+
 use gpui::*;
-use crate::{atoms::{Label, LabelVariant, Button, ButtonVariant, Icon, icons}, theme::Theme, utils::FocusTrap};
+use crate::{
+    atoms::{Label, LabelVariant, Button, ButtonSize, ButtonVariant, icons},
+    theme::use_theme,
+    utils::{AnchorOptions, AnchoredState, FocusTrap, Placement, PlacementAlign},
+};
 
 /// Popover positioning options
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -17,15 +23,35 @@ pub enum PopoverPosition {
     Right,
 }
 
+impl From<PopoverPosition> for Placement {
+    fn from(position: PopoverPosition) -> Self {
+        match position {
+            PopoverPosition::Top => Placement::Top,
+            PopoverPosition::Bottom => Placement::Bottom,
+            PopoverPosition::Left => Placement::Left,
+            PopoverPosition::Right => Placement::Right,
+        }
+    }
+}
+
+/// Builds custom popover content
+///
+/// Called on every render, so the content can reflect current state.
+pub type PopoverSlot = Rc<dyn Fn(&mut Window, &mut App) -> AnyElement>;
+
 /// Popover configuration properties
 #[derive(Clone)]
 pub struct PopoverProps {
     /// Popover title
     pub title: Option<SharedString>,
-    /// Popover content description
+    /// Popover content description, shown when there is no custom body
     pub content: SharedString,
+    /// Custom body content replacing the description
+    pub body: Option<PopoverSlot>,
     /// Positioning relative to target
     pub position: PopoverPosition,
+    /// Alignment along the target's side
+    pub align: PlacementAlign,
     /// Whether popover is visible/open
     pub open: bool,
     /// Whether to show close button
@@ -34,6 +60,14 @@ pub struct PopoverProps {
     pub show_arrow: bool,
     /// Whether clicking outside closes the popover
     pub close_on_outside_click: bool,
+    /// Whether pressing Escape closes the popover
+    pub close_on_escape: bool,
+    /// Focusable elements in the custom body, in Tab order
+    pub focus_handles: Vec<FocusHandle>,
+    /// Element that receives focus when the popover closes
+    pub trigger_focus: Option<FocusHandle>,
+    /// Called when the popover closes
+    pub on_close: Option<Rc<dyn Fn(&mut Window, &mut App)>>,
 }
 
 impl Default for PopoverProps {
@@ -42,10 +76,15 @@ impl Default for PopoverProps {
             title: None,
             content: "".into(),
             position: PopoverPosition::default(),
+            align: PlacementAlign::Center,
             open: false,
             show_close: true,
             show_arrow: true,
             close_on_outside_click: true,
+            close_on_escape: true,
+            focus_handles: Vec::new(),
+            trigger_focus: None,
+            on_close: None,
         }
     }
 }
@@ -58,13 +97,13 @@ impl Default for PopoverProps {
 ///
 /// ## Features
 ///
-/// - Multiple positioning options (top, bottom, left, right)
+/// - Multiple positioning options (top, bottom, left, right), flipping near window edges
 /// - Optional title and close button
 /// - Optional arrow pointer
-/// - Click-outside-to-close behavior
-/// - Focus trap for keyboard accessibility
+/// - Click-outside-to-close and Escape-to-close behavior
+/// - Focus trap for keyboard accessibility, returning focus to the trigger on close
 /// - ARIA attributes for screen readers
-/// - Can contain interactive content
+/// - Can contain interactive content (forms, buttons) through a custom body
 ///
 /// ## Example
 ///
@@ -86,8 +125,19 @@ impl Default for PopoverProps {
 ///     .show_arrow(false)
 ///     .close_on_outside_click(true);
 ///
-/// // In a component
+/// // Popover with interactive content
+/// Popover::new("")
+///     .title("Rename")
+///     .body(|_window, _cx| {
+///         div().flex().gap_2().child(name_field()).child(save_button()).into_any_element()
+///     })
+///     .focus_handles(vec![name_focus.clone(), save_focus.clone()])
+///     .trigger_focus(&rename_button_focus)
+///     .on_close(|_window, _cx| println!("closed"));
+///
+/// // In a component; the popover is positioned against its parent
 /// div()
+///     .relative()
 ///     .child(Button::new().label("Open Popover"))
 ///     .child(
 ///         Popover::new("Detailed explanation here")
@@ -100,12 +150,23 @@ impl Default for PopoverProps {
 ///
 /// - Uses ARIA `role="dialog"` for complex popovers
 /// - Keyboard accessible (Escape to close)
-/// - Focus trap when open
+/// - Focus trap when open; closing returns focus to the trigger
 /// - Connected to trigger with `aria-controls`
 /// - Meets WCAG 2.1 SC 2.4.3 (Focus Order)
 pub struct Popover {
     props: PopoverProps,
     focus_trap: FocusTrap,
+    anchor: AnchoredState,
+    /// Focus handles for the panel and close button, created on first open
+    focus: Option<PopoverFocus>,
+    /// Move focus into the popover on the next render
+    pending_focus: bool,
+}
+
+/// Focus handles owned by the popover
+struct PopoverFocus {
+    panel: FocusHandle,
+    close: FocusHandle,
 }
 
 impl Popover {
@@ -123,6 +184,9 @@ impl Popover {
                 ..Default::default()
             },
             focus_trap: FocusTrap::new(),
+            anchor: AnchoredState::new(),
+            focus: None,
+            pending_focus: false,
         }
     }
 
@@ -162,6 +226,18 @@ impl Popover {
         self
     }
 
+    /// Set the alignment along the target's side
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Popover::new("Content").position(PopoverPosition::Bottom).align(PlacementAlign::Start);
+    /// ```
+    pub fn align(mut self, align: PlacementAlign) -> Self {
+        self.props.align = align;
+        self
+    }
+
     /// Set whether the popover is open
     ///
     /// ## Example
@@ -171,6 +247,7 @@ impl Popover {
     /// ```
     pub fn open(mut self, open: bool) -> Self {
         self.props.open = open;
+        self.pending_focus = open;
         self
     }
 
@@ -209,50 +286,182 @@ impl Popover {
         self.props.close_on_outside_click = close_on_outside_click;
         self
     }
+
+    /// Set custom body content, replacing the text content
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Popover::new("").title("Filters").body(|_window, _cx| {
+    ///     div().flex().flex_col().children(filter_checkboxes()).into_any_element()
+    /// });
+    /// ```
+    pub fn body(mut self, body: impl Fn(&mut Window, &mut App) -> AnyElement + 'static) -> Self {
+        self.props.body = Some(Rc::new(body));
+        self
+    }
+
+    /// Register the focusable elements in the custom body, in Tab order
+    ///
+    /// Opening focuses the first of them, and Tab cycles through them and
+    /// the close button.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Popover::new("").body(rename_form).focus_handles(vec![name_focus.clone(), save_focus.clone()]);
+    /// ```
+    pub fn focus_handles(mut self, handles: Vec<FocusHandle>) -> Self {
+        self.props.focus_handles = handles;
+        self
+    }
+
+    /// Set whether pressing Escape closes the popover
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Popover::new("Content").close_on_escape(false);
+    /// ```
+    pub fn close_on_escape(mut self, close: bool) -> Self {
+        self.props.close_on_escape = close;
+        self
+    }
+
+    /// Set the trigger that receives focus when the popover closes
+    ///
+    /// Without one, focus returns to whatever was focused when it opened.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Popover::new("Content").trigger_focus(&self.info_button_focus);
+    /// ```
+    pub fn trigger_focus(mut self, handle: &FocusHandle) -> Self {
+        self.props.trigger_focus = Some(handle.clone());
+        self
+    }
+
+    /// Set the handler called when the popover closes
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Popover::new("Content").on_close(|_window, cx| cx.notify(parent_id));
+    /// ```
+    pub fn on_close(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.props.on_close = Some(Rc::new(handler));
+        self
+    }
+
+    /// Open or close the popover
+    ///
+    /// Opening moves focus into the popover. Closing returns focus to the
+    /// trigger and calls the `on_close` handler.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// popover.update(cx, |popover, cx| popover.set_open(true, window, cx));
+    /// ```
+    pub fn set_open(&mut self, open: bool, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.props.open == open {
+            return;
+        }
+        self.props.open = open;
+
+        if open {
+            self.pending_focus = true;
+        } else {
+            self.pending_focus = false;
+            self.focus_trap.cleanup(window);
+            if let Some(trigger) = &self.props.trigger_focus {
+                window.focus(trigger);
+            }
+            if let Some(handler) = self.props.on_close.clone() {
+                handler(window, cx);
+            }
+        }
+        cx.notify();
+    }
+
+    /// Close on a mouse down outside the popover, unless it lands on the trigger
+    ///
+    /// Clicks on the trigger are left to the trigger, which usually toggles
+    /// the popover; closing here too would reopen it straight away.
+    fn handle_outside_click(&mut self, event: &MouseDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if !self.props.close_on_outside_click {
+            return;
+        }
+        let on_trigger = self
+            .anchor
+            .anchor_bounds()
+            .is_some_and(|bounds| bounds.contains(&event.position));
+        if !on_trigger {
+            self.set_open(false, window, cx);
+        }
+    }
+
+    /// Handle Escape and trap Tab inside the popover
+    fn handle_key(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if event.keystroke.key == "escape" && self.props.close_on_escape {
+            self.set_open(false, window, cx);
+            cx.stop_propagation();
+        } else if self.focus_trap.handle_key_event(event, window) {
+            cx.stop_propagation();
+        }
+    }
 }
 
 impl Render for Popover {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
-        let theme = Theme::default();
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
 
         if !self.props.open {
             return div(); // Return empty div if not open
         }
 
+        let focus = self.focus.get_or_insert_with(|| PopoverFocus {
+            panel: cx.focus_handle(),
+            close: cx.focus_handle(),
+        });
+        let (panel_focus, close_focus) = (focus.panel.clone(), focus.close.clone());
+        if std::mem::take(&mut self.pending_focus) {
+            let mut handles = self.props.focus_handles.clone();
+            if self.props.show_close {
+                handles.push(close_focus.clone());
+            }
+            if handles.is_empty() {
+                handles.push(panel_focus.clone());
+            }
+            self.focus_trap.set_handles(handles);
+            self.focus_trap.initialize(window, cx);
+        }
+
+        let options = AnchorOptions::new(self.props.position.into())
+            .align(self.props.align)
+            .offset(theme.global.spacing_sm);
+        let position = self.anchor.position(&options, window);
+        // Near a window edge the popover may flip to the other side
+        let placement = position.map_or(options.placement, |position| position.placement);
+
         // Build popover container
         let mut popover = div()
-            .absolute()
+            .id("popover")
+            .track_focus(&panel_focus)
+            .on_key_down(cx.listener(Self::handle_key))
+            .on_mouse_down_out(cx.listener(Self::handle_outside_click))
+            .relative()
             .bg(theme.alias.color_surface)
             .border(px(1.0))
             .border_color(theme.alias.color_border)
             .rounded(theme.global.radius_lg)
             .shadow_xl()
-            .z_index(1000)
             .min_w(px(200.0))
             .max_w(px(400.0))
             .flex()
             .flex_col();
 
-        // Position the popover
-        popover = match self.props.position {
-            PopoverPosition::Top => popover
-                .bottom_full()
-                .left_half()
-                .mb(theme.global.spacing_sm),
-            PopoverPosition::Bottom => popover
-                .top_full()
-                .left_half()
-                .mt(theme.global.spacing_sm),
-            PopoverPosition::Left => popover
-                .right_full()
-                .top_half()
-                .mr(theme.global.spacing_sm),
-            PopoverPosition::Right => popover
-                .left_full()
-                .top_half()
-                .ml(theme.global.spacing_sm),
-        };
-
         // Add header if title exists or close button is shown
         if self.props.title.is_some() || self.props.show_close {
             let mut header = div()
@@ -269,7 +478,7 @@ impl Render for Popover {
             if let Some(ref title) = self.props.title {
                 header = header.child(
                     Label::new(title.clone())
-                        .variant(LabelVariant::Heading4)
+                        .variant(LabelVariant::Heading3)
                 );
             } else {
                 header = header.child(div()); // Empty spacer
@@ -279,24 +488,39 @@ impl Render for Popover {
             if self.props.show_close {
                 header = header.child(
                     Button::new()
-                        .label("×")
+                        .id("popover-close")
+                        .label("Close")
+                        .icon_left(icons::X)
+                        .icon_only(true)
                         .variant(ButtonVariant::Ghost)
+                        .size(ButtonSize::Sm)
+                        .track_focus(&close_focus)
+                        .on_click(cx.listener(|this, _event: &ClickEvent, window, cx| {
+                            this.set_open(false, window, cx);
+                        }))
+                        .to_element(&theme)
                 );
             }
 
             popover = popover.child(header);
         }
 
-        // Add content
-        popover = popover.child(
-            div()
-                .px(theme.global.spacing_md)
-                .py(theme.global.spacing_md)
+        // Add content: the custom body, or the text content
+        let body = match self.props.body.clone() {
+            Some(body) => body(window, cx),
+            None => div()
                 .child(
                     Label::new(self.props.content.clone())
                         .variant(LabelVariant::Body)
                         .color(theme.alias.color_text_secondary)
                 )
+                .into_any_element(),
+        };
+        popover = popover.child(
+            div()
+                .px(theme.global.spacing_md)
+                .py(theme.global.spacing_md)
+                .child(body)
         );
 
         // Add arrow if enabled
@@ -309,18 +533,18 @@ impl Render for Popover {
                 .border(px(1.0))
                 .border_color(theme.alias.color_border);
 
-            // Position arrow based on popover position
-            let arrow = match self.props.position {
-                PopoverPosition::Top => arrow
+            // Point the arrow at the target from the side the popover ended up on
+            let arrow = match placement {
+                Placement::Top => arrow
                     .bottom(px(-6.0))
                     .left_half(),
-                PopoverPosition::Bottom => arrow
+                Placement::Bottom => arrow
                     .top(px(-6.0))
                     .left_half(),
-                PopoverPosition::Left => arrow
+                Placement::Left => arrow
                     .right(px(-6.0))
                     .top_half(),
-                PopoverPosition::Right => arrow
+                Placement::Right => arrow
                     .left(px(-6.0))
                     .top_half(),
             };
@@ -328,7 +552,14 @@ impl Render for Popover {
             popover = popover.child(arrow);
         }
 
-        popover
+        // Cover the parent to measure it as the anchor
+        div()
+            .absolute()
+            .top(px(0.0))
+            .left(px(0.0))
+            .size_full()
+            .child(self.anchor.measure_anchor())
+            .child(self.anchor.overlay(position, popover))
     }
 }
 
@@ -372,6 +603,20 @@ mod tests {
         assert!(!popover.props.close_on_outside_click);
     }
 
+    #[test]
+    fn test_popover_dismissal_defaults() {
+        let popover = Popover::new("Test");
+        assert!(popover.props.close_on_escape);
+        assert!(popover.props.body.is_none());
+        assert!(popover.props.trigger_focus.is_none());
+
+        let popover = Popover::new("")
+            .body(|_window, _cx| div().into_any_element())
+            .close_on_escape(false);
+        assert!(popover.props.body.is_some());
+        assert!(!popover.props.close_on_escape);
+    }
+
     #[test]
     fn test_popover_positions() {
         let positions = vec![
@@ -0,0 +1,466 @@
+//! RadioGroup component for single selection from a list of options.
+
+use std::rc::Rc;
+
+use gpui::*;
+use crate::{
+    atoms::{Label, LabelVariant, Radio},
+    theme::use_theme,
+    utils::{RovingFocus, RovingOrientation},
+};
+
+/// Configuration for a single radio option
+#[derive(Clone, Debug)]
+pub struct RadioOption {
+    /// Option label
+    pub label: SharedString,
+    /// Option value
+    pub value: SharedString,
+    /// Whether option is disabled
+    pub disabled: bool,
+}
+
+impl RadioOption {
+    /// Create a new option with label and value
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let option = RadioOption::new("Email", "email");
+    /// ```
+    pub fn new(label: impl Into<SharedString>, value: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            value: value.into(),
+            disabled: false,
+        }
+    }
+
+    /// Set whether the option is disabled
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// RadioOption::new("Fax", "fax").disabled(true);
+    /// ```
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// RadioGroup layout direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RadioGroupOrientation {
+    /// Options stacked top to bottom
+    #[default]
+    Vertical,
+    /// Options laid out left to right
+    Horizontal,
+}
+
+/// RadioGroup configuration properties
+#[derive(Clone)]
+pub struct RadioGroupProps {
+    /// List of options
+    pub options: Vec<RadioOption>,
+    /// Currently selected option value
+    pub selected: Option<SharedString>,
+    /// Optional group label
+    pub label: Option<SharedString>,
+    /// Layout direction
+    pub orientation: RadioGroupOrientation,
+    /// Whether the whole group is disabled
+    pub disabled: bool,
+    /// Handler called with the newly selected value
+    pub on_change: Option<Rc<dyn Fn(SharedString, &mut Window, &mut App)>>,
+    /// Whether the selection is owned by the parent view
+    ///
+    /// Controlled groups only report selections through `on_change`; the
+    /// parent applies them with [`RadioGroup::set_selected`].
+    pub controlled: bool,
+}
+
+impl Default for RadioGroupProps {
+    fn default() -> Self {
+        Self {
+            options: Vec::new(),
+            selected: None,
+            label: None,
+            orientation: RadioGroupOrientation::default(),
+            disabled: false,
+            on_change: None,
+            controlled: false,
+        }
+    }
+}
+
+/// A group of radio buttons with single-selection management.
+///
+/// An uncontrolled RadioGroup owns the selected value, so selecting one
+/// option deselects the others without any bookkeeping in the parent view.
+///
+/// ## Features
+///
+/// - Mutually exclusive selection across options
+/// - Keyboard navigation following the WAI-ARIA radio group pattern
+/// - Disabled options are skipped during navigation
+/// - Vertical or horizontal layout
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::molecules::*;
+///
+/// RadioGroup::new()
+///     .label("Contact method")
+///     .options(vec![
+///         RadioOption::new("Email", "email"),
+///         RadioOption::new("Phone", "phone"),
+///         RadioOption::new("Fax", "fax").disabled(true),
+///     ])
+///     .default_selected("email")
+///     .on_change(|value, _window, _cx| println!("selected: {value}"));
+/// ```
+///
+/// ## Accessibility
+///
+/// - The group is a single tab stop
+/// - Arrow keys move the selection to the next/previous enabled option, wrapping at the ends
+/// - Home and End select the first and last enabled option
+/// - Space selects the first enabled option when nothing is selected yet
+pub struct RadioGroup {
+    props: RadioGroupProps,
+    focus_handle: Option<FocusHandle>,
+}
+
+impl RadioGroup {
+    /// Create a new radio group
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let group = RadioGroup::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            props: RadioGroupProps::default(),
+            focus_handle: None,
+        }
+    }
+
+    /// Set the options for the group
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// RadioGroup::new().options(vec![
+    ///     RadioOption::new("Small", "sm"),
+    ///     RadioOption::new("Large", "lg"),
+    /// ]);
+    /// ```
+    pub fn options(mut self, options: Vec<RadioOption>) -> Self {
+        self.props.options = options;
+        self
+    }
+
+    /// Set the currently selected value, making the group controlled
+    ///
+    /// A controlled group does not change its selection on click or arrow
+    /// keys; apply selections reported by `on_change` with
+    /// [`RadioGroup::set_selected`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// RadioGroup::new().selected("sm");
+    /// ```
+    pub fn selected(mut self, selected: impl Into<SharedString>) -> Self {
+        self.props.selected = Some(selected.into());
+        self.props.controlled = true;
+        self
+    }
+
+    /// Set the initially selected value of an uncontrolled group
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// RadioGroup::new().default_selected("sm");
+    /// ```
+    pub fn default_selected(mut self, selected: impl Into<SharedString>) -> Self {
+        self.props.selected = Some(selected.into());
+        self.props.controlled = false;
+        self
+    }
+
+    /// Update the selection from the parent view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// group.update(cx, |group, cx| group.set_selected(Some("lg".into()), cx));
+    /// ```
+    pub fn set_selected(&mut self, selected: Option<SharedString>, cx: &mut Context<'_, Self>) {
+        if self.props.selected != selected {
+            self.props.selected = selected;
+            cx.notify();
+        }
+    }
+
+    /// Set the group label
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// RadioGroup::new().label("Size");
+    /// ```
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.props.label = Some(label.into());
+        self
+    }
+
+    /// Set the layout direction
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// RadioGroup::new().orientation(RadioGroupOrientation::Horizontal);
+    /// ```
+    pub fn orientation(mut self, orientation: RadioGroupOrientation) -> Self {
+        self.props.orientation = orientation;
+        self
+    }
+
+    /// Set whether the whole group is disabled
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// RadioGroup::new().disabled(true);
+    /// ```
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.props.disabled = disabled;
+        self
+    }
+
+    /// Set the handler called when the selection changes
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// RadioGroup::new().on_change(|value, _window, cx| set_size(value, cx));
+    /// ```
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Get the currently selected value
+    pub fn value(&self) -> Option<&SharedString> {
+        self.props.selected.as_ref()
+    }
+
+    /// Check whether the option at `index` can be selected
+    fn is_enabled(&self, index: usize) -> bool {
+        !self.props.disabled && self.props.options.get(index).is_some_and(|o| !o.disabled)
+    }
+
+    /// Index of the selected option, if any
+    fn selected_index(&self) -> Option<usize> {
+        let selected = self.props.selected.as_ref()?;
+        self.props.options.iter().position(|o| &o.value == selected)
+    }
+
+    /// Option a navigation key moves the selection to, if any
+    ///
+    /// Every arrow key moves, wrapping at the ends and skipping disabled
+    /// options. With no selection, moving forward starts at the first
+    /// option and moving backward at the last.
+    fn key_target(&self, key: &str) -> Option<usize> {
+        RovingFocus::new(RovingOrientation::Both).target(
+            key,
+            self.selected_index(),
+            self.props.options.len(),
+            |index| self.is_enabled(index),
+        )
+    }
+
+    /// First enabled option
+    fn first_enabled(&self) -> Option<usize> {
+        RovingFocus::first(self.props.options.len(), |index| self.is_enabled(index))
+    }
+
+    /// Select the option at `index` and notify the handler if it changed
+    fn select(&mut self, index: usize, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if !self.is_enabled(index) || self.selected_index() == Some(index) {
+            return;
+        }
+
+        let value = self.props.options[index].value.clone();
+        if !self.props.controlled {
+            self.set_selected(Some(value.clone()), cx);
+        }
+
+        if let Some(handler) = self.props.on_change.clone() {
+            handler(value, window, cx);
+        }
+    }
+
+    /// Handle arrow, Home, End and Space keys
+    fn handle_key(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let target = match event.keystroke.key.as_str() {
+            "space" if self.selected_index().is_none() => self.first_enabled(),
+            key @ ("down" | "right" | "up" | "left" | "home" | "end") => self.key_target(key),
+            _ => return,
+        };
+
+        if let Some(index) = target {
+            self.select(index, window, cx);
+        }
+        cx.stop_propagation();
+    }
+}
+
+impl Render for RadioGroup {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        let focus_handle = self
+            .focus_handle
+            .get_or_insert_with(|| cx.focus_handle())
+            .clone();
+        let focused = focus_handle.is_focused(window);
+        let selected_index = self.selected_index();
+        // The focus ring sits on the selected option, or the first enabled one
+        let focus_index = selected_index.or_else(|| self.first_enabled());
+
+        let mut options = div().flex().gap(theme.global.spacing_sm);
+        options = match self.props.orientation {
+            RadioGroupOrientation::Vertical => options.flex_col(),
+            RadioGroupOrientation::Horizontal => options.flex_row().gap(theme.global.spacing_md),
+        };
+
+        for (index, option) in self.props.options.iter().enumerate() {
+            let enabled = self.is_enabled(index);
+
+            let mut radio = Radio::new()
+                .label(option.label.clone())
+                .value(option.value.clone())
+                .selected(selected_index == Some(index))
+                .disabled(!enabled)
+                .to_element(&theme)
+                .id(("radio-option", index))
+                .group("radio")
+                .rounded(theme.global.radius_sm);
+
+            if focused && focus_index == Some(index) {
+                radio = radio.outline_color(theme.alias.color_border_focus).outline_w(px(2.0));
+            }
+
+            radio = if enabled {
+                radio
+                    .cursor_pointer()
+                    .active(|style| style.opacity(0.8))
+                    .on_click(cx.listener(move |this, _event: &ClickEvent, window, cx| {
+                        this.select(index, window, cx);
+                    }))
+            } else {
+                radio.cursor_not_allowed()
+            };
+
+            options = options.child(radio);
+        }
+
+        let mut container = div()
+            .id("radio-group")
+            .track_focus(&focus_handle)
+            .flex()
+            .flex_col()
+            .gap(theme.global.spacing_sm);
+
+        if !self.props.disabled {
+            container = container.on_key_down(cx.listener(Self::handle_key));
+        }
+
+        if let Some(label) = &self.props.label {
+            container = container.child(
+                Label::new(label.clone()).variant(LabelVariant::Caption)
+            );
+        }
+
+        container.child(options)
+    }
+}
+
+impl Default for RadioGroup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn group() -> RadioGroup {
+        RadioGroup::new().options(vec![
+            RadioOption::new("One", "one"),
+            RadioOption::new("Two", "two").disabled(true),
+            RadioOption::new("Three", "three"),
+        ])
+    }
+
+    #[test]
+    fn test_option_creation() {
+        let option = RadioOption::new("Email", "email");
+        assert_eq!(option.label.as_ref(), "Email");
+        assert_eq!(option.value.as_ref(), "email");
+        assert!(!option.disabled);
+        assert!(RadioOption::new("Fax", "fax").disabled(true).disabled);
+    }
+
+    #[test]
+    fn test_radio_group_builder() {
+        let group = group()
+            .selected("three")
+            .label("Count")
+            .orientation(RadioGroupOrientation::Horizontal);
+
+        assert_eq!(group.props.options.len(), 3);
+        assert_eq!(group.value().map(AsRef::as_ref), Some("three"));
+        assert_eq!(group.selected_index(), Some(2));
+        assert_eq!(group.props.orientation, RadioGroupOrientation::Horizontal);
+        assert!(group.props.controlled);
+        assert!(!RadioGroup::new().default_selected("one").props.controlled);
+    }
+
+    #[test]
+    fn test_step_skips_disabled_and_wraps() {
+        let group = group().selected("one");
+        assert_eq!(group.key_target("down"), Some(2));
+        assert_eq!(group.key_target("left"), Some(2));
+
+        let group = group.selected("three");
+        assert_eq!(group.key_target("right"), Some(0));
+    }
+
+    #[test]
+    fn test_step_without_selection() {
+        let group = group();
+        assert_eq!(group.key_target("down"), Some(0));
+        assert_eq!(group.key_target("up"), Some(2));
+        assert_eq!(group.first_enabled(), Some(0));
+        assert_eq!(group.key_target("end"), Some(2));
+    }
+
+    #[test]
+    fn test_disabled_group_has_no_targets() {
+        let group = group().disabled(true);
+        assert_eq!(group.key_target("down"), None);
+        assert_eq!(group.first_enabled(), None);
+        assert!(!group.is_enabled(0));
+    }
+}
@@ -1,7 +1,23 @@
 //! SearchBar component combining input with search functionality.
 
+use std::{rc::Rc, time::Duration};
+
 use gpui::*;
-use crate::{atoms::{Input, Icon, IconSize, IconColor}, theme::Theme};
+use crate::{
+    atoms::{icons, Icon, IconColor, IconSize, Spinner, SpinnerSize},
+    theme::{use_theme, InputTokens},
+    utils::{motion::reduced_motion, Announcer},
+};
+
+/// When a SearchBar runs its search
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SearchMode {
+    /// Search as the user types, after the debounce delay
+    #[default]
+    Live,
+    /// Search only when the user presses Enter
+    Submit,
+}
 
 /// SearchBar configuration properties
 #[derive(Clone)]
@@ -12,6 +28,14 @@ pub struct SearchBarProps {
     pub placeholder: SharedString,
     /// Whether search is in loading state
     pub loading: bool,
+    /// When the search runs
+    pub mode: SearchMode,
+    /// Delay in milliseconds after the last keystroke before a live search runs
+    pub debounce: u32,
+    /// Handler called with the query when a search runs
+    pub on_search: Option<Rc<dyn Fn(SharedString, &mut Window, &mut App)>>,
+    /// Handler called after the clear button or Escape empties the query
+    pub on_clear: Option<Rc<dyn Fn(&mut Window, &mut App)>>,
 }
 
 impl Default for SearchBarProps {
@@ -20,13 +44,25 @@ impl Default for SearchBarProps {
             value: "".into(),
             placeholder: "Search...".into(),
             loading: false,
+            mode: SearchMode::default(),
+            debounce: 300, // 300ms default debounce
+            on_search: None,
+            on_clear: None,
         }
     }
 }
 
 /// A search bar component with input and search icon.
 ///
-/// SearchBar combines an input field with a search icon and optional loading state.
+/// SearchBar combines a text field with a search icon, a clear button and
+/// an optional loading state.
+///
+/// ## Features
+///
+/// - Live search debounced by a configurable delay, or Enter-to-submit
+/// - Enter runs a live search immediately, skipping the debounce
+/// - Clear button (and Escape) resets the query and keeps focus in the field
+/// - Loading spinner while results are fetched
 ///
 /// ## Example
 ///
@@ -44,9 +80,24 @@ impl Default for SearchBarProps {
 /// SearchBar::new()
 ///     .value("query")
 ///     .placeholder("Search...");
+///
+/// // Live search with a longer debounce
+/// SearchBar::new()
+///     .debounce(500)
+///     .on_search(|query, _window, cx| fetch_results(query, cx));
+///
+/// // Search only on Enter
+/// SearchBar::new()
+///     .mode(SearchMode::Submit)
+///     .on_search(|query, _window, cx| run_query(query, cx));
 /// ```
 pub struct SearchBar {
     props: SearchBarProps,
+    focus_handle: Option<FocusHandle>,
+    /// Whether the field should take focus on the next render
+    pending_focus: bool,
+    /// Pending debounced search; dropping it cancels the search
+    search_task: Option<Task<()>>,
 }
 
 impl SearchBar {
@@ -60,6 +111,9 @@ impl SearchBar {
     pub fn new() -> Self {
         Self {
             props: SearchBarProps::default(),
+            focus_handle: None,
+            pending_focus: false,
+            search_task: None,
         }
     }
 
@@ -98,37 +152,306 @@ impl SearchBar {
         self.props.loading = loading;
         self
     }
+
+    /// Set when the search runs
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// SearchBar::new().mode(SearchMode::Submit);
+    /// ```
+    pub fn mode(mut self, mode: SearchMode) -> Self {
+        self.props.mode = mode;
+        self
+    }
+
+    /// Set the live search debounce delay in milliseconds
+    ///
+    /// A delay of `0` searches on every keystroke.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// SearchBar::new().debounce(500);
+    /// ```
+    pub fn debounce(mut self, debounce: u32) -> Self {
+        self.props.debounce = debounce;
+        self
+    }
+
+    /// Set the handler called with the query when a search runs
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// SearchBar::new().on_search(|query, _window, cx| fetch_results(query, cx));
+    /// ```
+    pub fn on_search(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_search = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the handler called after the query is cleared
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// SearchBar::new().on_clear(|_window, cx| reset_results(cx));
+    /// ```
+    pub fn on_clear(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.props.on_clear = Some(Rc::new(handler));
+        self
+    }
+
+    /// Update the query from the parent view without running a search
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// search.update(cx, |search, cx| search.set_value("recent", cx));
+    /// ```
+    pub fn set_value(&mut self, value: impl Into<SharedString>, cx: &mut Context<'_, Self>) {
+        let value = value.into();
+        if self.props.value != value {
+            self.props.value = value;
+            self.search_task = None;
+            cx.notify();
+        }
+    }
+
+    /// Update the loading state, typically when results arrive
+    ///
+    /// Screen readers hear "Searching" and then "Search complete"; a
+    /// search finishing quickly is announced only as complete.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// search.update(cx, |search, cx| search.set_loading(false, cx));
+    /// ```
+    pub fn set_loading(&mut self, loading: bool, cx: &mut Context<'_, Self>) {
+        if self.props.loading != loading {
+            self.props.loading = loading;
+            let message = if loading { "Searching" } else { "Search complete" };
+            Announcer::polite(message).announce(cx);
+            cx.notify();
+        }
+    }
+
+    /// Empty the query, keep focus in the field and notify the handlers
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// search.update(cx, |search, cx| search.clear(window, cx));
+    /// ```
+    pub fn clear(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+        self.search_task = None;
+        self.pending_focus = true;
+        cx.notify();
+
+        if self.props.value.is_empty() {
+            return;
+        }
+        self.props.value = "".into();
+
+        if let Some(handler) = self.props.on_clear.clone() {
+            handler(window, cx);
+        }
+        if self.props.mode == SearchMode::Live {
+            self.search(window, cx);
+        }
+    }
+
+    /// Run the search for the current query now, cancelling a pending one
+    fn search(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+        self.search_task = None;
+        if let Some(handler) = self.props.on_search.clone() {
+            handler(self.props.value.clone(), window, cx);
+        }
+    }
+
+    /// Apply a typed query, scheduling a live search after the debounce
+    fn edit(&mut self, value: String, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.props.value.as_ref() == value {
+            return;
+        }
+        self.props.value = value.into();
+        cx.notify();
+
+        if self.props.mode != SearchMode::Live {
+            return;
+        }
+        if self.props.debounce == 0 {
+            self.search(window, cx);
+            return;
+        }
+
+        let delay = Duration::from_millis(u64::from(self.props.debounce));
+        self.search_task = Some(cx.spawn_in(window, async move |this, cx| {
+            cx.background_executor().timer(delay).await;
+            this.update_in(cx, |this, window, cx| this.search(window, cx)).ok();
+        }));
+    }
+
+    /// Handle typing, submit and clear keys
+    fn handle_key(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let keystroke = &event.keystroke;
+        let is_text = keystroke.key.chars().count() == 1
+            && !keystroke.modifiers.control
+            && !keystroke.modifiers.alt
+            && !keystroke.modifiers.platform;
+
+        match keystroke.key.as_str() {
+            "enter" => self.search(window, cx),
+            "escape" if !self.props.value.is_empty() => self.clear(window, cx),
+            "backspace" => {
+                let mut value = self.props.value.to_string();
+                value.pop();
+                self.edit(value, window, cx);
+            }
+            "space" => self.edit(format!("{} ", self.props.value), window, cx),
+            key if is_text => {
+                let key = keystroke.key_char.as_deref().unwrap_or(key);
+                self.edit(format!("{}{key}", self.props.value), window, cx);
+            }
+            _ => return,
+        }
+
+        cx.stop_propagation();
+    }
+}
+
+impl Default for SearchBar {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Render for SearchBar {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
-        let theme = Theme::default();
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        let tokens = InputTokens::from_theme(&theme);
 
-        // Build search bar container
-        div()
-            .relative()
+        let focus_handle = self
+            .focus_handle
+            .get_or_insert_with(|| cx.focus_handle())
+            .clone();
+        if std::mem::take(&mut self.pending_focus) {
+            window.focus(&focus_handle);
+        }
+        let focused = focus_handle.is_focused(window);
+
+        // Show placeholder or query
+        let content = if self.props.value.is_empty() {
+            div()
+                .flex_1()
+                .text_color(tokens.text_placeholder)
+                .child(self.props.placeholder.clone())
+        } else {
+            div().flex_1().child(self.props.value.clone())
+        };
+
+        // Build search bar field
+        let mut field = div()
+            .id("search-bar")
+            .track_focus(&focus_handle)
+            .on_key_down(cx.listener(Self::handle_key))
             .flex()
+            .flex_row()
             .items_center()
+            .gap(theme.global.spacing_sm)
+            .px(tokens.padding_x)
+            .py(tokens.padding_y)
+            .bg(tokens.background)
+            .text_color(tokens.text_color)
+            .text_size(tokens.font_size)
+            .font_weight(tokens.font_weight)
+            .border(tokens.border_width)
+            .border_color(if focused { tokens.border_focus } else { tokens.border_default })
+            .rounded(tokens.border_radius)
+            .cursor_text()
             .child(
                 // Search icon on the left
+                Icon::new(icons::SEARCH)
+                    .size(IconSize::Sm)
+                    .color(IconColor::Muted)
+                    .to_element(&theme),
+            )
+            .child(content);
+
+        if self.props.loading {
+            field = field.child(
+                Spinner::new()
+                    .size(SpinnerSize::Sm)
+                    .animated(!reduced_motion(cx))
+                    .to_element(&theme),
+            );
+        }
+
+        if !self.props.value.is_empty() {
+            field = field.child(
+                // Clear button resets the query and keeps focus in the field
                 div()
-                    .absolute()
-                    .left(theme.global.spacing_sm)
+                    .id("search-bar-clear")
+                    .flex()
+                    .flex_none()
+                    .items_center()
+                    .justify_center()
+                    .cursor_pointer()
+                    .hover(|style| style.opacity(0.7))
+                    .on_click(cx.listener(|this, _event: &ClickEvent, window, cx| {
+                        cx.stop_propagation();
+                        this.clear(window, cx);
+                    }))
                     .child(
-                        Icon::new("M21 21l-6-6m2-5a7 7 0 11-14 0 7 7 0 0114 0z".into()) // Search icon path
+                        Icon::new(icons::X)
                             .size(IconSize::Sm)
                             .color(IconColor::Muted)
-                    )
-            )
-            .child(
-                // Input field with left padding for icon
-                div()
-                    .pl(theme.global.spacing_2xl) // Space for search icon
-                    .child(
-                        Input::new()
-                            .value(self.props.value.clone())
-                            .placeholder(self.props.placeholder.clone())
-                    )
-            )
+                            .to_element(&theme),
+                    ),
+            );
+        }
+
+        field
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_bar_defaults() {
+        let search_bar = SearchBar::new();
+        assert_eq!(search_bar.props.value.as_ref(), "");
+        assert_eq!(search_bar.props.placeholder.as_ref(), "Search...");
+        assert_eq!(search_bar.props.mode, SearchMode::Live);
+        assert_eq!(search_bar.props.debounce, 300);
+        assert!(!search_bar.props.loading);
+        assert!(search_bar.props.on_search.is_none());
+    }
+
+    #[test]
+    fn test_search_bar_builder() {
+        let search_bar = SearchBar::new()
+            .value("query")
+            .placeholder("Find files")
+            .mode(SearchMode::Submit)
+            .debounce(0)
+            .loading(true)
+            .on_search(|_query, _window, _cx| {})
+            .on_clear(|_window, _cx| {});
+
+        assert_eq!(search_bar.props.value.as_ref(), "query");
+        assert_eq!(search_bar.props.placeholder.as_ref(), "Find files");
+        assert_eq!(search_bar.props.mode, SearchMode::Submit);
+        assert_eq!(search_bar.props.debounce, 0);
+        assert!(search_bar.props.loading);
+        assert!(search_bar.props.on_search.is_some());
+        assert!(search_bar.props.on_clear.is_some());
     }
 }
@@ -0,0 +1,297 @@
+//! Stat component for headline metrics with change indicators.
+
+use std::rc::Rc;
+
+use gpui::*;
+use crate::{
+    atoms::{icons, Icon, IconSize, Label, LabelVariant},
+    theme::{use_theme, BadgeTokens},
+};
+
+/// Renders the trend chart shown under a stat's value
+pub type StatSlot = Rc<dyn Fn(&mut Window, &mut App) -> AnyElement>;
+
+/// Whether a change is good, bad or neither
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatTrend {
+    /// The metric moved in the good direction
+    Positive,
+    /// The metric moved in the bad direction
+    Negative,
+    /// The metric did not change
+    Neutral,
+}
+
+/// Stat configuration properties
+#[derive(Clone)]
+pub struct StatProps {
+    /// What the metric measures
+    pub label: SharedString,
+    /// Formatted current value
+    pub value: SharedString,
+    /// Change since the comparison period, in `delta_unit`s
+    pub delta: Option<f64>,
+    /// Unit appended to the delta, such as "%"
+    pub delta_unit: SharedString,
+    /// Comparison period shown after the delta, such as "vs last month"
+    pub caption: Option<SharedString>,
+    /// Whether a decrease is good, as for error rates or latency
+    pub lower_is_better: bool,
+    /// Trend chart under the value, typically a sparkline
+    pub trend: Option<StatSlot>,
+}
+
+impl Default for StatProps {
+    fn default() -> Self {
+        Self {
+            label: "".into(),
+            value: "".into(),
+            delta: None,
+            delta_unit: "%".into(),
+            caption: None,
+            lower_is_better: false,
+            trend: None,
+        }
+    }
+}
+
+/// A headline metric with its change over time.
+///
+/// Stat shows what is measured, the current value, and how it changed:
+/// a delta pill with an up or down arrow, green when the change is good
+/// and red when it is bad. An optional slot below the value holds a trend
+/// chart.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::{charts::*, molecules::*};
+///
+/// Stat::new("Revenue", "$54,321")
+///     .delta(8.2)
+///     .caption("vs last month")
+///     .trend(move |_window, cx| {
+///         Sparkline::new(revenue.clone()).filled(true).to_element(&use_theme(cx)).into_any_element()
+///     });
+///
+/// // Falling latency is good news
+/// Stat::new("p95 latency", "182 ms")
+///     .delta(-12.0)
+///     .lower_is_better(true);
+/// ```
+///
+/// ## Accessibility
+///
+/// - The delta is spelled out with its sign, so it does not rely on color
+///   or the arrow alone
+pub struct Stat {
+    props: StatProps,
+}
+
+impl Stat {
+    /// Create a stat showing `value` for the metric `label`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let stat = Stat::new("Total users", "12,345");
+    /// ```
+    pub fn new(label: impl Into<SharedString>, value: impl Into<SharedString>) -> Self {
+        Self {
+            props: StatProps {
+                label: label.into(),
+                value: value.into(),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Set the change since the comparison period
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// // Shows "+12%"
+    /// Stat::new("Signups", "1,204").delta(12.0);
+    /// ```
+    pub fn delta(mut self, delta: f64) -> Self {
+        self.props.delta = Some(delta);
+        self
+    }
+
+    /// Set the unit appended to the delta
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// // Shows "+3 pts"
+    /// Stat::new("NPS", "48").delta(3.0).delta_unit(" pts");
+    /// ```
+    pub fn delta_unit(mut self, unit: impl Into<SharedString>) -> Self {
+        self.props.delta_unit = unit.into();
+        self
+    }
+
+    /// Set the comparison period shown after the delta
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Stat::new("Revenue", "$54,321").delta(8.0).caption("vs last month");
+    /// ```
+    pub fn caption(mut self, caption: impl Into<SharedString>) -> Self {
+        self.props.caption = Some(caption.into());
+        self
+    }
+
+    /// Set whether a decrease is good news
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Stat::new("Error rate", "0.4%").delta(-0.2).lower_is_better(true);
+    /// ```
+    pub fn lower_is_better(mut self, lower_is_better: bool) -> Self {
+        self.props.lower_is_better = lower_is_better;
+        self
+    }
+
+    /// Set the trend chart shown under the value
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Stat::new("Users", "12,345").trend(|_window, _cx| sparkline.clone().into_any_element());
+    /// ```
+    pub fn trend(mut self, trend: impl Fn(&mut Window, &mut App) -> AnyElement + 'static) -> Self {
+        self.props.trend = Some(Rc::new(trend));
+        self
+    }
+
+    /// Whether the change is good, bad or neither
+    fn trend_direction(&self) -> Option<StatTrend> {
+        let delta = self.props.delta?;
+        Some(if delta.abs() < f64::EPSILON {
+            StatTrend::Neutral
+        } else if (delta > 0.0) != self.props.lower_is_better {
+            StatTrend::Positive
+        } else {
+            StatTrend::Negative
+        })
+    }
+
+    /// Delta with its sign and unit, such as "+12%"
+    fn delta_text(&self) -> Option<SharedString> {
+        let delta = self.props.delta?;
+        let number = format!("{:.1}", delta.abs());
+        let number = number.trim_end_matches('0').trim_end_matches('.');
+        let sign = match delta {
+            d if d.abs() < f64::EPSILON => "",
+            d if d > 0.0 => "+",
+            _ => "-",
+        };
+        Some(format!("{sign}{number}{}", self.props.delta_unit).into())
+    }
+}
+
+impl Render for Stat {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        let tokens = BadgeTokens::from_theme(&theme);
+
+        let mut stat = div()
+            .flex()
+            .flex_col()
+            .gap(theme.global.spacing_sm)
+            .child(
+                Label::new(self.props.label.clone())
+                    .variant(LabelVariant::Caption)
+                    .color(theme.alias.color_text_secondary),
+            )
+            .child(Label::new(self.props.value.clone()).variant(LabelVariant::Heading1));
+
+        if let Some(trend) = self.props.trend.clone() {
+            stat = stat.child(trend(window, cx));
+        }
+
+        if let (Some(direction), Some(text)) = (self.trend_direction(), self.delta_text()) {
+            let (background, color) = match direction {
+                StatTrend::Positive => (tokens.background_success, tokens.text_success),
+                StatTrend::Negative => (tokens.background_danger, tokens.text_danger),
+                StatTrend::Neutral => (tokens.background_default, tokens.text_default),
+            };
+            let arrow = match self.props.delta {
+                _ if direction == StatTrend::Neutral => None,
+                Some(delta) if delta > 0.0 => Some(icons::ARROW_UP),
+                _ => Some(icons::ARROW_DOWN),
+            };
+
+            let pill = div()
+                .flex()
+                .flex_row()
+                .flex_none()
+                .items_center()
+                .gap(px(2.0))
+                .px(tokens.padding_x)
+                .py(tokens.padding_y)
+                .rounded(tokens.border_radius)
+                .bg(background)
+                .text_color(color)
+                .text_size(tokens.font_size)
+                .font_weight(FontWeight(tokens.font_weight as f32))
+                .children(arrow.map(|path| {
+                    Icon::new(path)
+                        .size(IconSize::Xs)
+                        .custom_color(color)
+                        .to_element(&theme)
+                }))
+                .child(text);
+
+            stat = stat.child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap(theme.global.spacing_xs)
+                    .child(pill)
+                    .children(self.props.caption.clone().map(|caption| {
+                        div()
+                            .text_size(theme.global.font_size_xs)
+                            .text_color(theme.alias.color_text_muted)
+                            .child(caption)
+                    })),
+            );
+        }
+
+        stat
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delta_text() {
+        assert_eq!(Stat::new("A", "1").delta(12.0).delta_text().unwrap().as_ref(), "+12%");
+        assert_eq!(Stat::new("A", "1").delta(-3.25).delta_text().unwrap().as_ref(), "-3.3%");
+        assert_eq!(Stat::new("A", "1").delta(0.0).delta_text().unwrap().as_ref(), "0%");
+        assert_eq!(
+            Stat::new("A", "1").delta(3.0).delta_unit(" pts").delta_text().unwrap().as_ref(),
+            "+3 pts"
+        );
+        assert!(Stat::new("A", "1").delta_text().is_none());
+    }
+
+    #[test]
+    fn test_trend_direction() {
+        assert_eq!(Stat::new("A", "1").delta(5.0).trend_direction(), Some(StatTrend::Positive));
+        assert_eq!(Stat::new("A", "1").delta(-5.0).trend_direction(), Some(StatTrend::Negative));
+        assert_eq!(
+            Stat::new("A", "1").delta(-5.0).lower_is_better(true).trend_direction(),
+            Some(StatTrend::Positive)
+        );
+        assert_eq!(Stat::new("A", "1").delta(0.0).trend_direction(), Some(StatTrend::Neutral));
+        assert_eq!(Stat::new("A", "1").trend_direction(), None);
+    }
+}
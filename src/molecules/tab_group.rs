@@ -1,7 +1,17 @@
 //! TabGroup component for tabbed navigation.
 
+use std::rc::Rc;
+
 use gpui::*;
-use crate::{atoms::{Label, LabelVariant}, theme::Theme};
+use gpui::prelude::FluentBuilder;
+use crate::{
+    atoms::{Label, LabelVariant, Icon, IconSize, icons},
+    theme::{use_theme, Theme},
+    utils::{RovingFocus, RovingOrientation},
+};
+
+/// Distance the tab strip scrolls per chevron click
+const SCROLL_STEP: f32 = 200.0;
 
 /// Configuration for a single tab
 #[derive(Clone, Debug)]
@@ -12,6 +22,8 @@ pub struct Tab {
     pub value: SharedString,
     /// Whether tab is disabled
     pub disabled: bool,
+    /// Whether the tab shows a close button
+    pub closable: bool,
 }
 
 impl Tab {
@@ -27,6 +39,7 @@ impl Tab {
             label: label.into(),
             value: value.into(),
             disabled: false,
+            closable: false,
         }
     }
 
@@ -41,6 +54,18 @@ impl Tab {
         self.disabled = disabled;
         self
     }
+
+    /// Set whether the tab shows a close button
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Tab::new("main.rs", "src/main.rs").closable(true);
+    /// ```
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
 }
 
 /// TabGroup visual variants
@@ -66,6 +91,19 @@ pub struct TabGroupProps {
     pub variant: TabGroupVariant,
     /// Whether tabs fill full width
     pub full_width: bool,
+    /// Handler called with the newly selected tab value
+    pub on_change: Option<Rc<dyn Fn(SharedString, &mut Window, &mut App)>>,
+    /// Handler called with the value of a closed tab
+    pub on_close: Option<Rc<dyn Fn(SharedString, &mut Window, &mut App)>>,
+    /// Handler for the "+" new-tab button, which is shown when set
+    pub on_add: Option<Rc<dyn Fn(&mut Window, &mut App)>>,
+    /// Whether tabs run right to left, swapping the Left and Right keys
+    pub rtl: bool,
+    /// Whether the selected tab is owned by the parent view
+    ///
+    /// Controlled tab groups only report selections through `on_change`;
+    /// the parent applies them with [`TabGroup::set_selected`].
+    pub controlled: bool,
 }
 
 impl Default for TabGroupProps {
@@ -75,6 +113,11 @@ impl Default for TabGroupProps {
             selected: "".into(),
             variant: TabGroupVariant::default(),
             full_width: false,
+            on_change: None,
+            on_close: None,
+            on_add: None,
+            rtl: false,
+            controlled: false,
         }
     }
 }
@@ -91,6 +134,8 @@ impl Default for TabGroupProps {
 /// - ARIA roles and attributes for accessibility
 /// - Disabled tab support
 /// - Full-width option
+/// - Closable tabs and a "+" new-tab button
+/// - Scrollable tab strip with chevrons when tabs overflow
 ///
 /// ## Example
 ///
@@ -121,16 +166,37 @@ impl Default for TabGroupProps {
 ///         Tab::new("Overview", "overview"),
 ///         Tab::new("Details", "details"),
 ///     ]);
+///
+/// // Editor-style closable tabs
+/// TabGroup::new()
+///     .tabs(open_files.iter().map(|file| Tab::new(file.name(), file.path()).closable(true)).collect())
+///     .on_close(|path, _window, cx| close_file(path, cx))
+///     .on_add(|window, cx| new_file(window, cx));
 /// ```
 ///
 /// ## Accessibility
 ///
 /// - Uses ARIA `role="tablist"`, `role="tab"`, and `role="tabpanel"`
-/// - Keyboard navigation: Arrow keys, Home, End, Tab
+/// - The tab list is a single tab stop; Left/Right select the previous/next
+///   enabled tab, wrapping at the ends, and Home/End the first/last
+/// - Left and Right swap in right-to-left layouts
 /// - Proper focus management and visual indicators
 /// - Meets WCAG 2.1 AA requirements
 pub struct TabGroup {
     props: TabGroupProps,
+    /// Horizontal scroll position of the tab strip
+    scroll_handle: ScrollHandle,
+    focus_handle: Option<FocusHandle>,
+}
+
+/// Tab to select after the selected tab at `removed` was closed
+///
+/// Prefers the enabled tab that took its place or follows it, then the
+/// nearest enabled tab before it.
+fn fallback_tab(tabs: &[Tab], removed: usize) -> Option<usize> {
+    (removed..tabs.len())
+        .chain((0..removed.min(tabs.len())).rev())
+        .find(|&index| !tabs[index].disabled)
 }
 
 impl TabGroup {
@@ -144,6 +210,8 @@ impl TabGroup {
     pub fn new() -> Self {
         Self {
             props: TabGroupProps::default(),
+            scroll_handle: ScrollHandle::new(),
+            focus_handle: None,
         }
     }
 
@@ -162,7 +230,10 @@ impl TabGroup {
         self
     }
 
-    /// Set the currently selected tab
+    /// Set the currently selected tab, making the tab group controlled
+    ///
+    /// A controlled tab group does not switch tabs on click; apply
+    /// selections reported by `on_change` with [`TabGroup::set_selected`].
     ///
     /// ## Example
     ///
@@ -171,9 +242,113 @@ impl TabGroup {
     /// ```
     pub fn selected(mut self, selected: impl Into<SharedString>) -> Self {
         self.props.selected = selected.into();
+        self.props.controlled = true;
+        self
+    }
+
+    /// Set the initially selected tab of an uncontrolled tab group
+    ///
+    /// The tab group keeps its own selection afterwards.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TabGroup::new().default_selected("home");
+    /// ```
+    pub fn default_selected(mut self, selected: impl Into<SharedString>) -> Self {
+        self.props.selected = selected.into();
+        self.props.controlled = false;
+        self
+    }
+
+    /// Set the handler called when the selected tab changes
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TabGroup::new().on_change(|value, _window, cx| navigate(value, cx));
+    /// ```
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Update the selected tab from the parent view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// tabs.update(cx, |tabs, cx| tabs.set_selected("settings", cx));
+    /// ```
+    pub fn set_selected(&mut self, selected: impl Into<SharedString>, cx: &mut Context<'_, Self>) {
+        let selected = selected.into();
+        if self.props.selected != selected {
+            self.props.selected = selected;
+            cx.notify();
+        }
+    }
+
+    /// Set the handler called when a tab is closed
+    ///
+    /// The tab group removes the closed tab itself, selecting a neighbor if
+    /// it was selected; the handler lets the parent update its own state.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TabGroup::new().on_close(|path, _window, cx| close_file(path, cx));
+    /// ```
+    pub fn on_close(
+        mut self,
+        handler: impl Fn(SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_close = Some(Rc::new(handler));
+        self
+    }
+
+    /// Show a "+" button after the tabs that calls `handler`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TabGroup::new().on_add(|window, cx| new_file(window, cx));
+    /// ```
+    pub fn on_add(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.props.on_add = Some(Rc::new(handler));
         self
     }
 
+    /// Replace the tabs from the parent view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// tabs.update(cx, |tabs, cx| tabs.set_tabs(open_file_tabs(), cx));
+    /// ```
+    pub fn set_tabs(&mut self, tabs: Vec<Tab>, cx: &mut Context<'_, Self>) {
+        self.props.tabs = tabs;
+        cx.notify();
+    }
+
+    /// Append a tab and select it, scrolling it into view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// tabs.update(cx, |tabs, cx| {
+    ///     tabs.add_tab(Tab::new("untitled", "untitled-1").closable(true), window, cx);
+    /// });
+    /// ```
+    pub fn add_tab(&mut self, tab: Tab, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let value = tab.value.clone();
+        self.props.tabs.push(tab);
+        self.select(value, window, cx);
+        cx.notify();
+    }
+
     /// Set the visual variant
     ///
     /// ## Example
@@ -197,18 +372,139 @@ impl TabGroup {
         self.props.full_width = full_width;
         self
     }
+
+    /// Set whether tabs run right to left
+    ///
+    /// Right-to-left tab groups move to the next tab with Left and the
+    /// previous one with Right.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TabGroup::new().rtl(true);
+    /// ```
+    pub fn rtl(mut self, rtl: bool) -> Self {
+        self.props.rtl = rtl;
+        self
+    }
+
+    /// Tab a navigation key moves the selection to, if any
+    fn key_target(&self, key: &str) -> Option<usize> {
+        let tabs = &self.props.tabs;
+        let selected = tabs.iter().position(|tab| tab.value == self.props.selected);
+        RovingFocus::new(RovingOrientation::Horizontal)
+            .rtl(self.props.rtl)
+            .target(key, selected, tabs.len(), |index| !tabs[index].disabled)
+    }
+
+    /// Handle Left, Right, Home and End, selecting the tab moved to
+    fn handle_key(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let Some(index) = self.key_target(&event.keystroke.key) else {
+            return;
+        };
+        self.select(self.props.tabs[index].value.clone(), window, cx);
+        cx.stop_propagation();
+    }
+
+    /// Select a clicked tab and notify the handler
+    fn select(&mut self, value: SharedString, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.props.selected == value {
+            return;
+        }
+
+        if let Some(index) = self.props.tabs.iter().position(|tab| tab.value == value) {
+            self.scroll_handle.scroll_to_item(index);
+        }
+
+        if !self.props.controlled {
+            self.set_selected(value.clone(), cx);
+        }
+
+        if let Some(handler) = self.props.on_change.clone() {
+            handler(value, window, cx);
+        }
+    }
+
+    /// Remove a closed tab, select a neighbor if it was selected, and notify the handler
+    fn close(&mut self, value: SharedString, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let Some(index) = self.props.tabs.iter().position(|tab| tab.value == value) else {
+            return;
+        };
+        self.props.tabs.remove(index);
+
+        if self.props.selected == value {
+            if let Some(next) = fallback_tab(&self.props.tabs, index) {
+                self.select(self.props.tabs[next].value.clone(), window, cx);
+            }
+        }
+
+        if let Some(handler) = self.props.on_close.clone() {
+            handler(value, window, cx);
+        }
+        cx.notify();
+    }
+
+    /// Scroll the tab strip by `delta`, clamped to its scrollable range
+    fn scroll_by(&mut self, delta: Pixels, cx: &mut Context<'_, Self>) {
+        let max = self.scroll_handle.max_offset().width;
+        let offset = self.scroll_handle.offset();
+        let x = offset.x - delta;
+        let x = if x > px(0.0) { px(0.0) } else if x < -max { -max } else { x };
+        self.scroll_handle.set_offset(point(x, offset.y));
+        cx.notify();
+    }
+}
+
+/// Small icon button used for the chevrons, close and "+" buttons
+fn icon_button(id: impl Into<ElementId>, path: &'static str, theme: &Theme) -> Stateful<Div> {
+    div()
+        .id(id)
+        .flex()
+        .flex_none()
+        .items_center()
+        .justify_center()
+        .p(px(2.0))
+        .rounded(theme.global.radius_sm)
+        .cursor_pointer()
+        .hover(|style| style.bg(theme.alias.color_surface_hover))
+        .child(
+            Icon::new(path)
+                .size(IconSize::Xs)
+                .custom_color(theme.alias.color_text_secondary)
+                .to_element(theme),
+        )
 }
 
 impl Render for TabGroup {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
-        let theme = Theme::default();
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        let focus_handle = self
+            .focus_handle
+            .get_or_insert_with(|| cx.focus_handle())
+            .clone();
+        let focused = focus_handle.is_focused(window);
 
         // Build tab list container
         let mut container = div()
+            .id("tab-group")
+            .track_focus(&focus_handle)
+            .on_key_down(cx.listener(Self::handle_key))
             .flex()
             .flex_row()
+            .items_center()
             .gap(theme.global.spacing_xs);
 
+        // Scrollable strip holding the tabs
+        let mut strip = div()
+            .id("tab-strip")
+            .flex()
+            .flex_row()
+            .flex_1()
+            .min_w(px(0.0))
+            .gap(theme.global.spacing_xs)
+            .overflow_x_scroll()
+            .track_scroll(&self.scroll_handle);
+
         // Apply variant-specific container styling
         container = match self.props.variant {
             TabGroupVariant::Line => container
@@ -222,16 +518,19 @@ impl Render for TabGroup {
         };
 
         // Add tabs
-        for tab in &self.props.tabs {
+        for (index, tab) in self.props.tabs.iter().enumerate() {
             let is_selected = tab.value == self.props.selected;
 
             let mut tab_button = div()
+                .id(("tab", index))
                 .px(theme.global.spacing_md)
                 .py(theme.global.spacing_sm)
                 .cursor_pointer()
                 .flex()
+                .flex_none()
                 .items_center()
-                .justify_center();
+                .justify_center()
+                .gap(theme.global.spacing_sm);
 
             // Apply full width if specified
             if self.props.full_width {
@@ -287,11 +586,25 @@ impl Render for TabGroup {
                 }
             };
 
+            // The focus ring sits on the selected tab
+            if focused && is_selected {
+                tab_button = tab_button
+                    .outline_color(theme.alias.color_border_focus)
+                    .outline_w(px(2.0));
+            }
+
             // Apply disabled state
             if tab.disabled {
                 tab_button = tab_button
                     .cursor_not_allowed()
                     .opacity(0.5);
+            } else {
+                let value = tab.value.clone();
+                tab_button = tab_button.on_click(cx.listener(
+                    move |this, _event: &ClickEvent, window, cx| {
+                        this.select(value.clone(), window, cx);
+                    },
+                ));
             }
 
             tab_button = tab_button.child(
@@ -299,7 +612,60 @@ impl Render for TabGroup {
                     .variant(LabelVariant::Body)
             );
 
-            container = container.child(tab_button);
+            if tab.closable {
+                // Close button, also reachable with a middle click on the tab
+                let value = tab.value.clone();
+                tab_button = tab_button
+                    .on_mouse_down(MouseButton::Middle, cx.listener({
+                        let value = value.clone();
+                        move |this, _event: &MouseDownEvent, window, cx| {
+                            this.close(value.clone(), window, cx);
+                        }
+                    }))
+                    .child(
+                        icon_button(("tab-close", index), icons::X, &theme)
+                            .on_click(cx.listener(move |this, _event: &ClickEvent, window, cx| {
+                                // Closing must not also select the tab
+                                cx.stop_propagation();
+                                this.close(value.clone(), window, cx);
+                            })),
+                    );
+            }
+
+            strip = strip.child(tab_button);
+        }
+
+        // Chevrons when the tabs overflow the strip
+        let max_scroll = self.scroll_handle.max_offset().width;
+        let overflowing = max_scroll > px(0.0);
+        let scroll_x = -self.scroll_handle.offset().x;
+        if overflowing {
+            let at_start = scroll_x <= px(0.0);
+            container = container.child(
+                icon_button("tab-scroll-left", icons::CHEVRON_LEFT, &theme)
+                    .when(at_start, |this| this.opacity(0.5).cursor_default())
+                    .on_click(cx.listener(|this, _event: &ClickEvent, _window, cx| {
+                        this.scroll_by(px(-SCROLL_STEP), cx);
+                    })),
+            );
+        }
+        container = container.child(strip);
+        if overflowing {
+            let at_end = scroll_x >= max_scroll;
+            container = container.child(
+                icon_button("tab-scroll-right", icons::CHEVRON_RIGHT, &theme)
+                    .when(at_end, |this| this.opacity(0.5).cursor_default())
+                    .on_click(cx.listener(|this, _event: &ClickEvent, _window, cx| {
+                        this.scroll_by(px(SCROLL_STEP), cx);
+                    })),
+            );
+        }
+
+        if let Some(on_add) = self.props.on_add.clone() {
+            container = container.child(
+                icon_button("tab-add", icons::PLUS, &theme)
+                    .on_click(move |_event, window, cx| on_add(window, cx)),
+            );
         }
 
         container
@@ -352,5 +718,53 @@ mod tests {
         assert_eq!(tab_group.props.selected.as_ref(), "tab1");
         assert_eq!(tab_group.props.variant, TabGroupVariant::Boxed);
         assert!(tab_group.props.full_width);
+        assert!(tab_group.props.controlled);
+    }
+
+    #[test]
+    fn test_tab_closable() {
+        assert!(!Tab::new("Test", "test").closable);
+        assert!(Tab::new("Test", "test").closable(true).closable);
+    }
+
+    #[test]
+    fn test_arrow_keys_skip_disabled_tabs() {
+        let tab_group = TabGroup::new()
+            .tabs(vec![
+                Tab::new("A", "a"),
+                Tab::new("B", "b").disabled(true),
+                Tab::new("C", "c"),
+            ])
+            .selected("a");
+        assert_eq!(tab_group.key_target("right"), Some(2));
+        assert_eq!(tab_group.key_target("left"), Some(2));
+        assert_eq!(tab_group.key_target("home"), Some(0));
+        assert_eq!(tab_group.key_target("down"), None);
+
+        let tab_group = tab_group.rtl(true);
+        assert_eq!(tab_group.key_target("left"), Some(2));
+    }
+
+    #[test]
+    fn test_fallback_after_close() {
+        let tabs = vec![
+            Tab::new("A", "a"),
+            Tab::new("B", "b").disabled(true),
+            Tab::new("C", "c"),
+        ];
+        // Disabled tabs are skipped in favour of the next enabled one
+        assert_eq!(fallback_tab(&tabs, 1), Some(2));
+        assert_eq!(fallback_tab(&tabs, 0), Some(0));
+        // Closing the last tab falls back to the one before it
+        assert_eq!(fallback_tab(&tabs, 3), Some(2));
+        assert_eq!(fallback_tab(&[Tab::new("A", "a").disabled(true)], 1), None);
+        assert_eq!(fallback_tab(&[], 0), None);
+    }
+
+    #[test]
+    fn test_tab_group_default_selected_is_uncontrolled() {
+        let tab_group = TabGroup::new().default_selected("tab2");
+        assert_eq!(tab_group.props.selected.as_ref(), "tab2");
+        assert!(!tab_group.props.controlled);
     }
 }
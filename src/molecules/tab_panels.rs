@@ -0,0 +1,280 @@
+//! TabPanels container showing the content of the selected tab.
+
+use std::{collections::HashMap, rc::Rc};
+
+use gpui::*;
+
+/// Builds the view shown for a tab the first time it is selected
+pub type TabPanelBuilder = Rc<dyn Fn(&mut Window, &mut App) -> AnyView>;
+
+/// Content associated with a single tab value
+#[derive(Clone)]
+pub struct TabPanel {
+    /// Value of the tab this panel belongs to
+    pub value: SharedString,
+    /// Builder for the panel's view
+    pub build: TabPanelBuilder,
+}
+
+/// TabPanels configuration properties
+#[derive(Clone, Default)]
+pub struct TabPanelsProps {
+    /// Panels in tab order
+    pub panels: Vec<TabPanel>,
+    /// Value of the tab whose panel is shown
+    pub selected: SharedString,
+    /// Whether inactive panels stay mounted to preserve their state
+    pub keep_alive: bool,
+}
+
+/// A container showing the panel for the selected tab of a [`TabGroup`](super::TabGroup).
+///
+/// Panels are mounted lazily: a panel's view is only built the first time
+/// its tab is selected. By default switching tabs unmounts the previous
+/// panel, so returning to it builds a fresh view; with `keep_alive` the
+/// views of visited panels are kept, preserving scroll positions, input
+/// contents and any other state they hold.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::molecules::*;
+///
+/// let panels = cx.new(|_| {
+///     TabPanels::new()
+///         .panel("profile", |_window, cx| cx.new(|_| ProfileView::new()).into())
+///         .panel("settings", |_window, cx| cx.new(|_| SettingsView::new()).into())
+///         .selected("profile")
+///         .keep_alive(true)
+/// });
+///
+/// let tabs = cx.new(|_| {
+///     let panels = panels.clone();
+///     TabGroup::new()
+///         .tabs(vec![Tab::new("Profile", "profile"), Tab::new("Settings", "settings")])
+///         .default_selected("profile")
+///         .on_change(move |value, _window, cx| {
+///             panels.update(cx, |panels, cx| panels.set_selected(value, cx));
+///         })
+/// });
+/// ```
+///
+/// ## Accessibility
+///
+/// - The shown panel uses ARIA `role="tabpanel"`, labelled by its tab
+/// - Unmounted and hidden panels are not reachable with Tab
+pub struct TabPanels {
+    props: TabPanelsProps,
+    /// Views of panels that have been shown, keyed by tab value
+    mounted: HashMap<SharedString, AnyView>,
+}
+
+impl TabPanels {
+    /// Create an empty tab panels container
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let panels = TabPanels::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            props: TabPanelsProps::default(),
+            mounted: HashMap::new(),
+        }
+    }
+
+    /// Add a panel for the tab with `value`, replacing any existing one
+    ///
+    /// `build` runs the first time the tab is selected, and again after the
+    /// panel was unmounted.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TabPanels::new().panel("profile", |_window, cx| cx.new(|_| ProfileView::new()).into());
+    /// ```
+    pub fn panel(
+        mut self,
+        value: impl Into<SharedString>,
+        build: impl Fn(&mut Window, &mut App) -> AnyView + 'static,
+    ) -> Self {
+        self.insert_panel(value.into(), Rc::new(build));
+        self
+    }
+
+    /// Set the initially selected tab
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TabPanels::new().selected("profile");
+    /// ```
+    pub fn selected(mut self, selected: impl Into<SharedString>) -> Self {
+        self.props.selected = selected.into();
+        self
+    }
+
+    /// Set whether inactive panels stay mounted
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TabPanels::new().keep_alive(true);
+    /// ```
+    pub fn keep_alive(mut self, keep_alive: bool) -> Self {
+        self.props.keep_alive = keep_alive;
+        self
+    }
+
+    /// Show the panel for `selected`, typically from a TabGroup's `on_change`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// panels.update(cx, |panels, cx| panels.set_selected("settings", cx));
+    /// ```
+    pub fn set_selected(&mut self, selected: impl Into<SharedString>, cx: &mut Context<'_, Self>) {
+        let selected = selected.into();
+        if self.props.selected == selected {
+            return;
+        }
+        if !self.props.keep_alive {
+            self.mounted.clear();
+        }
+        self.props.selected = selected;
+        cx.notify();
+    }
+
+    /// Add a panel from the parent view, e.g. after a tab was added
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// panels.update(cx, |panels, cx| {
+    ///     panels.add_panel("untitled-1", |_window, cx| cx.new(|_| Editor::new()).into(), cx);
+    /// });
+    /// ```
+    pub fn add_panel(
+        &mut self,
+        value: impl Into<SharedString>,
+        build: impl Fn(&mut Window, &mut App) -> AnyView + 'static,
+        cx: &mut Context<'_, Self>,
+    ) {
+        self.insert_panel(value.into(), Rc::new(build));
+        cx.notify();
+    }
+
+    /// Remove a panel and drop its view, e.g. after its tab was closed
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// panels.update(cx, |panels, cx| panels.remove_panel("untitled-1", cx));
+    /// ```
+    pub fn remove_panel(&mut self, value: &str, cx: &mut Context<'_, Self>) {
+        self.props.panels.retain(|panel| panel.value.as_ref() != value);
+        self.mounted.remove(value);
+        cx.notify();
+    }
+
+    /// Whether the panel for `value` currently has a mounted view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// if !panels.read(cx).is_mounted("history") { /* not visited yet */ }
+    /// ```
+    pub fn is_mounted(&self, value: &str) -> bool {
+        self.mounted.contains_key(value)
+    }
+
+    /// Add or replace a panel, dropping the stale view of a replaced one
+    fn insert_panel(&mut self, value: SharedString, build: TabPanelBuilder) {
+        self.mounted.remove(&value);
+        match self.props.panels.iter_mut().find(|panel| panel.value == value) {
+            Some(panel) => panel.build = build,
+            None => self.props.panels.push(TabPanel { value, build }),
+        }
+    }
+}
+
+impl Default for TabPanels {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Render for TabPanels {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let selected = self.props.selected.clone();
+        let panel = self
+            .props
+            .panels
+            .iter()
+            .find(|panel| panel.value == selected)
+            .cloned();
+
+        let mut container = div().size_full();
+
+        if let Some(panel) = panel {
+            // Mount the panel the first time its tab is shown
+            let view = match self.mounted.get(&selected) {
+                Some(view) => view.clone(),
+                None => {
+                    let view = (panel.build)(window, cx);
+                    self.mounted.insert(selected.clone(), view.clone());
+                    view
+                }
+            };
+
+            container = container.child(view);
+        }
+
+        container
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_panels(values: &[&'static str]) -> TabPanels {
+        values.iter().fold(TabPanels::new(), |panels, value| {
+            panels.panel(*value, |_window, _cx| unreachable!("panels are not rendered in tests"))
+        })
+    }
+
+    #[test]
+    fn test_tab_panels_creation() {
+        let panels = TabPanels::new();
+        assert!(panels.props.panels.is_empty());
+        assert_eq!(panels.props.selected.as_ref(), "");
+        assert!(!panels.props.keep_alive);
+    }
+
+    #[test]
+    fn test_tab_panels_builder() {
+        let panels = empty_panels(&["profile", "settings"])
+            .selected("settings")
+            .keep_alive(true);
+
+        assert_eq!(panels.props.panels.len(), 2);
+        assert_eq!(panels.props.selected.as_ref(), "settings");
+        assert!(panels.props.keep_alive);
+    }
+
+    #[test]
+    fn test_panels_are_not_mounted_until_shown() {
+        let panels = empty_panels(&["profile", "settings"]).selected("profile");
+        assert!(!panels.is_mounted("profile"));
+        assert!(!panels.is_mounted("settings"));
+    }
+
+    #[test]
+    fn test_panel_replaces_same_value() {
+        let panels = empty_panels(&["profile", "settings", "profile"]);
+        let values: Vec<&str> = panels.props.panels.iter().map(|panel| panel.value.as_ref()).collect();
+        assert_eq!(values, vec!["profile", "settings"]);
+    }
+}
@@ -0,0 +1,478 @@
+//! TagInput component for editing a list of tags as chips.
+
+use std::rc::Rc;
+
+use gpui::*;
+use crate::{
+    atoms::{Chip, Label, LabelVariant},
+    theme::{use_theme, InputTokens},
+};
+
+/// Validates a new tag, returning an error message to reject it
+pub type TagValidator = Rc<dyn Fn(&str) -> Result<(), SharedString>>;
+
+/// TagInput configuration properties
+#[derive(Clone)]
+pub struct TagInputProps {
+    /// Current tags
+    pub tags: Vec<SharedString>,
+    /// Placeholder shown while there are no tags and no text
+    pub placeholder: SharedString,
+    /// Most tags allowed
+    pub max_tags: Option<usize>,
+    /// Whether the same tag can be added twice
+    pub allow_duplicates: bool,
+    /// Whether the input is disabled
+    pub disabled: bool,
+    /// Check run on each new tag before it is added
+    pub validate: Option<TagValidator>,
+    /// Handler called with the new tags when a tag is added or removed
+    pub on_change: Option<Rc<dyn Fn(Vec<SharedString>, &mut Window, &mut App)>>,
+    /// Handler called with the text and message when a tag is rejected
+    pub on_reject: Option<Rc<dyn Fn(SharedString, SharedString, &mut Window, &mut App)>>,
+    /// Whether the tags are owned by the parent view
+    ///
+    /// Controlled inputs only report edits through `on_change`; the parent
+    /// applies them with [`TagInput::set_tags`].
+    pub controlled: bool,
+}
+
+impl Default for TagInputProps {
+    fn default() -> Self {
+        Self {
+            tags: Vec::new(),
+            placeholder: "Add a tag...".into(),
+            max_tags: None,
+            allow_duplicates: false,
+            disabled: false,
+            validate: None,
+            on_change: None,
+            on_reject: None,
+            controlled: false,
+        }
+    }
+}
+
+/// An input that turns typed text into removable chips.
+///
+/// Typing and pressing Enter or comma adds the text as a tag; Backspace
+/// on empty text removes the last tag. New tags are trimmed and checked
+/// against the tag limit, duplicates and the `validate` callback.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::molecules::*;
+///
+/// TagInput::new()
+///     .default_tags(["rust", "gpui"])
+///     .max_tags(5)
+///     .validate(|tag| {
+///         if tag.len() <= 20 { Ok(()) } else { Err("Tags are at most 20 characters".into()) }
+///     })
+///     .on_change(|tags, _window, cx| save_topics(tags, cx));
+/// ```
+///
+/// ## Accessibility
+///
+/// - Focusable; typing, Enter, comma and Backspace edit the tags
+/// - Rejected tags show their message as text below the field
+pub struct TagInput {
+    props: TagInputProps,
+    /// Text typed since the last tag
+    text: String,
+    /// Message for the most recently rejected tag
+    error: Option<SharedString>,
+    focus_handle: Option<FocusHandle>,
+    pending_focus: bool,
+}
+
+impl TagInput {
+    /// Create a new tag input
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let input = TagInput::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            props: TagInputProps::default(),
+            text: String::new(),
+            error: None,
+            focus_handle: None,
+            pending_focus: false,
+        }
+    }
+
+    /// Set the tags, making the input controlled
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TagInput::new().tags(topics.clone());
+    /// ```
+    pub fn tags<S: Into<SharedString>>(mut self, tags: impl IntoIterator<Item = S>) -> Self {
+        self.props.tags = tags.into_iter().map(Into::into).collect();
+        self.props.controlled = true;
+        self
+    }
+
+    /// Set the initial tags of an uncontrolled input
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TagInput::new().default_tags(["rust", "gpui"]);
+    /// ```
+    pub fn default_tags<S: Into<SharedString>>(mut self, tags: impl IntoIterator<Item = S>) -> Self {
+        self.props.tags = tags.into_iter().map(Into::into).collect();
+        self.props.controlled = false;
+        self
+    }
+
+    /// Set the placeholder text
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TagInput::new().placeholder("Add topics...");
+    /// ```
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.props.placeholder = placeholder.into();
+        self
+    }
+
+    /// Set the most tags allowed
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TagInput::new().max_tags(5);
+    /// ```
+    pub fn max_tags(mut self, max: usize) -> Self {
+        self.props.max_tags = Some(max);
+        self
+    }
+
+    /// Set whether the same tag can be added twice
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TagInput::new().allow_duplicates(true);
+    /// ```
+    pub fn allow_duplicates(mut self, allow: bool) -> Self {
+        self.props.allow_duplicates = allow;
+        self
+    }
+
+    /// Set whether the input is disabled
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TagInput::new().disabled(true);
+    /// ```
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.props.disabled = disabled;
+        self
+    }
+
+    /// Set a check run on each new tag before it is added
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TagInput::new().validate(|tag| {
+    ///     if tag.chars().all(char::is_alphanumeric) { Ok(()) } else { Err("Letters and digits only".into()) }
+    /// });
+    /// ```
+    pub fn validate(mut self, check: impl Fn(&str) -> Result<(), SharedString> + 'static) -> Self {
+        self.props.validate = Some(Rc::new(check));
+        self
+    }
+
+    /// Set the handler called when a tag is added or removed
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TagInput::new().on_change(|tags, _window, cx| save_topics(tags, cx));
+    /// ```
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(Vec<SharedString>, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the handler called with the text and message when a tag is rejected
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TagInput::new().on_reject(|text, message, _window, _cx| log_rejected(text, message));
+    /// ```
+    pub fn on_reject(
+        mut self,
+        handler: impl Fn(SharedString, SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_reject = Some(Rc::new(handler));
+        self
+    }
+
+    /// Current tags
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let topics = input.read(cx).current_tags().to_vec();
+    /// ```
+    pub fn current_tags(&self) -> &[SharedString] {
+        &self.props.tags
+    }
+
+    /// Update the tags from the parent view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// input.update(cx, |input, cx| input.set_tags(Vec::new(), cx));
+    /// ```
+    pub fn set_tags(&mut self, tags: Vec<SharedString>, cx: &mut Context<'_, Self>) {
+        if self.props.tags != tags {
+            self.props.tags = tags;
+            cx.notify();
+        }
+    }
+
+    /// Check `text` as a new tag, returning the tag or the rejection message
+    ///
+    /// Blank text yields `Ok(None)`.
+    fn check_tag(&self, text: &str) -> Result<Option<SharedString>, SharedString> {
+        let tag = text.trim();
+        if tag.is_empty() {
+            return Ok(None);
+        }
+        if self.props.max_tags.is_some_and(|max| self.props.tags.len() >= max) {
+            return Err(format!("At most {} tags", self.props.max_tags.unwrap_or_default()).into());
+        }
+        if !self.props.allow_duplicates && self.props.tags.iter().any(|existing| existing.as_ref() == tag) {
+            return Err(format!("\"{tag}\" is already added").into());
+        }
+        if let Some(check) = &self.props.validate {
+            check(tag)?;
+        }
+        Ok(Some(tag.to_string().into()))
+    }
+
+    /// Apply tags produced by the input itself and notify the handler
+    fn change_tags(&mut self, tags: Vec<SharedString>, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if !self.props.controlled {
+            self.set_tags(tags.clone(), cx);
+        }
+
+        if let Some(handler) = self.props.on_change.clone() {
+            handler(tags, window, cx);
+        }
+    }
+
+    /// Turn the typed text into a tag
+    fn commit(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+        match self.check_tag(&self.text) {
+            Ok(None) => {}
+            Ok(Some(tag)) => {
+                self.text.clear();
+                self.error = None;
+                let mut tags = self.props.tags.clone();
+                tags.push(tag);
+                self.change_tags(tags, window, cx);
+            }
+            Err(message) => {
+                let text: SharedString = self.text.trim().to_string().into();
+                self.error = Some(message.clone());
+                if let Some(handler) = self.props.on_reject.clone() {
+                    handler(text, message, window, cx);
+                }
+            }
+        }
+        cx.notify();
+    }
+
+    /// Remove the tag at `index`
+    fn remove(&mut self, index: usize, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.props.disabled || index >= self.props.tags.len() {
+            return;
+        }
+        let mut tags = self.props.tags.clone();
+        tags.remove(index);
+        self.error = None;
+        self.pending_focus = true;
+        self.change_tags(tags, window, cx);
+        cx.notify();
+    }
+
+    /// Handle typing, commit and removal keys
+    fn handle_key(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.props.disabled {
+            return;
+        }
+
+        let keystroke = &event.keystroke;
+        let is_text = keystroke.key.chars().count() == 1
+            && !keystroke.modifiers.control
+            && !keystroke.modifiers.alt
+            && !keystroke.modifiers.platform;
+
+        match keystroke.key.as_str() {
+            "enter" | "," => self.commit(window, cx),
+            "backspace" if self.text.is_empty() => {
+                if let Some(last) = self.props.tags.len().checked_sub(1) {
+                    self.remove(last, window, cx);
+                }
+            }
+            "backspace" => {
+                self.text.pop();
+                self.error = None;
+            }
+            "escape" if !self.text.is_empty() => {
+                self.text.clear();
+                self.error = None;
+            }
+            "space" => self.text.push(' '),
+            key if is_text => {
+                let key = keystroke.key_char.as_deref().unwrap_or(key);
+                self.text.push_str(key);
+                self.error = None;
+            }
+            _ => return,
+        }
+
+        cx.notify();
+        cx.stop_propagation();
+    }
+}
+
+impl Default for TagInput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Render for TagInput {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        let tokens = InputTokens::from_theme(&theme);
+
+        let focus_handle = self
+            .focus_handle
+            .get_or_insert_with(|| cx.focus_handle())
+            .clone();
+        if std::mem::take(&mut self.pending_focus) {
+            window.focus(&focus_handle);
+        }
+        let focused = focus_handle.is_focused(window);
+
+        let border = if self.error.is_some() {
+            tokens.border_error
+        } else if focused {
+            tokens.border_focus
+        } else {
+            tokens.border_default
+        };
+
+        let mut field = div()
+            .id("tag-input")
+            .track_focus(&focus_handle)
+            .on_key_down(cx.listener(Self::handle_key))
+            .flex()
+            .flex_row()
+            .flex_wrap()
+            .items_center()
+            .gap(theme.global.spacing_xs)
+            .px(tokens.padding_x)
+            .py(tokens.padding_y)
+            .bg(if self.props.disabled { tokens.background_disabled } else { tokens.background })
+            .text_color(if self.props.disabled { tokens.text_disabled } else { tokens.text_color })
+            .text_size(tokens.font_size)
+            .font_weight(tokens.font_weight)
+            .border(tokens.border_width)
+            .border_color(border)
+            .rounded(tokens.border_radius)
+            .cursor_text();
+
+        for (index, tag) in self.props.tags.iter().enumerate() {
+            let mut chip = Chip::new(tag.clone()).id(("tag-input-chip", index)).disabled(self.props.disabled);
+            if !self.props.disabled {
+                let remove = cx.listener(move |this, _: &(), window, cx| this.remove(index, window, cx));
+                chip = chip.on_remove(move |window, cx| remove(&(), window, cx));
+            }
+            field = field.child(chip.to_element(&theme));
+        }
+
+        // Typed text, or the placeholder while empty
+        if self.text.is_empty() && self.props.tags.is_empty() {
+            field = field.child(
+                div()
+                    .flex_1()
+                    .text_color(tokens.text_placeholder)
+                    .child(self.props.placeholder.clone()),
+            );
+        } else {
+            field = field.child(div().flex_1().min_w(px(40.0)).child(self.text.clone()));
+        }
+
+        let mut input = div().flex().flex_col().gap(theme.global.spacing_xs).child(field);
+        if let Some(error) = self.error.clone() {
+            input = input.child(
+                Label::new(error)
+                    .variant(LabelVariant::Caption)
+                    .color(tokens.text_error),
+            );
+        }
+
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tag_input_defaults() {
+        let input = TagInput::new();
+        assert!(input.current_tags().is_empty());
+        assert!(!input.props.allow_duplicates);
+        assert!(!input.props.controlled);
+        assert!(TagInput::new().tags(["a"]).props.controlled);
+    }
+
+    #[test]
+    fn test_check_tag_trims_and_skips_blank() {
+        let input = TagInput::new();
+        assert_eq!(input.check_tag("  rust "), Ok(Some("rust".into())));
+        assert_eq!(input.check_tag("   "), Ok(None));
+    }
+
+    #[test]
+    fn test_check_tag_limits() {
+        let input = TagInput::new().default_tags(["rust", "gpui"]).max_tags(2);
+        assert!(input.check_tag("ui").is_err());
+
+        let input = TagInput::new().default_tags(["rust"]);
+        assert!(input.check_tag("rust").is_err());
+        assert!(input.allow_duplicates(true).check_tag("rust").is_ok());
+    }
+
+    #[test]
+    fn test_check_tag_runs_validator() {
+        let input = TagInput::new().validate(|tag| {
+            if tag.starts_with('#') { Err("Leave out the #".into()) } else { Ok(()) }
+        });
+        assert_eq!(input.check_tag("#rust"), Err("Leave out the #".into()));
+        assert!(input.check_tag("rust").is_ok());
+    }
+}
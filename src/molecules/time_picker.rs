@@ -0,0 +1,754 @@
+//! TimePicker component with hour, minute and second spinners.
+
+use std::{fmt, rc::Rc};
+
+use gpui::*;
+use crate::{
+    atoms::{icons, Icon, IconSize},
+    theme::{use_theme, InputTokens, Theme},
+};
+
+/// A time of day with second precision
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct TimeValue {
+    /// Hour, 0-23
+    pub hour: u8,
+    /// Minute, 0-59
+    pub minute: u8,
+    /// Second, 0-59
+    pub second: u8,
+}
+
+impl TimeValue {
+    /// Create a time, or `None` when a component is out of range
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let lunch = TimeValue::new(12, 30, 0).unwrap();
+    /// ```
+    pub fn new(hour: u8, minute: u8, second: u8) -> Option<Self> {
+        (hour < 24 && minute < 60 && second < 60).then_some(Self { hour, minute, second })
+    }
+
+    /// Parse `14:30`, `14:30:15`, `2:30 PM` or `2:30:15 pm`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// assert_eq!(TimeValue::parse("2:30 PM"), TimeValue::new(14, 30, 0));
+    /// ```
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim().to_ascii_lowercase();
+        let (clock, period) = match text.strip_suffix("am").or_else(|| text.strip_suffix("pm")) {
+            Some(clock) => (clock.trim_end(), Some(text.ends_with("pm"))),
+            None => (text.as_str(), None),
+        };
+
+        let mut parts = clock.split(':').map(|part| part.parse::<u8>().ok());
+        let hour = parts.next()??;
+        let minute = parts.next()??;
+        let second = parts.next().unwrap_or(Some(0))?;
+        if parts.next().is_some() {
+            return None;
+        }
+
+        let hour = match period {
+            Some(_) if hour == 0 || hour > 12 => return None,
+            Some(pm) => hour % 12 + if pm { 12 } else { 0 },
+            None => hour,
+        };
+        Self::new(hour, minute, second)
+    }
+
+    /// Format the time, e.g. `14:30`, `2:30 PM` or `02:30:15 PM`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let label = time.format(TimeFormat::H12, false);
+    /// ```
+    pub fn format(self, format: TimeFormat, show_seconds: bool) -> String {
+        let hour = match format {
+            TimeFormat::H24 => format!("{:02}", self.hour),
+            TimeFormat::H12 => self.hour_12().to_string(),
+        };
+        let mut text = format!("{hour}:{:02}", self.minute);
+        if show_seconds {
+            text.push_str(&format!(":{:02}", self.second));
+        }
+        if format == TimeFormat::H12 {
+            text.push_str(if self.is_pm() { " PM" } else { " AM" });
+        }
+        text
+    }
+
+    /// Hour on a 12-hour clock, 1-12
+    fn hour_12(self) -> u8 {
+        match self.hour % 12 {
+            0 => 12,
+            hour => hour,
+        }
+    }
+
+    /// Whether the time is at or after noon
+    fn is_pm(self) -> bool {
+        self.hour >= 12
+    }
+
+    /// Time with `segment` moved by `steps`, wrapping within the segment
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn stepped(self, segment: TimeSegment, steps: i32) -> Self {
+        let wrap = |value: u8, modulo: i32| (i32::from(value) + steps).rem_euclid(modulo) as u8;
+        match segment {
+            TimeSegment::Hour => Self { hour: wrap(self.hour, 24), ..self },
+            TimeSegment::Minute => Self { minute: wrap(self.minute, 60), ..self },
+            TimeSegment::Second => Self { second: wrap(self.second, 60), ..self },
+            TimeSegment::Period => Self { hour: (self.hour + 12) % 24, ..self },
+        }
+    }
+}
+
+impl fmt::Display for TimeValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.format(TimeFormat::H24, true))
+    }
+}
+
+/// Clock used to display times
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeFormat {
+    /// 24-hour clock, 00-23
+    #[default]
+    H24,
+    /// 12-hour clock with AM/PM
+    H12,
+}
+
+/// Editable part of the time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TimeSegment {
+    Hour,
+    Minute,
+    Second,
+    Period,
+}
+
+/// TimePicker configuration properties
+#[derive(Clone)]
+pub struct TimePickerProps {
+    /// Selected time, `None` when empty
+    pub value: Option<TimeValue>,
+    /// 12 or 24-hour display
+    pub format: TimeFormat,
+    /// Whether seconds are shown and editable
+    pub show_seconds: bool,
+    /// Minutes moved per minute step
+    pub minute_step: u8,
+    /// Earliest valid time
+    pub min: Option<TimeValue>,
+    /// Latest valid time
+    pub max: Option<TimeValue>,
+    /// Whether the picker is disabled
+    pub disabled: bool,
+    /// Whether to show the error state regardless of `min`/`max`
+    pub error: bool,
+    /// Handler called with the new time when the picker changes it
+    pub on_change: Option<Rc<dyn Fn(TimeValue, &mut Window, &mut App)>>,
+    /// Whether the value is owned by the parent view
+    ///
+    /// Controlled pickers only report edits through `on_change`; the parent
+    /// applies them with [`TimePicker::set_value`].
+    pub controlled: bool,
+}
+
+impl Default for TimePickerProps {
+    fn default() -> Self {
+        Self {
+            value: None,
+            format: TimeFormat::default(),
+            show_seconds: false,
+            minute_step: 1,
+            min: None,
+            max: None,
+            disabled: false,
+            error: false,
+            on_change: None,
+            controlled: false,
+        }
+    }
+}
+
+/// A time picker with hour, minute and optional second spinners.
+///
+/// Each segment has stepper arrows and can be edited from the keyboard.
+/// Times outside `min`/`max` show the error state.
+///
+/// ## Features
+///
+/// - 12-hour (AM/PM) and 24-hour modes
+/// - Optional seconds
+/// - Minute step, e.g. 15-minute slots
+/// - Min/max validation
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::molecules::*;
+///
+/// // 24-hour picker
+/// TimePicker::new()
+///     .default_value(TimeValue::new(9, 0, 0).unwrap())
+///     .on_change(|time, _window, cx| save_start_time(time, cx));
+///
+/// // 12-hour picker in quarter-hour steps within business hours
+/// TimePicker::new()
+///     .format(TimeFormat::H12)
+///     .minute_step(15)
+///     .min(TimeValue::new(9, 0, 0).unwrap())
+///     .max(TimeValue::new(17, 0, 0).unwrap());
+/// ```
+///
+/// ## Accessibility
+///
+/// - Focusable; Left/Right move between segments, Up/Down change the focused segment
+/// - Digits type into the focused segment, A/P switch AM/PM in 12-hour mode
+pub struct TimePicker {
+    props: TimePickerProps,
+    focus_handle: Option<FocusHandle>,
+    /// Segment edited from the keyboard
+    active: TimeSegment,
+    /// Digits typed into the active segment so far
+    typed: String,
+}
+
+impl TimePicker {
+    /// Create a new time picker
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let picker = TimePicker::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            props: TimePickerProps::default(),
+            focus_handle: None,
+            active: TimeSegment::Hour,
+            typed: String::new(),
+        }
+    }
+
+    /// Set the time, making the picker controlled
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TimePicker::new().value(TimeValue::new(14, 30, 0).unwrap());
+    /// ```
+    pub fn value(mut self, value: TimeValue) -> Self {
+        self.props.value = Some(value);
+        self.props.controlled = true;
+        self
+    }
+
+    /// Set the initial time of an uncontrolled picker
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TimePicker::new().default_value(TimeValue::new(9, 0, 0).unwrap());
+    /// ```
+    pub fn default_value(mut self, value: TimeValue) -> Self {
+        self.props.value = Some(value);
+        self.props.controlled = false;
+        self
+    }
+
+    /// Set 12 or 24-hour display
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TimePicker::new().format(TimeFormat::H12);
+    /// ```
+    pub fn format(mut self, format: TimeFormat) -> Self {
+        self.props.format = format;
+        self
+    }
+
+    /// Set whether seconds are shown
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TimePicker::new().show_seconds(true);
+    /// ```
+    pub fn show_seconds(mut self, show_seconds: bool) -> Self {
+        self.props.show_seconds = show_seconds;
+        self
+    }
+
+    /// Set how many minutes the minute spinner moves per step
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TimePicker::new().minute_step(15);
+    /// ```
+    pub fn minute_step(mut self, step: u8) -> Self {
+        self.props.minute_step = step.clamp(1, 30);
+        self
+    }
+
+    /// Set the earliest valid time
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TimePicker::new().min(TimeValue::new(9, 0, 0).unwrap());
+    /// ```
+    pub fn min(mut self, min: TimeValue) -> Self {
+        self.props.min = Some(min);
+        self
+    }
+
+    /// Set the latest valid time
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TimePicker::new().max(TimeValue::new(17, 0, 0).unwrap());
+    /// ```
+    pub fn max(mut self, max: TimeValue) -> Self {
+        self.props.max = Some(max);
+        self
+    }
+
+    /// Set whether the picker is disabled
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TimePicker::new().disabled(true);
+    /// ```
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.props.disabled = disabled;
+        self
+    }
+
+    /// Set whether to show the error state, e.g. for validation done by the parent
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TimePicker::new().error(true);
+    /// ```
+    pub fn error(mut self, error: bool) -> Self {
+        self.props.error = error;
+        self
+    }
+
+    /// Set the handler called when the picker changes the time
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TimePicker::new().on_change(|time, _window, cx| save_start_time(time, cx));
+    /// ```
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(TimeValue, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Update the time from the parent view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// picker.update(cx, |picker, cx| picker.set_value(None, cx));
+    /// ```
+    pub fn set_value(&mut self, value: Option<TimeValue>, cx: &mut Context<'_, Self>) {
+        if self.props.value != value {
+            self.props.value = value;
+            cx.notify();
+        }
+    }
+
+    /// Replace all props, for pickers embedded in another control
+    ///
+    /// Only re-renders when something other than the handler changed, so
+    /// the parent can call this on every render.
+    pub(crate) fn set_props(&mut self, props: TimePickerProps, cx: &mut Context<'_, Self>) {
+        let old = &self.props;
+        let changed = old.value != props.value
+            || old.format != props.format
+            || old.show_seconds != props.show_seconds
+            || old.minute_step != props.minute_step
+            || old.min != props.min
+            || old.max != props.max
+            || old.disabled != props.disabled
+            || old.error != props.error
+            || old.controlled != props.controlled;
+        self.props = props;
+        if changed {
+            cx.notify();
+        }
+    }
+
+    /// Whether the time lies within `min` and `max`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let ok = picker.read(cx).is_valid();
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        self.props.value.is_none_or(|value| {
+            self.props.min.is_none_or(|min| value >= min)
+                && self.props.max.is_none_or(|max| value <= max)
+        })
+    }
+
+    /// Segments shown for the current format
+    fn segments(&self) -> Vec<TimeSegment> {
+        let mut segments = vec![TimeSegment::Hour, TimeSegment::Minute];
+        if self.props.show_seconds {
+            segments.push(TimeSegment::Second);
+        }
+        if self.props.format == TimeFormat::H12 {
+            segments.push(TimeSegment::Period);
+        }
+        segments
+    }
+
+    /// Displayed text of `segment`, or a placeholder when empty
+    fn segment_text(&self, segment: TimeSegment) -> SharedString {
+        let Some(value) = self.props.value else {
+            return "--".into();
+        };
+        match segment {
+            TimeSegment::Hour => match self.props.format {
+                TimeFormat::H24 => format!("{:02}", value.hour).into(),
+                TimeFormat::H12 => format!("{:02}", value.hour_12()).into(),
+            },
+            TimeSegment::Minute => format!("{:02}", value.minute).into(),
+            TimeSegment::Second => format!("{:02}", value.second).into(),
+            TimeSegment::Period => if value.is_pm() { "PM" } else { "AM" }.into(),
+        }
+    }
+
+    /// Time after stepping `segment` up (`direction > 0`) or down
+    fn stepped_value(&self, segment: TimeSegment, direction: i32) -> TimeValue {
+        let value = self.props.value.unwrap_or_default();
+        let steps = match segment {
+            TimeSegment::Minute => {
+                // Move to the next multiple of the step so minutes stay on its grid
+                let step = i32::from(self.props.minute_step);
+                let minute = i32::from(value.minute);
+                let target = if direction > 0 {
+                    minute.div_euclid(step) * step + step
+                } else {
+                    (minute + step - 1).div_euclid(step) * step - step
+                };
+                target - minute
+            }
+            _ => direction,
+        };
+        value.stepped(segment, steps)
+    }
+
+    /// Apply a time produced by the picker itself and notify the handler
+    fn change_value(&mut self, value: TimeValue, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.props.disabled || self.props.value == Some(value) {
+            return;
+        }
+
+        if !self.props.controlled {
+            self.set_value(Some(value), cx);
+        }
+
+        if let Some(handler) = self.props.on_change.clone() {
+            handler(value, window, cx);
+        }
+    }
+
+    /// Move the active segment left (`-1`) or right (`1`)
+    fn move_active(&mut self, direction: isize) {
+        let segments = self.segments();
+        let index = segments.iter().position(|s| *s == self.active).unwrap_or(0);
+        let next = index.saturating_add_signed(direction).min(segments.len() - 1);
+        self.active = segments[next];
+        self.typed.clear();
+    }
+
+    /// Apply a digit typed into the active segment
+    fn type_digit(&mut self, digit: char, window: &mut Window, cx: &mut Context<'_, Self>) {
+        self.typed.push(digit);
+        let Ok(number) = self.typed.parse::<u8>() else {
+            self.typed.clear();
+            return;
+        };
+
+        let value = self.props.value.unwrap_or_default();
+        let next = match self.active {
+            TimeSegment::Hour => match self.props.format {
+                TimeFormat::H24 => TimeValue::new(number, value.minute, value.second),
+                TimeFormat::H12 => (1..=12).contains(&number).then(|| {
+                    let hour = number % 12 + if value.is_pm() { 12 } else { 0 };
+                    TimeValue { hour, ..value }
+                }),
+            },
+            TimeSegment::Minute => TimeValue::new(value.hour, number, value.second),
+            TimeSegment::Second => TimeValue::new(value.hour, value.minute, number),
+            TimeSegment::Period => None,
+        };
+
+        match next {
+            Some(next) => self.change_value(next, window, cx),
+            None => self.typed.clear(),
+        }
+        // Two digits complete a segment
+        if self.typed.len() >= 2 {
+            self.move_active(1);
+        }
+    }
+
+    /// Handle segment navigation, stepping and typing
+    fn handle_key(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.props.disabled {
+            return;
+        }
+
+        let key = event.keystroke.key.as_str();
+        match key {
+            "left" => self.move_active(-1),
+            "right" => self.move_active(1),
+            "up" | "down" => {
+                let direction = if key == "up" { 1 } else { -1 };
+                self.typed.clear();
+                self.change_value(self.stepped_value(self.active, direction), window, cx);
+            }
+            "a" | "p" if self.props.format == TimeFormat::H12 => {
+                let value = self.props.value.unwrap_or_default();
+                if value.is_pm() != (key == "p") {
+                    self.change_value(value.stepped(TimeSegment::Period, 1), window, cx);
+                }
+            }
+            _ if key.len() == 1 && key.chars().all(|c| c.is_ascii_digit()) => {
+                if let Some(digit) = key.chars().next() {
+                    self.type_digit(digit, window, cx);
+                }
+            }
+            _ => return,
+        }
+        cx.notify();
+        cx.stop_propagation();
+    }
+
+    /// Render one segment with its stepper arrows
+    fn render_segment(
+        &self,
+        segment: TimeSegment,
+        index: usize,
+        focused: bool,
+        theme: &Theme,
+        cx: &mut Context<'_, Self>,
+    ) -> Div {
+        let active = focused && self.active == segment;
+        let arrow = |id: (&'static str, usize), path: &'static str| {
+            div()
+                .id(id)
+                .flex()
+                .items_center()
+                .justify_center()
+                .child(
+                    Icon::new(path)
+                        .size(IconSize::Xs)
+                        .custom_color(theme.alias.color_text_secondary)
+                        .to_element(theme),
+                )
+        };
+
+        let mut up = arrow(("time-picker-up", index), icons::CHEVRON_UP);
+        let mut down = arrow(("time-picker-down", index), icons::CHEVRON_DOWN);
+        if !self.props.disabled {
+            up = up
+                .cursor_pointer()
+                .hover(|style| style.opacity(0.7))
+                .on_click(cx.listener(move |this, _event: &ClickEvent, window, cx| {
+                    this.active = segment;
+                    this.change_value(this.stepped_value(segment, 1), window, cx);
+                }));
+            down = down
+                .cursor_pointer()
+                .hover(|style| style.opacity(0.7))
+                .on_click(cx.listener(move |this, _event: &ClickEvent, window, cx| {
+                    this.active = segment;
+                    this.change_value(this.stepped_value(segment, -1), window, cx);
+                }));
+        }
+
+        let mut text = div()
+            .id(("time-picker-segment", index))
+            .px(px(2.0))
+            .rounded(theme.global.radius_sm)
+            .child(self.segment_text(segment))
+            .on_click(cx.listener(move |this, _event: &ClickEvent, _window, cx| {
+                this.active = segment;
+                this.typed.clear();
+                cx.notify();
+            }));
+        if active {
+            text = text
+                .bg(theme.alias.color_primary)
+                .text_color(theme.alias.color_text_on_primary);
+        }
+
+        div()
+            .flex()
+            .flex_col()
+            .items_center()
+            .child(up)
+            .child(text)
+            .child(down)
+    }
+}
+
+impl Default for TimePicker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Render for TimePicker {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        let tokens = InputTokens::from_theme(&theme);
+
+        let focus_handle = self
+            .focus_handle
+            .get_or_insert_with(|| cx.focus_handle())
+            .clone();
+        let focused = focus_handle.is_focused(window);
+
+        let border = if self.props.error || !self.is_valid() {
+            tokens.border_error
+        } else if focused {
+            tokens.border_focus
+        } else {
+            tokens.border_default
+        };
+
+        let mut field = div()
+            .id("time-picker")
+            .track_focus(&focus_handle)
+            .on_key_down(cx.listener(Self::handle_key))
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap(px(2.0))
+            .px(tokens.padding_x)
+            .py(px(2.0))
+            .bg(if self.props.disabled { tokens.background_disabled } else { tokens.background })
+            .text_color(if self.props.disabled { tokens.text_disabled } else { tokens.text_color })
+            .text_size(tokens.font_size)
+            .font_weight(tokens.font_weight)
+            .border(tokens.border_width)
+            .border_color(border)
+            .rounded(tokens.border_radius);
+
+        for (index, segment) in self.segments().into_iter().enumerate() {
+            match segment {
+                TimeSegment::Minute | TimeSegment::Second => field = field.child(":"),
+                TimeSegment::Period => field = field.child(div().w(px(4.0))),
+                TimeSegment::Hour => {}
+            }
+            field = field.child(self.render_segment(segment, index, focused, &theme, cx));
+        }
+
+        field
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(hour: u8, minute: u8, second: u8) -> TimeValue {
+        TimeValue::new(hour, minute, second).unwrap()
+    }
+
+    #[test]
+    fn test_time_value_range() {
+        assert!(TimeValue::new(23, 59, 59).is_some());
+        assert!(TimeValue::new(24, 0, 0).is_none());
+        assert!(TimeValue::new(0, 60, 0).is_none());
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(TimeValue::parse("14:30"), Some(time(14, 30, 0)));
+        assert_eq!(TimeValue::parse("14:30:15"), Some(time(14, 30, 15)));
+        assert_eq!(TimeValue::parse("2:30 PM"), Some(time(14, 30, 0)));
+        assert_eq!(TimeValue::parse("12:05 am"), Some(time(0, 5, 0)));
+        assert_eq!(TimeValue::parse("12:00 PM"), Some(time(12, 0, 0)));
+        assert_eq!(TimeValue::parse("13:00 PM"), None);
+        assert_eq!(TimeValue::parse("25:00"), None);
+        assert_eq!(TimeValue::parse("1:2:3:4"), None);
+    }
+
+    #[test]
+    fn test_format() {
+        let t = time(14, 5, 9);
+        assert_eq!(t.format(TimeFormat::H24, false), "14:05");
+        assert_eq!(t.format(TimeFormat::H24, true), "14:05:09");
+        assert_eq!(t.format(TimeFormat::H12, false), "2:05 PM");
+        assert_eq!(time(0, 0, 0).format(TimeFormat::H12, false), "12:00 AM");
+    }
+
+    #[test]
+    fn test_stepping_wraps() {
+        assert_eq!(time(23, 0, 0).stepped(TimeSegment::Hour, 1), time(0, 0, 0));
+        assert_eq!(time(10, 0, 0).stepped(TimeSegment::Minute, -15), time(10, 45, 0));
+        assert_eq!(time(9, 0, 0).stepped(TimeSegment::Period, 1), time(21, 0, 0));
+    }
+
+    #[test]
+    fn test_minute_stepping_snaps_to_step() {
+        let picker = TimePicker::new().minute_step(15).default_value(time(10, 50, 0));
+        assert_eq!(picker.stepped_value(TimeSegment::Minute, 1), time(10, 0, 0));
+        assert_eq!(picker.stepped_value(TimeSegment::Minute, -1), time(10, 45, 0));
+
+        let picker = TimePicker::new().minute_step(15).default_value(time(10, 37, 0));
+        assert_eq!(picker.stepped_value(TimeSegment::Minute, 1), time(10, 45, 0));
+        assert_eq!(picker.stepped_value(TimeSegment::Minute, -1), time(10, 30, 0));
+
+        // Minutes already on the grid move by a full step
+        let picker = TimePicker::new().minute_step(15).default_value(time(10, 30, 0));
+        assert_eq!(picker.stepped_value(TimeSegment::Minute, 1), time(10, 45, 0));
+        assert_eq!(picker.stepped_value(TimeSegment::Minute, -1), time(10, 15, 0));
+    }
+
+    #[test]
+    fn test_min_max_validation() {
+        let picker = TimePicker::new()
+            .default_value(time(8, 0, 0))
+            .min(time(9, 0, 0))
+            .max(time(17, 0, 0));
+        assert!(!picker.is_valid());
+        assert!(TimePicker::new().min(time(9, 0, 0)).is_valid());
+    }
+
+    #[test]
+    fn test_segments_follow_format() {
+        let picker = TimePicker::new().format(TimeFormat::H12).show_seconds(true);
+        assert_eq!(
+            picker.segments(),
+            vec![TimeSegment::Hour, TimeSegment::Minute, TimeSegment::Second, TimeSegment::Period]
+        );
+    }
+}
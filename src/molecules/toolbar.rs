@@ -0,0 +1,719 @@
+//! Toolbar component: a row of actions that overflows into a menu.
+
+use std::{cell::{Cell, RefCell}, rc::Rc};
+
+use gpui::*;
+use crate::{
+    atoms::{icons, Button, ButtonSize, ButtonVariant, Icon, IconSize},
+    theme::use_theme,
+    utils::{AnchorOptions, AnchoredState, Placement, PlacementAlign, RovingFocus, RovingOrientation},
+};
+
+/// Width assumed for the overflow button before it is first measured
+const MORE_BUTTON_WIDTH: f32 = 32.0;
+
+/// A button in a [`Toolbar`]
+#[derive(Clone)]
+pub struct ToolbarAction {
+    /// Identifies the action among its siblings
+    pub id: SharedString,
+    /// Button label, also shown in the overflow menu
+    pub label: SharedString,
+    /// SVG path of an icon shown before the label
+    pub icon: Option<SharedString>,
+    /// Whether the toolbar button shows only the icon
+    pub icon_only: bool,
+    /// Whether the action is disabled
+    pub disabled: bool,
+    /// Called when the action is clicked or chosen from the overflow menu
+    pub on_click: Option<Rc<dyn Fn(&mut Window, &mut App)>>,
+}
+
+impl ToolbarAction {
+    /// Create an action with `id` and `label`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let save = ToolbarAction::new("save", "Save");
+    /// ```
+    pub fn new(id: impl Into<SharedString>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            icon: None,
+            icon_only: false,
+            disabled: false,
+            on_click: None,
+        }
+    }
+
+    /// Set the icon shown before the label
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ToolbarAction::new("download", "Download").icon(icons::DOWNLOAD);
+    /// ```
+    pub fn icon(mut self, path: impl Into<SharedString>) -> Self {
+        self.icon = Some(path.into());
+        self
+    }
+
+    /// Set whether the toolbar button shows only the icon
+    ///
+    /// The overflow menu always shows the label.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ToolbarAction::new("download", "Download").icon(icons::DOWNLOAD).icon_only(true);
+    /// ```
+    pub fn icon_only(mut self, icon_only: bool) -> Self {
+        self.icon_only = icon_only;
+        self
+    }
+
+    /// Set whether the action is disabled
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ToolbarAction::new("paste", "Paste").disabled(clipboard_empty);
+    /// ```
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the handler called when the action is chosen
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ToolbarAction::new("save", "Save").on_click(|window, cx| save(window, cx));
+    /// ```
+    pub fn on_click(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
+}
+
+/// An entry in a [`Toolbar`]
+#[derive(Clone)]
+pub enum ToolbarItem {
+    /// A button
+    Action(ToolbarAction),
+    /// A vertical line between groups of actions
+    Separator,
+}
+
+/// Toolbar configuration properties
+#[derive(Clone)]
+pub struct ToolbarProps {
+    /// Actions and separators, in order
+    pub items: Vec<ToolbarItem>,
+    /// Button size
+    pub size: ButtonSize,
+    /// Whether actions that don't fit move into an overflow menu
+    pub overflow: bool,
+}
+
+impl Default for ToolbarProps {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            size: ButtonSize::Sm,
+            overflow: true,
+        }
+    }
+}
+
+/// A horizontal bar of actions.
+///
+/// Toolbar lays out actions in a row, with separators between groups.
+/// Actions that don't fit the available width move, from the end, into a
+/// "More actions" menu opened by a button at the end of the row.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::molecules::*;
+///
+/// Toolbar::new()
+///     .action(ToolbarAction::new("new", "New").on_click(|window, cx| new_file(window, cx)))
+///     .action(ToolbarAction::new("open", "Open").on_click(|window, cx| open_file(window, cx)))
+///     .separator()
+///     .action(
+///         ToolbarAction::new("download", "Download")
+///             .icon(icons::DOWNLOAD)
+///             .icon_only(true)
+///             .on_click(|window, cx| download(window, cx)),
+///     );
+/// ```
+///
+/// ## Accessibility
+///
+/// - The toolbar is a single Tab stop; Left and Right move between enabled
+///   actions and the overflow button, Home and End jump to the ends
+/// - Enter or Space activates the action with the focus ring, or opens the
+///   overflow menu
+/// - In the menu, Up and Down move, Enter chooses and Escape closes
+pub struct Toolbar {
+    props: ToolbarProps,
+    focus_handle: Option<FocusHandle>,
+    /// Entry with the focus ring; `items.len()` is the overflow button
+    active: Option<usize>,
+    /// Measured width of each item, kept while the item is in the menu
+    widths: Rc<RefCell<Vec<Pixels>>>,
+    /// Measured width of the toolbar
+    available: Rc<Cell<Option<Pixels>>>,
+    /// Measured width of the overflow button
+    more_width: Rc<Cell<Option<Pixels>>>,
+    /// Whether the overflow menu is open
+    menu_open: bool,
+    /// Overflowed item highlighted in the menu
+    highlighted: Option<usize>,
+    anchor: AnchoredState,
+}
+
+impl Toolbar {
+    /// Create an empty toolbar
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let toolbar = Toolbar::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            props: ToolbarProps::default(),
+            focus_handle: None,
+            active: None,
+            widths: Rc::new(RefCell::new(Vec::new())),
+            available: Rc::new(Cell::new(None)),
+            more_width: Rc::new(Cell::new(None)),
+            menu_open: false,
+            highlighted: None,
+            anchor: AnchoredState::new(),
+        }
+    }
+
+    /// Add an action
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Toolbar::new().action(ToolbarAction::new("save", "Save"));
+    /// ```
+    pub fn action(mut self, action: ToolbarAction) -> Self {
+        self.props.items.push(ToolbarItem::Action(action));
+        self
+    }
+
+    /// Add a separator after the actions so far
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Toolbar::new().action(cut).action(copy).separator().action(undo);
+    /// ```
+    pub fn separator(mut self) -> Self {
+        self.props.items.push(ToolbarItem::Separator);
+        self
+    }
+
+    /// Set all items
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Toolbar::new().items(vec![ToolbarItem::Action(save), ToolbarItem::Separator]);
+    /// ```
+    pub fn items(mut self, items: Vec<ToolbarItem>) -> Self {
+        self.props.items = items;
+        self
+    }
+
+    /// Set the button size
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Toolbar::new().size(ButtonSize::Md);
+    /// ```
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.props.size = size;
+        self
+    }
+
+    /// Set whether actions that don't fit move into an overflow menu
+    ///
+    /// Without overflow, actions that don't fit are clipped.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Toolbar::new().overflow(false);
+    /// ```
+    pub fn overflow(mut self, overflow: bool) -> Self {
+        self.props.overflow = overflow;
+        self
+    }
+
+    /// Number of items shown in the row, from the measured widths
+    fn visible_count(&self, gap: Pixels) -> usize {
+        let len = self.props.items.len();
+        match self.available.get() {
+            Some(available) if self.props.overflow => {
+                let widths = self.widths.borrow();
+                let more_width = self.more_width.get().unwrap_or(px(MORE_BUTTON_WIDTH));
+                fitting_count(&widths[..len.min(widths.len())], available, gap, more_width)
+                    .min(len)
+            }
+            _ => len,
+        }
+    }
+
+    /// Number of items shown in the row, without trailing separators
+    fn row_len(&self, visible: usize) -> usize {
+        let mut len = visible;
+        while len > 0 && matches!(self.props.items[len - 1], ToolbarItem::Separator) {
+            len -= 1;
+        }
+        len
+    }
+
+    /// Indices of the actions in the overflow menu
+    fn overflowed(&self, visible: usize) -> Vec<usize> {
+        (visible..self.props.items.len())
+            .filter(|&index| matches!(self.props.items[index], ToolbarItem::Action(_)))
+            .collect()
+    }
+
+    /// Action at `index`, if it is one
+    fn action_at(&self, index: usize) -> Option<&ToolbarAction> {
+        match self.props.items.get(index) {
+            Some(ToolbarItem::Action(action)) => Some(action),
+            _ => None,
+        }
+    }
+
+    /// Whether the entry at `index` can take the focus ring
+    fn is_focusable(&self, index: usize, row_len: usize, has_overflow: bool) -> bool {
+        if index == self.props.items.len() {
+            has_overflow
+        } else {
+            index < row_len && self.action_at(index).is_some_and(|action| !action.disabled)
+        }
+    }
+
+    /// Run the action at `index` and close the menu
+    fn activate(&mut self, index: usize, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let Some(action) = self.action_at(index).filter(|action| !action.disabled) else {
+            return;
+        };
+        let handler = action.on_click.clone();
+        self.menu_open = false;
+        self.highlighted = None;
+        if let Some(handler) = handler {
+            handler(window, cx);
+        }
+        cx.notify();
+    }
+
+    /// Open or close the overflow menu
+    fn set_menu_open(&mut self, open: bool, overflowed: &[usize], cx: &mut Context<'_, Self>) {
+        self.menu_open = open;
+        self.highlighted = if open {
+            overflowed
+                .iter()
+                .copied()
+                .find(|&index| self.action_at(index).is_some_and(|action| !action.disabled))
+        } else {
+            None
+        };
+        cx.notify();
+    }
+
+    /// Close the menu on a mouse down outside it, unless it lands on the overflow button
+    fn handle_outside_click(&mut self, event: &MouseDownEvent, _window: &mut Window, cx: &mut Context<'_, Self>) {
+        let on_button = self
+            .anchor
+            .anchor_bounds()
+            .is_some_and(|bounds| bounds.contains(&event.position));
+        if !on_button {
+            self.menu_open = false;
+            self.highlighted = None;
+            cx.notify();
+        }
+    }
+
+    /// Handle roving focus in the row and navigation in the open menu
+    fn handle_key(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let gap = use_theme(cx).global.spacing_xs;
+        let visible = self.visible_count(gap);
+        let row_len = self.row_len(visible);
+        let overflowed = self.overflowed(visible);
+        let has_overflow = !overflowed.is_empty();
+        let key = event.keystroke.key.as_str();
+
+        if self.menu_open {
+            let position = self.highlighted.and_then(|index| overflowed.iter().position(|&i| i == index));
+            let enabled = |position: usize| self.action_at(overflowed[position]).is_some_and(|action| !action.disabled);
+            match key {
+                "escape" => self.set_menu_open(false, &overflowed, cx),
+                "enter" | "space" => {
+                    if let Some(index) = self.highlighted {
+                        self.activate(index, window, cx);
+                    }
+                }
+                key => {
+                    let roving = RovingFocus::new(RovingOrientation::Vertical);
+                    let Some(next) = roving.target(key, position, overflowed.len(), enabled) else {
+                        return;
+                    };
+                    self.highlighted = Some(overflowed[next]);
+                    cx.notify();
+                }
+            }
+            cx.stop_propagation();
+            return;
+        }
+
+        let len = self.props.items.len() + 1;
+        let focusable = |index: usize| self.is_focusable(index, row_len, has_overflow);
+        let active = self
+            .active
+            .filter(|&index| focusable(index))
+            .or_else(|| RovingFocus::first(len, focusable));
+        match key {
+            "enter" | "space" | "down" if active == Some(self.props.items.len()) => {
+                self.set_menu_open(true, &overflowed, cx);
+            }
+            "enter" | "space" => {
+                if let Some(index) = active {
+                    self.activate(index, window, cx);
+                }
+            }
+            key => {
+                let roving = RovingFocus::new(RovingOrientation::Horizontal);
+                let Some(next) = roving.target(key, active, len, focusable) else {
+                    return;
+                };
+                self.active = Some(next);
+                cx.notify();
+            }
+        }
+        cx.stop_propagation();
+    }
+
+    /// Overflow menu listing the actions that don't fit
+    fn menu(&self, overflowed: &[usize], cx: &mut Context<'_, Self>) -> Div {
+        let theme = use_theme(cx);
+        let mut menu = div()
+            .id("toolbar-menu")
+            .on_mouse_down_out(cx.listener(Self::handle_outside_click))
+            .min_w(px(160.0))
+            .py(px(4.0))
+            .flex()
+            .flex_col()
+            .bg(theme.alias.color_surface)
+            .border(px(1.0))
+            .border_color(theme.alias.color_border)
+            .rounded(theme.global.radius_md)
+            .shadow_lg();
+
+        let mut previous = None;
+        for &index in overflowed {
+            let Some(action) = self.action_at(index) else {
+                continue;
+            };
+            // Separators between the overflowed actions become dividers
+            let separated = previous.is_some_and(|previous: usize| {
+                self.props.items[previous + 1..index]
+                    .iter()
+                    .any(|item| matches!(item, ToolbarItem::Separator))
+            });
+            if separated {
+                menu = menu.child(div().h(px(1.0)).my(px(4.0)).bg(theme.alias.color_border));
+            }
+            previous = Some(index);
+
+            let mut row = div()
+                .id(("toolbar-menu-item", index))
+                .flex()
+                .flex_row()
+                .items_center()
+                .gap(theme.global.spacing_sm)
+                .px(theme.global.spacing_md)
+                .py(theme.global.spacing_xs)
+                .text_size(theme.global.font_size_sm)
+                .text_color(theme.alias.color_text_primary);
+            if let Some(icon) = &action.icon {
+                row = row.child(
+                    Icon::new(icon.clone())
+                        .size(IconSize::Sm)
+                        .custom_color(theme.alias.color_text_secondary)
+                        .to_element(&theme),
+                );
+            }
+            row = row.child(action.label.clone());
+
+            row = if action.disabled {
+                row.opacity(0.5).cursor_not_allowed()
+            } else {
+                if self.highlighted == Some(index) {
+                    row = row.bg(theme.alias.color_surface_hover);
+                }
+                row.cursor_pointer()
+                    .on_hover(cx.listener(move |this, hovered: &bool, _window, cx| {
+                        if *hovered && this.highlighted != Some(index) {
+                            this.highlighted = Some(index);
+                            cx.notify();
+                        }
+                    }))
+                    .on_click(cx.listener(move |this, _event: &ClickEvent, window, cx| {
+                        this.activate(index, window, cx);
+                    }))
+            };
+            menu = menu.child(row);
+        }
+        menu
+    }
+}
+
+/// Number of leading items that fit in `available` width
+///
+/// When not every item fits, room is kept for the overflow button.
+fn fitting_count(widths: &[Pixels], available: Pixels, gap: Pixels, more_width: Pixels) -> usize {
+    let span = |count: usize| {
+        #[allow(clippy::cast_precision_loss)]
+        let gaps = gap * count.saturating_sub(1) as f32;
+        widths[..count].iter().fold(gaps, |total, &width| total + width)
+    };
+    if span(widths.len()) <= available {
+        return widths.len();
+    }
+    (1..widths.len())
+        .rev()
+        .find(|&count| span(count) + gap + more_width <= available)
+        .unwrap_or(0)
+}
+
+impl Default for Toolbar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Render for Toolbar {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        let focus_handle = self
+            .focus_handle
+            .get_or_insert_with(|| cx.focus_handle())
+            .clone();
+        let focused = focus_handle.is_focused(window);
+
+        let gap = theme.global.spacing_xs;
+        self.widths.borrow_mut().resize(self.props.items.len(), px(0.0));
+        let visible = self.visible_count(gap);
+        let row_len = self.row_len(visible);
+        let overflowed = self.overflowed(visible);
+        let has_overflow = !overflowed.is_empty();
+        if !has_overflow {
+            self.menu_open = false;
+        }
+        let more_index = self.props.items.len();
+        let active = self
+            .active
+            .filter(|&index| self.is_focusable(index, row_len, has_overflow))
+            .or_else(|| RovingFocus::first(more_index + 1, |index| self.is_focusable(index, row_len, has_overflow)));
+        let ring = |element: Stateful<Div>, index: usize| {
+            if focused && active == Some(index) {
+                element.outline_color(theme.alias.color_border_focus).outline_w(px(2.0))
+            } else {
+                element
+            }
+        };
+
+        let available = self.available.clone();
+        let mut toolbar = div()
+            .id("toolbar")
+            .track_focus(&focus_handle)
+            .on_key_down(cx.listener(Self::handle_key))
+            .relative()
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap(gap)
+            .w_full()
+            .min_w(px(0.0))
+            .overflow_hidden()
+            .child(
+                canvas(
+                    move |bounds, window, _cx| {
+                        if available.get() != Some(bounds.size.width) {
+                            available.set(Some(bounds.size.width));
+                            window.request_animation_frame();
+                        }
+                    },
+                    |_, (), _, _| {},
+                )
+                .absolute()
+                .top(px(0.0))
+                .left(px(0.0))
+                .size_full(),
+            );
+
+        for (index, item) in self.props.items.iter().enumerate().take(row_len) {
+            let element = match item {
+                ToolbarItem::Separator => div()
+                    .id(("toolbar-separator", index))
+                    .w(px(1.0))
+                    .h(px(20.0))
+                    .mx(theme.global.spacing_xs)
+                    .bg(theme.alias.color_border),
+                ToolbarItem::Action(action) => {
+                    let mut button = Button::new()
+                        .id(("toolbar-action", index))
+                        .label(action.label.clone())
+                        .variant(ButtonVariant::Ghost)
+                        .size(self.props.size)
+                        .icon_only(action.icon_only && action.icon.is_some())
+                        .disabled(action.disabled);
+                    if let Some(icon) = &action.icon {
+                        button = button.icon_left(icon.clone());
+                    }
+                    let mut button = ring(button.to_element(&theme), index);
+                    if !action.disabled {
+                        button = button.on_click(cx.listener(move |this, _event: &ClickEvent, window, cx| {
+                            this.active = Some(index);
+                            this.activate(index, window, cx);
+                        }));
+                    }
+                    button
+                }
+            };
+
+            // Record each item's width for the overflow calculation
+            let widths = self.widths.clone();
+            toolbar = toolbar.child(
+                div().relative().flex_none().child(element).child(
+                    canvas(
+                        move |bounds, window, _cx| {
+                            let mut widths = widths.borrow_mut();
+                            if widths.get(index).is_some_and(|&width| width != bounds.size.width) {
+                                widths[index] = bounds.size.width;
+                                window.request_animation_frame();
+                            }
+                        },
+                        |_, (), _, _| {},
+                    )
+                    .absolute()
+                    .top(px(0.0))
+                    .left(px(0.0))
+                    .size_full(),
+                ),
+            );
+        }
+
+        if has_overflow {
+            let more_width = self.more_width.clone();
+            let overflowed_for_click = overflowed.clone();
+            let more = Button::new()
+                .id("toolbar-more")
+                .label("More actions")
+                .icon_left(icons::MORE_HORIZONTAL)
+                .icon_only(true)
+                .variant(ButtonVariant::Ghost)
+                .size(self.props.size)
+                .to_element(&theme)
+                .on_click(cx.listener(move |this, _event: &ClickEvent, _window, cx| {
+                    this.active = Some(this.props.items.len());
+                    let open = !this.menu_open;
+                    this.set_menu_open(open, &overflowed_for_click, cx);
+                }));
+            toolbar = toolbar.child(
+                div()
+                    .relative()
+                    .flex_none()
+                    .child(ring(more, more_index))
+                    .child(self.anchor.measure_anchor())
+                    .child(
+                        canvas(
+                            move |bounds, window, _cx| {
+                                if more_width.get() != Some(bounds.size.width) {
+                                    more_width.set(Some(bounds.size.width));
+                                    window.request_animation_frame();
+                                }
+                            },
+                            |_, (), _, _| {},
+                        )
+                        .absolute()
+                        .top(px(0.0))
+                        .left(px(0.0))
+                        .size_full(),
+                    ),
+            );
+
+            if self.menu_open {
+                let menu = self.menu(&overflowed, cx);
+                let options = AnchorOptions::new(Placement::Bottom)
+                    .align(PlacementAlign::End)
+                    .offset(px(4.0));
+                let position = self.anchor.position(&options, window);
+                toolbar = toolbar.child(self.anchor.overlay(position, menu));
+            }
+        }
+
+        toolbar
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn widths() -> Vec<Pixels> {
+        vec![px(40.0), px(40.0), px(40.0)]
+    }
+
+    #[test]
+    fn test_everything_fits() {
+        // 3 × 40 plus 2 gaps of 4
+        assert_eq!(fitting_count(&widths(), px(128.0), px(4.0), px(32.0)), 3);
+    }
+
+    #[test]
+    fn test_overflow_keeps_room_for_more_button() {
+        // Two items, a gap and the more button: 84 + 4 + 32 = 120
+        assert_eq!(fitting_count(&widths(), px(127.0), px(4.0), px(32.0)), 2);
+        assert_eq!(fitting_count(&widths(), px(119.0), px(4.0), px(32.0)), 1);
+        assert_eq!(fitting_count(&widths(), px(30.0), px(4.0), px(32.0)), 0);
+    }
+
+    #[test]
+    fn test_trailing_separators_hidden() {
+        let toolbar = Toolbar::new()
+            .action(ToolbarAction::new("cut", "Cut"))
+            .separator()
+            .action(ToolbarAction::new("undo", "Undo"));
+        assert_eq!(toolbar.row_len(2), 1);
+        assert_eq!(toolbar.overflowed(2), vec![2]);
+        assert_eq!(toolbar.row_len(3), 3);
+        assert!(toolbar.overflowed(3).is_empty());
+    }
+
+    #[test]
+    fn test_unmeasured_toolbar_shows_everything() {
+        let toolbar = Toolbar::new()
+            .action(ToolbarAction::new("cut", "Cut"))
+            .action(ToolbarAction::new("copy", "Copy").disabled(true));
+        assert_eq!(toolbar.visible_count(px(4.0)), 2);
+        assert!(toolbar.is_focusable(0, 2, false));
+        assert!(!toolbar.is_focusable(1, 2, false));
+        assert!(!toolbar.is_focusable(2, 2, false));
+    }
+}
@@ -1,7 +1,14 @@
 //! Tooltip component for contextual information.
 
+use std::time::Duration;
+
 use gpui::*;
-use crate::{atoms::{Label, LabelVariant}, theme::Theme};
+use gpui::prelude::FluentBuilder;
+use crate::{
+    atoms::{Label, LabelVariant},
+    theme::{use_theme, Theme},
+    utils::{AnchorOptions, AnchoredState, Placement, PlacementAlign},
+};
 
 /// Tooltip positioning options
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -17,6 +24,17 @@ pub enum TooltipPosition {
     Right,
 }
 
+impl From<TooltipPosition> for Placement {
+    fn from(position: TooltipPosition) -> Self {
+        match position {
+            TooltipPosition::Top => Placement::Top,
+            TooltipPosition::Bottom => Placement::Bottom,
+            TooltipPosition::Left => Placement::Left,
+            TooltipPosition::Right => Placement::Right,
+        }
+    }
+}
+
 /// Tooltip configuration properties
 #[derive(Clone)]
 pub struct TooltipProps {
@@ -24,6 +42,8 @@ pub struct TooltipProps {
     pub content: SharedString,
     /// Positioning relative to target
     pub position: TooltipPosition,
+    /// Alignment along the target's side
+    pub align: PlacementAlign,
     /// Whether tooltip is visible
     pub visible: bool,
     /// Delay before showing (milliseconds)
@@ -37,6 +57,7 @@ impl Default for TooltipProps {
         Self {
             content: "".into(),
             position: TooltipPosition::default(),
+            align: PlacementAlign::Center,
             visible: false,
             delay: 200, // 200ms default delay
             show_arrow: true,
@@ -56,7 +77,7 @@ impl Default for TooltipProps {
 /// - Optional arrow pointer
 /// - Keyboard and mouse trigger support
 /// - ARIA attributes for accessibility
-/// - Automatic positioning adjustment
+/// - Automatic positioning adjustment (flips to the other side near window edges)
 ///
 /// ## Example
 ///
@@ -75,8 +96,15 @@ impl Default for TooltipProps {
 /// Tooltip::new("Clean tooltip")
 ///     .show_arrow(false);
 ///
-/// // In a component
+/// // Attached to an element, showing on hover and focus
+/// Button::new()
+///     .label("Submit")
+///     .to_element(&theme)
+///     .with_tooltip("submit-tooltip", Tooltip::new("Click to submit"));
+///
+/// // Controlled; the tooltip is positioned against its parent
 /// div()
+///     .relative()
 ///     .child(Button::new().label("Hover me"))
 ///     .child(
 ///         Tooltip::new("Click to submit")
@@ -93,6 +121,7 @@ impl Default for TooltipProps {
 /// - Meets WCAG 2.1 SC 1.3.1 (Info and Relationships)
 pub struct Tooltip {
     props: TooltipProps,
+    anchor: AnchoredState,
 }
 
 impl Tooltip {
@@ -109,6 +138,7 @@ impl Tooltip {
                 content: content.into(),
                 ..Default::default()
             },
+            anchor: AnchoredState::new(),
         }
     }
 
@@ -136,6 +166,18 @@ impl Tooltip {
         self
     }
 
+    /// Set the alignment along the target's side
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Tooltip::new("Info").position(TooltipPosition::Bottom).align(PlacementAlign::Start);
+    /// ```
+    pub fn align(mut self, align: PlacementAlign) -> Self {
+        self.props.align = align;
+        self
+    }
+
     /// Set whether the tooltip is visible
     ///
     /// ## Example
@@ -173,81 +215,86 @@ impl Tooltip {
     }
 }
 
-impl Render for Tooltip {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
-        let theme = Theme::default();
+/// Positioning options for a tooltip against its target
+fn anchor_options(props: &TooltipProps) -> AnchorOptions {
+    AnchorOptions::new(props.position.into())
+        .align(props.align)
+        .offset(px(8.0))
+}
 
-        if !self.props.visible {
-            return div(); // Return empty div if not visible
-        }
+/// Build the tooltip bubble, with its arrow pointing from `placement` at the target
+fn tooltip_bubble(props: &TooltipProps, placement: Placement, theme: &Theme) -> Div {
+    // Build tooltip container
+    let mut tooltip = div()
+        .relative()
+        .bg(hsla(0.0, 0.0, 0.1, 0.95)) // Dark semi-transparent background
+        .text_color(hsla(0.0, 0.0, 1.0, 1.0)) // White text
+        .px(theme.global.spacing_sm)
+        .py(px(6.0))
+        .rounded(theme.global.radius_sm)
+        .shadow_lg()
+        .max_w(px(300.0));
 
-        // Build tooltip container
-        let mut tooltip = div()
+    // Add content
+    tooltip = tooltip.child(
+        Label::new(props.content.clone())
+            .variant(LabelVariant::Caption)
+            .color(hsla(0.0, 0.0, 1.0, 1.0))
+    );
+
+    // Add arrow if enabled
+    if props.show_arrow {
+        let arrow = div()
             .absolute()
-            .bg(hsla(0.0, 0.0, 0.1, 0.95)) // Dark semi-transparent background
-            .text_color(hsla(0.0, 0.0, 1.0, 1.0)) // White text
-            .px(theme.global.spacing_sm)
-            .py(px(6.0))
-            .rounded(theme.global.radius_sm)
-            .shadow_lg()
-            .z_index(1000)
-            .max_w(px(300.0));
-
-        // Position the tooltip
-        tooltip = match self.props.position {
-            TooltipPosition::Top => tooltip
-                .bottom_full()
-                .left_half()
-                .mb(px(8.0)),
-            TooltipPosition::Bottom => tooltip
-                .top_full()
-                .left_half()
-                .mt(px(8.0)),
-            TooltipPosition::Left => tooltip
-                .right_full()
-                .top_half()
-                .mr(px(8.0)),
-            TooltipPosition::Right => tooltip
-                .left_full()
-                .top_half()
-                .ml(px(8.0)),
+            .w(px(8.0))
+            .h(px(8.0))
+            .bg(hsla(0.0, 0.0, 0.1, 0.95));
+
+        // Point the arrow at the target from the side the tooltip ended up on
+        let arrow = match placement {
+            Placement::Top => arrow
+                .bottom(px(-4.0))
+                .left_half(),
+            Placement::Bottom => arrow
+                .top(px(-4.0))
+                .left_half(),
+            Placement::Left => arrow
+                .right(px(-4.0))
+                .top_half(),
+            Placement::Right => arrow
+                .left(px(-4.0))
+                .top_half(),
         };
 
-        // Add content
-        tooltip = tooltip.child(
-            Label::new(self.props.content.clone())
-                .variant(LabelVariant::Caption)
-                .color(hsla(0.0, 0.0, 1.0, 1.0))
-        );
+        tooltip = tooltip.child(arrow);
+    }
+
+    tooltip
+}
 
-        // Add arrow if enabled
-        if self.props.show_arrow {
-            let arrow = div()
-                .absolute()
-                .w(px(8.0))
-                .h(px(8.0))
-                .bg(hsla(0.0, 0.0, 0.1, 0.95));
-
-            // Position arrow based on tooltip position
-            let arrow = match self.props.position {
-                TooltipPosition::Top => arrow
-                    .bottom(px(-4.0))
-                    .left_half(),
-                TooltipPosition::Bottom => arrow
-                    .top(px(-4.0))
-                    .left_half(),
-                TooltipPosition::Left => arrow
-                    .right(px(-4.0))
-                    .top_half(),
-                TooltipPosition::Right => arrow
-                    .left(px(-4.0))
-                    .top_half(),
-            };
-
-            tooltip = tooltip.child(arrow);
+impl Render for Tooltip {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+
+        if !self.props.visible {
+            return div(); // Return empty div if not visible
         }
 
-        tooltip
+        let options = anchor_options(&self.props);
+        let position = self.anchor.position(&options, window);
+        // Near a window edge the tooltip may flip to the other side
+        let placement = position.map_or(options.placement, |position| position.placement);
+
+        let tooltip = tooltip_bubble(&self.props, placement, &theme);
+
+        // Cover the parent to measure it as the anchor
+        div()
+            .absolute()
+            .top(px(0.0))
+            .left(px(0.0))
+            .size_full()
+            .child(self.anchor.measure_anchor())
+            .child(self.anchor.overlay(position, tooltip))
     }
 }
 
@@ -257,6 +304,222 @@ impl Default for Tooltip {
     }
 }
 
+/// Hover and focus state behind a [`TooltipWrapper`], kept across renders
+struct TooltipTrigger {
+    /// Focus handle tracked when the target does not bring its own
+    focus_handle: FocusHandle,
+    /// Whether the pointer is over the target
+    hovered: bool,
+    /// Whether the pointer has rested on the target for the show delay
+    delay_elapsed: bool,
+    /// Whether Escape hid the tooltip; cleared once hover and focus leave
+    dismissed: bool,
+    /// Pending show after the delay; dropping it cancels the show
+    show_task: Option<Task<()>>,
+    anchor: AnchoredState,
+}
+
+impl TooltipTrigger {
+    fn new(focus_handle: FocusHandle) -> Self {
+        Self {
+            focus_handle,
+            hovered: false,
+            delay_elapsed: false,
+            dismissed: false,
+            show_task: None,
+            anchor: AnchoredState::new(),
+        }
+    }
+
+    /// Whether the tooltip shows, given whether the target has focus
+    ///
+    /// Hovering shows it once the delay has elapsed; focus shows it at once
+    /// so keyboard users are not kept waiting.
+    fn is_visible(&self, focused: bool) -> bool {
+        !self.dismissed && (self.delay_elapsed || focused)
+    }
+
+    /// Start the show delay when the pointer enters, or hide when it leaves
+    fn set_hovered(&mut self, hovered: bool, delay: Duration, cx: &mut Context<'_, Self>) {
+        if self.hovered == hovered {
+            return;
+        }
+        self.hovered = hovered;
+        self.delay_elapsed = false;
+
+        if hovered {
+            self.show_task = Some(cx.spawn(async move |this, cx| {
+                cx.background_executor().timer(delay).await;
+                this.update(cx, |this, cx| {
+                    this.delay_elapsed = true;
+                    cx.notify();
+                })
+                .ok();
+            }));
+        } else {
+            self.show_task = None;
+        }
+        cx.notify();
+    }
+
+    /// Forget an Escape dismissal once the pointer and focus have both left
+    fn reset_dismissed(&mut self, focused: bool) {
+        if !self.hovered && !focused {
+            self.dismissed = false;
+        }
+    }
+}
+
+/// An element with a tooltip that shows on hover and focus.
+///
+/// The wrapper attaches the listeners a tooltip needs to its child: the
+/// tooltip shows after the tooltip's delay while the pointer rests on the
+/// child, and at once while the child has focus. It hides when the pointer
+/// leaves, on blur, and on Escape. Usually created with
+/// [`TooltipExt::with_tooltip`].
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::molecules::*;
+///
+/// TooltipWrapper::new("delete-tooltip", delete_button)
+///     .tooltip(Tooltip::new("Delete permanently").position(TooltipPosition::Bottom))
+///     .focus_handle(&self.delete_focus);
+/// ```
+///
+/// ## Accessibility
+///
+/// - Shows on keyboard focus as well as hover
+/// - Escape hides the tooltip without moving focus
+#[derive(IntoElement)]
+pub struct TooltipWrapper {
+    id: ElementId,
+    child: AnyElement,
+    props: TooltipProps,
+    focus_handle: Option<FocusHandle>,
+}
+
+impl TooltipWrapper {
+    /// Wrap `child` with a tooltip, identified by `id` to keep its hover state
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TooltipWrapper::new("save-tooltip", save_button).tooltip(Tooltip::new("Save"));
+    /// ```
+    pub fn new(id: impl Into<ElementId>, child: impl IntoElement) -> Self {
+        Self {
+            id: id.into(),
+            child: child.into_any_element(),
+            props: TooltipProps::default(),
+            focus_handle: None,
+        }
+    }
+
+    /// Set the tooltip shown for the child
+    ///
+    /// The tooltip's content, position, alignment, delay and arrow are used;
+    /// its `visible` flag is ignored in favor of hover and focus.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TooltipWrapper::new("save-tooltip", save_button)
+    ///     .tooltip(Tooltip::new("Save").delay(500));
+    /// ```
+    pub fn tooltip(mut self, tooltip: Tooltip) -> Self {
+        self.props = tooltip.props;
+        self
+    }
+
+    /// Show the tooltip while this handle has focus
+    ///
+    /// Use the child's own focus handle when it has one. Otherwise the
+    /// wrapper tracks a handle of its own.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TooltipWrapper::new("name-tooltip", name_field).focus_handle(&self.name_focus);
+    /// ```
+    pub fn focus_handle(mut self, handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(handle.clone());
+        self
+    }
+}
+
+impl RenderOnce for TooltipWrapper {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let theme = use_theme(cx);
+        let trigger = window.use_keyed_state(self.id.clone(), cx, |_window, cx| {
+            TooltipTrigger::new(cx.focus_handle())
+        });
+
+        let own_focus = trigger.read(cx).focus_handle.clone();
+        let focus_handle = self.focus_handle.clone().unwrap_or_else(|| own_focus.clone());
+        let focused = focus_handle.contains_focused(window, cx);
+        trigger.update(cx, |trigger, _cx| trigger.reset_dismissed(focused));
+
+        let state = trigger.read(cx);
+        let visible = state.is_visible(focused);
+        let anchor = state.anchor.clone();
+        let delay = Duration::from_millis(u64::from(self.props.delay));
+
+        let tooltip = visible.then(|| {
+            let options = anchor_options(&self.props);
+            let position = anchor.position(&options, window);
+            let placement = position.map_or(options.placement, |position| position.placement);
+            anchor.overlay(position, tooltip_bubble(&self.props, placement, &theme))
+        });
+
+        let on_hover = trigger.clone();
+        let on_key = trigger;
+        div()
+            .id(self.id)
+            .relative()
+            .when(self.focus_handle.is_none(), |this| this.track_focus(&own_focus))
+            .on_hover(move |hovered, _window, cx| {
+                on_hover.update(cx, |trigger, cx| trigger.set_hovered(*hovered, delay, cx));
+            })
+            .on_key_down(move |event, _window, cx| {
+                if event.keystroke.key == "escape" && visible {
+                    on_key.update(cx, |trigger, cx| {
+                        trigger.dismissed = true;
+                        cx.notify();
+                    });
+                    cx.stop_propagation();
+                }
+            })
+            .child(self.child)
+            .child(anchor.measure_anchor())
+            .children(tooltip)
+    }
+}
+
+/// Attach a hover and focus tooltip to any element.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::molecules::*;
+///
+/// div()
+///     .child("Hover me")
+///     .with_tooltip("hover-tooltip", Tooltip::new("Shown on hover"));
+/// ```
+pub trait TooltipExt: IntoElement + Sized {
+    /// Wrap the element in a [`TooltipWrapper`] showing `tooltip`
+    ///
+    /// Named `with_tooltip` so it does not clash with GPUI's own `tooltip`
+    /// method on interactive elements.
+    fn with_tooltip(self, id: impl Into<ElementId>, tooltip: Tooltip) -> TooltipWrapper {
+        TooltipWrapper::new(id, self).tooltip(tooltip)
+    }
+}
+
+impl<E: IntoElement> TooltipExt for E {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -285,6 +548,18 @@ mod tests {
         assert!(!tooltip.props.show_arrow);
     }
 
+    #[test]
+    fn test_wrapper_uses_tooltip_props() {
+        let wrapper = div().with_tooltip(
+            "tip",
+            Tooltip::new("Save").position(TooltipPosition::Right).delay(750),
+        );
+        assert_eq!(wrapper.props.content.as_ref(), "Save");
+        assert_eq!(wrapper.props.position, TooltipPosition::Right);
+        assert_eq!(wrapper.props.delay, 750);
+        assert!(wrapper.focus_handle.is_none());
+    }
+
     #[test]
     fn test_tooltip_positions() {
         let positions = vec![
@@ -0,0 +1,437 @@
+//! AppShell component: the header, sidebar, content and footer frame of an app window.
+
+use std::rc::Rc;
+
+use gpui::*;
+use crate::{
+    atoms::{icons, Button, ButtonSize, ButtonVariant},
+    theme::use_theme,
+    utils::{
+        motion::{self, reduced_motion, Easing},
+        use_breakpoint, Breakpoint,
+    },
+};
+
+/// Renders one region of the shell
+pub type AppShellSlot = Rc<dyn Fn(&mut Window, &mut App) -> AnyElement>;
+
+/// AppShell configuration properties
+#[derive(Clone)]
+pub struct AppShellProps {
+    /// Header bar content, after the sidebar toggle
+    pub header: Option<AppShellSlot>,
+    /// Sidebar content, usually navigation
+    pub sidebar: Option<AppShellSlot>,
+    /// Main content area
+    pub content: Option<AppShellSlot>,
+    /// Footer or status bar content
+    pub footer: Option<AppShellSlot>,
+    /// Height of the header bar
+    pub header_height: Pixels,
+    /// Width of the sidebar
+    pub sidebar_width: Pixels,
+    /// Windows narrower than this show the sidebar as an overlay, `None` to
+    /// always keep it docked
+    pub collapse_below: Option<Breakpoint>,
+    /// Called with the new state when the sidebar is shown or hidden
+    pub on_sidebar_toggle: Option<Rc<dyn Fn(bool, &mut Window, &mut App)>>,
+}
+
+impl Default for AppShellProps {
+    fn default() -> Self {
+        Self {
+            header: None,
+            sidebar: None,
+            content: None,
+            footer: None,
+            header_height: px(48.0),
+            sidebar_width: px(240.0),
+            collapse_below: Some(Breakpoint::Md),
+            on_sidebar_toggle: None,
+        }
+    }
+}
+
+/// The standard frame of a desktop app window.
+///
+/// AppShell lays out a header bar across the top, a sidebar on the left, the
+/// main content beside it and an optional footer or status bar along the
+/// bottom. Each region is a slot, so an app gets a consistent layout in a
+/// few lines and fills it with its own components.
+///
+/// ## Features
+///
+/// - Header bar with a sidebar toggle button
+/// - Docked sidebar on wide windows, hidden by the toggle
+/// - On windows narrower than `collapse_below` the sidebar starts hidden
+///   and slides in over the content, closing on a backdrop click or Escape
+/// - Optional footer for status text
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::organisms::*;
+///
+/// let shell = cx.new(|_| {
+///     AppShell::new()
+///         .header(|_window, _cx| div().child("My App").into_any_element())
+///         .sidebar(move |_window, _cx| navigation.clone().into_any_element())
+///         .content(move |_window, _cx| editor.clone().into_any_element())
+///         .footer(|_window, _cx| div().child("Ready").into_any_element())
+/// });
+/// ```
+///
+/// ## Accessibility
+///
+/// - The sidebar toggle is a labelled button reachable by Tab
+/// - Escape closes the overlay sidebar
+pub struct AppShell {
+    props: AppShellProps,
+    /// Whether the sidebar is shown
+    sidebar_open: bool,
+    /// Whether the last render used the overlay layout, `None` before the first
+    compact: Option<bool>,
+    /// Counts overlay openings so each one restarts the slide-in
+    transition: usize,
+}
+
+impl AppShell {
+    /// Create an empty app shell
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let shell = cx.new(|_| AppShell::new());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            props: AppShellProps::default(),
+            sidebar_open: true,
+            compact: None,
+            transition: 0,
+        }
+    }
+
+    /// Set the header bar content
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// AppShell::new().header(|_window, _cx| title_bar().into_any_element());
+    /// ```
+    pub fn header(mut self, header: impl Fn(&mut Window, &mut App) -> AnyElement + 'static) -> Self {
+        self.props.header = Some(Rc::new(header));
+        self
+    }
+
+    /// Set the sidebar content
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// AppShell::new().sidebar(move |_window, _cx| tree.clone().into_any_element());
+    /// ```
+    pub fn sidebar(mut self, sidebar: impl Fn(&mut Window, &mut App) -> AnyElement + 'static) -> Self {
+        self.props.sidebar = Some(Rc::new(sidebar));
+        self
+    }
+
+    /// Set the main content
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// AppShell::new().content(move |_window, _cx| editor.clone().into_any_element());
+    /// ```
+    pub fn content(mut self, content: impl Fn(&mut Window, &mut App) -> AnyElement + 'static) -> Self {
+        self.props.content = Some(Rc::new(content));
+        self
+    }
+
+    /// Set the footer or status bar content
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// AppShell::new().footer(|_window, _cx| div().child("Ln 12, Col 4").into_any_element());
+    /// ```
+    pub fn footer(mut self, footer: impl Fn(&mut Window, &mut App) -> AnyElement + 'static) -> Self {
+        self.props.footer = Some(Rc::new(footer));
+        self
+    }
+
+    /// Set the height of the header bar
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// AppShell::new().header_height(px(56.0));
+    /// ```
+    pub fn header_height(mut self, height: Pixels) -> Self {
+        self.props.header_height = height;
+        self
+    }
+
+    /// Set the width of the sidebar
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// AppShell::new().sidebar_width(px(280.0));
+    /// ```
+    pub fn sidebar_width(mut self, width: Pixels) -> Self {
+        self.props.sidebar_width = width;
+        self
+    }
+
+    /// Set the breakpoint below which the sidebar becomes an overlay
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// // Keep the sidebar docked at every width
+    /// AppShell::new().collapse_below(None);
+    /// ```
+    pub fn collapse_below(mut self, breakpoint: Option<Breakpoint>) -> Self {
+        self.props.collapse_below = breakpoint;
+        self
+    }
+
+    /// Set the handler called when the sidebar is shown or hidden
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// AppShell::new().on_sidebar_toggle(|open, _window, cx| save_sidebar_state(open, cx));
+    /// ```
+    pub fn on_sidebar_toggle(
+        mut self,
+        handler: impl Fn(bool, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_sidebar_toggle = Some(Rc::new(handler));
+        self
+    }
+
+    /// Whether the sidebar is shown
+    pub fn is_sidebar_open(&self) -> bool {
+        self.sidebar_open
+    }
+
+    /// Show or hide the sidebar
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// shell.update(cx, |shell, cx| shell.set_sidebar_open(false, window, cx));
+    /// ```
+    pub fn set_sidebar_open(&mut self, open: bool, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.sidebar_open == open {
+            return;
+        }
+        self.sidebar_open = open;
+        if open {
+            self.transition += 1;
+        }
+        if let Some(handler) = self.props.on_sidebar_toggle.clone() {
+            handler(open, window, cx);
+        }
+        cx.notify();
+    }
+
+    /// Show the sidebar if hidden, or hide it if shown
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// shell.update(cx, |shell, cx| shell.toggle_sidebar(window, cx));
+    /// ```
+    pub fn toggle_sidebar(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+        self.set_sidebar_open(!self.sidebar_open, window, cx);
+    }
+
+    /// Whether a window at `breakpoint` shows the sidebar as an overlay
+    fn is_compact(&self, breakpoint: Option<Breakpoint>) -> bool {
+        self.props
+            .collapse_below
+            .is_some_and(|collapse_below| breakpoint < Some(collapse_below))
+    }
+
+    /// Close the overlay sidebar on Escape
+    fn handle_key(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if event.keystroke.key == "escape" && self.compact == Some(true) && self.sidebar_open {
+            self.set_sidebar_open(false, window, cx);
+            cx.stop_propagation();
+        }
+    }
+}
+
+impl Default for AppShell {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Render for AppShell {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+
+        // Switching layouts resets the sidebar: docked and shown on wide
+        // windows, hidden behind the toggle on narrow ones
+        let compact = self.is_compact(use_breakpoint(window, cx));
+        if self.compact != Some(compact) {
+            self.compact = Some(compact);
+            self.sidebar_open = !compact;
+        }
+
+        let has_sidebar = self.props.sidebar.is_some();
+        let sidebar_width = self.props.sidebar_width;
+
+        let mut header = div()
+            .flex()
+            .flex_row()
+            .flex_none()
+            .items_center()
+            .gap(theme.global.spacing_sm)
+            .h(self.props.header_height)
+            .px(theme.global.spacing_md)
+            .bg(theme.alias.color_surface)
+            .border_b(px(1.0))
+            .border_color(theme.alias.color_border);
+        if has_sidebar {
+            header = header.child(
+                Button::new()
+                    .id("app-shell-sidebar-toggle")
+                    .label(if self.sidebar_open { "Hide sidebar" } else { "Show sidebar" })
+                    .icon_left(icons::MENU)
+                    .icon_only(true)
+                    .variant(ButtonVariant::Ghost)
+                    .size(ButtonSize::Sm)
+                    .on_click(cx.listener(|this, _event: &ClickEvent, window, cx| {
+                        this.toggle_sidebar(window, cx);
+                    }))
+                    .to_element(&theme),
+            );
+        }
+        if let Some(slot) = self.props.header.clone() {
+            header = header.child(div().flex_1().min_w(px(0.0)).child(slot(window, cx)));
+        }
+
+        let mut body = div().relative().flex().flex_row().flex_1().min_h(px(0.0));
+
+        let sidebar = self
+            .props
+            .sidebar
+            .clone()
+            .filter(|_| self.sidebar_open)
+            .map(|slot| {
+                div()
+                    .flex()
+                    .flex_col()
+                    .flex_none()
+                    .w(sidebar_width)
+                    .h_full()
+                    .overflow_hidden()
+                    .bg(theme.alias.color_surface)
+                    .border_r(px(1.0))
+                    .border_color(theme.alias.color_border)
+                    .child(slot(window, cx))
+            });
+        let content = div()
+            .flex_1()
+            .min_w(px(0.0))
+            .h_full()
+            .overflow_hidden()
+            .children(self.props.content.clone().map(|slot| slot(window, cx)));
+
+        match sidebar {
+            Some(sidebar) if compact => {
+                // Overlay the sidebar on the content, sliding in from the left
+                let reduced = reduced_motion(cx);
+                let overlay = div()
+                    .absolute()
+                    .top(px(0.0))
+                    .left(px(0.0))
+                    .size_full()
+                    .occlude()
+                    .child(
+                        div()
+                            .id("app-shell-backdrop")
+                            .absolute()
+                            .size_full()
+                            .bg(hsla(0.0, 0.0, 0.0, 0.4))
+                            .on_click(cx.listener(|this, _event: &ClickEvent, window, cx| {
+                                this.set_sidebar_open(false, window, cx);
+                            })),
+                    )
+                    .child(
+                        sidebar.absolute().top(px(0.0)).shadow_xl().with_animation(
+                            ("app-shell-sidebar", self.transition),
+                            motion::animation(theme.global.duration_normal, Easing::Standard),
+                            move |sidebar, delta| {
+                                if reduced {
+                                    sidebar.left(px(0.0)).opacity(delta)
+                                } else {
+                                    sidebar.left(-(sidebar_width * (1.0 - delta)))
+                                }
+                            },
+                        ),
+                    );
+                body = body.child(content).child(overlay);
+            }
+            Some(sidebar) => body = body.child(sidebar).child(content),
+            None => body = body.child(content),
+        }
+
+        div()
+            .flex()
+            .flex_col()
+            .size_full()
+            .bg(theme.alias.color_surface)
+            .text_color(theme.alias.color_text_primary)
+            .on_key_down(cx.listener(Self::handle_key))
+            .child(header)
+            .child(body)
+            .children(self.props.footer.clone().map(|slot| {
+                div()
+                    .flex()
+                    .flex_row()
+                    .flex_none()
+                    .items_center()
+                    .gap(theme.global.spacing_md)
+                    .px(theme.global.spacing_md)
+                    .py(theme.global.spacing_xs)
+                    .bg(theme.alias.color_surface_elevated)
+                    .border_t(px(1.0))
+                    .border_color(theme.alias.color_border)
+                    .text_size(theme.global.font_size_xs)
+                    .text_color(theme.alias.color_text_muted)
+                    .child(slot(window, cx))
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_app_shell_defaults() {
+        let shell = AppShell::new();
+        assert!(shell.is_sidebar_open());
+        assert_eq!(shell.props.sidebar_width, px(240.0));
+        assert_eq!(shell.props.collapse_below, Some(Breakpoint::Md));
+        assert!(shell.props.sidebar.is_none());
+    }
+
+    #[test]
+    fn test_compact_below_breakpoint() {
+        let shell = AppShell::new().collapse_below(Some(Breakpoint::Lg));
+        assert!(shell.is_compact(None));
+        assert!(shell.is_compact(Some(Breakpoint::Md)));
+        assert!(!shell.is_compact(Some(Breakpoint::Lg)));
+        assert!(!shell.is_compact(Some(Breakpoint::Xl)));
+
+        let docked = AppShell::new().collapse_below(None);
+        assert!(!docked.is_compact(None));
+    }
+}
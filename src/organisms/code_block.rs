@@ -0,0 +1,706 @@
+//! Read-only code block with line numbers, highlighting, wrapping and copy.
+
+use std::{ops::Range, rc::Rc, time::Duration};
+
+use gpui::*;
+use crate::{
+    atoms::{icons, Button, ButtonSize, ButtonVariant, MONOSPACE_FONT},
+    theme::{use_theme, Theme},
+};
+#[cfg(feature = "syntect")]
+use crate::theme::ThemeMode;
+
+/// How long the copy button shows its confirmation
+const COPIED_DURATION: Duration = Duration::from_secs(2);
+
+/// Highlights code, given its text and language
+///
+/// Returns byte ranges of the code and the style to draw each with. The
+/// same signature is accepted by [`Markdown::highlighter`](super::Markdown::highlighter).
+pub type CodeHighlighter = Rc<dyn Fn(&str, Option<&str>) -> Vec<(Range<usize>, HighlightStyle)>>;
+
+/// Byte ranges of the lines of `code`, without their line breaks
+fn line_ranges(code: &str) -> Vec<Range<usize>> {
+    let mut lines = Vec::new();
+    let mut start = 0;
+    for (index, _) in code.match_indices('\n') {
+        let end = if code[..index].ends_with('\r') { index - 1 } else { index };
+        lines.push(start..end);
+        start = index + 1;
+    }
+    lines.push(start..code.len());
+    lines
+}
+
+/// Highlights overlapping `line`, clipped to it and relative to its start
+fn line_highlights(highlights: &[(Range<usize>, HighlightStyle)], line: &Range<usize>) -> Vec<(Range<usize>, HighlightStyle)> {
+    highlights
+        .iter()
+        .filter(|(range, _)| range.start < line.end && range.end > line.start)
+        .map(|(range, style)| {
+            let start = range.start.max(line.start) - line.start;
+            let end = range.end.min(line.end) - line.start;
+            (start..end, *style)
+        })
+        .collect()
+}
+
+/// Apply `overlay` on top of `highlights` within `range`
+///
+/// Highlights must be sorted and must not overlap, as for `StyledText`;
+/// the result keeps both properties.
+fn overlay_highlight(
+    highlights: &[(Range<usize>, HighlightStyle)],
+    range: Range<usize>,
+    overlay: HighlightStyle,
+) -> Vec<(Range<usize>, HighlightStyle)> {
+    if range.is_empty() {
+        return highlights.to_vec();
+    }
+
+    let mut bounds: Vec<usize> = highlights
+        .iter()
+        .flat_map(|(highlight, _)| [highlight.start, highlight.end])
+        .chain([range.start, range.end])
+        .collect();
+    bounds.sort_unstable();
+    bounds.dedup();
+
+    bounds
+        .windows(2)
+        .filter_map(|pair| {
+            let segment = pair[0]..pair[1];
+            let base = highlights
+                .iter()
+                .find(|(highlight, _)| highlight.start <= segment.start && segment.end <= highlight.end)
+                .map(|(_, style)| *style);
+            let selected = range.start <= segment.start && segment.end <= range.end;
+            let style = match (base, selected) {
+                (Some(mut style), true) => {
+                    style.highlight(overlay);
+                    style
+                }
+                (Some(style), false) => style,
+                (None, true) => overlay,
+                (None, false) => return None,
+            };
+            Some((segment, style))
+        })
+        .collect()
+}
+
+/// Highlighter backed by syntect's bundled grammars and themes
+///
+/// Languages are looked up by name or file extension, such as "rust" or
+/// "rs"; unknown languages are left plain. Requires the `syntect` feature.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// let highlighter = syntect_highlighter(theme.mode);
+/// CodeBlock::new(source).language("rust").highlighter(highlighter.clone());
+/// Markdown::new(notes).highlighter(move |code, language| highlighter(code, language));
+/// ```
+#[cfg(feature = "syntect")]
+pub fn syntect_highlighter(mode: ThemeMode) -> CodeHighlighter {
+    use syntect::{
+        easy::HighlightLines,
+        highlighting::{FontStyle as SyntectFontStyle, ThemeSet},
+        parsing::SyntaxSet,
+        util::LinesWithEndings,
+    };
+
+    let syntaxes = SyntaxSet::load_defaults_newlines();
+    let name = if mode == ThemeMode::Dark { "base16-ocean.dark" } else { "InspiredGitHub" };
+    let theme = ThemeSet::load_defaults().themes[name].clone();
+
+    Rc::new(move |code: &str, language: Option<&str>| {
+        let Some(syntax) = language.and_then(|language| syntaxes.find_syntax_by_token(language)) else {
+            return Vec::new();
+        };
+        let mut lines = HighlightLines::new(syntax, &theme);
+        let mut highlights = Vec::new();
+        let mut offset = 0;
+        for line in LinesWithEndings::from(code) {
+            let Ok(regions) = lines.highlight_line(line, &syntaxes) else {
+                break;
+            };
+            for (style, text) in regions {
+                let color = style.foreground;
+                let mut highlight = HighlightStyle {
+                    color: Some(
+                        Rgba {
+                            r: f32::from(color.r) / 255.0,
+                            g: f32::from(color.g) / 255.0,
+                            b: f32::from(color.b) / 255.0,
+                            a: f32::from(color.a) / 255.0,
+                        }
+                        .into(),
+                    ),
+                    ..HighlightStyle::default()
+                };
+                if style.font_style.contains(SyntectFontStyle::BOLD) {
+                    highlight.font_weight = Some(FontWeight::BOLD);
+                }
+                if style.font_style.contains(SyntectFontStyle::ITALIC) {
+                    highlight.font_style = Some(FontStyle::Italic);
+                }
+                highlights.push((offset..offset + text.len(), highlight));
+                offset += text.len();
+            }
+        }
+        highlights
+    })
+}
+
+/// CodeBlock configuration properties
+#[derive(Clone)]
+pub struct CodeBlockProps {
+    /// Code to show
+    pub code: SharedString,
+    /// Language passed to the highlighter and shown in the header
+    pub language: Option<SharedString>,
+    /// Title shown in the header, such as a file name
+    pub title: Option<SharedString>,
+    /// Whether a gutter shows line numbers
+    pub line_numbers: bool,
+    /// Number of the first line, for snippets taken from a larger file
+    pub start_line: usize,
+    /// Whether long lines wrap instead of scrolling horizontally
+    pub wrap: bool,
+    /// Whether the header shows a copy button
+    pub copyable: bool,
+    /// Height above which the block scrolls vertically
+    pub max_height: Option<Pixels>,
+    /// Syntax highlighter
+    pub highlighter: Option<CodeHighlighter>,
+}
+
+impl Default for CodeBlockProps {
+    fn default() -> Self {
+        Self {
+            code: "".into(),
+            language: None,
+            title: None,
+            line_numbers: true,
+            start_line: 1,
+            wrap: false,
+            copyable: true,
+            max_height: None,
+            highlighter: None,
+        }
+    }
+}
+
+/// A read-only block of code.
+///
+/// CodeBlock shows snippets, files and diffs inside dashboards and
+/// documentation in the platform monospace font.
+///
+/// ## Features
+///
+/// - Line number gutter, optionally starting past line 1
+/// - Syntax highlighting through a pluggable highlighter, with a syntect
+///   highlighter behind the `syntect` feature
+/// - Wrapping toggle in the header
+/// - Copy button, and mouse selection copied with Cmd/Ctrl+C
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::organisms::*;
+///
+/// let snippet = cx.new(|_| {
+///     CodeBlock::new(include_str!("../examples/dashboard.rs"))
+///         .language("rust")
+///         .title("dashboard.rs")
+///         .max_height(px(320.0))
+///         .highlighter(syntect_highlighter(theme.mode))
+/// });
+/// ```
+///
+/// ## Accessibility
+///
+/// - The code area is focusable; Cmd/Ctrl+A selects all, Cmd/Ctrl+C copies
+///   the selection or all code, and Escape clears the selection
+/// - Header buttons are labelled and the copy button confirms with "Copied"
+pub struct CodeBlock {
+    props: CodeBlockProps,
+    /// Byte ranges of the lines of the code
+    lines: Vec<Range<usize>>,
+    /// Highlights of the whole code, computed on first render
+    highlights: Option<Rc<Vec<(Range<usize>, HighlightStyle)>>>,
+    /// Selected bytes as anchor and head offsets into the code
+    selection: Option<(usize, usize)>,
+    /// Whether the mouse is extending the selection
+    selecting: bool,
+    /// Text layouts of the lines from the last render, for hit testing
+    line_layouts: Vec<TextLayout>,
+    /// Whether the copy button is showing its confirmation
+    copied: bool,
+    _copied_task: Option<Task<()>>,
+    focus_handle: Option<FocusHandle>,
+}
+
+impl CodeBlock {
+    /// Create a block showing `code`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let block = CodeBlock::new("cargo add purdah-gpui-components");
+    /// ```
+    pub fn new(code: impl Into<SharedString>) -> Self {
+        let code = code.into();
+        Self {
+            lines: line_ranges(&code),
+            props: CodeBlockProps {
+                code,
+                ..CodeBlockProps::default()
+            },
+            highlights: None,
+            selection: None,
+            selecting: false,
+            line_layouts: Vec::new(),
+            copied: false,
+            _copied_task: None,
+            focus_handle: None,
+        }
+    }
+
+    /// Set the language passed to the highlighter
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// CodeBlock::new(source).language("rust");
+    /// ```
+    pub fn language(mut self, language: impl Into<SharedString>) -> Self {
+        self.props.language = Some(language.into());
+        self.highlights = None;
+        self
+    }
+
+    /// Set the title shown in the header
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// CodeBlock::new(source).title("src/main.rs");
+    /// ```
+    pub fn title(mut self, title: impl Into<SharedString>) -> Self {
+        self.props.title = Some(title.into());
+        self
+    }
+
+    /// Set whether a gutter shows line numbers
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// CodeBlock::new("npm install").line_numbers(false);
+    /// ```
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.props.line_numbers = line_numbers;
+        self
+    }
+
+    /// Set the number of the first line
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// // Lines 120-140 of the file
+    /// CodeBlock::new(excerpt).start_line(120);
+    /// ```
+    pub fn start_line(mut self, start_line: usize) -> Self {
+        self.props.start_line = start_line;
+        self
+    }
+
+    /// Set whether long lines start wrapped
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// CodeBlock::new(log_output).wrap(true);
+    /// ```
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.props.wrap = wrap;
+        self
+    }
+
+    /// Set whether the header shows a copy button
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// CodeBlock::new(source).copyable(false);
+    /// ```
+    pub fn copyable(mut self, copyable: bool) -> Self {
+        self.props.copyable = copyable;
+        self
+    }
+
+    /// Set the height above which the block scrolls vertically
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// CodeBlock::new(source).max_height(px(400.0));
+    /// ```
+    pub fn max_height(mut self, max_height: Pixels) -> Self {
+        self.props.max_height = Some(max_height);
+        self
+    }
+
+    /// Set the syntax highlighter
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// CodeBlock::new(source).language("toml").highlighter(syntect_highlighter(theme.mode));
+    /// ```
+    pub fn highlighter(mut self, highlighter: CodeHighlighter) -> Self {
+        self.props.highlighter = Some(highlighter);
+        self.highlights = None;
+        self
+    }
+
+    /// Replace the code from the parent view, clearing the selection
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// block.update(cx, |block, cx| block.set_code(diff, cx));
+    /// ```
+    pub fn set_code(&mut self, code: impl Into<SharedString>, cx: &mut Context<'_, Self>) {
+        let code = code.into();
+        if code == self.props.code {
+            return;
+        }
+        self.lines = line_ranges(&code);
+        self.props.code = code;
+        self.highlights = None;
+        self.selection = None;
+        cx.notify();
+    }
+
+    /// Whether long lines wrap
+    pub fn is_wrapped(&self) -> bool {
+        self.props.wrap
+    }
+
+    /// Wrap or unwrap long lines from the parent view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// block.update(cx, |block, cx| block.set_wrap(true, cx));
+    /// ```
+    pub fn set_wrap(&mut self, wrap: bool, cx: &mut Context<'_, Self>) {
+        if self.props.wrap != wrap {
+            self.props.wrap = wrap;
+            cx.notify();
+        }
+    }
+
+    /// Selected text, if any
+    pub fn selected_text(&self) -> Option<&str> {
+        let range = self.selected_range()?;
+        Some(&self.props.code[range])
+    }
+
+    /// Selected bytes of the code, if the selection is not empty
+    fn selected_range(&self) -> Option<Range<usize>> {
+        let (anchor, head) = self.selection?;
+        let range = anchor.min(head)..anchor.max(head);
+        (!range.is_empty()).then_some(range)
+    }
+
+    /// Byte offset of `position` in the line at `line`
+    fn offset_at(&self, line: usize, position: Point<Pixels>) -> usize {
+        let column = self
+            .line_layouts
+            .get(line)
+            .map_or(0, |layout| match layout.index_for_position(position) {
+                Ok(index) | Err(index) => index,
+            });
+        let range = &self.lines[line];
+        (range.start + column).min(range.end)
+    }
+
+    /// Start selecting at `position` in `line`, or extend with Shift
+    fn start_selection(&mut self, line: usize, event: &MouseDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if let Some(focus_handle) = &self.focus_handle {
+            focus_handle.focus(window);
+        }
+        let offset = self.offset_at(line, event.position);
+        self.selection = match self.selection {
+            Some((anchor, _)) if event.modifiers.shift => Some((anchor, offset)),
+            _ => Some((offset, offset)),
+        };
+        self.selecting = true;
+        cx.notify();
+    }
+
+    /// Extend the selection while the mouse drags over `line`
+    fn extend_selection(&mut self, line: usize, event: &MouseMoveEvent, cx: &mut Context<'_, Self>) {
+        if !self.selecting {
+            return;
+        }
+        if !event.dragging() {
+            self.selecting = false;
+            return;
+        }
+        let offset = self.offset_at(line, event.position);
+        if let Some((anchor, head)) = self.selection {
+            if head != offset {
+                self.selection = Some((anchor, offset));
+                cx.notify();
+            }
+        }
+    }
+
+    /// Copy the selection, or all code without one, to the clipboard
+    fn copy(&mut self, all: bool, cx: &mut Context<'_, Self>) {
+        let text = match self.selected_text() {
+            Some(selected) if !all => selected.to_string(),
+            _ => self.props.code.to_string(),
+        };
+        cx.write_to_clipboard(ClipboardItem::new_string(text));
+        if all {
+            self.copied = true;
+            self._copied_task = Some(cx.spawn(async move |this, cx| {
+                cx.background_executor().timer(COPIED_DURATION).await;
+                this.update(cx, |this, cx| {
+                    this.copied = false;
+                    cx.notify();
+                })
+                .ok();
+            }));
+            cx.notify();
+        }
+    }
+
+    /// Copy with Cmd/Ctrl+C, select all with Cmd/Ctrl+A and clear with Escape
+    fn handle_key(&mut self, event: &KeyDownEvent, _window: &mut Window, cx: &mut Context<'_, Self>) {
+        let modifiers = &event.keystroke.modifiers;
+        let command = modifiers.platform || modifiers.control;
+        match event.keystroke.key.as_str() {
+            "c" if command => self.copy(false, cx),
+            "a" if command => {
+                self.selection = Some((0, self.props.code.len()));
+                cx.notify();
+            }
+            "escape" if self.selection.is_some() => {
+                self.selection = None;
+                cx.notify();
+            }
+            _ => return,
+        }
+        cx.stop_propagation();
+    }
+
+    /// Highlights of the whole code, computing them if needed
+    fn highlights(&mut self) -> Rc<Vec<(Range<usize>, HighlightStyle)>> {
+        let props = &self.props;
+        self.highlights
+            .get_or_insert_with(|| {
+                let highlights = props
+                    .highlighter
+                    .as_ref()
+                    .map(|highlight| highlight(&props.code, props.language.as_deref()))
+                    .unwrap_or_default();
+                Rc::new(highlights)
+            })
+            .clone()
+    }
+
+    /// Header with the title or language, the wrap toggle and the copy button
+    fn render_header(&self, theme: &Theme, cx: &mut Context<'_, Self>) -> Div {
+        let caption = self.props.title.clone().or_else(|| self.props.language.clone());
+        let mut header = div()
+            .flex()
+            .flex_row()
+            .flex_none()
+            .items_center()
+            .gap(theme.global.spacing_xs)
+            .px(theme.global.spacing_sm)
+            .py(theme.global.spacing_xs)
+            .border_b(px(1.0))
+            .border_color(theme.alias.color_border)
+            .child(
+                div()
+                    .flex_1()
+                    .min_w(px(0.0))
+                    .truncate()
+                    .text_size(theme.global.font_size_xs)
+                    .text_color(theme.alias.color_text_secondary)
+                    .children(caption),
+            )
+            .child(
+                Button::new()
+                    .id("code-block-wrap")
+                    .label(if self.props.wrap { "Don't wrap lines" } else { "Wrap lines" })
+                    .icon_left(icons::WRAP_TEXT)
+                    .icon_only(true)
+                    .variant(if self.props.wrap { ButtonVariant::Secondary } else { ButtonVariant::Ghost })
+                    .size(ButtonSize::Sm)
+                    .on_click(cx.listener(|this, _event: &ClickEvent, _window, cx| {
+                        let wrap = !this.props.wrap;
+                        this.set_wrap(wrap, cx);
+                    }))
+                    .to_element(theme),
+            );
+        if self.props.copyable {
+            header = header.child(
+                Button::new()
+                    .id("code-block-copy")
+                    .label(if self.copied { "Copied" } else { "Copy" })
+                    .icon_left(if self.copied { icons::CHECK } else { icons::COPY })
+                    .icon_only(true)
+                    .variant(ButtonVariant::Ghost)
+                    .size(ButtonSize::Sm)
+                    .on_click(cx.listener(|this, _event: &ClickEvent, _window, cx| this.copy(true, cx)))
+                    .to_element(theme),
+            );
+        }
+        header
+    }
+}
+
+impl Render for CodeBlock {
+    #[allow(clippy::cast_precision_loss)]
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        let focus_handle = self.focus_handle.get_or_insert_with(|| cx.focus_handle()).clone();
+        let highlights = self.highlights();
+        let selection = self.selected_range();
+        let selection_style = HighlightStyle {
+            background_color: Some(theme.alias.color_primary.opacity(0.25)),
+            ..HighlightStyle::default()
+        };
+        let last_number = self.props.start_line + self.lines.len().saturating_sub(1);
+        let gutter_chars = last_number.to_string().len();
+
+        let mut layouts = Vec::with_capacity(self.lines.len());
+        let mut rows = Vec::with_capacity(self.lines.len());
+        for (index, line) in self.lines.iter().enumerate() {
+            let mut line_highlights = line_highlights(&highlights, line);
+            if let Some(selected) = &selection {
+                let start = selected.start.clamp(line.start, line.end) - line.start;
+                let end = selected.end.clamp(line.start, line.end) - line.start;
+                line_highlights = overlay_highlight(&line_highlights, start..end, selection_style);
+            }
+            let text = StyledText::new(SharedString::from(self.props.code[line.clone()].to_string()))
+                .with_highlights(line_highlights);
+            layouts.push(text.layout().clone());
+
+            let mut row = div()
+                .id(("code-block-line", index))
+                .flex()
+                .flex_row()
+                .gap(theme.global.spacing_md)
+                .px(theme.global.spacing_md)
+                .on_mouse_down(MouseButton::Left, cx.listener(move |this, event: &MouseDownEvent, window, cx| {
+                    this.start_selection(index, event, window, cx);
+                }))
+                .on_mouse_move(cx.listener(move |this, event: &MouseMoveEvent, _window, cx| {
+                    this.extend_selection(index, event, cx);
+                }));
+            if self.props.line_numbers {
+                row = row.child(
+                    div()
+                        .flex_none()
+                        .flex()
+                        .justify_end()
+                        .min_w(theme.global.font_size_sm * (gutter_chars as f32 * 0.6))
+                        .text_color(theme.alias.color_text_muted)
+                        .child((self.props.start_line + index).to_string()),
+                );
+            }
+            let content = div().flex_1().min_w(px(0.0)).child(text);
+            row = row.child(if self.props.wrap { content } else { content.whitespace_nowrap() });
+            rows.push(row);
+        }
+        self.line_layouts = layouts;
+
+        let mut body = div()
+            .id("code-block-body")
+            .track_focus(&focus_handle)
+            .on_key_down(cx.listener(Self::handle_key))
+            .flex()
+            .flex_col()
+            .py(theme.global.spacing_sm)
+            .font_family(MONOSPACE_FONT)
+            .text_size(theme.global.font_size_sm)
+            .text_color(theme.alias.color_text_primary)
+            .cursor_text()
+            .overflow_y_scroll()
+            .children(rows);
+        if !self.props.wrap {
+            body = body.overflow_x_scroll();
+        }
+        if let Some(max_height) = self.props.max_height {
+            body = body.max_h(max_height);
+        }
+        if focus_handle.is_focused(window) {
+            body = body.outline_color(theme.alias.color_border_focus).outline_w(px(2.0));
+        }
+
+        div()
+            .flex()
+            .flex_col()
+            .w_full()
+            .bg(theme.alias.color_surface_hover)
+            .border(px(1.0))
+            .border_color(theme.alias.color_border)
+            .rounded(theme.global.radius_md)
+            .overflow_hidden()
+            .child(self.render_header(&theme, cx))
+            .child(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bold() -> HighlightStyle {
+        HighlightStyle { font_weight: Some(FontWeight::BOLD), ..HighlightStyle::default() }
+    }
+
+    fn italic() -> HighlightStyle {
+        HighlightStyle { font_style: Some(FontStyle::Italic), ..HighlightStyle::default() }
+    }
+
+    #[test]
+    fn test_line_ranges() {
+        let code = "fn main() {\r\n}\n";
+        let lines = line_ranges(code);
+        assert_eq!(lines, vec![0..11, 13..14, 15..15]);
+        assert_eq!(&code[lines[0].clone()], "fn main() {");
+        assert_eq!(line_ranges(""), vec![0..0]);
+    }
+
+    #[test]
+    fn test_line_highlights_clip_to_line() {
+        let highlights = vec![(0..4, bold()), (6..12, italic())];
+        assert_eq!(line_highlights(&highlights, &(5..10)), vec![(1..5, italic())]);
+        assert_eq!(line_highlights(&highlights, &(2..8)), vec![(0..2, bold()), (4..6, italic())]);
+    }
+
+    #[test]
+    fn test_overlay_splits_highlights() {
+        let mut bold_italic = bold();
+        bold_italic.highlight(italic());
+
+        let highlights = vec![(0..4, bold())];
+        assert_eq!(
+            overlay_highlight(&highlights, 2..6, italic()),
+            vec![(0..2, bold()), (2..4, bold_italic), (4..6, italic())]
+        );
+        assert_eq!(overlay_highlight(&highlights, 3..3, italic()), highlights);
+    }
+}
@@ -1,8 +1,60 @@
 //! CommandPalette component for command interface.
 
+use std::{
+    cmp::Reverse,
+    io,
+    path::{Path, PathBuf},
+    rc::Rc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
 use gpui::*;
 use gpui::prelude::FluentBuilder;
-use crate::{atoms::{Input, Label, LabelVariant}, theme::Theme};
+use serde_json::{json, Value};
+use crate::{
+    atoms::{icons, Icon, IconSize, KeybindingHint, Label, LabelVariant},
+    layout::{VirtualList, VirtualListState},
+    theme::{use_theme, Theme},
+    utils::{
+        fuzzy_match, register_shortcut, shortcut_keystrokes, FocusTrap, FuzzyMatch, Shortcut,
+        ShortcutConflict,
+    },
+};
+
+actions!(
+    command_palette,
+    [
+        /// Open the command palette, or close it when already open
+        ToggleCommandPalette
+    ]
+);
+
+/// Default keystroke bound to [`ToggleCommandPalette`] by [`CommandPalette::bind_keys`]
+#[cfg(target_os = "macos")]
+pub const TOGGLE_COMMAND_PALETTE_KEY: &str = "cmd-k";
+/// Default keystroke bound to [`ToggleCommandPalette`] by [`CommandPalette::bind_keys`]
+#[cfg(not(target_os = "macos"))]
+pub const TOGGLE_COMMAND_PALETTE_KEY: &str = "ctrl-k";
+/// Id of the shortcut registered by [`CommandPalette::bind_keys`]
+pub const TOGGLE_COMMAND_PALETTE_SHORTCUT: &str = "command_palette.toggle";
+
+/// Height of each command row in the results list
+const COMMAND_ROW_HEIGHT: f32 = 52.0;
+
+/// Number of recently used commands that get a ranking bonus
+const MAX_RECENT: usize = 10;
+
+/// Number of commands listed under "Recently used" when the query is empty
+const RECENT_SECTION_SIZE: usize = 5;
+
+/// Number of distinct commands kept in the history
+const MAX_HISTORY: usize = 200;
+
+/// Score bonus per step of recency; the most recent command gets the most
+const RECENCY_BONUS: i32 = 2;
+
+/// Callback run when a command is executed
+pub type CommandAction = Rc<dyn Fn(&mut Window, &mut App)>;
 
 /// Command item definition
 #[derive(Clone)]
@@ -11,6 +63,322 @@ pub struct Command {
     pub label: SharedString,
     /// Command description
     pub description: Option<SharedString>,
+    /// Callback run when the command is executed
+    pub action: Option<CommandAction>,
+    /// Icon path shown before the label
+    pub icon: Option<&'static str>,
+    /// Group the command is listed under
+    pub group: Option<SharedString>,
+    /// Keystrokes that run the command outside the palette, e.g. "cmd-shift-p"
+    pub keybinding: Option<SharedString>,
+    /// Id of the registered shortcut whose keystrokes are shown as the hint
+    pub shortcut: Option<SharedString>,
+}
+
+impl Command {
+    /// Create a new command with a label
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Command::new("Open File");
+    /// ```
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            description: None,
+            action: None,
+            icon: None,
+            group: None,
+            keybinding: None,
+            shortcut: None,
+        }
+    }
+
+    /// Set the description shown below the label
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Command::new("Open File").description("Open a file from disk");
+    /// ```
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the callback run when the command is executed
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Command::new("Save").action(|window, cx| save_document(window, cx));
+    /// ```
+    pub fn action(mut self, action: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.action = Some(Rc::new(action));
+        self
+    }
+
+    /// Set the icon shown before the label
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Command::new("Open File").icon(icons::FILE);
+    /// ```
+    pub fn icon(mut self, icon: &'static str) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Set the group the command is listed under
+    ///
+    /// Results are shown in sections, one per group, each with a header.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Command::new("Open File").group("File");
+    /// ```
+    pub fn group(mut self, group: impl Into<SharedString>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+
+    /// Set the keybinding hint shown at the end of the row
+    ///
+    /// Uses GPUI keystroke syntax: modifiers joined to the key with `-`, and
+    /// chords separated by spaces. The hint is display-only; bind the keys
+    /// separately.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Command::new("Save All").keybinding("cmd-alt-s");
+    /// Command::new("Open Settings").keybinding("cmd-k cmd-s");
+    /// ```
+    pub fn keybinding(mut self, keybinding: impl Into<SharedString>) -> Self {
+        self.keybinding = Some(keybinding.into());
+        self
+    }
+
+    /// Show the keystrokes of a registered shortcut as the hint
+    ///
+    /// The hint follows the shortcut when it is rebound. An explicit
+    /// [`Command::keybinding`] takes precedence.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// register_shortcut(Shortcut::new("file.save", "secondary-s"), SaveFile, cx)?;
+    /// Command::new("Save").shortcut("file.save");
+    /// ```
+    pub fn shortcut(mut self, id: impl Into<SharedString>) -> Self {
+        self.shortcut = Some(id.into());
+        self
+    }
+}
+
+/// One row of the results list
+#[derive(Debug, Clone, PartialEq)]
+enum PaletteRow {
+    /// Group header; `separated` draws a divider above it
+    Header { label: SharedString, separated: bool },
+    /// Command at `position` in the results
+    Command { position: usize },
+}
+
+/// One command's usage record in a [`CommandHistory`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandUse {
+    /// Label of the command
+    pub label: SharedString,
+    /// Number of times the command was executed
+    pub count: u32,
+    /// When the command was last executed, in seconds since the Unix epoch
+    pub last_used: u64,
+}
+
+/// Seconds since the Unix epoch
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+/// Frecency weight for a use `age` seconds old; recent uses count for more
+fn recency_weight(age: u64) -> u64 {
+    const DAY: u64 = 24 * 60 * 60;
+    match age {
+        age if age < 4 * DAY => 100,
+        age if age < 14 * DAY => 70,
+        age if age < 31 * DAY => 50,
+        age if age < 90 * DAY => 30,
+        _ => 10,
+    }
+}
+
+/// History of executed commands, used to rank the [`CommandPalette`].
+///
+/// Commands are identified by label. The history lives in memory unless
+/// created with [`CommandHistory::persistent`], in which case it is loaded
+/// from and saved to a JSON file so it survives restarts.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// let history = CommandHistory::persistent(config_dir.join("command_history.json"));
+/// CommandPalette::new().commands(commands).history(history);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CommandHistory {
+    entries: Vec<CommandUse>,
+    path: Option<PathBuf>,
+}
+
+impl CommandHistory {
+    /// Create an empty, in-memory history
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let history = CommandHistory::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a history persisted to the JSON file at `path`
+    ///
+    /// Existing history is loaded from the file. A missing or unreadable
+    /// file starts an empty history, which is written on the next save.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let history = CommandHistory::persistent("/home/me/.config/app/commands.json");
+    /// ```
+    pub fn persistent(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let entries = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|json| serde_json::from_str::<Value>(&json).ok())
+            .map(|root| entries_from_json(&root))
+            .unwrap_or_default();
+        Self {
+            entries,
+            path: Some(path),
+        }
+    }
+
+    /// File the history is saved to, if persistent
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Usage records, most recently used first
+    pub fn entries(&self) -> &[CommandUse] {
+        &self.entries
+    }
+
+    /// Record an execution of the command with `label`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// history.record("Open File");
+    /// ```
+    pub fn record(&mut self, label: impl Into<SharedString>) {
+        self.record_at(label.into(), unix_now());
+    }
+
+    /// Forget all recorded commands
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Write the history to its file; does nothing for in-memory histories
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// if let Err(err) = history.save() {
+    ///     eprintln!("failed to save command history: {err}");
+    /// }
+    /// ```
+    pub fn save(&self) -> io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, self.to_json())
+    }
+
+    /// Record an execution at `now`, keeping entries ordered by last use
+    fn record_at(&mut self, label: SharedString, now: u64) {
+        let count = match self.entries.iter().position(|entry| entry.label == label) {
+            Some(position) => self.entries.remove(position).count.saturating_add(1),
+            None => 1,
+        };
+        self.entries.insert(0, CommandUse { label, count, last_used: now });
+        self.entries.truncate(MAX_HISTORY);
+    }
+
+    /// Position of `label` among the most recently used commands
+    fn recency_rank(&self, label: &str) -> Option<usize> {
+        self.entries
+            .iter()
+            .take(MAX_RECENT)
+            .position(|entry| entry.label.as_ref() == label)
+    }
+
+    /// Frequency of use weighted by how recently the command was used
+    fn frecency_at(&self, label: &str, now: u64) -> u64 {
+        self.entries
+            .iter()
+            .find(|entry| entry.label.as_ref() == label)
+            .map_or(0, |entry| {
+                u64::from(entry.count) * recency_weight(now.saturating_sub(entry.last_used))
+            })
+    }
+
+    /// Serialize the entries as a JSON document
+    fn to_json(&self) -> String {
+        let commands: Vec<Value> = self
+            .entries
+            .iter()
+            .map(|entry| {
+                json!({
+                    "label": entry.label.as_ref(),
+                    "count": entry.count,
+                    "last_used": entry.last_used,
+                })
+            })
+            .collect();
+        json!({ "commands": commands }).to_string()
+    }
+}
+
+/// Read usage records from a saved history document, skipping malformed ones
+fn entries_from_json(root: &Value) -> Vec<CommandUse> {
+    let mut entries: Vec<CommandUse> = root
+        .get("commands")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            Some(CommandUse {
+                label: entry.get("label")?.as_str()?.to_string().into(),
+                count: u32::try_from(entry.get("count")?.as_u64()?).ok()?,
+                last_used: entry.get("last_used")?.as_u64()?,
+            })
+        })
+        .collect();
+    entries.sort_by_key(|entry| Reverse(entry.last_used));
+    entries.truncate(MAX_HISTORY);
+    entries
 }
 
 /// CommandPalette configuration properties
@@ -22,6 +390,10 @@ pub struct CommandPaletteProps {
     pub commands: Vec<Command>,
     /// Whether palette is open
     pub open: bool,
+    /// Text shown in the search field while the query is empty
+    pub placeholder: SharedString,
+    /// Called when the palette closes, after Escape or running a command
+    pub on_close: Option<Rc<dyn Fn(&mut Window, &mut App)>>,
 }
 
 impl Default for CommandPaletteProps {
@@ -30,64 +402,650 @@ impl Default for CommandPaletteProps {
             query: "".into(),
             commands: vec![],
             open: false,
+            placeholder: "Search commands...".into(),
+            on_close: None,
         }
     }
 }
 
+/// Score bonus for a command used `rank` commands ago (0 is the latest)
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+fn recency_bonus(rank: usize) -> i32 {
+    MAX_RECENT.saturating_sub(rank) as i32 * RECENCY_BONUS
+}
+
+/// Render `label` with the characters at `positions` emphasized
+fn highlighted_label(label: &str, positions: &[usize], theme: &Theme) -> Div {
+    let mut runs: Vec<(String, bool)> = Vec::new();
+    let mut positions = positions.iter().peekable();
+    for (index, c) in label.chars().enumerate() {
+        let matched = positions.next_if_eq(&&index).is_some();
+        match runs.last_mut() {
+            Some((text, run_matched)) if *run_matched == matched => text.push(c),
+            _ => runs.push((c.to_string(), matched)),
+        }
+    }
+
+    div()
+        .flex()
+        .flex_row()
+        .text_size(theme.global.font_size_base)
+        .text_color(theme.alias.color_text_primary)
+        .children(runs.into_iter().map(|(text, matched)| {
+            div()
+                .when(matched, |this| {
+                    this.font_weight(FontWeight::BOLD)
+                        .text_color(theme.alias.color_primary)
+                })
+                .child(SharedString::from(text))
+        }))
+}
+
 /// A command palette component.
 ///
-/// CommandPalette provides a searchable command interface.
+/// CommandPalette provides a searchable command interface. Commands are
+/// fuzzy matched against the query: every query character must appear in
+/// the label in order, and results are ranked by match quality with a
+/// bonus for recently used commands. Matched characters are highlighted.
+/// With an empty query, the most recently used commands are listed first
+/// under "Recently used" and the rest are ordered by frecency, a blend of
+/// how often and how recently each command was run.
+/// Results are listed in sections by command group, each under a header,
+/// with keybinding hints aligned to the right.
+///
+/// Register [`CommandPalette::bind_keys`] once at startup and handle
+/// [`ToggleCommandPalette`] in the view that owns the palette to open it
+/// with Cmd+K (Ctrl+K on other platforms).
 ///
 /// ## Example
 ///
 /// ```rust,ignore
 /// use purdah_gpui_components::organisms::*;
 ///
-/// CommandPalette::new()
-///     .commands(vec![
-///         Command {
-///             label: "Open File".into(),
-///             description: Some("Ctrl+O".into()),
-///         },
+/// CommandPalette::bind_keys(cx);
+///
+/// let palette = cx.new(|_| {
+///     CommandPalette::new().commands(vec![
+///         Command::new("Open File")
+///             .description("Open a file from disk")
+///             .icon(icons::FILE)
+///             .group("File")
+///             .keybinding("cmd-o")
+///             .action(|window, cx| open_file(window, cx)),
 ///     ])
-///     .open(true);
+/// });
+///
+/// // In the owning view's render
+/// div()
+///     .on_action(cx.listener(|this, _: &ToggleCommandPalette, window, cx| {
+///         this.palette.update(cx, |palette, cx| palette.toggle(window, cx));
+///     }))
+///     .child(self.palette.clone());
 /// ```
+///
+/// ## Accessibility
+///
+/// - Opening the palette focuses it; closing restores the previous focus
+/// - Focus is trapped in the palette, so Tab cannot move behind it
+/// - Typing filters the commands and Backspace deletes the last character
+/// - Up/Down move the highlight through the results, wrapping at the ends
+/// - Home and End highlight the first and last result
+/// - Enter runs the highlighted command and closes the palette
+/// - Escape closes the palette without running anything
 pub struct CommandPalette {
     props: CommandPaletteProps,
+    list_state: VirtualListState,
+    history: CommandHistory,
+    /// Number of leading results listed under "Recently used"
+    recent_count: usize,
+    /// Matching commands as (command index, match), best first
+    results: Vec<(usize, FuzzyMatch)>,
+    /// Position of the highlighted command in `results`
+    highlighted: usize,
+    focus_handle: Option<FocusHandle>,
+    focus_trap: FocusTrap,
+    /// Focus the palette on the next render, once its handle exists
+    pending_focus: bool,
 }
 
 impl CommandPalette {
+    /// Create a new, closed command palette with no commands
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let palette = cx.new(|_| CommandPalette::new().commands(commands));
+    /// ```
     pub fn new() -> Self {
         Self {
             props: CommandPaletteProps::default(),
+            list_state: VirtualListState::new(),
+            history: CommandHistory::new(),
+            recent_count: 0,
+            results: Vec::new(),
+            highlighted: 0,
+            focus_handle: None,
+            focus_trap: FocusTrap::new(),
+            pending_focus: false,
         }
     }
 
+    /// Bind the default keystroke for [`ToggleCommandPalette`]
+    ///
+    /// Binds Cmd+K on macOS and Ctrl+K elsewhere, registered in the
+    /// application's [`ShortcutRegistry`](crate::utils::ShortcutRegistry)
+    /// as [`TOGGLE_COMMAND_PALETTE_SHORTCUT`]. Call once during app
+    /// startup; the owning view still handles the action to toggle its
+    /// palette. Fails without binding anything when another shortcut
+    /// already uses the keystroke.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Application::new().run(|cx: &mut App| {
+    ///     CommandPalette::bind_keys(cx).expect("Cmd+K is free");
+    /// });
+    /// ```
+    pub fn bind_keys(cx: &mut App) -> Result<(), ShortcutConflict> {
+        let shortcut = Shortcut::new(TOGGLE_COMMAND_PALETTE_SHORTCUT, TOGGLE_COMMAND_PALETTE_KEY)
+            .description("Toggle the command palette");
+        register_shortcut(shortcut, ToggleCommandPalette, cx)
+    }
+
+    /// Set the initial search query
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// CommandPalette::new().commands(commands).query("open");
+    /// ```
     pub fn query(mut self, query: impl Into<SharedString>) -> Self {
         self.props.query = query.into();
+        self.refresh_results();
         self
     }
 
+    /// Set the available commands
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// CommandPalette::new().commands(vec![Command::new("Save")]);
+    /// ```
     pub fn commands(mut self, commands: Vec<Command>) -> Self {
         self.props.commands = commands;
+        self.refresh_results();
         self
     }
 
+    /// Set whether the palette is open initially
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// CommandPalette::new().commands(commands).open(true);
+    /// ```
     pub fn open(mut self, open: bool) -> Self {
         self.props.open = open;
+        self.pending_focus = open;
+        self
+    }
+
+    /// Set the history used to rank recently and frequently used commands
+    ///
+    /// Executed commands are recorded in it, and saved to disk when the
+    /// history is [persistent](CommandHistory::persistent).
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// CommandPalette::new()
+    ///     .commands(commands)
+    ///     .history(CommandHistory::persistent(data_dir.join("commands.json")));
+    /// ```
+    pub fn history(mut self, history: CommandHistory) -> Self {
+        self.history = history;
+        self.refresh_results();
+        self
+    }
+
+    /// Set the search field placeholder
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// CommandPalette::new().placeholder("Type a command...");
+    /// ```
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.props.placeholder = placeholder.into();
+        self
+    }
+
+    /// Set the handler called when the palette closes
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// CommandPalette::new().on_close(|_window, _cx| println!("palette closed"));
+    /// ```
+    pub fn on_close(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.props.on_close = Some(Rc::new(handler));
         self
     }
+
+    /// Open or close the palette
+    ///
+    /// Opening clears the query, highlights the best result and focuses the
+    /// palette. Closing restores the previously focused element and calls
+    /// the `on_close` handler.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// palette.update(cx, |palette, cx| palette.set_open(true, window, cx));
+    /// ```
+    pub fn set_open(&mut self, open: bool, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.props.open == open {
+            return;
+        }
+        self.props.open = open;
+
+        if open {
+            self.pending_focus = true;
+            self.props.query = "".into();
+            self.refresh_results();
+            self.list_state.scroll_to_top();
+        } else {
+            self.pending_focus = false;
+            self.focus_trap.cleanup(window);
+            if let Some(handler) = self.props.on_close.clone() {
+                handler(window, cx);
+            }
+        }
+        cx.notify();
+    }
+
+    /// Open the palette when closed, close it when open
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// palette.update(cx, |palette, cx| palette.toggle(window, cx));
+    /// ```
+    pub fn toggle(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+        self.set_open(!self.props.open, window, cx);
+    }
+
+    /// Replace the search query and re-rank the results
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// palette.update(cx, |palette, cx| palette.set_query("fmt doc", cx));
+    /// ```
+    pub fn set_query(&mut self, query: impl Into<SharedString>, cx: &mut Context<'_, Self>) {
+        let query = query.into();
+        if self.props.query == query {
+            return;
+        }
+        self.props.query = query;
+        self.refresh_results();
+        self.list_state.scroll_to_top();
+        cx.notify();
+    }
+
+    /// Record that the command with `label` was used
+    ///
+    /// Recently used commands rank above equally good matches, and above
+    /// all other commands when the query is empty. Executing a command from
+    /// the palette records it automatically. Persistent histories are saved
+    /// in the background.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// palette.update(cx, |palette, cx| palette.record_use("Open File", cx));
+    /// ```
+    pub fn record_use(&mut self, label: impl Into<SharedString>, cx: &mut Context<'_, Self>) {
+        self.history.record(label);
+        self.save_history(cx);
+        self.refresh_results();
+        cx.notify();
+    }
+
+    /// Command history used for ranking
+    pub fn command_history(&self) -> &CommandHistory {
+        &self.history
+    }
+
+    /// Write a persistent history to disk off the main thread
+    fn save_history(&self, cx: &mut Context<'_, Self>) {
+        if self.history.path().is_none() {
+            return;
+        }
+        let history = self.history.clone();
+        cx.background_executor()
+            .spawn(async move {
+                // History is a convenience; a failed write only loses ranking
+                history.save().ok();
+            })
+            .detach();
+    }
+
+    /// Recompute the ranked results for the current query
+    ///
+    /// The highlight resets to the best result.
+    fn refresh_results(&mut self) {
+        self.refresh_results_at(unix_now());
+    }
+
+    /// Recompute the ranked results, weighing frecency as of `now`
+    ///
+    /// With a query, results rank by match score plus a recency bonus.
+    /// Without one, the most recent commands come first in their own
+    /// section and the rest rank by frecency.
+    fn refresh_results_at(&mut self, now: u64) {
+        let query = self.props.query.as_ref();
+        let mut results: Vec<(usize, FuzzyMatch, i64)> = self
+            .props
+            .commands
+            .iter()
+            .enumerate()
+            .filter_map(|(index, command)| {
+                let matched = fuzzy_match(&command.label, query)?;
+                let rank = if query.is_empty() {
+                    i64::try_from(self.history.frecency_at(&command.label, now))
+                        .unwrap_or(i64::MAX)
+                } else {
+                    let bonus = self
+                        .history
+                        .recency_rank(&command.label)
+                        .map_or(0, recency_bonus);
+                    i64::from(matched.score + bonus)
+                };
+                Some((index, matched, rank))
+            })
+            .collect();
+
+        // Stable, so equally ranked commands keep their declared order
+        results.sort_by_key(|(_, _, rank)| Reverse(*rank));
+
+        // Pull the most recent commands into their own leading section
+        let mut recent = Vec::new();
+        if query.is_empty() {
+            for entry in self.history.entries() {
+                if recent.len() == RECENT_SECTION_SIZE {
+                    break;
+                }
+                let position = results
+                    .iter()
+                    .position(|(index, _, _)| self.props.commands[*index].label == entry.label);
+                if let Some(position) = position {
+                    recent.push(results.remove(position));
+                }
+            }
+        }
+
+        // Keep each group together, ordered by its best result
+        let mut groups: Vec<Option<&SharedString>> = Vec::new();
+        for (index, _, _) in &results {
+            let group = self.props.commands[*index].group.as_ref();
+            if !groups.contains(&group) {
+                groups.push(group);
+            }
+        }
+        let group_rank = |index: usize| {
+            let group = self.props.commands[index].group.as_ref();
+            groups.iter().position(|g| *g == group)
+        };
+        results.sort_by_key(|(index, _, _)| group_rank(*index));
+
+        self.recent_count = recent.len();
+        self.results = recent
+            .into_iter()
+            .chain(results)
+            .map(|(index, matched, _)| (index, matched))
+            .collect();
+        self.highlighted = 0;
+    }
+
+    /// Rows of the results list: commands with a header before each group
+    ///
+    /// Recently used commands come first under their own header. Ungrouped
+    /// commands get an "Other" header unless they come first.
+    fn palette_rows(&self) -> Vec<PaletteRow> {
+        let mut rows = Vec::new();
+        if self.recent_count > 0 {
+            rows.push(PaletteRow::Header { label: "Recently used".into(), separated: false });
+            rows.extend((0..self.recent_count).map(|position| PaletteRow::Command { position }));
+        }
+
+        let mut current: Option<Option<&SharedString>> = None;
+        for (position, (index, _)) in self.results.iter().enumerate().skip(self.recent_count) {
+            let group = self.props.commands[*index].group.as_ref();
+            if current != Some(group) {
+                let separated = !rows.is_empty();
+                match group {
+                    Some(label) => rows.push(PaletteRow::Header { label: label.clone(), separated }),
+                    None if separated => {
+                        rows.push(PaletteRow::Header { label: "Other".into(), separated });
+                    }
+                    None => {}
+                }
+                current = Some(group);
+            }
+            rows.push(PaletteRow::Command { position });
+        }
+        rows
+    }
+
+    /// Run the command at `position` in the results and close the palette
+    fn execute(&mut self, position: usize, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let Some(&(index, _)) = self.results.get(position) else {
+            return;
+        };
+        let command = self.props.commands[index].clone();
+
+        self.history.record(command.label.clone());
+        self.save_history(cx);
+        self.set_open(false, window, cx);
+        if let Some(action) = command.action {
+            action(window, cx);
+        }
+    }
+
+    /// Keep the highlighted result scrolled into view
+    fn scroll_to_highlighted(&self) {
+        let rows = self.palette_rows();
+        let row = rows.iter().position(|row| {
+            matches!(row, PaletteRow::Command { position } if *position == self.highlighted)
+        });
+        if let Some(row) = row {
+            // Show the group header along with the group's first command
+            let row = match row.checked_sub(1).map(|above| &rows[above]) {
+                Some(PaletteRow::Header { .. }) => row - 1,
+                _ => row,
+            };
+            self.list_state.scroll_to_item(row, px(COMMAND_ROW_HEIGHT));
+        }
+    }
+
+    /// Render one row of the results list
+    fn render_row(&self, row: &PaletteRow, theme: &Theme, cx: &mut Context<'_, Self>) -> AnyElement {
+        let position = match row {
+            PaletteRow::Header { label, separated } => {
+                return div()
+                    .size_full()
+                    .flex()
+                    .items_end()
+                    .px(theme.global.spacing_md)
+                    .pb(theme.global.spacing_xs)
+                    .when(*separated, |this| {
+                        this.border_t(px(1.0)).border_color(theme.alias.color_border)
+                    })
+                    .text_size(theme.global.font_size_xs)
+                    .font_weight(FontWeight::SEMIBOLD)
+                    .text_color(theme.alias.color_text_muted)
+                    .child(label.clone())
+                    .into_any_element();
+            }
+            PaletteRow::Command { position } => *position,
+        };
+
+        let (index, matched) = &self.results[position];
+        let cmd = &self.props.commands[*index];
+        let keybinding = cmd.keybinding.clone().or_else(|| {
+            cmd.shortcut.as_deref().and_then(|id| shortcut_keystrokes(id, cx))
+        });
+        div()
+            .id(("command", position))
+            .size_full()
+            .px(theme.global.spacing_md)
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap(theme.global.spacing_sm)
+            .cursor_pointer()
+            .when(position == self.highlighted, |this| {
+                this.bg(theme.alias.color_surface_hover)
+            })
+            .hover(|style| {
+                style.bg(theme.alias.color_surface_hover)
+            })
+            .on_click(cx.listener(move |this, _event: &ClickEvent, window, cx| {
+                this.execute(position, window, cx);
+            }))
+            .when_some(cmd.icon, |this, icon| {
+                this.child(
+                    Icon::new(icon)
+                        .size(IconSize::Sm)
+                        .custom_color(theme.alias.color_text_muted)
+                        .to_element(theme),
+                )
+            })
+            .child(
+                div()
+                    .flex_1()
+                    .min_w(px(0.0))
+                    .flex()
+                    .flex_col()
+                    .gap(px(2.0))
+                    .child(highlighted_label(&cmd.label, &matched.positions, theme))
+                    .when_some(cmd.description.clone(), |div, desc| {
+                        div.child(
+                            Label::new(desc)
+                                .variant(LabelVariant::Caption)
+                                .color(theme.alias.color_text_muted)
+                        )
+                    })
+            )
+            .when_some(keybinding, |this, binding| {
+                this.child(KeybindingHint::new(binding).to_element(theme))
+            })
+            .into_any_element()
+    }
+
+    /// Handle typing, navigation, execution and dismissal keys
+    fn handle_key(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if !self.props.open {
+            return;
+        }
+        if self.focus_trap.handle_key_event(event, window) {
+            cx.stop_propagation();
+            return;
+        }
+
+        let keystroke = &event.keystroke;
+        let is_text = keystroke.key.chars().count() == 1
+            && !keystroke.modifiers.control
+            && !keystroke.modifiers.alt
+            && !keystroke.modifiers.platform;
+        let len = self.results.len();
+
+        match keystroke.key.as_str() {
+            "down" if len > 0 => self.highlighted = (self.highlighted + 1) % len,
+            "up" if len > 0 => self.highlighted = (self.highlighted + len - 1) % len,
+            "home" => self.highlighted = 0,
+            "end" => self.highlighted = len.saturating_sub(1),
+            "enter" => {
+                self.execute(self.highlighted, window, cx);
+                cx.stop_propagation();
+                return;
+            }
+            "escape" => self.set_open(false, window, cx),
+            "backspace" => {
+                let mut query = self.props.query.to_string();
+                query.pop();
+                self.set_query(query, cx);
+            }
+            "space" => self.set_query(format!("{} ", self.props.query), cx),
+            key if is_text => {
+                let key = keystroke.key_char.as_deref().unwrap_or(key);
+                self.set_query(format!("{}{key}", self.props.query), cx);
+            }
+            _ => return,
+        }
+
+        self.scroll_to_highlighted();
+        cx.notify();
+        cx.stop_propagation();
+    }
 }
 
 impl Render for CommandPalette {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
-        let theme = Theme::default();
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
 
         if !self.props.open {
-            return div(); // Return empty div if not open
+            return div().into_any_element(); // Return empty div if not open
+        }
+
+        let focus_handle = self
+            .focus_handle
+            .get_or_insert_with(|| cx.focus_handle())
+            .clone();
+        if std::mem::take(&mut self.pending_focus) {
+            self.focus_trap.set_handles(vec![focus_handle.clone()]);
+            self.focus_trap.initialize(window, cx);
         }
 
+        let search_text = if self.props.query.is_empty() {
+            div()
+                .text_color(theme.alias.color_text_muted)
+                .child(self.props.placeholder.clone())
+        } else {
+            div()
+                .text_color(theme.alias.color_text_primary)
+                .child(self.props.query.clone())
+        };
+
+        let results: AnyElement = if self.results.is_empty() {
+            div()
+                .p(theme.global.spacing_md)
+                .child(
+                    Label::new("No matching commands")
+                        .variant(LabelVariant::Body)
+                        .color(theme.alias.color_text_muted)
+                )
+                .into_any_element()
+        } else {
+            // Commands list, virtualized so only visible results render
+            let rows = self.palette_rows();
+            VirtualList::new(
+                "command-palette-results",
+                rows.len(),
+                px(COMMAND_ROW_HEIGHT),
+                &self.list_state,
+            )
+            .max_height(px(400.0))
+            .to_element(|row| self.render_row(&rows[row], &theme, cx))
+            .into_any_element()
+        };
+
         div()
+            .id("command-palette")
+            .track_focus(&focus_handle)
+            .on_key_down(cx.listener(Self::handle_key))
             .fixed()
             .top(px(0.0))
             .left(px(0.0))
@@ -98,55 +1056,209 @@ impl Render for CommandPalette {
             .justify_center()
             .pt(px(100.0))
             .bg(hsla(0.0, 0.0, 0.0, 0.5))
+            // Clicking the backdrop outside the panel dismisses the palette
+            .on_click(cx.listener(|this, _event: &ClickEvent, window, cx| {
+                this.set_open(false, window, cx);
+            }))
             .child(
                 // Command palette panel
                 div()
+                    .id("command-palette-panel")
                     .w(px(600.0))
                     .bg(theme.alias.color_surface)
                     .rounded(theme.global.radius_lg)
                     .shadow_xl()
                     .overflow_hidden()
+                    // Keep clicks inside the panel from reaching the backdrop
+                    .on_click(|_event, _window, cx| cx.stop_propagation())
                     .child(
-                        // Search input
+                        // Search field showing the typed query
                         div()
-                            .p(theme.global.spacing_sm)
+                            .p(theme.global.spacing_md)
+                            .flex()
+                            .flex_row()
+                            .items_center()
+                            .gap(theme.global.spacing_sm)
                             .border_color(theme.alias.color_border)
                             .border_b(px(1.0))
+                            .text_size(theme.global.font_size_base)
                             .child(
-                                Input::new()
-                                    .value(self.props.query.clone())
-                                    .placeholder("Search commands...")
-                            )
-                    )
-                    .child(
-                        // Commands list
-                        div()
-                            .max_h(px(400.0))
-                            .overflow_y_scroll()
-                            .children(
-                                self.props.commands.iter().map(|cmd| {
-                                    div()
-                                        .p(theme.global.spacing_sm)
-                                        .flex()
-                                        .flex_col()
-                                        .gap(px(2.0))
-                                        .hover(|style| {
-                                            style.bg(theme.alias.color_surface_hover)
-                                        })
-                                        .child(
-                                            Label::new(cmd.label.clone())
-                                                .variant(LabelVariant::Body)
-                                        )
-                                        .when_some(cmd.description.clone(), |div, desc| {
-                                            div.child(
-                                                Label::new(desc)
-                                                    .variant(LabelVariant::Caption)
-                                                    .color(theme.alias.color_text_muted)
-                                            )
-                                        })
-                                }).collect::<Vec<_>>()
+                                Icon::new(icons::SEARCH)
+                                    .size(IconSize::Sm)
+                                    .custom_color(theme.alias.color_text_muted)
+                                    .to_element(&theme),
                             )
+                            .child(search_text)
                     )
+                    .child(results)
             )
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fixed "current time" for frecency tests
+    const NOW: u64 = 1_700_000_000;
+
+    fn palette(labels: &[&'static str]) -> CommandPalette {
+        CommandPalette::new().commands(
+            labels
+                .iter()
+                .map(|&label| Command::new(label))
+                .collect(),
+        )
+    }
+
+    fn result_labels(palette: &CommandPalette) -> Vec<&str> {
+        palette
+            .results
+            .iter()
+            .map(|(index, _)| palette.props.commands[*index].label.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn test_empty_query_keeps_declared_order() {
+        let palette = palette(&["Save", "Open File", "Close"]);
+        assert_eq!(result_labels(&palette), vec!["Save", "Open File", "Close"]);
+    }
+
+    #[test]
+    fn test_query_filters_and_ranks() {
+        let palette = palette(&["Toggle Soft Wrap", "Save", "Open File"]).query("of");
+        assert_eq!(result_labels(&palette), vec!["Open File", "Toggle Soft Wrap"]);
+        assert_eq!(palette.results[0].1.positions, vec![0, 5]);
+    }
+
+    #[test]
+    fn test_recency_bonus() {
+        assert_eq!(recency_bonus(0), 20);
+        assert!(recency_bonus(1) < recency_bonus(0));
+        assert_eq!(recency_bonus(MAX_RECENT), 0);
+
+        // Recently used commands outrank equally good matches
+        let mut palette = palette(&["Open File", "Open Folder"]).query("open");
+        palette.history.record_at("Open Folder".into(), NOW);
+        palette.refresh_results_at(NOW);
+        assert_eq!(result_labels(&palette), vec!["Open Folder", "Open File"]);
+    }
+
+    #[test]
+    fn test_history_record_moves_to_front() {
+        let mut history = CommandHistory::new();
+        for i in 0..MAX_HISTORY + 2 {
+            history.record_at(format!("Command {i}").into(), NOW);
+        }
+        history.record_at("Command 5".into(), NOW + 1);
+
+        let entries = history.entries();
+        assert_eq!(entries.len(), MAX_HISTORY);
+        assert_eq!(entries[0], CommandUse { label: "Command 5".into(), count: 2, last_used: NOW + 1 });
+        assert_eq!(entries.iter().filter(|e| e.label.as_ref() == "Command 5").count(), 1);
+    }
+
+    #[test]
+    fn test_frecency() {
+        const DAY: u64 = 24 * 60 * 60;
+        let mut history = CommandHistory::new();
+        history.record_at("Old".into(), NOW - 60 * DAY);
+        history.record_at("Old".into(), NOW - 60 * DAY);
+        history.record_at("Old".into(), NOW - 60 * DAY);
+        history.record_at("New".into(), NOW - DAY);
+
+        // Three uses two months ago weigh less than one use yesterday
+        assert_eq!(history.frecency_at("Old", NOW), 3 * 30);
+        assert_eq!(history.frecency_at("New", NOW), 100);
+        assert_eq!(history.frecency_at("Never", NOW), 0);
+    }
+
+    #[test]
+    fn test_empty_query_lists_recent_then_frecency() {
+        const DAY: u64 = 24 * 60 * 60;
+        let mut history = CommandHistory::new();
+        for _ in 0..3 {
+            history.record_at("Close".into(), NOW - 20 * DAY);
+        }
+        for i in 0..RECENT_SECTION_SIZE {
+            history.record_at(format!("Recent {i}").into(), NOW - 10 * DAY);
+        }
+
+        let mut labels = vec!["Save", "Close"];
+        let recent: Vec<String> = (0..RECENT_SECTION_SIZE).map(|i| format!("Recent {i}")).collect();
+        labels.extend(recent.iter().map(String::as_str));
+        let mut palette = CommandPalette::new()
+            .commands(labels.iter().map(|&label| Command::new(label.to_string())).collect());
+        palette.history = history;
+        palette.refresh_results_at(NOW);
+
+        // Most recent first, then "Close" by frecency ahead of unused "Save"
+        let mut expected: Vec<&str> = recent.iter().rev().map(String::as_str).collect();
+        expected.extend(["Close", "Save"]);
+        assert_eq!(result_labels(&palette), expected);
+        assert_eq!(palette.recent_count, RECENT_SECTION_SIZE);
+
+        let rows = palette.palette_rows();
+        assert_eq!(rows[0], PaletteRow::Header { label: "Recently used".into(), separated: false });
+        assert_eq!(
+            rows[RECENT_SECTION_SIZE + 1],
+            PaletteRow::Header { label: "Other".into(), separated: true }
+        );
+    }
+
+    #[test]
+    fn test_history_json_round_trip() {
+        let mut history = CommandHistory::new();
+        history.record_at("Open File".into(), NOW);
+        history.record_at("Save".into(), NOW + 5);
+        history.record_at("Save".into(), NOW + 10);
+
+        let root: Value = serde_json::from_str(&history.to_json()).unwrap();
+        assert_eq!(entries_from_json(&root), history.entries());
+
+        // Malformed entries are skipped rather than failing the whole load
+        let root = json!({ "commands": [{ "label": "Save" }, { "label": "Quit", "count": 1, "last_used": 3 }] });
+        assert_eq!(
+            entries_from_json(&root),
+            vec![CommandUse { label: "Quit".into(), count: 1, last_used: 3 }]
+        );
+    }
+
+    #[test]
+    fn test_groups_are_contiguous() {
+        let palette = CommandPalette::new()
+            .commands(vec![
+                Command::new("Open File").group("File"),
+                Command::new("Format Document").group("Edit"),
+                Command::new("Open Folder").group("File"),
+                Command::new("Open Settings"),
+            ])
+            .query("open");
+
+        assert_eq!(
+            result_labels(&palette),
+            vec!["Open File", "Open Folder", "Open Settings"]
+        );
+        assert_eq!(
+            palette.palette_rows(),
+            vec![
+                PaletteRow::Header { label: "File".into(), separated: false },
+                PaletteRow::Command { position: 0 },
+                PaletteRow::Command { position: 1 },
+                PaletteRow::Header { label: "Other".into(), separated: true },
+                PaletteRow::Command { position: 2 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ungrouped_first_has_no_header() {
+        let palette = palette(&["Save", "Close"]);
+        assert_eq!(
+            palette.palette_rows(),
+            vec![PaletteRow::Command { position: 0 }, PaletteRow::Command { position: 1 }]
+        );
     }
 }
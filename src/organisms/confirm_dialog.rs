@@ -0,0 +1,496 @@
+//! ConfirmDialog component for destructive-action confirmation.
+
+use std::rc::Rc;
+
+use gpui::*;
+use gpui::prelude::FluentBuilder;
+use crate::{
+    atoms::{Label, LabelVariant, Button, ButtonVariant},
+    theme::use_theme,
+    utils::FocusTrap,
+};
+
+/// How a [`ConfirmDialog`] was answered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfirmResult {
+    /// The user confirmed the action
+    Confirmed,
+    /// The user cancelled with the Cancel button, Escape or an overlay click
+    Cancelled,
+}
+
+/// ConfirmDialog configuration properties
+#[derive(Clone)]
+pub struct ConfirmDialogProps {
+    /// Dialog title, e.g. "Delete repository?"
+    pub title: SharedString,
+    /// Explanation of what the action does
+    pub message: Option<SharedString>,
+    /// Confirm button label
+    pub confirm_label: SharedString,
+    /// Cancel button label
+    pub cancel_label: SharedString,
+    /// Whether the action is destructive, styling the confirm button as danger
+    pub danger: bool,
+    /// Text the user must type before confirming, e.g. the name of the item
+    pub confirmation_text: Option<SharedString>,
+    /// Whether dialog is open
+    pub open: bool,
+    /// Called with the answer when the dialog closes
+    pub on_result: Option<Rc<dyn Fn(ConfirmResult, &mut Window, &mut App)>>,
+}
+
+impl Default for ConfirmDialogProps {
+    fn default() -> Self {
+        Self {
+            title: "Are you sure?".into(),
+            message: None,
+            confirm_label: "Delete".into(),
+            cancel_label: "Cancel".into(),
+            danger: true,
+            confirmation_text: None,
+            open: false,
+            on_result: None,
+        }
+    }
+}
+
+/// Focus handles for the panel, the confirmation field and the buttons
+struct ConfirmFocus {
+    panel: FocusHandle,
+    input: FocusHandle,
+    cancel: FocusHandle,
+    confirm: FocusHandle,
+}
+
+/// A confirmation dialog for destructive actions.
+///
+/// ConfirmDialog standardizes the "are you sure?" pattern: a danger-styled
+/// confirm button next to a cancel button, and optionally a field where the
+/// user must type a name before the confirm button enables. The answer is
+/// reported once, as a [`ConfirmResult`], when the dialog closes.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::organisms::*;
+///
+/// ConfirmDialog::new()
+///     .title("Delete repository?")
+///     .message("This permanently deletes the repository and its history.")
+///     .confirm_label("Delete repository")
+///     .confirmation_text("purdah-ui")
+///     .on_result(|result, _window, cx| {
+///         if result == ConfirmResult::Confirmed {
+///             delete_repository(cx);
+///         }
+///     })
+///     .open(true);
+/// ```
+///
+/// ## Accessibility
+///
+/// - Opening focuses the confirmation field, or the cancel button when there
+///   is none, so Enter never confirms by accident
+/// - Tab and Shift+Tab cycle through the field and buttons, wrapping at the ends
+/// - Enter in the confirmation field confirms once the text matches
+/// - Escape cancels; focus returns to the element focused before opening
+pub struct ConfirmDialog {
+    props: ConfirmDialogProps,
+    /// Text typed into the confirmation field
+    typed: String,
+    focus: Option<ConfirmFocus>,
+    focus_trap: FocusTrap,
+    /// Activate the focus trap on the next render, once the handles exist
+    pending_focus: bool,
+}
+
+impl ConfirmDialog {
+    /// Create a new, closed confirmation dialog
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let confirm = cx.new(|_| ConfirmDialog::new().title("Delete file?"));
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            props: ConfirmDialogProps::default(),
+            typed: String::new(),
+            focus: None,
+            focus_trap: FocusTrap::new(),
+            pending_focus: false,
+        }
+    }
+
+    /// Set the dialog title
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ConfirmDialog::new().title("Delete 3 files?");
+    /// ```
+    pub fn title(mut self, title: impl Into<SharedString>) -> Self {
+        self.props.title = title.into();
+        self
+    }
+
+    /// Set the explanation shown below the title
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ConfirmDialog::new().message("Deleted files cannot be recovered.");
+    /// ```
+    pub fn message(mut self, message: impl Into<SharedString>) -> Self {
+        self.props.message = Some(message.into());
+        self
+    }
+
+    /// Set the confirm button label
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ConfirmDialog::new().confirm_label("Remove member");
+    /// ```
+    pub fn confirm_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.props.confirm_label = label.into();
+        self
+    }
+
+    /// Set the cancel button label
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ConfirmDialog::new().cancel_label("Keep");
+    /// ```
+    pub fn cancel_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.props.cancel_label = label.into();
+        self
+    }
+
+    /// Set whether the action is destructive
+    ///
+    /// Destructive actions (the default) use a danger confirm button;
+    /// otherwise it is a primary button.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ConfirmDialog::new().title("Publish post?").confirm_label("Publish").danger(false);
+    /// ```
+    pub fn danger(mut self, danger: bool) -> Self {
+        self.props.danger = danger;
+        self
+    }
+
+    /// Require typing `text` before the confirm button enables
+    ///
+    /// The comparison is exact, including case.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ConfirmDialog::new().confirmation_text(project.name.clone());
+    /// ```
+    pub fn confirmation_text(mut self, text: impl Into<SharedString>) -> Self {
+        self.props.confirmation_text = Some(text.into());
+        self
+    }
+
+    /// Set whether the dialog is open initially
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ConfirmDialog::new().open(true);
+    /// ```
+    pub fn open(mut self, open: bool) -> Self {
+        self.props.open = open;
+        self.pending_focus = open;
+        self
+    }
+
+    /// Set the handler called with the answer when the dialog closes
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ConfirmDialog::new().on_result(|result, _window, cx| {
+    ///     if result == ConfirmResult::Confirmed {
+    ///         delete_selection(cx);
+    ///     }
+    /// });
+    /// ```
+    pub fn on_result(
+        mut self,
+        handler: impl Fn(ConfirmResult, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_result = Some(Rc::new(handler));
+        self
+    }
+
+    /// Open the dialog with an empty confirmation field
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// confirm.update(cx, |confirm, cx| confirm.show(cx));
+    /// ```
+    pub fn show(&mut self, cx: &mut Context<'_, Self>) {
+        if self.props.open {
+            return;
+        }
+        self.props.open = true;
+        self.typed.clear();
+        self.pending_focus = true;
+        cx.notify();
+    }
+
+    /// Whether the typed text allows confirming
+    fn can_confirm(&self) -> bool {
+        self.props
+            .confirmation_text
+            .as_ref()
+            .is_none_or(|text| self.typed == text.as_ref())
+    }
+
+    /// Close the dialog and report the answer
+    fn finish(&mut self, result: ConfirmResult, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if !self.props.open || (result == ConfirmResult::Confirmed && !self.can_confirm()) {
+            return;
+        }
+        self.props.open = false;
+        self.pending_focus = false;
+        self.typed.clear();
+        self.focus_trap.cleanup(window);
+        if let Some(handler) = self.props.on_result.clone() {
+            handler(result, window, cx);
+        }
+        cx.notify();
+    }
+
+    /// Handle Escape and trap Tab inside the dialog
+    fn handle_key(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if event.keystroke.key == "escape" {
+            self.finish(ConfirmResult::Cancelled, window, cx);
+            cx.stop_propagation();
+        } else if self.focus_trap.handle_key_event(event, window) {
+            cx.stop_propagation();
+        }
+    }
+
+    /// Handle typing in the confirmation field
+    fn handle_input_key(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let keystroke = &event.keystroke;
+        let is_text = keystroke.key.chars().count() == 1
+            && !keystroke.modifiers.control
+            && !keystroke.modifiers.alt
+            && !keystroke.modifiers.platform;
+
+        match keystroke.key.as_str() {
+            "enter" => self.finish(ConfirmResult::Confirmed, window, cx),
+            "backspace" => {
+                self.typed.pop();
+            }
+            "space" => self.typed.push(' '),
+            key if is_text => {
+                self.typed.push_str(keystroke.key_char.as_deref().unwrap_or(key));
+            }
+            _ => return,
+        }
+        cx.notify();
+        cx.stop_propagation();
+    }
+}
+
+impl Render for ConfirmDialog {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+
+        if !self.props.open {
+            return div().into_any_element(); // Return empty div if not open
+        }
+
+        let focus = self.focus.get_or_insert_with(|| ConfirmFocus {
+            panel: cx.focus_handle(),
+            input: cx.focus_handle(),
+            cancel: cx.focus_handle(),
+            confirm: cx.focus_handle(),
+        });
+        let panel_focus = focus.panel.clone();
+        let input = focus.input.clone();
+        let (cancel, confirm) = (focus.cancel.clone(), focus.confirm.clone());
+        if std::mem::take(&mut self.pending_focus) {
+            let mut handles = Vec::new();
+            if self.props.confirmation_text.is_some() {
+                handles.push(input.clone());
+            }
+            handles.extend([cancel.clone(), confirm.clone()]);
+            self.focus_trap.set_handles(handles);
+            self.focus_trap.initialize(window, cx);
+        }
+
+        // Confirmation field showing the typed text
+        let confirmation = self.props.confirmation_text.clone().map(|text| {
+            let input_focused = input.is_focused(window);
+            let field_text = if self.typed.is_empty() {
+                div()
+                    .text_color(theme.alias.color_text_muted)
+                    .child(text.clone())
+            } else {
+                div()
+                    .text_color(theme.alias.color_text_primary)
+                    .child(SharedString::from(self.typed.clone()))
+            };
+
+            div()
+                .flex()
+                .flex_col()
+                .gap(theme.global.spacing_xs)
+                .child(
+                    div()
+                        .flex()
+                        .flex_row()
+                        .gap(px(4.0))
+                        .text_size(theme.global.font_size_sm)
+                        .text_color(theme.alias.color_text_secondary)
+                        .child("Type")
+                        .child(div().font_weight(FontWeight::BOLD).child(text))
+                        .child("to confirm"),
+                )
+                .child(
+                    div()
+                        .id("confirm-dialog-input")
+                        .track_focus(&input)
+                        .on_key_down(cx.listener(Self::handle_input_key))
+                        .px(theme.global.spacing_sm)
+                        .py(theme.global.spacing_xs)
+                        .border(px(1.0))
+                        .border_color(if input_focused {
+                            theme.alias.color_border_focus
+                        } else {
+                            theme.alias.color_border
+                        })
+                        .rounded(theme.global.radius_sm)
+                        .text_size(theme.global.font_size_base)
+                        .cursor_text()
+                        .on_click(move |_event, window, _cx| window.focus(&input))
+                        .child(field_text),
+                )
+        });
+
+        let panel = div()
+            .id("confirm-dialog")
+            .track_focus(&panel_focus)
+            .on_key_down(cx.listener(Self::handle_key))
+            // Keep clicks inside the panel from reaching the overlay
+            .on_click(|_event, _window, cx| cx.stop_propagation())
+            .w(px(440.0))
+            .max_w_full()
+            .bg(theme.alias.color_surface)
+            .rounded(theme.global.radius_lg)
+            .p(theme.global.spacing_lg)
+            .shadow_lg()
+            .flex()
+            .flex_col()
+            .gap(theme.global.spacing_md)
+            .child(
+                Label::new(self.props.title.clone())
+                    .variant(LabelVariant::Heading2)
+            )
+            .when_some(self.props.message.clone(), |div, message| {
+                div.child(
+                    Label::new(message)
+                        .variant(LabelVariant::Body)
+                        .color(theme.alias.color_text_secondary)
+                )
+            })
+            .when_some(confirmation, |div, confirmation| div.child(confirmation))
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .gap(theme.global.spacing_sm)
+                    .justify_end()
+                    .child(
+                        Button::new()
+                            .id("confirm-dialog-cancel")
+                            .label(self.props.cancel_label.clone())
+                            .variant(ButtonVariant::Outline)
+                            .track_focus(&cancel)
+                            .on_click(cx.listener(|this, _event: &ClickEvent, window, cx| {
+                                this.finish(ConfirmResult::Cancelled, window, cx);
+                            }))
+                            .to_element(&theme)
+                    )
+                    .child(
+                        Button::new()
+                            .id("confirm-dialog-confirm")
+                            .label(self.props.confirm_label.clone())
+                            .variant(if self.props.danger {
+                                ButtonVariant::Danger
+                            } else {
+                                ButtonVariant::Primary
+                            })
+                            .disabled(!self.can_confirm())
+                            .track_focus(&confirm)
+                            .on_click(cx.listener(|this, _event: &ClickEvent, window, cx| {
+                                this.finish(ConfirmResult::Confirmed, window, cx);
+                            }))
+                            .to_element(&theme)
+                    )
+            );
+
+        div()
+            .id("confirm-dialog-overlay")
+            .fixed()
+            .top(px(0.0))
+            .left(px(0.0))
+            .w_full()
+            .h_full()
+            .flex()
+            .items_center()
+            .justify_center()
+            .bg(hsla(0.0, 0.0, 0.0, 0.5)) // Semi-transparent overlay
+            .occlude()
+            .on_click(cx.listener(|this, _event: &ClickEvent, window, cx| {
+                this.finish(ConfirmResult::Cancelled, window, cx);
+            }))
+            .child(panel)
+            .into_any_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_can_confirm_without_confirmation_text() {
+        let dialog = ConfirmDialog::new();
+        assert!(dialog.can_confirm());
+    }
+
+    #[test]
+    fn test_confirmation_text_must_match_exactly() {
+        let mut dialog = ConfirmDialog::new().confirmation_text("purdah-ui");
+        assert!(!dialog.can_confirm());
+
+        dialog.typed = "purdah".into();
+        assert!(!dialog.can_confirm());
+        dialog.typed = "Purdah-UI".into();
+        assert!(!dialog.can_confirm());
+        dialog.typed = "purdah-ui".into();
+        assert!(dialog.can_confirm());
+    }
+
+    #[test]
+    fn test_defaults_to_danger() {
+        let dialog = ConfirmDialog::new();
+        assert!(dialog.props.danger);
+        assert_eq!(dialog.props.confirm_label.as_ref(), "Delete");
+    }
+}
@@ -1,12 +1,47 @@
 //! Dialog modal component.
 
+use std::rc::Rc;
+
 use gpui::*;
 use gpui::prelude::FluentBuilder;
 use crate::{
     atoms::{Label, LabelVariant, Button, ButtonVariant},
-    theme::Theme,
+    theme::use_theme,
+    utils::{
+        motion::{animation, transition, Easing, SlideFrom, Transition},
+        FocusTrap,
+    },
 };
 
+/// Dialog width presets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DialogSize {
+    /// Narrow dialog for short messages (400px)
+    Sm,
+    /// Standard dialog (560px)
+    #[default]
+    Md,
+    /// Wide dialog for forms and tables (800px)
+    Lg,
+    /// Fills the window, leaving a small margin
+    Full,
+}
+
+impl DialogSize {
+    /// Panel width, or `None` when the panel fills the window
+    fn width(self) -> Option<Pixels> {
+        match self {
+            Self::Sm => Some(px(400.0)),
+            Self::Md => Some(px(560.0)),
+            Self::Lg => Some(px(800.0)),
+            Self::Full => None,
+        }
+    }
+}
+
+/// Renders custom dialog content (header, body or footer)
+pub type DialogSlot = Rc<dyn Fn(&mut Window, &mut App) -> AnyElement>;
+
 /// Dialog configuration properties
 #[derive(Clone)]
 pub struct DialogProps {
@@ -16,6 +51,32 @@ pub struct DialogProps {
     pub description: Option<SharedString>,
     /// Whether dialog is open
     pub open: bool,
+    /// Panel width preset
+    pub size: DialogSize,
+    /// Custom header replacing the title and description
+    pub header: Option<DialogSlot>,
+    /// Scrollable body content shown below the header
+    pub body: Option<DialogSlot>,
+    /// Custom footer replacing the Cancel and Confirm buttons
+    pub footer: Option<DialogSlot>,
+    /// Label of the default footer's confirm button
+    pub confirm_label: SharedString,
+    /// Label of the default footer's cancel button
+    pub cancel_label: SharedString,
+    /// Called when the default footer's confirm button is clicked, before closing
+    pub on_confirm: Option<Rc<dyn Fn(&mut Window, &mut App)>>,
+    /// Called when the default footer's cancel button is clicked, before closing
+    pub on_cancel: Option<Rc<dyn Fn(&mut Window, &mut App)>>,
+    /// Focusable elements in custom content, in Tab order, for the focus trap
+    pub focus_handles: Vec<FocusHandle>,
+    /// Element focused when the dialog opens, instead of the first registered one
+    pub initial_focus: Option<FocusHandle>,
+    /// Whether pressing Escape closes the dialog
+    pub close_on_escape: bool,
+    /// Whether clicking the overlay outside the panel closes the dialog
+    pub close_on_overlay_click: bool,
+    /// Called when the dialog closes
+    pub on_close: Option<Rc<dyn Fn(&mut Window, &mut App)>>,
 }
 
 impl Default for DialogProps {
@@ -24,13 +85,38 @@ impl Default for DialogProps {
             title: "".into(),
             description: None,
             open: false,
+            size: DialogSize::default(),
+            header: None,
+            body: None,
+            footer: None,
+            confirm_label: "Confirm".into(),
+            cancel_label: "Cancel".into(),
+            on_confirm: None,
+            on_cancel: None,
+            focus_handles: Vec::new(),
+            initial_focus: None,
+            close_on_escape: true,
+            close_on_overlay_click: true,
+            on_close: None,
         }
     }
 }
 
+/// Focus handles for the dialog panel and its buttons
+struct DialogFocus {
+    panel: FocusHandle,
+    cancel: FocusHandle,
+    confirm: FocusHandle,
+}
+
 /// A modal dialog component.
 ///
-/// Dialog creates a modal overlay with title, content, and action buttons.
+/// Dialog creates a modal overlay with a header, a scrollable body and a
+/// footer of action buttons. The header defaults to the title and
+/// description and the footer to Cancel and Confirm buttons; each section
+/// can be replaced with custom content. While open, keyboard focus is
+/// trapped inside the dialog and returns to the previously focused element
+/// when it closes.
 ///
 /// ## Example
 ///
@@ -43,13 +129,39 @@ impl Default for DialogProps {
 ///     .description("Are you sure?")
 ///     .open(true);
 ///
-/// // Dialog with custom content
+/// // Dialog that must be dismissed with its buttons
 /// Dialog::new()
 ///     .title("Settings")
+///     .close_on_overlay_click(false)
+///     .on_close(|_window, _cx| println!("closed"))
+///     .open(true);
+///
+/// // Large dialog with custom body and confirm/cancel actions
+/// Dialog::new()
+///     .title("Edit profile")
+///     .size(DialogSize::Lg)
+///     .body(|_window, cx| profile_form(cx).into_any_element())
+///     .confirm_cancel(
+///         |_window, cx| save_profile(cx),
+///         |_window, _cx| {},
+///     )
 ///     .open(true);
 /// ```
+///
+/// ## Accessibility
+///
+/// - Opening the dialog moves focus to its first focusable element, or to
+///   the `initial_focus` element
+/// - Tab and Shift+Tab cycle through the registered focus handles and the
+///   default footer's buttons, wrapping at the ends
+/// - Escape closes the dialog unless `close_on_escape` is off
+/// - Closing restores focus to the element focused before the dialog opened
 pub struct Dialog {
     props: DialogProps,
+    focus: Option<DialogFocus>,
+    focus_trap: FocusTrap,
+    /// Activate the focus trap on the next render, once the handles exist
+    pending_focus: bool,
 }
 
 impl Dialog {
@@ -63,6 +175,9 @@ impl Dialog {
     pub fn new() -> Self {
         Self {
             props: DialogProps::default(),
+            focus: None,
+            focus_trap: FocusTrap::new(),
+            pending_focus: false,
         }
     }
 
@@ -99,20 +214,368 @@ impl Dialog {
     /// ```
     pub fn open(mut self, open: bool) -> Self {
         self.props.open = open;
+        self.pending_focus = open;
+        self
+    }
+
+    /// Set the panel width preset
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Dialog::new().size(DialogSize::Lg);
+    /// ```
+    pub fn size(mut self, size: DialogSize) -> Self {
+        self.props.size = size;
+        self
+    }
+
+    /// Replace the title and description with custom header content
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Dialog::new().header(|_window, _cx| {
+    ///     div().flex().gap_2().child(Icon::new(icons::INFO)).child("About").into_any_element()
+    /// });
+    /// ```
+    pub fn header(mut self, header: impl Fn(&mut Window, &mut App) -> AnyElement + 'static) -> Self {
+        self.props.header = Some(Rc::new(header));
+        self
+    }
+
+    /// Set the body content
+    ///
+    /// The body scrolls when the dialog is taller than the window allows,
+    /// keeping the header and footer visible.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Dialog::new().title("Terms").body(|_window, _cx| {
+    ///     div().flex().flex_col().children(paragraphs()).into_any_element()
+    /// });
+    /// ```
+    pub fn body(mut self, body: impl Fn(&mut Window, &mut App) -> AnyElement + 'static) -> Self {
+        self.props.body = Some(Rc::new(body));
+        self
+    }
+
+    /// Replace the Cancel and Confirm buttons with custom footer content
+    ///
+    /// Register any focusable footer elements with [`Dialog::focus_handles`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Dialog::new().footer(|_window, _cx| {
+    ///     div().flex().justify_end().child(close_button()).into_any_element()
+    /// });
+    /// ```
+    pub fn footer(mut self, footer: impl Fn(&mut Window, &mut App) -> AnyElement + 'static) -> Self {
+        self.props.footer = Some(Rc::new(footer));
         self
     }
+
+    /// Use Cancel and Confirm buttons in the footer, calling the handlers
+    ///
+    /// Each handler runs when its button is clicked, after which the dialog
+    /// closes. Replaces a custom footer set with [`Dialog::footer`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Dialog::new()
+    ///     .title("Discard changes?")
+    ///     .confirm_cancel(|_window, cx| discard(cx), |_window, _cx| {});
+    /// ```
+    pub fn confirm_cancel(
+        mut self,
+        on_confirm: impl Fn(&mut Window, &mut App) + 'static,
+        on_cancel: impl Fn(&mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.footer = None;
+        self.props.on_confirm = Some(Rc::new(on_confirm));
+        self.props.on_cancel = Some(Rc::new(on_cancel));
+        self
+    }
+
+    /// Set the labels of the default footer's buttons
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Dialog::new().button_labels("Delete", "Keep");
+    /// ```
+    pub fn button_labels(
+        mut self,
+        confirm: impl Into<SharedString>,
+        cancel: impl Into<SharedString>,
+    ) -> Self {
+        self.props.confirm_label = confirm.into();
+        self.props.cancel_label = cancel.into();
+        self
+    }
+
+    /// Register focusable elements in custom content, in Tab order
+    ///
+    /// The focus trap cycles through these before the default footer's
+    /// buttons, and focuses the first when the dialog opens.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Dialog::new()
+    ///     .body(move |_window, _cx| name_field(&name_handle).into_any_element())
+    ///     .focus_handles(vec![self.name_handle.clone()]);
+    /// ```
+    pub fn focus_handles(mut self, handles: Vec<FocusHandle>) -> Self {
+        self.props.focus_handles = handles;
+        self
+    }
+
+    /// Set the element focused when the dialog opens
+    ///
+    /// By default the first registered element is focused.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Dialog::new()
+    ///     .focus_handles(vec![self.name_handle.clone(), self.notes_handle.clone()])
+    ///     .initial_focus(&self.notes_handle);
+    /// ```
+    pub fn initial_focus(mut self, handle: &FocusHandle) -> Self {
+        self.props.initial_focus = Some(handle.clone());
+        self
+    }
+
+    /// Set whether pressing Escape closes the dialog
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Dialog::new().close_on_escape(false);
+    /// ```
+    pub fn close_on_escape(mut self, close: bool) -> Self {
+        self.props.close_on_escape = close;
+        self
+    }
+
+    /// Set whether clicking the overlay outside the panel closes the dialog
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Dialog::new().close_on_overlay_click(false);
+    /// ```
+    pub fn close_on_overlay_click(mut self, close: bool) -> Self {
+        self.props.close_on_overlay_click = close;
+        self
+    }
+
+    /// Set the handler called when the dialog closes
+    ///
+    /// Called however the dialog is closed: Escape, an overlay click, a
+    /// default footer button or [`Dialog::set_open`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Dialog::new().on_close(|_window, cx| cx.notify(parent_id));
+    /// ```
+    pub fn on_close(mut self, handler: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.props.on_close = Some(Rc::new(handler));
+        self
+    }
+
+    /// Open or close the dialog
+    ///
+    /// Opening traps focus inside the dialog. Closing restores focus to the
+    /// element focused before it opened and calls the `on_close` handler.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// dialog.update(cx, |dialog, cx| dialog.set_open(true, window, cx));
+    /// ```
+    pub fn set_open(&mut self, open: bool, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.props.open == open {
+            return;
+        }
+        self.props.open = open;
+
+        if open {
+            self.pending_focus = true;
+        } else {
+            self.pending_focus = false;
+            self.focus_trap.cleanup(window);
+            if let Some(handler) = self.props.on_close.clone() {
+                handler(window, cx);
+            }
+        }
+        cx.notify();
+    }
+
+    /// Close the dialog
+    fn close(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+        self.set_open(false, window, cx);
+    }
+
+    /// Run the default footer's confirm (or cancel) handler, then close
+    fn respond(&mut self, confirmed: bool, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let handler = if confirmed {
+            self.props.on_confirm.clone()
+        } else {
+            self.props.on_cancel.clone()
+        };
+        if let Some(handler) = handler {
+            handler(window, cx);
+        }
+        self.close(window, cx);
+    }
+
+    /// Handle Escape and trap Tab inside the dialog
+    fn handle_key(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if event.keystroke.key == "escape" && self.props.close_on_escape {
+            self.close(window, cx);
+            cx.stop_propagation();
+        } else if self.focus_trap.handle_key_event(event, window) {
+            cx.stop_propagation();
+        }
+    }
 }
 
 impl Render for Dialog {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
-        let theme = Theme::default();
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
 
         if !self.props.open {
-            return div(); // Return empty div if not open
+            return div().into_any_element(); // Return empty div if not open
+        }
+
+        let focus = self.focus.get_or_insert_with(|| DialogFocus {
+            panel: cx.focus_handle(),
+            cancel: cx.focus_handle(),
+            confirm: cx.focus_handle(),
+        });
+        let panel_focus = focus.panel.clone();
+        let (cancel, confirm) = (focus.cancel.clone(), focus.confirm.clone());
+        if std::mem::take(&mut self.pending_focus) {
+            let mut handles = self.props.focus_handles.clone();
+            if self.props.footer.is_none() {
+                handles.extend([cancel.clone(), confirm.clone()]);
+            }
+            // Keep focus (and Escape handling) inside the panel when a custom
+            // footer provides nothing focusable
+            if handles.is_empty() {
+                handles.push(panel_focus.clone());
+            }
+            self.focus_trap.set_handles(handles);
+            self.focus_trap.set_initial_focus(self.props.initial_focus.clone());
+            self.focus_trap.initialize(window, cx);
         }
 
+        // Header: custom content, or the title and description
+        let header = match self.props.header.clone() {
+            Some(header) => header(window, cx),
+            None => div()
+                .flex()
+                .flex_col()
+                .gap(theme.global.spacing_sm)
+                .child(
+                    Label::new(self.props.title.clone())
+                        .variant(LabelVariant::Heading2)
+                )
+                .when_some(self.props.description.clone(), |div, desc| {
+                    div.child(
+                        Label::new(desc)
+                            .variant(LabelVariant::Body)
+                            .color(theme.alias.color_text_secondary)
+                    )
+                })
+                .into_any_element(),
+        };
+
+        let body = self.props.body.clone().map(|body| body(window, cx));
+
+        // Footer: custom content, or Cancel and Confirm buttons
+        let footer = match self.props.footer.clone() {
+            Some(footer) => footer(window, cx),
+            None => div()
+                .flex()
+                .flex_row()
+                .gap(theme.global.spacing_sm)
+                .justify_end()
+                .child(
+                    Button::new()
+                        .id("dialog-cancel")
+                        .label(self.props.cancel_label.clone())
+                        .variant(ButtonVariant::Outline)
+                        .track_focus(&cancel)
+                        .on_click(cx.listener(|this, _event: &ClickEvent, window, cx| {
+                            this.respond(false, window, cx);
+                        }))
+                        .to_element(&theme)
+                )
+                .child(
+                    Button::new()
+                        .id("dialog-confirm")
+                        .label(self.props.confirm_label.clone())
+                        .variant(ButtonVariant::Primary)
+                        .track_focus(&confirm)
+                        .on_click(cx.listener(|this, _event: &ClickEvent, window, cx| {
+                            this.respond(true, window, cx);
+                        }))
+                        .to_element(&theme)
+                )
+                .into_any_element(),
+        };
+
+        // Dialog panel
+        let panel = div()
+            .id("dialog")
+            .track_focus(&panel_focus)
+            .on_key_down(cx.listener(Self::handle_key))
+            // Keep clicks inside the panel from reaching the overlay
+            .on_click(|_event, _window, cx| cx.stop_propagation())
+            .bg(theme.alias.color_surface)
+            .rounded(theme.global.radius_lg)
+            .p(theme.global.spacing_lg)
+            .shadow_lg()
+            .flex()
+            .flex_col()
+            .gap(theme.global.spacing_md)
+            .map(|this| match self.props.size.width() {
+                Some(width) => this.w(width).max_w_full().max_h(relative(0.85)),
+                None => this.size_full(),
+            })
+            .child(div().flex_none().child(header))
+            .when_some(body, |this, body| {
+                this.child(
+                    div()
+                        .id("dialog-body")
+                        .flex_1()
+                        .min_h(px(0.0))
+                        .overflow_y_scroll()
+                        .child(body)
+                )
+            })
+            .child(div().flex_none().child(footer));
+        // The panel drops in slightly as the overlay fades in
+        let enter = animation(theme.global.duration_normal, Easing::Decelerate);
+        let panel = transition(
+            panel,
+            "dialog-panel",
+            Transition::Slide { from: SlideFrom::Top, distance: px(8.0) },
+            true,
+            enter.clone(),
+            cx,
+        );
+
         // Build dialog overlay and content
-        div()
+        let overlay = div()
+            .id("dialog-overlay")
             .fixed()
             .top(px(0.0))
             .left(px(0.0))
@@ -121,49 +584,15 @@ impl Render for Dialog {
             .flex()
             .items_center()
             .justify_center()
+            .when(self.props.size == DialogSize::Full, |this| this.p(theme.global.spacing_lg))
             .bg(hsla(0.0, 0.0, 0.0, 0.5)) // Semi-transparent overlay
-            .child(
-                // Dialog panel
-                div()
-                    .bg(theme.alias.color_surface)
-                    .rounded(theme.global.radius_lg)
-                    .p(theme.global.spacing_lg)
-                    .min_w(px(400.0))
-                    .max_w(px(600.0))
-                    .shadow_lg()
-                    .flex()
-                    .flex_col()
-                    .gap(theme.global.spacing_md)
-                    .child(
-                        // Title
-                        Label::new(self.props.title.clone())
-                            .variant(LabelVariant::Heading2)
-                    )
-                    .when_some(self.props.description.clone(), |div, desc| {
-                        div.child(
-                            Label::new(desc)
-                                .variant(LabelVariant::Body)
-                                .color(theme.alias.color_text_secondary)
-                        )
-                    })
-                    .child(
-                        // Action buttons
-                        div()
-                            .flex()
-                            .flex_row()
-                            .gap(theme.global.spacing_sm)
-                            .justify_end()
-                            .child(
-                                Button::new()
-                                    .label("Cancel")
-                                    .variant(ButtonVariant::Outline)
-                            )
-                            .child(
-                                Button::new()
-                                    .label("Confirm")
-                                    .variant(ButtonVariant::Primary)
-                            )
-                    )
-            )
+            .occlude()
+            .when(self.props.close_on_overlay_click, |this| {
+                this.on_click(cx.listener(|this, _event: &ClickEvent, window, cx| {
+                    this.close(window, cx);
+                }))
+            })
+            .child(panel);
+        transition(overlay, "dialog-overlay", Transition::Fade, true, enter, cx).into_any_element()
     }
 }
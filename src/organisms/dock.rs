@@ -0,0 +1,1425 @@
+//! Docking layout: tabbed panel groups that panels drag between, split and float.
+
+use std::{collections::HashMap, rc::Rc};
+
+use gpui::*;
+use serde_json::{json, Value};
+use crate::{
+    atoms::{icons, Button, ButtonSize, ButtonVariant},
+    layout::{
+        split_pane::{resolve_split, SplitLimits},
+        SplitOrientation, SplitSide,
+    },
+    organisms::{FloatingPanel, FloatingPanels},
+    theme::{use_theme, Theme},
+    utils::{RovingFocus, RovingOrientation},
+};
+
+/// Smallest size of either side of a split
+const MIN_GROUP_SIZE: f32 = 80.0;
+/// Share of a group, from each edge, that docks beside it instead of into it
+const EDGE_ZONE: f32 = 0.25;
+/// Share of a split given to a panel docked to the edge of a group
+const DOCKED_SHARE: f32 = 0.3;
+/// Height of a group's tab bar
+const TAB_BAR_HEIGHT: f32 = 32.0;
+
+/// Renders the content of a docked panel
+pub type DockSlot = Rc<dyn Fn(&mut Window, &mut App) -> AnyElement>;
+
+/// Where a dragged panel lands relative to the group it is dropped on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DockZone {
+    /// Split the group and place the panel on the left
+    Left,
+    /// Split the group and place the panel on the right
+    Right,
+    /// Split the group and place the panel above
+    Top,
+    /// Split the group and place the panel below
+    Bottom,
+    /// Add the panel as a tab of the group
+    Center,
+}
+
+impl DockZone {
+    /// Zone of a group with `bounds` under `position`
+    ///
+    /// The quarter nearest each edge docks beside the group, the middle into it.
+    fn at(bounds: Bounds<Pixels>, position: Point<Pixels>) -> Self {
+        if bounds.size.width <= px(0.0) || bounds.size.height <= px(0.0) {
+            return Self::Center;
+        }
+        let x = (position.x - bounds.left()) / bounds.size.width;
+        let y = (position.y - bounds.top()) / bounds.size.height;
+        [(Self::Left, x), (Self::Right, 1.0 - x), (Self::Top, y), (Self::Bottom, 1.0 - y)]
+            .into_iter()
+            .filter(|&(_, distance)| distance < EDGE_ZONE)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map_or(Self::Center, |(zone, _)| zone)
+    }
+}
+
+/// A node of a [`DockLayout`] tree
+#[derive(Debug, Clone, PartialEq)]
+pub enum DockNode {
+    /// Two nodes side by side or stacked
+    Split {
+        /// Direction the nodes are laid out in
+        orientation: SplitOrientation,
+        /// Share of the space given to the first node, 0.0-1.0
+        ratio: f32,
+        /// The left or top node
+        first: Box<DockNode>,
+        /// The right or bottom node
+        second: Box<DockNode>,
+    },
+    /// A group of panels shown as tabs
+    Tabs {
+        /// Panel ids in tab order
+        panels: Vec<SharedString>,
+        /// Index of the visible panel
+        active: usize,
+    },
+}
+
+impl DockNode {
+    /// Create a tab group showing its first panel
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let editors = DockNode::tabs(["main.rs", "lib.rs"]);
+    /// ```
+    pub fn tabs<I>(panels: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<SharedString>,
+    {
+        Self::Tabs {
+            panels: panels.into_iter().map(Into::into).collect(),
+            active: 0,
+        }
+    }
+
+    /// Create a split giving `ratio` of the space to `first`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let root = DockNode::split(
+    ///     SplitOrientation::Horizontal,
+    ///     0.25,
+    ///     DockNode::tabs(["files"]),
+    ///     DockNode::tabs(["editor"]),
+    /// );
+    /// ```
+    pub fn split(orientation: SplitOrientation, ratio: f32, first: DockNode, second: DockNode) -> Self {
+        Self::Split {
+            orientation,
+            ratio: ratio.clamp(0.0, 1.0),
+            first: Box::new(first),
+            second: Box::new(second),
+        }
+    }
+
+    /// Whether the panel with `id` is in this node
+    fn contains(&self, id: &str) -> bool {
+        match self {
+            Self::Split { first, second, .. } => first.contains(id) || second.contains(id),
+            Self::Tabs { panels, .. } => panels.iter().any(|panel| panel.as_ref() == id),
+        }
+    }
+
+    /// Panel ids of this node in layout order
+    fn collect_ids(&self, ids: &mut Vec<SharedString>) {
+        match self {
+            Self::Split { first, second, .. } => {
+                first.collect_ids(ids);
+                second.collect_ids(ids);
+            }
+            Self::Tabs { panels, .. } => ids.extend(panels.iter().cloned()),
+        }
+    }
+
+    /// Tab group holding the panel with `id`
+    fn group_mut(&mut self, id: &str) -> Option<&mut DockNode> {
+        match self {
+            Self::Split { first, second, .. } => {
+                if first.contains(id) {
+                    first.group_mut(id)
+                } else {
+                    second.group_mut(id)
+                }
+            }
+            Self::Tabs { .. } => self.contains(id).then_some(self),
+        }
+    }
+
+    /// Node reached by following `path` from this one
+    fn node_mut(&mut self, path: &[SplitSide]) -> Option<&mut DockNode> {
+        let Some((side, rest)) = path.split_first() else {
+            return Some(self);
+        };
+        match self {
+            Self::Split { first, .. } if *side == SplitSide::First => first.node_mut(rest),
+            Self::Split { second, .. } => second.node_mut(rest),
+            Self::Tabs { .. } => None,
+        }
+    }
+
+    /// This node without the panel with `id`, or `None` when nothing is left
+    ///
+    /// Emptied groups disappear and their sibling takes the parent split's place.
+    fn without(self, id: &str) -> Option<DockNode> {
+        match self {
+            Self::Split { orientation, ratio, first, second } => {
+                match (first.without(id), second.without(id)) {
+                    (Some(first), Some(second)) => Some(Self::split(orientation, ratio, first, second)),
+                    (Some(node), None) | (None, Some(node)) => Some(node),
+                    (None, None) => None,
+                }
+            }
+            Self::Tabs { mut panels, active } => {
+                let removed = panels.iter().position(|panel| panel.as_ref() == id);
+                panels.retain(|panel| panel.as_ref() != id);
+                if panels.is_empty() {
+                    return None;
+                }
+                let active = match removed {
+                    Some(index) if index < active => active - 1,
+                    _ => active,
+                };
+                Some(Self::Tabs {
+                    active: active.min(panels.len() - 1),
+                    panels,
+                })
+            }
+        }
+    }
+
+    /// Serialize the node as a JSON value
+    fn to_value(&self) -> Value {
+        match self {
+            Self::Split { orientation, ratio, first, second } => json!({
+                "split": match orientation {
+                    SplitOrientation::Horizontal => "horizontal",
+                    SplitOrientation::Vertical => "vertical",
+                },
+                "ratio": ratio,
+                "first": first.to_value(),
+                "second": second.to_value(),
+            }),
+            Self::Tabs { panels, active } => json!({
+                "tabs": panels.iter().map(AsRef::as_ref).collect::<Vec<&str>>(),
+                "active": active,
+            }),
+        }
+    }
+
+    /// Read a node from a saved layout, `None` if it is malformed
+    #[allow(clippy::cast_possible_truncation)]
+    fn from_value(value: &Value) -> Option<Self> {
+        if let Some(tabs) = value.get("tabs") {
+            let panels = tabs
+                .as_array()?
+                .iter()
+                .map(|panel| panel.as_str().map(|id| SharedString::from(id.to_string())))
+                .collect::<Option<Vec<_>>>()?;
+            if panels.is_empty() {
+                return None;
+            }
+            let active = value
+                .get("active")
+                .and_then(Value::as_u64)
+                .and_then(|active| usize::try_from(active).ok())
+                .unwrap_or(0)
+                .min(panels.len() - 1);
+            return Some(Self::Tabs { panels, active });
+        }
+
+        let orientation = match value.get("split")?.as_str()? {
+            "horizontal" => SplitOrientation::Horizontal,
+            "vertical" => SplitOrientation::Vertical,
+            _ => return None,
+        };
+        let ratio = value.get("ratio").and_then(Value::as_f64).unwrap_or(0.5) as f32;
+        Some(Self::split(
+            orientation,
+            ratio,
+            Self::from_value(value.get("first")?)?,
+            Self::from_value(value.get("second")?)?,
+        ))
+    }
+}
+
+/// A panel floating above the docked layout
+#[derive(Debug, Clone, PartialEq)]
+pub struct DockFloating {
+    /// Panel id
+    pub id: SharedString,
+    /// Position and size within the dock
+    pub bounds: Bounds<Pixels>,
+    /// Whether the panel is collapsed to its title bar
+    pub minimized: bool,
+}
+
+/// Arrangement of the panels of a [`Dock`]
+///
+/// The docked panels form a tree of splits whose leaves are tab groups;
+/// other panels float above it. Layouts convert to and from JSON so apps
+/// can save the arrangement and restore it on the next launch.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// let layout = std::fs::read_to_string(&layout_path)
+///     .ok()
+///     .and_then(|json| DockLayout::from_json(&json))
+///     .unwrap_or_else(|| {
+///         DockLayout::new(DockNode::split(
+///             SplitOrientation::Horizontal,
+///             0.2,
+///             DockNode::tabs(["files"]),
+///             DockNode::split(
+///                 SplitOrientation::Vertical,
+///                 0.7,
+///                 DockNode::tabs(["editor"]),
+///                 DockNode::tabs(["terminal", "problems"]),
+///             ),
+///         ))
+///     });
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DockLayout {
+    /// Docked panels, `None` when every panel floats or is closed
+    pub root: Option<DockNode>,
+    /// Floating panels from bottom to top
+    pub floating: Vec<DockFloating>,
+}
+
+impl DockLayout {
+    /// Create a layout with `root` docked and nothing floating
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let layout = DockLayout::new(DockNode::tabs(["editor"]));
+    /// ```
+    pub fn new(root: DockNode) -> Self {
+        Self {
+            root: Some(root),
+            floating: Vec::new(),
+        }
+    }
+
+    /// Whether the panel with `id` is docked or floating
+    pub fn contains(&self, id: &str) -> bool {
+        self.root.as_ref().is_some_and(|root| root.contains(id))
+            || self.floating.iter().any(|floating| floating.id.as_ref() == id)
+    }
+
+    /// Ids of the open panels, docked ones first
+    pub fn panel_ids(&self) -> Vec<SharedString> {
+        let mut ids = Vec::new();
+        if let Some(root) = &self.root {
+            root.collect_ids(&mut ids);
+        }
+        ids.extend(self.floating.iter().map(|floating| floating.id.clone()));
+        ids
+    }
+
+    /// Close the panel with `id`, returning whether it was open
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// layout.remove("terminal");
+    /// ```
+    pub fn remove(&mut self, id: &str) -> bool {
+        if !self.contains(id) {
+            return false;
+        }
+        self.root = self.root.take().and_then(|root| root.without(id));
+        self.floating.retain(|floating| floating.id.as_ref() != id);
+        true
+    }
+
+    /// Dock the panel with `id` in `zone` of the group holding `target`
+    ///
+    /// The panel moves from wherever it was. An empty layout takes the
+    /// panel as its only group. Returns whether the layout changed.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// // Open the terminal below the editor
+    /// layout.dock("terminal", "editor", DockZone::Bottom);
+    /// ```
+    pub fn dock(&mut self, id: &str, target: &str, zone: DockZone) -> bool {
+        let panel = SharedString::from(id.to_string());
+        let Some(root) = self.root.as_mut() else {
+            self.remove(id);
+            self.root = Some(DockNode::tabs([panel]));
+            return true;
+        };
+
+        // Another panel of the target group stays put while this one moves
+        let Some(DockNode::Tabs { panels, .. }) = root.group_mut(target) else {
+            return false;
+        };
+        if zone == DockZone::Center && panels.iter().any(|other| other.as_ref() == id) {
+            return self.activate(id);
+        }
+        let Some(anchor) = panels.iter().find(|other| other.as_ref() != id).cloned() else {
+            return false;
+        };
+
+        self.remove(id);
+        let Some(group) = self.root.as_mut().and_then(|root| root.group_mut(&anchor)) else {
+            return false;
+        };
+        if let DockNode::Tabs { panels, active } = group {
+            if zone == DockZone::Center {
+                panels.push(panel);
+                *active = panels.len() - 1;
+                return true;
+            }
+        }
+
+        let existing = std::mem::replace(group, DockNode::tabs(Vec::<SharedString>::new()));
+        let docked = DockNode::tabs([panel]);
+        *group = match zone {
+            DockZone::Left => DockNode::split(SplitOrientation::Horizontal, DOCKED_SHARE, docked, existing),
+            DockZone::Top => DockNode::split(SplitOrientation::Vertical, DOCKED_SHARE, docked, existing),
+            DockZone::Right => DockNode::split(SplitOrientation::Horizontal, 1.0 - DOCKED_SHARE, existing, docked),
+            DockZone::Bottom | DockZone::Center => {
+                DockNode::split(SplitOrientation::Vertical, 1.0 - DOCKED_SHARE, existing, docked)
+            }
+        };
+        true
+    }
+
+    /// Float the panel with `id` at `bounds` above the docked panels
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// layout.float("colors", Bounds::new(point(px(64.0), px(64.0)), size(px(240.0), px(320.0))));
+    /// ```
+    pub fn float(&mut self, id: &str, bounds: Bounds<Pixels>) {
+        self.remove(id);
+        self.floating.push(DockFloating {
+            id: SharedString::from(id.to_string()),
+            bounds,
+            minimized: false,
+        });
+    }
+
+    /// Show the panel with `id` in its tab group, returning whether the layout changed
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// layout.activate("problems");
+    /// ```
+    pub fn activate(&mut self, id: &str) -> bool {
+        let Some(DockNode::Tabs { panels, active }) = self.root.as_mut().and_then(|root| root.group_mut(id)) else {
+            return false;
+        };
+        let Some(index) = panels.iter().position(|panel| panel.as_ref() == id) else {
+            return false;
+        };
+        let changed = *active != index;
+        *active = index;
+        changed
+    }
+
+    /// Open the panel with `id` as a tab of the first group if it is closed
+    fn open(&mut self, id: &str) -> bool {
+        if self.contains(id) {
+            return false;
+        }
+        let mut docked = Vec::new();
+        if let Some(root) = &self.root {
+            root.collect_ids(&mut docked);
+        }
+        self.dock(id, docked.first().map_or("", AsRef::as_ref), DockZone::Center)
+    }
+
+    /// Set the ratio of the split at `path`, returning whether it changed
+    fn set_ratio(&mut self, path: &[SplitSide], ratio: f32) -> bool {
+        match self.root.as_mut().and_then(|root| root.node_mut(path)) {
+            Some(DockNode::Split { ratio: current, .. }) if (*current - ratio).abs() >= f32::EPSILON => {
+                *current = ratio;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Serialize the layout as a JSON document
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// std::fs::write(&layout_path, dock.read(cx).current_layout().to_json())?;
+    /// ```
+    pub fn to_json(&self) -> String {
+        let floating: Vec<Value> = self
+            .floating
+            .iter()
+            .map(|floating| {
+                json!({
+                    "id": floating.id.as_ref(),
+                    "x": f32::from(floating.bounds.origin.x),
+                    "y": f32::from(floating.bounds.origin.y),
+                    "width": f32::from(floating.bounds.size.width),
+                    "height": f32::from(floating.bounds.size.height),
+                    "minimized": floating.minimized,
+                })
+            })
+            .collect();
+        json!({
+            "root": self.root.as_ref().map_or(Value::Null, DockNode::to_value),
+            "floating": floating,
+        })
+        .to_string()
+    }
+
+    /// Read a layout saved with [`DockLayout::to_json`]
+    ///
+    /// Returns `None` for a document that is not a layout, so apps can
+    /// fall back to their default arrangement. Malformed floating panels
+    /// are skipped.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let layout = DockLayout::from_json(&saved).unwrap_or_else(default_layout);
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn from_json(json: &str) -> Option<Self> {
+        let document: Value = serde_json::from_str(json).ok()?;
+        let root = match document.get("root")? {
+            Value::Null => None,
+            node => Some(DockNode::from_value(node)?),
+        };
+        let number = |value: &Value, key: &str| value.get(key).and_then(Value::as_f64).map(|n| px(n as f32));
+        let floating = document
+            .get("floating")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|floating| {
+                Some(DockFloating {
+                    id: floating.get("id")?.as_str()?.to_string().into(),
+                    bounds: Bounds::new(
+                        point(number(floating, "x")?, number(floating, "y")?),
+                        size(number(floating, "width")?, number(floating, "height")?),
+                    ),
+                    minimized: floating.get("minimized").and_then(Value::as_bool).unwrap_or(false),
+                })
+            })
+            .collect();
+        Some(Self { root, floating })
+    }
+}
+
+/// A panel that can be docked in a [`Dock`]
+#[derive(Clone)]
+pub struct DockPanel {
+    /// Identifies the panel in the layout
+    pub id: SharedString,
+    /// Title shown on the panel's tab
+    pub title: SharedString,
+    /// Panel content
+    pub content: Option<DockSlot>,
+    /// Whether the panel's tab shows a close button
+    pub closable: bool,
+}
+
+impl DockPanel {
+    /// Create a panel with `id` and `title`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let files = DockPanel::new("files", "Files");
+    /// ```
+    pub fn new(id: impl Into<SharedString>, title: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            content: None,
+            closable: false,
+        }
+    }
+
+    /// Set the panel content
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// DockPanel::new("files", "Files").content(move |_window, _cx| file_tree.clone().into_any_element());
+    /// ```
+    pub fn content(mut self, content: impl Fn(&mut Window, &mut App) -> AnyElement + 'static) -> Self {
+        self.content = Some(Rc::new(content));
+        self
+    }
+
+    /// Set whether the panel's tab shows a close button
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// DockPanel::new("problems", "Problems").closable(true);
+    /// ```
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+}
+
+/// Drag payload for moving a panel of the dock with this entity id
+#[derive(Clone)]
+struct DockDrag {
+    dock: EntityId,
+    panel: SharedString,
+    title: SharedString,
+}
+
+impl Render for DockDrag {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        div()
+            .px(theme.global.spacing_sm)
+            .py(theme.global.spacing_xs)
+            .bg(theme.alias.color_surface_elevated)
+            .border_color(theme.alias.color_border_focus)
+            .border(px(1.0))
+            .rounded(theme.global.radius_sm)
+            .shadow_md()
+            .text_size(theme.global.font_size_sm)
+            .text_color(theme.alias.color_text_primary)
+            .child(self.title.clone())
+    }
+}
+
+/// Drag payload for moving the divider of the split at `path`
+#[derive(Clone)]
+struct DockSplitDrag {
+    dock: EntityId,
+    path: Vec<SplitSide>,
+}
+
+impl Render for DockSplitDrag {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
+        Empty
+    }
+}
+
+/// Element id suffix for the split at `path`
+fn path_key(path: &[SplitSide]) -> String {
+    path.iter()
+        .map(|side| if *side == SplitSide::First { '0' } else { '1' })
+        .collect()
+}
+
+/// Tab bar and content of a floating panel; dragging the tab docks it again
+fn floating_content(dock: EntityId, panel: &DockPanel, window: &mut Window, cx: &mut App) -> AnyElement {
+    let theme = use_theme(cx);
+    let tab = div()
+        .id(SharedString::from(format!("dock-floating-tab-{}", panel.id)))
+        .flex()
+        .items_center()
+        .h_full()
+        .px(theme.global.spacing_md)
+        .bg(theme.alias.color_surface)
+        .border_r(px(1.0))
+        .border_color(theme.alias.color_border)
+        .text_size(theme.global.font_size_sm)
+        .text_color(theme.alias.color_text_primary)
+        .cursor_grab()
+        .on_drag(
+            DockDrag { dock, panel: panel.id.clone(), title: panel.title.clone() },
+            |drag, _offset, _window, cx| cx.new(|_| drag.clone()),
+        )
+        .child(panel.title.clone());
+
+    div()
+        .flex()
+        .flex_col()
+        .size_full()
+        .child(
+            div()
+                .flex()
+                .flex_row()
+                .flex_none()
+                .h(px(TAB_BAR_HEIGHT))
+                .bg(theme.alias.color_surface_hover)
+                .border_b(px(1.0))
+                .border_color(theme.alias.color_border)
+                .child(tab),
+        )
+        .child(
+            div()
+                .relative()
+                .flex_1()
+                .min_h(px(0.0))
+                .overflow_hidden()
+                .children(panel.content.clone().map(|content| content(window, cx))),
+        )
+        .into_any_element()
+}
+
+/// Dock configuration properties
+#[derive(Clone, Default)]
+pub struct DockProps {
+    /// Panels that can be docked, in registration order
+    pub panels: Vec<DockPanel>,
+    /// Called with the new layout whenever panels move, resize or close
+    pub on_layout_change: Option<Rc<dyn Fn(&DockLayout, &mut Window, &mut App)>>,
+    /// Called with a panel's id after it is closed
+    pub on_close: Option<Rc<dyn Fn(SharedString, &mut Window, &mut App)>>,
+}
+
+/// A docking layout for IDE-like apps.
+///
+/// Dock arranges panels in tab groups separated by draggable dividers.
+/// Dragging a tab onto another group shows where it will land: the edges
+/// of the group split it and dock the panel beside it, the middle adds the
+/// panel as a tab. Double-clicking a tab floats the panel above the layout
+/// using [`FloatingPanels`]; dragging the floating panel's tab onto a group
+/// docks it again.
+///
+/// The arrangement is a [`DockLayout`] that apps save with
+/// `on_layout_change` and restore with `layout`.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::organisms::*;
+///
+/// let dock = cx.new(|_| {
+///     Dock::new()
+///         .panel(DockPanel::new("files", "Files").content(move |_window, _cx| files.clone().into_any_element()))
+///         .panel(DockPanel::new("editor", "Editor").content(move |_window, _cx| editor.clone().into_any_element()))
+///         .panel(DockPanel::new("terminal", "Terminal").closable(true))
+///         .layout(saved_layout)
+///         .on_layout_change(|layout, _window, _cx| save_layout(layout.to_json()))
+/// });
+/// ```
+///
+/// ## Accessibility
+///
+/// - Tabs are focusable; Left/Right and Home/End move between the tabs of a group
+/// - Tab close buttons are labelled with the panel title
+/// - Floating panels keep the keyboard support of [`FloatingPanels`]
+pub struct Dock {
+    props: DockProps,
+    layout: DockLayout,
+    /// A panel of the group under the dragged tab, and the zone it would land in
+    drop_target: Option<(SharedString, DockZone)>,
+    /// Host for floating panels, created on first render
+    floating: Option<Entity<FloatingPanels>>,
+    tab_focus: HashMap<SharedString, FocusHandle>,
+}
+
+impl Dock {
+    /// Create an empty dock
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let dock = cx.new(|_| Dock::new());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            props: DockProps::default(),
+            layout: DockLayout::default(),
+            drop_target: None,
+            floating: None,
+            tab_focus: HashMap::new(),
+        }
+    }
+
+    /// Register a panel, opening it as a tab of the first group
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Dock::new().panel(DockPanel::new("files", "Files"));
+    /// ```
+    pub fn panel(mut self, panel: DockPanel) -> Self {
+        self.layout.open(&panel.id);
+        self.props.panels.push(panel);
+        self
+    }
+
+    /// Set the arrangement, for example one saved by `on_layout_change`
+    ///
+    /// Call after registering panels; registered panels missing from the
+    /// layout stay closed.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Dock::new().panel(files).panel(editor).layout(saved_layout);
+    /// ```
+    pub fn layout(mut self, layout: DockLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Set the handler called with the new layout whenever it changes
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Dock::new().on_layout_change(|layout, _window, _cx| save_layout(layout.to_json()));
+    /// ```
+    pub fn on_layout_change(mut self, handler: impl Fn(&DockLayout, &mut Window, &mut App) + 'static) -> Self {
+        self.props.on_layout_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the handler called after a panel is closed
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Dock::new().on_close(|id, _window, cx| stop_panel_task(&id, cx));
+    /// ```
+    pub fn on_close(mut self, handler: impl Fn(SharedString, &mut Window, &mut App) + 'static) -> Self {
+        self.props.on_close = Some(Rc::new(handler));
+        self
+    }
+
+    /// Current arrangement
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let json = dock.read(cx).current_layout().to_json();
+    /// ```
+    pub fn current_layout(&self) -> &DockLayout {
+        &self.layout
+    }
+
+    /// Replace the arrangement from the parent view, dropping unknown panels
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// dock.update(cx, |dock, cx| dock.set_layout(default_layout(), cx));
+    /// ```
+    pub fn set_layout(&mut self, mut layout: DockLayout, cx: &mut Context<'_, Self>) {
+        for id in layout.panel_ids() {
+            if self.find_panel(&id).is_none() {
+                layout.remove(&id);
+            }
+        }
+        self.layout = layout;
+        self.drop_target = None;
+        cx.notify();
+    }
+
+    /// Dock the panel with `id` in `zone` of the group holding `target`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// dock.update(cx, |dock, cx| dock.dock_panel("terminal", "editor", DockZone::Bottom, window, cx));
+    /// ```
+    pub fn dock_panel(&mut self, id: &str, target: &str, zone: DockZone, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.find_panel(id).is_some() && self.layout.dock(id, target, zone) {
+            self.layout_changed(window, cx);
+        }
+    }
+
+    /// Float the panel with `id` at `bounds`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// dock.update(cx, |dock, cx| dock.float_panel("colors", bounds, window, cx));
+    /// ```
+    pub fn float_panel(&mut self, id: &str, bounds: Bounds<Pixels>, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.find_panel(id).is_some() {
+            self.layout.float(id, bounds);
+            self.layout_changed(window, cx);
+        }
+    }
+
+    /// Show the panel with `id`, opening it in the first group if it is closed
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// dock.update(cx, |dock, cx| dock.show_panel("problems", window, cx));
+    /// ```
+    pub fn show_panel(&mut self, id: &str, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.find_panel(id).is_none() {
+            return;
+        }
+        if self.layout.open(id) || self.layout.activate(id) {
+            self.layout_changed(window, cx);
+        }
+        if let Some(focus) = self.tab_focus.get(id) {
+            focus.focus(window);
+        }
+    }
+
+    /// Close the panel with `id`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// dock.update(cx, |dock, cx| dock.close_panel("terminal", window, cx));
+    /// ```
+    pub fn close_panel(&mut self, id: &str, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if !self.layout.remove(id) {
+            return;
+        }
+        self.layout_changed(window, cx);
+        if let Some(handler) = self.props.on_close.clone() {
+            handler(SharedString::from(id.to_string()), window, cx);
+        }
+    }
+
+    /// Registered panel with `id`
+    fn find_panel(&self, id: &str) -> Option<&DockPanel> {
+        self.props.panels.iter().find(|panel| panel.id.as_ref() == id)
+    }
+
+    /// Re-render and notify the layout handler
+    fn layout_changed(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+        cx.notify();
+        if let Some(handler) = self.props.on_layout_change.clone() {
+            handler(&self.layout, window, cx);
+        }
+    }
+
+    /// Default bounds for a panel floated from its tab, cascading from the top left
+    #[allow(clippy::cast_precision_loss)]
+    fn next_floating_bounds(&self) -> Bounds<Pixels> {
+        let offset = px(48.0 + 24.0 * (self.layout.floating.len() % 8) as f32);
+        Bounds::new(point(offset, offset), size(px(320.0), px(240.0)))
+    }
+
+    /// Record a floating panel's new bounds
+    fn floating_moved(&mut self, id: &str, bounds: Bounds<Pixels>, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if let Some(floating) = self.layout.floating.iter_mut().find(|floating| floating.id.as_ref() == id) {
+            floating.bounds = bounds;
+            self.layout_changed(window, cx);
+        }
+    }
+
+    /// Record a floating panel being minimized or restored
+    fn floating_minimized(&mut self, id: &str, minimized: bool, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if let Some(floating) = self.layout.floating.iter_mut().find(|floating| floating.id.as_ref() == id) {
+            floating.minimized = minimized;
+            self.layout_changed(window, cx);
+        }
+    }
+
+    /// Floating panel host, with its panels matching the layout
+    fn sync_floating(&mut self, cx: &mut Context<'_, Self>) -> Entity<FloatingPanels> {
+        let host = match &self.floating {
+            Some(host) => host.clone(),
+            None => {
+                let dock = cx.entity().downgrade();
+                let host = cx.new(|_| {
+                    FloatingPanels::new()
+                        .on_geometry_change({
+                            let dock = dock.clone();
+                            move |id, bounds, window, cx| {
+                                dock.update(cx, |dock, cx| dock.floating_moved(&id, bounds, window, cx)).ok();
+                            }
+                        })
+                        .on_minimize_change({
+                            let dock = dock.clone();
+                            move |id, minimized, window, cx| {
+                                dock.update(cx, |dock, cx| dock.floating_minimized(&id, minimized, window, cx))
+                                    .ok();
+                            }
+                        })
+                        .on_close(move |id, window, cx| {
+                            dock.update(cx, |dock, cx| dock.close_panel(&id, window, cx)).ok();
+                        })
+                });
+                self.floating = Some(host.clone());
+                host
+            }
+        };
+
+        let dock = cx.entity_id();
+        let wanted: Vec<(DockFloating, DockPanel)> = self
+            .layout
+            .floating
+            .iter()
+            .filter_map(|floating| Some((floating.clone(), self.find_panel(&floating.id)?.clone())))
+            .collect();
+        host.update(cx, |host, cx| {
+            for id in host.panel_order() {
+                if !wanted.iter().any(|(floating, _)| floating.id == id) {
+                    host.remove_panel(&id, cx);
+                }
+            }
+            for (floating, panel) in wanted {
+                if host.panel_bounds(&floating.id).is_some() {
+                    continue;
+                }
+                let content = panel.clone();
+                host.add_panel(
+                    FloatingPanel::new(panel.id.clone(), panel.title.clone())
+                        .bounds(floating.bounds)
+                        .minimized(floating.minimized)
+                        .closable(panel.closable)
+                        .content(move |window, cx| floating_content(dock, &content, window, cx)),
+                    cx,
+                );
+            }
+        });
+        host
+    }
+
+    /// Track the group and zone under a tab dragged over the group holding `anchor`
+    fn handle_tab_drag_move(&mut self, anchor: &SharedString, event: &DragMoveEvent<DockDrag>, cx: &mut Context<'_, Self>) {
+        let position = event.event.position;
+        let target = if event.bounds.contains(&position) && event.drag(cx).dock == cx.entity_id() {
+            Some((anchor.clone(), DockZone::at(event.bounds, position)))
+        } else if self.drop_target.as_ref().is_some_and(|(target, _)| target == anchor) {
+            None
+        } else {
+            return;
+        };
+
+        if self.drop_target != target {
+            self.drop_target = target;
+            cx.notify();
+        }
+    }
+
+    /// Dock the dropped panel at the current drop target
+    fn handle_drop(&mut self, drag: &DockDrag, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let Some((target, zone)) = self.drop_target.take() else {
+            return;
+        };
+        cx.notify();
+        if drag.dock == cx.entity_id() {
+            self.dock_panel(&drag.panel, &target, zone, window, cx);
+        }
+    }
+
+    /// Follow a divider drag of the split at `path`
+    fn handle_split_drag(
+        &mut self,
+        path: &[SplitSide],
+        orientation: SplitOrientation,
+        event: &DragMoveEvent<DockSplitDrag>,
+        window: &mut Window,
+        cx: &mut Context<'_, Self>,
+    ) {
+        let drag = event.drag(cx);
+        if drag.dock != cx.entity_id() || drag.path != path {
+            return;
+        }
+        let (offset, total) = match orientation {
+            SplitOrientation::Horizontal => (event.event.position.x - event.bounds.left(), event.bounds.size.width),
+            SplitOrientation::Vertical => (event.event.position.y - event.bounds.top(), event.bounds.size.height),
+        };
+        let limits = SplitLimits {
+            min_first: MIN_GROUP_SIZE,
+            min_second: MIN_GROUP_SIZE,
+            max_first: None,
+            collapsible: false,
+        };
+        let (ratio, _) = resolve_split(f32::from(offset), f32::from(total), limits);
+        if self.layout.set_ratio(path, ratio) {
+            self.layout_changed(window, cx);
+        }
+    }
+
+    /// Move between the tabs of a group with arrow keys, Home and End
+    fn handle_tab_key(&mut self, id: &str, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let Some(DockNode::Tabs { panels, .. }) = self.layout.root.as_mut().and_then(|root| root.group_mut(id)) else {
+            return;
+        };
+        let current = panels.iter().position(|panel| panel.as_ref() == id);
+        let Some(target) = RovingFocus::new(RovingOrientation::Horizontal).target(
+            event.keystroke.key.as_str(),
+            current,
+            panels.len(),
+            |_| true,
+        ) else {
+            return;
+        };
+        let target = panels[target].clone();
+        self.show_panel(&target, window, cx);
+        cx.stop_propagation();
+    }
+
+    /// Render `node`, found at `path` from the root
+    fn render_node(
+        &mut self,
+        node: &DockNode,
+        path: Vec<SplitSide>,
+        theme: &Theme,
+        window: &mut Window,
+        cx: &mut Context<'_, Self>,
+    ) -> AnyElement {
+        match node {
+            DockNode::Split { orientation, ratio, first, second } => {
+                let mut first_path = path.clone();
+                first_path.push(SplitSide::First);
+                let mut second_path = path.clone();
+                second_path.push(SplitSide::Second);
+                let first = self.render_node(first, first_path, theme, window, cx);
+                let second = self.render_node(second, second_path, theme, window, cx);
+                self.render_split(*orientation, *ratio, path, first, second, theme, cx)
+            }
+            DockNode::Tabs { panels, active } => self.render_group(panels, *active, theme, window, cx),
+        }
+    }
+
+    /// Render a split of `first` and `second` with a draggable divider
+    #[allow(clippy::too_many_arguments)]
+    fn render_split(
+        &self,
+        orientation: SplitOrientation,
+        ratio: f32,
+        path: Vec<SplitSide>,
+        first: AnyElement,
+        second: AnyElement,
+        theme: &Theme,
+        cx: &mut Context<'_, Self>,
+    ) -> AnyElement {
+        let horizontal = orientation == SplitOrientation::Horizontal;
+        let key = path_key(&path);
+        let hover_color = theme.alias.color_border_focus;
+        let group = SharedString::from(format!("dock-divider-{key}"));
+
+        let line = if horizontal { div().w(px(1.0)).h_full() } else { div().h(px(1.0)).w_full() };
+        let divider = div()
+            .id(group.clone())
+            .group(group.clone())
+            .flex()
+            .flex_none()
+            .items_center()
+            .justify_center()
+            .on_drag(
+                DockSplitDrag { dock: cx.entity_id(), path: path.clone() },
+                |drag, _offset, _window, cx| cx.new(|_| drag.clone()),
+            )
+            .child(
+                line.bg(theme.alias.color_border)
+                    .group_hover(group, move |style| style.bg(hover_color)),
+            );
+        let divider = if horizontal {
+            divider.w(px(5.0)).h_full().cursor_col_resize()
+        } else {
+            divider.h(px(5.0)).w_full().cursor_row_resize()
+        };
+
+        let first = div().relative().flex_none().overflow_hidden().child(first);
+        let first = if horizontal { first.h_full().w(relative(ratio)) } else { first.w_full().h(relative(ratio)) };
+        let second = div().relative().flex_1().size_full().overflow_hidden().child(second);
+
+        let container = div()
+            .id(SharedString::from(format!("dock-split-{key}")))
+            .flex()
+            .size_full()
+            .overflow_hidden()
+            .on_drag_move(cx.listener(move |this, event: &DragMoveEvent<DockSplitDrag>, window, cx| {
+                this.handle_split_drag(&path, orientation, event, window, cx);
+            }));
+        let container = if horizontal { container.flex_row() } else { container.flex_col() };
+        container.child(first).child(divider).child(second).into_any_element()
+    }
+
+    /// Render a tab group showing the panel at `active`
+    fn render_group(
+        &mut self,
+        panels: &[SharedString],
+        active: usize,
+        theme: &Theme,
+        window: &mut Window,
+        cx: &mut Context<'_, Self>,
+    ) -> AnyElement {
+        let dock = cx.entity_id();
+        // Groups are identified by their first panel while a tab is dragged
+        let anchor = panels[0].clone();
+
+        let mut tab_bar = div()
+            .flex()
+            .flex_row()
+            .flex_none()
+            .h(px(TAB_BAR_HEIGHT))
+            .overflow_hidden()
+            .bg(theme.alias.color_surface_hover)
+            .border_b(px(1.0))
+            .border_color(theme.alias.color_border);
+
+        for (index, id) in panels.iter().enumerate() {
+            let Some(panel) = self.find_panel(id).cloned() else {
+                continue;
+            };
+            let selected = index == active;
+            let focus_handle = self.tab_focus.entry(id.clone()).or_insert_with(|| cx.focus_handle()).clone();
+
+            let mut tab = div()
+                .id(SharedString::from(format!("dock-tab-{id}")))
+                .track_focus(&focus_handle)
+                .on_key_down(cx.listener({
+                    let id = id.clone();
+                    move |this, event: &KeyDownEvent, window, cx| this.handle_tab_key(&id, event, window, cx)
+                }))
+                .flex()
+                .flex_none()
+                .items_center()
+                .gap(theme.global.spacing_xs)
+                .h_full()
+                .px(theme.global.spacing_md)
+                .border_r(px(1.0))
+                .border_color(theme.alias.color_border)
+                .text_size(theme.global.font_size_sm)
+                .cursor_pointer()
+                .on_click(cx.listener({
+                    let id = id.clone();
+                    move |this, event: &ClickEvent, window, cx| {
+                        if event.click_count() >= 2 {
+                            let bounds = this.next_floating_bounds();
+                            this.float_panel(&id, bounds, window, cx);
+                        } else {
+                            this.show_panel(&id, window, cx);
+                        }
+                    }
+                }))
+                .on_drag(
+                    DockDrag { dock, panel: id.clone(), title: panel.title.clone() },
+                    |drag, _offset, _window, cx| cx.new(|_| drag.clone()),
+                )
+                .child(panel.title.clone());
+
+            tab = if selected {
+                tab.bg(theme.alias.color_surface)
+                    .text_color(theme.alias.color_text_primary)
+                    .border_t(px(2.0))
+                    .border_color(theme.alias.color_primary)
+            } else {
+                let hover_text = theme.alias.color_text_primary;
+                tab.text_color(theme.alias.color_text_secondary)
+                    .hover(move |style| style.text_color(hover_text))
+            };
+            if focus_handle.is_focused(window) {
+                tab = tab.outline_color(theme.alias.color_border_focus).outline_w(px(2.0));
+            }
+            if panel.closable {
+                tab = tab.child(
+                    Button::new()
+                        .id(SharedString::from(format!("dock-tab-close-{id}")))
+                        .label(format!("Close {}", panel.title))
+                        .icon_left(icons::X)
+                        .icon_only(true)
+                        .variant(ButtonVariant::Ghost)
+                        .size(ButtonSize::Sm)
+                        .on_click(cx.listener({
+                            let id = id.clone();
+                            move |this, _event: &ClickEvent, window, cx| {
+                                this.close_panel(&id, window, cx);
+                                cx.stop_propagation();
+                            }
+                        }))
+                        .to_element(theme),
+                );
+            }
+            tab_bar = tab_bar.child(tab);
+        }
+
+        let content = panels
+            .get(active)
+            .and_then(|id| self.find_panel(id))
+            .and_then(|panel| panel.content.clone())
+            .map(|content| content(window, cx));
+
+        let mut group = div()
+            .id(SharedString::from(format!("dock-group-{anchor}")))
+            .relative()
+            .flex()
+            .flex_col()
+            .size_full()
+            .bg(theme.alias.color_surface)
+            .on_drag_move(cx.listener({
+                let anchor = anchor.clone();
+                move |this, event: &DragMoveEvent<DockDrag>, _window, cx| {
+                    this.handle_tab_drag_move(&anchor, event, cx);
+                }
+            }))
+            .on_drop(cx.listener(Self::handle_drop))
+            .child(tab_bar)
+            .child(div().relative().flex_1().min_h(px(0.0)).overflow_hidden().children(content));
+
+        // Preview where the dragged panel will land
+        if let Some((_, zone)) = self.drop_target.as_ref().filter(|(target, _)| *target == anchor) {
+            let preview = div()
+                .absolute()
+                .bg(theme.alias.color_primary.opacity(0.2))
+                .border(px(2.0))
+                .border_color(theme.alias.color_primary);
+            let preview = match zone {
+                DockZone::Left => preview.top(px(0.0)).bottom(px(0.0)).left(px(0.0)).w(relative(DOCKED_SHARE)),
+                DockZone::Right => preview.top(px(0.0)).bottom(px(0.0)).right(px(0.0)).w(relative(DOCKED_SHARE)),
+                DockZone::Top => preview.left(px(0.0)).right(px(0.0)).top(px(0.0)).h(relative(DOCKED_SHARE)),
+                DockZone::Bottom => preview.left(px(0.0)).right(px(0.0)).bottom(px(0.0)).h(relative(DOCKED_SHARE)),
+                DockZone::Center => preview.top(px(0.0)).left(px(0.0)).size_full(),
+            };
+            group = group.child(preview);
+        }
+
+        group.into_any_element()
+    }
+}
+
+impl Default for Dock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Render for Dock {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+
+        // A drag that ended outside every group leaves no drop target
+        if self.drop_target.is_some() && !cx.has_active_drag() {
+            self.drop_target = None;
+        }
+
+        let floating = self.sync_floating(cx);
+        let body = match self.layout.root.clone() {
+            Some(root) => self.render_node(&root, Vec::new(), &theme, window, cx),
+            None => {
+                let highlight = theme.alias.color_primary.opacity(0.12);
+                div()
+                    .id("dock-empty")
+                    .size_full()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .text_size(theme.global.font_size_sm)
+                    .text_color(theme.alias.color_text_muted)
+                    .drag_over::<DockDrag>(move |style, _drag, _window, _cx| style.bg(highlight))
+                    .on_drop(cx.listener(|this, drag: &DockDrag, window, cx| {
+                        if drag.dock == cx.entity_id() {
+                            this.dock_panel(&drag.panel, "", DockZone::Center, window, cx);
+                        }
+                    }))
+                    .child("Drag a panel here to dock it")
+                    .into_any_element()
+            }
+        };
+
+        div()
+            .id("dock")
+            .relative()
+            .size_full()
+            .overflow_hidden()
+            .bg(theme.alias.color_surface)
+            .child(body)
+            .child(div().absolute().top(px(0.0)).left(px(0.0)).size_full().child(floating))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(layout: &DockLayout) -> Vec<String> {
+        layout.panel_ids().iter().map(ToString::to_string).collect()
+    }
+
+    fn ide() -> DockLayout {
+        DockLayout::new(DockNode::split(
+            SplitOrientation::Horizontal,
+            0.2,
+            DockNode::tabs(["files"]),
+            DockNode::tabs(["editor", "terminal"]),
+        ))
+    }
+
+    #[test]
+    fn test_zone_at() {
+        let bounds = Bounds::new(point(px(0.0), px(0.0)), size(px(400.0), px(200.0)));
+        assert_eq!(DockZone::at(bounds, point(px(200.0), px(100.0))), DockZone::Center);
+        assert_eq!(DockZone::at(bounds, point(px(20.0), px(100.0))), DockZone::Left);
+        assert_eq!(DockZone::at(bounds, point(px(390.0), px(100.0))), DockZone::Right);
+        assert_eq!(DockZone::at(bounds, point(px(200.0), px(10.0))), DockZone::Top);
+        assert_eq!(DockZone::at(bounds, point(px(200.0), px(190.0))), DockZone::Bottom);
+    }
+
+    #[test]
+    fn test_dock_to_edge_splits_group() {
+        let mut layout = ide();
+        assert!(layout.dock("terminal", "editor", DockZone::Bottom));
+        let expected = DockNode::split(
+            SplitOrientation::Horizontal,
+            0.2,
+            DockNode::tabs(["files"]),
+            DockNode::split(
+                SplitOrientation::Vertical,
+                1.0 - DOCKED_SHARE,
+                DockNode::tabs(["editor"]),
+                DockNode::tabs(["terminal"]),
+            ),
+        );
+        assert_eq!(layout.root, Some(expected));
+    }
+
+    #[test]
+    fn test_dock_to_center_adds_tab_and_collapses_empty_group() {
+        let mut layout = ide();
+        assert!(layout.dock("files", "editor", DockZone::Center));
+        assert_eq!(
+            layout.root,
+            Some(DockNode::Tabs {
+                panels: vec!["editor".into(), "terminal".into(), "files".into()],
+                active: 2,
+            })
+        );
+        assert_eq!(ids(&layout), vec!["editor", "terminal", "files"]);
+
+        // A lone panel cannot dock beside itself
+        let mut layout = ide();
+        assert!(!layout.dock("files", "files", DockZone::Left));
+    }
+
+    #[test]
+    fn test_float_and_remove() {
+        let mut layout = ide();
+        let bounds = Bounds::new(point(px(10.0), px(20.0)), size(px(300.0), px(200.0)));
+        layout.float("terminal", bounds);
+        assert_eq!(ids(&layout), vec!["files", "editor", "terminal"]);
+        assert_eq!(layout.floating[0].bounds, bounds);
+
+        assert!(layout.remove("terminal"));
+        assert!(layout.remove("files"));
+        assert_eq!(layout.root, Some(DockNode::tabs(["editor"])));
+        assert!(layout.floating.is_empty());
+        assert!(!layout.remove("missing"));
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let mut layout = ide();
+        layout.activate("terminal");
+        layout.float("colors", Bounds::new(point(px(40.0), px(40.0)), size(px(240.0), px(320.0))));
+        layout.floating[0].minimized = true;
+        assert_eq!(DockLayout::from_json(&layout.to_json()), Some(layout));
+
+        assert_eq!(DockLayout::from_json(&DockLayout::default().to_json()), Some(DockLayout::default()));
+        assert_eq!(DockLayout::from_json("not json"), None);
+        assert_eq!(DockLayout::from_json(r#"{"root": {"tabs": []}}"#), None);
+    }
+}
@@ -1,7 +1,19 @@
 //! Drawer side panel component.
 
+use std::rc::Rc;
+
 use gpui::*;
-use crate::{atoms::{Label, LabelVariant, Button, ButtonVariant}, theme::Theme};
+use gpui::prelude::FluentBuilder;
+use crate::{
+    atoms::{Label, LabelVariant, Button, ButtonVariant},
+    layout::ScrollArea,
+    theme::use_theme,
+    utils::{
+        motion::{self, reduced_motion, Easing},
+        FocusTrap,
+    },
+};
+
 
 /// Drawer position variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -13,6 +25,16 @@ pub enum DrawerPosition {
     Right,
 }
 
+/// Drag payload for resizing the drawer by its inner edge
+#[derive(Clone)]
+struct DrawerResize;
+
+impl Render for DrawerResize {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
+        Empty
+    }
+}
+
 /// Drawer configuration properties
 #[derive(Clone)]
 pub struct DrawerProps {
@@ -22,8 +44,24 @@ pub struct DrawerProps {
     pub position: DrawerPosition,
     /// Whether drawer is open
     pub open: bool,
-    /// Drawer width
+    /// Drawer width, restored by double-clicking the resize handle
     pub width: Pixels,
+    /// Whether the drawer can be resized by dragging its inner edge
+    pub resizable: bool,
+    /// Narrowest width the drawer can be resized to
+    pub min_width: Pixels,
+    /// Widest width the drawer can be resized to, `None` for no limit
+    pub max_width: Option<Pixels>,
+    /// Called with the new width whenever the drawer is resized
+    pub on_resize: Option<Rc<dyn Fn(Pixels, &mut Window, &mut App)>>,
+    /// Focusable elements in the drawer's content, in Tab order, for the focus trap
+    pub focus_handles: Vec<FocusHandle>,
+    /// Whether pressing Escape closes the drawer
+    pub close_on_escape: bool,
+    /// Whether clicking the backdrop closes the drawer
+    pub close_on_backdrop_click: bool,
+    /// Called with the new state when the drawer opens or closes
+    pub on_open_change: Option<Rc<dyn Fn(bool, &mut Window, &mut App)>>,
 }
 
 impl Default for DrawerProps {
@@ -33,13 +71,23 @@ impl Default for DrawerProps {
             position: DrawerPosition::default(),
             open: false,
             width: px(400.0),
+            resizable: false,
+            min_width: px(240.0),
+            max_width: None,
+            on_resize: None,
+            focus_handles: Vec::new(),
+            close_on_escape: true,
+            close_on_backdrop_click: true,
+            on_open_change: None,
         }
     }
 }
 
 /// A drawer side panel component.
 ///
-/// Drawer creates a sliding panel from the side of the screen.
+/// Drawer creates a sliding panel from the side of the screen. Opening
+/// slides the panel in over a dimmed backdrop; closing slides it back out
+/// before it is removed.
 ///
 /// ## Example
 ///
@@ -49,98 +97,486 @@ impl Default for DrawerProps {
 /// Drawer::new()
 ///     .title("Settings")
 ///     .position(DrawerPosition::Right)
+///     .on_open_change(|open, _window, _cx| println!("drawer open: {open}"))
 ///     .open(true);
 /// ```
+///
+/// ## Accessibility
+///
+/// - Opening the drawer moves focus into it
+/// - Tab and Shift+Tab cycle through the registered focus handles and the
+///   close button, wrapping at the ends
+/// - Escape closes the drawer unless `close_on_escape` is off
+/// - Closing restores focus to the element focused before the drawer opened
+///
+/// ## Resizing
+///
+/// A resizable drawer can be dragged wider or narrower by its inner edge,
+/// within `min_width` and `max_width`. Double-clicking the edge restores
+/// the configured width. Report the size through `on_resize` and pass it
+/// back with `restore_width` to persist it:
+///
+/// ```rust,ignore
+/// Drawer::new()
+///     .title("Inspector")
+///     .resizable(true)
+///     .min_width(px(280.0))
+///     .max_width(px(720.0))
+///     .restore_width(settings.inspector_width)
+///     .on_resize(|width, _window, cx| save_inspector_width(width, cx));
+/// ```
 pub struct Drawer {
     props: DrawerProps,
+    /// Width set by dragging, `None` while at the configured width
+    resized_width: Option<Pixels>,
+    focus_trap: FocusTrap,
+    /// Focus handles for the panel and close button, created on first open
+    focus: Option<DrawerFocus>,
+    /// Move focus into the drawer on the next render, once its handles exist
+    pending_focus: bool,
+    /// Whether the slide-out transition is still playing after closing
+    closing: bool,
+    /// Counts open/close changes so each one restarts the transition
+    transition: usize,
+    /// Ends the slide-out transition; dropping it keeps the drawer closing
+    _close_task: Option<Task<()>>,
+}
+
+/// Focus handles owned by the drawer
+struct DrawerFocus {
+    panel: FocusHandle,
+    close: FocusHandle,
 }
 
 impl Drawer {
+    /// Create a new, closed drawer
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let drawer = cx.new(|_| Drawer::new().title("Filters"));
+    /// ```
     pub fn new() -> Self {
         Self {
             props: DrawerProps::default(),
+            resized_width: None,
+            focus_trap: FocusTrap::new(),
+            focus: None,
+            pending_focus: false,
+            closing: false,
+            transition: 0,
+            _close_task: None,
         }
     }
 
+    /// Set the drawer title
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Drawer::new().title("Settings");
+    /// ```
     pub fn title(mut self, title: impl Into<SharedString>) -> Self {
         self.props.title = title.into();
         self
     }
 
+    /// Set which side of the window the drawer slides in from
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Drawer::new().position(DrawerPosition::Left);
+    /// ```
     pub fn position(mut self, position: DrawerPosition) -> Self {
         self.props.position = position;
         self
     }
 
+    /// Set whether the drawer is open initially
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Drawer::new().open(true);
+    /// ```
     pub fn open(mut self, open: bool) -> Self {
         self.props.open = open;
+        self.pending_focus = open;
         self
     }
 
+    /// Set the drawer width
+    ///
+    /// A resized drawer returns to this width when its resize handle is
+    /// double-clicked.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Drawer::new().width(px(320.0));
+    /// ```
     pub fn width(mut self, width: Pixels) -> Self {
         self.props.width = width;
         self
     }
+
+    /// Set whether the drawer can be resized by dragging its inner edge
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Drawer::new().resizable(true);
+    /// ```
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.props.resizable = resizable;
+        self
+    }
+
+    /// Set the narrowest width the drawer can be resized to
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Drawer::new().resizable(true).min_width(px(280.0));
+    /// ```
+    pub fn min_width(mut self, min_width: Pixels) -> Self {
+        self.props.min_width = min_width;
+        self
+    }
+
+    /// Set the widest width the drawer can be resized to
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Drawer::new().resizable(true).max_width(px(720.0));
+    /// ```
+    pub fn max_width(mut self, max_width: Pixels) -> Self {
+        self.props.max_width = Some(max_width);
+        self
+    }
+
+    /// Start at a width previously reported through `on_resize`
+    ///
+    /// Unlike [`Drawer::width`], this does not change the width restored
+    /// by double-clicking the resize handle.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Drawer::new().resizable(true).restore_width(settings.inspector_width);
+    /// ```
+    pub fn restore_width(mut self, width: Pixels) -> Self {
+        self.resized_width = Some(width);
+        self
+    }
+
+    /// Set the handler called with the new width whenever the drawer is resized
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Drawer::new()
+    ///     .resizable(true)
+    ///     .on_resize(|width, _window, cx| save_inspector_width(width, cx));
+    /// ```
+    pub fn on_resize(mut self, handler: impl Fn(Pixels, &mut Window, &mut App) + 'static) -> Self {
+        self.props.on_resize = Some(Rc::new(handler));
+        self
+    }
+
+    /// Current drawer width, within the min/max constraints
+    pub fn current_width(&self) -> Pixels {
+        self.clamp_width(self.resized_width.unwrap_or(self.props.width))
+    }
+
+    /// Clamp `width` to the drawer's width constraints
+    fn clamp_width(&self, width: Pixels) -> Pixels {
+        let width = if width < self.props.min_width { self.props.min_width } else { width };
+        match self.props.max_width {
+            Some(max_width) if width > max_width => max_width,
+            _ => width,
+        }
+    }
+
+    /// Resize the drawer, or return it to its configured width with `None`
+    fn resize(&mut self, width: Option<Pixels>, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let before = self.current_width();
+        self.resized_width = width.map(|width| self.clamp_width(width));
+        let after = self.current_width();
+        if before != after {
+            if let Some(handler) = self.props.on_resize.clone() {
+                handler(after, window, cx);
+            }
+            cx.notify();
+        }
+    }
+
+    /// Follow a resize drag with the drawer's inner edge
+    fn handle_resize_drag(
+        &mut self,
+        event: &DragMoveEvent<DrawerResize>,
+        window: &mut Window,
+        cx: &mut Context<'_, Self>,
+    ) {
+        let x = event.event.position.x;
+        let width = match self.props.position {
+            DrawerPosition::Left => x,
+            DrawerPosition::Right => window.viewport_size().width - x,
+        };
+        self.resize(Some(width), window, cx);
+    }
+
+    /// Register focusable elements in the drawer's content, in Tab order
+    ///
+    /// The focus trap cycles through these before the close button.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Drawer::new()
+    ///     .title("Filters")
+    ///     .focus_handles(vec![self.search_handle.clone(), self.apply_handle.clone()]);
+    /// ```
+    pub fn focus_handles(mut self, handles: Vec<FocusHandle>) -> Self {
+        self.props.focus_handles = handles;
+        self
+    }
+
+    /// Set whether pressing Escape closes the drawer
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Drawer::new().close_on_escape(false);
+    /// ```
+    pub fn close_on_escape(mut self, close: bool) -> Self {
+        self.props.close_on_escape = close;
+        self
+    }
+
+    /// Set whether clicking the backdrop closes the drawer
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Drawer::new().close_on_backdrop_click(false);
+    /// ```
+    pub fn close_on_backdrop_click(mut self, close: bool) -> Self {
+        self.props.close_on_backdrop_click = close;
+        self
+    }
+
+    /// Set the handler called when the drawer opens or closes
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Drawer::new().on_open_change(|open, _window, cx| save_panel_state(open, cx));
+    /// ```
+    pub fn on_open_change(
+        mut self,
+        handler: impl Fn(bool, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_open_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Open or close the drawer with a slide transition
+    ///
+    /// Opening focuses the drawer; closing restores the previous focus.
+    /// Both call the `on_open_change` handler.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// drawer.update(cx, |drawer, cx| drawer.set_open(true, window, cx));
+    /// ```
+    pub fn set_open(&mut self, open: bool, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.props.open == open {
+            return;
+        }
+        self.props.open = open;
+        self.transition += 1;
+
+        if open {
+            self.closing = false;
+            self._close_task = None;
+            self.pending_focus = true;
+        } else {
+            self.closing = true;
+            self.pending_focus = false;
+            let duration = use_theme(cx).global.duration_normal;
+            self._close_task = Some(cx.spawn(async move |this, cx| {
+                cx.background_executor().timer(duration).await;
+                this.update(cx, |this, cx| {
+                    this.closing = false;
+                    cx.notify();
+                })
+                .ok();
+            }));
+            self.focus_trap.cleanup(window);
+        }
+
+        if let Some(handler) = self.props.on_open_change.clone() {
+            handler(open, window, cx);
+        }
+        cx.notify();
+    }
+
+    /// Handle Escape and trap Tab inside the drawer
+    fn handle_key(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if event.keystroke.key == "escape" && self.props.close_on_escape {
+            self.set_open(false, window, cx);
+            cx.stop_propagation();
+        } else if self.focus_trap.handle_key_event(event, window) {
+            cx.stop_propagation();
+        }
+    }
 }
 
 impl Render for Drawer {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
-        let theme = Theme::default();
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
 
-        if !self.props.open {
-            return div(); // Return empty div if not open
+        if !self.props.open && !self.closing {
+            return div().into_any_element(); // Return empty div if not open
         }
 
-        div()
-            .fixed()
+        let focus = self.focus.get_or_insert_with(|| DrawerFocus {
+            panel: cx.focus_handle(),
+            close: cx.focus_handle(),
+        });
+        let (focus_handle, close_focus) = (focus.panel.clone(), focus.close.clone());
+        if std::mem::take(&mut self.pending_focus) {
+            // Start on the panel itself; Tab then enters the content
+            let mut handles = self.props.focus_handles.clone();
+            handles.push(close_focus.clone());
+            self.focus_trap.set_handles(handles);
+            self.focus_trap.set_initial_focus(Some(focus_handle.clone()));
+            self.focus_trap.initialize(window, cx);
+        }
+
+        // Transitions run forwards when opening and backwards when closing
+        let entering = self.props.open;
+        let animation = motion::animation(theme.global.duration_normal, Easing::Standard);
+        let reduced = reduced_motion(cx);
+        let visible = move |delta: f32| if entering { delta } else { 1.0 - delta };
+        let width = self.current_width();
+        let position = self.props.position;
+
+        let mut backdrop = div()
+            .id("drawer-backdrop")
+            .absolute()
             .top(px(0.0))
             .left(px(0.0))
-            .w_full()
+            .size_full()
+            .bg(hsla(0.0, 0.0, 0.0, 0.5));
+        if self.props.close_on_backdrop_click && entering {
+            backdrop = backdrop.on_click(cx.listener(|this, _event: &ClickEvent, window, cx| {
+                this.set_open(false, window, cx);
+            }));
+        }
+
+        let panel = div()
+            .id("drawer")
+            .track_focus(&focus_handle)
+            .on_key_down(cx.listener(Self::handle_key))
+            .absolute()
+            .top(px(0.0))
+            .w(width)
             .h_full()
+            .bg(theme.alias.color_surface)
+            .shadow_xl()
             .flex()
-            .flex_row()
-            .child(
-                // Overlay
-                div()
-                    .flex_1()
-                    .bg(hsla(0.0, 0.0, 0.0, 0.5))
-            )
+            .flex_col()
             .child(
-                // Drawer panel
+                // Header
                 div()
-                    .w(self.props.width)
-                    .h_full()
-                    .bg(theme.alias.color_surface)
-                    .shadow_xl()
                     .flex()
-                    .flex_col()
+                    .flex_row()
+                    .items_center()
+                    .justify_between()
+                    .p(theme.global.spacing_lg)
+                    .border_color(theme.alias.color_border)
+                    .border_b(px(1.0))
                     .child(
-                        // Header
-                        div()
-                            .flex()
-                            .flex_row()
-                            .items_center()
-                            .justify_between()
-                            .p(theme.global.spacing_lg)
-                            .border_color(theme.alias.color_border)
-                            .border_b(px(1.0))
-                            .child(
-                                Label::new(self.props.title.clone())
-                                    .variant(LabelVariant::Heading2)
-                            )
-                            .child(
-                                Button::new()
-                                    .label("✕")
-                                    .variant(ButtonVariant::Ghost)
-                            )
+                        Label::new(self.props.title.clone())
+                            .variant(LabelVariant::Heading2)
                     )
                     .child(
-                        // Content area
-                        div()
-                            .flex_1()
-                            .p(theme.global.spacing_lg)
-                            .child("Drawer content goes here")
+                        Button::new()
+                            .id("drawer-close")
+                            .label("✕")
+                            .variant(ButtonVariant::Ghost)
+                            .track_focus(&close_focus)
+                            .on_click(cx.listener(|this, _event: &ClickEvent, window, cx| {
+                                this.set_open(false, window, cx);
+                            }))
+                            .to_element(&theme)
                     )
             )
+            .child(
+                // Content area, scrolling when it outgrows the panel
+                div()
+                    .flex_1()
+                    .min_h(px(0.0))
+                    .child(
+                        ScrollArea::new("drawer-body")
+                            .child(div().p(theme.global.spacing_lg).child("Drawer content goes here"))
+                    )
+            )
+            .when(self.props.resizable, |panel| {
+                // Resize handle along the inner edge
+                let handle = div()
+                    .id("drawer-resize")
+                    .absolute()
+                    .top(px(0.0))
+                    .h_full()
+                    .w(px(4.0))
+                    .cursor_col_resize()
+                    .hover(|style| style.bg(theme.alias.color_border_focus))
+                    .on_drag(DrawerResize, |drag, _offset, _window, cx| cx.new(|_| drag.clone()))
+                    .on_click(cx.listener(|this, event: &ClickEvent, window, cx| {
+                        if event.click_count() >= 2 {
+                            this.resize(None, window, cx);
+                        }
+                        cx.stop_propagation();
+                    }));
+                panel.child(match position {
+                    DrawerPosition::Left => handle.right(px(0.0)),
+                    DrawerPosition::Right => handle.left(px(0.0)),
+                })
+            });
+
+        div()
+            .fixed()
+            .top(px(0.0))
+            .left(px(0.0))
+            .w_full()
+            .h_full()
+            .occlude()
+            .on_drag_move(cx.listener(Self::handle_resize_drag))
+            .child(backdrop.with_animation(
+                ("drawer-backdrop", self.transition),
+                animation.clone(),
+                move |backdrop, delta| backdrop.opacity(visible(delta)),
+            ))
+            .child(panel.with_animation(
+                ("drawer-panel", self.transition),
+                animation,
+                move |panel, delta| {
+                    // Slide from fully off-screen to flush with the edge, or
+                    // fade in place when motion is reduced
+                    let offset = if reduced { px(0.0) } else { -(width * (1.0 - visible(delta))) };
+                    let panel = match position {
+                        DrawerPosition::Left => panel.left(offset),
+                        DrawerPosition::Right => panel.right(offset),
+                    };
+                    if reduced { panel.opacity(visible(delta)) } else { panel }
+                },
+            ))
+            .into_any_element()
     }
 }
@@ -0,0 +1,876 @@
+//! Floating panels: draggable, resizable tool windows inside the app window.
+
+use std::{cell::Cell, rc::Rc};
+
+use gpui::*;
+use crate::{
+    atoms::{icons, Button, ButtonSize, ButtonVariant},
+    theme::use_theme,
+};
+
+/// Height of a panel's title bar, and of a minimized panel
+const TITLE_BAR_HEIGHT: f32 = 32.0;
+/// Thickness of the resize handles along the right and bottom edges
+const HANDLE_SIZE: f32 = 6.0;
+/// Distance the arrow keys move or resize a panel without a snap grid
+const KEYBOARD_STEP: f32 = 8.0;
+
+/// Renders the content of a floating panel
+pub type FloatingPanelSlot = Rc<dyn Fn(&mut Window, &mut App) -> AnyElement>;
+
+/// A panel hosted by [`FloatingPanels`]
+#[derive(Clone)]
+pub struct FloatingPanel {
+    /// Identifies the panel in handlers and persisted layouts
+    pub id: SharedString,
+    /// Title shown in the title bar
+    pub title: SharedString,
+    /// Panel content
+    pub content: Option<FloatingPanelSlot>,
+    /// Position and size within the host, including the title bar
+    pub bounds: Bounds<Pixels>,
+    /// Smallest size the panel can be resized to
+    pub min_size: Size<Pixels>,
+    /// Whether the panel can be resized by its right and bottom edges
+    pub resizable: bool,
+    /// Whether the title bar shows a close button
+    pub closable: bool,
+    /// Whether the panel is collapsed to its title bar
+    pub minimized: bool,
+    /// Focus handle of the title bar, created on first render
+    focus_handle: Option<FocusHandle>,
+}
+
+impl FloatingPanel {
+    /// Create a panel with `id` and `title`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let layers = FloatingPanel::new("layers", "Layers");
+    /// ```
+    pub fn new(id: impl Into<SharedString>, title: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            content: None,
+            bounds: Bounds::new(point(px(24.0), px(24.0)), size(px(280.0), px(320.0))),
+            min_size: size(px(160.0), px(96.0)),
+            resizable: true,
+            closable: false,
+            minimized: false,
+            focus_handle: None,
+        }
+    }
+
+    /// Set the panel content
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FloatingPanel::new("layers", "Layers").content(move |_window, _cx| layers.clone().into_any_element());
+    /// ```
+    pub fn content(mut self, content: impl Fn(&mut Window, &mut App) -> AnyElement + 'static) -> Self {
+        self.content = Some(Rc::new(content));
+        self
+    }
+
+    /// Set the position and size, for example from a saved layout
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FloatingPanel::new("layers", "Layers").bounds(settings.layers_bounds);
+    /// ```
+    pub fn bounds(mut self, bounds: Bounds<Pixels>) -> Self {
+        self.bounds = bounds;
+        self
+    }
+
+    /// Set the smallest size the panel can be resized to
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FloatingPanel::new("colors", "Colors").min_size(size(px(200.0), px(160.0)));
+    /// ```
+    pub fn min_size(mut self, min_size: Size<Pixels>) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Set whether the panel can be resized
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FloatingPanel::new("tools", "Tools").resizable(false);
+    /// ```
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// Set whether the title bar shows a close button
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FloatingPanel::new("history", "History").closable(true);
+    /// ```
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Set whether the panel starts collapsed to its title bar
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FloatingPanel::new("history", "History").minimized(settings.history_minimized);
+    /// ```
+    pub fn minimized(mut self, minimized: bool) -> Self {
+        self.minimized = minimized;
+        self
+    }
+}
+
+/// What dragging a part of a panel does
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PanelGesture {
+    /// Dragging the title bar moves the panel
+    Move,
+    /// Dragging an edge or corner resizes the panel
+    Resize {
+        /// Whether the right edge follows the mouse
+        right: bool,
+        /// Whether the bottom edge follows the mouse
+        bottom: bool,
+    },
+}
+
+/// Drag payload for moving or resizing a panel of the host with this entity id
+#[derive(Clone)]
+struct PanelDrag {
+    host: EntityId,
+    id: SharedString,
+    gesture: PanelGesture,
+}
+
+impl Render for PanelDrag {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
+        Empty
+    }
+}
+
+/// Mouse position and panel bounds when a gesture started
+#[derive(Debug, Clone, Copy)]
+struct GestureStart {
+    mouse: Point<Pixels>,
+    bounds: Bounds<Pixels>,
+}
+
+/// FloatingPanels configuration properties
+#[derive(Clone)]
+pub struct FloatingPanelsProps {
+    /// Grid that panel positions and sizes snap to, `None` for free placement
+    pub snap_grid: Option<Pixels>,
+    /// Distance within which panel edges snap to the host's edges and to
+    /// other panels, zero to turn edge snapping off
+    pub snap_distance: Pixels,
+    /// Called with a panel's id and new bounds whenever it moves or resizes
+    pub on_geometry_change: Option<Rc<dyn Fn(SharedString, Bounds<Pixels>, &mut Window, &mut App)>>,
+    /// Called with a panel's id and new state when it is minimized or restored
+    pub on_minimize_change: Option<Rc<dyn Fn(SharedString, bool, &mut Window, &mut App)>>,
+    /// Called with a panel's id after its close button removes it
+    pub on_close: Option<Rc<dyn Fn(SharedString, &mut Window, &mut App)>>,
+}
+
+impl Default for FloatingPanelsProps {
+    fn default() -> Self {
+        Self {
+            snap_grid: None,
+            snap_distance: px(8.0),
+            on_geometry_change: None,
+            on_minimize_change: None,
+            on_close: None,
+        }
+    }
+}
+
+/// A layer of floating tool panels.
+///
+/// FloatingPanels hosts panels that float over the app, like the tool
+/// palettes of a drawing program. Panels move by their title bar and resize
+/// by their right and bottom edges, staying inside the host. Pressing on a
+/// panel raises it above the others. Minimizing collapses a panel to its
+/// title bar.
+///
+/// Place the host over the app content, for example as the last child of a
+/// `relative()` root with `absolute().size_full()`; it only captures the
+/// mouse where panels are.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::organisms::*;
+///
+/// let panels = cx.new(|_| {
+///     FloatingPanels::new()
+///         .snap_grid(px(8.0))
+///         .panel(
+///             FloatingPanel::new("layers", "Layers")
+///                 .bounds(settings.layers_bounds)
+///                 .content(move |_window, _cx| layers.clone().into_any_element()),
+///         )
+///         .on_geometry_change(|id, bounds, _window, cx| save_panel_bounds(&id, bounds, cx))
+/// });
+/// ```
+///
+/// ## Accessibility
+///
+/// - Each title bar is a Tab stop; arrow keys move the panel and
+///   Shift+arrow keys resize it
+/// - Enter or double-clicking the title bar minimizes and restores
+/// - The minimize and close buttons are labelled
+pub struct FloatingPanels {
+    props: FloatingPanelsProps,
+    /// Panels from bottom to top
+    panels: Vec<FloatingPanel>,
+    /// Where the current drag started
+    gesture_start: Option<GestureStart>,
+    /// Host bounds from the last paint, for keeping panels inside
+    host_bounds: Rc<Cell<Option<Bounds<Pixels>>>>,
+}
+
+impl FloatingPanels {
+    /// Create an empty host
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let panels = cx.new(|_| FloatingPanels::new());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            props: FloatingPanelsProps::default(),
+            panels: Vec::new(),
+            gesture_start: None,
+            host_bounds: Rc::new(Cell::new(None)),
+        }
+    }
+
+    /// Add a panel above the ones added before it
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FloatingPanels::new().panel(FloatingPanel::new("tools", "Tools"));
+    /// ```
+    pub fn panel(mut self, panel: FloatingPanel) -> Self {
+        self.panels.push(panel);
+        self
+    }
+
+    /// Set the grid that positions and sizes snap to
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FloatingPanels::new().snap_grid(px(8.0));
+    /// ```
+    pub fn snap_grid(mut self, grid: Pixels) -> Self {
+        self.props.snap_grid = Some(grid);
+        self
+    }
+
+    /// Set the distance within which edges snap to the host and other panels
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// // Turn edge snapping off
+    /// FloatingPanels::new().snap_distance(px(0.0));
+    /// ```
+    pub fn snap_distance(mut self, distance: Pixels) -> Self {
+        self.props.snap_distance = distance;
+        self
+    }
+
+    /// Set the handler called whenever a panel moves or resizes
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FloatingPanels::new().on_geometry_change(|id, bounds, _window, cx| save_panel_bounds(&id, bounds, cx));
+    /// ```
+    pub fn on_geometry_change(
+        mut self,
+        handler: impl Fn(SharedString, Bounds<Pixels>, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_geometry_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the handler called when a panel is minimized or restored
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FloatingPanels::new().on_minimize_change(|id, minimized, _window, cx| save_minimized(&id, minimized, cx));
+    /// ```
+    pub fn on_minimize_change(
+        mut self,
+        handler: impl Fn(SharedString, bool, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_minimize_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the handler called after a panel's close button removes it
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// FloatingPanels::new().on_close(|id, _window, cx| mark_panel_hidden(&id, cx));
+    /// ```
+    pub fn on_close(mut self, handler: impl Fn(SharedString, &mut Window, &mut App) + 'static) -> Self {
+        self.props.on_close = Some(Rc::new(handler));
+        self
+    }
+
+    /// Show a panel on top of the others, replacing any panel with the same id
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// panels.update(cx, |panels, cx| panels.add_panel(FloatingPanel::new("find", "Find"), cx));
+    /// ```
+    pub fn add_panel(&mut self, panel: FloatingPanel, cx: &mut Context<'_, Self>) {
+        self.panels.retain(|existing| existing.id != panel.id);
+        self.panels.push(panel);
+        cx.notify();
+    }
+
+    /// Remove the panel with `id`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// panels.update(cx, |panels, cx| panels.remove_panel("find", cx));
+    /// ```
+    pub fn remove_panel(&mut self, id: &str, cx: &mut Context<'_, Self>) {
+        let before = self.panels.len();
+        self.panels.retain(|panel| panel.id.as_ref() != id);
+        if self.panels.len() != before {
+            cx.notify();
+        }
+    }
+
+    /// Raise the panel with `id` above the others
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// panels.update(cx, |panels, cx| panels.bring_to_front("layers", cx));
+    /// ```
+    pub fn bring_to_front(&mut self, id: &str, cx: &mut Context<'_, Self>) {
+        let Some(index) = self.index_of(id) else {
+            return;
+        };
+        if index + 1 != self.panels.len() {
+            let panel = self.panels.remove(index);
+            self.panels.push(panel);
+            cx.notify();
+        }
+    }
+
+    /// Minimize or restore the panel with `id`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// panels.update(cx, |panels, cx| panels.set_minimized("layers", true, window, cx));
+    /// ```
+    pub fn set_minimized(&mut self, id: &str, minimized: bool, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let Some(index) = self.index_of(id) else {
+            return;
+        };
+        if self.panels[index].minimized == minimized {
+            return;
+        }
+        self.panels[index].minimized = minimized;
+        if let Some(handler) = self.props.on_minimize_change.clone() {
+            handler(self.panels[index].id.clone(), minimized, window, cx);
+        }
+        cx.notify();
+    }
+
+    /// Bounds of the panel with `id`
+    pub fn panel_bounds(&self, id: &str) -> Option<Bounds<Pixels>> {
+        self.index_of(id).map(|index| self.panels[index].bounds)
+    }
+
+    /// Panel ids from bottom to top, for saving the stacking order
+    pub fn panel_order(&self) -> Vec<SharedString> {
+        self.panels.iter().map(|panel| panel.id.clone()).collect()
+    }
+
+    /// Position of the panel with `id` in the stacking order
+    fn index_of(&self, id: &str) -> Option<usize> {
+        self.panels.iter().position(|panel| panel.id.as_ref() == id)
+    }
+
+    /// Size of the host, or unbounded before the first paint
+    fn area(&self) -> Size<Pixels> {
+        self.host_bounds
+            .get()
+            .map_or(size(px(f32::MAX), px(f32::MAX)), |bounds| bounds.size)
+    }
+
+    /// Move or resize the panel at `index` and notify the handler if it changed
+    fn set_bounds(&mut self, index: usize, bounds: Bounds<Pixels>, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.panels[index].bounds == bounds {
+            return;
+        }
+        self.panels[index].bounds = bounds;
+        if let Some(handler) = self.props.on_geometry_change.clone() {
+            handler(self.panels[index].id.clone(), bounds, window, cx);
+        }
+        cx.notify();
+    }
+
+    /// Bounds of the panel at `index` after applying `gesture` from `start` by `delta`
+    fn gesture_bounds(
+        &self,
+        index: usize,
+        gesture: PanelGesture,
+        start: Bounds<Pixels>,
+        delta: Point<Pixels>,
+    ) -> Bounds<Pixels> {
+        let panel = &self.panels[index];
+        let area = self.area();
+        match gesture {
+            PanelGesture::Move => {
+                let others: Vec<Bounds<Pixels>> = self
+                    .panels
+                    .iter()
+                    .enumerate()
+                    .filter(|&(other, _)| other != index)
+                    .map(|(_, other)| other.bounds)
+                    .collect();
+                let moved = Bounds::new(start.origin + delta, start.size);
+                snap_move(moved, area, &others, self.props.snap_grid, self.props.snap_distance)
+            }
+            PanelGesture::Resize { right, bottom } => {
+                let mut resized = start;
+                if right {
+                    resized.size.width += delta.x;
+                }
+                if bottom {
+                    resized.size.height += delta.y;
+                }
+                snap_resize(resized, panel.min_size, area, self.props.snap_grid, self.props.snap_distance)
+            }
+        }
+    }
+
+    /// Remember where a drag on the panel with `id` started
+    fn start_gesture(&mut self, id: &str, mouse: Point<Pixels>, cx: &mut Context<'_, Self>) {
+        self.bring_to_front(id, cx);
+        if let Some(index) = self.index_of(id) {
+            self.gesture_start = Some(GestureStart {
+                mouse,
+                bounds: self.panels[index].bounds,
+            });
+        }
+    }
+
+    /// Follow a move or resize drag belonging to this host
+    fn handle_drag(&mut self, event: &DragMoveEvent<PanelDrag>, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let drag = event.drag(cx).clone();
+        if drag.host != cx.entity_id() {
+            return;
+        }
+        let (Some(start), Some(index)) = (self.gesture_start, self.index_of(&drag.id)) else {
+            return;
+        };
+        let delta = event.event.position - start.mouse;
+        let bounds = self.gesture_bounds(index, drag.gesture, start.bounds, delta);
+        self.set_bounds(index, bounds, window, cx);
+    }
+
+    /// Move with arrow keys, resize with Shift+arrow keys, and minimize with Enter
+    fn handle_key(&mut self, id: &str, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let Some(index) = self.index_of(id) else {
+            return;
+        };
+        let step = self.props.snap_grid.unwrap_or(px(KEYBOARD_STEP));
+        let delta = match event.keystroke.key.as_str() {
+            "left" => point(-step, px(0.0)),
+            "right" => point(step, px(0.0)),
+            "up" => point(px(0.0), -step),
+            "down" => point(px(0.0), step),
+            "enter" => {
+                let minimized = !self.panels[index].minimized;
+                self.set_minimized(id, minimized, window, cx);
+                cx.stop_propagation();
+                return;
+            }
+            _ => return,
+        };
+
+        let resize = event.keystroke.modifiers.shift && self.panels[index].resizable && !self.panels[index].minimized;
+        let gesture = if resize {
+            PanelGesture::Resize { right: true, bottom: true }
+        } else {
+            PanelGesture::Move
+        };
+        let bounds = self.gesture_bounds(index, gesture, self.panels[index].bounds, delta);
+        self.set_bounds(index, bounds, window, cx);
+        cx.stop_propagation();
+    }
+
+    /// Remove the panel with `id` and notify the handler
+    fn close(&mut self, id: SharedString, window: &mut Window, cx: &mut Context<'_, Self>) {
+        self.remove_panel(&id, cx);
+        if let Some(handler) = self.props.on_close.clone() {
+            handler(id, window, cx);
+        }
+    }
+}
+
+impl Default for FloatingPanels {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Round `value` to the nearest multiple of `grid`
+fn snap_to_grid(value: Pixels, grid: Option<Pixels>) -> Pixels {
+    match grid {
+        Some(grid) if grid > px(0.0) => grid * (value / grid).round(),
+        _ => value,
+    }
+}
+
+/// Shift a span starting at `start` so one of its ends meets the nearest target within `distance`
+fn snap_span(start: Pixels, len: Pixels, targets: &[Pixels], distance: Pixels) -> Pixels {
+    let mut best: Option<Pixels> = None;
+    for &target in targets {
+        for shift in [target - start, target - (start + len)] {
+            if shift.abs() <= distance && best.is_none_or(|best| shift.abs() < best.abs()) {
+                best = Some(shift);
+            }
+        }
+    }
+    start + best.unwrap_or(px(0.0))
+}
+
+/// Keep a span starting at `start` inside `0..limit` where it fits
+fn clamp_span(start: Pixels, len: Pixels, limit: Pixels) -> Pixels {
+    let max = (limit - len).max(px(0.0));
+    start.clamp(px(0.0), max)
+}
+
+/// Snap a moved panel to the grid, then to the host's and other panels' edges, inside the host
+fn snap_move(
+    bounds: Bounds<Pixels>,
+    area: Size<Pixels>,
+    others: &[Bounds<Pixels>],
+    grid: Option<Pixels>,
+    distance: Pixels,
+) -> Bounds<Pixels> {
+    let mut xs = vec![px(0.0), area.width];
+    let mut ys = vec![px(0.0), area.height];
+    for other in others {
+        xs.extend([other.left(), other.right()]);
+        ys.extend([other.top(), other.bottom()]);
+    }
+
+    let x = snap_span(snap_to_grid(bounds.origin.x, grid), bounds.size.width, &xs, distance);
+    let y = snap_span(snap_to_grid(bounds.origin.y, grid), bounds.size.height, &ys, distance);
+    Bounds::new(
+        point(
+            clamp_span(x, bounds.size.width, area.width),
+            clamp_span(y, bounds.size.height, area.height),
+        ),
+        bounds.size,
+    )
+}
+
+/// Snap a resized panel's size to the grid and its far edges to the host, within limits
+fn snap_resize(
+    bounds: Bounds<Pixels>,
+    min_size: Size<Pixels>,
+    area: Size<Pixels>,
+    grid: Option<Pixels>,
+    distance: Pixels,
+) -> Bounds<Pixels> {
+    let snap_edge = |start: Pixels, len: Pixels, limit: Pixels| {
+        let end = snap_to_grid(start + len, grid);
+        let end = if (limit - end).abs() <= distance { limit } else { end };
+        end.min(limit) - start
+    };
+    let width = snap_edge(bounds.origin.x, bounds.size.width, area.width).max(min_size.width);
+    let height = snap_edge(bounds.origin.y, bounds.size.height, area.height).max(min_size.height);
+    Bounds::new(bounds.origin, size(width, height))
+}
+
+impl Render for FloatingPanels {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        let host = cx.entity_id();
+
+        let host_bounds = self.host_bounds.clone();
+        let mut layer = div()
+            .id("floating-panels")
+            .relative()
+            .size_full()
+            .on_drag_move(cx.listener(Self::handle_drag))
+            .child(
+                canvas(
+                    move |bounds, window, _cx| {
+                        if host_bounds.get() != Some(bounds) {
+                            host_bounds.set(Some(bounds));
+                            window.request_animation_frame();
+                        }
+                    },
+                    |_, (), _, _| {},
+                )
+                .absolute()
+                .top(px(0.0))
+                .left(px(0.0))
+                .size_full(),
+            );
+
+        // Later panels paint above earlier ones
+        for (index, panel) in self.panels.iter_mut().enumerate() {
+            let focus_handle = panel.focus_handle.get_or_insert_with(|| cx.focus_handle()).clone();
+            let focused = focus_handle.is_focused(window);
+            let id = panel.id.clone();
+            let bounds = panel.bounds;
+            let minimized = panel.minimized;
+
+            let mut title_bar = div()
+                .id(("floating-panel-title", index))
+                .track_focus(&focus_handle)
+                .on_key_down(cx.listener({
+                    let id = id.clone();
+                    move |this, event: &KeyDownEvent, window, cx| this.handle_key(&id, event, window, cx)
+                }))
+                .flex()
+                .flex_row()
+                .flex_none()
+                .items_center()
+                .gap(theme.global.spacing_xs)
+                .h(px(TITLE_BAR_HEIGHT))
+                .pl(theme.global.spacing_sm)
+                .pr(theme.global.spacing_xs)
+                .bg(theme.alias.color_surface_elevated)
+                .text_size(theme.global.font_size_sm)
+                .font_weight(FontWeight::MEDIUM)
+                .text_color(theme.alias.color_text_primary)
+                .cursor_grab()
+                .on_mouse_down(MouseButton::Left, cx.listener({
+                    let id = id.clone();
+                    move |this, event: &MouseDownEvent, _window, cx| {
+                        this.start_gesture(&id, event.position, cx);
+                    }
+                }))
+                .on_drag(
+                    PanelDrag { host, id: id.clone(), gesture: PanelGesture::Move },
+                    |drag, _offset, _window, cx| cx.new(|_| drag.clone()),
+                )
+                .on_click(cx.listener({
+                    let id = id.clone();
+                    move |this, event: &ClickEvent, window, cx| {
+                        if event.click_count() >= 2 {
+                            this.set_minimized(&id, !minimized, window, cx);
+                        }
+                    }
+                }))
+                .child(div().flex_1().min_w(px(0.0)).truncate().child(panel.title.clone()))
+                .child(
+                    Button::new()
+                        .id(("floating-panel-minimize", index))
+                        .label(if minimized { "Restore" } else { "Minimize" })
+                        .icon_left(if minimized { icons::CHEVRON_DOWN } else { icons::CHEVRON_UP })
+                        .icon_only(true)
+                        .variant(ButtonVariant::Ghost)
+                        .size(ButtonSize::Sm)
+                        .on_click(cx.listener({
+                            let id = id.clone();
+                            move |this, _event: &ClickEvent, window, cx| {
+                                this.set_minimized(&id, !minimized, window, cx);
+                                cx.stop_propagation();
+                            }
+                        }))
+                        .to_element(&theme),
+                );
+            if panel.closable {
+                title_bar = title_bar.child(
+                    Button::new()
+                        .id(("floating-panel-close", index))
+                        .label("Close")
+                        .icon_left(icons::X)
+                        .icon_only(true)
+                        .variant(ButtonVariant::Ghost)
+                        .size(ButtonSize::Sm)
+                        .on_click(cx.listener({
+                            let id = id.clone();
+                            move |this, _event: &ClickEvent, window, cx| {
+                                this.close(id.clone(), window, cx);
+                                cx.stop_propagation();
+                            }
+                        }))
+                        .to_element(&theme),
+                );
+            }
+            if focused {
+                title_bar = title_bar.outline_color(theme.alias.color_border_focus).outline_w(px(2.0));
+            }
+
+            let mut element = div()
+                .id(("floating-panel", index))
+                .absolute()
+                .left(bounds.origin.x)
+                .top(bounds.origin.y)
+                .w(bounds.size.width)
+                .h(if minimized { px(TITLE_BAR_HEIGHT) } else { bounds.size.height })
+                .flex()
+                .flex_col()
+                .bg(theme.alias.color_surface)
+                .border(px(1.0))
+                .border_color(theme.alias.color_border)
+                .rounded(theme.global.radius_md)
+                .shadow_lg()
+                .overflow_hidden()
+                .occlude()
+                .on_mouse_down(MouseButton::Left, cx.listener({
+                    let id = id.clone();
+                    move |this, _event: &MouseDownEvent, _window, cx| this.bring_to_front(&id, cx)
+                }))
+                .child(title_bar);
+
+            if !minimized {
+                element = element.child(
+                    div()
+                        .flex_1()
+                        .min_h(px(0.0))
+                        .border_t(px(1.0))
+                        .border_color(theme.alias.color_border)
+                        .children(panel.content.clone().map(|content| content(window, cx))),
+                );
+
+                if panel.resizable {
+                    let handle = |name: &'static str, right: bool, bottom: bool, cx: &mut Context<'_, Self>| {
+                        let id = id.clone();
+                        div()
+                            .id((name, index))
+                            .absolute()
+                            .on_mouse_down(MouseButton::Left, cx.listener({
+                                let id = id.clone();
+                                move |this, event: &MouseDownEvent, _window, cx| {
+                                    this.start_gesture(&id, event.position, cx);
+                                    cx.stop_propagation();
+                                }
+                            }))
+                            .on_drag(
+                                PanelDrag { host, id, gesture: PanelGesture::Resize { right, bottom } },
+                                |drag, _offset, _window, cx| cx.new(|_| drag.clone()),
+                            )
+                    };
+                    element = element
+                        .child(
+                            handle("floating-panel-resize-right", true, false, cx)
+                                .top(px(0.0))
+                                .right(px(0.0))
+                                .bottom(px(HANDLE_SIZE))
+                                .w(px(HANDLE_SIZE))
+                                .cursor_col_resize(),
+                        )
+                        .child(
+                            handle("floating-panel-resize-bottom", false, true, cx)
+                                .left(px(0.0))
+                                .right(px(HANDLE_SIZE))
+                                .bottom(px(0.0))
+                                .h(px(HANDLE_SIZE))
+                                .cursor_row_resize(),
+                        )
+                        .child(
+                            handle("floating-panel-resize-corner", true, true, cx)
+                                .right(px(0.0))
+                                .bottom(px(0.0))
+                                .size(px(HANDLE_SIZE * 2.0))
+                                .cursor_nwse_resize(),
+                        );
+                }
+            }
+
+            layer = layer.child(element);
+        }
+
+        layer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn area() -> Size<Pixels> {
+        size(px(800.0), px(600.0))
+    }
+
+    fn panel_at(x: f32, y: f32) -> Bounds<Pixels> {
+        Bounds::new(point(px(x), px(y)), size(px(200.0), px(100.0)))
+    }
+
+    #[test]
+    fn test_move_stays_inside_host() {
+        let moved = snap_move(panel_at(-50.0, 700.0), area(), &[], None, px(0.0));
+        assert_eq!(moved.origin, point(px(0.0), px(500.0)));
+    }
+
+    #[test]
+    fn test_move_snaps_to_grid() {
+        let moved = snap_move(panel_at(101.0, 53.0), area(), &[], Some(px(8.0)), px(0.0));
+        assert_eq!(moved.origin, point(px(104.0), px(56.0)));
+    }
+
+    #[test]
+    fn test_move_snaps_to_edges() {
+        // Near the host's right edge
+        let moved = snap_move(panel_at(594.0, 200.0), area(), &[], None, px(8.0));
+        assert_eq!(moved.origin.x, px(600.0));
+
+        // Left edge meets another panel's right edge
+        let other = panel_at(100.0, 300.0);
+        let moved = snap_move(panel_at(305.0, 300.0), area(), &[other], None, px(8.0));
+        assert_eq!(moved.origin.x, px(300.0));
+    }
+
+    #[test]
+    fn test_resize_respects_limits() {
+        let small = Bounds::new(point(px(100.0), px(100.0)), size(px(20.0), px(20.0)));
+        let resized = snap_resize(small, size(px(160.0), px(96.0)), area(), None, px(0.0));
+        assert_eq!(resized.size, size(px(160.0), px(96.0)));
+
+        let large = Bounds::new(point(px(700.0), px(100.0)), size(px(300.0), px(100.0)));
+        let resized = snap_resize(large, size(px(160.0), px(96.0)), area(), None, px(0.0));
+        // Capped at the host edge, then raised back to the minimum width
+        assert_eq!(resized.size.width, px(160.0));
+    }
+
+    #[test]
+    fn test_panels_stack_in_order() {
+        let panels = FloatingPanels::new()
+            .panel(FloatingPanel::new("a", "A"))
+            .panel(FloatingPanel::new("b", "B").minimized(true));
+        assert_eq!(panels.panel_order(), vec![SharedString::from("a"), SharedString::from("b")]);
+        assert!(panels.panels[1].minimized);
+        assert!(panels.panel_bounds("missing").is_none());
+    }
+}
@@ -0,0 +1,764 @@
+//! List component with selectable items and section headers.
+
+use std::rc::Rc;
+
+use gpui::*;
+use crate::{
+    atoms::{Icon, IconSize},
+    layout::{VirtualList, VirtualListState},
+    theme::{use_theme, Theme},
+};
+
+/// Custom content for a list item's leading or trailing slot
+pub type ListSlot = Rc<dyn Fn(&mut Window, &mut App) -> AnyElement>;
+
+/// List item selection behavior
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ListSelectionMode {
+    /// Items cannot be selected
+    None,
+    /// At most one item is selected
+    #[default]
+    Single,
+    /// Any number of items, with shift-click ranges and cmd/ctrl-click toggling
+    Multiple,
+}
+
+/// A row of a [`List`]
+#[derive(Clone)]
+pub struct ListItem {
+    /// Stable id used to report selection and activation
+    pub id: SharedString,
+    /// Main text
+    pub primary: SharedString,
+    /// Supporting text below the main text
+    pub secondary: Option<SharedString>,
+    /// SVG path of a leading icon
+    pub icon: Option<SharedString>,
+    /// Custom leading content, such as a checkbox; replaces `icon`
+    pub leading: Option<ListSlot>,
+    /// Trailing content, such as a badge, timestamp or switch
+    pub trailing: Option<ListSlot>,
+    /// Whether the item is disabled
+    pub disabled: bool,
+}
+
+impl ListItem {
+    /// Create an item with `id` and main text `primary`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ListItem::new("inbox", "Inbox");
+    /// ```
+    pub fn new(id: impl Into<SharedString>, primary: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            primary: primary.into(),
+            secondary: None,
+            icon: None,
+            leading: None,
+            trailing: None,
+            disabled: false,
+        }
+    }
+
+    /// Set the supporting text
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ListItem::new("msg-1", "Quarterly report").secondary("Ada Lovelace · 2h ago");
+    /// ```
+    pub fn secondary(mut self, secondary: impl Into<SharedString>) -> Self {
+        self.secondary = Some(secondary.into());
+        self
+    }
+
+    /// Set a leading icon
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ListItem::new("inbox", "Inbox").icon(icons::MAIL);
+    /// ```
+    pub fn icon(mut self, path: impl Into<SharedString>) -> Self {
+        self.icon = Some(path.into());
+        self
+    }
+
+    /// Set custom leading content
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ListItem::new("task-1", "Write release notes").leading(|_window, cx| {
+    ///     Checkbox::new().checked(true).to_element(&use_theme(cx)).into_any_element()
+    /// });
+    /// ```
+    pub fn leading(mut self, leading: impl Fn(&mut Window, &mut App) -> AnyElement + 'static) -> Self {
+        self.leading = Some(Rc::new(leading));
+        self
+    }
+
+    /// Set trailing content
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ListItem::new("inbox", "Inbox").trailing(|_window, cx| {
+    ///     Chip::new("12").to_element(&use_theme(cx)).into_any_element()
+    /// });
+    /// ```
+    pub fn trailing(mut self, trailing: impl Fn(&mut Window, &mut App) -> AnyElement + 'static) -> Self {
+        self.trailing = Some(Rc::new(trailing));
+        self
+    }
+
+    /// Set whether the item is disabled
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ListItem::new("archive", "Archive").disabled(true);
+    /// ```
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// A group of list items under an optional header
+#[derive(Clone)]
+pub struct ListSection {
+    /// Header text, `None` for an untitled group
+    pub title: Option<SharedString>,
+    /// Items in the section
+    pub items: Vec<ListItem>,
+}
+
+/// A rendered row: a section header or an item
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ListRow {
+    /// Header of the section at this index
+    Header(usize),
+    /// Item at (section, item)
+    Item(usize, usize),
+}
+
+/// Flatten `sections` into rows, with a header row before each titled section
+fn flatten(sections: &[ListSection]) -> Vec<ListRow> {
+    let mut rows = Vec::new();
+    for (section_index, section) in sections.iter().enumerate() {
+        if section.title.is_some() {
+            rows.push(ListRow::Header(section_index));
+        }
+        rows.extend((0..section.items.len()).map(|item| ListRow::Item(section_index, item)));
+    }
+    rows
+}
+
+/// List configuration properties
+#[derive(Clone)]
+pub struct ListProps {
+    /// Item sections
+    pub sections: Vec<ListSection>,
+    /// Height of each row, including section headers, at comfortable density
+    ///
+    /// Scaled by the theme [`Density`](crate::theme::Density) when rendered.
+    pub item_height: Pixels,
+    /// Maximum height of the scrolling list
+    pub max_height: Pixels,
+    /// Message shown when there are no items
+    pub empty_message: SharedString,
+    /// Item selection behavior
+    pub selection_mode: ListSelectionMode,
+    /// Ids of the selected items
+    pub selected: Vec<SharedString>,
+    /// Handler called with the selected item ids when the selection changes
+    pub on_selection_change: Option<Rc<dyn Fn(Vec<SharedString>, &mut Window, &mut App)>>,
+    /// Whether the selection is owned by the parent view
+    ///
+    /// Controlled lists only report selections through
+    /// `on_selection_change`; the parent applies them with
+    /// [`List::set_selected`].
+    pub controlled: bool,
+    /// Handler called with an item's id when it is activated with Enter or a double click
+    pub on_activate: Option<Rc<dyn Fn(SharedString, &mut Window, &mut App)>>,
+}
+
+impl Default for ListProps {
+    fn default() -> Self {
+        Self {
+            sections: Vec::new(),
+            item_height: px(48.0),
+            max_height: px(400.0),
+            empty_message: "No items".into(),
+            selection_mode: ListSelectionMode::default(),
+            selected: Vec::new(),
+            on_selection_change: None,
+            controlled: false,
+            on_activate: None,
+        }
+    }
+}
+
+/// A virtualized list of items with optional section headers.
+///
+/// The building block for sidebars, inboxes and settings lists. Every row,
+/// section headers included, shares one height so long lists only render
+/// what is visible.
+///
+/// ## Features
+///
+/// - Leading icon or custom content, primary and secondary text, trailing content
+/// - Section headers
+/// - Hover and selected states, single or multiple selection
+/// - Keyboard navigation that skips headers and disabled items
+/// - Virtualized rows
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::organisms::*;
+///
+/// List::new()
+///     .section("Mailboxes", vec![
+///         ListItem::new("inbox", "Inbox").icon(icons::MAIL),
+///         ListItem::new("starred", "Starred").icon(icons::STAR),
+///     ])
+///     .section("Labels", vec![ListItem::new("work", "Work")])
+///     .default_selected(vec!["inbox".into()])
+///     .on_selection_change(|ids, _window, cx| open_mailbox(ids, cx));
+/// ```
+///
+/// ## Accessibility
+///
+/// - Up/Down move between enabled items, Home/End jump to the first/last
+/// - Space selects, Enter activates
+pub struct List {
+    props: ListProps,
+    /// Rows in display order
+    rows: Vec<ListRow>,
+    list_state: VirtualListState,
+    /// Row index of the keyboard-focused item
+    cursor: Option<usize>,
+    /// Row index that shift-click ranges extend from
+    anchor: Option<usize>,
+    focus_handle: Option<FocusHandle>,
+}
+
+impl List {
+    /// Create a new empty list
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let list = List::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            props: ListProps::default(),
+            rows: Vec::new(),
+            list_state: VirtualListState::new(),
+            cursor: None,
+            anchor: None,
+            focus_handle: None,
+        }
+    }
+
+    /// Add items without a section header
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// List::new().items(vec![ListItem::new("general", "General")]);
+    /// ```
+    pub fn items(mut self, items: Vec<ListItem>) -> Self {
+        self.props.sections.push(ListSection { title: None, items });
+        self.rows = flatten(&self.props.sections);
+        self
+    }
+
+    /// Add a section of items under a header
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// List::new().section("Labels", vec![ListItem::new("work", "Work")]);
+    /// ```
+    pub fn section(mut self, title: impl Into<SharedString>, items: Vec<ListItem>) -> Self {
+        self.props.sections.push(ListSection { title: Some(title.into()), items });
+        self.rows = flatten(&self.props.sections);
+        self
+    }
+
+    /// Replace the sections from the parent view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// list.update(cx, |list, cx| list.set_sections(sections, cx));
+    /// ```
+    pub fn set_sections(&mut self, sections: Vec<ListSection>, cx: &mut Context<'_, Self>) {
+        self.props.sections = sections;
+        self.rows = flatten(&self.props.sections);
+        self.cursor = None;
+        self.anchor = None;
+        cx.notify();
+    }
+
+    /// Set the height of each row
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// List::new().item_height(px(36.0));
+    /// ```
+    pub fn item_height(mut self, item_height: Pixels) -> Self {
+        self.props.item_height = item_height;
+        self
+    }
+
+    /// Set the maximum height of the scrolling list
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// List::new().max_height(px(600.0));
+    /// ```
+    pub fn max_height(mut self, max_height: Pixels) -> Self {
+        self.props.max_height = max_height;
+        self
+    }
+
+    /// Set the message shown when there are no items
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// List::new().empty_message("No messages");
+    /// ```
+    pub fn empty_message(mut self, message: impl Into<SharedString>) -> Self {
+        self.props.empty_message = message.into();
+        self
+    }
+
+    /// Set the item selection behavior
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// List::new().selection_mode(ListSelectionMode::Multiple);
+    /// ```
+    pub fn selection_mode(mut self, mode: ListSelectionMode) -> Self {
+        self.props.selection_mode = mode;
+        self
+    }
+
+    /// Set the selected item ids, making the selection controlled
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// List::new().selected(vec!["inbox".into()]);
+    /// ```
+    pub fn selected(mut self, selected: Vec<SharedString>) -> Self {
+        self.props.selected = selected;
+        self.props.controlled = true;
+        self
+    }
+
+    /// Set the initially selected item ids of an uncontrolled list
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// List::new().default_selected(vec!["inbox".into()]);
+    /// ```
+    pub fn default_selected(mut self, selected: Vec<SharedString>) -> Self {
+        self.props.selected = selected;
+        self.props.controlled = false;
+        self
+    }
+
+    /// Set the handler called when the selection changes
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// List::new().on_selection_change(|ids, _window, cx| open_mailbox(ids, cx));
+    /// ```
+    pub fn on_selection_change(
+        mut self,
+        handler: impl Fn(Vec<SharedString>, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_selection_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Update the selection from the parent view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// list.update(cx, |list, cx| list.set_selected(Vec::new(), cx));
+    /// ```
+    pub fn set_selected(&mut self, selected: Vec<SharedString>, cx: &mut Context<'_, Self>) {
+        if self.props.selected != selected {
+            self.props.selected = selected;
+            cx.notify();
+        }
+    }
+
+    /// Set the handler called when an item is activated with Enter or a double click
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// List::new().on_activate(|id, _window, cx| open_settings_page(id, cx));
+    /// ```
+    pub fn on_activate(mut self, handler: impl Fn(SharedString, &mut Window, &mut App) + 'static) -> Self {
+        self.props.on_activate = Some(Rc::new(handler));
+        self
+    }
+
+    /// Item shown at row `row`, if the row is an item
+    fn item_at(&self, row: usize) -> Option<&ListItem> {
+        match self.rows.get(row)? {
+            ListRow::Item(section, item) => self.props.sections[*section].items.get(*item),
+            ListRow::Header(_) => None,
+        }
+    }
+
+    /// Whether row `row` is an enabled item
+    fn is_focusable(&self, row: usize) -> bool {
+        self.item_at(row).is_some_and(|item| !item.disabled)
+    }
+
+    /// Row the cursor moves to for a navigation key, if any
+    fn navigation_target(&self, key: &str) -> Option<usize> {
+        let mut focusable = (0..self.rows.len()).filter(|&row| self.is_focusable(row));
+        match (key, self.cursor) {
+            ("down", Some(cursor)) => focusable.find(|&row| row > cursor).or(Some(cursor)),
+            ("up", Some(cursor)) => focusable.rev().find(|&row| row < cursor).or(Some(cursor)),
+            ("down" | "home", _) | ("up", None) => focusable.next(),
+            ("end", _) => focusable.next_back(),
+            _ => None,
+        }
+    }
+
+    /// Whether the item at row `row` is selected
+    fn is_selected(&self, row: usize) -> bool {
+        self.item_at(row).is_some_and(|item| self.props.selected.contains(&item.id))
+    }
+
+    /// Selection after choosing the item at row `row`
+    ///
+    /// `extend` selects the enabled items from the anchor row and `toggle`
+    /// adds or removes a single item; both only apply in multiple selection.
+    fn clicked_selection(&mut self, row: usize, extend: bool, toggle: bool) -> Vec<SharedString> {
+        let Some(id) = self.item_at(row).map(|item| item.id.clone()) else {
+            return self.props.selected.clone();
+        };
+        let multiple = self.props.selection_mode == ListSelectionMode::Multiple;
+
+        match self.anchor {
+            Some(anchor) if multiple && extend => {
+                return (anchor.min(row)..=anchor.max(row))
+                    .filter(|&r| self.is_focusable(r))
+                    .filter_map(|r| self.item_at(r).map(|item| item.id.clone()))
+                    .collect();
+            }
+            _ => {}
+        }
+
+        self.anchor = Some(row);
+        if multiple && toggle {
+            let mut selected = self.props.selected.clone();
+            if let Some(existing) = selected.iter().position(|s| *s == id) {
+                selected.remove(existing);
+            } else {
+                selected.push(id);
+            }
+            selected
+        } else {
+            vec![id]
+        }
+    }
+
+    /// Apply a new selection and notify the handler if it changed
+    fn change_selection(&mut self, selected: Vec<SharedString>, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.props.selected == selected {
+            return;
+        }
+
+        if !self.props.controlled {
+            self.set_selected(selected.clone(), cx);
+        }
+
+        if let Some(handler) = self.props.on_selection_change.clone() {
+            handler(selected, window, cx);
+        }
+    }
+
+    /// Activate the item at row `row`
+    fn activate(&mut self, row: usize, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let Some(id) = self.item_at(row).map(|item| item.id.clone()) else {
+            return;
+        };
+        if let Some(handler) = self.props.on_activate.clone() {
+            handler(id, window, cx);
+        }
+    }
+
+    /// Handle navigation, selection and activation keys
+    fn handle_key(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let keystroke = &event.keystroke;
+        if keystroke.modifiers.alt || keystroke.modifiers.control || keystroke.modifiers.platform {
+            return;
+        }
+
+        let key = keystroke.key.as_str();
+        let selectable = self.props.selection_mode != ListSelectionMode::None;
+        match (key, self.cursor) {
+            ("enter", Some(cursor)) => self.activate(cursor, window, cx),
+            ("space", Some(cursor)) if selectable => {
+                let selected = self.clicked_selection(cursor, keystroke.modifiers.shift, true);
+                self.change_selection(selected, window, cx);
+            }
+            _ => {
+                let Some(target) = self.navigation_target(key) else {
+                    return;
+                };
+                self.cursor = Some(target);
+                let item_height = use_theme(cx).density.scale(self.props.item_height);
+                self.list_state.scroll_to_item(target, item_height);
+                cx.notify();
+            }
+        }
+        cx.stop_propagation();
+    }
+
+    /// Render a section header row
+    fn render_header(&self, title: SharedString, theme: &Theme) -> Div {
+        div()
+            .size_full()
+            .flex()
+            .items_end()
+            .px(theme.global.spacing_md)
+            .pb(theme.global.spacing_xs)
+            .text_size(theme.global.font_size_xs)
+            .font_weight(FontWeight::SEMIBOLD)
+            .text_color(theme.alias.color_text_muted)
+            .child(title)
+    }
+
+    /// Render the item at row `row`
+    ///
+    /// `focused` shows a focus indicator on the cursor row.
+    fn render_item(
+        &self,
+        row: usize,
+        item: &ListItem,
+        theme: &Theme,
+        focused: bool,
+        window: &mut Window,
+        cx: &mut Context<'_, Self>,
+    ) -> Stateful<Div> {
+        let selectable = self.props.selection_mode != ListSelectionMode::None;
+        let mut element = div()
+            .id(("list-item", row))
+            .size_full()
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap(theme.global.spacing_sm)
+            .px(theme.global.spacing_md)
+            .border_l(px(2.0))
+            .border_color(hsla(0.0, 0.0, 0.0, 0.0));
+
+        if item.disabled {
+            element = element.opacity(0.5).cursor_not_allowed();
+        } else {
+            element = if self.is_selected(row) {
+                element.bg(theme.alias.color_primary.opacity(0.12))
+            } else {
+                element.hover(|style| style.bg(theme.alias.color_surface_hover))
+            };
+            element = element
+                .cursor_pointer()
+                .on_click(cx.listener(move |this, event: &ClickEvent, window, cx| {
+                    this.cursor = Some(row);
+                    cx.notify();
+                    if event.click_count() >= 2 {
+                        this.activate(row, window, cx);
+                    } else if selectable {
+                        let modifiers = event.modifiers();
+                        let toggle = modifiers.platform || modifiers.control;
+                        let selected = this.clicked_selection(row, modifiers.shift, toggle);
+                        this.change_selection(selected, window, cx);
+                    }
+                }));
+        }
+
+        if focused && self.cursor == Some(row) {
+            element = element.border_color(theme.alias.color_border_focus);
+        }
+
+        if let Some(leading) = &item.leading {
+            element = element.child(div().flex_none().child(leading(window, cx)));
+        } else if let Some(path) = &item.icon {
+            element = element.child(
+                Icon::new(path.clone())
+                    .size(IconSize::Md)
+                    .custom_color(theme.alias.color_text_secondary)
+                    .to_element(theme),
+            );
+        }
+
+        let mut text = div()
+            .flex()
+            .flex_col()
+            .flex_1()
+            .min_w_0()
+            .child(
+                div()
+                    .truncate()
+                    .text_size(theme.global.font_size_sm)
+                    .text_color(theme.alias.color_text_primary)
+                    .child(item.primary.clone()),
+            );
+        if let Some(secondary) = &item.secondary {
+            text = text.child(
+                div()
+                    .truncate()
+                    .text_size(theme.global.font_size_xs)
+                    .text_color(theme.alias.color_text_secondary)
+                    .child(secondary.clone()),
+            );
+        }
+        element = element.child(text);
+
+        if let Some(trailing) = &item.trailing {
+            element = element.child(div().flex_none().child(trailing(window, cx)));
+        }
+
+        element
+    }
+}
+
+impl Default for List {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Render for List {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        let focus_handle = self
+            .focus_handle
+            .get_or_insert_with(|| cx.focus_handle())
+            .clone();
+        let focused = focus_handle.is_focused(window);
+        let item_height = theme.density.scale(self.props.item_height);
+
+        let body = if self.rows.is_empty() {
+            div()
+                .p(theme.global.spacing_lg)
+                .text_color(theme.alias.color_text_muted)
+                .child(self.props.empty_message.clone())
+        } else {
+            div().child(
+                VirtualList::new("list-body", self.rows.len(), item_height, &self.list_state)
+                    .max_height(self.props.max_height)
+                    .to_element(|row| match self.rows[row] {
+                        ListRow::Header(section) => {
+                            let title = self.props.sections[section].title.clone().unwrap_or_default();
+                            self.render_header(title, &theme).into_any_element()
+                        }
+                        ListRow::Item(section, item) => {
+                            let item = &self.props.sections[section].items[item];
+                            self.render_item(row, item, &theme, focused, window, cx).into_any_element()
+                        }
+                    }),
+            )
+        };
+
+        div()
+            .id("list")
+            .track_focus(&focus_handle)
+            .on_key_down(cx.listener(Self::handle_key))
+            .w_full()
+            .child(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Rows: 0 = header A, 1 = a1, 2 = a2 (disabled), 3 = header B, 4 = b1
+    fn list() -> List {
+        List::new()
+            .section("A", vec![ListItem::new("a1", "A1"), ListItem::new("a2", "A2").disabled(true)])
+            .section("B", vec![ListItem::new("b1", "B1")])
+            .selection_mode(ListSelectionMode::Multiple)
+    }
+
+    fn ids(ids: &[&'static str]) -> Vec<SharedString> {
+        ids.iter().map(|&id| id.into()).collect()
+    }
+
+    #[test]
+    fn test_flatten_sections() {
+        let list = list().items(vec![ListItem::new("c1", "C1")]);
+        assert_eq!(
+            list.rows,
+            vec![
+                ListRow::Header(0),
+                ListRow::Item(0, 0),
+                ListRow::Item(0, 1),
+                ListRow::Header(1),
+                ListRow::Item(1, 0),
+                ListRow::Item(2, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_navigation_skips_headers_and_disabled() {
+        let mut list = list();
+        assert_eq!(list.navigation_target("down"), Some(1));
+        assert_eq!(list.navigation_target("end"), Some(4));
+
+        list.cursor = Some(1);
+        assert_eq!(list.navigation_target("down"), Some(4));
+        assert_eq!(list.navigation_target("up"), Some(1));
+
+        list.cursor = Some(4);
+        assert_eq!(list.navigation_target("up"), Some(1));
+        assert_eq!(list.navigation_target("home"), Some(1));
+    }
+
+    #[test]
+    fn test_selection() {
+        let mut list = list();
+        assert_eq!(list.clicked_selection(1, false, false), ids(&["a1"]));
+        assert_eq!(list.clicked_selection(4, true, false), ids(&["a1", "b1"]));
+
+        list.props.selected = ids(&["a1"]);
+        assert_eq!(list.clicked_selection(4, false, true), ids(&["a1", "b1"]));
+        // Headers cannot be selected
+        assert_eq!(list.clicked_selection(0, false, false), ids(&["a1"]));
+    }
+
+    #[test]
+    fn test_list_defaults() {
+        let list = List::new();
+        assert!(list.rows.is_empty());
+        assert_eq!(list.props.selection_mode, ListSelectionMode::Single);
+        assert!(!list.props.controlled);
+    }
+}
@@ -0,0 +1,694 @@
+//! Markdown component rendering CommonMark documents.
+
+use std::{ops::Range, rc::Rc};
+
+use gpui::*;
+use pulldown_cmark::{Alignment, CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use crate::{
+    atoms::MONOSPACE_FONT,
+    theme::{use_theme, LabelTokens, Theme},
+};
+
+/// Highlights the code of a fenced block, given its text and info-string language
+///
+/// Returns byte ranges of the code and the style to draw each with.
+pub type MarkdownHighlighter = Rc<dyn Fn(&str, Option<&str>) -> Vec<(Range<usize>, HighlightStyle)>>;
+
+/// Emphasis applied to a run of inline text
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct InlineStyle {
+    strong: bool,
+    emphasis: bool,
+    strikethrough: bool,
+    code: bool,
+}
+
+/// A styled or linked range of an [`Inline`]
+#[derive(Debug, Clone, PartialEq)]
+struct InlineRun {
+    range: Range<usize>,
+    style: InlineStyle,
+    link: Option<SharedString>,
+}
+
+/// Text of a paragraph, heading or table cell with its styled ranges
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Inline {
+    text: String,
+    runs: Vec<InlineRun>,
+}
+
+impl Inline {
+    /// Append `text` drawn with `style`, linking to `link` if set
+    fn push(&mut self, text: &str, style: InlineStyle, link: Option<&SharedString>) {
+        let start = self.text.len();
+        self.text.push_str(text);
+        if style != InlineStyle::default() || link.is_some() {
+            self.runs.push(InlineRun {
+                range: start..self.text.len(),
+                style,
+                link: link.cloned(),
+            });
+        }
+    }
+}
+
+/// A block of a parsed document
+#[derive(Debug, Clone, PartialEq)]
+enum Block {
+    Heading { level: u8, text: Inline },
+    Paragraph(Inline),
+    List { start: Option<u64>, items: Vec<Vec<Block>> },
+    CodeBlock { language: Option<String>, code: String },
+    BlockQuote(Vec<Block>),
+    Table { alignments: Vec<Alignment>, header: Vec<Inline>, rows: Vec<Vec<Inline>> },
+    Rule,
+}
+
+/// A container being filled while parsing
+enum Frame {
+    Blocks(Vec<Block>),
+    Quote(Vec<Block>),
+    List { start: Option<u64>, items: Vec<Vec<Block>> },
+    Item(Vec<Block>),
+}
+
+/// Table being filled while parsing
+#[derive(Default)]
+struct TableBuilder {
+    alignments: Vec<Alignment>,
+    header: Vec<Inline>,
+    rows: Vec<Vec<Inline>>,
+    row: Vec<Inline>,
+}
+
+/// Builds blocks from the parser's event stream
+struct DocumentBuilder {
+    frames: Vec<Frame>,
+    inline: Option<Inline>,
+    heading: Option<u8>,
+    code: Option<(Option<String>, String)>,
+    table: Option<TableBuilder>,
+    style: InlineStyle,
+    link: Option<SharedString>,
+}
+
+impl DocumentBuilder {
+    fn new() -> Self {
+        Self {
+            frames: vec![Frame::Blocks(Vec::new())],
+            inline: None,
+            heading: None,
+            code: None,
+            table: None,
+            style: InlineStyle::default(),
+            link: None,
+        }
+    }
+
+    /// Add a finished block to the innermost container
+    fn push_block(&mut self, block: Block) {
+        match self.frames.last_mut() {
+            Some(Frame::Blocks(blocks) | Frame::Quote(blocks) | Frame::Item(blocks)) => blocks.push(block),
+            // Lists only hold items; stray blocks start one
+            Some(Frame::List { items, .. }) => items.push(vec![block]),
+            None => {}
+        }
+    }
+
+    /// Finish text outside a paragraph, as in the items of tight lists
+    fn flush_text(&mut self) {
+        if self.table.is_none() {
+            if let Some(inline) = self.inline.take() {
+                self.push_block(Block::Paragraph(inline));
+            }
+        }
+    }
+
+    /// Append inline text with the current style
+    fn push_text(&mut self, text: &str, style: InlineStyle) {
+        if let Some((_, code)) = &mut self.code {
+            code.push_str(text);
+            return;
+        }
+        self.inline.get_or_insert_with(Inline::default).push(text, style, self.link.as_ref());
+    }
+
+    fn start(&mut self, tag: Tag<'_>) {
+        match tag {
+            Tag::Paragraph => {
+                self.flush_text();
+                self.inline = Some(Inline::default());
+            }
+            Tag::Heading { level, .. } => {
+                self.flush_text();
+                self.heading = Some(heading_level(level));
+                self.inline = Some(Inline::default());
+            }
+            Tag::BlockQuote(_) => {
+                self.flush_text();
+                self.frames.push(Frame::Quote(Vec::new()));
+            }
+            Tag::CodeBlock(kind) => {
+                self.flush_text();
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => info
+                        .split(|c: char| c.is_whitespace() || c == ',')
+                        .next()
+                        .filter(|language| !language.is_empty())
+                        .map(ToString::to_string),
+                    CodeBlockKind::Indented => None,
+                };
+                self.code = Some((language, String::new()));
+            }
+            Tag::List(start) => {
+                self.flush_text();
+                self.frames.push(Frame::List { start, items: Vec::new() });
+            }
+            Tag::Item => self.frames.push(Frame::Item(Vec::new())),
+            Tag::Table(alignments) => {
+                self.flush_text();
+                self.table = Some(TableBuilder { alignments, ..TableBuilder::default() });
+            }
+            Tag::TableCell => self.inline = Some(Inline::default()),
+            Tag::Emphasis => self.style.emphasis = true,
+            Tag::Strong => self.style.strong = true,
+            Tag::Strikethrough => self.style.strikethrough = true,
+            Tag::Link { dest_url, .. } => self.link = Some(SharedString::from(dest_url.to_string())),
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => {
+                if let Some(inline) = self.inline.take() {
+                    self.push_block(Block::Paragraph(inline));
+                }
+            }
+            TagEnd::Heading(_) => {
+                let text = self.inline.take().unwrap_or_default();
+                let level = self.heading.take().unwrap_or(1);
+                self.push_block(Block::Heading { level, text });
+            }
+            TagEnd::BlockQuote(_) => {
+                self.flush_text();
+                if let Some(Frame::Quote(blocks)) = self.frames.pop() {
+                    self.push_block(Block::BlockQuote(blocks));
+                }
+            }
+            TagEnd::CodeBlock => {
+                if let Some((language, mut code)) = self.code.take() {
+                    if code.ends_with('\n') {
+                        code.pop();
+                    }
+                    self.push_block(Block::CodeBlock { language, code });
+                }
+            }
+            TagEnd::List(_) => {
+                if let Some(Frame::List { start, items }) = self.frames.pop() {
+                    self.push_block(Block::List { start, items });
+                }
+            }
+            TagEnd::Item => {
+                self.flush_text();
+                if let Some(Frame::Item(blocks)) = self.frames.pop() {
+                    if let Some(Frame::List { items, .. }) = self.frames.last_mut() {
+                        items.push(blocks);
+                    }
+                }
+            }
+            TagEnd::TableCell => {
+                let cell = self.inline.take().unwrap_or_default();
+                if let Some(table) = &mut self.table {
+                    table.row.push(cell);
+                }
+            }
+            TagEnd::TableHead => {
+                if let Some(table) = &mut self.table {
+                    table.header = std::mem::take(&mut table.row);
+                }
+            }
+            TagEnd::TableRow => {
+                if let Some(table) = &mut self.table {
+                    let row = std::mem::take(&mut table.row);
+                    table.rows.push(row);
+                }
+            }
+            TagEnd::Table => {
+                if let Some(table) = self.table.take() {
+                    self.push_block(Block::Table {
+                        alignments: table.alignments,
+                        header: table.header,
+                        rows: table.rows,
+                    });
+                }
+            }
+            TagEnd::Emphasis => self.style.emphasis = false,
+            TagEnd::Strong => self.style.strong = false,
+            TagEnd::Strikethrough => self.style.strikethrough = false,
+            TagEnd::Link => self.link = None,
+            _ => {}
+        }
+    }
+
+    fn event(&mut self, event: Event<'_>) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => self.push_text(&text, self.style),
+            Event::Code(code) => self.push_text(&code, InlineStyle { code: true, ..self.style }),
+            Event::InlineHtml(html) => self.push_text(&html, self.style),
+            Event::SoftBreak => self.push_text(" ", self.style),
+            Event::HardBreak => self.push_text("\n", self.style),
+            Event::Rule => {
+                self.flush_text();
+                self.push_block(Block::Rule);
+            }
+            // Raw HTML blocks, footnotes and task markers are not rendered
+            _ => {}
+        }
+    }
+
+    fn finish(mut self) -> Vec<Block> {
+        self.flush_text();
+        match self.frames.into_iter().next() {
+            Some(Frame::Blocks(blocks)) => blocks,
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Heading level as a number from 1 to 6
+fn heading_level(level: HeadingLevel) -> u8 {
+    match level {
+        HeadingLevel::H1 => 1,
+        HeadingLevel::H2 => 2,
+        HeadingLevel::H3 => 3,
+        HeadingLevel::H4 => 4,
+        HeadingLevel::H5 => 5,
+        HeadingLevel::H6 => 6,
+    }
+}
+
+/// Parse a CommonMark document with tables and strikethrough
+fn parse(source: &str) -> Vec<Block> {
+    let mut builder = DocumentBuilder::new();
+    for event in Parser::new_ext(source, Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH) {
+        builder.event(event);
+    }
+    builder.finish()
+}
+
+/// Markdown configuration properties
+#[derive(Clone)]
+pub struct MarkdownProps {
+    /// CommonMark source
+    pub source: SharedString,
+    /// Handler called with the destination of a clicked link
+    pub on_link_click: Option<Rc<dyn Fn(SharedString, &mut Window, &mut App)>>,
+    /// Highlighter for fenced code blocks
+    pub highlighter: Option<MarkdownHighlighter>,
+}
+
+impl Default for MarkdownProps {
+    fn default() -> Self {
+        Self {
+            source: "".into(),
+            on_link_click: None,
+            highlighter: None,
+        }
+    }
+}
+
+/// A rendered CommonMark document.
+///
+/// Markdown renders the text of chat messages, release notes and help pages
+/// with the theme's typography.
+///
+/// ## Features
+///
+/// - Headings, paragraphs, block quotes and thematic breaks
+/// - Bulleted and numbered lists, nested to any depth
+/// - Strong, emphasis, strikethrough, inline code and links
+/// - Fenced code blocks with a pluggable syntax highlighter
+/// - Tables with column alignment
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::organisms::*;
+///
+/// let reply = cx.new(|_| {
+///     Markdown::new("Run `cargo build` first.\n\nSee the [guide](https://example.com/guide).")
+///         .on_link_click(|url, _window, cx| cx.open_url(&url))
+/// });
+///
+/// // Stream more text into the message as it arrives
+/// reply.update(cx, |reply, cx| reply.set_source(text, cx));
+/// ```
+///
+/// ## Accessibility
+///
+/// - Links are underlined as well as colored
+/// - Heading sizes follow the label typography scale
+pub struct Markdown {
+    props: MarkdownProps,
+    /// Blocks parsed from the source
+    blocks: Rc<Vec<Block>>,
+}
+
+impl Markdown {
+    /// Create a document from CommonMark `source`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let notes = Markdown::new(include_str!("../CHANGELOG.md"));
+    /// ```
+    pub fn new(source: impl Into<SharedString>) -> Self {
+        let source = source.into();
+        Self {
+            blocks: Rc::new(parse(&source)),
+            props: MarkdownProps {
+                source,
+                ..MarkdownProps::default()
+            },
+        }
+    }
+
+    /// Set the handler called with the destination of a clicked link
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Markdown::new(source).on_link_click(|url, _window, cx| cx.open_url(&url));
+    /// ```
+    pub fn on_link_click(mut self, handler: impl Fn(SharedString, &mut Window, &mut App) + 'static) -> Self {
+        self.props.on_link_click = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the highlighter for fenced code blocks
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Markdown::new(source).highlighter(move |code, language| syntax.highlight(code, language));
+    /// ```
+    pub fn highlighter(
+        mut self,
+        highlighter: impl Fn(&str, Option<&str>) -> Vec<(Range<usize>, HighlightStyle)> + 'static,
+    ) -> Self {
+        self.props.highlighter = Some(Rc::new(highlighter));
+        self
+    }
+
+    /// CommonMark source being rendered
+    pub fn source(&self) -> &SharedString {
+        &self.props.source
+    }
+
+    /// Replace the source from the parent view, for example while streaming
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// message.update(cx, |message, cx| message.set_source(text, cx));
+    /// ```
+    pub fn set_source(&mut self, source: impl Into<SharedString>, cx: &mut Context<'_, Self>) {
+        let source = source.into();
+        if source == self.props.source {
+            return;
+        }
+        self.blocks = Rc::new(parse(&source));
+        self.props.source = source;
+        cx.notify();
+    }
+
+    /// Convert to a GPUI element for embedding inside another component's render
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// bubble.child(Markdown::new(message.text.clone()).to_element(&theme));
+    /// ```
+    pub fn to_element(&self, theme: &Theme) -> Div {
+        let mut ids = 0;
+        self.render_blocks(&self.blocks, theme, &mut ids)
+            .text_size(theme.alias.font_size_body)
+            .text_color(theme.alias.color_text_primary)
+    }
+
+    /// Stack of `blocks`; `ids` numbers the interactive text elements
+    fn render_blocks(&self, blocks: &[Block], theme: &Theme, ids: &mut usize) -> Div {
+        div()
+            .flex()
+            .flex_col()
+            .gap(theme.global.spacing_md)
+            .children(blocks.iter().map(|block| self.render_block(block, theme, ids)))
+    }
+
+    fn render_block(&self, block: &Block, theme: &Theme, ids: &mut usize) -> AnyElement {
+        match block {
+            Block::Heading { level, text } => {
+                let tokens = LabelTokens::from_theme(theme);
+                let (size, weight) = match level {
+                    1 => (tokens.font_size_heading_1, tokens.font_weight_heading_1),
+                    2 => (tokens.font_size_heading_2, tokens.font_weight_heading_2),
+                    3 => (tokens.font_size_heading_3, tokens.font_weight_heading_3),
+                    _ => (tokens.font_size_body, tokens.font_weight_heading_3),
+                };
+                div()
+                    .text_size(size)
+                    .font_weight(weight)
+                    .child(self.render_inline(text, theme, ids))
+                    .into_any_element()
+            }
+            Block::Paragraph(text) => div().child(self.render_inline(text, theme, ids)).into_any_element(),
+            Block::List { start, items } => {
+                let rows = items.iter().enumerate().map(|(index, item)| {
+                    let marker = match start {
+                        Some(start) => format!("{}.", *start + index as u64),
+                        None => "•".to_string(),
+                    };
+                    div()
+                        .flex()
+                        .flex_row()
+                        .gap(theme.global.spacing_sm)
+                        .child(
+                            div()
+                                .flex_none()
+                                .min_w(theme.global.spacing_md)
+                                .text_color(theme.alias.color_text_secondary)
+                                .child(marker),
+                        )
+                        .child(self.render_blocks(item, theme, ids).flex_1().min_w(px(0.0)).gap(theme.global.spacing_xs))
+                });
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap(theme.global.spacing_xs)
+                    .children(rows.collect::<Vec<_>>())
+                    .into_any_element()
+            }
+            Block::CodeBlock { language, code } => {
+                *ids += 1;
+                let highlights = self
+                    .props
+                    .highlighter
+                    .as_ref()
+                    .map(|highlight| highlight(code, language.as_deref()))
+                    .unwrap_or_default();
+                div()
+                    .id(("markdown-code", *ids))
+                    .overflow_x_scroll()
+                    .p(theme.global.spacing_md)
+                    .rounded(theme.global.radius_md)
+                    .bg(theme.alias.color_surface_hover)
+                    .font_family(MONOSPACE_FONT)
+                    .text_size(theme.global.font_size_sm)
+                    .whitespace_nowrap()
+                    .child(StyledText::new(code.clone()).with_highlights(highlights))
+                    .into_any_element()
+            }
+            Block::BlockQuote(blocks) => self
+                .render_blocks(blocks, theme, ids)
+                .pl(theme.global.spacing_md)
+                .border_l(px(3.0))
+                .border_color(theme.alias.color_border)
+                .text_color(theme.alias.color_text_secondary)
+                .into_any_element(),
+            Block::Table { alignments, header, rows } => {
+                let row = |cells: &[Inline], ids: &mut usize| {
+                    div().flex().flex_row().children(
+                        cells
+                            .iter()
+                            .enumerate()
+                            .map(|(column, cell)| {
+                                let cell_div = div()
+                                    .flex()
+                                    .flex_1()
+                                    .min_w(px(0.0))
+                                    .px(theme.global.spacing_sm)
+                                    .py(theme.global.spacing_xs);
+                                let cell_div = match alignments.get(column) {
+                                    Some(Alignment::Center) => cell_div.justify_center(),
+                                    Some(Alignment::Right) => cell_div.justify_end(),
+                                    _ => cell_div,
+                                };
+                                cell_div.child(self.render_inline(cell, theme, ids))
+                            })
+                            .collect::<Vec<_>>(),
+                    )
+                };
+                let mut table = div()
+                    .flex()
+                    .flex_col()
+                    .border(px(1.0))
+                    .border_color(theme.alias.color_border)
+                    .rounded(theme.global.radius_sm)
+                    .overflow_hidden()
+                    .child(
+                        row(header, ids)
+                            .bg(theme.alias.color_surface_hover)
+                            .font_weight(FontWeight(f32::from(theme.global.font_weight_semibold))),
+                    );
+                for cells in rows {
+                    table = table.child(row(cells, ids).border_t(px(1.0)).border_color(theme.alias.color_border));
+                }
+                table.into_any_element()
+            }
+            Block::Rule => div().h(px(1.0)).w_full().bg(theme.alias.color_border).into_any_element(),
+        }
+    }
+
+    /// Styled text of `inline`, with clickable links when a handler is set
+    fn render_inline(&self, inline: &Inline, theme: &Theme, ids: &mut usize) -> AnyElement {
+        let highlights: Vec<(Range<usize>, HighlightStyle)> = inline
+            .runs
+            .iter()
+            .map(|run| (run.range.clone(), run_style(run, theme)))
+            .collect();
+        let text = StyledText::new(inline.text.clone()).with_highlights(highlights);
+
+        let links: Vec<(Range<usize>, SharedString)> = inline
+            .runs
+            .iter()
+            .filter_map(|run| Some((run.range.clone(), run.link.clone()?)))
+            .collect();
+        let Some(handler) = self.props.on_link_click.clone().filter(|_| !links.is_empty()) else {
+            return text.into_any_element();
+        };
+
+        *ids += 1;
+        let (ranges, urls): (Vec<_>, Vec<_>) = links.into_iter().unzip();
+        InteractiveText::new(("markdown-text", *ids), text)
+            .on_click(ranges, move |index, window, cx| handler(urls[index].clone(), window, cx))
+            .into_any_element()
+    }
+}
+
+/// Highlight for a styled or linked run of text
+fn run_style(run: &InlineRun, theme: &Theme) -> HighlightStyle {
+    let mut style = HighlightStyle::default();
+    if run.style.strong {
+        style.font_weight = Some(FontWeight(f32::from(theme.global.font_weight_bold)));
+    }
+    if run.style.emphasis {
+        style.font_style = Some(FontStyle::Italic);
+    }
+    if run.style.strikethrough {
+        style.strikethrough = Some(StrikethroughStyle { thickness: px(1.0), color: None });
+    }
+    if run.style.code {
+        style.background_color = Some(theme.alias.color_surface_hover);
+    }
+    if run.link.is_some() {
+        style.color = Some(theme.alias.color_primary);
+        style.underline = Some(UnderlineStyle {
+            thickness: px(1.0),
+            color: Some(theme.alias.color_primary),
+            wavy: false,
+        });
+    }
+    style
+}
+
+impl Render for Markdown {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        self.to_element(&theme)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paragraph(blocks: &[Block]) -> &Inline {
+        match blocks {
+            [Block::Paragraph(inline)] => inline,
+            other => panic!("expected one paragraph, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_inline_runs() {
+        let blocks = parse("Some **bold**, `code` and [a link](https://example.com).");
+        let inline = paragraph(&blocks);
+        assert_eq!(inline.text, "Some bold, code and a link.");
+
+        let bold = &inline.runs[0];
+        assert_eq!(&inline.text[bold.range.clone()], "bold");
+        assert!(bold.style.strong);
+        assert!(inline.runs[1].style.code);
+
+        let link = &inline.runs[2];
+        assert_eq!(&inline.text[link.range.clone()], "a link");
+        assert_eq!(link.link.as_ref().map(AsRef::as_ref), Some("https://example.com"));
+    }
+
+    #[test]
+    fn test_headings_rules_and_quotes() {
+        let blocks = parse("# Title\n\n---\n\n> quoted\n\n### Small");
+        assert!(matches!(&blocks[0], Block::Heading { level: 1, text } if text.text == "Title"));
+        assert_eq!(blocks[1], Block::Rule);
+        assert!(matches!(&blocks[2], Block::BlockQuote(inner) if paragraph(inner).text == "quoted"));
+        assert!(matches!(&blocks[3], Block::Heading { level: 3, .. }));
+    }
+
+    #[test]
+    fn test_nested_lists() {
+        let blocks = parse("3. one\n4. two\n   - inner\n");
+        let Block::List { start, items } = &blocks[0] else {
+            panic!("expected a list, got {blocks:?}");
+        };
+        assert_eq!(*start, Some(3));
+        assert_eq!(items.len(), 2);
+        assert_eq!(paragraph(&items[0]).text, "one");
+        assert!(matches!(&items[1][1], Block::List { start: None, items } if items.len() == 1));
+    }
+
+    #[test]
+    fn test_code_block_language() {
+        let blocks = parse("```rust,ignore\nfn main() {}\n```\n\n    indented\n");
+        assert_eq!(
+            blocks[0],
+            Block::CodeBlock { language: Some("rust".into()), code: "fn main() {}".into() }
+        );
+        assert_eq!(blocks[1], Block::CodeBlock { language: None, code: "indented".into() });
+    }
+
+    #[test]
+    fn test_table() {
+        let blocks = parse("| Name | Size |\n| :--- | ---: |\n| a | 1 |\n| b | 2 |\n");
+        let Block::Table { alignments, header, rows } = &blocks[0] else {
+            panic!("expected a table, got {blocks:?}");
+        };
+        assert_eq!(alignments, &vec![Alignment::Left, Alignment::Right]);
+        assert_eq!(header[1].text, "Size");
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1][0].text, "b");
+    }
+}
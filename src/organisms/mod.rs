@@ -6,9 +6,18 @@
 //! ## Available Organisms
 //!
 //! - [`Dialog`]: Modal dialog with overlay and focus management
+//! - [`ConfirmDialog`]: Confirmation dialog for destructive actions
 //! - [`Drawer`]: Side panel drawer with slide-in animation
-//! - [`Table`]: Data table with sortable columns
-//! - [`CommandPalette`]: Searchable command interface
+//! - [`Table`]: Typed data table with sorting, selection, pagination, editing and tree rows
+//! - [`CommandPalette`]: Searchable command interface with fuzzy matching
+//! - [`ToastManager`]: Stacked toast notifications with auto-dismiss
+//! - [`TreeView`]: Hierarchical data with lazy loading, selection and drag-to-reorder hooks
+//! - [`List`]: Virtualized list of items with sections, selection and keyboard navigation
+//! - [`AppShell`]: Header, sidebar, content and footer layout that collapses the sidebar on narrow windows
+//! - [`FloatingPanels`]: Draggable, resizable, snapping tool panels floating over the app
+//! - [`Dock`]: Tabbed panel groups that drag between dock zones, with a savable layout tree
+//! - [`Markdown`]: CommonMark documents with headings, lists, code blocks, tables and links
+//! - [`CodeBlock`]: Read-only code with line numbers, highlighting, wrapping, selection and copy
 //!
 //! ## Example
 //!
@@ -28,23 +37,58 @@
 //!     .open(true);
 //!
 //! // Table
-//! Table::new()
+//! Table::<User>::new()
 //!     .columns(vec![
-//!         TableColumn { header: "Name".into(), width: Some(px(200.0)) },
-//!     ]);
+//!         TableColumn::new("Name").width(px(200.0)).cell(|user: &User| user.name.clone()),
+//!     ])
+//!     .rows(users);
 //!
 //! // Command Palette
 //! CommandPalette::new()
-//!     .commands(vec![Command { label: "Open".into(), description: None }])
+//!     .commands(vec![Command::new("Open").action(|window, cx| open_file(window, cx))])
 //!     .open(true);
+//!
+//! // Toasts
+//! toasts.update(cx, |toasts, cx| {
+//!     toasts.toast_success("Settings saved", cx);
+//! });
 //! ```
 
 pub mod dialog;
+pub mod confirm_dialog;
 pub mod drawer;
 pub mod table;
 pub mod command_palette;
+pub mod toast;
+pub mod tree_view;
+pub mod list;
+pub mod app_shell;
+pub mod floating_panel;
+pub mod dock;
+pub mod markdown;
+pub mod code_block;
 
-pub use dialog::{Dialog, DialogProps};
+pub use dialog::{Dialog, DialogProps, DialogSize, DialogSlot};
+pub use confirm_dialog::{ConfirmDialog, ConfirmDialogProps, ConfirmResult};
 pub use drawer::{Drawer, DrawerPosition, DrawerProps};
-pub use table::{Table, TableColumn, TableProps};
-pub use command_palette::{Command, CommandPalette, CommandPaletteProps};
+pub use table::{
+    RowId, SortDirection, Table, TableCellEditor, TableCellRenderer, TableChildLoader, TableColumn,
+    TableComparator, TableLayout, TableProps, TableSelectionMode,
+};
+pub use command_palette::{
+    Command, CommandAction, CommandHistory, CommandPalette, CommandPaletteProps, CommandUse,
+    ToggleCommandPalette, TOGGLE_COMMAND_PALETTE_KEY, TOGGLE_COMMAND_PALETTE_SHORTCUT,
+};
+pub use toast::{Toast, ToastAction, ToastId, ToastKind, ToastManager, ToastPlacement};
+pub use tree_view::{
+    TreeChildLoader, TreeDropPosition, TreeIcon, TreeMove, TreeNodeId, TreeSelectionMode, TreeView,
+    TreeViewProps,
+};
+pub use list::{List, ListItem, ListProps, ListSection, ListSelectionMode, ListSlot};
+pub use app_shell::{AppShell, AppShellProps, AppShellSlot};
+pub use floating_panel::{FloatingPanel, FloatingPanelSlot, FloatingPanels, FloatingPanelsProps};
+pub use dock::{Dock, DockFloating, DockLayout, DockNode, DockPanel, DockProps, DockSlot, DockZone};
+pub use markdown::{Markdown, MarkdownHighlighter, MarkdownProps};
+pub use code_block::{CodeBlock, CodeBlockProps, CodeHighlighter};
+#[cfg(feature = "syntect")]
+pub use code_block::syntect_highlighter;
@@ -1,109 +1,2431 @@
 //! Table component for data display.
 
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::rc::Rc;
+
 use gpui::*;
-use crate::{atoms::Label, theme::Theme};
+use gpui::prelude::FluentBuilder;
+use crate::{
+    atoms::{Checkbox, CheckboxState, Icon, IconSize, Label, Spinner, SpinnerSize, icons},
+    layout::{VirtualList, VirtualListState},
+    molecules::pagination::{page_button, page_items},
+    theme::{use_theme, InputTokens, Theme},
+    utils::{motion::reduced_motion, Announcer, RovingFocus, RovingOrientation},
+};
 
-/// Table column definition
+/// Renders the cell of a column for one row
+pub type TableCellRenderer<T> = Rc<dyn Fn(&T) -> AnyElement>;
+
+/// Orders two rows for a sortable column
+pub type TableComparator<T> = Rc<dyn Fn(&T, &T) -> Ordering>;
+
+/// Stable identifier of a table row, used to report selections
+pub type RowId = SharedString;
+
+/// Renders the editor of a cell being edited from the text typed so far
+pub type TableCellEditor = Rc<dyn Fn(&str, &Theme) -> AnyElement>;
+
+/// A cell being edited inline
+struct CellEdit {
+    /// Index of the row in `rows`
+    index: usize,
+    /// Column index
+    column: usize,
+    /// Value when editing started
+    original: SharedString,
+    /// Text typed so far
+    text: String,
+}
+
+/// Loads the children of a tree table row on first expansion
+pub type TableChildLoader<T> = Rc<dyn Fn(&T, &mut App) -> Task<Vec<T>>>;
+
+/// Position of a row in a tree table
+#[derive(Debug, Clone, Default)]
+struct TreeNode {
+    /// Index of the parent row in `rows`
+    parent: Option<usize>,
+    /// Nesting level, zero for top-level rows
+    depth: usize,
+    /// Indices of the child rows in `rows`, `None` until loaded
+    children: Option<Vec<usize>>,
+}
+
+/// Column order and widths of a table, for persisting user layout changes
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TableLayout {
+    /// Column indices in display order
+    pub order: Vec<usize>,
+    /// Width of each column by column index, `None` for flexible columns
+    pub widths: Vec<Option<Pixels>>,
+}
+
+/// Drag payload for resizing a column by its header's right edge
+#[derive(Clone)]
+struct ColumnResize {
+    column: usize,
+}
+
+impl Render for ColumnResize {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
+        Empty
+    }
+}
+
+/// Drag payload for reordering a column by its header
 #[derive(Clone)]
-pub struct TableColumn {
+struct ColumnMove {
+    column: usize,
+    header: SharedString,
+}
+
+impl Render for ColumnMove {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        div()
+            .px(theme.global.spacing_sm)
+            .py(theme.global.spacing_xs)
+            .bg(theme.alias.color_surface_elevated)
+            .border_color(theme.alias.color_border_focus)
+            .border(px(1.0))
+            .rounded(theme.global.radius_sm)
+            .shadow_md()
+            .text_size(theme.global.font_size_sm)
+            .text_color(theme.alias.color_text_primary)
+            .child(self.header.clone())
+    }
+}
+
+/// Width change of one keyboard resize step
+const RESIZE_STEP: f32 = 16.0;
+
+/// Table row selection behavior
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TableSelectionMode {
+    /// Rows cannot be selected
+    #[default]
+    None,
+    /// At most one row is selected
+    Single,
+    /// Any number of rows, with shift-click ranges and cmd/ctrl-click toggling
+    Multiple,
+}
+
+/// Table sort direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortDirection {
+    /// Smallest first
+    #[default]
+    Ascending,
+    /// Largest first
+    Descending,
+}
+
+impl SortDirection {
+    /// The opposite direction
+    pub fn reversed(self) -> Self {
+        match self {
+            SortDirection::Ascending => SortDirection::Descending,
+            SortDirection::Descending => SortDirection::Ascending,
+        }
+    }
+}
+
+/// Table column definition
+pub struct TableColumn<T> {
     /// Column header text
     pub header: SharedString,
     /// Column width
     pub width: Option<Pixels>,
+    /// Cell renderer, leaving the cell empty when unset
+    pub cell: Option<TableCellRenderer<T>>,
+    /// Whether clicking the header sorts by this column
+    pub sortable: bool,
+    /// Comparator used to sort rows locally
+    ///
+    /// Sortable columns without one only report sorting through `on_sort`,
+    /// for rows sorted by a server.
+    pub comparator: Option<TableComparator<T>>,
+    /// Reads the value to edit, making the cell editable on double-click
+    pub edit_value: Option<Rc<dyn Fn(&T) -> SharedString>>,
+    /// Custom editor, replacing the default text field
+    pub editor: Option<TableCellEditor>,
+    /// Smallest width the column can be resized to
+    pub min_width: Pixels,
+    /// Largest width the column can be resized to
+    pub max_width: Option<Pixels>,
+}
+
+impl<T> Clone for TableColumn<T> {
+    fn clone(&self) -> Self {
+        Self {
+            header: self.header.clone(),
+            width: self.width,
+            cell: self.cell.clone(),
+            sortable: self.sortable,
+            comparator: self.comparator.clone(),
+            edit_value: self.edit_value.clone(),
+            editor: self.editor.clone(),
+            min_width: self.min_width,
+            max_width: self.max_width,
+        }
+    }
+}
+
+impl<T> TableColumn<T> {
+    /// Create a new column with a header
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let column = TableColumn::<User>::new("Name");
+    /// ```
+    pub fn new(header: impl Into<SharedString>) -> Self {
+        Self {
+            header: header.into(),
+            width: None,
+            cell: None,
+            sortable: false,
+            comparator: None,
+            edit_value: None,
+            editor: None,
+            min_width: px(48.0),
+            max_width: None,
+        }
+    }
+
+    /// Set a fixed column width; columns without one share the remaining space
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TableColumn::<User>::new("Name").width(px(200.0));
+    /// ```
+    pub fn width(mut self, width: Pixels) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the cell renderer for this column
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TableColumn::new("Email").cell(|user: &User| user.email.clone());
+    /// ```
+    pub fn cell<E: IntoElement>(mut self, render: impl Fn(&T) -> E + 'static) -> Self {
+        self.cell = Some(Rc::new(move |row| render(row).into_any_element()));
+        self
+    }
+
+    /// Set whether clicking the header sorts by this column
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// // Sorted by the server in `on_sort`
+    /// TableColumn::<User>::new("Created").sortable(true);
+    /// ```
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    /// Make the column sortable, ordering rows locally with `compare`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TableColumn::new("Name").sort_by(|a: &User, b: &User| a.name.cmp(&b.name));
+    /// ```
+    pub fn sort_by(mut self, compare: impl Fn(&T, &T) -> Ordering + 'static) -> Self {
+        self.sortable = true;
+        self.comparator = Some(Rc::new(compare));
+        self
+    }
+
+    /// Set the smallest width the column can be resized to
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TableColumn::<User>::new("Name").min_width(px(120.0));
+    /// ```
+    pub fn min_width(mut self, min_width: Pixels) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Set the largest width the column can be resized to
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TableColumn::<User>::new("Name").max_width(px(400.0));
+    /// ```
+    pub fn max_width(mut self, max_width: Pixels) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Clamp `width` to the column's width constraints
+    fn clamp_width(&self, width: Pixels) -> Pixels {
+        let width = if width < self.min_width { self.min_width } else { width };
+        match self.max_width {
+            Some(max_width) if width > max_width => max_width,
+            _ => width,
+        }
+    }
+
+    /// Make cells editable on double-click, starting from the value `edit_value` reads
+    ///
+    /// Edits are reported through [`Table::on_cell_edit`]; the table does
+    /// not change its rows itself.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TableColumn::new("Name")
+    ///     .cell(|user: &User| user.name.clone())
+    ///     .editable(|user: &User| user.name.clone());
+    /// ```
+    pub fn editable(mut self, edit_value: impl Fn(&T) -> SharedString + 'static) -> Self {
+        self.edit_value = Some(Rc::new(edit_value));
+        self
+    }
+
+    /// Replace the default text field shown while editing
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TableColumn::new("Email")
+    ///     .editable(|user: &User| user.email.clone())
+    ///     .editor(|text, theme| div().text_color(theme.alias.color_primary).child(text.to_string()));
+    /// ```
+    pub fn editor<E: IntoElement>(mut self, editor: impl Fn(&str, &Theme) -> E + 'static) -> Self {
+        self.editor = Some(Rc::new(move |text, theme| editor(text, theme).into_any_element()));
+        self
+    }
+
+    /// Size a header or body cell to this column
+    fn sized(&self, cell: Div) -> Div {
+        match self.width {
+            Some(width) => cell.w(width).flex_none(),
+            None => cell.flex_1(),
+        }
+    }
+}
+
+/// Page size choices offered by default in the pagination footer
+const DEFAULT_PAGE_SIZES: [usize; 4] = [10, 25, 50, 100];
+
+/// Fixed-width cell holding a selection checkbox
+fn checkbox_cell(theme: &Theme) -> Div {
+    div()
+        .w(px(40.0))
+        .h_full()
+        .flex_none()
+        .flex()
+        .items_center()
+        .justify_center()
+        .px(theme.global.spacing_sm)
+}
+
+/// Default editor: an input-styled field showing the text typed so far
+fn default_editor(text: &str, theme: &Theme) -> Div {
+    let tokens = InputTokens::from_theme(theme);
+    div()
+        .w_full()
+        .flex()
+        .flex_row()
+        .items_center()
+        .px(tokens.padding_x / 2.0)
+        .bg(tokens.background)
+        .text_color(tokens.text_color)
+        .text_size(tokens.font_size)
+        .border_color(tokens.border_focus)
+        .border(tokens.border_width)
+        .rounded(tokens.border_radius)
+        .child(SharedString::from(text.to_string()))
+        // Caret
+        .child(div().w(px(1.0)).h(tokens.font_size).bg(tokens.text_color))
 }
 
 /// Table configuration properties
 #[derive(Clone)]
-pub struct TableProps {
+pub struct TableProps<T> {
     /// Table columns
-    pub columns: Vec<TableColumn>,
+    pub columns: Vec<TableColumn<T>>,
+    /// Table rows, followed by the child rows of tree tables
+    pub rows: Vec<T>,
+    /// Height of each body row at comfortable density
+    ///
+    /// Scaled by the theme [`Density`](crate::theme::Density) when rendered.
+    pub row_height: Pixels,
+    /// Maximum height of the scrolling body
+    pub max_height: Pixels,
+    /// Message shown when there are no rows
+    pub empty_message: SharedString,
+    /// Sorted column index and direction
+    pub sort: Option<(usize, SortDirection)>,
+    /// Handler called with the column and direction when a header is clicked
+    pub on_sort: Option<Rc<dyn Fn(usize, SortDirection, &mut Window, &mut App)>>,
+    /// Row selection behavior
+    pub selection_mode: TableSelectionMode,
+    /// Whether to show a leading checkbox column for selection
+    pub checkbox_column: bool,
+    /// Derives a row's id, falling back to its index in `rows`
+    pub row_id: Option<Rc<dyn Fn(&T) -> RowId>>,
+    /// Ids of the selected rows
+    pub selected: Vec<RowId>,
+    /// Handler called with the selected row ids when the selection changes
+    pub on_selection_change: Option<Rc<dyn Fn(Vec<RowId>, &mut Window, &mut App)>>,
+    /// Whether the selection is owned by the parent view
+    ///
+    /// Controlled tables only report selections through
+    /// `on_selection_change`; the parent applies them with
+    /// [`Table::set_selected`].
+    pub controlled: bool,
+    /// Rows per page, or `None` to show every row without a pagination footer
+    pub page_size: Option<usize>,
+    /// Page size choices offered in the footer
+    pub page_sizes: Vec<usize>,
+    /// Current zero-based page
+    pub page: usize,
+    /// Total row count of server-driven data
+    ///
+    /// When set, `rows` holds only the current page and page changes are
+    /// only reported through `on_page_change`; the parent loads the page and
+    /// applies it with [`Table::set_page`] and [`Table::set_rows`].
+    pub total_rows: Option<usize>,
+    /// Handler called with the new page and page size
+    pub on_page_change: Option<Rc<dyn Fn(usize, usize, &mut Window, &mut App)>>,
+    /// Whether the header row stays visible while the body scrolls
+    pub sticky_header: bool,
+    /// Number of leading columns kept in place when scrolling horizontally
+    pub frozen_columns: usize,
+    /// Handler called with the row id, column and new value of an edited cell
+    pub on_cell_edit: Option<Rc<dyn Fn(RowId, usize, SharedString, &mut Window, &mut App)>>,
+    /// Whether columns can be resized by dragging their header's right edge
+    pub resizable_columns: bool,
+    /// Whether columns can be reordered by dragging their header
+    pub reorderable_columns: bool,
+    /// Handler called with the new layout when columns are resized or moved
+    pub on_layout_change: Option<Rc<dyn Fn(TableLayout, &mut Window, &mut App)>>,
+    /// Returns the child rows of a row, making the table a tree
+    pub child_rows: Option<Rc<dyn Fn(&T) -> Vec<T>>>,
+    /// Whether a row has children to load, for tables with a child loader
+    pub has_children: Option<Rc<dyn Fn(&T) -> bool>>,
+    /// Loads child rows on first expansion
+    pub child_loader: Option<TableChildLoader<T>>,
+    /// Indentation of each tree level
+    pub indent: Pixels,
 }
 
-impl Default for TableProps {
+impl<T> Default for TableProps<T> {
     fn default() -> Self {
         Self {
             columns: vec![],
+            rows: vec![],
+            row_height: px(40.0),
+            max_height: px(400.0),
+            empty_message: "No data".into(),
+            sort: None,
+            on_sort: None,
+            selection_mode: TableSelectionMode::default(),
+            checkbox_column: false,
+            row_id: None,
+            selected: vec![],
+            on_selection_change: None,
+            controlled: false,
+            page_size: None,
+            page_sizes: DEFAULT_PAGE_SIZES.to_vec(),
+            page: 0,
+            total_rows: None,
+            on_page_change: None,
+            sticky_header: true,
+            frozen_columns: 0,
+            on_cell_edit: None,
+            resizable_columns: false,
+            reorderable_columns: false,
+            on_layout_change: None,
+            child_rows: None,
+            has_children: None,
+            child_loader: None,
+            indent: px(20.0),
         }
     }
 }
 
 /// A table component for displaying data.
 ///
-/// Table provides a structured layout for tabular data with headers.
+/// Table renders typed rows of any type `T`; each column turns a row into
+/// its cell with a renderer closure. The body is virtualized, so only the
+/// rows in view are rendered.
+///
+/// Clicking a sortable column header sorts by it, and clicking again
+/// reverses the direction. Columns with a comparator sort rows locally;
+/// `on_sort` reports every sort change for server-side sorting.
+///
+/// With a selection mode set, clicking a row selects it. In multiple
+/// selection, shift-click selects a range and cmd/ctrl-click toggles a row;
+/// the optional checkbox column adds a select-all header checkbox.
+///
+/// Setting a page size adds a pagination footer. By default the table
+/// slices its rows into pages itself; with `total_rows` set it shows
+/// server-driven pages loaded in `on_page_change`.
+///
+/// Wide tables scroll horizontally with the header following the body.
+/// Frozen leading columns, and the checkbox column, stay in place.
+///
+/// Double-clicking an editable cell edits it inline. Enter or moving focus
+/// away commits the edit through `on_cell_edit` and Escape cancels it.
+///
+/// Columns can be resized by dragging a header's right edge and reordered
+/// by dragging headers; `on_layout_change` reports the new layout so it
+/// can be persisted and restored with [`Table::column_layout`].
+///
+/// Rows can have children, shown indented under an expand/collapse
+/// chevron. Children come from the rows themselves or are loaded on first
+/// expansion.
+///
+/// ## Accessibility
+///
+/// - Clicking a header makes it the active column
+/// - Alt+Left/Right narrows or widens the active column
+/// - Alt+Shift+Left/Right moves the active column
+/// - Up/Down move the focused row, Home/End jump to the first/last row
+/// - Right expands the focused tree row or moves to its first child
+/// - Left collapses it or moves to its parent
+/// - Space selects the focused row and Enter edits its first editable cell
+/// - Lazily loaded child rows are announced to screen readers when they arrive
 ///
 /// ## Example
 ///
 /// ```rust,ignore
 /// use purdah_gpui_components::organisms::*;
 ///
-/// Table::new()
+/// struct User {
+///     name: SharedString,
+///     email: SharedString,
+/// }
+///
+/// Table::<User>::new()
 ///     .columns(vec![
-///         TableColumn { header: "Name".into(), width: Some(px(200.0)) },
-///         TableColumn { header: "Email".into(), width: None },
-///     ]);
+///         TableColumn::new("Name")
+///             .width(px(200.0))
+///             .cell(|user: &User| user.name.clone())
+///             .sort_by(|a: &User, b: &User| a.name.cmp(&b.name)),
+///         TableColumn::new("Email").cell(|user: &User| user.email.clone()),
+///     ])
+///     .rows(users)
+///     .sort(0, SortDirection::Ascending);
 /// ```
-pub struct Table {
-    props: TableProps,
+pub struct Table<T: 'static> {
+    props: TableProps<T>,
+    list_state: VirtualListState,
+    /// Row indices in display order
+    order: Vec<usize>,
+    /// Display position that shift-click ranges extend from
+    anchor: Option<usize>,
+    /// Horizontal scroll position shared by the header and every row
+    horizontal_scroll: ScrollHandle,
+    focus_handle: Option<FocusHandle>,
+    /// Commits the edit when the table loses focus
+    _focus_out: Option<Subscription>,
+    /// Cell being edited inline
+    editing: Option<CellEdit>,
+    /// Column indices in display order
+    column_order: Vec<usize>,
+    /// Header cell bounds from the last paint, by column index
+    header_bounds: Rc<RefCell<HashMap<usize, Bounds<Pixels>>>>,
+    /// Column whose header was clicked last, the target of layout keys
+    active_column: Option<usize>,
+    /// Number of top-level rows at the start of `rows`
+    root_count: usize,
+    /// Tree position of each row in `rows`
+    tree: Vec<TreeNode>,
+    /// Indices of expanded rows
+    expanded: HashSet<usize>,
+    /// Child loads in flight, by row index; dropping one cancels it
+    loading: HashMap<usize, Task<()>>,
+    /// Index of the keyboard-focused row in `rows`
+    cursor: Option<usize>,
 }
 
-impl Table {
+impl<T: 'static> Table<T> {
+    /// Create a new empty table
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let table = Table::<User>::new();
+    /// ```
     pub fn new() -> Self {
         Self {
             props: TableProps::default(),
+            list_state: VirtualListState::new(),
+            order: Vec::new(),
+            anchor: None,
+            horizontal_scroll: ScrollHandle::new(),
+            focus_handle: None,
+            _focus_out: None,
+            editing: None,
+            column_order: Vec::new(),
+            header_bounds: Rc::default(),
+            active_column: None,
+            root_count: 0,
+            tree: Vec::new(),
+            expanded: HashSet::new(),
+            loading: HashMap::new(),
+            cursor: None,
         }
     }
 
-    pub fn columns(mut self, columns: Vec<TableColumn>) -> Self {
+    /// Set the table columns
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<User>::new().columns(vec![TableColumn::new("Name")]);
+    /// ```
+    pub fn columns(mut self, columns: Vec<TableColumn<T>>) -> Self {
+        self.column_order = (0..columns.len()).collect();
         self.props.columns = columns;
+        self.sort_rows();
         self
     }
-}
 
-impl Render for Table {
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
-        let theme = Theme::default();
+    /// Set the table rows
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<User>::new().rows(users);
+    /// ```
+    pub fn rows(mut self, rows: Vec<T>) -> Self {
+        self.root_count = rows.len();
+        self.props.rows = rows;
+        self.build_tree();
+        self
+    }
 
-        div()
-            .w_full()
+    /// Set the height of each body row
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<User>::new().row_height(px(32.0));
+    /// ```
+    pub fn row_height(mut self, row_height: Pixels) -> Self {
+        self.props.row_height = row_height;
+        self
+    }
+
+    /// Set the maximum height of the body before it scrolls
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<User>::new().max_height(px(600.0));
+    /// ```
+    pub fn max_height(mut self, max_height: Pixels) -> Self {
+        self.props.max_height = max_height;
+        self
+    }
+
+    /// Set the message shown when there are no rows
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<User>::new().empty_message("No users yet");
+    /// ```
+    pub fn empty_message(mut self, message: impl Into<SharedString>) -> Self {
+        self.props.empty_message = message.into();
+        self
+    }
+
+    /// Replace the rows from the parent view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// table.update(cx, |table, cx| table.set_rows(users, cx));
+    /// ```
+    pub fn set_rows(&mut self, rows: Vec<T>, cx: &mut Context<'_, Self>) {
+        self.root_count = rows.len();
+        self.props.rows = rows;
+        self.build_tree();
+        cx.notify();
+    }
+
+    /// Make the table a tree, reading each row's children with `children`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<Folder>::new().child_rows(|folder| folder.subfolders.clone());
+    /// ```
+    pub fn child_rows(mut self, children: impl Fn(&T) -> Vec<T> + 'static) -> Self {
+        self.props.child_rows = Some(Rc::new(children));
+        self.build_tree();
+        self
+    }
+
+    /// Make the table a tree whose children load on first expansion
+    ///
+    /// `has_children` decides which rows show an expand chevron before
+    /// their children are loaded.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<Folder>::new().load_children(
+    ///     |folder| folder.has_subfolders,
+    ///     |folder, cx| fetch_subfolders(folder.id.clone(), cx),
+    /// );
+    /// ```
+    pub fn load_children(
+        mut self,
+        has_children: impl Fn(&T) -> bool + 'static,
+        loader: impl Fn(&T, &mut App) -> Task<Vec<T>> + 'static,
+    ) -> Self {
+        self.props.has_children = Some(Rc::new(has_children));
+        self.props.child_loader = Some(Rc::new(loader));
+        self
+    }
+
+    /// Set the indentation of each tree level
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<Folder>::new().indent(px(16.0));
+    /// ```
+    pub fn indent(mut self, indent: Pixels) -> Self {
+        self.props.indent = indent;
+        self
+    }
+
+    /// Whether rows can have children
+    fn is_tree(&self) -> bool {
+        self.props.child_rows.is_some() || self.props.child_loader.is_some()
+    }
+
+    /// Reset the tree to the top-level rows, reading all children from `child_rows`
+    fn build_tree(&mut self) {
+        self.props.rows.truncate(self.root_count);
+        self.tree = vec![TreeNode::default(); self.root_count];
+        self.expanded.clear();
+        self.loading.clear();
+        self.cursor = None;
+
+        if let Some(child_rows) = self.props.child_rows.clone() {
+            let mut index = 0;
+            while index < self.props.rows.len() {
+                let children = child_rows(&self.props.rows[index]);
+                self.add_children(index, children);
+                index += 1;
+            }
+        }
+        self.sort_rows();
+    }
+
+    /// Append `children` to the rows as the children of the row at `parent`
+    fn add_children(&mut self, parent: usize, children: Vec<T>) {
+        let start = self.props.rows.len();
+        let depth = self.tree[parent].depth + 1;
+        self.tree[parent].children = Some((start..start + children.len()).collect());
+        self.tree.extend(children.iter().map(|_| TreeNode {
+            parent: Some(parent),
+            depth,
+            children: None,
+        }));
+        self.props.rows.extend(children);
+    }
+
+    /// Whether the row at `index` has or may have children
+    fn can_expand(&self, index: usize) -> bool {
+        match (&self.tree[index].children, &self.props.has_children) {
+            (Some(children), _) => !children.is_empty(),
+            (None, Some(has_children)) => has_children(&self.props.rows[index]),
+            (None, None) => false,
+        }
+    }
+
+    /// Expand or collapse the row at `index`, loading its children if needed
+    fn set_expanded(&mut self, index: usize, expanded: bool, cx: &mut Context<'_, Self>) {
+        if !expanded {
+            self.expanded.remove(&index);
+            self.loading.remove(&index);
+        } else if self.can_expand(index) {
+            self.expanded.insert(index);
+            if self.tree[index].children.is_none() {
+                self.load_children_of(index, cx);
+            }
+        }
+        self.sort_rows();
+        cx.notify();
+    }
+
+    /// Start loading the children of the row at `index`
+    fn load_children_of(&mut self, index: usize, cx: &mut Context<'_, Self>) {
+        let Some(loader) = self.props.child_loader.clone() else {
+            return;
+        };
+        if self.loading.contains_key(&index) {
+            return;
+        }
+
+        Announcer::polite("Loading").announce(cx);
+        let task = loader(&self.props.rows[index], cx);
+        let load = cx.spawn(async move |this, cx| {
+            let children = task.await;
+            this.update(cx, |this, cx| {
+                this.loading.remove(&index);
+                let loaded = match children.len() {
+                    1 => "1 item loaded".to_string(),
+                    count => format!("{count} items loaded"),
+                };
+                this.add_children(index, children);
+                this.sort_rows();
+                Announcer::polite(loaded).announce(cx);
+                cx.notify();
+            })
+            .ok();
+        });
+        self.loading.insert(index, load);
+    }
+
+    /// Set the initial sort column and direction
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<User>::new().sort(0, SortDirection::Descending);
+    /// ```
+    pub fn sort(mut self, column: usize, direction: SortDirection) -> Self {
+        self.props.sort = Some((column, direction));
+        self.sort_rows();
+        self
+    }
+
+    /// Set the handler called when a sortable header is clicked
+    ///
+    /// Use it to fetch rows sorted by a server for columns without a
+    /// comparator.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<User>::new().on_sort(|column, direction, _window, cx| {
+    ///     fetch_users(column, direction, cx);
+    /// });
+    /// ```
+    pub fn on_sort(
+        mut self,
+        handler: impl Fn(usize, SortDirection, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_sort = Some(Rc::new(handler));
+        self
+    }
+
+    /// Update the sort column and direction from the parent view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// table.update(cx, |table, cx| table.set_sort(None, cx));
+    /// ```
+    pub fn set_sort(&mut self, sort: Option<(usize, SortDirection)>, cx: &mut Context<'_, Self>) {
+        if self.props.sort != sort {
+            self.props.sort = sort;
+            self.sort_rows();
+            cx.notify();
+        }
+    }
+
+    /// Set the row selection behavior
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<User>::new().selection_mode(TableSelectionMode::Multiple);
+    /// ```
+    pub fn selection_mode(mut self, mode: TableSelectionMode) -> Self {
+        self.props.selection_mode = mode;
+        self
+    }
+
+    /// Set whether to show a leading checkbox column for selection
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<User>::new()
+    ///     .selection_mode(TableSelectionMode::Multiple)
+    ///     .checkbox_column(true);
+    /// ```
+    pub fn checkbox_column(mut self, checkbox_column: bool) -> Self {
+        self.props.checkbox_column = checkbox_column;
+        self
+    }
+
+    /// Set how row ids are derived from rows
+    ///
+    /// Without it rows are identified by their index in `rows`, which
+    /// changes when the rows are replaced.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<User>::new().row_id(|user| user.id.clone());
+    /// ```
+    pub fn row_id<I: Into<RowId>>(mut self, row_id: impl Fn(&T) -> I + 'static) -> Self {
+        self.props.row_id = Some(Rc::new(move |row| row_id(row).into()));
+        self
+    }
+
+    /// Set the selected row ids, making the selection controlled
+    ///
+    /// A controlled table does not change its selection on click; apply
+    /// selections reported by `on_selection_change` with
+    /// [`Table::set_selected`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<User>::new().selected(vec!["user-1".into()]);
+    /// ```
+    pub fn selected(mut self, selected: Vec<RowId>) -> Self {
+        self.props.selected = selected;
+        self.props.controlled = true;
+        self
+    }
+
+    /// Set the initially selected row ids of an uncontrolled table
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<User>::new().default_selected(vec!["user-1".into()]);
+    /// ```
+    pub fn default_selected(mut self, selected: Vec<RowId>) -> Self {
+        self.props.selected = selected;
+        self.props.controlled = false;
+        self
+    }
+
+    /// Set the handler called when the selection changes
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<User>::new().on_selection_change(|ids, _window, cx| {
+    ///     update_toolbar(ids.len(), cx);
+    /// });
+    /// ```
+    pub fn on_selection_change(
+        mut self,
+        handler: impl Fn(Vec<RowId>, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_selection_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Update the selection from the parent view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// table.update(cx, |table, cx| table.set_selected(Vec::new(), cx));
+    /// ```
+    pub fn set_selected(&mut self, selected: Vec<RowId>, cx: &mut Context<'_, Self>) {
+        if self.props.selected != selected {
+            self.props.selected = selected;
+            cx.notify();
+        }
+    }
+
+    /// Id of the row at `index` in `rows`
+    fn id_of(&self, index: usize) -> RowId {
+        match &self.props.row_id {
+            Some(row_id) => row_id(&self.props.rows[index]),
+            None => index.to_string().into(),
+        }
+    }
+
+    /// Whether the row at `index` in `rows` is selected
+    fn is_selected(&self, index: usize) -> bool {
+        !self.props.selected.is_empty() && self.props.selected.contains(&self.id_of(index))
+    }
+
+    /// State of the select-all header checkbox
+    fn select_all_state(&self) -> CheckboxState {
+        let count = (0..self.props.rows.len()).filter(|&i| self.is_selected(i)).count();
+        if count == 0 {
+            CheckboxState::Unchecked
+        } else if count == self.props.rows.len() {
+            CheckboxState::Checked
+        } else {
+            CheckboxState::Indeterminate
+        }
+    }
+
+    /// Selection after clicking the row at display position `position`
+    ///
+    /// `extend` selects the range from the anchor row and `toggle` adds or
+    /// removes a single row; both only apply in multiple selection.
+    fn clicked_selection(&mut self, position: usize, extend: bool, toggle: bool) -> Vec<RowId> {
+        let id = self.id_of(self.order[position]);
+        let multiple = self.props.selection_mode == TableSelectionMode::Multiple;
+
+        match self.anchor {
+            Some(anchor) if multiple && extend => {
+                let range = anchor.min(position)..=anchor.max(position);
+                return range.map(|p| self.id_of(self.order[p])).collect();
+            }
+            _ => {}
+        }
+
+        self.anchor = Some(position);
+        if multiple && toggle {
+            let mut selected = self.props.selected.clone();
+            if let Some(existing) = selected.iter().position(|s| *s == id) {
+                selected.remove(existing);
+            } else {
+                selected.push(id);
+            }
+            selected
+        } else {
+            vec![id]
+        }
+    }
+
+    /// Apply a new selection and notify the handler if it changed
+    fn change_selection(&mut self, selected: Vec<RowId>, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.props.selected == selected {
+            return;
+        }
+
+        if !self.props.controlled {
+            self.set_selected(selected.clone(), cx);
+        }
+
+        if let Some(handler) = self.props.on_selection_change.clone() {
+            handler(selected, window, cx);
+        }
+    }
+
+    /// Select every row, or clear the selection if all are selected
+    fn toggle_select_all(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let selected = if self.select_all_state() == CheckboxState::Checked {
+            Vec::new()
+        } else {
+            (0..self.props.rows.len()).map(|i| self.id_of(i)).collect()
+        };
+        self.change_selection(selected, window, cx);
+    }
+
+    /// Set whether the header row stays visible while the body scrolls
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<User>::new().sticky_header(false);
+    /// ```
+    pub fn sticky_header(mut self, sticky_header: bool) -> Self {
+        self.props.sticky_header = sticky_header;
+        self
+    }
+
+    /// Keep the first `count` columns in place when scrolling horizontally
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<User>::new().freeze_columns(1);
+    /// ```
+    pub fn freeze_columns(mut self, count: usize) -> Self {
+        self.props.frozen_columns = count;
+        self
+    }
+
+    /// Set the handler called when an inline cell edit is committed
+    ///
+    /// The handler receives the row id, column index and new value; apply
+    /// it to the rows with [`Table::set_rows`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<User>::new().on_cell_edit(|row, column, value, _window, cx| {
+    ///     rename_user(row, column, value, cx);
+    /// });
+    /// ```
+    pub fn on_cell_edit(
+        mut self,
+        handler: impl Fn(RowId, usize, SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_cell_edit = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set whether columns can be resized by dragging their header's right edge
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<User>::new().resizable_columns(true);
+    /// ```
+    pub fn resizable_columns(mut self, resizable: bool) -> Self {
+        self.props.resizable_columns = resizable;
+        self
+    }
+
+    /// Set whether columns can be reordered by dragging their header
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<User>::new().reorderable_columns(true);
+    /// ```
+    pub fn reorderable_columns(mut self, reorderable: bool) -> Self {
+        self.props.reorderable_columns = reorderable;
+        self
+    }
+
+    /// Restore a column layout previously reported by `on_layout_change`
+    ///
+    /// Call after [`Table::columns`]. Layouts that don't match the columns
+    /// are ignored.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<User>::new().columns(columns).column_layout(saved_layout);
+    /// ```
+    pub fn column_layout(mut self, layout: TableLayout) -> Self {
+        let len = self.props.columns.len();
+        let mut sorted = layout.order.clone();
+        sorted.sort_unstable();
+        if layout.widths.len() != len || !sorted.iter().copied().eq(0..len) {
+            return self;
+        }
+
+        for (col, width) in self.props.columns.iter_mut().zip(layout.widths) {
+            col.width = width.map(|width| col.clamp_width(width));
+        }
+        self.column_order = layout.order;
+        self
+    }
+
+    /// Set the handler called when columns are resized or moved
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<User>::new().on_layout_change(|layout, _window, cx| save_layout(layout, cx));
+    /// ```
+    pub fn on_layout_change(
+        mut self,
+        handler: impl Fn(TableLayout, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_layout_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Current column order and widths
+    fn current_layout(&self) -> TableLayout {
+        TableLayout {
+            order: self.column_order.clone(),
+            widths: self.props.columns.iter().map(|col| col.width).collect(),
+        }
+    }
+
+    /// Notify the handler of the current layout
+    fn layout_changed(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+        cx.notify();
+        if let Some(handler) = self.props.on_layout_change.clone() {
+            handler(self.current_layout(), window, cx);
+        }
+    }
+
+    /// Rendered width of `column`: its fixed width, or its last painted width
+    fn column_width(&self, column: usize) -> Option<Pixels> {
+        self.props.columns[column].width.or_else(|| {
+            self.header_bounds.borrow().get(&column).map(|bounds| bounds.size.width)
+        })
+    }
+
+    /// Set the width of `column` within its constraints
+    fn resize_column(&mut self, column: usize, width: Pixels, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let col = &mut self.props.columns[column];
+        let width = Some(col.clamp_width(width));
+        if col.width != width {
+            col.width = width;
+            self.layout_changed(window, cx);
+        }
+    }
+
+    /// Move the column at display position `from` to display position `to`
+    fn move_column(&mut self, from: usize, to: usize, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let len = self.column_order.len();
+        if from == to || from >= len || to >= len {
+            return;
+        }
+
+        let column = self.column_order.remove(from);
+        self.column_order.insert(to, column);
+        self.layout_changed(window, cx);
+    }
+
+    /// Follow a column resize drag with the header's right edge
+    fn handle_resize_drag(
+        &mut self,
+        event: &DragMoveEvent<ColumnResize>,
+        window: &mut Window,
+        cx: &mut Context<'_, Self>,
+    ) {
+        let column = event.drag(cx).column;
+        let left = self.header_bounds.borrow().get(&column).map(|bounds| bounds.left());
+        if let Some(left) = left {
+            self.resize_column(column, event.event.position.x - left, window, cx);
+        }
+    }
+
+    /// Handle Alt+Left/Right resizing and Alt+Shift+Left/Right moving of the active column
+    fn handle_layout_key(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let keystroke = &event.keystroke;
+        let Some(column) = self.active_column else {
+            return;
+        };
+        if !keystroke.modifiers.alt {
+            return;
+        }
+
+        let forward = match keystroke.key.as_str() {
+            "right" => true,
+            "left" => false,
+            _ => return,
+        };
+
+        if keystroke.modifiers.shift {
+            if !self.props.reorderable_columns {
+                return;
+            }
+            let Some(from) = self.column_order.iter().position(|&c| c == column) else {
+                return;
+            };
+            let to = if forward { from + 1 } else { from.saturating_sub(1) };
+            self.move_column(from, to, window, cx);
+        } else {
+            if !self.props.resizable_columns {
+                return;
+            }
+            let Some(width) = self.column_width(column) else {
+                return;
+            };
+            let step = px(RESIZE_STEP);
+            self.resize_column(column, if forward { width + step } else { width - step }, window, cx);
+        }
+        cx.stop_propagation();
+    }
+
+    /// Start editing the cell of the row at `index` in `rows` and `column`
+    fn start_edit(&mut self, index: usize, column: usize, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let Some(edit_value) = self
+            .props
+            .columns
+            .get(column)
+            .and_then(|col| col.edit_value.clone())
+        else {
+            return;
+        };
+
+        self.commit_edit(window, cx);
+        let original = edit_value(&self.props.rows[index]);
+        self.editing = Some(CellEdit {
+            index,
+            column,
+            text: original.to_string(),
+            original,
+        });
+        if let Some(focus_handle) = &self.focus_handle {
+            window.focus(focus_handle);
+        }
+        cx.notify();
+    }
+
+    /// Row id, column and new value of the current edit, if the value changed
+    fn edited_value(&self) -> Option<(RowId, usize, SharedString)> {
+        let edit = self.editing.as_ref()?;
+        (edit.text != edit.original.as_ref())
+            .then(|| (self.id_of(edit.index), edit.column, edit.text.clone().into()))
+    }
+
+    /// Finish the current edit and notify the handler if the value changed
+    fn commit_edit(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let edited = self.edited_value();
+        if self.editing.take().is_none() {
+            return;
+        }
+        cx.notify();
+
+        if let (Some((row, column, value)), Some(handler)) = (edited, self.props.on_cell_edit.clone()) {
+            handler(row, column, value, window, cx);
+        }
+    }
+
+    /// Discard the current edit
+    fn cancel_edit(&mut self, cx: &mut Context<'_, Self>) {
+        if self.editing.take().is_some() {
+            cx.notify();
+        }
+    }
+
+    /// Handle typing, Enter and Escape while editing a cell, and layout and
+    /// navigation keys otherwise
+    fn handle_key(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let Some(edit) = self.editing.as_mut() else {
+            // Layout keys all use Alt, which navigation keys never do
+            self.handle_layout_key(event, window, cx);
+            self.handle_navigation_key(event, window, cx);
+            return;
+        };
+
+        let keystroke = &event.keystroke;
+        match keystroke.key.as_str() {
+            "enter" => self.commit_edit(window, cx),
+            "escape" => self.cancel_edit(cx),
+            "backspace" => {
+                edit.text.pop();
+                cx.notify();
+            }
+            _ if keystroke.modifiers.control
+                || keystroke.modifiers.alt
+                || keystroke.modifiers.platform => return,
+            _ => {
+                let Some(text) = keystroke.key_char.as_deref() else {
+                    return;
+                };
+                edit.text.push_str(text);
+                cx.notify();
+            }
+        }
+        cx.stop_propagation();
+    }
+
+    /// Display position of the row at `index`, if shown
+    fn position_of(&self, index: usize) -> Option<usize> {
+        self.order.iter().position(|&i| i == index)
+    }
+
+    /// Row the cursor moves to for a navigation key, if any
+    fn navigation_target(&self, key: &str) -> Option<usize> {
+        let range = self.page_range();
+        if range.is_empty() {
+            return None;
+        }
+        let position = self.cursor.and_then(|index| self.position_of(index));
+
+        let target = match (key, position) {
+            // Up, Down, Home and End move within the page without wrapping
+            ("down" | "up" | "home" | "end", _) => {
+                let active = position
+                    .filter(|p| range.contains(p))
+                    .map(|p| p - range.start);
+                let rows = RovingFocus::new(RovingOrientation::Vertical).wrap(false);
+                range.start + rows.target(key, active, range.len(), |_| true)?
+            }
+            // First child of an expanded row
+            ("right", Some(p)) => {
+                let index = self.order[p];
+                let next = *self.order.get(p + 1)?;
+                if self.tree[next].parent != Some(index) {
+                    return None;
+                }
+                p + 1
+            }
+            ("left", Some(p)) => {
+                let parent = self.tree[self.order[p]].parent?;
+                self.position_of(parent)?
+            }
+            _ => return None,
+        };
+        Some(self.order[target.clamp(range.start, range.end - 1)])
+    }
+
+    /// Handle row focus, expansion, selection and editing keys
+    fn handle_navigation_key(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let keystroke = &event.keystroke;
+        if keystroke.modifiers.alt || keystroke.modifiers.control || keystroke.modifiers.platform {
+            return;
+        }
+
+        let key = keystroke.key.as_str();
+        let cursor = self.cursor.filter(|&index| self.position_of(index).is_some());
+        match (key, cursor) {
+            ("right", Some(index)) if self.can_expand(index) && !self.expanded.contains(&index) => {
+                self.set_expanded(index, true, cx);
+            }
+            ("left", Some(index)) if self.expanded.contains(&index) => {
+                self.set_expanded(index, false, cx);
+            }
+            ("space", Some(index)) if self.props.selection_mode != TableSelectionMode::None => {
+                let Some(position) = self.position_of(index) else {
+                    return;
+                };
+                let selected = self.clicked_selection(position, keystroke.modifiers.shift, true);
+                self.change_selection(selected, window, cx);
+            }
+            ("enter", Some(index)) => {
+                let column = self
+                    .column_order
+                    .iter()
+                    .copied()
+                    .find(|&column| self.props.columns[column].edit_value.is_some());
+                if let Some(column) = column {
+                    self.start_edit(index, column, window, cx);
+                }
+            }
+            _ => {
+                let Some(target) = self.navigation_target(key) else {
+                    return;
+                };
+                self.cursor = Some(target);
+                self.scroll_to_cursor(cx);
+                cx.notify();
+            }
+        }
+        cx.stop_propagation();
+    }
+
+    /// Scroll the body so the cursor row is visible
+    fn scroll_to_cursor(&self, cx: &App) {
+        let Some(position) = self.cursor.and_then(|index| self.position_of(index)) else {
+            return;
+        };
+        let header_items = usize::from(!self.props.sticky_header);
+        let item = position.saturating_sub(self.page_range().start) + header_items;
+        self.list_state
+            .scroll_to_item(item, use_theme(cx).density.scale(self.props.row_height));
+    }
+
+    /// Paginate rows with `page_size` rows per page
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<User>::new().page_size(25);
+    /// ```
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.props.page_size = Some(page_size.max(1));
+        self
+    }
+
+    /// Set the page size choices offered in the footer
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<User>::new().page_size(20).page_sizes(vec![20, 50]);
+    /// ```
+    pub fn page_sizes(mut self, page_sizes: Vec<usize>) -> Self {
+        self.props.page_sizes = page_sizes;
+        self
+    }
+
+    /// Set the current zero-based page
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<User>::new().page_size(25).page(2);
+    /// ```
+    pub fn page(mut self, page: usize) -> Self {
+        self.props.page = page;
+        self
+    }
+
+    /// Set the total row count of server-driven data, making pagination controlled
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<User>::new()
+    ///     .page_size(25)
+    ///     .total_rows(1_204)
+    ///     .rows(first_page)
+    ///     .on_page_change(|page, page_size, _window, cx| fetch_users(page, page_size, cx));
+    /// ```
+    pub fn total_rows(mut self, total_rows: usize) -> Self {
+        self.props.total_rows = Some(total_rows);
+        self
+    }
+
+    /// Set the handler called with the new page and page size
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Table::<User>::new().on_page_change(|page, _page_size, _window, _cx| {
+    ///     println!("page {}", page + 1);
+    /// });
+    /// ```
+    pub fn on_page_change(
+        mut self,
+        handler: impl Fn(usize, usize, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_page_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Update the current page and page size from the parent view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// table.update(cx, |table, cx| table.set_page(page, page_size, cx));
+    /// ```
+    pub fn set_page(&mut self, page: usize, page_size: usize, cx: &mut Context<'_, Self>) {
+        let page_size = Some(page_size.max(1));
+        if self.props.page != page || self.props.page_size != page_size {
+            self.props.page = page;
+            self.props.page_size = page_size;
+            self.anchor = None;
+            self.list_state.scroll_to_top();
+            cx.notify();
+        }
+    }
+
+    /// Row count across all pages
+    fn total_row_count(&self) -> usize {
+        self.props.total_rows.unwrap_or(self.props.rows.len())
+    }
+
+    /// Number of pages, at least one
+    fn page_count(&self) -> usize {
+        match self.props.page_size {
+            Some(page_size) => self.total_row_count().div_ceil(page_size).max(1),
+            None => 1,
+        }
+    }
+
+    /// Display positions of the rows shown on the current page
+    fn page_range(&self) -> Range<usize> {
+        let len = self.order.len();
+        match self.props.page_size {
+            Some(page_size) if self.props.total_rows.is_none() => {
+                let page = self.props.page.min(self.page_count() - 1);
+                let start = (page * page_size).min(len);
+                start..(start + page_size).min(len)
+            }
+            _ => 0..len,
+        }
+    }
+
+    /// "x–y of z" summary of the rows on the current page
+    fn range_text(&self) -> String {
+        let total = self.total_row_count();
+        let shown = self.page_range().len();
+        if shown == 0 {
+            return format!("0 of {total}");
+        }
+
+        let first = self.props.page.min(self.page_count() - 1) * self.props.page_size.unwrap_or(0);
+        format!("{}–{} of {total}", first + 1, first + shown)
+    }
+
+    /// Move to `page` with `page_size` rows per page and notify the handler
+    fn change_page(&mut self, page: usize, page_size: usize, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let page_count = self.total_row_count().div_ceil(page_size.max(1)).max(1);
+        let page = page.min(page_count - 1);
+        if page == self.props.page && Some(page_size) == self.props.page_size {
+            return;
+        }
+
+        if self.props.total_rows.is_none() {
+            self.set_page(page, page_size, cx);
+        }
+
+        if let Some(handler) = self.props.on_page_change.clone() {
+            handler(page, page_size, window, cx);
+        }
+    }
+
+    /// Recompute the display order: top-level rows followed depth-first by
+    /// the children of expanded rows
+    ///
+    /// Each group of siblings is ordered by the sort column's comparator,
+    /// keeping its given order when the column has none.
+    fn sort_rows(&mut self) {
+        self.anchor = None;
+
+        let mut roots: Vec<usize> = (0..self.root_count.min(self.props.rows.len())).collect();
+        self.sort_siblings(&mut roots);
+
+        let mut order = Vec::with_capacity(self.props.rows.len());
+        let mut stack: Vec<usize> = roots.into_iter().rev().collect();
+        while let Some(index) = stack.pop() {
+            order.push(index);
+            if !self.expanded.contains(&index) {
+                continue;
+            }
+            if let Some(mut children) = self.tree.get(index).and_then(|node| node.children.clone()) {
+                self.sort_siblings(&mut children);
+                stack.extend(children.into_iter().rev());
+            }
+        }
+        self.order = order;
+    }
+
+    /// Order sibling rows by the sort column's comparator
+    fn sort_siblings(&self, siblings: &mut [usize]) {
+        let Some((column, direction)) = self.props.sort else {
+            return;
+        };
+        let Some(compare) = self
+            .props
+            .columns
+            .get(column)
+            .and_then(|col| col.comparator.as_ref())
+        else {
+            return;
+        };
+
+        let rows = &self.props.rows;
+        siblings.sort_by(|&a, &b| {
+            let ordering = compare(&rows[a], &rows[b]);
+            match direction {
+                SortDirection::Ascending => ordering,
+                SortDirection::Descending => ordering.reverse(),
+            }
+        });
+    }
+
+    /// Sort by `column`, reversing the direction if it is already sorted
+    fn toggle_sort(&mut self, column: usize, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let direction = match self.props.sort {
+            Some((sorted, direction)) if sorted == column => direction.reversed(),
+            _ => SortDirection::Ascending,
+        };
+        self.set_sort(Some((column, direction)), cx);
+
+        if let Some(handler) = self.props.on_sort.clone() {
+            handler(column, direction, window, cx);
+        }
+    }
+
+    /// Render the body row at display position `position`
+    ///
+    /// `focused` shows a focus indicator on the cursor row.
+    fn render_row(
+        &self,
+        position: usize,
+        theme: &Theme,
+        focused: bool,
+        cx: &mut Context<'_, Self>,
+    ) -> Stateful<Div> {
+        let index = self.order[position];
+        let row = &self.props.rows[index];
+        let selected = self.is_selected(index);
+        let selectable = self.props.selection_mode != TableSelectionMode::None;
+
+        let mut row_element = div()
+            .id(("table-row", position))
+            .size_full()
+            .flex()
+            .flex_row()
+            .items_center()
             .border_color(theme.alias.color_border)
-            .border(px(1.0))
-            .rounded(theme.global.radius_md)
-            .overflow_hidden()
-            .child(
-                // Header row
+            .border_b(px(1.0));
+
+        row_element = if selected {
+            row_element.bg(theme.alias.color_primary.opacity(0.12))
+        } else {
+            row_element.hover(|style| style.bg(theme.alias.color_surface_hover))
+        };
+
+        if focused && self.cursor == Some(index) {
+            row_element = row_element
+                .border_l(px(2.0))
+                .border_color(theme.alias.color_border_focus);
+        }
+
+        row_element = row_element
+            .when(selectable, |this| this.cursor_pointer())
+            .on_click(cx.listener(move |this, event: &ClickEvent, window, cx| {
+                this.commit_edit(window, cx);
+                this.cursor = Some(index);
+                cx.notify();
+                if selectable {
+                    let modifiers = event.modifiers();
+                    let toggle = modifiers.platform || modifiers.control;
+                    let selected = this.clicked_selection(position, modifiers.shift, toggle);
+                    this.change_selection(selected, window, cx);
+                }
+            }));
+
+        let mut frozen = self.frozen_section(theme);
+        if self.props.checkbox_column {
+            frozen = frozen.child(
+                checkbox_cell(theme)
+                    .id(("table-row-checkbox", position))
+                    .group("checkbox")
+                    .when(selectable, |this| {
+                        this.on_click(cx.listener(move |this, _event: &ClickEvent, window, cx| {
+                            let selected = this.clicked_selection(position, false, true);
+                            this.change_selection(selected, window, cx);
+                            cx.stop_propagation();
+                        }))
+                    })
+                    .child(Checkbox::new().checked(selected).to_element(theme)),
+            );
+        }
+
+        let mut scrolling = self.scroll_section(("table-row-scroll", position));
+        for (slot, &column) in self.column_order.iter().enumerate() {
+            let col = &self.props.columns[column];
+            let mut cell = col.sized(
                 div()
+                    .h_full()
                     .flex()
-                    .flex_row()
-                    .bg(if theme.is_dark() {
-                        theme.global.gray_800
-                    } else {
-                        theme.global.gray_50
-                    })
-                    .border_color(theme.alias.color_border)
-                    .border_b(px(1.0))
-                    .children(
-                        self.props.columns.iter().map(|col| {
-                            let mut cell = div()
-                                .p(theme.global.spacing_sm)
-                                .flex_1();
-
-                            if let Some(width) = col.width {
-                                cell = cell.w(width).flex_none();
+                    .items_center()
+                    .px(theme.global.spacing_sm)
+                    .overflow_hidden(),
+            );
+            if slot == 0 && self.is_tree() {
+                cell = cell.child(self.render_tree_toggle(index, theme, cx));
+            }
+            let editing = self
+                .editing
+                .as_ref()
+                .filter(|edit| edit.index == index && edit.column == column);
+
+            let cell = if let Some(edit) = editing {
+                let editor = match &col.editor {
+                    Some(editor) => editor(&edit.text, theme),
+                    None => default_editor(&edit.text, theme).into_any_element(),
+                };
+                cell.child(editor).into_any_element()
+            } else {
+                let cell = match &col.cell {
+                    Some(render) => cell.child(render(row)),
+                    None => cell,
+                };
+                if col.edit_value.is_some() {
+                    cell.id(("table-cell", column))
+                        .on_click(cx.listener(move |this, event: &ClickEvent, window, cx| {
+                            if event.click_count() >= 2 {
+                                this.start_edit(index, column, window, cx);
+                                cx.stop_propagation();
                             }
+                        }))
+                        .into_any_element()
+                } else {
+                    cell.into_any_element()
+                }
+            };
+
+            if slot < self.props.frozen_columns {
+                frozen = frozen.child(cell);
+            } else {
+                scrolling = scrolling.child(cell);
+            }
+        }
 
-                            cell.child(
-                                Label::new(col.header.clone())
-                                    .color(theme.alias.color_text_primary)
-                            )
-                        }).collect::<Vec<_>>()
+        row_element.child(frozen).child(scrolling)
+    }
+
+    /// Indentation and expand/collapse chevron of a tree row
+    #[allow(clippy::cast_precision_loss)]
+    fn render_tree_toggle(&self, index: usize, theme: &Theme, cx: &mut Context<'_, Self>) -> Div {
+        let node = &self.tree[index];
+        let indent = div().flex_none().w(self.props.indent * node.depth as f32);
+        let toggle = div()
+            .flex_none()
+            .size(px(16.0))
+            .mr(theme.global.spacing_xs)
+            .flex()
+            .items_center()
+            .justify_center();
+
+        let toggle = if self.loading.contains_key(&index) {
+            toggle.child(
+                Spinner::new()
+                    .size(SpinnerSize::Sm)
+                    .animated(!reduced_motion(cx))
+                    .to_element(theme),
+            )
+        } else if self.can_expand(index) {
+            let expanded = self.expanded.contains(&index);
+            let icon = if expanded { icons::CHEVRON_DOWN } else { icons::CHEVRON_RIGHT };
+            toggle.child(
+                div()
+                    .id(("table-row-toggle", index))
+                    .cursor_pointer()
+                    .child(
+                        Icon::new(icon)
+                            .size(IconSize::Sm)
+                            .custom_color(theme.alias.color_text_muted)
+                            .to_element(theme),
                     )
+                    .on_click(cx.listener(move |this, _event: &ClickEvent, _window, cx| {
+                        this.set_expanded(index, !expanded, cx);
+                        cx.stop_propagation();
+                    })),
             )
+        } else {
+            // Keeps leaf rows aligned with their expandable siblings
+            toggle
+        };
+
+        div().flex().flex_row().items_center().child(indent).child(toggle)
+    }
+
+    /// Leading section of a row holding the checkbox and frozen columns
+    fn frozen_section(&self, theme: &Theme) -> Div {
+        div()
+            .h_full()
+            .flex()
+            .flex_row()
+            .flex_none()
+            .when(self.props.frozen_columns > 0, |this| {
+                this.border_r(px(1.0)).border_color(theme.alias.color_border)
+            })
+    }
+
+    /// Section of a row holding the unfrozen columns
+    ///
+    /// Every section tracks the same scroll handle, so scrolling the header
+    /// or any row horizontally scrolls them all together.
+    fn scroll_section(&self, id: impl Into<ElementId>) -> Stateful<Div> {
+        div()
+            .id(id)
+            .flex_1()
+            .h_full()
+            .flex()
+            .flex_row()
+            .overflow_x_scroll()
+            .restrict_scroll_to_axis()
+            .track_scroll(&self.horizontal_scroll)
+    }
+
+    /// Render the header row
+    ///
+    /// `focused` shows a focus indicator on the active column.
+    fn render_header_row(&self, theme: &Theme, focused: bool, cx: &mut Context<'_, Self>) -> Div {
+        let mut frozen = self.frozen_section(theme);
+        if self.props.checkbox_column {
+            let multiple = self.props.selection_mode == TableSelectionMode::Multiple;
+            frozen = frozen.child(
+                checkbox_cell(theme)
+                    .id("table-select-all")
+                    .group("checkbox")
+                    .when(multiple, |this| {
+                        this.cursor_pointer().on_click(cx.listener(
+                            |this, _event: &ClickEvent, window, cx| {
+                                this.toggle_select_all(window, cx);
+                            },
+                        ))
+                    })
+                    .child(
+                        Checkbox::new()
+                            .state(self.select_all_state())
+                            .disabled(!multiple)
+                            .to_element(theme),
+                    ),
+            );
+        }
+
+        let mut scrolling = self.scroll_section("table-header-scroll");
+        for (slot, &column) in self.column_order.iter().enumerate() {
+            let cell = self.render_header(slot, column, theme, focused, cx);
+            if slot < self.props.frozen_columns {
+                frozen = frozen.child(cell);
+            } else {
+                scrolling = scrolling.child(cell);
+            }
+        }
+
+        div()
+            .flex()
+            .flex_row()
+            .bg(if theme.is_dark() {
+                theme.global.gray_800
+            } else {
+                theme.global.gray_50
+            })
+            .border_color(theme.alias.color_border)
+            .border_b(px(1.0))
+            .child(frozen)
+            .child(scrolling)
+    }
+
+    /// Render the pagination footer
+    fn render_pagination(&self, theme: &Theme, cx: &mut Context<'_, Self>) -> Div {
+        let page_size = self.props.page_size.unwrap_or(1);
+        let page_count = self.page_count();
+        let page = self.props.page.min(page_count - 1);
+
+        // Page size selector
+        let mut sizes = div()
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap(theme.global.spacing_xs)
+            .child(
+                div()
+                    .mr(theme.global.spacing_xs)
+                    .text_color(theme.alias.color_text_muted)
+                    .child("Rows per page"),
+            );
+        for &size in &self.props.page_sizes {
+            sizes = sizes.child(
+                page_button(("table-page-size", size), size == page_size, theme)
+                    .child(SharedString::from(size.to_string()))
+                    .on_click(cx.listener(move |this, _event: &ClickEvent, window, cx| {
+                        // Keep the first visible row on screen
+                        let first = this.props.page * this.props.page_size.unwrap_or(size);
+                        this.change_page(first / size, size, window, cx);
+                    })),
+            );
+        }
+
+        // Previous, page numbers and next
+        let mut pages = div()
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap(theme.global.spacing_xs)
             .child(
-                // Placeholder for data rows
                 div()
-                    .p(theme.global.spacing_lg)
+                    .mr(theme.global.spacing_sm)
                     .text_color(theme.alias.color_text_muted)
-                    .child("Table rows would go here")
+                    .child(SharedString::from(self.range_text())),
+            );
+
+        let mut prev = page_button("table-page-prev", false, theme)
+            .child(Icon::new(icons::CHEVRON_LEFT).size(IconSize::Sm).to_element(theme));
+        prev = if page == 0 {
+            prev.opacity(0.5).cursor_not_allowed()
+        } else {
+            prev.on_click(cx.listener(move |this, _event: &ClickEvent, window, cx| {
+                this.change_page(page - 1, page_size, window, cx);
+            }))
+        };
+        pages = pages.child(prev);
+
+        // Same page window as the standalone Pagination control
+        for item in page_items(page, page_count, 1, 1) {
+            pages = match item {
+                Some(number) => pages.child(
+                    page_button(("table-page", number), number == page, theme)
+                        .child(SharedString::from((number + 1).to_string()))
+                        .on_click(cx.listener(move |this, _event: &ClickEvent, window, cx| {
+                            this.change_page(number, page_size, window, cx);
+                        })),
+                ),
+                None => pages.child(
+                    div()
+                        .px(theme.global.spacing_xs)
+                        .text_color(theme.alias.color_text_muted)
+                        .child("…"),
+                ),
+            };
+        }
+
+        let mut next = page_button("table-page-next", false, theme)
+            .child(Icon::new(icons::CHEVRON_RIGHT).size(IconSize::Sm).to_element(theme));
+        next = if page + 1 >= page_count {
+            next.opacity(0.5).cursor_not_allowed()
+        } else {
+            next.on_click(cx.listener(move |this, _event: &ClickEvent, window, cx| {
+                this.change_page(page + 1, page_size, window, cx);
+            }))
+        };
+        pages = pages.child(next);
+
+        div()
+            .flex()
+            .flex_row()
+            .items_center()
+            .justify_between()
+            .px(theme.global.spacing_sm)
+            .py(theme.global.spacing_xs)
+            .border_color(theme.alias.color_border)
+            .border_t(px(1.0))
+            .text_size(theme.global.font_size_sm)
+            .child(sizes)
+            .child(pages)
+    }
+
+    /// Render the header cell of `column` at display position `slot`
+    ///
+    /// Sortable columns get a sort indicator, and resizable or reorderable
+    /// tables their drag handles.
+    fn render_header(
+        &self,
+        slot: usize,
+        column: usize,
+        theme: &Theme,
+        focused: bool,
+        cx: &mut Context<'_, Self>,
+    ) -> Stateful<Div> {
+        let col = &self.props.columns[column];
+        let mut cell = col
+            .sized(div().p(theme.global.spacing_sm))
+            .id(("table-header", column))
+            .relative()
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap(theme.global.spacing_xs)
+            .child(
+                Label::new(col.header.clone())
+                    .color(theme.alias.color_text_primary)
+            );
+
+        if focused && self.active_column == Some(column) {
+            cell = cell.border_b(px(2.0)).border_color(theme.alias.color_border_focus);
+        }
+
+        if col.sortable {
+            let direction = self
+                .props
+                .sort
+                .filter(|&(sorted, _)| sorted == column)
+                .map(|(_, direction)| direction);
+            let indicator = match direction {
+                Some(SortDirection::Ascending) => Icon::new(icons::ARROW_UP)
+                    .custom_color(theme.alias.color_text_primary),
+                Some(SortDirection::Descending) => Icon::new(icons::ARROW_DOWN)
+                    .custom_color(theme.alias.color_text_primary),
+                // Dimmed arrow hinting that the column can be sorted
+                None => Icon::new(icons::ARROW_DOWN)
+                    .custom_color(theme.alias.color_text_muted),
+            };
+
+            cell = cell
+                .cursor_pointer()
+                .hover(|style| style.bg(theme.alias.color_surface_hover))
+                .child(indicator.size(IconSize::Sm).to_element(theme));
+        }
+
+        let rearrangeable = self.props.resizable_columns || self.props.reorderable_columns;
+        if col.sortable || rearrangeable {
+            let sortable = col.sortable;
+            cell = cell.on_click(cx.listener(move |this, _event: &ClickEvent, window, cx| {
+                this.active_column = Some(column);
+                if let Some(focus_handle) = &this.focus_handle {
+                    window.focus(focus_handle);
+                }
+                if sortable {
+                    this.toggle_sort(column, window, cx);
+                } else {
+                    cx.notify();
+                }
+            }));
+        }
+
+        if rearrangeable {
+            // Record the painted bounds for resizing
+            let header_bounds = self.header_bounds.clone();
+            cell = cell.child(
+                canvas(
+                    move |bounds, _window, _cx| {
+                        header_bounds.borrow_mut().insert(column, bounds);
+                    },
+                    |_, (), _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            );
+        }
+
+        if self.props.reorderable_columns {
+            let header = col.header.clone();
+            let drop_background = theme.alias.color_surface_hover;
+            cell = cell
+                .on_drag(ColumnMove { column, header }, |drag, _offset, _window, cx| {
+                    cx.new(|_| drag.clone())
+                })
+                .drag_over::<ColumnMove>(move |style, _drag, _window, _cx| style.bg(drop_background))
+                .on_drop(cx.listener(move |this, drag: &ColumnMove, window, cx| {
+                    if let Some(from) = this.column_order.iter().position(|&c| c == drag.column) {
+                        this.move_column(from, slot, window, cx);
+                    }
+                }));
+        }
+
+        if self.props.resizable_columns {
+            cell = cell.child(
+                div()
+                    .id(("table-resize", column))
+                    .absolute()
+                    .top_0()
+                    .right_0()
+                    .h_full()
+                    .w(px(4.0))
+                    .cursor_col_resize()
+                    .hover(|style| style.bg(theme.alias.color_border_focus))
+                    .on_drag(ColumnResize { column }, |drag, _offset, _window, cx| {
+                        cx.new(|_| drag.clone())
+                    })
+                    // Clicking the handle must not sort
+                    .on_click(|_event, _window, cx| cx.stop_propagation()),
+            );
+        }
+
+        cell
+    }
+}
+
+impl<T: 'static> Render for Table<T> {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        let focus_handle = match &self.focus_handle {
+            Some(focus_handle) => focus_handle.clone(),
+            None => {
+                let focus_handle = cx.focus_handle();
+                self._focus_out = Some(cx.on_focus_out(&focus_handle, window, |this, _event, window, cx| {
+                    this.commit_edit(window, cx);
+                }));
+                self.focus_handle = Some(focus_handle.clone());
+                focus_handle
+            }
+        };
+        let focused = focus_handle.is_focused(window);
+
+        let page_range = self.page_range();
+        let sticky_header = self.props.sticky_header;
+        let body = if page_range.is_empty() {
+            div()
+                .p(theme.global.spacing_lg)
+                .text_color(theme.alias.color_text_muted)
+                .child(self.props.empty_message.clone())
+        } else {
+            // A non-sticky header is the first list item, scrolling with the rows
+            let header_items = usize::from(!sticky_header);
+            div().child(
+                VirtualList::new(
+                    "table-body",
+                    page_range.len() + header_items,
+                    theme.density.scale(self.props.row_height),
+                    &self.list_state,
+                )
+                .max_height(self.props.max_height)
+                .to_element(|item| {
+                    if item < header_items {
+                        self.render_header_row(&theme, focused, cx).h_full().into_any_element()
+                    } else {
+                        self.render_row(page_range.start + item - header_items, &theme, focused, cx)
+                            .into_any_element()
+                    }
+                }),
             )
+        };
+
+        div()
+            .id("table")
+            .track_focus(&focus_handle)
+            .on_key_down(cx.listener(Self::handle_key))
+            .on_drag_move(cx.listener(Self::handle_resize_drag))
+            .w_full()
+            .border_color(theme.alias.color_border)
+            .border(px(1.0))
+            .rounded(theme.global.radius_md)
+            .overflow_hidden()
+            .when(sticky_header || page_range.is_empty(), |this| {
+                this.child(self.render_header_row(&theme, focused, cx))
+            })
+            .child(body)
+            .when(self.props.page_size.is_some(), |this| {
+                this.child(self.render_pagination(&theme, cx))
+            })
+    }
+}
+
+impl<T: 'static> Default for Table<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Score = (&'static str, u32);
+
+    fn scores() -> Table<Score> {
+        Table::new()
+            .columns(vec![
+                TableColumn::new("Name").sort_by(|a: &Score, b: &Score| a.0.cmp(b.0)),
+                TableColumn::new("Score").sort_by(|a: &Score, b: &Score| a.1.cmp(&b.1)),
+                TableColumn::new("Rank").sortable(true),
+            ])
+            .rows(vec![("Cara", 70), ("Abe", 90), ("Bo", 80)])
+    }
+
+    #[test]
+    fn test_unsorted_keeps_row_order() {
+        assert_eq!(scores().order, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_sort_with_comparator() {
+        assert_eq!(scores().sort(0, SortDirection::Ascending).order, vec![1, 2, 0]);
+        assert_eq!(scores().sort(1, SortDirection::Descending).order, vec![1, 2, 0]);
+        assert_eq!(scores().sort(1, SortDirection::Ascending).order, vec![0, 2, 1]);
+    }
+
+    #[test]
+    fn test_sort_without_comparator_keeps_row_order() {
+        let table = scores().sort(2, SortDirection::Descending);
+        assert_eq!(table.order, vec![0, 1, 2]);
+        assert!(table.props.columns[2].sortable);
+        assert!(!TableColumn::<u32>::new("Plain").sortable);
+    }
+
+    fn selectable() -> Table<Score> {
+        scores()
+            .row_id(|row: &Score| row.0)
+            .selection_mode(TableSelectionMode::Multiple)
+    }
+
+    fn ids(ids: &[&'static str]) -> Vec<RowId> {
+        ids.iter().map(|&id| id.into()).collect()
+    }
+
+    #[test]
+    fn test_row_ids() {
+        assert_eq!(scores().id_of(1).as_ref(), "1");
+        assert_eq!(selectable().id_of(1).as_ref(), "Abe");
+    }
+
+    #[test]
+    fn test_click_selects_single_row() {
+        let mut table = selectable().default_selected(ids(&["Cara"]));
+        assert_eq!(table.clicked_selection(1, false, false), ids(&["Abe"]));
+        assert_eq!(table.anchor, Some(1));
+    }
+
+    #[test]
+    fn test_toggle_click_adds_and_removes() {
+        let mut table = selectable().default_selected(ids(&["Cara"]));
+        assert_eq!(table.clicked_selection(2, false, true), ids(&["Cara", "Bo"]));
+        assert_eq!(table.clicked_selection(0, false, true), Vec::<RowId>::new());
+    }
+
+    #[test]
+    fn test_shift_click_selects_range_in_display_order() {
+        // Sorted by name: Abe, Bo, Cara
+        let mut table = selectable().sort(0, SortDirection::Ascending);
+        table.clicked_selection(2, false, false);
+        assert_eq!(table.clicked_selection(0, true, false), ids(&["Abe", "Bo", "Cara"]));
+        // The anchor stays put while extending
+        assert_eq!(table.clicked_selection(1, true, false), ids(&["Bo", "Cara"]));
+    }
+
+    #[test]
+    fn test_single_mode_ignores_modifiers() {
+        let mut table = selectable()
+            .selection_mode(TableSelectionMode::Single)
+            .default_selected(ids(&["Cara"]));
+        assert_eq!(table.clicked_selection(1, false, true), ids(&["Abe"]));
+        assert_eq!(table.clicked_selection(2, true, false), ids(&["Bo"]));
+    }
+
+    #[test]
+    fn test_select_all_state() {
+        assert_eq!(selectable().select_all_state(), CheckboxState::Unchecked);
+        let table = selectable().selected(ids(&["Bo"]));
+        assert_eq!(table.select_all_state(), CheckboxState::Indeterminate);
+        assert!(table.props.controlled);
+        let table = selectable().selected(ids(&["Bo", "Abe", "Cara"]));
+        assert_eq!(table.select_all_state(), CheckboxState::Checked);
+    }
+
+    #[test]
+    fn test_client_side_pages() {
+        let table = scores().page_size(2);
+        assert_eq!(table.page_count(), 2);
+        assert_eq!(table.page_range(), 0..2);
+        assert_eq!(table.range_text(), "1–2 of 3");
+
+        let table = table.page(1);
+        assert_eq!(table.page_range(), 2..3);
+        assert_eq!(table.range_text(), "3–3 of 3");
+
+        // Out of range pages clamp to the last one
+        assert_eq!(scores().page_size(2).page(7).page_range(), 2..3);
+    }
+
+    #[test]
+    fn test_server_driven_pages_show_all_rows() {
+        let table = scores().page_size(3).total_rows(30).page(4);
+        assert_eq!(table.page_count(), 10);
+        assert_eq!(table.page_range(), 0..3);
+        assert_eq!(table.range_text(), "13–15 of 30");
+    }
+
+    #[test]
+    fn test_without_pagination() {
+        let table = scores();
+        assert_eq!(table.page_count(), 1);
+        assert_eq!(table.page_range(), 0..3);
+        assert_eq!(Table::<Score>::new().page_size(10).range_text(), "0 of 0");
+    }
+
+    #[test]
+    fn test_header_and_frozen_columns() {
+        let table = scores();
+        assert!(table.props.sticky_header);
+        assert_eq!(table.props.frozen_columns, 0);
+
+        let table = scores().sticky_header(false).freeze_columns(1);
+        assert!(!table.props.sticky_header);
+        assert_eq!(table.props.frozen_columns, 1);
+    }
+
+    #[test]
+    fn test_edited_value_only_when_changed() {
+        let mut table = scores()
+            .row_id(|row: &Score| row.0)
+            .columns(vec![TableColumn::new("Name").editable(|row: &Score| row.0.into())]);
+        assert!(table.edited_value().is_none());
+
+        table.editing = Some(CellEdit {
+            index: 1,
+            column: 0,
+            original: "Abe".into(),
+            text: "Abe".into(),
+        });
+        assert!(table.edited_value().is_none());
+
+        table.editing.as_mut().unwrap().text.push('l');
+        assert_eq!(table.edited_value(), Some(("Abe".into(), 0, "Abel".into())));
+    }
+
+    #[test]
+    fn test_column_layout_restores_valid_layouts() {
+        let layout = TableLayout {
+            order: vec![2, 0, 1],
+            widths: vec![Some(px(10.0)), None, Some(px(300.0))],
+        };
+        let table = scores().column_layout(layout);
+        assert_eq!(table.column_order, vec![2, 0, 1]);
+        // Widths are clamped to the column's minimum
+        assert_eq!(table.current_layout().widths, vec![Some(px(48.0)), None, Some(px(300.0))]);
+
+        let mismatched = TableLayout {
+            order: vec![0, 0, 1],
+            widths: vec![None; 3],
+        };
+        assert_eq!(scores().column_layout(mismatched).column_order, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_clamp_width() {
+        let col = TableColumn::<Score>::new("Name").min_width(px(60.0)).max_width(px(200.0));
+        assert_eq!(col.clamp_width(px(20.0)), px(60.0));
+        assert_eq!(col.clamp_width(px(120.0)), px(120.0));
+        assert_eq!(col.clamp_width(px(500.0)), px(200.0));
+    }
+
+    #[derive(Clone)]
+    struct Node {
+        name: &'static str,
+        children: Vec<Node>,
+    }
+
+    fn node(name: &'static str, children: Vec<Node>) -> Node {
+        Node { name, children }
+    }
+
+    /// Rows: 0 = b, 1 = a, and b's children 2 = b2, 3 = b1
+    fn tree() -> Table<Node> {
+        Table::new()
+            .columns(vec![
+                TableColumn::new("Name").sort_by(|a: &Node, b: &Node| a.name.cmp(b.name)),
+            ])
+            .rows(vec![
+                node("b", vec![node("b2", vec![]), node("b1", vec![])]),
+                node("a", vec![]),
+            ])
+            .child_rows(|n: &Node| n.children.clone())
+    }
+
+    #[test]
+    fn test_tree_children_shown_when_expanded() {
+        let mut table = tree();
+        assert_eq!(table.props.rows.len(), 4);
+        assert_eq!(table.order, vec![0, 1]);
+        assert!(table.can_expand(0));
+        assert!(!table.can_expand(1));
+
+        table.expanded.insert(0);
+        table.sort_rows();
+        assert_eq!(table.order, vec![0, 2, 3, 1]);
+        assert_eq!(table.tree[3].depth, 1);
+        assert_eq!(table.tree[3].parent, Some(0));
+    }
+
+    #[test]
+    fn test_tree_sorts_within_siblings() {
+        let mut table = tree().sort(0, SortDirection::Ascending);
+        table.expanded.insert(0);
+        table.sort_rows();
+        assert_eq!(table.order, vec![1, 0, 3, 2]);
+    }
+
+    #[test]
+    fn test_navigation_across_expanded_rows() {
+        let mut table = tree();
+        assert_eq!(table.navigation_target("down"), Some(0));
+
+        table.expanded.insert(0);
+        table.sort_rows();
+        table.cursor = Some(0);
+        assert_eq!(table.navigation_target("right"), Some(2));
+        assert_eq!(table.navigation_target("up"), Some(0));
+        assert_eq!(table.navigation_target("end"), Some(1));
+
+        table.cursor = Some(3);
+        assert_eq!(table.navigation_target("left"), Some(0));
+        assert_eq!(table.navigation_target("down"), Some(1));
+
+        // Leaf rows have no children to move into
+        table.cursor = Some(1);
+        assert_eq!(table.navigation_target("right"), None);
+        assert_eq!(table.navigation_target("down"), Some(1));
+    }
+
+    #[test]
+    fn test_lazy_children() {
+        let mut table = Table::new()
+            .rows(vec![node("a", vec![node("a1", vec![])]), node("b", vec![])])
+            .load_children(|n: &Node| !n.children.is_empty(), |_, _| Task::ready(Vec::new()));
+        assert!(table.is_tree());
+        assert!(table.can_expand(0));
+        assert!(!table.can_expand(1));
+        assert_eq!(table.props.rows.len(), 2);
+
+        table.add_children(0, vec![node("a1", vec![])]);
+        assert_eq!(table.tree[0].children, Some(vec![2]));
+        assert!(table.can_expand(0));
+    }
+
+    #[test]
+    fn test_sort_direction_reversed() {
+        assert_eq!(SortDirection::Ascending.reversed(), SortDirection::Descending);
+        assert_eq!(SortDirection::Descending.reversed(), SortDirection::Ascending);
     }
 }
@@ -0,0 +1,761 @@
+//! Toast notification system.
+
+use std::{
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use gpui::*;
+use gpui::prelude::FluentBuilder;
+use crate::{
+    atoms::{icons, Button, ButtonSize, ButtonVariant, Icon, IconColor, IconSize},
+    theme::use_theme,
+    utils::{
+        motion::{animation, transition, Easing, SlideFrom, Transition},
+        Announcer, AnnouncerPriority,
+    },
+};
+
+/// How long toasts stay visible when no duration is given
+const DEFAULT_DURATION: Duration = Duration::from_secs(5);
+/// Toasts shown at once before the oldest are dismissed
+const DEFAULT_MAX_VISIBLE: usize = 5;
+/// Width of each toast
+const TOAST_WIDTH: f32 = 360.0;
+
+/// Toast severity variants
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ToastKind {
+    /// Neutral information (default)
+    #[default]
+    Info,
+    /// Successful completion
+    Success,
+    /// Something needs attention
+    Warning,
+    /// Something failed
+    Error,
+}
+
+impl ToastKind {
+    /// Icon shown beside the toast title
+    fn icon(self) -> &'static str {
+        match self {
+            Self::Info => icons::INFO,
+            Self::Success => icons::CHECK_CIRCLE,
+            Self::Warning => icons::ALERT_TRIANGLE,
+            Self::Error => icons::X_CIRCLE,
+        }
+    }
+
+    /// Color of the toast icon
+    fn icon_color(self) -> IconColor {
+        match self {
+            Self::Info => IconColor::Primary,
+            Self::Success => IconColor::Success,
+            Self::Warning => IconColor::Warning,
+            Self::Error => IconColor::Danger,
+        }
+    }
+
+    /// How urgently screen readers announce the toast
+    fn priority(self) -> AnnouncerPriority {
+        match self {
+            Self::Info | Self::Success => AnnouncerPriority::Polite,
+            Self::Warning | Self::Error => AnnouncerPriority::Assertive,
+        }
+    }
+}
+
+/// Where toasts stack on screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ToastPlacement {
+    /// Top-left corner
+    TopLeft,
+    /// Top edge, centered
+    TopCenter,
+    /// Top-right corner
+    TopRight,
+    /// Bottom-left corner
+    BottomLeft,
+    /// Bottom edge, centered
+    BottomCenter,
+    /// Bottom-right corner (default)
+    #[default]
+    BottomRight,
+}
+
+impl ToastPlacement {
+    /// Whether toasts stack down from the top edge
+    fn is_top(self) -> bool {
+        matches!(self, Self::TopLeft | Self::TopCenter | Self::TopRight)
+    }
+}
+
+/// Identifies a shown toast so it can be dismissed early
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ToastId(usize);
+
+/// A button shown on a toast
+///
+/// Clicking it runs the handler and dismisses the toast.
+#[derive(Clone)]
+pub struct ToastAction {
+    /// Button label
+    pub label: SharedString,
+    /// Click handler
+    pub handler: Rc<dyn Fn(&mut Window, &mut App)>,
+}
+
+/// A toast notification
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::organisms::*;
+///
+/// let toast = Toast::new(ToastKind::Success, "File deleted")
+///     .description("report.pdf was moved to the trash")
+///     .action("Undo", |window, cx| restore_file(window, cx))
+///     .duration(Duration::from_secs(8));
+/// ```
+#[derive(Clone)]
+pub struct Toast {
+    /// Toast severity
+    pub kind: ToastKind,
+    /// Main message
+    pub title: SharedString,
+    /// Secondary text below the title
+    pub description: Option<SharedString>,
+    /// Buttons shown below the message
+    pub actions: Vec<ToastAction>,
+    /// How long the toast stays visible; `None` uses the manager's default
+    pub duration: Option<Duration>,
+    /// Whether the toast stays until dismissed
+    pub persistent: bool,
+}
+
+impl Toast {
+    /// Create a new toast
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let toast = Toast::new(ToastKind::Info, "Update available");
+    /// ```
+    pub fn new(kind: ToastKind, title: impl Into<SharedString>) -> Self {
+        Self {
+            kind,
+            title: title.into(),
+            description: None,
+            actions: Vec::new(),
+            duration: None,
+            persistent: false,
+        }
+    }
+
+    /// Set the secondary text below the title
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Toast::new(ToastKind::Error, "Upload failed").description("The server is unreachable");
+    /// ```
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Add an action button
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Toast::new(ToastKind::Error, "Upload failed").action("Retry", |window, cx| retry(window, cx));
+    /// ```
+    pub fn action(
+        mut self,
+        label: impl Into<SharedString>,
+        handler: impl Fn(&mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.actions.push(ToastAction {
+            label: label.into(),
+            handler: Rc::new(handler),
+        });
+        self
+    }
+
+    /// Set how long the toast stays visible
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Toast::new(ToastKind::Info, "Copied").duration(Duration::from_secs(2));
+    /// ```
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Set whether the toast stays until dismissed
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Toast::new(ToastKind::Warning, "You are offline").persistent(true);
+    /// ```
+    pub fn persistent(mut self, persistent: bool) -> Self {
+        self.persistent = persistent;
+        self
+    }
+
+    /// Text read to screen readers
+    fn announcement(&self) -> SharedString {
+        match &self.description {
+            Some(description) => format!("{}. {description}", self.title).into(),
+            None => self.title.clone(),
+        }
+    }
+}
+
+/// Auto-dismiss countdown that can be paused while the toast is hovered
+#[derive(Debug, Clone, Copy)]
+struct DismissTimer {
+    /// Time left as of the last pause
+    remaining: Duration,
+    /// When the countdown last resumed, `None` while paused
+    running_since: Option<Instant>,
+}
+
+impl DismissTimer {
+    /// Create a paused countdown of `duration`
+    fn new(duration: Duration) -> Self {
+        Self {
+            remaining: duration,
+            running_since: None,
+        }
+    }
+
+    /// Time left at `now`
+    fn remaining_at(&self, now: Instant) -> Duration {
+        match self.running_since {
+            Some(since) => self.remaining.saturating_sub(now.saturating_duration_since(since)),
+            None => self.remaining,
+        }
+    }
+
+    /// Resume counting down from `now`
+    fn resume_at(&mut self, now: Instant) {
+        if self.running_since.is_none() {
+            self.running_since = Some(now);
+        }
+    }
+
+    /// Stop counting down at `now`, keeping the time left
+    fn pause_at(&mut self, now: Instant) {
+        self.remaining = self.remaining_at(now);
+        self.running_since = None;
+    }
+}
+
+/// A toast currently on screen
+struct ActiveToast {
+    id: ToastId,
+    toast: Toast,
+    /// `None` for persistent toasts
+    timer: Option<DismissTimer>,
+    /// Dismisses the toast when its timer runs out; dropped while paused
+    _dismiss_task: Option<Task<()>>,
+}
+
+/// Global slot holding the application-wide [`ToastManager`]
+struct GlobalToastManager(Entity<ToastManager>);
+
+impl Global for GlobalToastManager {}
+
+/// Shows and stacks toast notifications.
+///
+/// ToastManager is an entity rendered once near the root of a window, above
+/// the rest of the content. Toasts stack at the configured placement,
+/// newest nearest the screen edge, and dismiss themselves after a few
+/// seconds. Hovering a toast pauses its timer.
+///
+/// Create one per window with `cx.new`, or register an application-wide
+/// manager with [`ToastManager::init`] and reach it from anywhere with
+/// [`ToastManager::global`].
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::organisms::*;
+///
+/// // At startup
+/// let toasts = ToastManager::init(cx);
+///
+/// // In the root view's render
+/// div().size_full().child(content).child(toasts.clone());
+///
+/// // Anywhere else
+/// if let Some(toasts) = ToastManager::global(cx) {
+///     toasts.update(cx, |toasts, cx| {
+///         toasts.toast_success("Settings saved", cx);
+///     });
+/// }
+/// ```
+///
+/// ## Accessibility
+///
+/// - Each toast is announced through the [`Announcer`] live region, which
+///   the application renders with [`Announcer::live_region`]
+/// - Errors and warnings are announced assertively, others politely
+/// - Hovering a toast pauses auto-dismiss so it can be read
+pub struct ToastManager {
+    toasts: Vec<ActiveToast>,
+    placement: ToastPlacement,
+    default_duration: Duration,
+    max_visible: usize,
+    next_id: usize,
+}
+
+impl ToastManager {
+    /// Create a new toast manager with no toasts
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let toasts = cx.new(|_| ToastManager::new().placement(ToastPlacement::TopRight));
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            toasts: Vec::new(),
+            placement: ToastPlacement::default(),
+            default_duration: DEFAULT_DURATION,
+            max_visible: DEFAULT_MAX_VISIBLE,
+            next_id: 0,
+        }
+    }
+
+    /// Create a toast manager and register it as the application-wide manager
+    ///
+    /// The returned entity still needs to be rendered in each window that
+    /// should show toasts.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let toasts = ToastManager::init(cx);
+    /// ```
+    pub fn init(cx: &mut App) -> Entity<Self> {
+        let manager = cx.new(|_| Self::new());
+        cx.set_global(GlobalToastManager(manager.clone()));
+        manager
+    }
+
+    /// The manager registered with [`ToastManager::init`], if any
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// if let Some(toasts) = ToastManager::global(cx) {
+    ///     toasts.update(cx, |toasts, cx| toasts.toast_error("Sync failed", cx));
+    /// }
+    /// ```
+    pub fn global(cx: &App) -> Option<Entity<Self>> {
+        cx.try_global::<GlobalToastManager>().map(|global| global.0.clone())
+    }
+
+    /// Set where toasts stack on screen
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ToastManager::new().placement(ToastPlacement::TopCenter);
+    /// ```
+    pub fn placement(mut self, placement: ToastPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Set how long toasts without their own duration stay visible
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ToastManager::new().default_duration(Duration::from_secs(3));
+    /// ```
+    pub fn default_duration(mut self, duration: Duration) -> Self {
+        self.default_duration = duration;
+        self
+    }
+
+    /// Set how many toasts are shown at once
+    ///
+    /// Showing another toast beyond this dismisses the oldest one.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ToastManager::new().max_visible(3);
+    /// ```
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible.max(1);
+        self
+    }
+
+    /// Show a toast
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let id = toasts.show(
+    ///     Toast::new(ToastKind::Info, "Exporting…").persistent(true),
+    ///     cx,
+    /// );
+    /// ```
+    pub fn show(&mut self, toast: Toast, cx: &mut Context<'_, Self>) -> ToastId {
+        let id = ToastId(self.next_id);
+        self.next_id += 1;
+
+        Announcer::new(toast.kind.priority())
+            .message(toast.announcement())
+            .announce(cx);
+
+        let timer = (!toast.persistent)
+            .then(|| DismissTimer::new(toast.duration.unwrap_or(self.default_duration)));
+        self.toasts.push(ActiveToast {
+            id,
+            toast,
+            timer,
+            _dismiss_task: None,
+        });
+        self.resume(id, cx);
+
+        let overflow = self.toasts.len().saturating_sub(self.max_visible);
+        self.toasts.drain(..overflow);
+
+        cx.notify();
+        id
+    }
+
+    /// Show a success toast
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// toasts.toast_success("Settings saved", cx);
+    /// ```
+    pub fn toast_success(&mut self, title: impl Into<SharedString>, cx: &mut Context<'_, Self>) -> ToastId {
+        self.show(Toast::new(ToastKind::Success, title), cx)
+    }
+
+    /// Show an error toast
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// toasts.toast_error("Could not connect to the server", cx);
+    /// ```
+    pub fn toast_error(&mut self, title: impl Into<SharedString>, cx: &mut Context<'_, Self>) -> ToastId {
+        self.show(Toast::new(ToastKind::Error, title), cx)
+    }
+
+    /// Show a warning toast
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// toasts.toast_warning("Battery low", cx);
+    /// ```
+    pub fn toast_warning(&mut self, title: impl Into<SharedString>, cx: &mut Context<'_, Self>) -> ToastId {
+        self.show(Toast::new(ToastKind::Warning, title), cx)
+    }
+
+    /// Show an informational toast
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// toasts.toast_info("A new version is available", cx);
+    /// ```
+    pub fn toast_info(&mut self, title: impl Into<SharedString>, cx: &mut Context<'_, Self>) -> ToastId {
+        self.show(Toast::new(ToastKind::Info, title), cx)
+    }
+
+    /// Dismiss a toast before its timer runs out
+    ///
+    /// Does nothing if the toast is already gone.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let id = toasts.show(Toast::new(ToastKind::Info, "Exporting…").persistent(true), cx);
+    /// // Later, once the export finishes
+    /// toasts.dismiss(id, cx);
+    /// ```
+    pub fn dismiss(&mut self, id: ToastId, cx: &mut Context<'_, Self>) {
+        let before = self.toasts.len();
+        self.toasts.retain(|active| active.id != id);
+        if self.toasts.len() != before {
+            cx.notify();
+        }
+    }
+
+    /// Dismiss every toast
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// toasts.dismiss_all(cx);
+    /// ```
+    pub fn dismiss_all(&mut self, cx: &mut Context<'_, Self>) {
+        if !self.toasts.is_empty() {
+            self.toasts.clear();
+            cx.notify();
+        }
+    }
+
+    /// Number of toasts on screen
+    pub fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    /// Whether no toasts are on screen
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+
+    /// Start or continue a toast's countdown, dismissing it when it runs out
+    fn resume(&mut self, id: ToastId, cx: &mut Context<'_, Self>) {
+        let Some(active) = self.toasts.iter_mut().find(|active| active.id == id) else {
+            return;
+        };
+        let Some(timer) = active.timer.as_mut() else {
+            return;
+        };
+
+        let now = Instant::now();
+        timer.resume_at(now);
+        let remaining = timer.remaining_at(now);
+        active._dismiss_task = Some(cx.spawn(async move |this, cx| {
+            cx.background_executor().timer(remaining).await;
+            this.update(cx, |this, cx| this.dismiss(id, cx)).ok();
+        }));
+    }
+
+    /// Stop a toast's countdown while it is hovered
+    fn pause(&mut self, id: ToastId) {
+        let Some(active) = self.toasts.iter_mut().find(|active| active.id == id) else {
+            return;
+        };
+        if let Some(timer) = active.timer.as_mut() {
+            timer.pause_at(Instant::now());
+            active._dismiss_task = None;
+        }
+    }
+
+    /// Render one toast
+    fn render_toast(&self, active: &ActiveToast, cx: &mut Context<'_, Self>) -> Stateful<Div> {
+        let theme = use_theme(cx);
+        let id = active.id;
+        let toast = &active.toast;
+
+        let actions: Vec<Stateful<Div>> = toast.actions.iter().enumerate().map(|(index, action)| {
+            let handler = action.handler.clone();
+            Button::new()
+                .id(("toast-action", index))
+                .label(action.label.clone())
+                .variant(ButtonVariant::Outline)
+                .size(ButtonSize::Sm)
+                .on_click(cx.listener(move |this, _event: &ClickEvent, window, cx| {
+                    handler(window, cx);
+                    this.dismiss(id, cx);
+                }))
+                .to_element(&theme)
+        }).collect();
+
+        div()
+            .id(("toast", id.0))
+            .on_hover(cx.listener(move |this, hovered: &bool, _window, cx| {
+                if *hovered {
+                    this.pause(id);
+                } else {
+                    this.resume(id, cx);
+                }
+            }))
+            .w(px(TOAST_WIDTH))
+            .max_w_full()
+            .flex()
+            .flex_row()
+            .items_start()
+            .gap(theme.global.spacing_sm)
+            .p(theme.global.spacing_base)
+            .bg(theme.alias.color_surface_elevated)
+            .border(px(1.0))
+            .border_color(theme.alias.color_border)
+            .rounded(theme.global.radius_md)
+            .shadow_lg()
+            .child(
+                Icon::new(toast.kind.icon())
+                    .size(IconSize::Md)
+                    .color(toast.kind.icon_color())
+                    .to_element(&theme),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .flex()
+                    .flex_col()
+                    .gap(theme.global.spacing_xs)
+                    .child(
+                        div()
+                            .text_size(theme.global.font_size_sm)
+                            .font_weight(FontWeight::SEMIBOLD)
+                            .text_color(theme.alias.color_text_primary)
+                            .child(toast.title.clone()),
+                    )
+                    .when_some(toast.description.clone(), |this, description| {
+                        this.child(
+                            div()
+                                .text_size(theme.global.font_size_sm)
+                                .text_color(theme.alias.color_text_secondary)
+                                .child(description),
+                        )
+                    })
+                    .when(!toast.actions.is_empty(), |this| {
+                        this.child(
+                            div()
+                                .flex()
+                                .flex_row()
+                                .gap(theme.global.spacing_sm)
+                                .pt(theme.global.spacing_xs)
+                                .children(actions),
+                        )
+                    }),
+            )
+            .child(
+                Button::new()
+                    .id("toast-close")
+                    .label("Dismiss")
+                    .icon_left(icons::X)
+                    .icon_only(true)
+                    .variant(ButtonVariant::Ghost)
+                    .size(ButtonSize::Sm)
+                    .on_click(cx.listener(move |this, _event: &ClickEvent, _window, cx| {
+                        this.dismiss(id, cx);
+                    }))
+                    .to_element(&theme),
+            )
+    }
+}
+
+impl Default for ToastManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Render for ToastManager {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        let placement = self.placement;
+
+        // Newest toasts sit nearest the screen edge, sliding in from it
+        let enter = animation(theme.global.duration_normal, Easing::Decelerate);
+        let from = if placement.is_top() { SlideFrom::Top } else { SlideFrom::Bottom };
+        let mut toasts: Vec<_> = self
+            .toasts
+            .iter()
+            .map(|active| {
+                transition(
+                    self.render_toast(active, cx),
+                    ("toast-enter", active.id.0),
+                    Transition::Slide { from, distance: px(16.0) },
+                    true,
+                    enter.clone(),
+                    cx,
+                )
+            })
+            .collect();
+        if placement.is_top() {
+            toasts.reverse();
+        }
+
+        div()
+            .fixed()
+            .left(px(0.0))
+            .right(px(0.0))
+            .flex()
+            .flex_col()
+            .gap(theme.global.spacing_sm)
+            .p(theme.global.spacing_base)
+            .map(|stack| {
+                if placement.is_top() {
+                    stack.top(px(0.0))
+                } else {
+                    stack.bottom(px(0.0))
+                }
+            })
+            .map(|stack| match placement {
+                ToastPlacement::TopLeft | ToastPlacement::BottomLeft => stack.items_start(),
+                ToastPlacement::TopCenter | ToastPlacement::BottomCenter => stack.items_center(),
+                ToastPlacement::TopRight | ToastPlacement::BottomRight => stack.items_end(),
+            })
+            .children(toasts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timer_counts_down_while_running() {
+        let start = Instant::now();
+        let mut timer = DismissTimer::new(Duration::from_secs(5));
+        assert_eq!(timer.remaining_at(start + Duration::from_secs(2)), Duration::from_secs(5));
+
+        timer.resume_at(start);
+        assert_eq!(timer.remaining_at(start + Duration::from_secs(2)), Duration::from_secs(3));
+        assert_eq!(timer.remaining_at(start + Duration::from_secs(9)), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_timer_pause_keeps_remaining_time() {
+        let start = Instant::now();
+        let mut timer = DismissTimer::new(Duration::from_secs(5));
+        timer.resume_at(start);
+        timer.pause_at(start + Duration::from_secs(2));
+
+        // Hovering for a while does not use up the countdown
+        assert_eq!(timer.remaining_at(start + Duration::from_secs(60)), Duration::from_secs(3));
+
+        timer.resume_at(start + Duration::from_secs(60));
+        assert_eq!(timer.remaining_at(start + Duration::from_secs(61)), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_resume_while_running_keeps_start() {
+        let start = Instant::now();
+        let mut timer = DismissTimer::new(Duration::from_secs(5));
+        timer.resume_at(start);
+        timer.resume_at(start + Duration::from_secs(3));
+        assert_eq!(timer.remaining_at(start + Duration::from_secs(4)), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_errors_announced_assertively() {
+        assert_eq!(ToastKind::Error.priority(), AnnouncerPriority::Assertive);
+        assert_eq!(ToastKind::Warning.priority(), AnnouncerPriority::Assertive);
+        assert_eq!(ToastKind::Success.priority(), AnnouncerPriority::Polite);
+        assert_eq!(ToastKind::Info.priority(), AnnouncerPriority::Polite);
+    }
+
+    #[test]
+    fn test_announcement_includes_description() {
+        let toast = Toast::new(ToastKind::Error, "Upload failed");
+        assert_eq!(toast.announcement().as_ref(), "Upload failed");
+
+        let toast = toast.description("The server is unreachable");
+        assert_eq!(toast.announcement().as_ref(), "Upload failed. The server is unreachable");
+    }
+}
@@ -0,0 +1,1225 @@
+//! TreeView component for hierarchical data.
+
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use gpui::*;
+use gpui::prelude::FluentBuilder;
+use crate::{
+    atoms::{Icon, IconSize, Spinner, SpinnerSize, icons},
+    layout::{VirtualList, VirtualListState},
+    theme::{use_theme, Theme},
+    utils::{motion::reduced_motion, Announcer},
+};
+
+/// Stable identifier of a tree node, used to report selections and moves
+pub type TreeNodeId = SharedString;
+
+/// Loads the children of a tree node on first expansion
+pub type TreeChildLoader<T> = Rc<dyn Fn(&T, &mut App) -> Task<Vec<T>>>;
+
+/// Returns the SVG icon path of a node, given whether it is expanded
+pub type TreeIcon<T> = Rc<dyn Fn(&T, bool) -> Option<SharedString>>;
+
+/// Tree node selection behavior
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TreeSelectionMode {
+    /// Nodes cannot be selected
+    None,
+    /// At most one node is selected
+    #[default]
+    Single,
+    /// Any number of nodes, with shift ranges and cmd/ctrl-click toggling
+    Multiple,
+}
+
+/// Where a dragged node was dropped relative to the target node
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreeDropPosition {
+    /// Before the target, as its previous sibling
+    Before,
+    /// Inside the target, as its child
+    Inside,
+    /// After the target, as its next sibling
+    After,
+}
+
+/// A node dragged onto another node
+///
+/// The tree does not rearrange its nodes itself; the `on_move` handler
+/// updates the data and applies it with [`TreeView::set_roots`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeMove {
+    /// Id of the dragged node
+    pub node: TreeNodeId,
+    /// Id of the node it was dropped on
+    pub target: TreeNodeId,
+    /// Where it was dropped relative to the target
+    pub position: TreeDropPosition,
+}
+
+/// Position of a node in the tree
+#[derive(Debug, Clone, Default)]
+struct TreeNode {
+    /// Index of the parent node in `nodes`
+    parent: Option<usize>,
+    /// Nesting level, zero for root nodes
+    depth: usize,
+    /// Indices of the child nodes in `nodes`, `None` until loaded
+    children: Option<Vec<usize>>,
+}
+
+/// Drag payload for moving a node
+#[derive(Clone)]
+struct TreeDrag {
+    index: usize,
+    label: SharedString,
+}
+
+impl Render for TreeDrag {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        div()
+            .px(theme.global.spacing_sm)
+            .py(theme.global.spacing_xs)
+            .bg(theme.alias.color_surface_elevated)
+            .border_color(theme.alias.color_border_focus)
+            .border(px(1.0))
+            .rounded(theme.global.radius_sm)
+            .shadow_md()
+            .text_size(theme.global.font_size_sm)
+            .text_color(theme.alias.color_text_primary)
+            .child(self.label.clone())
+    }
+}
+
+/// Drop position for a pointer `fraction` of the way down a row
+///
+/// The top and bottom quarters drop beside the row, the middle inside it.
+fn drop_position(fraction: f32) -> TreeDropPosition {
+    if fraction < 0.25 {
+        TreeDropPosition::Before
+    } else if fraction > 0.75 {
+        TreeDropPosition::After
+    } else {
+        TreeDropPosition::Inside
+    }
+}
+
+/// TreeView configuration properties
+#[derive(Clone)]
+pub struct TreeViewProps<T> {
+    /// Root nodes, followed by the loaded child nodes
+    pub nodes: Vec<T>,
+    /// Derives a node's id, falling back to its index in `nodes`
+    pub node_id: Option<Rc<dyn Fn(&T) -> TreeNodeId>>,
+    /// Derives a node's label, falling back to its id
+    pub label: Option<Rc<dyn Fn(&T) -> SharedString>>,
+    /// Derives a node's icon
+    pub icon: Option<TreeIcon<T>>,
+    /// Returns the child nodes of a node
+    pub child_nodes: Option<Rc<dyn Fn(&T) -> Vec<T>>>,
+    /// Whether a node has children to load, for trees with a child loader
+    pub has_children: Option<Rc<dyn Fn(&T) -> bool>>,
+    /// Loads child nodes on first expansion
+    pub child_loader: Option<TreeChildLoader<T>>,
+    /// Ids of nodes expanded when the tree is built
+    pub default_expanded: Vec<TreeNodeId>,
+    /// Indentation of each level
+    pub indent: Pixels,
+    /// Height of each row at comfortable density
+    ///
+    /// Scaled by the theme [`Density`](crate::theme::Density) when rendered.
+    pub row_height: Pixels,
+    /// Maximum height of the scrolling tree
+    pub max_height: Pixels,
+    /// Message shown when there are no nodes
+    pub empty_message: SharedString,
+    /// Node selection behavior
+    pub selection_mode: TreeSelectionMode,
+    /// Ids of the selected nodes
+    pub selected: Vec<TreeNodeId>,
+    /// Handler called with the selected node ids when the selection changes
+    pub on_selection_change: Option<Rc<dyn Fn(Vec<TreeNodeId>, &mut Window, &mut App)>>,
+    /// Whether the selection is owned by the parent view
+    ///
+    /// Controlled trees only report selections through
+    /// `on_selection_change`; the parent applies them with
+    /// [`TreeView::set_selected`].
+    pub controlled: bool,
+    /// Handler called with a node's id when it is activated with Enter or a double click
+    pub on_activate: Option<Rc<dyn Fn(TreeNodeId, &mut Window, &mut App)>>,
+    /// Handler called with a node's id and new state when it is expanded or collapsed
+    pub on_expand: Option<Rc<dyn Fn(TreeNodeId, bool, &mut Window, &mut App)>>,
+    /// Whether nodes can be dragged onto other nodes
+    pub reorderable: bool,
+    /// Handler called when a node is dropped onto another node
+    pub on_move: Option<Rc<dyn Fn(TreeMove, &mut Window, &mut App)>>,
+}
+
+impl<T> Default for TreeViewProps<T> {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            node_id: None,
+            label: None,
+            icon: None,
+            child_nodes: None,
+            has_children: None,
+            child_loader: None,
+            default_expanded: Vec::new(),
+            indent: px(16.0),
+            row_height: px(28.0),
+            max_height: px(400.0),
+            empty_message: "No items".into(),
+            selection_mode: TreeSelectionMode::default(),
+            selected: Vec::new(),
+            on_selection_change: None,
+            controlled: false,
+            on_activate: None,
+            on_expand: None,
+            reorderable: false,
+            on_move: None,
+        }
+    }
+}
+
+/// A tree of expandable nodes for hierarchical data.
+///
+/// Children come either all at once from `child_nodes` or lazily from a
+/// loader on first expansion. Only the visible rows are rendered, so large
+/// trees stay fast.
+///
+/// ## Features
+///
+/// - Expand/collapse with chevrons, lazy child loading with a spinner
+/// - Single or multiple selection
+/// - Keyboard navigation following the ARIA tree pattern
+/// - Drag-to-reorder hooks reporting before/inside/after drops
+/// - Virtualized rows
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::organisms::*;
+///
+/// TreeView::<Entry>::new()
+///     .roots(workspace.entries.clone())
+///     .node_id(|entry: &Entry| entry.path.clone())
+///     .label(|entry: &Entry| entry.name.clone())
+///     .icon(|entry: &Entry, _expanded| {
+///         Some(if entry.is_dir { icons::FOLDER } else { icons::FILE }.into())
+///     })
+///     .load_children(|entry| entry.is_dir, |entry, cx| read_dir(entry.path.clone(), cx))
+///     .on_activate(|path, _window, cx| open_file(path, cx));
+/// ```
+///
+/// ## Accessibility
+///
+/// - Up/Down move between visible nodes, Home/End jump to the first/last
+/// - Right expands a node or moves to its first child; Left collapses it or moves to its parent
+/// - Enter activates, Space selects, `*` expands all siblings
+/// - Typing a character moves to the next node whose label starts with it
+/// - Lazily loaded children are announced to screen readers when they arrive
+pub struct TreeView<T: 'static> {
+    props: TreeViewProps<T>,
+    list_state: VirtualListState,
+    /// Number of root nodes at the start of `nodes`
+    root_count: usize,
+    /// Tree position of each node in `nodes`
+    tree: Vec<TreeNode>,
+    /// Node indices in display order
+    order: Vec<usize>,
+    /// Indices of expanded nodes
+    expanded: HashSet<usize>,
+    /// Child loads in flight, by node index; dropping one cancels it
+    loading: HashMap<usize, Task<()>>,
+    /// Index of the keyboard-focused node in `nodes`
+    cursor: Option<usize>,
+    /// Display position that shift ranges extend from
+    anchor: Option<usize>,
+    /// Node and position under the pointer while dragging
+    drop_target: Option<(usize, TreeDropPosition)>,
+    focus_handle: Option<FocusHandle>,
+}
+
+impl<T: 'static> TreeView<T> {
+    /// Create a new empty tree
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let tree = TreeView::<Entry>::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            props: TreeViewProps::default(),
+            list_state: VirtualListState::new(),
+            root_count: 0,
+            tree: Vec::new(),
+            order: Vec::new(),
+            expanded: HashSet::new(),
+            loading: HashMap::new(),
+            cursor: None,
+            anchor: None,
+            drop_target: None,
+            focus_handle: None,
+        }
+    }
+
+    /// Set the root nodes
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TreeView::<Entry>::new().roots(entries);
+    /// ```
+    pub fn roots(mut self, roots: Vec<T>) -> Self {
+        self.root_count = roots.len();
+        self.props.nodes = roots;
+        self.build_tree();
+        self
+    }
+
+    /// Replace the root nodes from the parent view
+    ///
+    /// Expansion is kept for nodes whose ids are still present.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// tree.update(cx, |tree, cx| tree.set_roots(entries, cx));
+    /// ```
+    pub fn set_roots(&mut self, roots: Vec<T>, cx: &mut Context<'_, Self>) {
+        let expanded: Vec<TreeNodeId> = self.expanded.iter().map(|&index| self.id_of(index)).collect();
+        let default_expanded = std::mem::replace(&mut self.props.default_expanded, expanded);
+        self.root_count = roots.len();
+        self.props.nodes = roots;
+        self.build_tree();
+        self.props.default_expanded = default_expanded;
+        cx.notify();
+    }
+
+    /// Read each node's children with `children`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TreeView::<Employee>::new().child_nodes(|employee| employee.reports.clone());
+    /// ```
+    pub fn child_nodes(mut self, children: impl Fn(&T) -> Vec<T> + 'static) -> Self {
+        self.props.child_nodes = Some(Rc::new(children));
+        self.build_tree();
+        self
+    }
+
+    /// Load each node's children on first expansion
+    ///
+    /// `has_children` decides which nodes show an expand chevron before
+    /// their children are loaded.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TreeView::<Entry>::new().load_children(
+    ///     |entry| entry.is_dir,
+    ///     |entry, cx| read_dir(entry.path.clone(), cx),
+    /// );
+    /// ```
+    pub fn load_children(
+        mut self,
+        has_children: impl Fn(&T) -> bool + 'static,
+        loader: impl Fn(&T, &mut App) -> Task<Vec<T>> + 'static,
+    ) -> Self {
+        self.props.has_children = Some(Rc::new(has_children));
+        self.props.child_loader = Some(Rc::new(loader));
+        self
+    }
+
+    /// Derive each node's id
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TreeView::<Entry>::new().node_id(|entry: &Entry| entry.path.clone());
+    /// ```
+    pub fn node_id<I: Into<TreeNodeId>>(mut self, node_id: impl Fn(&T) -> I + 'static) -> Self {
+        self.props.node_id = Some(Rc::new(move |node| node_id(node).into()));
+        self.build_tree();
+        self
+    }
+
+    /// Derive each node's label
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TreeView::<Entry>::new().label(|entry: &Entry| entry.name.clone());
+    /// ```
+    pub fn label<L: Into<SharedString>>(mut self, label: impl Fn(&T) -> L + 'static) -> Self {
+        self.props.label = Some(Rc::new(move |node| label(node).into()));
+        self
+    }
+
+    /// Derive each node's icon from the node and whether it is expanded
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TreeView::<Entry>::new().icon(|entry: &Entry, _expanded| {
+    ///     Some(if entry.is_dir { icons::FOLDER } else { icons::FILE }.into())
+    /// });
+    /// ```
+    pub fn icon(mut self, icon: impl Fn(&T, bool) -> Option<SharedString> + 'static) -> Self {
+        self.props.icon = Some(Rc::new(icon));
+        self
+    }
+
+    /// Expand the nodes with these ids when the tree is built
+    ///
+    /// Only applies to nodes whose children are read with `child_nodes`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TreeView::<Entry>::new().default_expanded(vec!["src".into()]);
+    /// ```
+    pub fn default_expanded(mut self, ids: Vec<TreeNodeId>) -> Self {
+        self.props.default_expanded = ids;
+        self.build_tree();
+        self
+    }
+
+    /// Set the indentation of each level
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TreeView::<Entry>::new().indent(px(12.0));
+    /// ```
+    pub fn indent(mut self, indent: Pixels) -> Self {
+        self.props.indent = indent;
+        self
+    }
+
+    /// Set the height of each row
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TreeView::<Entry>::new().row_height(px(24.0));
+    /// ```
+    pub fn row_height(mut self, row_height: Pixels) -> Self {
+        self.props.row_height = row_height;
+        self
+    }
+
+    /// Set the maximum height of the scrolling tree
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TreeView::<Entry>::new().max_height(px(600.0));
+    /// ```
+    pub fn max_height(mut self, max_height: Pixels) -> Self {
+        self.props.max_height = max_height;
+        self
+    }
+
+    /// Set the message shown when there are no nodes
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TreeView::<Entry>::new().empty_message("This folder is empty");
+    /// ```
+    pub fn empty_message(mut self, message: impl Into<SharedString>) -> Self {
+        self.props.empty_message = message.into();
+        self
+    }
+
+    /// Set the node selection behavior
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TreeView::<Entry>::new().selection_mode(TreeSelectionMode::Multiple);
+    /// ```
+    pub fn selection_mode(mut self, mode: TreeSelectionMode) -> Self {
+        self.props.selection_mode = mode;
+        self
+    }
+
+    /// Set the selected node ids, making the selection controlled
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TreeView::<Entry>::new().selected(vec!["src/main.rs".into()]);
+    /// ```
+    pub fn selected(mut self, selected: Vec<TreeNodeId>) -> Self {
+        self.props.selected = selected;
+        self.props.controlled = true;
+        self
+    }
+
+    /// Set the initially selected node ids of an uncontrolled tree
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TreeView::<Entry>::new().default_selected(vec!["src/main.rs".into()]);
+    /// ```
+    pub fn default_selected(mut self, selected: Vec<TreeNodeId>) -> Self {
+        self.props.selected = selected;
+        self.props.controlled = false;
+        self
+    }
+
+    /// Set the handler called when the selection changes
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TreeView::<Entry>::new().on_selection_change(|ids, _window, cx| preview(ids, cx));
+    /// ```
+    pub fn on_selection_change(
+        mut self,
+        handler: impl Fn(Vec<TreeNodeId>, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_selection_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Update the selection from the parent view
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// tree.update(cx, |tree, cx| tree.set_selected(Vec::new(), cx));
+    /// ```
+    pub fn set_selected(&mut self, selected: Vec<TreeNodeId>, cx: &mut Context<'_, Self>) {
+        if self.props.selected != selected {
+            self.props.selected = selected;
+            cx.notify();
+        }
+    }
+
+    /// Set the handler called when a node is activated with Enter or a double click
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TreeView::<Entry>::new().on_activate(|path, _window, cx| open_file(path, cx));
+    /// ```
+    pub fn on_activate(mut self, handler: impl Fn(TreeNodeId, &mut Window, &mut App) + 'static) -> Self {
+        self.props.on_activate = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the handler called when a node is expanded or collapsed
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TreeView::<Entry>::new().on_expand(|path, expanded, _window, cx| remember(path, expanded, cx));
+    /// ```
+    pub fn on_expand(
+        mut self,
+        handler: impl Fn(TreeNodeId, bool, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.props.on_expand = Some(Rc::new(handler));
+        self
+    }
+
+    /// Let nodes be dragged onto other nodes, reporting drops to `handler`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TreeView::<Entry>::new().on_move(|tree_move, _window, cx| move_entry(tree_move, cx));
+    /// ```
+    pub fn on_move(mut self, handler: impl Fn(TreeMove, &mut Window, &mut App) + 'static) -> Self {
+        self.props.reorderable = true;
+        self.props.on_move = Some(Rc::new(handler));
+        self
+    }
+
+    /// Whether the node with `id` is expanded
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let open = tree.read(cx).is_expanded("src");
+    /// ```
+    pub fn is_expanded(&self, id: &str) -> bool {
+        self.expanded.iter().any(|&index| self.id_of(index).as_ref() == id)
+    }
+
+    /// Reset the tree to the root nodes, reading all children from `child_nodes`
+    fn build_tree(&mut self) {
+        self.props.nodes.truncate(self.root_count);
+        self.tree = vec![TreeNode::default(); self.root_count];
+        self.expanded.clear();
+        self.loading.clear();
+        self.cursor = None;
+
+        if let Some(child_nodes) = self.props.child_nodes.clone() {
+            let mut index = 0;
+            while index < self.props.nodes.len() {
+                let children = child_nodes(&self.props.nodes[index]);
+                self.add_children(index, children);
+                index += 1;
+            }
+        }
+
+        if !self.props.default_expanded.is_empty() {
+            self.expanded = (0..self.props.nodes.len())
+                .filter(|&index| self.can_expand(index))
+                .filter(|&index| self.props.default_expanded.contains(&self.id_of(index)))
+                .collect();
+        }
+        self.update_order();
+    }
+
+    /// Append `children` to the nodes as the children of the node at `parent`
+    fn add_children(&mut self, parent: usize, children: Vec<T>) {
+        let start = self.props.nodes.len();
+        let depth = self.tree[parent].depth + 1;
+        self.tree[parent].children = Some((start..start + children.len()).collect());
+        self.tree.extend(children.iter().map(|_| TreeNode {
+            parent: Some(parent),
+            depth,
+            children: None,
+        }));
+        self.props.nodes.extend(children);
+    }
+
+    /// Whether the node at `index` has or may have children
+    fn can_expand(&self, index: usize) -> bool {
+        match (&self.tree[index].children, &self.props.has_children) {
+            (Some(children), _) => !children.is_empty(),
+            (None, Some(has_children)) => has_children(&self.props.nodes[index]),
+            (None, None) => false,
+        }
+    }
+
+    /// Rebuild the display order from the roots and expanded nodes
+    fn update_order(&mut self) {
+        self.anchor = None;
+
+        let mut order = Vec::with_capacity(self.props.nodes.len());
+        let mut stack: Vec<usize> = (0..self.root_count.min(self.props.nodes.len())).rev().collect();
+        while let Some(index) = stack.pop() {
+            order.push(index);
+            if !self.expanded.contains(&index) {
+                continue;
+            }
+            if let Some(children) = self.tree.get(index).and_then(|node| node.children.as_ref()) {
+                stack.extend(children.iter().rev());
+            }
+        }
+        self.order = order;
+    }
+
+    /// Expand or collapse the node at `index`, loading its children if needed
+    fn set_expanded(&mut self, index: usize, expanded: bool, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if expanded == self.expanded.contains(&index) || (expanded && !self.can_expand(index)) {
+            return;
+        }
+
+        if expanded {
+            self.expanded.insert(index);
+            if self.tree[index].children.is_none() {
+                self.load_children_of(index, cx);
+            }
+        } else {
+            self.expanded.remove(&index);
+            self.loading.remove(&index);
+            // Keep the cursor visible when its ancestor collapses
+            if self.cursor.is_some_and(|cursor| self.is_descendant(cursor, index)) {
+                self.cursor = Some(index);
+            }
+        }
+        self.update_order();
+        cx.notify();
+
+        if let Some(handler) = self.props.on_expand.clone() {
+            handler(self.id_of(index), expanded, window, cx);
+        }
+    }
+
+    /// Start loading the children of the node at `index`
+    fn load_children_of(&mut self, index: usize, cx: &mut Context<'_, Self>) {
+        let Some(loader) = self.props.child_loader.clone() else {
+            return;
+        };
+        if self.loading.contains_key(&index) {
+            return;
+        }
+
+        Announcer::polite("Loading").announce(cx);
+        let task = loader(&self.props.nodes[index], cx);
+        let load = cx.spawn(async move |this, cx| {
+            let children = task.await;
+            this.update(cx, |this, cx| {
+                this.loading.remove(&index);
+                let loaded = match children.len() {
+                    1 => "1 item loaded".to_string(),
+                    count => format!("{count} items loaded"),
+                };
+                this.add_children(index, children);
+                this.update_order();
+                Announcer::polite(loaded).announce(cx);
+                cx.notify();
+            })
+            .ok();
+        });
+        self.loading.insert(index, load);
+    }
+
+    /// Expand every sibling of the node at `index`
+    fn expand_siblings(&mut self, index: usize, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let siblings: Vec<usize> = match self.tree[index].parent {
+            Some(parent) => self.tree[parent].children.clone().unwrap_or_default(),
+            None => (0..self.root_count).collect(),
+        };
+        for sibling in siblings {
+            self.set_expanded(sibling, true, window, cx);
+        }
+    }
+
+    /// Whether the node at `index` lies below the node at `ancestor`
+    fn is_descendant(&self, index: usize, ancestor: usize) -> bool {
+        let mut parent = self.tree[index].parent;
+        while let Some(current) = parent {
+            if current == ancestor {
+                return true;
+            }
+            parent = self.tree[current].parent;
+        }
+        false
+    }
+
+    /// Id of the node at `index` in `nodes`
+    fn id_of(&self, index: usize) -> TreeNodeId {
+        match &self.props.node_id {
+            Some(node_id) => node_id(&self.props.nodes[index]),
+            None => index.to_string().into(),
+        }
+    }
+
+    /// Label of the node at `index` in `nodes`
+    fn label_of(&self, index: usize) -> SharedString {
+        match &self.props.label {
+            Some(label) => label(&self.props.nodes[index]),
+            None => self.id_of(index),
+        }
+    }
+
+    /// Whether the node at `index` in `nodes` is selected
+    fn is_selected(&self, index: usize) -> bool {
+        !self.props.selected.is_empty() && self.props.selected.contains(&self.id_of(index))
+    }
+
+    /// Display position of the node at `index`, if shown
+    fn position_of(&self, index: usize) -> Option<usize> {
+        self.order.iter().position(|&i| i == index)
+    }
+
+    /// Selection after choosing the node at display position `position`
+    ///
+    /// `extend` selects the range from the anchor node and `toggle` adds or
+    /// removes a single node; both only apply in multiple selection.
+    fn clicked_selection(&mut self, position: usize, extend: bool, toggle: bool) -> Vec<TreeNodeId> {
+        let id = self.id_of(self.order[position]);
+        let multiple = self.props.selection_mode == TreeSelectionMode::Multiple;
+
+        match self.anchor {
+            Some(anchor) if multiple && extend => {
+                let range = anchor.min(position)..=anchor.max(position);
+                return range.map(|p| self.id_of(self.order[p])).collect();
+            }
+            _ => {}
+        }
+
+        self.anchor = Some(position);
+        if multiple && toggle {
+            let mut selected = self.props.selected.clone();
+            if let Some(existing) = selected.iter().position(|s| *s == id) {
+                selected.remove(existing);
+            } else {
+                selected.push(id);
+            }
+            selected
+        } else {
+            vec![id]
+        }
+    }
+
+    /// Apply a new selection and notify the handler if it changed
+    fn change_selection(&mut self, selected: Vec<TreeNodeId>, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if self.props.selected == selected {
+            return;
+        }
+
+        if !self.props.controlled {
+            self.set_selected(selected.clone(), cx);
+        }
+
+        if let Some(handler) = self.props.on_selection_change.clone() {
+            handler(selected, window, cx);
+        }
+    }
+
+    /// Activate the node at `index`
+    fn activate(&mut self, index: usize, window: &mut Window, cx: &mut Context<'_, Self>) {
+        if let Some(handler) = self.props.on_activate.clone() {
+            handler(self.id_of(index), window, cx);
+        }
+    }
+
+    /// Node the cursor moves to for a navigation key, if any
+    fn navigation_target(&self, key: &str) -> Option<usize> {
+        let last = self.order.len().checked_sub(1)?;
+        let position = self.cursor.and_then(|index| self.position_of(index));
+
+        let target = match (key, position) {
+            ("down", Some(p)) => (p + 1).min(last),
+            ("up", Some(p)) => p.saturating_sub(1),
+            ("down" | "up" | "home", None) | ("home", Some(_)) => 0,
+            ("end", _) => last,
+            // First child of an expanded node
+            ("right", Some(p)) => {
+                let index = self.order[p];
+                let next = *self.order.get(p + 1)?;
+                if self.tree[next].parent != Some(index) {
+                    return None;
+                }
+                p + 1
+            }
+            ("left", Some(p)) => {
+                let parent = self.tree[self.order[p]].parent?;
+                self.position_of(parent)?
+            }
+            _ => return None,
+        };
+        Some(self.order[target])
+    }
+
+    /// Next visible node after the cursor whose label starts with `ch`, wrapping around
+    fn typeahead_target(&self, ch: char) -> Option<usize> {
+        let start = self
+            .cursor
+            .and_then(|index| self.position_of(index))
+            .map_or(0, |p| p + 1);
+        let count = self.order.len();
+        (0..count)
+            .map(|offset| self.order[(start + offset) % count])
+            .find(|&index| {
+                self.label_of(index)
+                    .chars()
+                    .next()
+                    .is_some_and(|first| first.to_lowercase().eq(ch.to_lowercase()))
+            })
+    }
+
+    /// Move the cursor to `index` and scroll it into view
+    fn move_cursor(&mut self, index: usize, cx: &mut Context<'_, Self>) {
+        self.cursor = Some(index);
+        if let Some(position) = self.position_of(index) {
+            let row_height = use_theme(cx).density.scale(self.props.row_height);
+            self.list_state.scroll_to_item(position, row_height);
+        }
+        cx.notify();
+    }
+
+    /// Handle navigation, expansion, selection and activation keys
+    fn handle_key(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let keystroke = &event.keystroke;
+        if keystroke.modifiers.alt || keystroke.modifiers.control || keystroke.modifiers.platform {
+            return;
+        }
+
+        let key = keystroke.key.as_str();
+        let cursor = self.cursor.filter(|&index| self.position_of(index).is_some());
+        let selectable = self.props.selection_mode != TreeSelectionMode::None;
+        match (key, cursor) {
+            ("right", Some(index)) if self.can_expand(index) && !self.expanded.contains(&index) => {
+                self.set_expanded(index, true, window, cx);
+            }
+            ("left", Some(index)) if self.expanded.contains(&index) => {
+                self.set_expanded(index, false, window, cx);
+            }
+            ("enter", Some(index)) => self.activate(index, window, cx),
+            ("space", Some(index)) if selectable => {
+                let Some(position) = self.position_of(index) else {
+                    return;
+                };
+                let selected = self.clicked_selection(position, keystroke.modifiers.shift, true);
+                self.change_selection(selected, window, cx);
+            }
+            (_, Some(index)) if keystroke.key_char.as_deref() == Some("*") => {
+                self.expand_siblings(index, window, cx);
+            }
+            _ => {
+                let target = match keystroke.key_char.as_deref().and_then(|text| text.chars().next()) {
+                    Some(ch) if key.chars().count() == 1 && !ch.is_whitespace() => self.typeahead_target(ch),
+                    _ => self.navigation_target(key),
+                };
+                let Some(target) = target else {
+                    return;
+                };
+                self.move_cursor(target, cx);
+
+                // Shift+Up/Down extends the selection in multiple mode
+                if keystroke.modifiers.shift && matches!(key, "up" | "down") && selectable {
+                    if let Some(position) = self.position_of(target) {
+                        if self.anchor.is_none() {
+                            self.anchor = cursor.and_then(|index| self.position_of(index));
+                        }
+                        let selected = self.clicked_selection(position, true, false);
+                        self.change_selection(selected, window, cx);
+                    }
+                }
+            }
+        }
+        cx.stop_propagation();
+    }
+
+    /// Track the drop position while a node is dragged over the row of `index`
+    fn handle_drag_move(&mut self, index: usize, event: &DragMoveEvent<TreeDrag>, cx: &mut Context<'_, Self>) {
+        let bounds = event.bounds;
+        let target = if bounds.contains(&event.event.position) {
+            let dragged = event.drag(cx).index;
+            if dragged == index || self.is_descendant(index, dragged) {
+                None
+            } else {
+                let fraction = (event.event.position.y - bounds.top()) / bounds.size.height;
+                Some((index, drop_position(fraction)))
+            }
+        } else if self.drop_target.is_some_and(|(target, _)| target == index) {
+            None
+        } else {
+            return;
+        };
+
+        if self.drop_target != target {
+            self.drop_target = target;
+            cx.notify();
+        }
+    }
+
+    /// Report dropping the dragged node on the current drop target
+    fn handle_drop(&mut self, drag: &TreeDrag, window: &mut Window, cx: &mut Context<'_, Self>) {
+        let Some((target, position)) = self.drop_target.take() else {
+            return;
+        };
+        cx.notify();
+
+        if let Some(handler) = self.props.on_move.clone() {
+            let tree_move = TreeMove {
+                node: self.id_of(drag.index),
+                target: self.id_of(target),
+                position,
+            };
+            handler(tree_move, window, cx);
+        }
+    }
+
+    /// Render the row at display position `position`
+    ///
+    /// `focused` shows a focus indicator on the cursor row.
+    #[allow(clippy::cast_precision_loss)]
+    fn render_row(
+        &self,
+        position: usize,
+        theme: &Theme,
+        focused: bool,
+        cx: &mut Context<'_, Self>,
+    ) -> Stateful<Div> {
+        let index = self.order[position];
+        let node = &self.tree[index];
+        let expanded = self.expanded.contains(&index);
+        let selectable = self.props.selection_mode != TreeSelectionMode::None;
+        let label = self.label_of(index);
+
+        let mut row = div()
+            .id(("tree-row", index))
+            .size_full()
+            .flex()
+            .flex_row()
+            .items_center()
+            .pr(theme.global.spacing_sm)
+            .text_size(theme.global.font_size_sm)
+            .text_color(theme.alias.color_text_primary)
+            .cursor_pointer();
+
+        row = if self.is_selected(index) {
+            row.bg(theme.alias.color_primary.opacity(0.12))
+        } else {
+            row.hover(|style| style.bg(theme.alias.color_surface_hover))
+        };
+
+        if focused && self.cursor == Some(index) {
+            row = row.border_l(px(2.0)).border_color(theme.alias.color_border_focus);
+        }
+
+        match self.drop_target {
+            Some((target, TreeDropPosition::Before)) if target == index => {
+                row = row.border_t(px(2.0)).border_color(theme.alias.color_primary);
+            }
+            Some((target, TreeDropPosition::After)) if target == index => {
+                row = row.border_b(px(2.0)).border_color(theme.alias.color_primary);
+            }
+            Some((target, TreeDropPosition::Inside)) if target == index => {
+                row = row.bg(theme.alias.color_primary.opacity(0.2));
+            }
+            _ => {}
+        }
+
+        row = row.on_click(cx.listener(move |this, event: &ClickEvent, window, cx| {
+            this.move_cursor(index, cx);
+            if event.click_count() >= 2 {
+                this.set_expanded(index, !this.expanded.contains(&index), window, cx);
+                this.activate(index, window, cx);
+                return;
+            }
+            if selectable {
+                let Some(position) = this.position_of(index) else {
+                    return;
+                };
+                let modifiers = event.modifiers();
+                let toggle = modifiers.platform || modifiers.control;
+                let selected = this.clicked_selection(position, modifiers.shift, toggle);
+                this.change_selection(selected, window, cx);
+            }
+        }));
+
+        if self.props.reorderable {
+            row = row
+                .on_drag(TreeDrag { index, label: label.clone() }, |drag, _offset, _window, cx| {
+                    cx.new(|_| drag.clone())
+                })
+                .on_drag_move(cx.listener(move |this, event: &DragMoveEvent<TreeDrag>, _window, cx| {
+                    this.handle_drag_move(index, event, cx);
+                }))
+                .on_drop(cx.listener(Self::handle_drop));
+        }
+
+        // Indentation keeps leaf nodes aligned with their expandable siblings
+        let toggle = div()
+            .flex_none()
+            .size(px(16.0))
+            .mr(theme.global.spacing_xs)
+            .flex()
+            .items_center()
+            .justify_center();
+        let toggle = if self.loading.contains_key(&index) {
+            toggle.child(
+                Spinner::new()
+                    .size(SpinnerSize::Sm)
+                    .animated(!reduced_motion(cx))
+                    .to_element(theme),
+            )
+        } else if self.can_expand(index) {
+            let icon = if expanded { icons::CHEVRON_DOWN } else { icons::CHEVRON_RIGHT };
+            toggle.child(
+                div()
+                    .id(("tree-toggle", index))
+                    .child(
+                        Icon::new(icon)
+                            .size(IconSize::Sm)
+                            .custom_color(theme.alias.color_text_muted)
+                            .to_element(theme),
+                    )
+                    .on_click(cx.listener(move |this, _event: &ClickEvent, window, cx| {
+                        this.set_expanded(index, !expanded, window, cx);
+                        cx.stop_propagation();
+                    })),
+            )
+        } else {
+            toggle
+        };
+
+        let icon = self
+            .props
+            .icon
+            .as_ref()
+            .and_then(|icon| icon(&self.props.nodes[index], expanded));
+
+        row.child(div().flex_none().w(theme.global.spacing_xs + self.props.indent * node.depth as f32))
+            .child(toggle)
+            .when_some(icon, |this, path| {
+                this.child(
+                    div().flex_none().mr(theme.global.spacing_xs).child(
+                        Icon::new(path)
+                            .size(IconSize::Sm)
+                            .custom_color(theme.alias.color_text_secondary)
+                            .to_element(theme),
+                    ),
+                )
+            })
+            .child(div().flex_1().truncate().child(label))
+    }
+}
+
+impl<T: 'static> Render for TreeView<T> {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+        let theme = use_theme(cx);
+        let focus_handle = self
+            .focus_handle
+            .get_or_insert_with(|| cx.focus_handle())
+            .clone();
+        let focused = focus_handle.is_focused(window);
+
+        let body = if self.order.is_empty() {
+            div()
+                .p(theme.global.spacing_lg)
+                .text_color(theme.alias.color_text_muted)
+                .child(self.props.empty_message.clone())
+        } else {
+            div().child(
+                VirtualList::new(
+                    "tree-body",
+                    self.order.len(),
+                    theme.density.scale(self.props.row_height),
+                    &self.list_state,
+                )
+                .max_height(self.props.max_height)
+                .to_element(|position| self.render_row(position, &theme, focused, cx)),
+            )
+        };
+
+        div()
+            .id("tree-view")
+            .track_focus(&focus_handle)
+            .on_key_down(cx.listener(Self::handle_key))
+            .w_full()
+            .child(body)
+    }
+}
+
+impl<T: 'static> Default for TreeView<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone)]
+    struct Node {
+        name: &'static str,
+        children: Vec<Node>,
+    }
+
+    fn node(name: &'static str, children: Vec<Node>) -> Node {
+        Node { name, children }
+    }
+
+    /// Nodes: 0 = a, 1 = b, a's children 2 = a1, 3 = a2, a1's child 4 = a1x
+    fn tree() -> TreeView<Node> {
+        TreeView::new()
+            .roots(vec![
+                node("a", vec![node("a1", vec![node("a1x", vec![])]), node("a2", vec![])]),
+                node("b", vec![]),
+            ])
+            .node_id(|n: &Node| n.name)
+            .label(|n: &Node| n.name)
+            .child_nodes(|n: &Node| n.children.clone())
+    }
+
+    fn ids(ids: &[&'static str]) -> Vec<TreeNodeId> {
+        ids.iter().map(|&id| id.into()).collect()
+    }
+
+    #[test]
+    fn test_children_shown_when_expanded() {
+        let mut tree = tree();
+        assert_eq!(tree.props.nodes.len(), 5);
+        assert_eq!(tree.order, vec![0, 1]);
+        assert!(tree.can_expand(0));
+        assert!(!tree.can_expand(1));
+
+        tree.expanded.extend([0, 2]);
+        tree.update_order();
+        assert_eq!(tree.order, vec![0, 2, 4, 3, 1]);
+        assert_eq!(tree.tree[4].depth, 2);
+        assert!(tree.is_descendant(4, 0));
+        assert!(!tree.is_descendant(1, 0));
+    }
+
+    #[test]
+    fn test_default_expanded() {
+        let tree = tree().default_expanded(ids(&["a"]));
+        assert_eq!(tree.order, vec![0, 2, 3, 1]);
+        assert!(tree.is_expanded("a"));
+        assert!(!tree.is_expanded("a1"));
+    }
+
+    #[test]
+    fn test_navigation() {
+        let mut tree = tree().default_expanded(ids(&["a"]));
+        assert_eq!(tree.navigation_target("down"), Some(0));
+
+        tree.cursor = Some(0);
+        assert_eq!(tree.navigation_target("right"), Some(2));
+        assert_eq!(tree.navigation_target("up"), Some(0));
+        assert_eq!(tree.navigation_target("end"), Some(1));
+
+        tree.cursor = Some(3);
+        assert_eq!(tree.navigation_target("left"), Some(0));
+        assert_eq!(tree.navigation_target("down"), Some(1));
+
+        // Leaf nodes have no children to move into
+        tree.cursor = Some(1);
+        assert_eq!(tree.navigation_target("right"), None);
+        assert_eq!(tree.navigation_target("down"), Some(1));
+    }
+
+    #[test]
+    fn test_typeahead_wraps() {
+        let mut tree = tree().default_expanded(ids(&["a"]));
+        tree.cursor = Some(0);
+        assert_eq!(tree.typeahead_target('A'), Some(2));
+        tree.cursor = Some(1);
+        assert_eq!(tree.typeahead_target('a'), Some(0));
+        assert_eq!(tree.typeahead_target('z'), None);
+    }
+
+    #[test]
+    fn test_selection() {
+        let mut tree = tree()
+            .default_expanded(ids(&["a"]))
+            .selection_mode(TreeSelectionMode::Multiple);
+        assert_eq!(tree.clicked_selection(0, false, false), ids(&["a"]));
+        assert_eq!(tree.clicked_selection(2, true, false), ids(&["a", "a1", "a2"]));
+
+        let mut tree = tree.selection_mode(TreeSelectionMode::Single);
+        tree.anchor = Some(0);
+        assert_eq!(tree.clicked_selection(2, true, true), ids(&["a2"]));
+    }
+
+    #[test]
+    fn test_lazy_children() {
+        let mut tree = TreeView::new()
+            .roots(vec![node("a", vec![node("a1", vec![])]), node("b", vec![])])
+            .load_children(|n: &Node| !n.children.is_empty(), |_, _| Task::ready(Vec::new()));
+        assert!(tree.can_expand(0));
+        assert!(!tree.can_expand(1));
+        assert_eq!(tree.props.nodes.len(), 2);
+
+        tree.add_children(0, vec![node("a1", vec![])]);
+        assert_eq!(tree.tree[0].children, Some(vec![2]));
+    }
+
+    #[test]
+    fn test_drop_position() {
+        assert_eq!(drop_position(0.1), TreeDropPosition::Before);
+        assert_eq!(drop_position(0.5), TreeDropPosition::Inside);
+        assert_eq!(drop_position(0.9), TreeDropPosition::After);
+    }
+}
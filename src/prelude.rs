@@ -8,44 +8,79 @@
 
 // Re-export theme types
 pub use crate::theme::{
-    AliasTokens, AvatarTokens, BadgeTokens, ButtonTokens, CheckboxTokens, GlobalTokens,
-    IconTokens, InputTokens, LabelTokens, RadioTokens, SpinnerTokens, SwitchTokens,
-    Theme, ThemeMode,
+    AliasTokens, AvatarTokens, BadgeTokens, ButtonTokens, CheckboxTokens, Density,
+    GlobalTokens, IconTokens, InputTokens, LabelTokens, RadioTokens, ScrollbarTokens,
+    SpinnerTokens, SwitchTokens, Theme, ThemeBuilder, ThemeError, ThemeMode, ThemeProvider,
+    TokenOverrides, TokenScope, observe_theme, token_scope, use_theme,
 };
 
 // Re-export atom components
 pub use crate::atoms::{
     Avatar, AvatarProps, AvatarSize, AvatarStatus,
-    Badge, BadgeProps, BadgeVariant,
+    Badge, BadgePlacement, BadgeProps, BadgeSlot, BadgeVariant,
     Button, ButtonProps, ButtonSize, ButtonVariant,
     Checkbox, CheckboxProps, CheckboxState,
+    Chip, ChipProps, ChipVariant,
+    Code, CodeProps,
     Icon, IconColor, IconSize,
-    Input, InputProps,
+    Input, InputProps, InputType,
+    Kbd, KbdProps,
+    KeybindingHint, KeybindingHintProps,
     Label, LabelVariant,
+    Link, LinkProps,
+    NumberInput, NumberInputProps,
     Radio, RadioProps,
-    Spinner, SpinnerColor, SpinnerProps, SpinnerSize,
+    Segment, SegmentedControl, SegmentedControlProps,
+    LoadingOverlay, LoadingOverlayProps, Spinner, SpinnerColor, SpinnerProps, SpinnerSize,
     Switch, SwitchProps,
 };
 
 // Re-export layout components
 pub use crate::layout::{
-    Alignment, Container, Divider, DividerOrientation, HStack, Justify, Spacer, VStack,
+    Alignment, Container, Divider, DividerOrientation, HStack, Justify, ScrollArea,
+    ScrollAreaState, ScrollAxis, Spacer, SplitOrientation, SplitPane, SplitPaneProps,
+    SplitPaneSlot, SplitSide, VStack, VirtualList, VirtualListState, Wrap,
 };
 
 // Re-export molecule components
 pub use crate::molecules::{
-    Card, CardProps, CardVariant,
-    FormGroup, FormGroupProps,
-    SearchBar, SearchBarProps,
+    ButtonGroup, ButtonGroupItem, ButtonGroupProps, ButtonGroupSelection,
+    Card, CardProps, CardSlot, CardVariant,
+    FormControlSlot, FormGroup, FormGroupProps,
+    RadioGroup, RadioGroupOrientation, RadioGroupProps, RadioOption,
+    SearchBar, SearchBarProps, SearchMode,
+    Stat, StatProps, StatSlot, StatTrend,
+    Toolbar, ToolbarAction, ToolbarItem, ToolbarProps,
+};
+
+// Re-export charts
+pub use crate::charts::{
+    BarChart, BarChartProps, ChartScale, ChartSeries, LineChart, LineChartProps, Sparkline,
+    SparklineProps,
 };
 
 // Re-export organism components
 pub use crate::organisms::{
-    Command, CommandPalette, CommandPaletteProps,
-    Dialog, DialogProps,
+    AppShell, AppShellProps, AppShellSlot,
+    CodeBlock, CodeBlockProps, CodeHighlighter,
+    Command, CommandAction, CommandHistory, CommandPalette, CommandPaletteProps, CommandUse,
+    ToggleCommandPalette,
+    ConfirmDialog, ConfirmDialogProps, ConfirmResult,
+    Dialog, DialogProps, DialogSize, DialogSlot,
+    Dock, DockFloating, DockLayout, DockNode, DockPanel, DockProps, DockSlot, DockZone,
     Drawer, DrawerPosition, DrawerProps,
-    Table, TableColumn, TableProps,
+    FloatingPanel, FloatingPanelSlot, FloatingPanels, FloatingPanelsProps,
+    List, ListItem, ListProps, ListSection, ListSelectionMode, ListSlot,
+    Markdown, MarkdownHighlighter, MarkdownProps,
+    RowId, SortDirection, Table, TableCellEditor, TableCellRenderer, TableChildLoader, TableColumn,
+    TableComparator, TableLayout, TableProps, TableSelectionMode,
+    Toast, ToastAction, ToastId, ToastKind, ToastManager, ToastPlacement,
+    TreeChildLoader, TreeDropPosition, TreeIcon, TreeMove, TreeNodeId, TreeSelectionMode, TreeView,
+    TreeViewProps,
 };
 
+// Re-export form validation
+pub use crate::form::{FieldState, Form, FormField, FormValues, Rule};
+
 // Re-export GPUI core types for convenience
 pub use gpui::*;
@@ -0,0 +1,374 @@
+//! Builder for custom themes with token overrides.
+
+use gpui::{Hsla, Pixels, px};
+
+use super::{AliasTokens, Density, GlobalTokens, Theme, ThemeError, ThemeMode};
+
+type GlobalOverride = Box<dyn FnOnce(&mut GlobalTokens)>;
+type AliasOverride = Box<dyn FnOnce(&mut AliasTokens)>;
+
+/// Builder for custom themes.
+///
+/// Overrides are applied in layer order: global token overrides first, then
+/// alias tokens are derived from the customized globals, then alias token
+/// overrides are applied on top. Component tokens (`ButtonTokens`,
+/// `InputTokens`, ...) read from the resulting theme in `from_theme`, so they
+/// pick up every override automatically.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use gpui::{hsla, px};
+/// use purdah_gpui_components::theme::{ThemeBuilder, ThemeMode};
+///
+/// let theme = ThemeBuilder::new()
+///     .mode(ThemeMode::Dark)
+///     .scale_radius(0.5)
+///     .global(|g| g.spacing_base = px(14.0))
+///     .alias(|a| a.color_primary = hsla(280.0 / 360.0, 0.7, 0.55, 1.0))
+///     .build()
+///     .expect("valid theme");
+/// ```
+pub struct ThemeBuilder {
+    mode: ThemeMode,
+    density: Density,
+    global: GlobalTokens,
+    global_overrides: Vec<GlobalOverride>,
+    alias_overrides: Vec<AliasOverride>,
+}
+
+impl ThemeBuilder {
+    /// Create a builder starting from the default light theme tokens
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::ThemeBuilder;
+    ///
+    /// let theme = ThemeBuilder::new().build().unwrap();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            mode: ThemeMode::Light,
+            density: Density::default(),
+            global: GlobalTokens::default(),
+            global_overrides: Vec::new(),
+            alias_overrides: Vec::new(),
+        }
+    }
+
+    /// Create a builder starting from an existing theme's global tokens, mode
+    /// and density
+    ///
+    /// Alias tokens are re-derived on build, so alias customizations of the
+    /// source theme must be re-applied with [`ThemeBuilder::alias`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::{Theme, ThemeBuilder};
+    ///
+    /// let theme = ThemeBuilder::from_theme(&Theme::dark()).scale_spacing(0.75).build();
+    /// ```
+    pub fn from_theme(theme: &Theme) -> Self {
+        Self {
+            mode: theme.mode,
+            density: theme.density,
+            global: theme.global.clone(),
+            global_overrides: Vec::new(),
+            alias_overrides: Vec::new(),
+        }
+    }
+
+    /// Set the theme mode
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::{ThemeBuilder, ThemeMode};
+    ///
+    /// ThemeBuilder::new().mode(ThemeMode::Dark);
+    /// ```
+    pub fn mode(mut self, mode: ThemeMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set the control density
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::{Density, ThemeBuilder};
+    ///
+    /// ThemeBuilder::new().density(Density::Compact);
+    /// ```
+    pub fn density(mut self, density: Density) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// Replace the primary (brand) color scale, lightest to darkest
+    ///
+    /// The ten entries map to `blue_50` through `blue_900`, which the alias
+    /// layer uses for primary actions and focus rings.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ThemeBuilder::new().primary_scale(brand_purple_scale);
+    /// ```
+    pub fn primary_scale(mut self, scale: [Hsla; 10]) -> Self {
+        let g = &mut self.global;
+        [
+            g.blue_50, g.blue_100, g.blue_200, g.blue_300, g.blue_400,
+            g.blue_500, g.blue_600, g.blue_700, g.blue_800, g.blue_900,
+        ] = scale;
+        self
+    }
+
+    /// Multiply every spacing token by `factor`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::ThemeBuilder;
+    ///
+    /// ThemeBuilder::new().scale_spacing(0.75);
+    /// ```
+    pub fn scale_spacing(mut self, factor: f32) -> Self {
+        let g = &mut self.global;
+        for token in [
+            &mut g.spacing_xs, &mut g.spacing_sm, &mut g.spacing_base, &mut g.spacing_md,
+            &mut g.spacing_lg, &mut g.spacing_xl, &mut g.spacing_2xl,
+        ] {
+            *token = *token * factor;
+        }
+        self
+    }
+
+    /// Multiply every border radius token (except `radius_full`) by `factor`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::ThemeBuilder;
+    ///
+    /// // Sharper corners
+    /// ThemeBuilder::new().scale_radius(0.5);
+    /// ```
+    pub fn scale_radius(mut self, factor: f32) -> Self {
+        let g = &mut self.global;
+        for token in [&mut g.radius_sm, &mut g.radius_md, &mut g.radius_lg, &mut g.radius_xl] {
+            *token = *token * factor;
+        }
+        self
+    }
+
+    /// Override arbitrary global tokens
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ThemeBuilder::new().global(|g| g.font_size_base = px(15.0));
+    /// ```
+    pub fn global(mut self, f: impl FnOnce(&mut GlobalTokens) + 'static) -> Self {
+        self.global_overrides.push(Box::new(f));
+        self
+    }
+
+    /// Override arbitrary alias tokens after they are derived from globals
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ThemeBuilder::new().alias(|a| a.color_primary = brand_color);
+    /// ```
+    pub fn alias(mut self, f: impl FnOnce(&mut AliasTokens) + 'static) -> Self {
+        self.alias_overrides.push(Box::new(f));
+        self
+    }
+
+    /// Apply all overrides and validate the resulting theme
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::ThemeBuilder;
+    ///
+    /// let theme = ThemeBuilder::new().scale_spacing(1.25).build()?;
+    /// # Ok::<(), purdah_gpui_components::theme::ThemeError>(())
+    /// ```
+    pub fn build(self) -> Result<Theme, ThemeError> {
+        let mut global = self.global;
+        for apply in self.global_overrides {
+            apply(&mut global);
+        }
+
+        let mut alias = AliasTokens::from_mode(&global, self.mode);
+        for apply in self.alias_overrides {
+            apply(&mut alias);
+        }
+
+        let theme = Theme {
+            global,
+            alias,
+            mode: self.mode,
+            density: self.density,
+        };
+        validate(&theme)?;
+        Ok(theme)
+    }
+}
+
+impl Default for ThemeBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Check that a scale is non-negative and ordered from smallest to largest
+fn validate_scale(scale: &'static str, tokens: &[(&'static str, Pixels)]) -> Result<(), ThemeError> {
+    for (token, value) in tokens {
+        if *value < px(0.0) {
+            return Err(ThemeError::NegativeValue { token });
+        }
+    }
+    if tokens.windows(2).any(|pair| pair[0].1 > pair[1].1) {
+        return Err(ThemeError::NonMonotonicScale { scale });
+    }
+    Ok(())
+}
+
+/// Check that every component of a color is within 0.0..=1.0
+fn validate_color(token: &str, color: Hsla) -> Result<(), ThemeError> {
+    let in_range = |v: f32| (0.0..=1.0).contains(&v);
+    if [color.h, color.s, color.l, color.a].into_iter().all(in_range) {
+        Ok(())
+    } else {
+        Err(ThemeError::InvalidColor { token: token.to_string() })
+    }
+}
+
+/// Validate token scales and the primary color scale of a theme
+pub(crate) fn validate(theme: &Theme) -> Result<(), ThemeError> {
+    let g = &theme.global;
+
+    validate_scale("spacing", &[
+        ("spacing_xs", g.spacing_xs),
+        ("spacing_sm", g.spacing_sm),
+        ("spacing_base", g.spacing_base),
+        ("spacing_md", g.spacing_md),
+        ("spacing_lg", g.spacing_lg),
+        ("spacing_xl", g.spacing_xl),
+        ("spacing_2xl", g.spacing_2xl),
+    ])?;
+    validate_scale("font size", &[
+        ("font_size_xs", g.font_size_xs),
+        ("font_size_sm", g.font_size_sm),
+        ("font_size_base", g.font_size_base),
+        ("font_size_lg", g.font_size_lg),
+        ("font_size_xl", g.font_size_xl),
+        ("font_size_2xl", g.font_size_2xl),
+        ("font_size_3xl", g.font_size_3xl),
+        ("font_size_4xl", g.font_size_4xl),
+    ])?;
+    validate_scale("radius", &[
+        ("radius_none", g.radius_none),
+        ("radius_sm", g.radius_sm),
+        ("radius_md", g.radius_md),
+        ("radius_lg", g.radius_lg),
+        ("radius_xl", g.radius_xl),
+        ("radius_full", g.radius_full),
+    ])?;
+    validate_scale("breakpoint", &[
+        ("breakpoint_sm", g.breakpoint_sm),
+        ("breakpoint_md", g.breakpoint_md),
+        ("breakpoint_lg", g.breakpoint_lg),
+        ("breakpoint_xl", g.breakpoint_xl),
+    ])?;
+
+    for (token, color) in [
+        ("blue_50", g.blue_50), ("blue_100", g.blue_100), ("blue_200", g.blue_200),
+        ("blue_300", g.blue_300), ("blue_400", g.blue_400), ("blue_500", g.blue_500),
+        ("blue_600", g.blue_600), ("blue_700", g.blue_700), ("blue_800", g.blue_800),
+        ("blue_900", g.blue_900),
+        ("color_primary", theme.alias.color_primary),
+        ("color_surface", theme.alias.color_surface),
+        ("color_text_primary", theme.alias.color_text_primary),
+    ] {
+        validate_color(token, color)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gpui::hsla;
+
+    #[test]
+    fn test_default_builder_matches_light_theme() {
+        let theme = ThemeBuilder::new().build().unwrap();
+        assert!(theme.is_light());
+        assert_eq!(theme.alias.color_primary, Theme::light().alias.color_primary);
+    }
+
+    #[test]
+    fn test_global_overrides_flow_into_alias_tokens() {
+        let theme = ThemeBuilder::new()
+            .global(|g| g.spacing_base = px(20.0))
+            .global(|g| g.spacing_md = px(28.0))
+            .build()
+            .unwrap();
+        assert_eq!(theme.alias.spacing_component_padding, px(20.0));
+    }
+
+    #[test]
+    fn test_primary_scale_drives_primary_color() {
+        let purple = hsla(270.0 / 360.0, 0.6, 0.5, 1.0);
+        let theme = ThemeBuilder::new().primary_scale([purple; 10]).build().unwrap();
+        assert_eq!(theme.alias.color_primary, purple);
+    }
+
+    #[test]
+    fn test_alias_override_applied_last() {
+        let red = hsla(0.0, 0.8, 0.5, 1.0);
+        let theme = ThemeBuilder::new()
+            .mode(ThemeMode::Dark)
+            .alias(move |a| a.color_primary = red)
+            .build()
+            .unwrap();
+        assert!(theme.is_dark());
+        assert_eq!(theme.alias.color_primary, red);
+    }
+
+    #[test]
+    fn test_scale_spacing_and_radius() {
+        let theme = ThemeBuilder::new().scale_spacing(0.5).scale_radius(2.0).build().unwrap();
+        assert_eq!(theme.global.spacing_base, px(8.0));
+        assert_eq!(theme.global.radius_md, px(16.0));
+        assert_eq!(theme.global.radius_full, px(9999.0));
+    }
+
+    #[test]
+    fn test_negative_spacing_rejected() {
+        let result = ThemeBuilder::new().global(|g| g.spacing_xs = px(-1.0)).build();
+        assert_eq!(result.unwrap_err(), ThemeError::NegativeValue { token: "spacing_xs" });
+    }
+
+    #[test]
+    fn test_unordered_scale_rejected() {
+        let result = ThemeBuilder::new().global(|g| g.radius_sm = px(40.0)).build();
+        assert_eq!(result.unwrap_err(), ThemeError::NonMonotonicScale { scale: "radius" });
+    }
+
+    #[test]
+    fn test_invalid_color_rejected() {
+        let result = ThemeBuilder::new()
+            .alias(|a| a.color_surface = Hsla { h: 0.0, s: 0.0, l: 1.5, a: 1.0 })
+            .build();
+        assert!(matches!(result, Err(ThemeError::InvalidColor { .. })));
+    }
+}
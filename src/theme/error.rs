@@ -0,0 +1,76 @@
+//! Errors produced while constructing or loading themes.
+
+use std::fmt;
+
+/// Error returned when a custom theme fails validation or a token file
+/// cannot be loaded.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::theme::{ThemeBuilder, ThemeError};
+///
+/// match ThemeBuilder::new().global(|g| g.spacing_sm = px(-1.0)).build() {
+///     Ok(theme) => { /* use theme */ }
+///     Err(ThemeError::NegativeValue { token }) => eprintln!("bad token: {token}"),
+///     Err(err) => eprintln!("{err}"),
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum ThemeError {
+    /// A size token (spacing, radius, font size) is negative
+    NegativeValue {
+        /// Name of the offending token
+        token: &'static str,
+    },
+    /// A token scale is not ordered from smallest to largest
+    NonMonotonicScale {
+        /// Name of the offending scale
+        scale: &'static str,
+    },
+    /// A color token has a component outside the 0.0..=1.0 range
+    InvalidColor {
+        /// Name of the offending token
+        token: String,
+    },
+    /// A token value could not be parsed (malformed hex color, unknown unit, ...)
+    InvalidValue {
+        /// Path of the offending token in the token file
+        token: String,
+        /// The raw value as written in the file
+        value: String,
+    },
+    /// The token file is not valid JSON or TOML
+    Parse {
+        /// Parser error message
+        message: String,
+    },
+    /// The token file could not be read
+    Io {
+        /// Path of the file
+        path: String,
+        /// I/O error message
+        message: String,
+    },
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NegativeValue { token } => write!(f, "token `{token}` must not be negative"),
+            Self::NonMonotonicScale { scale } => {
+                write!(f, "{scale} scale must be ordered from smallest to largest")
+            }
+            Self::InvalidColor { token } => {
+                write!(f, "color token `{token}` has a component outside 0.0..=1.0")
+            }
+            Self::InvalidValue { token, value } => {
+                write!(f, "token `{token}` has invalid value `{value}`")
+            }
+            Self::Parse { message } => write!(f, "failed to parse token file: {message}"),
+            Self::Io { path, message } => write!(f, "failed to read `{path}`: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for ThemeError {}
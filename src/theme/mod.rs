@@ -6,6 +6,12 @@
 //! 2. **Alias Tokens** ([`AliasTokens`]): Semantic mappings (primary, danger, surface)
 //! 3. **Component Tokens**: Component-specific tokens (ButtonTokens, InputTokens, etc.)
 //!
+//! The active theme is shared through [`ThemeProvider`], a GPUI global that
+//! components read with [`use_theme`] during render. Custom themes are
+//! built with [`ThemeBuilder`] or loaded from design-token files with
+//! [`Theme::from_file`]. Parts of the UI can override tokens for their
+//! subtree with [`TokenOverrides`] and [`token_scope`].
+//!
 //! ## Example
 //!
 //! ```rust,no_run
@@ -22,9 +28,19 @@
 
 mod tokens;
 mod themes;
+mod provider;
+mod builder;
+mod error;
+mod token_file;
+mod overrides;
 
 pub use tokens::{
     AliasTokens, AvatarTokens, BadgeTokens, ButtonTokens, CheckboxTokens, GlobalTokens,
-    IconTokens, InputTokens, LabelTokens, RadioTokens, SpinnerTokens, SwitchTokens
+    IconTokens, InputTokens, LabelTokens, RadioTokens, ScrollbarTokens, SpinnerTokens,
+    SwitchTokens
 };
-pub use themes::{Theme, ThemeMode};
+pub use themes::{Density, Theme, ThemeMode};
+pub use provider::{observe_theme, use_theme, ThemeProvider};
+pub use builder::ThemeBuilder;
+pub use error::ThemeError;
+pub use overrides::{token_scope, TokenOverrides, TokenScope};
@@ -0,0 +1,294 @@
+//! Scoped token overrides that cascade to a subtree of the UI.
+
+use std::rc::Rc;
+
+use gpui::{
+    AnyElement, App, Bounds, Element, ElementId, Global, GlobalElementId, InspectorElementId,
+    IntoElement, LayoutId, Pixels, Window,
+};
+
+use super::{provider::use_theme, AliasTokens, Density, GlobalTokens, Theme};
+
+type GlobalOverride = Rc<dyn Fn(&mut GlobalTokens)>;
+type AliasOverride = Rc<dyn Fn(&mut AliasTokens)>;
+
+/// Typed overrides of theme tokens for part of the UI.
+///
+/// Overrides are applied on top of the nearest enclosing theme: the
+/// registered [`ThemeProvider`](super::ThemeProvider) theme, or the theme of
+/// an outer [`token_scope`]. Global overrides run first, then alias
+/// overrides. Unlike [`ThemeBuilder`](super::ThemeBuilder), alias tokens are
+/// not re-derived from the changed globals, so outer alias overrides survive.
+/// Component tokens (`ButtonTokens`, `InputTokens`, ...) are read from the
+/// resulting theme in `from_theme`, so they follow both layers. A scope can
+/// also change the [`Density`], e.g. for a compact data table.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::theme::{token_scope, TokenOverrides};
+///
+/// // A "danger zone" section whose primary actions are red
+/// let danger = TokenOverrides::new().alias(|a| {
+///     a.color_primary = a.color_danger;
+///     a.color_border_focus = a.color_danger;
+/// });
+/// token_scope(danger, self.danger_zone.clone());
+/// ```
+#[derive(Clone, Default)]
+pub struct TokenOverrides {
+    global: Vec<GlobalOverride>,
+    alias: Vec<AliasOverride>,
+    density: Option<Density>,
+}
+
+impl TokenOverrides {
+    /// Create an empty set of overrides
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::TokenOverrides;
+    ///
+    /// let overrides = TokenOverrides::new();
+    /// assert!(overrides.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override global tokens
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TokenOverrides::new().global(|g| g.font_size_base = px(14.0));
+    /// ```
+    pub fn global(mut self, f: impl Fn(&mut GlobalTokens) + 'static) -> Self {
+        self.global.push(Rc::new(f));
+        self
+    }
+
+    /// Override alias tokens
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// TokenOverrides::new().alias(|a| a.color_surface = a.color_surface_elevated);
+    /// ```
+    pub fn alias(mut self, f: impl Fn(&mut AliasTokens) + 'static) -> Self {
+        self.alias.push(Rc::new(f));
+        self
+    }
+
+    /// Override the control density
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::{Density, TokenOverrides};
+    ///
+    /// TokenOverrides::new().density(Density::Compact);
+    /// ```
+    pub fn density(mut self, density: Density) -> Self {
+        self.density = Some(density);
+        self
+    }
+
+    /// Check whether no tokens are overridden
+    pub fn is_empty(&self) -> bool {
+        self.global.is_empty() && self.alias.is_empty() && self.density.is_none()
+    }
+
+    /// Return `theme` with the overrides applied
+    ///
+    /// Use this for components built with `to_element` in the same render
+    /// that creates the scope, which read their theme before it applies.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let danger_theme = danger.apply(&use_theme(cx));
+    /// Button::new().label("Delete project").to_element(&danger_theme);
+    /// ```
+    pub fn apply(&self, theme: &Theme) -> Theme {
+        let mut theme = theme.clone();
+        for apply in &self.global {
+            apply(&mut theme.global);
+        }
+        for apply in &self.alias {
+            apply(&mut theme.alias);
+        }
+        if let Some(density) = self.density {
+            theme.density = density;
+        }
+        theme
+    }
+}
+
+/// Themes of the token scopes currently being laid out or painted, innermost last
+#[derive(Default)]
+pub(crate) struct ScopedThemes(pub(crate) Vec<Theme>);
+
+impl Global for ScopedThemes {}
+
+/// Element rendering its child with [`TokenOverrides`] in effect.
+///
+/// Created with [`token_scope`].
+pub struct TokenScope {
+    overrides: TokenOverrides,
+    child: AnyElement,
+    /// Overridden theme, resolved when layout starts
+    theme: Option<Theme>,
+}
+
+/// Render `child` with `overrides` applied to the theme.
+///
+/// Views rendered inside the child read the overridden theme from
+/// [`use_theme`], and nested scopes cascade on top of it. Elements the
+/// caller builds with `to_element` before creating the scope keep the theme
+/// they were given; use [`TokenOverrides::apply`] for those.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// div()
+///     .child(self.settings.clone())
+///     .child(token_scope(
+///         TokenOverrides::new().alias(|a| a.color_primary = a.color_danger),
+///         self.danger_zone.clone(),
+///     ));
+/// ```
+pub fn token_scope(overrides: TokenOverrides, child: impl IntoElement) -> TokenScope {
+    TokenScope {
+        overrides,
+        child: child.into_any_element(),
+        theme: None,
+    }
+}
+
+impl TokenScope {
+    /// Run `f` on the child with the overridden theme on top of the scope stack
+    fn scoped<R>(
+        &mut self,
+        window: &mut Window,
+        cx: &mut App,
+        f: impl FnOnce(&mut AnyElement, &mut Window, &mut App) -> R,
+    ) -> R {
+        let overrides = &self.overrides;
+        let theme = self
+            .theme
+            .get_or_insert_with(|| overrides.apply(&use_theme(cx)))
+            .clone();
+        cx.default_global::<ScopedThemes>().0.push(theme);
+        let result = f(&mut self.child, window, cx);
+        cx.default_global::<ScopedThemes>().0.pop();
+        result
+    }
+}
+
+impl IntoElement for TokenScope {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for TokenScope {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static std::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let layout_id =
+            self.scoped(window, cx, |child, window, cx| child.request_layout(window, cx));
+        (layout_id, ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        self.scoped(window, cx, |child, window, cx| {
+            child.prepaint(window, cx);
+        });
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        self.scoped(window, cx, |child, window, cx| child.paint(window, cx));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gpui::px;
+
+    #[test]
+    fn test_empty_overrides_keep_theme() {
+        let theme = Theme::dark();
+        let overridden = TokenOverrides::new().apply(&theme);
+        assert!(TokenOverrides::new().is_empty());
+        assert_eq!(overridden.alias.color_primary, theme.alias.color_primary);
+        assert_eq!(overridden.mode, theme.mode);
+    }
+
+    #[test]
+    fn test_alias_override() {
+        let theme = Theme::light();
+        let danger = TokenOverrides::new().alias(|a| a.color_primary = a.color_danger);
+        let overridden = danger.apply(&theme);
+        assert_eq!(overridden.alias.color_primary, theme.alias.color_danger);
+        assert_eq!(overridden.alias.color_surface, theme.alias.color_surface);
+    }
+
+    #[test]
+    fn test_overrides_cascade() {
+        let theme = Theme::light();
+        let outer = TokenOverrides::new()
+            .global(|g| g.spacing_md = px(10.0))
+            .alias(|a| a.color_primary = a.color_danger);
+        let inner = TokenOverrides::new().global(|g| g.spacing_md = g.spacing_md * 2.0);
+
+        let nested = inner.apply(&outer.apply(&theme));
+        assert_eq!(nested.global.spacing_md, px(20.0));
+        // Inner global overrides do not re-derive the outer alias overrides away
+        assert_eq!(nested.alias.color_primary, theme.alias.color_danger);
+    }
+
+    #[test]
+    fn test_density_override() {
+        let compact = TokenOverrides::new().density(Density::Compact);
+        assert!(!compact.is_empty());
+        assert_eq!(compact.apply(&Theme::dark()).density, Density::Compact);
+        assert_eq!(TokenOverrides::new().apply(&Theme::dark()).density, Density::Comfortable);
+    }
+}
@@ -0,0 +1,377 @@
+//! Theme provider for sharing the active theme through GPUI's global context.
+
+use gpui::{App, Context, Global, Subscription, Window, WindowAppearance};
+
+use super::{overrides::ScopedThemes, Density, Theme, ThemeMode};
+
+/// Global holder for the application's active theme.
+///
+/// Register a `ThemeProvider` once at startup and every component in the
+/// library reads its tokens from it during render, so replacing the theme
+/// restyles the whole tree.
+///
+/// Changes made through [`ThemeProvider::switch_mode`] or
+/// [`ThemeProvider::switch_theme`] notify every view registered with
+/// [`observe_theme`] (or `cx.observe_global::<ThemeProvider>`).
+///
+/// With [`ThemeMode::System`] the provider resolves the mode to light or dark
+/// from the OS appearance, and follows appearance changes once
+/// [`ThemeProvider::observe_system_appearance`] is registered for a window.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::theme::{use_theme, Theme, ThemeProvider};
+///
+/// App::new().run(|cx| {
+///     ThemeProvider::init(cx, Theme::dark());
+///
+///     let theme = use_theme(cx);
+///     assert!(theme.is_dark());
+/// });
+/// ```
+#[derive(Debug, Clone)]
+pub struct ThemeProvider {
+    /// The active theme
+    theme: Theme,
+    /// Whether the active mode was resolved from [`ThemeMode::System`]
+    follows_system: bool,
+    /// Last known OS appearance, used to resolve [`ThemeMode::System`]
+    system_appearance: WindowAppearance,
+}
+
+impl Default for ThemeProvider {
+    fn default() -> Self {
+        Self::new(Theme::default())
+    }
+}
+
+impl Global for ThemeProvider {}
+
+impl ThemeProvider {
+    /// Create a provider holding the given theme
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::{Theme, ThemeProvider};
+    ///
+    /// let provider = ThemeProvider::new(Theme::dark());
+    /// ```
+    pub fn new(theme: Theme) -> Self {
+        let mut provider = Self {
+            theme,
+            follows_system: false,
+            system_appearance: WindowAppearance::Light,
+        };
+        if provider.theme.mode == ThemeMode::System {
+            provider.set_mode(ThemeMode::System);
+        }
+        provider
+    }
+
+    /// Register the provider as a GPUI global with the given theme
+    ///
+    /// Calling this again replaces the previously registered theme. A theme
+    /// in [`ThemeMode::System`] is resolved against the current OS appearance.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ThemeProvider::init(cx, Theme::light());
+    /// ```
+    pub fn init(cx: &mut App, theme: Theme) {
+        let mut provider = Self::new(theme);
+        provider.set_system_appearance(cx.window_appearance());
+        cx.set_global(provider);
+    }
+
+    /// Get the active theme
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let theme = cx.global::<ThemeProvider>().current_theme();
+    /// ```
+    pub fn current_theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// Check whether the active mode follows the OS appearance
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::{Theme, ThemeMode, ThemeProvider};
+    ///
+    /// let provider = ThemeProvider::new(Theme::from_mode(ThemeMode::System));
+    /// assert!(provider.follows_system());
+    /// ```
+    pub fn follows_system(&self) -> bool {
+        self.follows_system
+    }
+
+    /// Change the mode of the held theme, preserving custom global tokens
+    ///
+    /// [`ThemeMode::System`] is resolved against the last known OS appearance.
+    /// This mutates the provider in place; use [`ThemeProvider::switch_mode`]
+    /// to change the registered global and notify observers.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::{Theme, ThemeMode, ThemeProvider};
+    ///
+    /// let mut provider = ThemeProvider::new(Theme::light());
+    /// provider.set_mode(ThemeMode::Dark);
+    /// assert!(provider.current_theme().is_dark());
+    /// ```
+    pub fn set_mode(&mut self, mode: ThemeMode) {
+        self.follows_system = mode == ThemeMode::System;
+        self.theme = self
+            .theme
+            .clone()
+            .with_mode(mode.resolve(self.system_appearance));
+    }
+
+    /// Record the OS appearance, re-resolving the theme if it follows the system
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use gpui::WindowAppearance;
+    /// use purdah_gpui_components::theme::{Theme, ThemeMode, ThemeProvider};
+    ///
+    /// let mut provider = ThemeProvider::new(Theme::from_mode(ThemeMode::System));
+    /// provider.set_system_appearance(WindowAppearance::Dark);
+    /// assert!(provider.current_theme().is_dark());
+    /// ```
+    pub fn set_system_appearance(&mut self, appearance: WindowAppearance) {
+        self.system_appearance = appearance;
+        if self.follows_system {
+            self.set_mode(ThemeMode::System);
+        }
+    }
+
+    /// Replace the held theme
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::{Theme, ThemeProvider};
+    ///
+    /// let mut provider = ThemeProvider::default();
+    /// provider.set_theme(Theme::dark());
+    /// ```
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+        self.follows_system = false;
+        if self.theme.mode == ThemeMode::System {
+            self.set_mode(ThemeMode::System);
+        }
+    }
+
+    /// Switch the registered theme to a different mode and re-render all windows
+    ///
+    /// Registers a default provider first if none exists.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ThemeProvider::switch_mode(cx, ThemeMode::Dark);
+    /// ```
+    pub fn switch_mode(cx: &mut App, mode: ThemeMode) {
+        let appearance = cx.window_appearance();
+        cx.update_default_global::<Self, _>(|provider, _cx| {
+            provider.system_appearance = appearance;
+            provider.set_mode(mode);
+        });
+        cx.refresh_windows();
+    }
+
+    /// Switch the registered theme to a different density and re-render all windows
+    ///
+    /// Registers a default provider first if none exists.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ThemeProvider::switch_density(cx, Density::Compact);
+    /// ```
+    pub fn switch_density(cx: &mut App, density: Density) {
+        cx.update_default_global::<Self, _>(|provider, _cx| {
+            provider.theme.density = density;
+        });
+        cx.refresh_windows();
+    }
+
+    /// Replace the registered theme and re-render all windows
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// ThemeProvider::switch_theme(cx, Theme::dark());
+    /// ```
+    pub fn switch_theme(cx: &mut App, theme: Theme) {
+        let appearance = cx.window_appearance();
+        cx.update_default_global::<Self, _>(|provider, _cx| {
+            provider.system_appearance = appearance;
+            provider.set_theme(theme);
+        });
+        cx.refresh_windows();
+    }
+
+    /// Follow OS appearance changes (light/dark) reported for a window
+    ///
+    /// When the registered theme uses [`ThemeMode::System`], appearance
+    /// switches re-resolve the theme and re-render all windows. Keep the
+    /// returned [`Subscription`] alive, or detach it, for as long as the app
+    /// should follow the OS.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// cx.open_window(WindowOptions::default(), |window, cx| {
+    ///     ThemeProvider::observe_system_appearance(window, cx).detach();
+    ///     cx.new(|cx| MyApp::new(cx))
+    /// });
+    /// ```
+    pub fn observe_system_appearance(window: &mut Window, cx: &mut App) -> Subscription {
+        cx.update_default_global::<Self, _>(|provider, _cx| {
+            provider.set_system_appearance(window.appearance());
+        });
+
+        window.observe_window_appearance(|window, cx| {
+            let appearance = window.appearance();
+            let follows_system = cx.update_default_global::<Self, _>(|provider, _cx| {
+                provider.set_system_appearance(appearance);
+                provider.follows_system()
+            });
+            if follows_system {
+                cx.refresh_windows();
+            }
+        })
+    }
+
+    /// Toggle the registered theme between light and dark mode
+    ///
+    /// High contrast themes switch between their light and dark variants.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Button::new()
+    ///     .label("Toggle theme")
+    ///     .on_click(|_, _window, cx| ThemeProvider::toggle_dark_mode(cx));
+    /// ```
+    pub fn toggle_dark_mode(cx: &mut App) {
+        let mode = match use_theme(cx).mode {
+            ThemeMode::HighContrast { dark } => ThemeMode::HighContrast { dark: !dark },
+            ThemeMode::Dark => ThemeMode::Light,
+            _ => ThemeMode::Dark,
+        };
+        Self::switch_mode(cx, mode);
+    }
+}
+
+/// Read the active theme from the GPUI global context.
+///
+/// Inside a [`token_scope`](super::token_scope) this returns the scope's
+/// overridden theme. Falls back to [`Theme::default`] when no
+/// [`ThemeProvider`] has been registered, so components still render in
+/// tests and small examples.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// impl Render for MyView {
+///     fn render(&mut self, _window: &mut Window, cx: &mut Context<'_, Self>) -> impl IntoElement {
+///         let theme = use_theme(cx);
+///         div().bg(theme.alias.color_surface)
+///     }
+/// }
+/// ```
+pub fn use_theme(cx: &App) -> Theme {
+    if let Some(theme) = cx
+        .try_global::<ScopedThemes>()
+        .and_then(|scoped| scoped.0.last())
+    {
+        return theme.clone();
+    }
+    cx.try_global::<ThemeProvider>()
+        .map(|provider| provider.current_theme().clone())
+        .unwrap_or_default()
+}
+
+/// Re-render a view whenever the registered theme changes.
+///
+/// Keep the returned [`Subscription`] alive for as long as the view should
+/// follow theme changes (typically by storing it on the view).
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// struct MyView {
+///     _theme_subscription: Subscription,
+/// }
+///
+/// impl MyView {
+///     fn new(cx: &mut Context<Self>) -> Self {
+///         Self {
+///             _theme_subscription: observe_theme(cx),
+///         }
+///     }
+/// }
+/// ```
+pub fn observe_theme<V: 'static>(cx: &mut Context<V>) -> Subscription {
+    cx.observe_global::<ThemeProvider>(|_view, cx| cx.notify())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_provider_holds_theme() {
+        let provider = ThemeProvider::new(Theme::dark());
+        assert!(provider.current_theme().is_dark());
+    }
+
+    #[test]
+    fn test_default_provider_is_light() {
+        let provider = ThemeProvider::default();
+        assert!(provider.current_theme().is_light());
+    }
+
+    #[test]
+    fn test_set_mode() {
+        let mut provider = ThemeProvider::new(Theme::light());
+        provider.set_mode(ThemeMode::Dark);
+        assert!(provider.current_theme().is_dark());
+        assert_eq!(provider.current_theme().mode, ThemeMode::Dark);
+    }
+
+    #[test]
+    fn test_system_mode_follows_appearance() {
+        let mut provider = ThemeProvider::new(Theme::light());
+        provider.set_mode(ThemeMode::System);
+        assert!(provider.follows_system());
+        assert!(provider.current_theme().is_light());
+
+        provider.set_system_appearance(WindowAppearance::VibrantDark);
+        assert!(provider.current_theme().is_dark());
+        assert_eq!(provider.current_theme().mode, ThemeMode::Dark);
+
+        provider.set_mode(ThemeMode::Light);
+        assert!(!provider.follows_system());
+        provider.set_system_appearance(WindowAppearance::Dark);
+        assert!(provider.current_theme().is_light());
+    }
+
+    #[test]
+    fn test_set_theme() {
+        let mut provider = ThemeProvider::default();
+        provider.set_theme(Theme::dark());
+        assert!(provider.current_theme().is_dark());
+    }
+}
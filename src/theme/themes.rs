@@ -1,6 +1,8 @@
 //! Theme definitions and theming system.
 
-use super::{AliasTokens, GlobalTokens};
+use gpui::{Pixels, WindowAppearance};
+
+use super::{AliasTokens, GlobalTokens, ThemeBuilder};
 
 /// Theme mode variants
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -9,8 +11,139 @@ pub enum ThemeMode {
     Light,
     /// Dark theme mode
     Dark,
-    /// Follow system theme preference
+    /// Follow the OS appearance (light or dark)
+    ///
+    /// Resolved to [`ThemeMode::Light`] or [`ThemeMode::Dark`] by
+    /// [`ThemeMode::resolve`]; `ThemeProvider` does this automatically and
+    /// tracks appearance changes.
     System,
+    /// High contrast mode meeting WCAG AAA contrast, with thicker borders
+    /// and focus rings
+    HighContrast {
+        /// Whether to use the dark high contrast variant
+        dark: bool,
+    },
+}
+
+impl ThemeMode {
+    /// Check if this mode uses dark colors
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::ThemeMode;
+    ///
+    /// assert!(ThemeMode::HighContrast { dark: true }.is_dark());
+    /// ```
+    pub fn is_dark(self) -> bool {
+        matches!(self, Self::Dark | Self::HighContrast { dark: true })
+    }
+
+    /// Check if this mode uses light colors
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::ThemeMode;
+    ///
+    /// assert!(ThemeMode::Light.is_light());
+    /// ```
+    pub fn is_light(self) -> bool {
+        matches!(self, Self::Light | Self::HighContrast { dark: false })
+    }
+
+    /// Resolve [`ThemeMode::System`] to light or dark for an OS appearance
+    ///
+    /// Other modes are returned unchanged.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use gpui::WindowAppearance;
+    /// use purdah_gpui_components::theme::ThemeMode;
+    ///
+    /// let mode = ThemeMode::System.resolve(WindowAppearance::Dark);
+    /// assert_eq!(mode, ThemeMode::Dark);
+    /// ```
+    pub fn resolve(self, appearance: WindowAppearance) -> Self {
+        match (self, appearance) {
+            (Self::System, WindowAppearance::Dark | WindowAppearance::VibrantDark) => Self::Dark,
+            (Self::System, WindowAppearance::Light | WindowAppearance::VibrantLight) => Self::Light,
+            (mode, _) => mode,
+        }
+    }
+
+    /// Check if this is a high contrast mode
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::ThemeMode;
+    ///
+    /// assert!(ThemeMode::HighContrast { dark: false }.is_high_contrast());
+    /// ```
+    pub fn is_high_contrast(self) -> bool {
+        matches!(self, Self::HighContrast { .. })
+    }
+}
+
+/// Spacing density of controls
+///
+/// Density scales the padding of controls and the row height of lists and
+/// tables, so data-heavy apps can switch to a compact layout globally.
+/// Component tokens apply it in `from_theme`; global and alias spacing
+/// tokens are left unchanged.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use gpui::px;
+/// use purdah_gpui_components::theme::Density;
+///
+/// assert_eq!(Density::Compact.scale(px(40.0)), px(30.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Density {
+    /// Tighter padding and rows for data-heavy screens
+    Compact,
+    /// Default spacing
+    #[default]
+    Comfortable,
+    /// Roomier padding and rows, e.g. for touch input
+    Spacious,
+}
+
+impl Density {
+    /// Multiplier applied to density-aware sizes
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::Density;
+    ///
+    /// assert_eq!(Density::Comfortable.factor(), 1.0);
+    /// ```
+    pub fn factor(self) -> f32 {
+        match self {
+            Self::Compact => 0.75,
+            Self::Comfortable => 1.0,
+            Self::Spacious => 1.25,
+        }
+    }
+
+    /// Scale a size given for comfortable density, rounded to whole pixels
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use gpui::px;
+    /// use purdah_gpui_components::theme::Density;
+    ///
+    /// assert_eq!(Density::Spacious.scale(px(28.0)), px(35.0));
+    /// ```
+    pub fn scale(self, value: Pixels) -> Pixels {
+        (value * self.factor()).round()
+    }
 }
 
 /// Complete theme containing all token layers
@@ -39,6 +172,8 @@ pub struct Theme {
     pub alias: AliasTokens,
     /// Theme mode
     pub mode: ThemeMode,
+    /// Control density
+    pub density: Density,
 }
 
 impl Theme {
@@ -59,6 +194,7 @@ impl Theme {
             global,
             alias,
             mode: ThemeMode::Light,
+            density: Density::default(),
         }
     }
 
@@ -79,9 +215,36 @@ impl Theme {
             global,
             alias,
             mode: ThemeMode::Dark,
+            density: Density::default(),
         }
     }
 
+    /// Create a high contrast light theme with default tokens
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::Theme;
+    ///
+    /// let theme = Theme::high_contrast_light();
+    /// ```
+    pub fn high_contrast_light() -> Self {
+        Self::light().with_mode(ThemeMode::HighContrast { dark: false })
+    }
+
+    /// Create a high contrast dark theme with default tokens
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::Theme;
+    ///
+    /// let theme = Theme::high_contrast_dark();
+    /// ```
+    pub fn high_contrast_dark() -> Self {
+        Self::light().with_mode(ThemeMode::HighContrast { dark: true })
+    }
+
     /// Create a theme based on the specified mode
     ///
     /// ## Example
@@ -95,18 +258,18 @@ impl Theme {
         match mode {
             ThemeMode::Light => Self::light(),
             ThemeMode::Dark => Self::dark(),
-            ThemeMode::System => {
-                // TODO: Detect system theme preference
-                // For now, default to light mode
-                Self::light()
-            }
+            ThemeMode::HighContrast { .. } => Self::light().with_mode(mode),
+            // Uses light tokens until resolved against the OS appearance,
+            // which `ThemeProvider` does on registration
+            ThemeMode::System => Self::light().with_mode(mode),
         }
     }
 
     /// Switch to a different theme mode
     ///
     /// This creates a new theme with the specified mode while preserving
-    /// custom global token overrides if any.
+    /// custom global token overrides and the density. Alias token overrides are
+    /// re-derived and therefore lost; rebuild with [`ThemeBuilder`] to keep them.
     ///
     /// ## Example
     ///
@@ -117,16 +280,43 @@ impl Theme {
     /// theme = theme.with_mode(ThemeMode::Dark);
     /// ```
     pub fn with_mode(self, mode: ThemeMode) -> Self {
-        let is_dark = matches!(mode, ThemeMode::Dark);
-        let alias = AliasTokens::from_global(&self.global, is_dark);
+        let alias = AliasTokens::from_mode(&self.global, mode);
 
         Self {
             global: self.global,
             alias,
             mode,
+            density: self.density,
         }
     }
 
+    /// Switch to a different control density
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::{Density, Theme};
+    ///
+    /// let theme = Theme::dark().with_density(Density::Compact);
+    /// ```
+    pub fn with_density(mut self, density: Density) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// Start building a custom theme
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::{Theme, ThemeMode};
+    ///
+    /// let theme = Theme::builder().mode(ThemeMode::Dark).scale_radius(0.5).build();
+    /// ```
+    pub fn builder() -> ThemeBuilder {
+        ThemeBuilder::new()
+    }
+
     /// Check if this is a dark theme
     ///
     /// ## Example
@@ -138,7 +328,7 @@ impl Theme {
     /// assert!(theme.is_dark());
     /// ```
     pub fn is_dark(&self) -> bool {
-        matches!(self.mode, ThemeMode::Dark)
+        self.mode.is_dark()
     }
 
     /// Check if this is a light theme
@@ -152,7 +342,21 @@ impl Theme {
     /// assert!(theme.is_light());
     /// ```
     pub fn is_light(&self) -> bool {
-        matches!(self.mode, ThemeMode::Light)
+        self.mode.is_light()
+    }
+
+    /// Check if this is a high contrast theme
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::Theme;
+    ///
+    /// let theme = Theme::high_contrast_dark();
+    /// assert!(theme.is_high_contrast());
+    /// ```
+    pub fn is_high_contrast(&self) -> bool {
+        self.mode.is_high_contrast()
     }
 }
 
@@ -202,5 +406,65 @@ mod tests {
 
         let dark = Theme::from_mode(ThemeMode::Dark);
         assert!(dark.is_dark());
+
+        let system = Theme::from_mode(ThemeMode::System);
+        assert_eq!(system.mode, ThemeMode::System);
+
+        let high_contrast = Theme::from_mode(ThemeMode::HighContrast { dark: true });
+        assert!(high_contrast.is_high_contrast());
+        assert!(high_contrast.is_dark());
+    }
+
+    #[test]
+    fn test_resolve_system_mode() {
+        assert_eq!(ThemeMode::System.resolve(WindowAppearance::Dark), ThemeMode::Dark);
+        assert_eq!(ThemeMode::System.resolve(WindowAppearance::VibrantLight), ThemeMode::Light);
+        assert_eq!(ThemeMode::Dark.resolve(WindowAppearance::Light), ThemeMode::Dark);
+    }
+
+    #[test]
+    fn test_high_contrast_themes() {
+        let light = Theme::high_contrast_light();
+        assert!(light.is_high_contrast());
+        assert!(light.is_light());
+        assert!(!light.is_dark());
+        assert_ne!(light.alias.color_text_primary, Theme::light().alias.color_text_primary);
+
+        let dark = Theme::high_contrast_dark();
+        assert!(dark.is_high_contrast());
+        assert!(dark.is_dark());
+        assert_ne!(dark.alias.color_surface, Theme::dark().alias.color_surface);
+    }
+
+    #[test]
+    fn test_high_contrast_component_tokens() {
+        use crate::theme::{ButtonTokens, InputTokens};
+
+        let regular = ButtonTokens::from_theme(&Theme::light());
+        let high_contrast = ButtonTokens::from_theme(&Theme::high_contrast_light());
+        assert!(high_contrast.border_width > regular.border_width);
+        assert!(high_contrast.focus_ring_width > regular.focus_ring_width);
+
+        let input = InputTokens::from_theme(&Theme::high_contrast_dark());
+        assert!(input.border_width > InputTokens::from_theme(&Theme::dark()).border_width);
+    }
+
+    #[test]
+    fn test_density() {
+        use crate::theme::{ButtonTokens, InputTokens};
+        use gpui::px;
+
+        assert_eq!(Theme::light().density, Density::Comfortable);
+        assert_eq!(Density::Compact.scale(px(48.0)), px(36.0));
+
+        let compact = Theme::light().with_density(Density::Compact);
+        let spacious = compact.clone().with_density(Density::Spacious);
+        assert!(ButtonTokens::from_theme(&compact).padding_y_md
+            < ButtonTokens::from_theme(&Theme::light()).padding_y_md);
+        assert!(InputTokens::from_theme(&spacious).padding_y
+            > InputTokens::from_theme(&Theme::light()).padding_y);
+
+        // Switching mode keeps the density
+        assert_eq!(compact.with_mode(ThemeMode::Dark).density, Density::Compact);
     }
 }
@@ -0,0 +1,878 @@
+//! Loading and exporting themes as design-token files.
+//!
+//! Token files are nested groups of tokens, either as W3C design tokens JSON
+//! (leaves wrapped in `{ "$value": ... }`) or as plain JSON/TOML values. Any
+//! token missing from the file keeps its default value.
+//!
+//! ```json
+//! {
+//!   "mode": "dark",
+//!   "density": "compact",
+//!   "color": {
+//!     "blue": { "600": { "$value": "#2563eb", "$type": "color" } }
+//!   },
+//!   "spacing": { "base": { "$value": "16px", "$type": "dimension" } },
+//!   "alias": { "color-primary": { "$value": "#7c3aed" } }
+//! }
+//! ```
+//!
+//! ```toml
+//! mode = "dark"
+//!
+//! [color.blue]
+//! 600 = "#2563eb"
+//!
+//! [spacing]
+//! base = 16
+//! ```
+//!
+//! Group and token names are matched ignoring case, `-` and `_`, so
+//! `font-size`, `font_size` and `fontSize` all refer to the same group.
+//!
+//! [`Theme::to_json`] writes the same layout back out, so exported files can
+//! be loaded again.
+
+use std::path::Path;
+use std::time::Duration;
+
+use gpui::{px, rgba, FontWeight, Hsla, Pixels, Rgba};
+use serde_json::{json, Map, Value};
+
+use super::{
+    builder, AliasTokens, AvatarTokens, BadgeTokens, ButtonTokens, CheckboxTokens, Density,
+    GlobalTokens, IconTokens, InputTokens, LabelTokens, RadioTokens, ScrollbarTokens,
+    SpinnerTokens, SwitchTokens, Theme, ThemeError, ThemeMode,
+};
+
+/// Root font size used to convert `rem` dimensions to pixels
+const REM_BASE: f32 = 16.0;
+
+/// Generate a reader and a writer mapping token file paths to the fields of a
+/// token struct.
+///
+/// The reader applies every token found in a document; the writer exports
+/// every field at its path.
+macro_rules! token_table {
+    (
+        $target:ty => $read:ident, $write:ident {
+            colors { $($cpath:literal => $cfield:ident),* $(,)? }
+            sizes { $($spath:literal => $sfield:ident),* $(,)? }
+            weights { $($wpath:literal => $wfield:ident),* $(,)? }
+            durations { $($dpath:literal => $dfield:ident),* $(,)? }
+        }
+    ) => {
+        fn $read(target: &mut $target, root: &Value) -> Result<(), ThemeError> {
+            $(
+                if let Some(value) = lookup(root, $cpath) {
+                    target.$cfield = parse_color($cpath, value)?;
+                }
+            )*
+            $(
+                if let Some(value) = lookup(root, $spath) {
+                    target.$sfield = parse_size($spath, value)?;
+                }
+            )*
+            $(
+                if let Some(value) = lookup(root, $wpath) {
+                    target.$wfield = parse_weight($wpath, value)?;
+                }
+            )*
+            $(
+                if let Some(value) = lookup(root, $dpath) {
+                    target.$dfield = parse_duration($dpath, value)?;
+                }
+            )*
+            Ok(())
+        }
+
+        fn $write(source: &$target, root: &mut Map<String, Value>) {
+            $(insert_path(root, $cpath, source.$cfield.to_token());)*
+            $(insert_path(root, $spath, source.$sfield.to_token());)*
+            $(insert_path(root, $wpath, source.$wfield.to_token());)*
+            $(insert_path(root, $dpath, source.$dfield.to_token());)*
+        }
+    };
+}
+
+/// Generate a function exporting component tokens as a flat token group.
+///
+/// Component tokens are derived from the global and alias layers, so they are
+/// written for reference only and ignored when loading.
+macro_rules! component_writer {
+    ($write:ident($target:ty) { $($field:ident),* $(,)? }) => {
+        fn $write(theme: &Theme) -> Value {
+            let tokens = <$target>::from_theme(theme);
+            let mut group = Map::new();
+            $(group.insert(stringify!($field).to_string(), tokens.$field.to_token());)*
+            Value::Object(group)
+        }
+    };
+}
+
+token_table! {
+    GlobalTokens => read_global, write_global {
+        colors {
+            "color.blue.50" => blue_50, "color.blue.100" => blue_100,
+            "color.blue.200" => blue_200, "color.blue.300" => blue_300,
+            "color.blue.400" => blue_400, "color.blue.500" => blue_500,
+            "color.blue.600" => blue_600, "color.blue.700" => blue_700,
+            "color.blue.800" => blue_800, "color.blue.900" => blue_900,
+            "color.gray.50" => gray_50, "color.gray.100" => gray_100,
+            "color.gray.200" => gray_200, "color.gray.300" => gray_300,
+            "color.gray.400" => gray_400, "color.gray.500" => gray_500,
+            "color.gray.600" => gray_600, "color.gray.700" => gray_700,
+            "color.gray.800" => gray_800, "color.gray.900" => gray_900,
+            "color.gray.950" => gray_950,
+            "color.red.50" => red_50, "color.red.100" => red_100,
+            "color.red.200" => red_200, "color.red.300" => red_300,
+            "color.red.400" => red_400, "color.red.500" => red_500,
+            "color.red.600" => red_600, "color.red.700" => red_700,
+            "color.red.800" => red_800, "color.red.900" => red_900,
+            "color.green.50" => green_50, "color.green.100" => green_100,
+            "color.green.200" => green_200, "color.green.300" => green_300,
+            "color.green.400" => green_400, "color.green.500" => green_500,
+            "color.green.600" => green_600, "color.green.700" => green_700,
+            "color.green.800" => green_800, "color.green.900" => green_900,
+            "color.yellow.50" => yellow_50, "color.yellow.100" => yellow_100,
+            "color.yellow.200" => yellow_200, "color.yellow.300" => yellow_300,
+            "color.yellow.400" => yellow_400, "color.yellow.500" => yellow_500,
+            "color.yellow.600" => yellow_600, "color.yellow.700" => yellow_700,
+            "color.yellow.800" => yellow_800, "color.yellow.900" => yellow_900,
+        }
+        sizes {
+            "spacing.xs" => spacing_xs, "spacing.sm" => spacing_sm,
+            "spacing.base" => spacing_base, "spacing.md" => spacing_md,
+            "spacing.lg" => spacing_lg, "spacing.xl" => spacing_xl,
+            "spacing.2xl" => spacing_2xl,
+            "font_size.xs" => font_size_xs, "font_size.sm" => font_size_sm,
+            "font_size.base" => font_size_base, "font_size.lg" => font_size_lg,
+            "font_size.xl" => font_size_xl, "font_size.2xl" => font_size_2xl,
+            "font_size.3xl" => font_size_3xl, "font_size.4xl" => font_size_4xl,
+            "radius.none" => radius_none, "radius.sm" => radius_sm,
+            "radius.md" => radius_md, "radius.lg" => radius_lg,
+            "radius.xl" => radius_xl, "radius.full" => radius_full,
+            "breakpoint.sm" => breakpoint_sm, "breakpoint.md" => breakpoint_md,
+            "breakpoint.lg" => breakpoint_lg, "breakpoint.xl" => breakpoint_xl,
+        }
+        weights {
+            "font_weight.normal" => font_weight_normal,
+            "font_weight.medium" => font_weight_medium,
+            "font_weight.semibold" => font_weight_semibold,
+            "font_weight.bold" => font_weight_bold,
+        }
+        durations {
+            "duration.fast" => duration_fast, "duration.normal" => duration_normal,
+            "duration.slow" => duration_slow,
+        }
+    }
+}
+
+token_table! {
+    AliasTokens => read_alias, write_alias {
+        colors {
+            "alias.color_primary" => color_primary,
+            "alias.color_primary_hover" => color_primary_hover,
+            "alias.color_primary_active" => color_primary_active,
+            "alias.color_secondary" => color_secondary,
+            "alias.color_secondary_hover" => color_secondary_hover,
+            "alias.color_danger" => color_danger,
+            "alias.color_danger_hover" => color_danger_hover,
+            "alias.color_success" => color_success,
+            "alias.color_success_hover" => color_success_hover,
+            "alias.color_warning" => color_warning,
+            "alias.color_warning_hover" => color_warning_hover,
+            "alias.color_surface" => color_surface,
+            "alias.color_surface_hover" => color_surface_hover,
+            "alias.color_surface_elevated" => color_surface_elevated,
+            "alias.color_text_primary" => color_text_primary,
+            "alias.color_text_secondary" => color_text_secondary,
+            "alias.color_text_muted" => color_text_muted,
+            "alias.color_text_on_primary" => color_text_on_primary,
+            "alias.color_border" => color_border,
+            "alias.color_border_hover" => color_border_hover,
+            "alias.color_border_focus" => color_border_focus,
+        }
+        sizes {
+            "alias.spacing_component_padding" => spacing_component_padding,
+            "alias.spacing_component_gap" => spacing_component_gap,
+            "alias.spacing_section_gap" => spacing_section_gap,
+            "alias.font_size_body" => font_size_body,
+            "alias.font_size_caption" => font_size_caption,
+            "alias.font_size_heading" => font_size_heading,
+        }
+        weights {}
+        durations {}
+    }
+}
+
+component_writer! {
+    write_button(ButtonTokens) {
+        background_primary, background_primary_hover, background_primary_active,
+        background_primary_disabled, background_secondary, background_secondary_hover,
+        border_outline, border_outline_hover, background_outline, background_outline_hover,
+        background_ghost, background_ghost_hover, background_danger, background_danger_hover,
+        text_primary, text_secondary, text_outline, text_ghost, text_danger, text_disabled,
+        padding_x_md, padding_y_md, padding_x_sm, padding_y_sm, padding_x_lg, padding_y_lg, gap,
+        font_size_md, font_size_sm, font_size_lg, font_weight, border_width, border_radius,
+        focus_ring_color, focus_ring_width,
+    }
+}
+
+component_writer! {
+    write_label(LabelTokens) {
+        font_size_body, font_size_caption, font_size_heading_1, font_size_heading_2,
+        font_size_heading_3, font_weight_body, font_weight_caption, font_weight_heading_1,
+        font_weight_heading_2, font_weight_heading_3, color_primary, color_secondary,
+    }
+}
+
+component_writer! {
+    write_input(InputTokens) {
+        background, background_disabled, border_default, border_hover, border_focus,
+        border_error, text_color, text_placeholder, text_disabled, text_error, padding_x,
+        padding_y, font_size, font_weight, border_width, border_radius, focus_ring_color,
+        focus_ring_width,
+    }
+}
+
+component_writer! {
+    write_icon(IconTokens) {
+        size_xs, size_sm, size_md, size_lg, size_xl, color_default, color_muted, color_primary,
+        color_danger, color_success, color_warning,
+    }
+}
+
+component_writer! {
+    write_badge(BadgeTokens) {
+        background_default, background_primary, background_success, background_warning,
+        background_danger, background_premium, text_default, text_primary, text_success,
+        text_warning, text_danger, text_premium, dot_default, dot_primary, dot_success,
+        dot_warning, dot_danger, dot_premium, padding_x, padding_y, gap, font_size, font_weight,
+        border_radius, dot_size,
+    }
+}
+
+component_writer! {
+    write_avatar(AvatarTokens) {
+        size_xs, size_sm, size_md, size_lg, size_xl, font_size_xs, font_size_sm, font_size_md,
+        font_size_lg, font_size_xl, font_weight, background_default, text_color, status_online,
+        status_offline, status_away, status_busy, status_size_xs, status_size_sm,
+        status_size_md, status_size_lg, status_size_xl, status_border, status_border_width,
+    }
+}
+
+component_writer! {
+    write_checkbox(CheckboxTokens) {
+        size, background_unchecked, background_checked, background_disabled, border_unchecked,
+        border_checked, border_disabled, border_width, border_radius, icon_color, icon_size,
+        label_gap, label_font_size, label_color, label_color_disabled,
+    }
+}
+
+component_writer! {
+    write_radio(RadioTokens) {
+        size, background_unselected, background_selected, background_disabled,
+        border_unselected, border_selected, border_disabled, border_width, dot_size, dot_color,
+        label_gap, label_font_size, label_color, label_color_disabled,
+    }
+}
+
+component_writer! {
+    write_switch(SwitchTokens) {
+        width, height, background_off, background_on, background_disabled, thumb_size,
+        thumb_color, thumb_disabled, thumb_padding, label_gap, label_font_size, label_color,
+        label_color_disabled,
+    }
+}
+
+component_writer! {
+    write_spinner(SpinnerTokens) {
+        size_sm, size_md, size_lg, border_width, color_default, color_muted, color_success,
+        color_warning, color_danger,
+    }
+}
+
+component_writer! {
+    write_scrollbar(ScrollbarTokens) {
+        thickness, thickness_hover, min_thumb_length, inset, thumb_color, thumb_color_hover,
+        track_color_hover,
+    }
+}
+
+impl Theme {
+    /// Load a theme from a JSON design-tokens document
+    ///
+    /// Accepts W3C design tokens (`{ "$value": ... }` leaves) as well as plain
+    /// values. Tokens missing from the document fall back to their defaults,
+    /// and alias tokens not listed under `alias` are derived from the loaded
+    /// global tokens.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::Theme;
+    ///
+    /// let theme = Theme::from_json_str(r##"{
+    ///     "mode": "dark",
+    ///     "color": { "blue": { "600": { "$value": "#7c3aed" } } }
+    /// }"##)?;
+    /// # Ok::<(), purdah_gpui_components::theme::ThemeError>(())
+    /// ```
+    pub fn from_json_str(json: &str) -> Result<Self, ThemeError> {
+        let root: Value = serde_json::from_str(json).map_err(|err| ThemeError::Parse {
+            message: err.to_string(),
+        })?;
+        Self::from_token_value(&root)
+    }
+
+    /// Load a theme from a TOML design-tokens document
+    ///
+    /// Uses the same token layout as [`Theme::from_json_str`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::Theme;
+    ///
+    /// let theme = Theme::from_toml_str(r##"
+    ///     mode = "light"
+    ///
+    ///     [spacing]
+    ///     base = "20px"
+    /// "##)?;
+    /// # Ok::<(), purdah_gpui_components::theme::ThemeError>(())
+    /// ```
+    pub fn from_toml_str(toml: &str) -> Result<Self, ThemeError> {
+        let root: Value = toml::from_str(toml).map_err(|err| ThemeError::Parse {
+            message: err.to_string(),
+        })?;
+        Self::from_token_value(&root)
+    }
+
+    /// Load a theme from a design-tokens file
+    ///
+    /// Files with a `.toml` extension are parsed as TOML, everything else as
+    /// JSON.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::Theme;
+    ///
+    /// let theme = Theme::from_file("assets/tokens.json")?;
+    /// # Ok::<(), purdah_gpui_components::theme::ThemeError>(())
+    /// ```
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ThemeError> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(|err| ThemeError::Io {
+            path: path.display().to_string(),
+            message: err.to_string(),
+        })?;
+
+        let is_toml = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("toml"));
+        if is_toml {
+            Self::from_toml_str(&contents)
+        } else {
+            Self::from_json_str(&contents)
+        }
+    }
+
+    /// Export the theme as a JSON design-tokens document
+    ///
+    /// Writes the mode, density, every global and alias token in the layout
+    /// read by [`Theme::from_json_str`], and the derived component tokens
+    /// under `components` for reference. Colors are written as hex, so loading
+    /// the export reproduces the theme up to 8-bit color precision.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::Theme;
+    ///
+    /// let json = Theme::dark().to_json();
+    /// let restored = Theme::from_json_str(&json)?;
+    /// assert!(restored.is_dark());
+    /// # Ok::<(), purdah_gpui_components::theme::ThemeError>(())
+    /// ```
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.to_token_value())
+            .expect("token documents always serialize")
+    }
+
+    /// Save the theme to a design-tokens file
+    ///
+    /// Files with a `.toml` extension are written as TOML, everything else as
+    /// JSON.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// use purdah_gpui_components::theme::Theme;
+    ///
+    /// Theme::dark().save_to_file("assets/tokens.json")?;
+    /// # Ok::<(), purdah_gpui_components::theme::ThemeError>(())
+    /// ```
+    pub fn save_to_file(&self, path: impl AsRef<Path>) -> Result<(), ThemeError> {
+        let path = path.as_ref();
+        let is_toml = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("toml"));
+        let contents = if is_toml {
+            toml::to_string_pretty(&self.to_token_value()).map_err(|err| ThemeError::Parse {
+                message: err.to_string(),
+            })?
+        } else {
+            self.to_json()
+        };
+
+        std::fs::write(path, contents).map_err(|err| ThemeError::Io {
+            path: path.display().to_string(),
+            message: err.to_string(),
+        })
+    }
+
+    /// Build a token document covering all three token layers
+    fn to_token_value(&self) -> Value {
+        let mode = match self.mode {
+            ThemeMode::Light => "light",
+            ThemeMode::Dark => "dark",
+            ThemeMode::System => "system",
+            ThemeMode::HighContrast { dark: false } => "high-contrast-light",
+            ThemeMode::HighContrast { dark: true } => "high-contrast-dark",
+        };
+
+        let density = match self.density {
+            Density::Compact => "compact",
+            Density::Comfortable => "comfortable",
+            Density::Spacious => "spacious",
+        };
+
+        let mut root = Map::new();
+        root.insert("mode".to_string(), Value::from(mode));
+        root.insert("density".to_string(), Value::from(density));
+        write_global(&self.global, &mut root);
+        write_alias(&self.alias, &mut root);
+        root.insert(
+            "components".to_string(),
+            json!({
+                "button": write_button(self),
+                "label": write_label(self),
+                "input": write_input(self),
+                "icon": write_icon(self),
+                "badge": write_badge(self),
+                "avatar": write_avatar(self),
+                "checkbox": write_checkbox(self),
+                "radio": write_radio(self),
+                "switch": write_switch(self),
+                "spinner": write_spinner(self),
+                "scrollbar": write_scrollbar(self),
+            }),
+        );
+        Value::Object(root)
+    }
+
+    /// Build a theme from a parsed token document
+    fn from_token_value(root: &Value) -> Result<Self, ThemeError> {
+        let mode = match lookup(root, "mode") {
+            Some(value) => parse_mode(value)?,
+            None => ThemeMode::Light,
+        };
+        let density = match lookup(root, "density") {
+            Some(value) => parse_density(value)?,
+            None => Density::default(),
+        };
+
+        let mut global = GlobalTokens::default();
+        read_global(&mut global, root)?;
+
+        let mut alias = AliasTokens::from_mode(&global, mode);
+        read_alias(&mut alias, root)?;
+
+        let theme = Self {
+            global,
+            alias,
+            mode,
+            density,
+        };
+        builder::validate(&theme)?;
+        Ok(theme)
+    }
+}
+
+/// Conversion of a token value into a W3C design token
+trait ToToken {
+    fn to_token(&self) -> Value;
+}
+
+impl ToToken for Hsla {
+    fn to_token(&self) -> Value {
+        json!({ "$value": to_hex(*self), "$type": "color" })
+    }
+}
+
+impl ToToken for Pixels {
+    fn to_token(&self) -> Value {
+        json!({ "$value": format!("{}px", f32::from(*self)), "$type": "dimension" })
+    }
+}
+
+impl ToToken for u16 {
+    fn to_token(&self) -> Value {
+        json!({ "$value": self, "$type": "fontWeight" })
+    }
+}
+
+impl ToToken for FontWeight {
+    fn to_token(&self) -> Value {
+        json!({ "$value": self.0, "$type": "fontWeight" })
+    }
+}
+
+impl ToToken for Duration {
+    fn to_token(&self) -> Value {
+        json!({ "$value": format!("{}ms", self.as_millis()), "$type": "duration" })
+    }
+}
+
+/// Insert a value at a dot-separated path, creating groups as needed
+fn insert_path(root: &mut Map<String, Value>, path: &str, value: Value) {
+    let mut segments: Vec<&str> = path.split('.').collect();
+    let Some(leaf) = segments.pop() else {
+        return;
+    };
+
+    let mut group = root;
+    for segment in segments {
+        let entry = group
+            .entry(segment.to_string())
+            .or_insert_with(|| Value::Object(Map::new()));
+        let Value::Object(next) = entry else {
+            return;
+        };
+        group = next;
+    }
+    group.insert(leaf.to_string(), value);
+}
+
+/// Format a color as `#rrggbb`, or `#rrggbbaa` when it is not fully opaque
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub(crate) fn to_hex(color: Hsla) -> String {
+    let rgba = Rgba::from(color);
+    let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    let (r, g, b, a) = (channel(rgba.r), channel(rgba.g), channel(rgba.b), channel(rgba.a));
+    if a == u8::MAX {
+        format!("#{r:02x}{g:02x}{b:02x}")
+    } else {
+        format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+    }
+}
+
+/// Normalize a group or token name so `font-size`, `font_size` and `fontSize` match
+fn normalize(key: &str) -> String {
+    key.chars()
+        .filter(|c| *c != '-' && *c != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Find the value of the token at a dot-separated path
+///
+/// W3C (`$value`) and Style Dictionary (`value`) wrapped leaves are unwrapped.
+fn lookup<'a>(root: &'a Value, path: &str) -> Option<&'a Value> {
+    let mut node = root;
+    for segment in path.split('.') {
+        let segment = normalize(segment);
+        node = node
+            .as_object()?
+            .iter()
+            .find(|(key, _)| normalize(key) == segment)
+            .map(|(_, value)| value)?;
+    }
+
+    match node {
+        Value::Object(map) => map.get("$value").or_else(|| map.get("value")),
+        leaf => Some(leaf),
+    }
+}
+
+/// Error for a token value that could not be parsed
+fn invalid(token: &str, value: &Value) -> ThemeError {
+    ThemeError::InvalidValue {
+        token: token.to_string(),
+        value: value.as_str().map_or_else(|| value.to_string(), str::to_string),
+    }
+}
+
+/// Parse a `#rgb`, `#rrggbb` or `#rrggbbaa` hex color
+pub(crate) fn parse_hex(hex: &str) -> Option<Hsla> {
+    let digits = hex.trim().strip_prefix('#')?;
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let value = u32::from_str_radix(digits, 16).ok()?;
+    let rgba_value = match digits.len() {
+        3 => {
+            let (r, g, b) = ((value >> 8) & 0xf, (value >> 4) & 0xf, value & 0xf);
+            (r * 0x11) << 24 | (g * 0x11) << 16 | (b * 0x11) << 8 | 0xff
+        }
+        6 => value << 8 | 0xff,
+        8 => value,
+        _ => return None,
+    };
+    Some(rgba(rgba_value).into())
+}
+
+fn parse_color(token: &str, value: &Value) -> Result<Hsla, ThemeError> {
+    value
+        .as_str()
+        .and_then(parse_hex)
+        .ok_or_else(|| invalid(token, value))
+}
+
+/// Parse a dimension given as a number of pixels, `"16px"` or `"1rem"`
+#[allow(clippy::cast_possible_truncation)]
+fn parse_size(token: &str, value: &Value) -> Result<Pixels, ThemeError> {
+    let pixels = match value {
+        Value::Number(number) => number.as_f64().map(|n| n as f32),
+        Value::String(text) => {
+            let text = text.trim();
+            if let Some(rem) = text.strip_suffix("rem") {
+                rem.trim().parse::<f32>().ok().map(|n| n * REM_BASE)
+            } else {
+                text.strip_suffix("px").unwrap_or(text).trim().parse::<f32>().ok()
+            }
+        }
+        _ => None,
+    };
+    pixels.map(px).ok_or_else(|| invalid(token, value))
+}
+
+/// Parse a numeric font weight such as `600` or `"600"`
+fn parse_weight(token: &str, value: &Value) -> Result<u16, ThemeError> {
+    let weight = match value {
+        Value::Number(number) => number.as_u64().and_then(|n| u16::try_from(n).ok()),
+        Value::String(text) => text.trim().parse::<u16>().ok(),
+        _ => None,
+    };
+    weight.ok_or_else(|| invalid(token, value))
+}
+
+/// Parse a duration such as `200`, `"200ms"` or `"0.2s"`; bare numbers are milliseconds
+fn parse_duration(token: &str, value: &Value) -> Result<Duration, ThemeError> {
+    let millis = match value {
+        Value::Number(number) => number.as_f64(),
+        Value::String(text) => {
+            let text = text.trim();
+            if let Some(millis) = text.strip_suffix("ms") {
+                millis.trim().parse::<f64>().ok()
+            } else if let Some(seconds) = text.strip_suffix('s') {
+                seconds.trim().parse::<f64>().ok().map(|seconds| seconds * 1000.0)
+            } else {
+                text.parse::<f64>().ok()
+            }
+        }
+        _ => None,
+    };
+    millis
+        .filter(|millis| millis.is_finite() && *millis >= 0.0)
+        .map(|millis| Duration::from_secs_f64(millis / 1000.0))
+        .ok_or_else(|| invalid(token, value))
+}
+
+fn parse_mode(value: &Value) -> Result<ThemeMode, ThemeError> {
+    match value.as_str().map(normalize).as_deref() {
+        Some("light") => Ok(ThemeMode::Light),
+        Some("dark") => Ok(ThemeMode::Dark),
+        Some("system") => Ok(ThemeMode::System),
+        Some("highcontrastlight") => Ok(ThemeMode::HighContrast { dark: false }),
+        Some("highcontrastdark") => Ok(ThemeMode::HighContrast { dark: true }),
+        _ => Err(invalid("mode", value)),
+    }
+}
+
+fn parse_density(value: &Value) -> Result<Density, ThemeError> {
+    match value.as_str().map(normalize).as_deref() {
+        Some("compact") => Ok(Density::Compact),
+        Some("comfortable") => Ok(Density::Comfortable),
+        Some("spacious") => Ok(Density::Spacious),
+        _ => Err(invalid("density", value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::theme::ThemeBuilder;
+    use gpui::rgb;
+
+    #[test]
+    fn test_parse_hex() {
+        assert_eq!(parse_hex("#ff0000"), Some(rgb(0xff0000).into()));
+        assert_eq!(parse_hex("#f00"), Some(rgb(0xff0000).into()));
+        assert_eq!(parse_hex("#ff000080"), Some(rgba(0xff000080).into()));
+        assert_eq!(parse_hex("ff0000"), None);
+        assert_eq!(parse_hex("#ff00"), None);
+        assert_eq!(parse_hex("#gg0000"), None);
+    }
+
+    #[test]
+    fn test_empty_document_uses_defaults() {
+        let theme = Theme::from_json_str("{}").unwrap();
+        assert!(theme.is_light());
+        assert_eq!(theme.global.spacing_base, Theme::light().global.spacing_base);
+        assert_eq!(theme.alias.color_primary, Theme::light().alias.color_primary);
+    }
+
+    #[test]
+    fn test_w3c_json_tokens() {
+        let theme = Theme::from_json_str(
+            r##"{
+                "mode": "dark",
+                "color": { "blue": { "500": { "$value": "#7c3aed", "$type": "color" } } },
+                "spacing": { "base": { "$value": "20px", "$type": "dimension" } },
+                "fontSize": { "base": { "$value": "1rem" } },
+                "font-weight": { "bold": { "$value": 800 } }
+            }"##,
+        )
+        .unwrap();
+
+        assert!(theme.is_dark());
+        assert_eq!(theme.global.blue_500, rgb(0x7c3aed).into());
+        // Dark mode primary derives from blue_500
+        assert_eq!(theme.alias.color_primary, rgb(0x7c3aed).into());
+        assert_eq!(theme.global.spacing_base, px(20.0));
+        assert_eq!(theme.global.font_size_base, px(16.0));
+        assert_eq!(theme.global.font_weight_bold, 800);
+    }
+
+    #[test]
+    fn test_toml_tokens() {
+        let theme = Theme::from_toml_str(
+            r##"
+                [color.red]
+                600 = "#dc2626"
+
+                [radius]
+                md = 6
+
+                [alias]
+                color_surface = "#fafafa"
+            "##,
+        )
+        .unwrap();
+
+        assert_eq!(theme.global.red_600, rgb(0xdc2626).into());
+        assert_eq!(theme.global.radius_md, px(6.0));
+        assert_eq!(theme.alias.color_surface, rgb(0xfafafa).into());
+    }
+
+    #[test]
+    fn test_duration_tokens() {
+        let theme = Theme::from_json_str(
+            r##"{ "duration": { "fast": { "$value": "80ms" }, "normal": 250, "slow": "0.4s" } }"##,
+        )
+        .unwrap();
+
+        assert_eq!(theme.global.duration_fast, Duration::from_millis(80));
+        assert_eq!(theme.global.duration_normal, Duration::from_millis(250));
+        assert_eq!(theme.global.duration_slow, Duration::from_millis(400));
+        assert!(Theme::from_json_str(r##"{ "duration": { "fast": "soon" } }"##).is_err());
+    }
+
+    #[test]
+    fn test_invalid_color_value() {
+        let result = Theme::from_json_str(r#"{ "color": { "gray": { "50": "white" } } }"#);
+        assert_eq!(
+            result.unwrap_err(),
+            ThemeError::InvalidValue {
+                token: "color.gray.50".to_string(),
+                value: "white".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_density() {
+        let theme = Theme::from_json_str(r#"{ "density": "compact" }"#).unwrap();
+        assert_eq!(theme.density, Density::Compact);
+
+        let restored = Theme::from_json_str(&theme.to_json()).unwrap();
+        assert_eq!(restored.density, Density::Compact);
+        assert!(Theme::from_json_str(r#"{ "density": "dense" }"#).is_err());
+    }
+
+    #[test]
+    fn test_high_contrast_mode() {
+        let theme = Theme::from_json_str(r#"{ "mode": "high-contrast-dark" }"#).unwrap();
+        assert_eq!(theme.mode, ThemeMode::HighContrast { dark: true });
+
+        let restored = Theme::from_json_str(&Theme::high_contrast_light().to_json()).unwrap();
+        assert_eq!(restored.mode, ThemeMode::HighContrast { dark: false });
+    }
+
+    #[test]
+    fn test_invalid_mode() {
+        let result = Theme::from_json_str(r#"{ "mode": "sepia" }"#);
+        assert!(matches!(result, Err(ThemeError::InvalidValue { .. })));
+    }
+
+    #[test]
+    fn test_malformed_document() {
+        assert!(matches!(Theme::from_json_str("{"), Err(ThemeError::Parse { .. })));
+        assert!(matches!(Theme::from_toml_str("= 1"), Err(ThemeError::Parse { .. })));
+    }
+
+    #[test]
+    fn test_loaded_theme_is_validated() {
+        let result = Theme::from_json_str(r#"{ "spacing": { "sm": "-4px" } }"#);
+        assert_eq!(
+            result.unwrap_err(),
+            ThemeError::NegativeValue { token: "spacing_sm" }
+        );
+    }
+
+    #[test]
+    fn test_to_hex() {
+        assert_eq!(to_hex(rgb(0x2563eb).into()), "#2563eb");
+        assert_eq!(to_hex(rgba(0xff000080).into()), "#ff000080");
+    }
+
+    #[test]
+    fn test_export_contains_all_layers() {
+        let root: Value = serde_json::from_str(&Theme::dark().to_json()).unwrap();
+        assert_eq!(root["mode"], "dark");
+        assert_eq!(root["spacing"]["base"]["$value"], "16px");
+        assert_eq!(root["color"]["blue"]["500"]["$type"], "color");
+        assert!(root["alias"]["color_primary"]["$value"].is_string());
+        assert!(root["components"]["button"]["background_primary"].is_object());
+        assert!(root["components"]["spinner"]["size_md"].is_object());
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let original = ThemeBuilder::new()
+            .mode(ThemeMode::Dark)
+            .global(|g| g.spacing_base = px(18.0))
+            .alias(|a| a.color_surface = rgb(0x101820).into())
+            .build()
+            .unwrap();
+
+        let json = original.to_json();
+        let restored = Theme::from_json_str(&json).unwrap();
+
+        assert!(restored.is_dark());
+        assert_eq!(restored.global.spacing_base, px(18.0));
+        assert_eq!(to_hex(restored.alias.color_surface), "#101820");
+        // Exporting the restored theme is stable
+        assert_eq!(restored.to_json(), json);
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let result = Theme::from_file("does/not/exist.json");
+        assert!(matches!(result, Err(ThemeError::Io { .. })));
+    }
+}
@@ -1,5 +1,7 @@
 //! Design token definitions for the 3-layer token system.
 
+use std::time::Duration;
+
 use gpui::{hsla, px, FontWeight, Hsla, Pixels};
 
 /// Layer 1: Global Tokens - Foundational values
@@ -187,6 +189,24 @@ pub struct GlobalTokens {
     pub radius_xl: Pixels,
     /// Fully rounded: 9999px (pill shape)
     pub radius_full: Pixels,
+
+    // Breakpoints (minimum widths)
+    /// Small breakpoint: 640px
+    pub breakpoint_sm: Pixels,
+    /// Medium breakpoint: 768px
+    pub breakpoint_md: Pixels,
+    /// Large breakpoint: 1024px
+    pub breakpoint_lg: Pixels,
+    /// Extra large breakpoint: 1280px
+    pub breakpoint_xl: Pixels,
+
+    // Motion durations
+    /// Fast transitions such as hover and press feedback: 100ms
+    pub duration_fast: Duration,
+    /// Standard transitions such as panels and popovers: 200ms
+    pub duration_normal: Duration,
+    /// Slow transitions for large surfaces: 300ms
+    pub duration_slow: Duration,
 }
 
 impl Default for GlobalTokens {
@@ -285,6 +305,17 @@ impl Default for GlobalTokens {
             radius_lg: px(12.0),
             radius_xl: px(16.0),
             radius_full: px(9999.0),
+
+            // Breakpoints
+            breakpoint_sm: px(640.0),
+            breakpoint_md: px(768.0),
+            breakpoint_lg: px(1024.0),
+            breakpoint_xl: px(1280.0),
+
+            // Motion durations
+            duration_fast: Duration::from_millis(100),
+            duration_normal: Duration::from_millis(200),
+            duration_slow: Duration::from_millis(300),
         }
     }
 }
@@ -395,6 +426,23 @@ impl AliasTokens {
         }
     }
 
+    /// Create alias tokens from global tokens for a theme mode
+    ///
+    /// High contrast modes use mappings that meet WCAG AAA contrast (7:1) for
+    /// text on surfaces.
+    ///
+    /// # Arguments
+    ///
+    /// * `global` - Global tokens to map from
+    /// * `mode` - Theme mode to map for
+    pub fn from_mode(global: &GlobalTokens, mode: super::ThemeMode) -> Self {
+        match mode {
+            super::ThemeMode::HighContrast { dark: true } => Self::high_contrast_dark_mode(global),
+            super::ThemeMode::HighContrast { dark: false } => Self::high_contrast_light_mode(global),
+            mode => Self::from_global(global, mode.is_dark()),
+        }
+    }
+
     /// Create light mode alias tokens
     fn light_mode(global: &GlobalTokens) -> Self {
         Self {
@@ -498,6 +546,110 @@ impl AliasTokens {
             font_size_heading: global.font_size_xl,
         }
     }
+
+    /// Create high contrast light mode alias tokens
+    fn high_contrast_light_mode(global: &GlobalTokens) -> Self {
+        Self {
+            // Primary colors (darkest blues)
+            color_primary: global.blue_800,
+            color_primary_hover: global.blue_900,
+            color_primary_active: global.blue_900,
+
+            // Secondary colors
+            color_secondary: global.gray_800,
+            color_secondary_hover: global.gray_900,
+
+            // Danger colors
+            color_danger: global.red_800,
+            color_danger_hover: global.red_900,
+
+            // Success colors
+            color_success: global.green_800,
+            color_success_hover: global.green_900,
+
+            // Warning colors
+            color_warning: global.yellow_800,
+            color_warning_hover: global.yellow_900,
+
+            // Surface colors
+            color_surface: hsla(0.0, 0.0, 1.0, 1.0), // Pure white
+            color_surface_hover: global.gray_100,
+            color_surface_elevated: hsla(0.0, 0.0, 1.0, 1.0),
+
+            // Text colors
+            color_text_primary: hsla(0.0, 0.0, 0.0, 1.0), // Pure black
+            color_text_secondary: global.gray_800,
+            color_text_muted: global.gray_700,
+            color_text_on_primary: hsla(0.0, 0.0, 1.0, 1.0),
+
+            // Border colors (strong outlines)
+            color_border: global.gray_800,
+            color_border_hover: hsla(0.0, 0.0, 0.0, 1.0),
+            color_border_focus: global.blue_800,
+
+            // Spacing (same as light mode)
+            spacing_component_padding: global.spacing_base,
+            spacing_component_gap: global.spacing_sm,
+            spacing_section_gap: global.spacing_lg,
+
+            // Typography (same as light mode)
+            font_size_body: global.font_size_base,
+            font_size_caption: global.font_size_sm,
+            font_size_heading: global.font_size_xl,
+        }
+    }
+
+    /// Create high contrast dark mode alias tokens
+    fn high_contrast_dark_mode(global: &GlobalTokens) -> Self {
+        Self {
+            // Primary colors (lightest blues)
+            color_primary: global.blue_200,
+            color_primary_hover: global.blue_100,
+            color_primary_active: global.blue_50,
+
+            // Secondary colors
+            color_secondary: global.gray_300,
+            color_secondary_hover: global.gray_200,
+
+            // Danger colors
+            color_danger: global.red_300,
+            color_danger_hover: global.red_200,
+
+            // Success colors
+            color_success: global.green_300,
+            color_success_hover: global.green_200,
+
+            // Warning colors
+            color_warning: global.yellow_300,
+            color_warning_hover: global.yellow_200,
+
+            // Surface colors
+            color_surface: hsla(0.0, 0.0, 0.0, 1.0), // Pure black
+            color_surface_hover: global.gray_900,
+            color_surface_elevated: global.gray_950,
+
+            // Text colors
+            color_text_primary: hsla(0.0, 0.0, 1.0, 1.0), // Pure white
+            color_text_secondary: global.gray_100,
+            color_text_muted: global.gray_300,
+            color_text_on_primary: hsla(0.0, 0.0, 0.0, 1.0),
+
+            // Border colors (strong outlines)
+            color_border: global.gray_200,
+            color_border_hover: hsla(0.0, 0.0, 1.0, 1.0),
+            color_border_focus: global.blue_200,
+
+            // Spacing (same as light mode)
+            spacing_component_padding: global.spacing_base,
+            spacing_component_gap: global.spacing_sm,
+            spacing_section_gap: global.spacing_lg,
+
+            // Typography (same as light mode)
+            font_size_body: global.font_size_base,
+            font_size_caption: global.font_size_sm,
+            font_size_heading: global.font_size_xl,
+        }
+    }
 }
 
 /// Layer 3: Component-Specific Tokens - Button
@@ -659,13 +811,13 @@ impl ButtonTokens {
             text_danger: theme.alias.color_text_on_primary,
             text_disabled: theme.alias.color_text_muted,
 
-            // Layout - based on spacing scale
-            padding_x_md: theme.alias.spacing_component_padding,
-            padding_y_md: theme.alias.spacing_component_gap,
-            padding_x_sm: theme.global.spacing_sm,
-            padding_y_sm: px(4.0),
-            padding_x_lg: theme.global.spacing_md,
-            padding_y_lg: theme.global.spacing_sm,
+            // Layout - based on spacing scale, scaled by density
+            padding_x_md: theme.density.scale(theme.alias.spacing_component_padding),
+            padding_y_md: theme.density.scale(theme.alias.spacing_component_gap),
+            padding_x_sm: theme.density.scale(theme.global.spacing_sm),
+            padding_y_sm: theme.density.scale(px(4.0)),
+            padding_x_lg: theme.density.scale(theme.global.spacing_md),
+            padding_y_lg: theme.density.scale(theme.global.spacing_sm),
             gap: theme.alias.spacing_component_gap,
 
             // Typography
@@ -675,12 +827,12 @@ impl ButtonTokens {
             font_weight: theme.global.font_weight_medium,
 
             // Border & radius
-            border_width: px(1.0),
+            border_width: if theme.is_high_contrast() { px(2.0) } else { px(1.0) },
             border_radius: theme.global.radius_md,
 
             // Focus state
             focus_ring_color: theme.alias.color_border_focus,
-            focus_ring_width: px(2.0),
+            focus_ring_width: if theme.is_high_contrast() { px(3.0) } else { px(2.0) },
         }
     }
 }
@@ -864,21 +1016,21 @@ impl InputTokens {
             text_disabled: theme.alias.color_text_muted,
             text_error: theme.alias.color_danger,
 
-            // Layout - standard form input sizing
-            padding_x: theme.alias.spacing_component_padding,
-            padding_y: theme.alias.spacing_component_gap,
+            // Layout - standard form input sizing, scaled by density
+            padding_x: theme.density.scale(theme.alias.spacing_component_padding),
+            padding_y: theme.density.scale(theme.alias.spacing_component_gap),
 
             // Typography - body text sizing
             font_size: theme.alias.font_size_body,
             font_weight: FontWeight(theme.global.font_weight_normal as f32),
 
             // Border & radius
-            border_width: px(1.0),
+            border_width: if theme.is_high_contrast() { px(2.0) } else { px(1.0) },
             border_radius: theme.global.radius_md,
 
             // Focus state - consistent with Button
             focus_ring_color: theme.alias.color_border_focus,
-            focus_ring_width: px(2.0),
+            focus_ring_width: if theme.is_high_contrast() { px(3.0) } else { px(2.0) },
         }
     }
 }
@@ -1303,7 +1455,7 @@ impl CheckboxTokens {
             border_unchecked: theme.alias.color_border,
             border_checked: theme.alias.color_primary,
             border_disabled: theme.global.gray_300,
-            border_width: px(1.0),
+            border_width: if theme.is_high_contrast() { px(2.0) } else { px(1.0) },
             border_radius: theme.global.radius_sm,
             icon_color: theme.alias.color_text_on_primary,
             icon_size: px(14.0),
@@ -1348,7 +1500,7 @@ impl RadioTokens {
             border_unselected: theme.alias.color_border,
             border_selected: theme.alias.color_primary,
             border_disabled: theme.global.gray_300,
-            border_width: px(1.0),
+            border_width: if theme.is_high_contrast() { px(2.0) } else { px(1.0) },
             dot_size: px(10.0),
             dot_color: theme.alias.color_primary,
             label_gap: theme.global.spacing_sm,
@@ -1434,3 +1586,34 @@ impl SpinnerTokens {
         }
     }
 }
+
+/// Layer 3: Component-Specific Tokens - Scrollbar
+#[derive(Debug, Clone)]
+pub struct ScrollbarTokens {
+    pub thickness: Pixels,
+    pub thickness_hover: Pixels,
+    pub min_thumb_length: Pixels,
+    pub inset: Pixels,
+    pub thumb_color: Hsla,
+    pub thumb_color_hover: Hsla,
+    pub track_color_hover: Hsla,
+}
+
+impl ScrollbarTokens {
+    pub fn from_theme(theme: &super::Theme) -> Self {
+        let (thumb, thumb_hover, track) = if theme.is_dark() {
+            (theme.global.gray_600, theme.global.gray_500, theme.global.gray_800)
+        } else {
+            (theme.global.gray_400, theme.global.gray_500, theme.global.gray_100)
+        };
+        Self {
+            thickness: px(6.0),
+            thickness_hover: px(10.0),
+            min_thumb_length: px(24.0),
+            inset: px(2.0),
+            thumb_color: thumb,
+            thumb_color_hover: thumb_hover,
+            track_color_hover: track,
+        }
+    }
+}
@@ -0,0 +1,430 @@
+//! Anchored positioning for overlays such as dropdown menus, tooltips and popovers.
+//!
+//! An overlay is placed on one side of its anchor (the trigger element),
+//! aligned along that side, and kept inside the window: when the preferred
+//! side has no room it flips to the opposite side, and it is shifted along
+//! the side so it does not run off the window edge.
+//!
+//! [`anchored_position`] does the geometry. [`AnchoredState`] measures the
+//! anchor and overlay while painting and renders the overlay above the rest
+//! of the window at the computed position.
+
+use std::{cell::Cell, rc::Rc};
+
+use gpui::*;
+use gpui::prelude::FluentBuilder;
+
+/// Side of the anchor an overlay is placed on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Placement {
+    /// Above the anchor
+    Top,
+    /// Below the anchor (default)
+    #[default]
+    Bottom,
+    /// Left of the anchor
+    Left,
+    /// Right of the anchor
+    Right,
+}
+
+impl Placement {
+    /// The placement on the other side of the anchor
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
+    /// Whether the overlay sits above or below the anchor
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Self::Top | Self::Bottom)
+    }
+}
+
+/// Alignment of an overlay along the side of its anchor
+///
+/// For top and bottom placements `Start` lines up the left edges; for left
+/// and right placements it lines up the top edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlacementAlign {
+    /// Line up the leading edges
+    Start,
+    /// Center the overlay on the anchor (default)
+    #[default]
+    Center,
+    /// Line up the trailing edges
+    End,
+}
+
+/// Options for positioning an overlay against its anchor
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnchorOptions {
+    /// Preferred side of the anchor
+    pub placement: Placement,
+    /// Alignment along that side
+    pub align: PlacementAlign,
+    /// Gap between the anchor and the overlay
+    pub offset: Pixels,
+    /// Whether to flip to the opposite side when the preferred one has no room
+    pub flip: bool,
+    /// Minimum distance kept between the overlay and the window edges
+    pub padding: Pixels,
+}
+
+impl Default for AnchorOptions {
+    fn default() -> Self {
+        Self {
+            placement: Placement::default(),
+            align: PlacementAlign::default(),
+            offset: px(4.0),
+            flip: true,
+            padding: px(8.0),
+        }
+    }
+}
+
+impl AnchorOptions {
+    /// Create options for the given placement
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let options = AnchorOptions::new(Placement::Top).offset(px(8.0));
+    /// ```
+    pub fn new(placement: Placement) -> Self {
+        Self {
+            placement,
+            ..Default::default()
+        }
+    }
+
+    /// Set the alignment along the anchor's side
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// AnchorOptions::new(Placement::Bottom).align(PlacementAlign::Start);
+    /// ```
+    pub fn align(mut self, align: PlacementAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Set the gap between the anchor and the overlay
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// AnchorOptions::new(Placement::Top).offset(px(8.0));
+    /// ```
+    pub fn offset(mut self, offset: Pixels) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Set whether the overlay flips to the opposite side when out of room
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// AnchorOptions::new(Placement::Right).flip(false);
+    /// ```
+    pub fn flip(mut self, flip: bool) -> Self {
+        self.flip = flip;
+        self
+    }
+
+    /// Set the minimum distance kept from the window edges
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// AnchorOptions::new(Placement::Bottom).padding(px(16.0));
+    /// ```
+    pub fn padding(mut self, padding: Pixels) -> Self {
+        self.padding = padding;
+        self
+    }
+}
+
+/// Where an overlay ends up relative to its anchor
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnchoredPosition {
+    /// Top-left corner of the overlay in window coordinates
+    pub origin: Point<Pixels>,
+    /// Side actually used, which differs from the preferred one after a flip
+    pub placement: Placement,
+}
+
+/// Room between the anchor and the window edge on the given side
+fn space(anchor: Bounds<Pixels>, viewport: Size<Pixels>, placement: Placement) -> Pixels {
+    match placement {
+        Placement::Top => anchor.top(),
+        Placement::Bottom => viewport.height - anchor.bottom(),
+        Placement::Left => anchor.left(),
+        Placement::Right => viewport.width - anchor.right(),
+    }
+}
+
+/// Keep `start` within `min..=max`, preferring `min` when the range is empty
+fn clamp(start: Pixels, min: Pixels, max: Pixels) -> Pixels {
+    let start = if start > max { max } else { start };
+    if start < min { min } else { start }
+}
+
+/// Position an overlay of `size` against `anchor` inside a window of `viewport`
+///
+/// The overlay goes on the preferred side unless it does not fit there and
+/// flipping is enabled, in which case it goes on the opposite side if that
+/// side has more room. It is then shifted along the side to stay at least
+/// `padding` from the window edges.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::utils::*;
+///
+/// let position = anchored_position(
+///     trigger_bounds,
+///     menu_size,
+///     window.viewport_size(),
+///     &AnchorOptions::new(Placement::Bottom).align(PlacementAlign::Start),
+/// );
+/// ```
+pub fn anchored_position(
+    anchor: Bounds<Pixels>,
+    size: Size<Pixels>,
+    viewport: Size<Pixels>,
+    options: &AnchorOptions,
+) -> AnchoredPosition {
+    let needed = |placement: Placement| {
+        let extent = if placement.is_vertical() { size.height } else { size.width };
+        extent + options.offset + options.padding
+    };
+
+    let mut placement = options.placement;
+    if options.flip && space(anchor, viewport, placement) < needed(placement) {
+        let opposite = placement.opposite();
+        if space(anchor, viewport, opposite) > space(anchor, viewport, placement) {
+            placement = opposite;
+        }
+    }
+
+    // Position along the side from the alignment, then keep it in the window
+    let (anchor_start, anchor_len, overlay_len, viewport_len) = if placement.is_vertical() {
+        (anchor.left(), anchor.size.width, size.width, viewport.width)
+    } else {
+        (anchor.top(), anchor.size.height, size.height, viewport.height)
+    };
+    let cross = match options.align {
+        PlacementAlign::Start => anchor_start,
+        PlacementAlign::Center => anchor_start + (anchor_len - overlay_len) / 2.0,
+        PlacementAlign::End => anchor_start + anchor_len - overlay_len,
+    };
+    let cross = clamp(cross, options.padding, viewport_len - overlay_len - options.padding);
+
+    let origin = match placement {
+        Placement::Top => point(cross, anchor.top() - options.offset - size.height),
+        Placement::Bottom => point(cross, anchor.bottom() + options.offset),
+        Placement::Left => point(anchor.left() - options.offset - size.width, cross),
+        Placement::Right => point(anchor.right() + options.offset, cross),
+    };
+
+    AnchoredPosition { origin, placement }
+}
+
+/// Measured anchor and overlay bounds for an anchored overlay.
+///
+/// Store it on the view that renders the overlay. While painting, it records
+/// where the anchor is and how big the overlay is; the next render uses those
+/// measurements to position the overlay. Cloning shares the measurements.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::utils::*;
+///
+/// // In render: measure the trigger…
+/// let trigger = div().child("Open").child(self.anchor.measure_anchor());
+///
+/// // …and position the menu against it
+/// let options = AnchorOptions::new(Placement::Bottom).align(PlacementAlign::Start);
+/// let position = self.anchor.position(&options, window);
+/// div()
+///     .child(trigger)
+///     .child(self.anchor.overlay(position, menu));
+/// ```
+#[derive(Clone, Default)]
+pub struct AnchoredState {
+    anchor: Rc<Cell<Option<Bounds<Pixels>>>>,
+    overlay: Rc<Cell<Option<Size<Pixels>>>>,
+}
+
+impl AnchoredState {
+    /// Create a new state with nothing measured yet
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// struct MyMenu {
+    ///     anchor: AnchoredState,
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Anchor bounds from the last paint, in window coordinates
+    pub fn anchor_bounds(&self) -> Option<Bounds<Pixels>> {
+        self.anchor.get()
+    }
+
+    /// Overlay size from the last paint
+    pub fn overlay_size(&self) -> Option<Size<Pixels>> {
+        self.overlay.get()
+    }
+
+    /// Element that records the bounds of the element it is placed in
+    ///
+    /// Add it as a child of the anchor; it fills the anchor without
+    /// affecting its layout.
+    pub fn measure_anchor(&self) -> impl IntoElement {
+        let anchor = self.anchor.clone();
+        canvas(
+            move |bounds, window, _cx| {
+                if anchor.get() != Some(bounds) {
+                    anchor.set(Some(bounds));
+                    window.request_animation_frame();
+                }
+            },
+            |_, (), _, _| {},
+        )
+        .absolute()
+        .top(px(0.0))
+        .left(px(0.0))
+        .size_full()
+    }
+
+    /// Position for the overlay, or `None` until both it and the anchor are measured
+    pub fn position(&self, options: &AnchorOptions, window: &Window) -> Option<AnchoredPosition> {
+        let anchor = self.anchor.get()?;
+        let size = self.overlay.get()?;
+        Some(anchored_position(anchor, size, window.viewport_size(), options))
+    }
+
+    /// Render `content` above the rest of the window at `position`
+    ///
+    /// Without a position the overlay is rendered invisibly so it can be
+    /// measured, and shows up positioned on the next frame.
+    pub fn overlay(&self, position: Option<AnchoredPosition>, content: impl IntoElement) -> impl IntoElement {
+        let overlay = self.overlay.clone();
+        let measure = canvas(
+            move |bounds, window, _cx| {
+                if overlay.get() != Some(bounds.size) {
+                    overlay.set(Some(bounds.size));
+                    window.request_animation_frame();
+                }
+            },
+            |_, (), _, _| {},
+        )
+        .absolute()
+        .top(px(0.0))
+        .left(px(0.0))
+        .size_full();
+
+        let origin = position.map_or_else(Point::default, |position| position.origin);
+        deferred(
+            anchored().position(origin).child(
+                div()
+                    .relative()
+                    .when(position.is_none(), |this| this.invisible())
+                    .child(content)
+                    .child(measure),
+            ),
+        )
+        .with_priority(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn viewport() -> Size<Pixels> {
+        size(px(800.0), px(600.0))
+    }
+
+    fn anchor_at(x: f32, y: f32) -> Bounds<Pixels> {
+        Bounds::new(point(px(x), px(y)), size(px(100.0), px(40.0)))
+    }
+
+    #[test]
+    fn test_places_on_preferred_side() {
+        let overlay = size(px(200.0), px(100.0));
+        let options = AnchorOptions::new(Placement::Bottom).align(PlacementAlign::Start);
+        let position = anchored_position(anchor_at(300.0, 200.0), overlay, viewport(), &options);
+        assert_eq!(position.placement, Placement::Bottom);
+        assert_eq!(position.origin, point(px(300.0), px(244.0)));
+
+        let options = AnchorOptions::new(Placement::Right).align(PlacementAlign::Start);
+        let position = anchored_position(anchor_at(300.0, 200.0), overlay, viewport(), &options);
+        assert_eq!(position.placement, Placement::Right);
+        assert_eq!(position.origin, point(px(404.0), px(200.0)));
+    }
+
+    #[test]
+    fn test_alignment() {
+        let overlay = size(px(200.0), px(100.0));
+        let anchor = anchor_at(300.0, 200.0);
+        let at = |align| {
+            let options = AnchorOptions::new(Placement::Top).align(align);
+            anchored_position(anchor, overlay, viewport(), &options).origin
+        };
+        assert_eq!(at(PlacementAlign::Start), point(px(300.0), px(96.0)));
+        assert_eq!(at(PlacementAlign::Center), point(px(250.0), px(96.0)));
+        assert_eq!(at(PlacementAlign::End), point(px(200.0), px(96.0)));
+    }
+
+    #[test]
+    fn test_flips_when_out_of_room() {
+        let overlay = size(px(200.0), px(100.0));
+        // Anchor near the bottom edge: a bottom menu flips above
+        let options = AnchorOptions::new(Placement::Bottom);
+        let position = anchored_position(anchor_at(300.0, 520.0), overlay, viewport(), &options);
+        assert_eq!(position.placement, Placement::Top);
+        assert_eq!(position.origin.y, px(416.0));
+
+        // Without flipping it stays on the preferred side
+        let position = anchored_position(anchor_at(300.0, 520.0), overlay, viewport(), &options.flip(false));
+        assert_eq!(position.placement, Placement::Bottom);
+
+        // Anchor near the left edge: a left tooltip flips to the right
+        let options = AnchorOptions::new(Placement::Left);
+        let position = anchored_position(anchor_at(20.0, 200.0), overlay, viewport(), &options);
+        assert_eq!(position.placement, Placement::Right);
+    }
+
+    #[test]
+    fn test_does_not_flip_into_less_room() {
+        // Neither side fits; stay on the preferred side since it has more room
+        let overlay = size(px(200.0), px(500.0));
+        let options = AnchorOptions::new(Placement::Bottom);
+        let position = anchored_position(anchor_at(300.0, 200.0), overlay, viewport(), &options);
+        assert_eq!(position.placement, Placement::Bottom);
+    }
+
+    #[test]
+    fn test_shifts_to_stay_in_window() {
+        let overlay = size(px(200.0), px(100.0));
+        let options = AnchorOptions::new(Placement::Bottom).align(PlacementAlign::Center);
+        let position = anchored_position(anchor_at(0.0, 200.0), overlay, viewport(), &options);
+        assert_eq!(position.origin.x, px(8.0));
+
+        let position = anchored_position(anchor_at(750.0, 200.0), overlay, viewport(), &options);
+        assert_eq!(position.origin.x, px(592.0));
+    }
+}
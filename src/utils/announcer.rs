@@ -2,9 +2,19 @@
 //!
 //! The Announcer provides a way to communicate dynamic updates to screen
 //! reader users through ARIA live regions.
+//!
+//! Announcements go to a single application-wide [`LiveRegion`], rendered
+//! once per window with [`Announcer::live_region`]. Polite announcements
+//! are debounced so a burst of updates reads only the last one; assertive
+//! announcements are read at once and drop any polite one still waiting.
+
+use std::time::Duration;
 
 use gpui::*;
 
+/// How long polite announcements wait for a newer one before being read
+const ANNOUNCE_DEBOUNCE: Duration = Duration::from_millis(150);
+
 /// Priority level for screen reader announcements.
 ///
 /// These map to ARIA live region politeness levels.
@@ -44,23 +54,27 @@ impl Default for AnnouncerPriority {
 ///
 /// - Polite announcements (wait for screen reader)
 /// - Assertive announcements (interrupt screen reader)
-/// - Automatic announcement queuing
-/// - Thread-safe announcement management
+/// - Debouncing of rapid polite announcements
+/// - One live region per window, shared by every component
 ///
 /// ## Example
 ///
 /// ```rust,ignore
 /// use purdah_gpui_components::utils::*;
 ///
+/// // Once, in the root view's render
+/// div().size_full().child(content).child(Announcer::live_region(cx));
+///
 /// // Polite announcement for status updates
-/// Announcer::polite("Form saved successfully");
+/// Announcer::polite("Form saved successfully").announce(cx);
 ///
 /// // Assertive announcement for errors
-/// Announcer::assertive("Error: Failed to save form");
+/// Announcer::assertive("Error: Failed to save form").announce(cx);
 ///
-/// // Create an announcer instance
-/// let announcer = Announcer::new(AnnouncerPriority::Polite);
-/// announcer.announce("Loading complete", cx);
+/// // Or build one up
+/// Announcer::new(AnnouncerPriority::Polite)
+///     .message("Loading complete")
+///     .announce(cx);
 /// ```
 ///
 /// ## Accessibility
@@ -149,7 +163,8 @@ impl Announcer {
 
     /// Announce the current message with the configured priority.
     ///
-    /// This triggers the announcement to be read by screen readers.
+    /// The message is sent to the application's [`LiveRegion`] for screen
+    /// readers to read. Empty messages and the `Off` priority are ignored.
     ///
     /// ## Example
     ///
@@ -157,16 +172,26 @@ impl Announcer {
     /// let announcer = Announcer::polite("Form saved");
     /// announcer.announce(cx);
     /// ```
-    pub fn announce<V>(&self, _cx: &mut Context<V>) {
-        // In a full implementation, this would:
-        // 1. Create or update a live region element
-        // 2. Set the appropriate aria-live attribute
-        // 3. Update the element's text content
-        // 4. Manage announcement queuing for rapid updates
-
-        // For now, this is a stub that demonstrates the API
-        // The actual implementation would integrate with GPUI's
-        // accessibility infrastructure
+    pub fn announce(&self, cx: &mut App) {
+        if self.message.is_empty() || self.priority == AnnouncerPriority::Off {
+            return;
+        }
+        let (message, priority) = (self.message.clone(), self.priority);
+        LiveRegion::global(cx).update(cx, |region, cx| region.push(message, priority, cx));
+    }
+
+    /// The application's live region, drawn in the window's overlay layer.
+    ///
+    /// Render this once near the root of each window; announcements made
+    /// anywhere in the application are read from it.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// div().size_full().child(content).child(Announcer::live_region(cx))
+    /// ```
+    pub fn live_region(cx: &mut App) -> impl IntoElement {
+        deferred(LiveRegion::global(cx))
     }
 
     /// Get the current priority level.
@@ -204,8 +229,8 @@ impl Announcer {
 
     /// Render the announcer as a live region element.
     ///
-    /// This should be included in your component tree to enable
-    /// screen reader announcements.
+    /// This shows this announcer's message only; prefer
+    /// [`Announcer::announce`] with a shared [`Announcer::live_region`].
     ///
     /// ## Example
     ///
@@ -222,16 +247,138 @@ impl Announcer {
             AnnouncerPriority::Off => "off",
         };
 
+        // The id stands in for the aria-live attribute
+        visually_hidden(aria_live).child(self.message.clone())
+    }
+}
+
+/// A region shown off screen, where only screen readers find it
+fn visually_hidden(id: impl Into<ElementId>) -> Stateful<Div> {
+    div()
+        .id(id)
+        .absolute()
+        .left(px(-10000.0))
+        .w(px(1.0))
+        .h(px(1.0))
+        .overflow_hidden()
+}
+
+/// Polite announcement waiting out the debounce
+#[derive(Debug, Default)]
+struct AnnouncementQueue {
+    pending: Option<SharedString>,
+}
+
+impl AnnouncementQueue {
+    /// Queue `message`, returning it when it must be read immediately
+    ///
+    /// A polite message replaces the one waiting; an assertive message
+    /// skips the queue and drops the waiting one.
+    fn push(&mut self, message: SharedString, priority: AnnouncerPriority) -> Option<SharedString> {
+        match priority {
+            AnnouncerPriority::Polite => {
+                self.pending = Some(message);
+                None
+            }
+            AnnouncerPriority::Assertive => {
+                self.pending = None;
+                Some(message)
+            }
+            AnnouncerPriority::Off => None,
+        }
+    }
+
+    /// Take the waiting polite message once the debounce has passed
+    fn take(&mut self) -> Option<SharedString> {
+        self.pending.take()
+    }
+}
+
+/// Global slot holding the application-wide [`LiveRegion`]
+struct GlobalLiveRegion(Entity<LiveRegion>);
+
+impl Global for GlobalLiveRegion {}
+
+/// The application-wide live region screen readers read announcements from.
+///
+/// LiveRegion holds one polite and one assertive region, each showing the
+/// latest announcement of its priority. It is created on first use; render
+/// it with [`Announcer::live_region`] and feed it with
+/// [`Announcer::announce`].
+pub struct LiveRegion {
+    queue: AnnouncementQueue,
+    polite: SharedString,
+    assertive: SharedString,
+    /// Counts announcements so repeating a message is read again
+    generation: usize,
+    /// Reads the waiting polite message once no newer one arrives
+    _debounce: Option<Task<()>>,
+}
+
+impl LiveRegion {
+    /// The application's live region, created on first use
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let latest = LiveRegion::global(cx).read(cx).polite_message().clone();
+    /// ```
+    pub fn global(cx: &mut App) -> Entity<Self> {
+        if let Some(global) = cx.try_global::<GlobalLiveRegion>() {
+            return global.0.clone();
+        }
+        let region = cx.new(|_| Self {
+            queue: AnnouncementQueue::default(),
+            polite: "".into(),
+            assertive: "".into(),
+            generation: 0,
+            _debounce: None,
+        });
+        cx.set_global(GlobalLiveRegion(region.clone()));
+        region
+    }
+
+    /// The polite announcement last read
+    pub fn polite_message(&self) -> &SharedString {
+        &self.polite
+    }
+
+    /// The assertive announcement last read
+    pub fn assertive_message(&self) -> &SharedString {
+        &self.assertive
+    }
+
+    /// Queue an announcement, reading assertive ones immediately
+    fn push(&mut self, message: SharedString, priority: AnnouncerPriority, cx: &mut Context<'_, Self>) {
+        if let Some(message) = self.queue.push(message, priority) {
+            // Interrupt: the waiting polite message was dropped
+            self._debounce = None;
+            self.assertive = message;
+            self.generation += 1;
+            cx.notify();
+        } else if priority == AnnouncerPriority::Polite {
+            // Restarting the timer keeps a burst of updates to one reading
+            self._debounce = Some(cx.spawn(async move |this, cx| {
+                cx.background_executor().timer(ANNOUNCE_DEBOUNCE).await;
+                this.update(cx, |this, cx| {
+                    if let Some(message) = this.queue.take() {
+                        this.polite = message;
+                        this.generation += 1;
+                        cx.notify();
+                    }
+                })
+                .ok();
+            }));
+        }
+    }
+}
+
+impl Render for LiveRegion {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<'_, Self>) -> impl IntoElement {
+        // Keying the regions by generation makes each announcement new content
         div()
-            // Visually hidden but accessible to screen readers
-            .absolute()
-            .left(px(-10000.0))
-            .w(px(1.0))
-            .h(px(1.0))
-            .overflow_hidden()
-            // ARIA attributes (would need GPUI support)
-            .id(aria_live) // Placeholder for aria-live attribute
-            .child(self.message.clone())
+            .child(visually_hidden(("polite", self.generation)).child(self.polite.clone()))
+            .child(visually_hidden(("assertive", self.generation)).child(self.assertive.clone()))
     }
 }
 
@@ -248,10 +395,8 @@ impl Default for Announcer {
 /// ```rust,ignore
 /// announce_polite("Changes saved", cx);
 /// ```
-pub fn announce_polite<V>(message: impl Into<SharedString>, _cx: &mut Context<V>) {
-    let announcer = Announcer::polite(message);
-    // In a full implementation, this would trigger the announcement
-    drop(announcer);
+pub fn announce_polite(message: impl Into<SharedString>, cx: &mut App) {
+    Announcer::polite(message).announce(cx);
 }
 
 /// Convenience function to make an assertive announcement.
@@ -261,10 +406,8 @@ pub fn announce_polite<V>(message: impl Into<SharedString>, _cx: &mut Context<V>
 /// ```rust,ignore
 /// announce_assertive("Critical error occurred", cx);
 /// ```
-pub fn announce_assertive<V>(message: impl Into<SharedString>, _cx: &mut Context<V>) {
-    let announcer = Announcer::assertive(message);
-    // In a full implementation, this would trigger the announcement
-    drop(announcer);
+pub fn announce_assertive(message: impl Into<SharedString>, cx: &mut App) {
+    Announcer::assertive(message).announce(cx);
 }
 
 #[cfg(test)]
@@ -308,4 +451,30 @@ mod tests {
         announcer.clear();
         assert_eq!(announcer.get_message(), "");
     }
+
+    #[test]
+    fn test_polite_burst_keeps_latest() {
+        let mut queue = AnnouncementQueue::default();
+        assert_eq!(queue.push("Loading".into(), AnnouncerPriority::Polite), None);
+        assert_eq!(queue.push("12 results".into(), AnnouncerPriority::Polite), None);
+        assert_eq!(queue.take().as_deref(), Some("12 results"));
+        assert_eq!(queue.take(), None);
+    }
+
+    #[test]
+    fn test_assertive_interrupts_pending() {
+        let mut queue = AnnouncementQueue::default();
+        queue.push("Saving".into(), AnnouncerPriority::Polite);
+        let immediate = queue.push("Save failed".into(), AnnouncerPriority::Assertive);
+        assert_eq!(immediate.as_deref(), Some("Save failed"));
+        assert_eq!(queue.take(), None);
+    }
+
+    #[test]
+    fn test_off_is_ignored() {
+        let mut queue = AnnouncementQueue::default();
+        queue.push("Saving".into(), AnnouncerPriority::Polite);
+        assert_eq!(queue.push("Hidden".into(), AnnouncerPriority::Off), None);
+        assert_eq!(queue.take().as_deref(), Some("Saving"));
+    }
 }
@@ -0,0 +1,248 @@
+//! Responsive breakpoints for window and container widths.
+//!
+//! Breakpoints are minimum widths, smallest first, configured by the
+//! `breakpoint_*` global tokens. A width takes the largest breakpoint it
+//! reaches; widths narrower than [`Breakpoint::Sm`] have no breakpoint.
+//! [`Responsive`] values follow the same mobile-first rule: each value
+//! applies from its breakpoint up until a larger one overrides it.
+
+use gpui::{App, Pixels, Window};
+
+use crate::theme::{use_theme, GlobalTokens};
+
+/// Named width breakpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Breakpoint {
+    /// At least `breakpoint_sm` wide (640px by default)
+    Sm,
+    /// At least `breakpoint_md` wide (768px by default)
+    Md,
+    /// At least `breakpoint_lg` wide (1024px by default)
+    Lg,
+    /// At least `breakpoint_xl` wide (1280px by default)
+    Xl,
+}
+
+impl Breakpoint {
+    /// All breakpoints, smallest first
+    pub const ALL: [Breakpoint; 4] = [Breakpoint::Sm, Breakpoint::Md, Breakpoint::Lg, Breakpoint::Xl];
+
+    /// Minimum width of this breakpoint
+    pub fn min_width(self, tokens: &GlobalTokens) -> Pixels {
+        match self {
+            Breakpoint::Sm => tokens.breakpoint_sm,
+            Breakpoint::Md => tokens.breakpoint_md,
+            Breakpoint::Lg => tokens.breakpoint_lg,
+            Breakpoint::Xl => tokens.breakpoint_xl,
+        }
+    }
+
+    /// Largest breakpoint `width` reaches, or `None` below `Sm`
+    ///
+    /// Pass a container's measured width to respond to the space a
+    /// component actually has rather than to the window.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let compact = Breakpoint::for_width(panel_width, &theme.global) < Some(Breakpoint::Md);
+    /// ```
+    pub fn for_width(width: Pixels, tokens: &GlobalTokens) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .rev()
+            .find(|breakpoint| width >= breakpoint.min_width(tokens))
+    }
+}
+
+/// Breakpoint of the window's current width, using the active theme's tokens
+///
+/// Windows re-render when resized, so views calling this during render
+/// follow the window size.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// let show_sidebar = use_breakpoint(window, cx) >= Some(Breakpoint::Lg);
+/// ```
+pub fn use_breakpoint(window: &Window, cx: &App) -> Option<Breakpoint> {
+    let theme = use_theme(cx);
+    Breakpoint::for_width(window.viewport_size().width, &theme.global)
+}
+
+/// A value that varies by breakpoint.
+///
+/// Starts from a base value for the narrowest widths; each breakpoint value
+/// applies from that breakpoint up until a larger one overrides it.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::utils::*;
+///
+/// let gap = Responsive::new(px(8.0)).md(px(16.0)).xl(px(24.0));
+/// let vertical = Responsive::new(true).md(false);
+/// let show_details = Responsive::new(false).lg(true);
+///
+/// let (gap, vertical) = (gap.resolve(window, cx), vertical.resolve(window, cx));
+/// let stack = if vertical {
+///     VStack::new().gap(gap).to_element()
+/// } else {
+///     HStack::new().gap(gap).to_element()
+/// };
+/// stack.when(show_details.resolve(window, cx), |stack| stack.child(details));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Responsive<T> {
+    base: T,
+    sm: Option<T>,
+    md: Option<T>,
+    lg: Option<T>,
+    xl: Option<T>,
+}
+
+impl<T: Clone> Responsive<T> {
+    /// Create a responsive value that is `base` at every width
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let columns = Responsive::new(1);
+    /// ```
+    pub fn new(base: T) -> Self {
+        Self { base, sm: None, md: None, lg: None, xl: None }
+    }
+
+    /// Set the value from the `Sm` breakpoint up
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Responsive::new(1).sm(2);
+    /// ```
+    pub fn sm(mut self, value: T) -> Self {
+        self.sm = Some(value);
+        self
+    }
+
+    /// Set the value from the `Md` breakpoint up
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Responsive::new(1).md(3);
+    /// ```
+    pub fn md(mut self, value: T) -> Self {
+        self.md = Some(value);
+        self
+    }
+
+    /// Set the value from the `Lg` breakpoint up
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Responsive::new(1).lg(4);
+    /// ```
+    pub fn lg(mut self, value: T) -> Self {
+        self.lg = Some(value);
+        self
+    }
+
+    /// Set the value from the `Xl` breakpoint up
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Responsive::new(1).xl(6);
+    /// ```
+    pub fn xl(mut self, value: T) -> Self {
+        self.xl = Some(value);
+        self
+    }
+
+    /// Value at `breakpoint`, falling back to smaller breakpoints and the base
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let columns = *Responsive::new(1).md(3).at(Some(Breakpoint::Lg)); // 3
+    /// ```
+    pub fn at(&self, breakpoint: Option<Breakpoint>) -> &T {
+        let values = [&self.sm, &self.md, &self.lg, &self.xl];
+        let reached = breakpoint.map_or(0, |breakpoint| breakpoint as usize + 1);
+        values[..reached]
+            .iter()
+            .rev()
+            .find_map(|value| value.as_ref())
+            .unwrap_or(&self.base)
+    }
+
+    /// Value for a container `width`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let columns = *columns.for_width(self.measured_width, &theme.global);
+    /// ```
+    pub fn for_width(&self, width: Pixels, tokens: &GlobalTokens) -> &T {
+        self.at(Breakpoint::for_width(width, tokens))
+    }
+
+    /// Value for the window's current width
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// VStack::new().gap(gap.resolve(window, cx));
+    /// ```
+    pub fn resolve(&self, window: &Window, cx: &App) -> T {
+        self.at(use_breakpoint(window, cx)).clone()
+    }
+}
+
+impl<T: Clone> From<T> for Responsive<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gpui::px;
+
+    #[test]
+    fn test_breakpoint_for_width() {
+        let tokens = GlobalTokens::default();
+        assert_eq!(Breakpoint::for_width(px(320.0), &tokens), None);
+        assert_eq!(Breakpoint::for_width(px(640.0), &tokens), Some(Breakpoint::Sm));
+        assert_eq!(Breakpoint::for_width(px(900.0), &tokens), Some(Breakpoint::Md));
+        assert_eq!(Breakpoint::for_width(px(1024.0), &tokens), Some(Breakpoint::Lg));
+        assert_eq!(Breakpoint::for_width(px(1920.0), &tokens), Some(Breakpoint::Xl));
+    }
+
+    #[test]
+    fn test_breakpoints_follow_tokens() {
+        let tokens = GlobalTokens { breakpoint_md: px(600.0), ..GlobalTokens::default() };
+        assert_eq!(Breakpoint::for_width(px(620.0), &tokens), Some(Breakpoint::Md));
+    }
+
+    #[test]
+    fn test_responsive_falls_back_to_smaller() {
+        let columns = Responsive::new(1).md(3).xl(6);
+        assert_eq!(*columns.at(None), 1);
+        assert_eq!(*columns.at(Some(Breakpoint::Sm)), 1);
+        assert_eq!(*columns.at(Some(Breakpoint::Md)), 3);
+        assert_eq!(*columns.at(Some(Breakpoint::Lg)), 3);
+        assert_eq!(*columns.at(Some(Breakpoint::Xl)), 6);
+    }
+
+    #[test]
+    fn test_responsive_for_width() {
+        let tokens = GlobalTokens::default();
+        let vertical = Responsive::from(true).md(false);
+        assert!(*vertical.for_width(px(500.0), &tokens));
+        assert!(!*vertical.for_width(px(800.0), &tokens));
+    }
+}
@@ -0,0 +1,261 @@
+//! Color contrast checking for WCAG compliance.
+//!
+//! Implements the WCAG 2.1 contrast ratio formula so custom themes can be
+//! verified against success criteria 1.4.3 (Contrast Minimum, level AA) and
+//! 1.4.6 (Contrast Enhanced, level AAA).
+
+use gpui::{Hsla, Rgba};
+
+use crate::theme::Theme;
+
+/// Minimum contrast for normal text at level AA
+pub const WCAG_AA_NORMAL_TEXT: f32 = 4.5;
+/// Minimum contrast for large text (18pt, or 14pt bold) at level AA
+pub const WCAG_AA_LARGE_TEXT: f32 = 3.0;
+/// Minimum contrast for normal text at level AAA
+pub const WCAG_AAA_NORMAL_TEXT: f32 = 7.0;
+/// Minimum contrast for large text (18pt, or 14pt bold) at level AAA
+pub const WCAG_AAA_LARGE_TEXT: f32 = 4.5;
+
+/// WCAG conformance level for text contrast.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContrastLevel {
+    /// Level AA, "Contrast (Minimum)": 4.5:1 for normal text
+    Minimum,
+    /// Level AAA, "Contrast (Enhanced)": 7:1 for normal text
+    Enhanced,
+}
+
+impl ContrastLevel {
+    /// Required contrast ratio for normal-size text at this level
+    pub fn required_ratio(self) -> f32 {
+        match self {
+            Self::Minimum => WCAG_AA_NORMAL_TEXT,
+            Self::Enhanced => WCAG_AAA_NORMAL_TEXT,
+        }
+    }
+}
+
+/// A text/background token pairing that fails the required contrast.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContrastIssue {
+    /// Alias token used as the text color
+    pub foreground: &'static str,
+    /// Alias token used as the background color
+    pub background: &'static str,
+    /// Measured contrast ratio
+    pub ratio: f32,
+    /// Required contrast ratio
+    pub required: f32,
+}
+
+/// Linearize an sRGB channel
+fn linearize(channel: f32) -> f32 {
+    if channel <= 0.039_28 {
+        channel / 12.92
+    } else {
+        ((channel + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Relative luminance of a color as defined by WCAG 2.1
+///
+/// Returns a value between 0.0 (black) and 1.0 (white). Alpha is ignored.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use gpui::hsla;
+/// use purdah_gpui_components::utils::contrast::relative_luminance;
+///
+/// assert_eq!(relative_luminance(hsla(0.0, 0.0, 1.0, 1.0)), 1.0);
+/// ```
+pub fn relative_luminance(color: Hsla) -> f32 {
+    let rgba = Rgba::from(color);
+    0.2126 * linearize(rgba.r) + 0.7152 * linearize(rgba.g) + 0.0722 * linearize(rgba.b)
+}
+
+/// Blend a translucent foreground over an opaque background
+fn composite(fg: Hsla, bg: Hsla) -> Hsla {
+    if fg.a >= 1.0 {
+        return fg;
+    }
+    let (fg, bg) = (Rgba::from(fg), Rgba::from(bg));
+    let mix = |f: f32, b: f32| f * fg.a + b * (1.0 - fg.a);
+    Rgba {
+        r: mix(fg.r, bg.r),
+        g: mix(fg.g, bg.g),
+        b: mix(fg.b, bg.b),
+        a: 1.0,
+    }
+    .into()
+}
+
+/// Contrast ratio between a foreground and background color
+///
+/// Returns a value between 1.0 (no contrast) and 21.0 (black on white). A
+/// translucent foreground is blended over the background first.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use gpui::hsla;
+/// use purdah_gpui_components::utils::contrast::contrast_ratio;
+///
+/// let black = hsla(0.0, 0.0, 0.0, 1.0);
+/// let white = hsla(0.0, 0.0, 1.0, 1.0);
+/// assert!((contrast_ratio(black, white) - 21.0).abs() < 0.01);
+/// ```
+pub fn contrast_ratio(fg: Hsla, bg: Hsla) -> f32 {
+    let fg = relative_luminance(composite(fg, bg));
+    let bg = relative_luminance(bg);
+    let (lighter, darker) = if fg > bg { (fg, bg) } else { (bg, fg) };
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+/// Check that normal-size text meets WCAG level AA (4.5:1)
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// assert!(meets_wcag_aa(theme.alias.color_text_primary, theme.alias.color_surface));
+/// ```
+pub fn meets_wcag_aa(fg: Hsla, bg: Hsla) -> bool {
+    contrast_ratio(fg, bg) >= WCAG_AA_NORMAL_TEXT
+}
+
+/// Check that normal-size text meets WCAG level AAA (7:1)
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// assert!(meets_wcag_aaa(theme.alias.color_text_primary, theme.alias.color_surface));
+/// ```
+pub fn meets_wcag_aaa(fg: Hsla, bg: Hsla) -> bool {
+    contrast_ratio(fg, bg) >= WCAG_AAA_NORMAL_TEXT
+}
+
+/// Check every alias text/surface pairing of a theme against a WCAG level
+///
+/// Returns the pairings that fail; an empty list means the theme passes.
+///
+/// ## Example
+///
+/// ```rust,no_run
+/// use purdah_gpui_components::theme::Theme;
+/// use purdah_gpui_components::utils::contrast::{check_theme, ContrastLevel};
+///
+/// for issue in check_theme(&Theme::dark(), ContrastLevel::Minimum) {
+///     eprintln!("{} on {}: {:.2}:1", issue.foreground, issue.background, issue.ratio);
+/// }
+/// ```
+pub fn check_theme(theme: &Theme, level: ContrastLevel) -> Vec<ContrastIssue> {
+    let alias = &theme.alias;
+    let pairings = [
+        ("color_text_primary", alias.color_text_primary, "color_surface", alias.color_surface),
+        ("color_text_primary", alias.color_text_primary, "color_surface_hover", alias.color_surface_hover),
+        ("color_text_primary", alias.color_text_primary, "color_surface_elevated", alias.color_surface_elevated),
+        ("color_text_secondary", alias.color_text_secondary, "color_surface", alias.color_surface),
+        ("color_text_secondary", alias.color_text_secondary, "color_surface_elevated", alias.color_surface_elevated),
+        ("color_text_muted", alias.color_text_muted, "color_surface", alias.color_surface),
+        ("color_text_on_primary", alias.color_text_on_primary, "color_primary", alias.color_primary),
+        ("color_text_on_primary", alias.color_text_on_primary, "color_primary_hover", alias.color_primary_hover),
+        ("color_danger", alias.color_danger, "color_surface", alias.color_surface),
+    ];
+
+    let required = level.required_ratio();
+    pairings
+        .into_iter()
+        .filter_map(|(foreground, fg, background, bg)| {
+            let ratio = contrast_ratio(fg, bg);
+            (ratio < required).then_some(ContrastIssue {
+                foreground,
+                background,
+                ratio,
+                required,
+            })
+        })
+        .collect()
+}
+
+/// Panic in debug builds if any alias text/surface pairing fails a WCAG level
+///
+/// Does nothing in release builds. Call it after constructing a custom theme
+/// to catch contrast regressions during development.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// let theme = ThemeBuilder::new().primary_scale(brand_scale).build()?;
+/// debug_assert_contrast(&theme, ContrastLevel::Minimum);
+/// ThemeProvider::init(cx, theme);
+/// ```
+pub fn debug_assert_contrast(theme: &Theme, level: ContrastLevel) {
+    if cfg!(debug_assertions) {
+        let issues = check_theme(theme, level);
+        assert!(
+            issues.is_empty(),
+            "theme fails {level:?} contrast: {}",
+            issues
+                .iter()
+                .map(|issue| format!(
+                    "{} on {} is {:.2}:1 (needs {:.1}:1)",
+                    issue.foreground, issue.background, issue.ratio, issue.required
+                ))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gpui::{hsla, rgb};
+
+    #[test]
+    fn test_luminance_extremes() {
+        assert!(relative_luminance(hsla(0.0, 0.0, 0.0, 1.0)).abs() < 0.001);
+        assert!((relative_luminance(hsla(0.0, 0.0, 1.0, 1.0)) - 1.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_contrast_ratio_is_symmetric() {
+        let black = hsla(0.0, 0.0, 0.0, 1.0);
+        let white = hsla(0.0, 0.0, 1.0, 1.0);
+        assert!((contrast_ratio(black, white) - 21.0).abs() < 0.01);
+        assert!((contrast_ratio(white, black) - 21.0).abs() < 0.01);
+        assert!((contrast_ratio(white, white) - 1.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_known_ratio() {
+        // #767676 on white is the classic 4.54:1 AA boundary gray
+        let ratio = contrast_ratio(rgb(0x767676).into(), rgb(0xffffff).into());
+        assert!((ratio - 4.54).abs() < 0.01);
+        assert!(meets_wcag_aa(rgb(0x767676).into(), rgb(0xffffff).into()));
+        assert!(!meets_wcag_aaa(rgb(0x767676).into(), rgb(0xffffff).into()));
+    }
+
+    #[test]
+    fn test_translucent_foreground() {
+        let transparent_black = hsla(0.0, 0.0, 0.0, 0.0);
+        let white = hsla(0.0, 0.0, 1.0, 1.0);
+        assert!((contrast_ratio(transparent_black, white) - 1.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_check_theme_reports_failures() {
+        let mut theme = Theme::light();
+        theme.alias.color_text_muted = theme.alias.color_surface;
+        let issues = check_theme(&theme, ContrastLevel::Minimum);
+        assert!(issues.iter().any(|issue| issue.foreground == "color_text_muted"));
+    }
+
+    #[test]
+    fn test_high_contrast_themes_meet_aaa() {
+        assert_eq!(check_theme(&Theme::high_contrast_light(), ContrastLevel::Enhanced), vec![]);
+        assert_eq!(check_theme(&Theme::high_contrast_dark(), ContrastLevel::Enhanced), vec![]);
+        debug_assert_contrast(&Theme::high_contrast_dark(), ContrastLevel::Enhanced);
+    }
+}
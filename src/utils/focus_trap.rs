@@ -13,12 +13,16 @@ use gpui::*;
 /// without leaving the boundary. This is critical for modal dialogs to
 /// meet WCAG 2.1 AA requirements.
 ///
+/// The focusable elements are registered as GPUI focus handles, in Tab
+/// order, with [`FocusTrap::set_handles`] or [`FocusTrap::register`].
+///
 /// ## Features
 ///
-/// - Traps Tab/Shift+Tab navigation within boundary
+/// - Traps Tab/Shift+Tab navigation within boundary, wrapping at the ends
 /// - Optionally restores focus when unmounted
 /// - Provides focus management for modal dialogs
-/// - Supports auto-focus on first/last element
+/// - Supports auto-focus on the first element, or on a chosen initial element
+/// - Only traps keys while active, between `initialize` and `cleanup`
 ///
 /// ## Example
 ///
@@ -26,16 +30,23 @@ use gpui::*;
 /// use purdah_gpui_components::utils::*;
 ///
 /// // Basic focus trap for a dialog
-/// let focus_trap = FocusTrap::new()
+/// let mut focus_trap = FocusTrap::new()
 ///     .auto_focus(true)
 ///     .restore_on_unmount(true);
+/// focus_trap.set_handles(vec![cancel_handle.clone(), confirm_handle.clone()]);
+/// // Start on Cancel rather than the first element
+/// focus_trap.set_initial_focus(Some(cancel_handle.clone()));
+///
+/// // When the dialog opens
+/// focus_trap.initialize(window, cx);
+///
+/// // In the dialog's key handler
+/// if focus_trap.handle_key_event(event, window) {
+///     cx.stop_propagation();
+/// }
 ///
-/// // In a dialog component
-/// div()
-///     .on_key_down(move |event, _window, cx| {
-///         focus_trap.handle_key_event(event, cx);
-///     })
-///     .child(/* dialog content */)
+/// // When the dialog closes
+/// focus_trap.cleanup(window);
 /// ```
 ///
 /// ## Accessibility
@@ -50,6 +61,28 @@ pub struct FocusTrap {
     pub restore_focus: bool,
     /// The previously focused element (for restoration)
     previous_focus: Option<FocusHandle>,
+    /// Focusable elements inside the boundary, in Tab order
+    handles: Vec<FocusHandle>,
+    /// Element focused on initialization instead of the first
+    initial_focus: Option<FocusHandle>,
+    /// Whether the trap is between `initialize` and `cleanup`
+    active: bool,
+}
+
+/// Index Tab moves focus to among `len` elements, wrapping at the ends
+///
+/// With nothing focused, Tab goes to the first element and Shift+Tab to
+/// the last.
+fn cycle_index(current: Option<usize>, len: usize, backward: bool) -> Option<usize> {
+    if len == 0 {
+        return None;
+    }
+    Some(match (current, backward) {
+        (None, false) => 0,
+        (None, true) => len - 1,
+        (Some(i), false) => (i + 1) % len,
+        (Some(i), true) => (i + len - 1) % len,
+    })
 }
 
 impl FocusTrap {
@@ -65,6 +98,9 @@ impl FocusTrap {
             auto_focus: true,
             restore_focus: true,
             previous_focus: None,
+            handles: Vec::new(),
+            initial_focus: None,
+            active: false,
         }
     }
 
@@ -92,19 +128,88 @@ impl FocusTrap {
         self
     }
 
+    /// Register the focusable elements inside the boundary, in Tab order.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// trap.set_handles(vec![name_input.clone(), save_button.clone()]);
+    /// ```
+    pub fn set_handles(&mut self, handles: Vec<FocusHandle>) {
+        self.handles = handles;
+    }
+
+    /// Register one more focusable element, after those already registered.
+    ///
+    /// Registering an element twice has no effect.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// trap.register(close_button.clone());
+    /// ```
+    pub fn register(&mut self, handle: FocusHandle) {
+        if !self.handles.contains(&handle) {
+            self.handles.push(handle);
+        }
+    }
+
+    /// Set the element focused on initialization, `None` for the first.
+    ///
+    /// The element does not need to be registered, so a trap can start on
+    /// its container and let Tab enter the registered elements.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// // Destructive confirmations start on the safe choice
+    /// trap.set_initial_focus(Some(cancel_handle.clone()));
+    /// ```
+    pub fn set_initial_focus(&mut self, handle: Option<FocusHandle>) {
+        self.initial_focus = handle;
+    }
+
+    /// Whether the trap is active, between `initialize` and `cleanup`.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Whether focus is on one of the registered elements.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// if !trap.contains_focus(window) {
+    ///     trap.focus_first(window);
+    /// }
+    /// ```
+    pub fn contains_focus(&self, window: &Window) -> bool {
+        self.handles.iter().any(|handle| handle.is_focused(window))
+    }
+
     /// Initialize the focus trap, capturing current focus if needed.
     ///
-    /// This should be called when the component mounts.
+    /// This should be called when the component mounts. With auto-focus
+    /// enabled, focus moves to the initial element, or else the first
+    /// registered one. Initializing an active trap keeps the element
+    /// captured for restoration when it was first initialized.
     ///
     /// ## Example
     ///
     /// ```rust,ignore
     /// let mut trap = FocusTrap::new();
-    /// trap.initialize(cx);
+    /// trap.initialize(window, cx);
     /// ```
-    pub fn initialize<V>(&mut self, cx: &mut Context<V>) {
-        if self.restore_focus {
-            self.previous_focus = cx.focused();
+    pub fn initialize(&mut self, window: &mut Window, cx: &App) {
+        if self.restore_focus && !self.active {
+            self.previous_focus = window.focused(cx);
+        }
+        self.active = true;
+        if self.auto_focus {
+            match &self.initial_focus {
+                Some(handle) => window.focus(handle),
+                None => self.focus_first(window),
+            }
         }
     }
 
@@ -115,12 +220,13 @@ impl FocusTrap {
     /// ## Example
     ///
     /// ```rust,ignore
-    /// trap.cleanup(cx);
+    /// trap.cleanup(window);
     /// ```
-    pub fn cleanup<V>(&self, cx: &mut Context<V>) {
-        if self.restore_focus {
-            if let Some(ref handle) = self.previous_focus {
-                cx.focus(handle);
+    pub fn cleanup(&mut self, window: &mut Window) {
+        self.active = false;
+        if let Some(handle) = self.previous_focus.take() {
+            if self.restore_focus {
+                window.focus(&handle);
             }
         }
     }
@@ -128,36 +234,37 @@ impl FocusTrap {
     /// Handle keyboard events to trap focus within boundary.
     ///
     /// This method intercepts Tab and Shift+Tab events to cycle focus
-    /// within the trapped boundary.
+    /// through the registered elements. Returns whether the event was
+    /// handled; the caller should then stop its propagation. Inactive
+    /// traps, and Tab with Ctrl, Alt or Cmd held, leave the event alone.
     ///
     /// ## Example
     ///
     /// ```rust,ignore
     /// div()
-    ///     .on_key_down(move |event, window, cx| {
-    ///         trap.handle_key_event(event, cx);
-    ///     })
+    ///     .on_key_down(cx.listener(|this, event, window, cx| {
+    ///         if this.trap.handle_key_event(event, window) {
+    ///             cx.stop_propagation();
+    ///         }
+    ///     }))
     /// ```
-    pub fn handle_key_event<V>(
-        &self,
-        event: &KeyDownEvent,
-        _cx: &mut Context<V>,
-    ) -> bool {
-        // Check if Tab key was pressed
-        if event.keystroke.key == "tab" {
-            // In a full implementation, this would:
-            // 1. Get all focusable elements within the boundary
-            // 2. Determine current focus position
-            // 3. Move to next/previous based on Shift modifier
-            // 4. Wrap around at boundaries
-
-            // For now, return true to indicate we handled the event
-            // The actual focus cycling logic would need to query the
-            // DOM-like structure in GPUI for focusable elements
-            return true;
+    pub fn handle_key_event(&self, event: &KeyDownEvent, window: &mut Window) -> bool {
+        let modifiers = &event.keystroke.modifiers;
+        if !self.active
+            || event.keystroke.key != "tab"
+            || modifiers.control
+            || modifiers.alt
+            || modifiers.platform
+            || self.handles.is_empty()
+        {
+            return false;
         }
 
-        false // Event not handled
+        let current = self.handles.iter().position(|handle| handle.is_focused(window));
+        if let Some(next) = cycle_index(current, self.handles.len(), event.keystroke.modifiers.shift) {
+            window.focus(&self.handles[next]);
+        }
+        true
     }
 
     /// Focus the first focusable element in the trap boundary.
@@ -165,11 +272,12 @@ impl FocusTrap {
     /// ## Example
     ///
     /// ```rust,ignore
-    /// trap.focus_first(cx);
+    /// trap.focus_first(window);
     /// ```
-    pub fn focus_first<V>(&self, _cx: &mut Context<V>) {
-        // Implementation would query for first focusable element
-        // and call cx.focus() on it
+    pub fn focus_first(&self, window: &mut Window) {
+        if let Some(handle) = self.handles.first() {
+            window.focus(handle);
+        }
     }
 
     /// Focus the last focusable element in the trap boundary.
@@ -177,11 +285,12 @@ impl FocusTrap {
     /// ## Example
     ///
     /// ```rust,ignore
-    /// trap.focus_last(cx);
+    /// trap.focus_last(window);
     /// ```
-    pub fn focus_last<V>(&self, _cx: &mut Context<V>) {
-        // Implementation would query for last focusable element
-        // and call cx.focus() on it
+    pub fn focus_last(&self, window: &mut Window) {
+        if let Some(handle) = self.handles.last() {
+            window.focus(handle);
+        }
     }
 }
 
@@ -200,6 +309,7 @@ mod tests {
         let trap = FocusTrap::new();
         assert!(trap.auto_focus);
         assert!(trap.restore_focus);
+        assert!(!trap.is_active());
     }
 
     #[test]
@@ -211,4 +321,14 @@ mod tests {
         assert!(!trap.auto_focus);
         assert!(!trap.restore_focus);
     }
+
+    #[test]
+    fn test_tab_cycles_and_wraps() {
+        assert_eq!(cycle_index(Some(0), 3, false), Some(1));
+        assert_eq!(cycle_index(Some(2), 3, false), Some(0));
+        assert_eq!(cycle_index(Some(0), 3, true), Some(2));
+        assert_eq!(cycle_index(None, 3, false), Some(0));
+        assert_eq!(cycle_index(None, 3, true), Some(2));
+        assert_eq!(cycle_index(None, 0, false), None);
+    }
 }
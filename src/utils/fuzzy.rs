@@ -0,0 +1,202 @@
+//! Fuzzy subsequence matching for search-as-you-type lists.
+//!
+//! A query matches a candidate when every query character appears in the
+//! candidate in order, ignoring case. Matches are scored the way editor
+//! pickers rank them: characters at the start of the candidate or of a word
+//! and runs of consecutive characters score higher, while skipped characters
+//! cost a little. The matched character positions are returned so the caller
+//! can highlight them.
+
+/// Score awarded for every matched character
+const SCORE_MATCH: i32 = 16;
+/// Bonus when the match is the first character of the candidate
+const BONUS_FIRST_CHAR: i32 = 24;
+/// Bonus when the match starts a word (after a separator or a camelCase hump)
+const BONUS_WORD_START: i32 = 20;
+/// Bonus when the match directly follows the previous matched character
+const BONUS_CONSECUTIVE: i32 = 16;
+/// Penalty for each candidate character skipped between two matches
+const PENALTY_GAP: i32 = 1;
+/// Penalty for each candidate character skipped before the first match
+const PENALTY_LEADING: i32 = 1;
+/// Cap on the leading penalty so late-starting matches still rank
+const MAX_LEADING_PENALTY: i32 = 8;
+
+/// Result of a successful [`fuzzy_match`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// Match quality; higher is better
+    pub score: i32,
+    /// Character indices (not byte offsets) of the matched characters, ascending
+    pub positions: Vec<usize>,
+}
+
+/// Lowercase a character, keeping it a single character
+fn fold(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+/// Whether `c` separates words
+fn is_separator(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '_' | '-' | '.' | '/' | ':' | '\\')
+}
+
+/// Bonus for matching the character at `index`, based on what precedes it
+fn position_bonus(chars: &[char], index: usize) -> i32 {
+    if index == 0 {
+        return BONUS_FIRST_CHAR;
+    }
+    let prev = chars[index - 1];
+    let current = chars[index];
+    if is_separator(prev) || (prev.is_lowercase() && current.is_uppercase()) {
+        BONUS_WORD_START
+    } else {
+        0
+    }
+}
+
+/// Fuzzy match `query` against `candidate`
+///
+/// Returns `None` unless every character of `query` occurs in `candidate` in
+/// order (case-insensitively). When several alignments are possible the
+/// highest-scoring one is chosen. An empty query matches everything with a
+/// score of zero and no positions.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::utils::fuzzy_match;
+///
+/// let m = fuzzy_match("Open Recent File", "orf").unwrap();
+/// assert_eq!(m.positions, vec![0, 5, 12]);
+/// assert!(fuzzy_match("Save", "xyz").is_none());
+/// ```
+#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+pub fn fuzzy_match(candidate: &str, query: &str) -> Option<FuzzyMatch> {
+    let query: Vec<char> = query.chars().filter(|c| !c.is_whitespace()).map(fold).collect();
+    if query.is_empty() {
+        return Some(FuzzyMatch { score: 0, positions: Vec::new() });
+    }
+
+    let chars: Vec<char> = candidate.chars().collect();
+    let folded: Vec<char> = chars.iter().copied().map(fold).collect();
+    let (n, m) = (chars.len(), query.len());
+    if m > n {
+        return None;
+    }
+
+    // best[i][j]: best score matching query[..=i] with query[i] at chars[j];
+    // from[i][j]: where query[i - 1] was matched on that best path
+    let mut best = vec![vec![None::<i32>; n]; m];
+    let mut from = vec![vec![0usize; n]; m];
+
+    for j in 0..n {
+        if folded[j] == query[0] {
+            let leading = (j as i32 * PENALTY_LEADING).min(MAX_LEADING_PENALTY);
+            best[0][j] = Some(SCORE_MATCH + position_bonus(&chars, j) - leading);
+        }
+    }
+
+    for i in 1..m {
+        for j in i..n {
+            if folded[j] != query[i] {
+                continue;
+            }
+            let bonus = SCORE_MATCH + position_bonus(&chars, j);
+            let mut cell: Option<(i32, usize)> = None;
+            for (k, prev) in best[i - 1].iter().enumerate().take(j).skip(i - 1) {
+                let Some(prev) = *prev else { continue };
+                let link = if k + 1 == j {
+                    BONUS_CONSECUTIVE
+                } else {
+                    -((j - k - 1) as i32) * PENALTY_GAP
+                };
+                let score = prev + bonus + link;
+                if cell.is_none_or(|(s, _)| score > s) {
+                    cell = Some((score, k));
+                }
+            }
+            if let Some((score, k)) = cell {
+                best[i][j] = Some(score);
+                from[i][j] = k;
+            }
+        }
+    }
+
+    // Pick the best end position, preferring the earliest on ties
+    let (mut j, score) = best[m - 1]
+        .iter()
+        .enumerate()
+        .filter_map(|(j, score)| score.map(|s| (j, s)))
+        .fold(None, |acc: Option<(usize, i32)>, (j, s)| match acc {
+            Some((_, best)) if best >= s => acc,
+            _ => Some((j, s)),
+        })?;
+
+    let mut positions = vec![0; m];
+    for i in (0..m).rev() {
+        positions[i] = j;
+        if i > 0 {
+            j = from[i][j];
+        }
+    }
+
+    Some(FuzzyMatch { score, positions })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_query_matches() {
+        let m = fuzzy_match("Open File", "").unwrap();
+        assert_eq!(m.score, 0);
+        assert!(m.positions.is_empty());
+    }
+
+    #[test]
+    fn test_requires_ordered_subsequence() {
+        assert!(fuzzy_match("Open File", "of").is_some());
+        assert!(fuzzy_match("Open File", "fo").is_none());
+        assert!(fuzzy_match("Save", "saves").is_none());
+    }
+
+    #[test]
+    fn test_case_insensitive() {
+        let m = fuzzy_match("Open File", "OPEN").unwrap();
+        assert_eq!(m.positions, vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_prefers_word_starts() {
+        let m = fuzzy_match("Open Recent File", "orf").unwrap();
+        assert_eq!(m.positions, vec![0, 5, 12]);
+
+        let m = fuzzy_match("git_status_bar", "sb").unwrap();
+        assert_eq!(m.positions, vec![4, 11]);
+
+        let m = fuzzy_match("goToLine", "tl").unwrap();
+        assert_eq!(m.positions, vec![2, 4]);
+    }
+
+    #[test]
+    fn test_ranking() {
+        let score = |candidate| fuzzy_match(candidate, "of").unwrap().score;
+
+        // Word-start matches beat matches buried inside words
+        assert!(score("Open File") > score("Toggle Soft Wrap"));
+        // Consecutive matches beat scattered ones
+        let consecutive = fuzzy_match("Format Document", "form").unwrap().score;
+        let scattered = fuzzy_match("Fold Other Regions Marked", "form").unwrap().score;
+        assert!(consecutive > scattered);
+        // Earlier matches beat later ones
+        assert!(fuzzy_match("Close", "clo").unwrap().score > fuzzy_match("Reclose", "clo").unwrap().score);
+    }
+
+    #[test]
+    fn test_positions_are_char_indices() {
+        let m = fuzzy_match("Ünïcode Ärger", "ä").unwrap();
+        assert_eq!(m.positions, vec![8]);
+    }
+}
@@ -6,7 +6,14 @@
 //! ## Available Utilities
 //!
 //! - [`FocusTrap`]: Manages focus within a boundary (dialogs, modals)
-//! - [`Announcer`]: Communicates updates to screen readers via live regions
+//! - [`Announcer`]: Communicates updates to screen readers through a shared [`LiveRegion`]
+//! - [`contrast`]: WCAG contrast ratio checks for colors and themes
+//! - [`fuzzy_match`]: Fuzzy subsequence matching for search-as-you-type lists
+//! - [`AnchoredState`]: Positions overlays against their trigger, flipping near window edges
+//! - [`Breakpoint`] and [`Responsive`]: Values that vary with window or container width
+//! - [`motion`]: Easings, enter/exit transitions and the reduced-motion preference
+//! - [`RovingFocus`]: Arrow-key, Home and End navigation through composite widgets
+//! - [`ShortcutRegistry`]: Keyboard shortcuts with platform-aware modifiers and conflict detection
 //!
 //! ## Example
 //!
@@ -14,16 +21,36 @@
 //! use purdah_gpui_components::utils::*;
 //!
 //! // Trap focus within a dialog
-//! FocusTrap::new()
-//!     .boundary_element(dialog_element)
-//!     .restore_on_unmount(true);
+//! let mut trap = FocusTrap::new().restore_on_unmount(true);
+//! trap.set_handles(vec![cancel_handle, confirm_handle]);
+//! trap.initialize(window, cx);
 //!
 //! // Announce a status update
-//! Announcer::polite("Form saved successfully");
+//! Announcer::polite("Form saved successfully").announce(cx);
+//!
+//! // Verify text contrast
+//! assert!(contrast::meets_wcag_aa(text_color, background_color));
 //! ```
 
 pub mod focus_trap;
 pub mod announcer;
+pub mod contrast;
+pub mod fuzzy;
+pub mod anchored;
+pub mod breakpoint;
+pub mod motion;
+pub mod roving_focus;
+pub mod shortcuts;
 
 pub use focus_trap::FocusTrap;
-pub use announcer::{Announcer, AnnouncerPriority};
+pub use announcer::{announce_assertive, announce_polite, Announcer, AnnouncerPriority, LiveRegion};
+pub use contrast::{contrast_ratio, meets_wcag_aa, meets_wcag_aaa, ContrastLevel};
+pub use fuzzy::{fuzzy_match, FuzzyMatch};
+pub use anchored::{anchored_position, AnchorOptions, AnchoredPosition, AnchoredState, Placement, PlacementAlign};
+pub use breakpoint::{use_breakpoint, Breakpoint, Responsive};
+pub use motion::{reduced_motion, set_reduced_motion, Easing, SlideFrom, Transition};
+pub use roving_focus::{RovingFocus, RovingOrientation};
+pub use shortcuts::{
+    keybinding_labels, platform_keystrokes, register_shortcut, shortcut_keystrokes, KeyLabelStyle,
+    Shortcut, ShortcutConflict, ShortcutRegistry,
+};
@@ -0,0 +1,260 @@
+//! Motion helpers: easings, enter/exit transitions and reduced motion.
+//!
+//! Durations are design tokens (`duration_fast`, `duration_normal` and
+//! `duration_slow` in [`GlobalTokens`](crate::theme::GlobalTokens)).
+//! Components build their animations with [`animation`] and describe
+//! movement with a [`Transition`], so a single [`set_reduced_motion`] call
+//! turns slides into plain fades everywhere, as WCAG 2.3.3 asks.
+
+use std::time::Duration;
+
+use gpui::*;
+
+/// Easing curves for transitions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Easing {
+    /// Constant speed
+    Linear,
+    /// Speeds up then slows down; for elements moving between two resting places
+    #[default]
+    Standard,
+    /// Starts fast and settles; for elements entering the screen
+    Decelerate,
+    /// Starts slow and speeds away; for elements leaving the screen
+    Accelerate,
+}
+
+impl Easing {
+    /// Eased progress for linear progress `t` in 0.0-1.0
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::Standard => ease_in_out(t),
+            Easing::Decelerate => 1.0 - (1.0 - t).powi(3),
+            Easing::Accelerate => t.powi(3),
+        }
+    }
+}
+
+/// Edge a sliding element enters from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlideFrom {
+    /// Slide down from above
+    Top,
+    /// Slide up from below
+    Bottom,
+    /// Slide in from the left
+    Left,
+    /// Slide in from the right
+    Right,
+}
+
+/// How an element enters and leaves
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Transition {
+    /// Fade opacity in and out
+    Fade,
+    /// Fade while sliding `distance` from an edge
+    Slide {
+        /// Edge the element enters from
+        from: SlideFrom,
+        /// Distance travelled
+        distance: Pixels,
+    },
+    /// Fade while growing from `from` times the element's size
+    ///
+    /// GPUI only transforms SVGs, so elements scale through [`scale_svg`];
+    /// other elements fade.
+    Scale {
+        /// Starting scale, such as 0.95
+        from: f32,
+    },
+}
+
+impl Transition {
+    /// The transition to use when motion is reduced: movement becomes a fade
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let transition = Transition::Slide { from: SlideFrom::Right, distance: px(24.0) }
+    ///     .reduced(reduced_motion(cx));
+    /// ```
+    pub fn reduced(self, reduced: bool) -> Self {
+        if reduced { Transition::Fade } else { self }
+    }
+
+    /// Style `element` at `shown` of the way from hidden (0.0) to at rest (1.0)
+    ///
+    /// Slides offset the element with margins, so they suit elements in
+    /// normal flow or positioned from the top left.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// panel.with_animation("panel", animation, move |panel, delta| transition.apply(panel, delta));
+    /// ```
+    pub fn apply<E: Styled>(self, element: E, shown: f32) -> E {
+        let element = element.opacity(shown);
+        let Transition::Slide { from, distance } = self else {
+            return element;
+        };
+        let offset = distance * (1.0 - shown);
+        match from {
+            SlideFrom::Top => element.mt(-offset),
+            SlideFrom::Bottom => element.mt(offset),
+            SlideFrom::Left => element.ml(-offset),
+            SlideFrom::Right => element.ml(offset),
+        }
+    }
+
+    /// Scale factor at `shown` of the way from hidden to at rest
+    pub fn scale_at(self, shown: f32) -> f32 {
+        match self {
+            Transition::Scale { from } => from + (1.0 - from) * shown,
+            Transition::Fade | Transition::Slide { .. } => 1.0,
+        }
+    }
+}
+
+/// Whether the user asked for reduced motion
+#[derive(Default)]
+struct MotionSettings {
+    reduced: bool,
+}
+
+impl Global for MotionSettings {}
+
+/// Whether motion is reduced; off unless [`set_reduced_motion`] turned it on
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// let offset = if reduced_motion(cx) { px(0.0) } else { slide_offset };
+/// ```
+pub fn reduced_motion(cx: &App) -> bool {
+    cx.try_global::<MotionSettings>().is_some_and(|settings| settings.reduced)
+}
+
+/// Turn reduced motion on or off for every component and re-render all windows
+///
+/// Call this from the application's accessibility settings.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// set_reduced_motion(settings.reduce_motion, cx);
+/// ```
+pub fn set_reduced_motion(reduced: bool, cx: &mut App) {
+    cx.set_global(MotionSettings { reduced });
+    cx.refresh_windows();
+}
+
+/// An animation of `duration` following `easing`
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// let animation = animation(theme.global.duration_normal, Easing::Decelerate);
+/// ```
+pub fn animation(duration: Duration, easing: Easing) -> Animation {
+    Animation::new(duration).with_easing(move |t| easing.apply(t))
+}
+
+/// Animate `element` in when `entering`, or out otherwise
+///
+/// `id` must change for the animation to restart, so include a counter
+/// that increases on every open and close. The transition is reduced to a
+/// fade when motion is reduced.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// transition(
+///     panel,
+///     ("panel", self.transition),
+///     Transition::Slide { from: SlideFrom::Top, distance: px(8.0) },
+///     self.open,
+///     animation(theme.global.duration_normal, Easing::Decelerate),
+///     cx,
+/// )
+/// ```
+pub fn transition<E: Styled + IntoElement + 'static>(
+    element: E,
+    id: impl Into<ElementId>,
+    transition: Transition,
+    entering: bool,
+    animation: Animation,
+    cx: &App,
+) -> AnimationElement<E> {
+    let transition = transition.reduced(reduced_motion(cx));
+    element.with_animation(id, animation, move |element, delta| {
+        let shown = if entering { delta } else { 1.0 - delta };
+        transition.apply(element, shown)
+    })
+}
+
+/// Animate an SVG in or out with a [`Transition::Scale`]
+///
+/// Other transitions, and any transition while motion is reduced, fade the SVG.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// scale_svg(svg().path(icons::CHECK), "check-pop", Transition::Scale { from: 0.5 }, true, anim, cx)
+/// ```
+pub fn scale_svg(
+    svg: Svg,
+    id: impl Into<ElementId>,
+    transition: Transition,
+    entering: bool,
+    animation: Animation,
+    cx: &App,
+) -> AnimationElement<Svg> {
+    let transition = transition.reduced(reduced_motion(cx));
+    svg.with_animation(id, animation, move |svg, delta| {
+        let shown = if entering { delta } else { 1.0 - delta };
+        let scale = transition.scale_at(shown);
+        transition
+            .apply(svg, shown)
+            .with_transformation(Transformation::scale(size(scale, scale)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-5, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn test_easings_start_and_end_at_rest() {
+        for easing in [Easing::Linear, Easing::Standard, Easing::Decelerate, Easing::Accelerate] {
+            assert_close(easing.apply(0.0), 0.0);
+            assert_close(easing.apply(1.0), 1.0);
+        }
+        // Decelerating covers most of the distance early; accelerating late
+        assert!(Easing::Decelerate.apply(0.5) > 0.5);
+        assert!(Easing::Accelerate.apply(0.5) < 0.5);
+    }
+
+    #[test]
+    fn test_reduced_transition_fades() {
+        let slide = Transition::Slide { from: SlideFrom::Left, distance: px(24.0) };
+        assert_eq!(slide.reduced(false), slide);
+        assert_eq!(slide.reduced(true), Transition::Fade);
+        assert_eq!(Transition::Scale { from: 0.9 }.reduced(true), Transition::Fade);
+    }
+
+    #[test]
+    fn test_scale_at() {
+        let scale = Transition::Scale { from: 0.5 };
+        assert_close(scale.scale_at(0.0), 0.5);
+        assert_close(scale.scale_at(0.5), 0.75);
+        assert_close(scale.scale_at(1.0), 1.0);
+        assert_close(Transition::Fade.scale_at(0.0), 1.0);
+    }
+}
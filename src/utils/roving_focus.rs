@@ -0,0 +1,246 @@
+//! Roving focus: arrow-key navigation through a set of items.
+//!
+//! Composite widgets such as tab lists, radio groups and menus are a single
+//! Tab stop; once inside, the arrow keys move an active item through their
+//! children. In GPUI the widget owns one focus handle and tracks the
+//! active item itself, showing the focus ring on that item only.
+//! [`RovingFocus`] decides where each key moves the active item.
+
+/// Arrow keys that move the active item
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RovingOrientation {
+    /// Left and Right, for items laid out in a row
+    Horizontal,
+    /// Up and Down, for items laid out in a column
+    #[default]
+    Vertical,
+    /// All four arrow keys, as radio groups expect
+    Both,
+}
+
+/// Keyboard navigation policy for a set of items.
+///
+/// Arrow keys step to the next or previous enabled item, skipping disabled
+/// ones, and Home/End jump to the first/last enabled item. At the ends the
+/// active item wraps around unless wrapping is turned off. In right-to-left
+/// layouts Left moves forward and Right backward.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::utils::*;
+///
+/// let roving = RovingFocus::new(RovingOrientation::Horizontal).rtl(is_rtl);
+///
+/// // In the widget's key handler
+/// let key = event.keystroke.key.as_str();
+/// if let Some(next) = roving.target(key, self.active, self.items.len(), |i| !self.items[i].disabled) {
+///     self.active = Some(next);
+///     cx.stop_propagation();
+/// }
+/// ```
+///
+/// ## Accessibility
+///
+/// Implements the roving tabindex keyboard pattern of the WAI-ARIA
+/// Authoring Practices for tabs, radio groups, menus, listboxes and grids.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RovingFocus {
+    orientation: RovingOrientation,
+    wrap: bool,
+    rtl: bool,
+}
+
+impl RovingFocus {
+    /// Create a wrapping, left-to-right policy for `orientation`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let roving = RovingFocus::new(RovingOrientation::Vertical);
+    /// ```
+    pub fn new(orientation: RovingOrientation) -> Self {
+        Self {
+            orientation,
+            wrap: true,
+            rtl: false,
+        }
+    }
+
+    /// Set whether stepping past the last item returns to the first
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// RovingFocus::new(RovingOrientation::Vertical).wrap(false);
+    /// ```
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Set whether items run right to left, swapping Left and Right
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// RovingFocus::new(RovingOrientation::Horizontal).rtl(true);
+    /// ```
+    pub fn rtl(mut self, rtl: bool) -> Self {
+        self.rtl = rtl;
+        self
+    }
+
+    /// The arrow keys this policy responds to
+    pub fn orientation(&self) -> RovingOrientation {
+        self.orientation
+    }
+
+    /// Item `key` moves the active item to among `len` items
+    ///
+    /// Returns `None` when `key` is not a navigation key for this policy or
+    /// no item is enabled. Without wrapping, stepping past an end keeps the
+    /// active item.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let next = roving.target("down", Some(2), options.len(), |i| !options[i].disabled);
+    /// ```
+    pub fn target(
+        &self,
+        key: &str,
+        active: Option<usize>,
+        len: usize,
+        enabled: impl Fn(usize) -> bool,
+    ) -> Option<usize> {
+        use RovingOrientation::{Both, Horizontal, Vertical};
+
+        let forward = match (key, self.orientation) {
+            ("home", _) => return Self::first(len, enabled),
+            ("end", _) => return Self::last(len, enabled),
+            ("down", Vertical | Both) => true,
+            ("up", Vertical | Both) => false,
+            ("right", Horizontal | Both) => !self.rtl,
+            ("left", Horizontal | Both) => self.rtl,
+            _ => return None,
+        };
+        self.step(active, forward, len, enabled)
+    }
+
+    /// Next enabled item after `from`, or before it when not `forward`
+    ///
+    /// With no active item, stepping forward starts at the first item and
+    /// backward at the last.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let next = roving.step(self.highlighted, true, options.len(), |i| self.is_enabled(i));
+    /// ```
+    pub fn step(
+        &self,
+        from: Option<usize>,
+        forward: bool,
+        len: usize,
+        enabled: impl Fn(usize) -> bool,
+    ) -> Option<usize> {
+        let from = from.filter(|&index| index < len);
+        let mut index = from;
+        for _ in 0..len {
+            let next = match (index, forward) {
+                (None, true) => 0,
+                (None, false) => len - 1,
+                (Some(i), true) if i + 1 < len => i + 1,
+                (Some(i), false) if i > 0 => i - 1,
+                // At an end: wrap around, or stay on the active item
+                (Some(_), true) if self.wrap => 0,
+                (Some(_), false) if self.wrap => len - 1,
+                (Some(_), _) => return from,
+            };
+            if enabled(next) {
+                return Some(next);
+            }
+            index = Some(next);
+        }
+        from
+    }
+
+    /// First enabled item among `len` items
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let initial = RovingFocus::first(tabs.len(), |i| !tabs[i].disabled);
+    /// ```
+    pub fn first(len: usize, enabled: impl Fn(usize) -> bool) -> Option<usize> {
+        (0..len).find(|&index| enabled(index))
+    }
+
+    /// Last enabled item among `len` items
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let last = RovingFocus::last(tabs.len(), |i| !tabs[i].disabled);
+    /// ```
+    pub fn last(len: usize, enabled: impl Fn(usize) -> bool) -> Option<usize> {
+        (0..len).rev().find(|&index| enabled(index))
+    }
+}
+
+impl Default for RovingFocus {
+    fn default() -> Self {
+        Self::new(RovingOrientation::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arrows_follow_orientation() {
+        let vertical = RovingFocus::new(RovingOrientation::Vertical);
+        assert_eq!(vertical.target("down", Some(0), 3, |_| true), Some(1));
+        assert_eq!(vertical.target("up", Some(1), 3, |_| true), Some(0));
+        assert_eq!(vertical.target("right", Some(0), 3, |_| true), None);
+
+        let horizontal = RovingFocus::new(RovingOrientation::Horizontal);
+        assert_eq!(horizontal.target("right", Some(0), 3, |_| true), Some(1));
+        assert_eq!(horizontal.target("down", Some(0), 3, |_| true), None);
+
+        let both = RovingFocus::new(RovingOrientation::Both);
+        assert_eq!(both.target("left", Some(1), 3, |_| true), Some(0));
+        assert_eq!(both.target("down", Some(1), 3, |_| true), Some(2));
+    }
+
+    #[test]
+    fn test_wraps_and_skips_disabled() {
+        let roving = RovingFocus::new(RovingOrientation::Vertical);
+        let enabled = |i| i != 1;
+        assert_eq!(roving.target("down", Some(0), 4, enabled), Some(2));
+        assert_eq!(roving.target("down", Some(3), 4, enabled), Some(0));
+        assert_eq!(roving.target("up", Some(0), 4, enabled), Some(3));
+        assert_eq!(roving.target("up", None, 4, enabled), Some(3));
+        assert_eq!(roving.target("home", Some(3), 4, |i| i > 0), Some(1));
+        assert_eq!(roving.target("end", None, 4, |i| i < 3), Some(2));
+        assert_eq!(roving.target("down", None, 0, |_| true), None);
+    }
+
+    #[test]
+    fn test_no_wrap_stays_at_ends() {
+        let roving = RovingFocus::new(RovingOrientation::Vertical).wrap(false);
+        assert_eq!(roving.target("down", Some(2), 3, |_| true), Some(2));
+        assert_eq!(roving.target("up", Some(0), 3, |_| true), Some(0));
+        // Disabled items at the end are skipped without wrapping
+        assert_eq!(roving.target("down", Some(0), 3, |i| i == 0), Some(0));
+    }
+
+    #[test]
+    fn test_rtl_swaps_left_and_right() {
+        let roving = RovingFocus::new(RovingOrientation::Horizontal).rtl(true);
+        assert_eq!(roving.target("left", Some(0), 3, |_| true), Some(1));
+        assert_eq!(roving.target("right", Some(0), 3, |_| true), Some(2));
+    }
+}
@@ -0,0 +1,494 @@
+//! Keyboard shortcut registry with platform-aware modifiers and conflict detection.
+//!
+//! Shortcuts are written in GPUI's keystroke syntax: modifiers and a key
+//! joined by `-` (`"cmd-shift-p"`), with chords separated by spaces
+//! (`"cmd-k cmd-s"`). The `secondary` modifier stands for Cmd on macOS and
+//! Ctrl elsewhere, so a single binding suits every platform.
+//!
+//! Registering a shortcut that another shortcut in the same key context
+//! already uses, or that starts with or is the start of another chord
+//! sequence, is refused with a [`ShortcutConflict`].
+
+use std::fmt;
+
+use gpui::{Action, App, Global, KeyBinding, SharedString};
+
+/// Modifiers in the order they are written and displayed
+const MODIFIER_ORDER: [&str; 5] = ["ctrl", "alt", "shift", "cmd", "fn"];
+
+/// The modifier `secondary` stands for on this platform
+#[cfg(target_os = "macos")]
+const SECONDARY: &str = "cmd";
+/// The modifier `secondary` stands for on this platform
+#[cfg(not(target_os = "macos"))]
+const SECONDARY: &str = "ctrl";
+
+/// Canonical name of a modifier, resolving aliases and `secondary`
+fn canonical_modifier(modifier: &str) -> String {
+    let modifier = modifier.to_lowercase();
+    match modifier.as_str() {
+        "secondary" => SECONDARY,
+        "control" => "ctrl",
+        "option" => "alt",
+        "platform" | "super" | "win" => "cmd",
+        "function" => "fn",
+        _ => return modifier,
+    }
+    .to_string()
+}
+
+/// Split one keystroke into its modifiers and key
+fn split_keystroke(keystroke: &str) -> (Vec<&str>, &str) {
+    // The minus key itself ends the keystroke with a second `-`
+    let (modifiers, key) = if keystroke == "-" || keystroke.ends_with("--") {
+        (keystroke.trim_end_matches('-'), "-")
+    } else {
+        keystroke.rsplit_once('-').unwrap_or(("", keystroke))
+    };
+    let modifiers = modifiers.split('-').filter(|modifier| !modifier.is_empty()).collect();
+    (modifiers, key)
+}
+
+/// Normalize a binding for this platform
+///
+/// Resolves `secondary` and modifier aliases, lowercases, orders the
+/// modifiers consistently and collapses whitespace, so equal bindings
+/// compare equal however they were written.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// // "ctrl-shift-p" on Linux and Windows, "shift-cmd-p" on macOS
+/// let binding = platform_keystrokes("secondary-shift-P");
+/// ```
+pub fn platform_keystrokes(binding: &str) -> SharedString {
+    binding
+        .split_whitespace()
+        .map(|keystroke| {
+            let (modifiers, key) = split_keystroke(keystroke);
+            let mut modifiers: Vec<String> = modifiers.into_iter().map(canonical_modifier).collect();
+            modifiers.sort_by_key(|modifier| {
+                MODIFIER_ORDER
+                    .iter()
+                    .position(|known| known == modifier)
+                    .unwrap_or(MODIFIER_ORDER.len())
+            });
+            modifiers.dedup();
+            modifiers.push(key.to_lowercase());
+            modifiers.join("-")
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+        .into()
+}
+
+/// How key caps label modifiers and special keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyLabelStyle {
+    /// Symbols such as ⌘, ⇧ and ↩, as macOS shows them
+    Symbols,
+    /// Words such as Ctrl, Shift and Enter
+    Text,
+}
+
+impl Default for KeyLabelStyle {
+    /// Symbols on macOS, words elsewhere
+    fn default() -> Self {
+        if cfg!(target_os = "macos") {
+            Self::Symbols
+        } else {
+            Self::Text
+        }
+    }
+}
+
+/// Display label for one part of a keystroke
+fn key_label(key: &str, style: KeyLabelStyle) -> SharedString {
+    let key = canonical_modifier(key);
+    let symbol = match key.as_str() {
+        "cmd" => Some("⌘"),
+        "ctrl" => Some("⌃"),
+        "alt" => Some("⌥"),
+        "shift" => Some("⇧"),
+        "enter" => Some("↩"),
+        "backspace" => Some("⌫"),
+        "tab" => Some("⇥"),
+        _ => None,
+    };
+    if let (KeyLabelStyle::Symbols, Some(symbol)) = (style, symbol) {
+        return symbol.into();
+    }
+
+    let label = match key.as_str() {
+        "cmd" => "Cmd",
+        "ctrl" => "Ctrl",
+        "alt" => "Alt",
+        "shift" => "Shift",
+        "fn" => "Fn",
+        "escape" => "Esc",
+        "up" => "↑",
+        "down" => "↓",
+        "left" => "←",
+        "right" => "→",
+        key if key.chars().count() == 1 => return key.to_uppercase().into(),
+        key => {
+            let mut chars = key.chars();
+            return chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+                .into();
+        }
+    };
+    label.into()
+}
+
+/// Split a binding into chords, each a list of key cap labels
+///
+/// `"cmd-k cmd-s"` becomes `[["Cmd", "K"], ["Cmd", "S"]]` in the text
+/// style and `[["⌘", "K"], ["⌘", "S"]]` with symbols.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// let chords = keybinding_labels("secondary-shift-p", KeyLabelStyle::default());
+/// ```
+pub fn keybinding_labels(binding: &str, style: KeyLabelStyle) -> Vec<Vec<SharedString>> {
+    binding
+        .split_whitespace()
+        .map(|keystroke| {
+            let (modifiers, key) = split_keystroke(keystroke);
+            modifiers
+                .into_iter()
+                .chain(std::iter::once(key))
+                .map(|part| key_label(part, style))
+                .collect()
+        })
+        .collect()
+}
+
+/// A named keyboard shortcut.
+///
+/// Shortcuts without a context are app-level and apply everywhere;
+/// component-level shortcuts name the GPUI key context they apply in.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::utils::*;
+///
+/// let save = Shortcut::new("file.save", "secondary-s").description("Save the file");
+/// let rename = Shortcut::new("tree.rename", "f2").context("TreeView");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shortcut {
+    /// Stable identifier, used to look the shortcut up
+    pub id: SharedString,
+    /// Keystrokes, normalized for this platform
+    pub keystrokes: SharedString,
+    /// Key context the shortcut applies in, `None` for app-level
+    pub context: Option<SharedString>,
+    /// What the shortcut does, for help screens
+    pub description: Option<SharedString>,
+}
+
+impl Shortcut {
+    /// Create an app-level shortcut
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let shortcut = Shortcut::new("palette.toggle", "secondary-k");
+    /// ```
+    pub fn new(id: impl Into<SharedString>, keystrokes: &str) -> Self {
+        Self {
+            id: id.into(),
+            keystrokes: platform_keystrokes(keystrokes),
+            context: None,
+            description: None,
+        }
+    }
+
+    /// Limit the shortcut to a GPUI key context
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Shortcut::new("editor.duplicate", "secondary-d").context("Editor");
+    /// ```
+    pub fn context(mut self, context: impl Into<SharedString>) -> Self {
+        self.context = Some(context.into());
+        self
+    }
+
+    /// Describe what the shortcut does
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// Shortcut::new("file.save", "secondary-s").description("Save the file");
+    /// ```
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// GPUI key binding dispatching `action` for this shortcut
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// cx.bind_keys([shortcut.key_binding(SaveFile)]);
+    /// ```
+    pub fn key_binding<A: Action>(&self, action: A) -> KeyBinding {
+        KeyBinding::new(&self.keystrokes, action, self.context.as_deref())
+    }
+
+    /// Whether both shortcuts would fight over the same keystrokes
+    ///
+    /// They conflict in the same context when one's chords equal or begin
+    /// the other's: after `cmd-k`, `cmd-k cmd-s` could never be typed.
+    fn conflicts_with(&self, other: &Shortcut) -> bool {
+        if self.context != other.context {
+            return false;
+        }
+        let ours: Vec<&str> = self.keystrokes.split(' ').collect();
+        let theirs: Vec<&str> = other.keystrokes.split(' ').collect();
+        let shared = ours.len().min(theirs.len());
+        ours[..shared] == theirs[..shared]
+    }
+}
+
+/// A shortcut refused because its keystrokes are already taken.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortcutConflict {
+    /// Id of the shortcut already registered
+    pub existing: SharedString,
+    /// Id of the shortcut that was refused
+    pub rejected: SharedString,
+    /// Keystrokes of the refused shortcut
+    pub keystrokes: SharedString,
+}
+
+impl fmt::Display for ShortcutConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "shortcut `{}` ({}) conflicts with `{}`",
+            self.rejected, self.keystrokes, self.existing
+        )
+    }
+}
+
+impl std::error::Error for ShortcutConflict {}
+
+/// Registry of the application's keyboard shortcuts.
+///
+/// The registry records which shortcut owns which keystrokes so conflicts
+/// are caught at registration, and lets menus, command palettes and help
+/// screens show the current binding of a shortcut by id. Use
+/// [`register_shortcut`] to record a shortcut in the application-wide
+/// registry and bind it to an action in one step.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// use purdah_gpui_components::utils::*;
+///
+/// let mut registry = ShortcutRegistry::new();
+/// registry.register(Shortcut::new("file.save", "secondary-s"))?;
+///
+/// // Refused: the first chord is already Save
+/// assert!(registry.register(Shortcut::new("file.save_as", "secondary-s secondary-a")).is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ShortcutRegistry {
+    shortcuts: Vec<Shortcut>,
+}
+
+impl Global for ShortcutRegistry {}
+
+impl ShortcutRegistry {
+    /// Create an empty registry
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let registry = ShortcutRegistry::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a shortcut, refusing it when it conflicts with another
+    ///
+    /// Registering an id again replaces its earlier shortcut, so bindings
+    /// can be changed at runtime.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// if let Err(conflict) = registry.register(shortcut) {
+    ///     eprintln!("{conflict}");
+    /// }
+    /// ```
+    pub fn register(&mut self, shortcut: Shortcut) -> Result<(), ShortcutConflict> {
+        if let Some(existing) = self
+            .shortcuts
+            .iter()
+            .find(|existing| existing.id != shortcut.id && existing.conflicts_with(&shortcut))
+        {
+            return Err(ShortcutConflict {
+                existing: existing.id.clone(),
+                rejected: shortcut.id,
+                keystrokes: shortcut.keystrokes,
+            });
+        }
+        self.shortcuts.retain(|existing| existing.id != shortcut.id);
+        self.shortcuts.push(shortcut);
+        Ok(())
+    }
+
+    /// Remove the shortcut with `id`, returning it
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// registry.unregister("file.save");
+    /// ```
+    pub fn unregister(&mut self, id: &str) -> Option<Shortcut> {
+        let index = self.shortcuts.iter().position(|shortcut| shortcut.id.as_ref() == id)?;
+        Some(self.shortcuts.remove(index))
+    }
+
+    /// The shortcut with `id`
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let keys = registry.get("file.save").map(|shortcut| shortcut.keystrokes.clone());
+    /// ```
+    pub fn get(&self, id: &str) -> Option<&Shortcut> {
+        self.shortcuts.iter().find(|shortcut| shortcut.id.as_ref() == id)
+    }
+
+    /// Every registered shortcut, in registration order
+    pub fn shortcuts(&self) -> &[Shortcut] {
+        &self.shortcuts
+    }
+
+    /// The application-wide registry, if any shortcut was registered
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let count = ShortcutRegistry::global(cx).map_or(0, |registry| registry.shortcuts().len());
+    /// ```
+    pub fn global(cx: &App) -> Option<&Self> {
+        cx.try_global::<Self>()
+    }
+}
+
+/// Register `shortcut` application-wide and bind it to `action`
+///
+/// Nothing is bound when the shortcut conflicts with one already
+/// registered.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// register_shortcut(
+///     Shortcut::new("file.save", "secondary-s").description("Save the file"),
+///     SaveFile,
+///     cx,
+/// )?;
+/// ```
+pub fn register_shortcut<A: Action>(
+    shortcut: Shortcut,
+    action: A,
+    cx: &mut App,
+) -> Result<(), ShortcutConflict> {
+    let binding = shortcut.key_binding(action);
+    cx.default_global::<ShortcutRegistry>().register(shortcut)?;
+    cx.bind_keys([binding]);
+    Ok(())
+}
+
+/// Keystrokes of the application-wide shortcut with `id`
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// let hint = shortcut_keystrokes("file.save", cx).map(KeybindingHint::new);
+/// ```
+pub fn shortcut_keystrokes(id: &str, cx: &App) -> Option<SharedString> {
+    ShortcutRegistry::global(cx)?.get(id).map(|shortcut| shortcut.keystrokes.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(binding: &str, style: KeyLabelStyle) -> Vec<Vec<String>> {
+        keybinding_labels(binding, style)
+            .iter()
+            .map(|chord| chord.iter().map(ToString::to_string).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_keybinding_labels() {
+        let keys = |binding| labels(binding, KeyLabelStyle::Text);
+        assert_eq!(keys("cmd-shift-p"), vec![vec!["Cmd", "Shift", "P"]]);
+        assert_eq!(keys("ctrl-k ctrl-s"), vec![vec!["Ctrl", "K"], vec!["Ctrl", "S"]]);
+        assert_eq!(keys("alt-enter"), vec![vec!["Alt", "Enter"]]);
+        assert_eq!(keys("cmd--"), vec![vec!["Cmd", "-"]]);
+        assert_eq!(keys("escape"), vec![vec!["Esc"]]);
+    }
+
+    #[test]
+    fn test_keybinding_symbols() {
+        let keys = |binding| labels(binding, KeyLabelStyle::Symbols);
+        assert_eq!(keys("cmd-shift-p"), vec![vec!["⌘", "⇧", "P"]]);
+        assert_eq!(keys("alt-enter"), vec![vec!["⌥", "↩"]]);
+        assert_eq!(keys("escape"), vec![vec!["Esc"]]);
+    }
+
+    #[test]
+    fn test_platform_keystrokes() {
+        assert_eq!(platform_keystrokes("Shift-Cmd-P").as_ref(), "shift-cmd-p");
+        assert_eq!(platform_keystrokes("option-control-x").as_ref(), "ctrl-alt-x");
+        assert_eq!(platform_keystrokes("cmd-k  cmd-s").as_ref(), "cmd-k cmd-s");
+        assert_eq!(platform_keystrokes("cmd--").as_ref(), "cmd--");
+        assert_eq!(platform_keystrokes("secondary-s").as_ref(), format!("{SECONDARY}-s"));
+    }
+
+    #[test]
+    fn test_conflicts_are_refused() {
+        let mut registry = ShortcutRegistry::new();
+        registry.register(Shortcut::new("save", "cmd-s")).unwrap();
+
+        let conflict = registry.register(Shortcut::new("sync", "Cmd-S")).unwrap_err();
+        assert_eq!(conflict.existing.as_ref(), "save");
+        assert_eq!(conflict.rejected.as_ref(), "sync");
+
+        // A chord sequence starting with a bound keystroke could never be typed
+        assert!(registry.register(Shortcut::new("save_all", "cmd-s cmd-a")).is_err());
+        assert_eq!(registry.shortcuts().len(), 1);
+    }
+
+    #[test]
+    fn test_contexts_and_rebinding() {
+        let mut registry = ShortcutRegistry::new();
+        registry.register(Shortcut::new("save", "cmd-s")).unwrap();
+        // The same keys in a component context do not conflict
+        registry.register(Shortcut::new("editor.save", "cmd-s").context("Editor")).unwrap();
+        // Re-registering an id replaces its binding
+        registry.register(Shortcut::new("save", "cmd-alt-s")).unwrap();
+
+        assert_eq!(registry.get("save").unwrap().keystrokes.as_ref(), "alt-cmd-s");
+        assert_eq!(registry.shortcuts().len(), 2);
+        assert!(registry.unregister("save").is_some());
+        assert!(registry.get("save").is_none());
+    }
+}